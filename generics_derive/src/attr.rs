@@ -0,0 +1,171 @@
+use syn::spanned::Spanned;
+use syn::{Attribute, Error, Lit, LitStr, Meta, MetaNameValue, NestedMeta};
+
+/// Collects the options inside every `#[generic(...)]` attribute.
+pub fn generic_options(attrs: &[Attribute]) -> Result<Vec<Meta>, Error> {
+    let mut options = Vec::new();
+    for attr in attrs {
+        if !attr.path.is_ident("generic") {
+            continue;
+        }
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested {
+                    match nested {
+                        NestedMeta::Meta(meta) => options.push(meta),
+                        NestedMeta::Literal(lit) => {
+                            return Err(Error::new(lit.span(), "expected `#[generic(...)]` option"))
+                        }
+                    }
+                }
+            }
+            meta => return Err(Error::new(meta.name().span(), "expected `#[generic(...)]`")),
+        }
+    }
+    Ok(options)
+}
+
+/// Collects the lines of the doc comment in `attrs`, without the space after each `///`, or `""` if there are none.
+pub fn doc_comment(attrs: &[Attribute]) -> String {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(lit), ..
+            })) => Some(lit.value()),
+            _ => None,
+        })
+        .map(|line| match line.strip_prefix(' ') {
+            Some(line) => line.to_string(),
+            None => line,
+        })
+        .collect::<Vec<_>>();
+    lines.join("\n").trim().to_string()
+}
+
+/// Options which may be applied to a field of a type deriving `Generic`.
+#[derive(Default)]
+pub struct FieldOptions {
+    /// `#[generic(opaque)]`: represent the field as an `Opaque` leaf.
+    pub opaque: bool,
+    /// `#[generic(sensitive)]`: represent the field as a `Sensitive` leaf, which is redacted in debug output.
+    pub sensitive: bool,
+    /// `#[generic(default = "...")]`: the value `#[derive(GenericDefault)]` uses for the field.
+    pub default: Option<LitStr>,
+    /// `#[generic(ignore_eq)]`: skip the field when comparing or hashing with the derives which must agree on equality.
+    pub ignore_eq: bool,
+    /// `#[generic(merge = "...")]`: the strategy `#[derive(GenericMerge)]` uses for the field.
+    pub merge: Option<LitStr>,
+    /// `#[generic(strategy = "...")]`: the proptest strategy `#[derive(GenericStrategy)]` uses for the field.
+    pub strategy: Option<LitStr>,
+    /// `#[generic(sanitize = "...")]`: the rules `#[derive(GenericSanitize)]` applies to the field.
+    pub sanitize: Option<LitStr>,
+    /// `#[generic(env_default = "...")]`: the text `ops::from_env` parses when the field's variable is unset.
+    pub env_default: Option<LitStr>,
+}
+
+impl FieldOptions {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self, Error> {
+        let mut options = FieldOptions::default();
+        for meta in generic_options(attrs)? {
+            match &meta {
+                Meta::Word(ident) if ident == "opaque" => options.opaque = true,
+                Meta::Word(ident) if ident == "sensitive" => options.sensitive = true,
+                Meta::Word(ident) if ident == "ignore_eq" => options.ignore_eq = true,
+                Meta::NameValue(MetaNameValue {
+                    ident,
+                    lit: Lit::Str(lit),
+                    ..
+                }) if ident == "default" => options.default = Some(lit.clone()),
+                Meta::NameValue(MetaNameValue {
+                    ident,
+                    lit: Lit::Str(lit),
+                    ..
+                }) if ident == "merge" => options.merge = Some(lit.clone()),
+                Meta::NameValue(MetaNameValue {
+                    ident,
+                    lit: Lit::Str(lit),
+                    ..
+                }) if ident == "strategy" => options.strategy = Some(lit.clone()),
+                Meta::NameValue(MetaNameValue {
+                    ident,
+                    lit: Lit::Str(lit),
+                    ..
+                }) if ident == "sanitize" => options.sanitize = Some(lit.clone()),
+                Meta::NameValue(MetaNameValue {
+                    ident,
+                    lit: Lit::Str(lit),
+                    ..
+                }) if ident == "env_default" => options.env_default = Some(lit.clone()),
+                _ => {
+                    return Err(Error::new(
+                        meta.name().span(),
+                        "unknown `#[generic(...)]` field option",
+                    ))
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Options which may be applied to a variant of an enum deriving `Generic`.
+#[derive(Default)]
+pub struct VariantOptions {
+    /// `#[generic(default)]`: use the variant for `#[derive(GenericDefault)]`.
+    pub default: bool,
+    /// `#[generic(rename = "...")]`: name the variant differently in its metadata.
+    pub rename: Option<String>,
+}
+
+impl VariantOptions {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self, Error> {
+        let mut options = VariantOptions::default();
+        for meta in generic_options(attrs)? {
+            match &meta {
+                Meta::Word(ident) if ident == "default" => options.default = true,
+                Meta::NameValue(MetaNameValue {
+                    ident,
+                    lit: Lit::Str(lit),
+                    ..
+                }) if ident == "rename" => options.rename = Some(lit.value()),
+                _ => {
+                    return Err(Error::new(
+                        meta.name().span(),
+                        "unknown `#[generic(...)]` variant option",
+                    ))
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Options which may be applied to a type deriving `Generic`.
+#[derive(Default)]
+pub struct ContainerOptions {
+    /// `#[generic(const_fn)]`: also emit `const fn` conversions.
+    pub const_fn: bool,
+    /// `#[generic(case_insensitive)]`: ignore ASCII case in `#[derive(GenericFromStr)]`.
+    pub case_insensitive: bool,
+}
+
+impl ContainerOptions {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self, Error> {
+        let mut options = ContainerOptions::default();
+        for meta in generic_options(attrs)? {
+            match &meta {
+                Meta::Word(ident) if ident == "const_fn" => options.const_fn = true,
+                Meta::Word(ident) if ident == "case_insensitive" => options.case_insensitive = true,
+                _ => {
+                    return Err(Error::new(
+                        meta.name().span(),
+                        "unknown `#[generic(...)]` option",
+                    ))
+                }
+            }
+        }
+        Ok(options)
+    }
+}
@@ -0,0 +1,1276 @@
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{
+    Data, DataEnum, DataStruct, DeriveInput, Error, Expr, ExprCall, ExprPath, Fields, Ident,
+    IntSuffix, LitInt, LitStr, Path, Token, Type, TypePath, Visibility, WhereClause,
+};
+
+use crate::attr::{doc_comment, ContainerOptions, FieldOptions, VariantOptions};
+
+/// Primitive types whose representation is themselves, so they need no conversion in `const` contexts.
+#[rustfmt::skip]
+const CONST_LEAVES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize",
+    "i8", "i16", "i32", "i64", "i128", "isize",
+    "f32", "f64",
+    "bool", "char",
+];
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        attrs,
+        generics,
+        data,
+    } = input;
+
+    let options = ContainerOptions::parse(&attrs)?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let constructors = constructors(&name, &vis, &data)?;
+
+    let ty_predicates = constructors
+        .iter()
+        .flat_map(|con| con.generic_field_tys())
+        .map(|field_ty| quote! { #field_ty : ::generics::Generic })
+        .collect::<Vec<_>>();
+    let markers = constructors.iter().flat_map(|con| &con.markers);
+    // A struct's constructor is documented by the struct's doc comment.
+    let con_docs = constructors.iter().map(|con| match &data {
+        Data::Struct(_) => documented(&con.con_name, &doc_comment(&attrs)),
+        _ => documented(&con.con_name, &con.doc),
+    });
+    let con_values = constructors
+        .iter()
+        .map(|con| con.con_value.as_str())
+        .collect::<Vec<_>>();
+    let datatypes = constructors
+        .iter()
+        .map(|con| datatype(&con.con_name, &unraw(&name), &con_values));
+
+    let (ty, into, from, checked, into_const, from_const) = match &data {
+        Data::Struct(_) => {
+            let con = &constructors[0];
+            let Constructor {
+                ty, repr, value, ..
+            } = con;
+            let (into_conversions, from_conversions) = con.conversions();
+            let checked_conversions = con.checked_conversions();
+            let (const_into_conversions, const_from_conversions) = con.const_conversions();
+            (
+                ty.clone(),
+                quote! {
+                    let #value = self;
+                    #( #into_conversions )*
+                    #repr
+                },
+                quote! {
+                    let #repr = repr;
+                    #( #from_conversions )*
+                    #value
+                },
+                quote! {
+                    let #repr = repr;
+                    #( #checked_conversions )*
+                    ::std::result::Result::Ok(#value)
+                },
+                quote! {
+                    let #value = self;
+                    #( #const_into_conversions )*
+                    #repr
+                },
+                quote! {
+                    let #repr = repr;
+                    #( #const_from_conversions )*
+                    #value
+                },
+            )
+        }
+        _ => {
+            // Each variant `i` is represented as `Right^i(Left(..))`, ending in `Void`.
+            let ty = constructors
+                .iter()
+                .rev()
+                .fold(quote! { ::generics::Void }, |acc, con| {
+                    let con_ty = &con.ty;
+                    quote! { ::generics::Sum<#con_ty, #acc> }
+                });
+            let injections = constructors
+                .iter()
+                .enumerate()
+                .map(|(i, con)| inject(i, &con.repr))
+                .collect::<Vec<_>>();
+            let void = (0..constructors.len()).fold(
+                quote! { void },
+                |acc, _| quote! { ::generics::Sum::Right(#acc) },
+            );
+
+            let mut into_arms = Vec::new();
+            let mut from_arms = Vec::new();
+            let mut checked_arms = Vec::new();
+            let mut const_into_arms = Vec::new();
+            let mut const_from_arms = Vec::new();
+            for (con, injection) in constructors.iter().zip(&injections) {
+                let value = &con.value;
+                let (into_conversions, from_conversions) = con.conversions();
+                let checked_conversions = con.checked_conversions();
+                let (const_into_conversions, const_from_conversions) = con.const_conversions();
+                into_arms.push(quote! {
+                    #value => {
+                        #( #into_conversions )*
+                        #injection
+                    }
+                });
+                from_arms.push(quote! {
+                    #injection => {
+                        #( #from_conversions )*
+                        #value
+                    }
+                });
+                checked_arms.push(quote! {
+                    #injection => {
+                        #( #checked_conversions )*
+                        ::std::result::Result::Ok(#value)
+                    }
+                });
+                const_into_arms.push(quote! {
+                    #value => {
+                        #( #const_into_conversions )*
+                        #injection
+                    }
+                });
+                const_from_arms.push(quote! {
+                    #injection => {
+                        #( #const_from_conversions )*
+                        #value
+                    }
+                });
+            }
+            let (from_arms, const_from_arms) = (&from_arms, &const_from_arms);
+            (
+                ty,
+                quote! {
+                    match self {
+                        #( #into_arms )*
+                    }
+                },
+                quote! {
+                    match repr {
+                        #( #from_arms )*
+                        #void => match void {},
+                    }
+                },
+                quote! {
+                    match repr {
+                        #( #checked_arms )*
+                        #void => match void {},
+                    }
+                },
+                quote! {
+                    match self {
+                        #( #const_into_arms )*
+                    }
+                },
+                quote! {
+                    match repr {
+                        #( #const_from_arms )*
+                        #void => match void {},
+                    }
+                },
+            )
+        }
+    };
+
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    let const_fns = if options.const_fn {
+        let into_doc = format!(
+            "Converts `{}` into its generic representation, in a `const` context.",
+            name
+        );
+        let from_doc = format!(
+            "Constructs `{}` from its generic representation, in a `const` context.",
+            name
+        );
+        quote! {
+            impl #impl_generics #name #ty_generics #combined_where_clause {
+                #[doc = #into_doc]
+                #[allow(dead_code)]
+                #vis const fn into_repr_const(self) -> <Self as ::generics::Generic>::Repr {
+                    #into_const
+                }
+
+                #[doc = #from_doc]
+                #[allow(dead_code)]
+                #vis const fn from_repr_const(repr: <Self as ::generics::Generic>::Repr) -> Self {
+                    #from_const
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        #( #markers )*
+        #( #con_docs )*
+        #( #datatypes )*
+
+        impl #impl_generics ::generics::Generic for #name #ty_generics #combined_where_clause {
+            type Repr = #ty;
+            fn into_repr(self) -> Self::Repr {
+                #into
+            }
+            fn from_repr(repr: Self::Repr) -> Self {
+                #from
+            }
+            fn checked_from_repr(
+                repr: Self::Repr,
+            ) -> ::std::result::Result<Self, ::generics::ReprError> {
+                #checked
+            }
+        }
+
+        #const_fns
+    })
+}
+
+/// The constructors of a struct or enum, in declaration order.
+pub fn constructors<'a>(
+    name: &Ident,
+    vis: &Visibility,
+    data: &'a Data,
+) -> Result<Vec<Constructor<'a>>, Error> {
+    match data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            let name = unraw(name);
+            Ok(vec![Constructor::new(
+                vis,
+                &name,
+                &name,
+                None,
+                quote! { Self },
+                fields,
+            )?])
+        }
+        Data::Enum(DataEnum { variants, .. }) => variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                let options = VariantOptions::parse(&variant.attrs)?;
+                let con = Constructor::new(
+                    vis,
+                    &format!("{}_{}", unraw(name), unraw(ident)),
+                    &format!("{}::{}", unraw(name), unraw(ident)),
+                    options.rename.as_deref(),
+                    quote! { Self::#ident },
+                    &variant.fields,
+                )?;
+                Ok(Constructor {
+                    default: options.default,
+                    doc: doc_comment(&variant.attrs),
+                    ..con
+                })
+            })
+            .collect(),
+        Data::Union(_) => Err(Error::new(
+            name.span(),
+            "`Generic` cannot be derived for unions",
+        )),
+    }
+}
+
+/// An expression borrowing each field of `expr`, a reference to a value, into a representation of `Borrowed` leaves.
+pub fn borrowed_repr(data: &Data, constructors: &[Constructor], expr: TokenStream) -> TokenStream {
+    view_repr(
+        data,
+        constructors,
+        expr,
+        |con| con.borrowed_ty.clone(),
+        |con| con.borrows(),
+    )
+}
+
+/// Like `borrowed_repr`, but with each sensitive field's leaf wrapped in `Sensitive`.
+pub fn redacted_repr(data: &Data, constructors: &[Constructor], expr: TokenStream) -> TokenStream {
+    view_repr(
+        data,
+        constructors,
+        expr,
+        |con| con.redacted_ty.clone(),
+        |con| con.redacted_borrows(),
+    )
+}
+
+/// Like `borrowed_repr`, but with each `#[generic(ignore_eq)]` field's leaf wrapped in `Ignored`.
+pub fn compared_repr(data: &Data, constructors: &[Constructor], expr: TokenStream) -> TokenStream {
+    view_repr(
+        data,
+        constructors,
+        expr,
+        |con| con.compared_ty.clone(),
+        |con| con.compared_borrows(),
+    )
+}
+
+/// An expression binding each field of `expr`, a value or a reference to one, in an `Opaque` leaf,
+/// into a representation of type `Con<Prod<Meta<Opaque<_>, Foo_a>, ..>, Foo_Name>`.
+pub fn opaque_repr(data: &Data, constructors: &[Constructor], expr: TokenStream) -> TokenStream {
+    view_repr(
+        data,
+        constructors,
+        expr,
+        |con| con.opaque_ty.clone(),
+        |con| con.opaque_borrows(),
+    )
+}
+
+/// An expression binding each field of `expr`, a mutable reference to a value, with its rules,
+/// into a representation of type `Con<Prod<Meta<Rules<'_, u8, _>, Foo_a>, ..>, Foo_Name>`.
+pub fn sanitized_repr(data: &Data, constructors: &[Constructor], expr: TokenStream) -> TokenStream {
+    view_repr(
+        data,
+        constructors,
+        expr,
+        |con| con.sanitized_ty.clone(),
+        |con| con.sanitized_borrows(),
+    )
+}
+
+/// An expression binding each field of `expr`, a reference to a value, with `borrows` into a representation of type `ty`.
+fn view_repr(
+    data: &Data,
+    constructors: &[Constructor],
+    expr: TokenStream,
+    ty: impl Fn(&Constructor) -> TokenStream,
+    borrows: impl Fn(&Constructor) -> Vec<TokenStream>,
+) -> TokenStream {
+    let view_ty = &ty;
+    let view_borrows = &borrows;
+    let (ty, arms) = match data {
+        Data::Enum(_) => {
+            let ty = constructors
+                .iter()
+                .rev()
+                .fold(quote! { ::generics::Void }, |acc, con| {
+                    let borrowed_ty = view_ty(con);
+                    quote! { ::generics::Sum<#borrowed_ty, #acc> }
+                });
+            let arms = constructors
+                .iter()
+                .enumerate()
+                .map(|(i, con)| {
+                    let Constructor { repr, value, .. } = con;
+                    let borrows = view_borrows(con);
+                    let injection = inject(i, repr);
+                    quote! {
+                        #value => {
+                            #( #borrows )*
+                            #injection
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+            (ty, arms)
+        }
+        _ => {
+            let con = &constructors[0];
+            let Constructor { repr, value, .. } = con;
+            let borrowed_ty = view_ty(con);
+            let borrows = view_borrows(con);
+            let arm = quote! {
+                #value => {
+                    #( #borrows )*
+                    #repr
+                }
+            };
+            (borrowed_ty, vec![arm])
+        }
+    };
+    let body = if arms.is_empty() {
+        quote! { match *#expr {} }
+    } else {
+        quote! {
+            match #expr {
+                #( #arms )*
+            }
+        }
+    };
+    quote! {{
+        let borrowed: #ty = #body;
+        borrowed
+    }}
+}
+
+/// The pieces of a `Generic` impl for a single constructor.
+pub struct Constructor<'a> {
+    /// The representation's type, e.g. `Con<Prod<Meta<..>, ..>, Foo_Name>`.
+    pub ty: TokenStream,
+    /// The type of the representation with each field left as a `Borrowed` leaf,
+    /// e.g. `Con<Prod<Meta<Borrowed<'_, u8>, Foo_a>, ..>, Foo_Name>`.
+    pub borrowed_ty: TokenStream,
+    /// The type of the representation with each field left as an `Opaque` leaf, whose type is inferred,
+    /// e.g. `Con<Prod<Meta<Opaque<_>, Foo_a>, ..>, Foo_Name>`.
+    pub opaque_ty: TokenStream,
+    /// The type of the borrowed representation with each sensitive field wrapped in `Sensitive`,
+    /// e.g. `Con<Prod<Meta<Sensitive<Borrowed<'_, String>>, Foo_a>, ..>, Foo_Name>`.
+    pub redacted_ty: TokenStream,
+    /// The type of the borrowed representation with each `#[generic(ignore_eq)]` field wrapped in `Ignored`,
+    /// e.g. `Con<Prod<Meta<Ignored<Borrowed<'_, u64>>, Foo_a>, ..>, Foo_Name>`.
+    pub compared_ty: TokenStream,
+    /// The type of the representation with each field borrowed in `Rules` along with its sanitization rules,
+    /// whose types are inferred, e.g. `Con<Prod<Meta<Rules<'_, u8, _>, Foo_a>, ..>, Foo_Name>`.
+    pub sanitized_ty: TokenStream,
+    /// The type of the mutable representation, borrowing each field for `'__generic`,
+    /// e.g. `Con<Prod<Meta<<u8 as GenericMut<'__generic>>::ReprMut, Foo_a>, ..>, Foo_Name>`.
+    pub mut_ty: TokenStream,
+    /// The type of the representation with each field wrapped in its merge strategy,
+    /// e.g. `Con<Prod<Meta<WithStrategy<TakeNewer, u8>, Foo_a>, ..>, Foo_Name>`.
+    pub merge_ty: TokenStream,
+    /// The type of the representation with each field given a strategy by `#[generic(strategy = "...")]`
+    /// left as an `Override` leaf, e.g. `Con<Prod<Meta<Override<Self, Foo_a>, Foo_a>, ..>, Foo_Name>`.
+    pub strategy_ty: TokenStream,
+    /// The type of the representation with each field mapped by `Columns`, i.e. made a column,
+    /// e.g. `Con<Prod<Meta<Vec<u8>, Foo_a>, ..>, Foo_Name>`.
+    pub columns_ty: TokenStream,
+    /// The type of the representation with each field mapped by `Partial`, i.e. made optional,
+    /// e.g. `Con<Prod<Meta<Option<u8>, Foo_a>, ..>, Foo_Name>`.
+    pub partial_ty: TokenStream,
+    /// The merge strategy of each field, from `#[generic(merge = "...")]`.
+    pub strategies: Vec<TokenStream>,
+    /// The default value of each field, from `#[generic(default = "...")]`.
+    pub field_defaults: Vec<Option<Expr>>,
+    /// The proptest strategy of each field, from `#[generic(strategy = "...")]`.
+    pub field_strategies: Vec<Option<Expr>>,
+    /// The sanitization rules of each field, from `#[generic(sanitize = "...")]`.
+    sanitizers: Vec<Vec<TokenStream>>,
+    /// The representation, binding each field's representation to an ordinal.
+    /// Usable as both a pattern and an expression.
+    pub repr: TokenStream,
+    /// The value, binding each field to an ordinal, e.g. `Self { a: _0 }`.
+    /// Usable as both a pattern and an expression.
+    pub value: TokenStream,
+    /// The ordinal bound to each field, e.g. `_0`.
+    pub ordinals: Vec<Ident>,
+    /// The member naming each field, e.g. `a` or `0`.
+    pub members: Vec<TokenStream>,
+    /// The selector marking each field, e.g. `Foo_a`.
+    pub selectors: Vec<Ident>,
+    /// The type of each field.
+    pub field_tys: Vec<&'a Type>,
+    /// The leaf wrapping each field which isn't converted to its representation,
+    /// i.e. `Opaque` for `#[generic(opaque)]` or `Sensitive` for `#[generic(sensitive)]`.
+    wrappers: Vec<Option<TokenStream>>,
+    /// Whether each field is `#[generic(sensitive)]`.
+    sensitive: Vec<bool>,
+    /// Whether each field is `#[generic(opaque)]`.
+    opaque: Vec<bool>,
+    /// Whether each field is `#[generic(ignore_eq)]`.
+    ignore_eq: Vec<bool>,
+    /// Whether the constructor is a variant marked `#[generic(default)]`.
+    pub default: bool,
+    /// The doc comment of the constructor, if it's a variant.
+    doc: String,
+    /// The `Singleton` naming the constructor, e.g. `Foo_Name`.
+    con_name: Ident,
+    /// The name held by `con_name`, e.g. `Bar` for `Foo::Bar`.
+    con_value: String,
+    /// Declarations of the `Singleton`s naming the constructor and its fields.
+    markers: Vec<TokenStream>,
+}
+
+impl<'a> Constructor<'a> {
+    /// `prefix` is used to name the constructor's metadata, e.g. `Foo_Bar` for `Foo_Bar_Name`, `Foo_Bar_a`, etc.
+    ///
+    /// `name` is the constructor's name as it appears in docs, e.g. `Foo::Bar`,
+    /// and `path` is used to construct and match it, e.g. `Self::Bar`.
+    /// `rename` replaces the name stored in the constructor's metadata, e.g. `bar` instead of `Bar`.
+    fn new(
+        vis: &Visibility,
+        prefix: &str,
+        name: &str,
+        rename: Option<&str>,
+        path: TokenStream,
+        fields: &'a Fields,
+    ) -> Result<Self, Error> {
+        let field_names = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| match &field.ident {
+                Some(ident) => unraw(ident),
+                None => i.to_string(),
+            })
+            .collect::<Vec<_>>();
+        let selectors = field_names
+            .iter()
+            .map(|field_name| Ident::new(&format!("{}_{}", prefix, field_name), Span::call_site()))
+            .collect::<Vec<_>>();
+        let field_tys = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+        let options = fields
+            .iter()
+            .map(|field| FieldOptions::parse(&field.attrs))
+            .collect::<Result<Vec<_>, _>>()?;
+        let sensitive = options
+            .iter()
+            .map(|options| options.sensitive)
+            .collect::<Vec<_>>();
+        let opaque = options
+            .iter()
+            .map(|options| options.opaque)
+            .collect::<Vec<_>>();
+        let ignore_eq = options
+            .iter()
+            .map(|options| options.ignore_eq)
+            .collect::<Vec<_>>();
+        let wrappers = options
+            .iter()
+            .map(|options| {
+                if options.sensitive {
+                    Some(quote! { ::generics::Sensitive })
+                } else if options.opaque {
+                    Some(quote! { ::generics::Opaque })
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        let strategies = options
+            .iter()
+            .map(|options| merge_strategy(options.merge.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let field_defaults = options
+            .iter()
+            .map(|options| options.default.as_ref().map(LitStr::parse).transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+        let field_strategies = options
+            .iter()
+            .map(|options| options.strategy.as_ref().map(LitStr::parse).transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+        let sanitizers = options
+            .iter()
+            .map(|options| sanitize_rules(options.sanitize.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let ordinals = (0..field_tys.len())
+            .map(|i| Ident::new(&format!("_{}", i), Span::call_site()))
+            .collect::<Vec<_>>();
+        let con_name = Ident::new(&format!("{}_Name", prefix), Span::call_site());
+        // For variants, only the variant's own name, e.g. `Bar` for `Foo::Bar`.
+        let con_value = rename.unwrap_or_else(|| name.rsplit("::").next().unwrap_or(name));
+
+        let prod_ty = field_tys.iter().zip(&wrappers).zip(&selectors).rev().fold(
+            quote! { ::generics::Unit },
+            |acc, ((field_ty, wrapper), selector)| {
+                let field_repr = match wrapper {
+                    Some(wrapper) => quote! { #wrapper<#field_ty> },
+                    None => quote! { <#field_ty as ::generics::Generic>::Repr },
+                };
+                quote! {
+                    ::generics::Prod<::generics::Meta<#field_repr, #selector>, #acc>
+                }
+            },
+        );
+        let ty = quote! { ::generics::Con<#prod_ty, #con_name> };
+
+        let borrowed_prod_ty = field_tys.iter().zip(&selectors).rev().fold(
+            quote! { ::generics::Unit },
+            |acc, (field_ty, selector)| {
+                quote! {
+                    ::generics::Prod<
+                        ::generics::Meta<::generics::ops::Borrowed<'_, #field_ty>, #selector>,
+                        #acc
+                    >
+                }
+            },
+        );
+        let borrowed_ty = quote! { ::generics::Con<#borrowed_prod_ty, #con_name> };
+
+        let opaque_prod_ty =
+            selectors
+                .iter()
+                .rev()
+                .fold(quote! { ::generics::Unit }, |acc, selector| {
+                    quote! {
+                        ::generics::Prod<::generics::Meta<::generics::Opaque<_>, #selector>, #acc>
+                    }
+                });
+        let opaque_ty = quote! { ::generics::Con<#opaque_prod_ty, #con_name> };
+
+        let redacted_prod_ty = field_tys.iter().zip(&sensitive).zip(&selectors).rev().fold(
+            quote! { ::generics::Unit },
+            |acc, ((field_ty, &sensitive), selector)| {
+                let field_repr = if sensitive {
+                    quote! { ::generics::Sensitive<::generics::ops::Borrowed<'_, #field_ty>> }
+                } else {
+                    quote! { ::generics::ops::Borrowed<'_, #field_ty> }
+                };
+                quote! {
+                    ::generics::Prod<::generics::Meta<#field_repr, #selector>, #acc>
+                }
+            },
+        );
+        let redacted_ty = quote! { ::generics::Con<#redacted_prod_ty, #con_name> };
+
+        let compared_prod_ty = field_tys.iter().zip(&ignore_eq).zip(&selectors).rev().fold(
+            quote! { ::generics::Unit },
+            |acc, ((field_ty, &ignore_eq), selector)| {
+                let field_repr = if ignore_eq {
+                    quote! { ::generics::ops::Ignored<::generics::ops::Borrowed<'_, #field_ty>> }
+                } else {
+                    quote! { ::generics::ops::Borrowed<'_, #field_ty> }
+                };
+                quote! {
+                    ::generics::Prod<::generics::Meta<#field_repr, #selector>, #acc>
+                }
+            },
+        );
+        let compared_ty = quote! { ::generics::Con<#compared_prod_ty, #con_name> };
+
+        let sanitized_prod_ty = field_tys.iter().zip(&opaque).zip(&selectors).rev().fold(
+            quote! { ::generics::Unit },
+            |acc, ((field_ty, &opaque), selector)| {
+                let field_repr = if opaque {
+                    quote! { ::generics::Opaque<::generics::ops::Rules<'_, #field_ty, _>> }
+                } else {
+                    quote! { ::generics::ops::Rules<'_, #field_ty, _> }
+                };
+                quote! {
+                    ::generics::Prod<::generics::Meta<#field_repr, #selector>, #acc>
+                }
+            },
+        );
+        let sanitized_ty = quote! { ::generics::Con<#sanitized_prod_ty, #con_name> };
+
+        let mut_prod_ty = field_tys.iter().zip(&wrappers).zip(&selectors).rev().fold(
+            quote! { ::generics::Unit },
+            |acc, ((field_ty, wrapper), selector)| {
+                let field_repr = match wrapper {
+                    Some(wrapper) => quote! { #wrapper<&'__generic mut #field_ty> },
+                    None => quote! { <#field_ty as ::generics::GenericMut<'__generic>>::ReprMut },
+                };
+                quote! {
+                    ::generics::Prod<::generics::Meta<#field_repr, #selector>, #acc>
+                }
+            },
+        );
+        let mut_ty = quote! { ::generics::Con<#mut_prod_ty, #con_name> };
+
+        let merge_prod_ty = field_tys.iter().zip(&strategies).zip(&selectors).rev().fold(
+            quote! { ::generics::Unit },
+            |acc, ((field_ty, strategy), selector)| {
+                quote! {
+                    ::generics::Prod<
+                        ::generics::Meta<::generics::ops::WithStrategy<#strategy, #field_ty>, #selector>,
+                        #acc
+                    >
+                }
+            },
+        );
+        let merge_ty = quote! { ::generics::Con<#merge_prod_ty, #con_name> };
+
+        let strategy_prod_ty = field_tys
+            .iter()
+            .zip(&wrappers)
+            .zip(&field_strategies)
+            .zip(&selectors)
+            .rev()
+            .fold(
+                quote! { ::generics::Unit },
+                |acc, (((field_ty, wrapper), field_strategy), selector)| {
+                    let field_repr = match (field_strategy, wrapper) {
+                        (Some(_), _) => quote! { ::generics::ops::Override<Self, #selector> },
+                        (None, Some(wrapper)) => quote! { #wrapper<#field_ty> },
+                        (None, None) => quote! { <#field_ty as ::generics::Generic>::Repr },
+                    };
+                    quote! {
+                        ::generics::Prod<::generics::Meta<#field_repr, #selector>, #acc>
+                    }
+                },
+            );
+        let strategy_ty = quote! { ::generics::Con<#strategy_prod_ty, #con_name> };
+
+        let columns_prod_ty = field_tys.iter().zip(&selectors).rev().fold(
+            quote! { ::generics::Unit },
+            |acc, (field_ty, selector)| {
+                quote! {
+                    ::generics::Prod<
+                        ::generics::Meta<
+                            <::generics::ops::Columns as ::generics::TypeFn<#field_ty>>::Output,
+                            #selector
+                        >,
+                        #acc
+                    >
+                }
+            },
+        );
+        let columns_ty = quote! { ::generics::Con<#columns_prod_ty, #con_name> };
+
+        let partial_prod_ty = field_tys.iter().zip(&selectors).rev().fold(
+            quote! { ::generics::Unit },
+            |acc, (field_ty, selector)| {
+                quote! {
+                    ::generics::Prod<
+                        ::generics::Meta<
+                            <::generics::ops::Partial as ::generics::TypeFn<#field_ty>>::Output,
+                            #selector
+                        >,
+                        #acc
+                    >
+                }
+            },
+        );
+        let partial_ty = quote! { ::generics::Con<#partial_prod_ty, #con_name> };
+
+        let prod = ordinals
+            .iter()
+            .rev()
+            .fold(quote! { ::generics::Unit }, |acc, ordinal| {
+                quote! {
+                    ::generics::Prod(
+                        ::generics::Meta(#ordinal, ::std::marker::PhantomData),
+                        #acc
+                    )
+                }
+            });
+        let repr = quote! { ::generics::Con(#prod, ::std::marker::PhantomData) };
+
+        let members = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| match &field.ident {
+                Some(ident) => quote! { #ident },
+                None => {
+                    let lit = LitInt::new(i as u64, IntSuffix::None, Span::call_site());
+                    quote! { #lit }
+                }
+            })
+            .collect::<Vec<_>>();
+        let value = {
+            let (members, ordinals) = (&members, &ordinals);
+            quote! { #path { #(#members : #ordinals),* } }
+        };
+
+        let con_doc = format!("Name of the `{}` constructor.", name);
+        let markers = field_names
+            .iter()
+            .zip(&selectors)
+            .zip(fields.iter().zip(&options))
+            .flat_map(|((field_name, selector), (field, options))| {
+                let doc = format!("Selector for the `{}` field of `{}`.", field_name, name);
+                vec![
+                    singleton(vis, selector, &doc, field_name),
+                    documented(selector, &doc_comment(&field.attrs)),
+                    field_meta(selector, &field.ty, options.env_default.as_ref()),
+                ]
+            })
+            .chain(Some(singleton(vis, &con_name, &con_doc, con_value)))
+            .chain(Some(constructor(&con_name, fields, &field_names)))
+            .collect::<Vec<_>>();
+
+        Ok(Constructor {
+            ty,
+            borrowed_ty,
+            opaque_ty,
+            redacted_ty,
+            compared_ty,
+            sanitized_ty,
+            mut_ty,
+            merge_ty,
+            strategy_ty,
+            columns_ty,
+            partial_ty,
+            strategies,
+            field_defaults,
+            field_strategies,
+            sanitizers,
+            repr,
+            value,
+            ordinals,
+            members,
+            selectors,
+            field_tys,
+            wrappers,
+            sensitive,
+            opaque,
+            ignore_eq,
+            default: false,
+            doc: String::new(),
+            con_name,
+            con_value: con_value.to_string(),
+            markers,
+        })
+    }
+
+    /// Statements wrapping each field, bound by reference to its ordinal, in `Borrowed`.
+    pub fn borrows(&self) -> Vec<TokenStream> {
+        self.ordinals
+            .iter()
+            .map(|ordinal| quote! { let #ordinal = ::generics::ops::Borrowed(#ordinal); })
+            .collect()
+    }
+
+    /// Statements wrapping each field, bound by reference to its ordinal, in `Borrowed`,
+    /// and each sensitive field additionally in `Sensitive`.
+    pub fn redacted_borrows(&self) -> Vec<TokenStream> {
+        self.ordinals
+            .iter()
+            .zip(&self.sensitive)
+            .map(|(ordinal, &sensitive)| {
+                if sensitive {
+                    quote! {
+                        let #ordinal = ::generics::Sensitive(::generics::ops::Borrowed(#ordinal));
+                    }
+                } else {
+                    quote! { let #ordinal = ::generics::ops::Borrowed(#ordinal); }
+                }
+            })
+            .collect()
+    }
+
+    /// Statements wrapping each field, bound by reference to its ordinal, in `Borrowed`,
+    /// and each `#[generic(ignore_eq)]` field additionally in `Ignored`.
+    pub fn compared_borrows(&self) -> Vec<TokenStream> {
+        self.ordinals
+            .iter()
+            .zip(&self.ignore_eq)
+            .map(|(ordinal, &ignore_eq)| {
+                if ignore_eq {
+                    quote! {
+                        let #ordinal = ::generics::ops::Ignored(::generics::ops::Borrowed(#ordinal));
+                    }
+                } else {
+                    quote! { let #ordinal = ::generics::ops::Borrowed(#ordinal); }
+                }
+            })
+            .collect()
+    }
+
+    /// Statements wrapping each field, bound by value or by reference to its ordinal, in `Opaque`.
+    pub fn opaque_borrows(&self) -> Vec<TokenStream> {
+        self.ordinals
+            .iter()
+            .map(|ordinal| quote! { let #ordinal = ::generics::Opaque(#ordinal); })
+            .collect()
+    }
+
+    /// Statements wrapping each field, bound by mutable reference to its ordinal, in `Rules` along with its rules,
+    /// and in `Opaque` if it's opaque.
+    pub fn sanitized_borrows(&self) -> Vec<TokenStream> {
+        self.ordinals
+            .iter()
+            .zip(&self.opaque)
+            .zip(&self.sanitizers)
+            .map(|((ordinal, &opaque), rules)| {
+                let rules = quote! { ::generics::ops::Rules(#ordinal, ( #( #rules, )* )) };
+                if opaque {
+                    quote! { let #ordinal = ::generics::Opaque(#rules); }
+                } else {
+                    quote! { let #ordinal = #rules; }
+                }
+            })
+            .collect()
+    }
+
+    /// Statements borrowing each field, bound by mutable reference to its ordinal, as its mutable representation.
+    pub fn mut_conversions(&self) -> Vec<TokenStream> {
+        self.ordinals
+            .iter()
+            .zip(&self.wrappers)
+            .map(|(ordinal, wrapper)| match wrapper {
+                Some(wrapper) => quote! { let #ordinal = #wrapper(#ordinal); },
+                None => quote! { let #ordinal = ::generics::GenericMut::as_repr_mut(#ordinal); },
+            })
+            .collect()
+    }
+
+    /// Statements converting each field, bound to its ordinal in the representation, to its value.
+    pub fn value_conversions(&self) -> Vec<TokenStream> {
+        self.conversions().1
+    }
+
+    /// Statements converting each field, bound to its ordinal in the representation of type `strategy_ty`, to its value.
+    pub fn strategy_conversions(&self) -> Vec<TokenStream> {
+        self.ordinals
+            .iter()
+            .zip(&self.wrappers)
+            .zip(&self.field_strategies)
+            .map(
+                |((ordinal, wrapper), field_strategy)| match (field_strategy, wrapper) {
+                    (Some(_), _) => {
+                        quote! { let ::generics::ops::Override(#ordinal, _) = #ordinal; }
+                    }
+                    (None, Some(wrapper)) => wrapper_conversions(wrapper, ordinal).1,
+                    (None, None) => {
+                        quote! { let #ordinal = ::generics::Generic::from_repr(#ordinal); }
+                    }
+                },
+            )
+            .collect()
+    }
+
+    /// The types of fields which are converted to their representation, i.e. which aren't opaque or sensitive.
+    pub fn generic_field_tys(&self) -> impl Iterator<Item = &&'a Type> {
+        self.field_tys
+            .iter()
+            .zip(&self.wrappers)
+            .filter(|(_, wrapper)| wrapper.is_none())
+            .map(|(field_ty, _)| field_ty)
+    }
+
+    /// The types of fields which are sanitized with their own `Sanitize` impl, i.e. which aren't opaque.
+    pub fn sanitized_field_tys(&self) -> impl Iterator<Item = &&'a Type> {
+        self.field_tys
+            .iter()
+            .zip(&self.opaque)
+            .filter(|(_, &opaque)| !opaque)
+            .map(|(field_ty, _)| field_ty)
+    }
+
+    /// The types of fields which are compared, i.e. which aren't `#[generic(ignore_eq)]`.
+    pub fn compared_field_tys(&self) -> impl Iterator<Item = &&'a Type> {
+        self.field_tys
+            .iter()
+            .zip(&self.ignore_eq)
+            .filter(|(_, &ignore_eq)| !ignore_eq)
+            .map(|(field_ty, _)| field_ty)
+    }
+
+    /// The types of fields whose values appear in debug output, i.e. which aren't sensitive.
+    pub fn unredacted_field_tys(&self) -> impl Iterator<Item = &&'a Type> {
+        self.field_tys
+            .iter()
+            .zip(&self.sensitive)
+            .filter(|(_, &sensitive)| !sensitive)
+            .map(|(field_ty, _)| field_ty)
+    }
+
+    /// Statements converting each field, bound to its ordinal in the representation, to its value,
+    /// returning early if its representation is invalid.
+    fn checked_conversions(&self) -> Vec<TokenStream> {
+        self.ordinals
+            .iter()
+            .zip(&self.wrappers)
+            .map(|(ordinal, wrapper)| match wrapper {
+                Some(wrapper) => wrapper_conversions(wrapper, ordinal).1,
+                None => {
+                    quote! { let #ordinal = ::generics::Generic::checked_from_repr(#ordinal)?; }
+                }
+            })
+            .collect()
+    }
+
+    /// Field conversions, in each direction.
+    fn conversions(&self) -> (Vec<TokenStream>, Vec<TokenStream>) {
+        self.ordinals
+            .iter()
+            .zip(&self.wrappers)
+            .map(|(ordinal, wrapper)| match wrapper {
+                Some(wrapper) => wrapper_conversions(wrapper, ordinal),
+                None => (
+                    quote! { let #ordinal = ::generics::Generic::into_repr(#ordinal); },
+                    quote! { let #ordinal = ::generics::Generic::from_repr(#ordinal); },
+                ),
+            })
+            .unzip()
+    }
+
+    /// Field conversions usable in a `const fn`, in each direction.
+    ///
+    /// Leaves need no conversion, and other fields are assumed to provide `const` conversions of their own.
+    fn const_conversions(&self) -> (Vec<TokenStream>, Vec<TokenStream>) {
+        self.field_tys
+            .iter()
+            .zip(&self.wrappers)
+            .zip(&self.ordinals)
+            .filter_map(|((field_ty, wrapper), ordinal)| {
+                if let Some(wrapper) = wrapper {
+                    Some(wrapper_conversions(wrapper, ordinal))
+                } else if is_const_leaf(field_ty) {
+                    None
+                } else {
+                    Some((
+                        quote! { let #ordinal = <#field_ty>::into_repr_const(#ordinal); },
+                        quote! { let #ordinal = <#field_ty>::from_repr_const(#ordinal); },
+                    ))
+                }
+            })
+            .unzip()
+    }
+}
+
+/// The strategy named by `#[generic(merge = "...")]`, either a built-in strategy or the path to a type.
+///
+/// Fields without a strategy take the newer value.
+fn merge_strategy(lit: Option<&LitStr>) -> Result<TokenStream, Error> {
+    let lit = match lit {
+        Some(lit) => lit,
+        None => return Ok(quote! { ::generics::ops::TakeNewer }),
+    };
+    Ok(match lit.value().as_str() {
+        "newer" => quote! { ::generics::ops::TakeNewer },
+        "non_default" => quote! { ::generics::ops::TakeNonDefault },
+        "concat" => quote! { ::generics::ops::Concat },
+        "deep" => quote! { ::generics::ops::Deep },
+        _ => {
+            let path = lit.parse::<Path>()?;
+            quote! { #path }
+        }
+    })
+}
+
+/// The rules named by `#[generic(sanitize = "...")]`, a comma-separated list of built-in rules,
+/// e.g. `trim` or `clamp(0, 100)`, or expressions evaluating to a rule.
+fn sanitize_rules(lit: Option<&LitStr>) -> Result<Vec<TokenStream>, Error> {
+    let lit = match lit {
+        Some(lit) => lit,
+        None => return Ok(Vec::new()),
+    };
+    let rules = lit.parse_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+    Ok(rules
+        .into_iter()
+        .map(|rule| match &rule {
+            Expr::Path(ExprPath {
+                qself: None, path, ..
+            }) if path.is_ident("trim") => {
+                quote! { ::generics::ops::Trim }
+            }
+            Expr::Path(ExprPath {
+                qself: None, path, ..
+            }) if path.is_ident("lowercase") => {
+                quote! { ::generics::ops::Lowercase }
+            }
+            Expr::Path(ExprPath {
+                qself: None, path, ..
+            }) if path.is_ident("nfc") => {
+                quote! { ::generics::ops::Nfc }
+            }
+            Expr::Path(ExprPath {
+                qself: None, path, ..
+            }) if path.is_ident("dedup") => {
+                quote! { ::generics::ops::Dedup }
+            }
+            Expr::Call(ExprCall { func, args, .. }) => match &**func {
+                Expr::Path(ExprPath {
+                    qself: None, path, ..
+                }) if path.is_ident("clamp") => {
+                    quote! { ::generics::ops::Clamp(#args) }
+                }
+                _ => quote! { #rule },
+            },
+            _ => quote! { #rule },
+        })
+        .collect())
+}
+
+/// Conversions of a field wrapped in a leaf such as `Opaque`, in each direction. These are usable in a `const fn`.
+fn wrapper_conversions(wrapper: &TokenStream, ordinal: &Ident) -> (TokenStream, TokenStream) {
+    (
+        quote! { let #ordinal = #wrapper(#ordinal); },
+        quote! { let #wrapper(#ordinal) = #ordinal; },
+    )
+}
+
+fn is_const_leaf(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => CONST_LEAVES
+            .iter()
+            .any(|leaf| path.is_ident(Ident::new(leaf, Span::call_site()))),
+        _ => false,
+    }
+}
+
+/// Injects the representation of the `i`th constructor of an enum into the enum's representation,
+/// i.e. `Right^i(Left(repr))`.
+pub fn inject(i: usize, repr: &TokenStream) -> TokenStream {
+    (0..i).fold(
+        quote! { ::generics::Sum::Left(#repr) },
+        |acc, _| quote! { ::generics::Sum::Right(#acc) },
+    )
+}
+
+/// Fails unless `data` is an enum whose variants have no fields, as required by the derive named `derive`.
+pub fn check_fieldless_enum(name: &Ident, data: &Data, derive: &str) -> Result<(), Error> {
+    match data {
+        Data::Enum(DataEnum { variants, .. }) => match variants
+            .iter()
+            .find(|variant| !matches!(variant.fields, Fields::Unit))
+        {
+            Some(variant) => Err(Error::new(
+                variant.ident.span(),
+                format!("`{}` can only be derived for enums without fields", derive),
+            )),
+            None => Ok(()),
+        },
+        _ => Err(Error::new(
+            name.span(),
+            format!("`{}` can only be derived for enums", derive),
+        )),
+    }
+}
+
+/// Appends `predicates` to an existing where clause, if any.
+pub fn combine_where_clause(
+    predicates: &[TokenStream],
+    where_clause: Option<&WhereClause>,
+) -> TokenStream {
+    match where_clause {
+        Some(WhereClause {
+            where_token: _,
+            predicates: existing,
+        }) => {
+            quote! {
+                where #(#predicates ,)* #existing
+            }
+        }
+        None => {
+            quote! {
+                where #(#predicates ,)*
+            }
+        }
+    }
+}
+
+/// Strips the `r#` prefix from raw identifiers.
+fn unraw(ident: &Ident) -> String {
+    let ident = ident.to_string();
+    match ident.get(..2) {
+        Some("r#") => ident[2..].to_string(),
+        _ => ident,
+    }
+}
+
+/// Implements `Datatype` for the `Singleton` naming a constructor, with the name of its type and its constructors.
+fn datatype(ident: &Ident, value: &str, con_values: &[&str]) -> TokenStream {
+    quote! {
+        impl ::generics::Datatype for #ident {
+            fn datatype_name() -> &'static str {
+                #value
+            }
+
+            fn constructor_names() -> &'static [&'static str] {
+                &[#( #con_values ),*]
+            }
+        }
+    }
+}
+
+/// Implements `Constructor` for the `Singleton` naming a constructor, with how its fields are declared and their names.
+fn constructor(ident: &Ident, fields: &Fields, field_names: &[String]) -> TokenStream {
+    let kind = match fields {
+        Fields::Unit => quote! { Unit },
+        Fields::Unnamed(_) => quote! { Tuple },
+        Fields::Named(_) => quote! { Braced },
+    };
+    quote! {
+        impl ::generics::Constructor for #ident {
+            fn con_kind() -> ::generics::ConKind {
+                ::generics::ConKind::#kind
+            }
+
+            fn field_names() -> &'static [&'static str] {
+                &[#( #field_names ),*]
+            }
+        }
+    }
+}
+
+/// Implements `Documented` for the `Singleton` naming a constructor or field, with its doc comment.
+fn documented(ident: &Ident, value: &str) -> TokenStream {
+    quote! {
+        impl ::generics::Documented for #ident {
+            fn doc() -> &'static str {
+                #value
+            }
+        }
+    }
+}
+
+/// Implements `FieldMeta` for the `Singleton` naming a field, with its type and `#[generic(env_default = "...")]`.
+///
+/// Attributes the field doesn't have are left to their defaults in `FieldMeta`.
+fn field_meta(ident: &Ident, ty: &Type, env_default: Option<&LitStr>) -> TokenStream {
+    let mut type_name = String::new();
+    write_tokens(quote!(#ty), &mut type_name);
+    let type_name = type_name.trim_end();
+    let env_default = env_default.map(|value| {
+        quote! {
+            fn env_default() -> ::std::option::Option<&'static str> {
+                ::std::option::Option::Some(#value)
+            }
+        }
+    });
+    quote! {
+        impl ::generics::FieldMeta for #ident {
+            fn type_name() -> &'static str {
+                #type_name
+            }
+
+            #env_default
+        }
+    }
+}
+
+/// Appends `tokens` to `out` as they'd be written by hand, e.g. `Vec<u8>`, rather than `quote`'s `Vec < u8 >`.
+fn write_tokens(tokens: TokenStream, out: &mut String) {
+    // The previous token, if it was an identifier or literal, which is spaced from the next one.
+    let mut word = None;
+    for token in tokens {
+        match token {
+            TokenTree::Ident(_) | TokenTree::Literal(_) => {
+                if word.is_some() {
+                    out.push(' ');
+                }
+                let text = token.to_string();
+                out.push_str(&text);
+                word = Some(text);
+            }
+            TokenTree::Punct(punct) => {
+                match punct.as_char() {
+                    ',' | ';' => out.push_str(&format!("{} ", punct.as_char())),
+                    '=' | '+' | '-' => out.push_str(&format!(" {} ", punct.as_char())),
+                    // The end of `->`.
+                    '>' if out.ends_with(" - ") => {
+                        out.truncate(out.len() - 1);
+                        out.push_str("> ");
+                    }
+                    c => out.push(c),
+                }
+                word = None;
+            }
+            TokenTree::Group(group) => {
+                // Keywords are spaced from a following group, e.g. `&mut [u8]`, unlike paths, e.g. `Fn(u8)`.
+                if let Some("mut" | "dyn" | "impl" | "const") = word.as_deref() {
+                    out.push(' ');
+                }
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::None => ("", ""),
+                };
+                out.push_str(open);
+                write_tokens(group.stream(), out);
+                out.truncate(out.trim_end().len());
+                out.push_str(close);
+                word = None;
+            }
+        }
+    }
+}
+
+/// Declares a zero-sized `Singleton` holding a name.
+fn singleton(vis: &Visibility, ident: &Ident, doc: &str, value: &str) -> TokenStream {
+    quote! {
+        #[doc = #doc]
+        #[allow(dead_code, non_camel_case_types)]
+        #vis struct #ident;
+
+        impl ::generics::Singleton for #ident {
+            type T = &'static str;
+            fn get() -> Self::T {
+                #value
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error};
+
+use crate::generic::{borrowed_repr, combine_where_clause, constructors};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+
+    let ty_predicates = constructors
+        .iter()
+        .flat_map(|con| &con.field_tys)
+        .map(|field_ty| quote! { #field_ty : ::generics::ops::ApproxEq })
+        .collect::<Vec<_>>();
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    let body = if constructors.is_empty() {
+        quote! { match *self {} }
+    } else {
+        let this = borrowed_repr(&data, &constructors, quote! { self });
+        let other = borrowed_repr(&data, &constructors, quote! { other });
+        quote! { ::generics::ops::GApproxEq::gapprox_eq(&#this, &#other, epsilon) }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::generics::ops::ApproxEq for #name #ty_generics #combined_where_clause {
+            fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,37 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{DeriveInput, Error, GenericParam, Lifetime, LifetimeDef};
+
+use crate::generic::combine_where_clause;
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        generics,
+        ..
+    } = input;
+
+    // The lifetime of the fuzzer input, which `Arbitrary` is parameterized by.
+    let lifetime = Lifetime::new("'__arbitrary", Span::call_site());
+    let mut impl_generics = generics.clone();
+    impl_generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeDef::new(lifetime.clone())),
+    );
+    let (impl_generics, _, _) = impl_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+
+    let ty_predicates = vec![
+        quote! { Self: ::generics::Generic },
+        quote! { <Self as ::generics::Generic>::Repr: ::generics::ops::GArbitrary<#lifetime> },
+    ];
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    Ok(quote! {
+        impl #impl_generics ::arbitrary::Arbitrary<#lifetime> for #name #ty_generics #combined_where_clause {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<#lifetime>) -> ::arbitrary::Result<Self> {
+                ::generics::ops::garbitrary(u)
+            }
+        }
+    })
+}
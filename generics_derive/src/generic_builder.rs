@@ -0,0 +1,124 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Data, DataStruct, DeriveInput, Error, Fields, Ident};
+
+use crate::generic::{constructors, Constructor};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let members = match &data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => fields
+            .named
+            .iter()
+            .filter_map(|field| field.ident.as_ref())
+            .collect::<Vec<_>>(),
+        _ => {
+            return Err(Error::new(
+                name.span(),
+                "`GenericBuilder` can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+    let Constructor {
+        partial_ty,
+        repr,
+        ordinals,
+        field_tys,
+        ..
+    } = &constructors[0];
+
+    let builder = Ident::new(&format!("{}Builder", name), Span::call_site());
+    let field_tys = field_tys
+        .iter()
+        .map(|field_ty| quote! { <::generics::ops::Partial as ::generics::TypeFn<#field_ty>>::Output })
+        .collect::<Vec<_>>();
+    let setters = members
+        .iter()
+        .zip(&constructors[0].field_tys)
+        .map(|(member, field_ty)| {
+            let doc = format!("Sets the `{}` field.", member);
+            quote! {
+                #[doc = #doc]
+                #[allow(dead_code)]
+                #vis fn #member(mut self, #member: #field_ty) -> Self {
+                    self.#member = ::std::option::Option::Some(#member);
+                    self
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    let name_str = name.to_string();
+    let builder_doc = format!(
+        "A builder for `{}`, holding each of its fields if it has been set.",
+        name
+    );
+    let builder_fn_doc = format!("Creates a `{}` with no fields set.", builder);
+    let build_doc = format!(
+        "Builds a `{}`, or reports which fields haven't been set.",
+        name
+    );
+    let members = &members;
+
+    let build = quote! {
+        #[doc = #build_doc]
+        #[allow(dead_code)]
+        #vis fn build(self) -> ::std::result::Result<#name #ty_generics, ::generics::ops::MissingFields> {
+            let #builder { #( #members : #ordinals, )* } = self;
+            let partial: #partial_ty = #repr;
+            let mut missing = ::std::vec::Vec::new();
+            match ::generics::ops::GBuild::gbuild(partial, &mut missing) {
+                ::std::option::Option::Some(#repr) => {
+                    ::std::result::Result::Ok(#name { #( #members : #ordinals, )* })
+                }
+                ::std::option::Option::None => {
+                    ::std::result::Result::Err(::generics::ops::MissingFields {
+                        name: #name_str,
+                        fields: missing,
+                    })
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        #[doc = #builder_doc]
+        #vis struct #builder #impl_generics #where_clause {
+            #( #members : #field_tys, )*
+        }
+
+        impl #impl_generics ::std::default::Default for #builder #ty_generics #where_clause {
+            fn default() -> Self {
+                #builder {
+                    #( #members : ::std::option::Option::None, )*
+                }
+            }
+        }
+
+        impl #impl_generics #builder #ty_generics #where_clause {
+            #( #setters )*
+
+            #build
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #builder_fn_doc]
+            #[allow(dead_code)]
+            #vis fn builder() -> #builder #ty_generics {
+                ::std::default::Default::default()
+            }
+        }
+    })
+}
@@ -0,0 +1,64 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error};
+
+use crate::generic::{combine_where_clause, constructors, Constructor};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+
+    let ty_predicates = constructors
+        .iter()
+        .flat_map(|con| &con.field_tys)
+        .map(|field_ty| quote! { #field_ty : ::std::clone::Clone })
+        .collect::<Vec<_>>();
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    // Each field is borrowed into a representation, which is cloned into a representation of owned fields.
+    let arms = constructors
+        .iter()
+        .map(|con| {
+            let Constructor {
+                borrowed_ty,
+                repr,
+                value,
+                ..
+            } = con;
+            let borrows = con.borrows();
+            quote! {
+                #value => {
+                    #( #borrows )*
+                    let borrowed: #borrowed_ty = #repr;
+                    let #repr = ::generics::ops::GClone::gclone(&borrowed);
+                    #value
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    let body = if arms.is_empty() {
+        quote! { match *self {} }
+    } else {
+        quote! {
+            match self {
+                #( #arms )*
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::std::clone::Clone for #name #ty_generics #combined_where_clause {
+            fn clone(&self) -> Self {
+                #body
+            }
+        }
+    })
+}
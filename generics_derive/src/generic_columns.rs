@@ -0,0 +1,199 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Data, DataStruct, DeriveInput, Error, Fields, Ident};
+
+use crate::generic::{constructors, Constructor};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let members =
+        match &data {
+            Data::Struct(DataStruct {
+                fields: Fields::Named(fields),
+                ..
+            }) if !fields.named.is_empty() => fields
+                .named
+                .iter()
+                .filter_map(|field| field.ident.as_ref())
+                .collect::<Vec<_>>(),
+            _ => return Err(Error::new(
+                name.span(),
+                "`GenericColumns` can only be derived for structs with at least one named field",
+            )),
+        };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+    let Constructor {
+        columns_ty,
+        repr,
+        ordinals,
+        field_tys,
+        ..
+    } = &constructors[0];
+
+    let columns = Ident::new(&format!("{}Columns", name), Span::call_site());
+    let column_tys = field_tys
+        .iter()
+        .map(|field_ty| quote! { <::generics::ops::Columns as ::generics::TypeFn<#field_ty>>::Output })
+        .collect::<Vec<_>>();
+    let column_fields = members
+        .iter()
+        .zip(&column_tys)
+        .map(|(member, column_ty)| {
+            let doc = format!("The `{}` field of each row.", member);
+            quote! {
+                #[doc = #doc]
+                #vis #member : #column_ty,
+            }
+        })
+        .collect::<Vec<_>>();
+    let cloned_fields = members
+        .iter()
+        .map(|member| quote! { #member : ::std::clone::Clone::clone(self.#member.get(index)?), })
+        .collect::<Vec<_>>();
+    let columns_doc = format!(
+        "The rows of `{}`, stored as a column for each of its fields.",
+        name
+    );
+    let first = &members[0];
+    let members = &members;
+
+    let mut clone_predicates = where_clause
+        .map(|where_clause| {
+            where_clause
+                .predicates
+                .iter()
+                .map(|p| quote! { #p })
+                .collect()
+        })
+        .unwrap_or_else(Vec::new);
+    // Bounds which hold for no lifetime in particular aren't checked until used,
+    // so rows which aren't `Clone` can still be pushed and popped.
+    clone_predicates.extend(
+        field_tys
+            .iter()
+            .map(|field_ty| quote! { for<'__columns> #field_ty : ::std::clone::Clone }),
+    );
+
+    // The columns are moved into their representation to push or pop a row, then moved back.
+    let take = quote! {
+        let mut columns: #columns_ty = {
+            let #columns { #( #members : #ordinals, )* } = ::std::mem::take(self);
+            #repr
+        };
+    };
+    let restore = quote! {
+        let #repr = columns;
+        *self = #columns { #( #members : #ordinals, )* };
+    };
+
+    let methods = quote! {
+        impl #impl_generics #columns #ty_generics #where_clause {
+            /// Creates an empty set of columns.
+            #[allow(dead_code)]
+            #vis fn new() -> Self {
+                ::std::default::Default::default()
+            }
+
+            /// The number of rows.
+            #[allow(dead_code)]
+            #vis fn len(&self) -> usize {
+                self.#first.len()
+            }
+
+            /// Whether there are no rows.
+            #[allow(dead_code)]
+            #vis fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Appends a row to the end of each column.
+            #[allow(dead_code)]
+            #vis fn push(&mut self, row: #name #ty_generics) {
+                #take
+                let #name { #( #members : #ordinals, )* } = row;
+                ::generics::ops::GColumns::gpush(&mut columns, #repr);
+                #restore
+            }
+
+            /// Removes the last row, if any.
+            #[allow(dead_code)]
+            #vis fn pop(&mut self) -> ::std::option::Option<#name #ty_generics> {
+                #take
+                let row = ::generics::ops::GColumns::gpop(&mut columns);
+                #restore
+                row.map(|#repr| #name { #( #members : #ordinals, )* })
+            }
+        }
+    };
+
+    let clone_methods = quote! {
+        impl #impl_generics #columns #ty_generics where #( #clone_predicates, )* {
+            /// A copy of the row at `index`, if any.
+            #[allow(dead_code)]
+            #vis fn get(&self, index: usize) -> ::std::option::Option<#name #ty_generics> {
+                ::std::option::Option::Some(#name {
+                    #( #cloned_fields )*
+                })
+            }
+
+            /// Iterates over copies of each row.
+            #[allow(dead_code)]
+            #vis fn iter(&self) -> impl ::std::iter::Iterator<Item = #name #ty_generics> + '_ {
+                (0..self.len()).filter_map(move |index| self.get(index))
+            }
+        }
+    };
+
+    let conversions = quote! {
+        impl #impl_generics ::std::convert::From<::std::vec::Vec<#name #ty_generics>> for #columns #ty_generics #where_clause {
+            fn from(rows: ::std::vec::Vec<#name #ty_generics>) -> Self {
+                let mut columns = Self::default();
+                for row in rows {
+                    columns.push(row);
+                }
+                columns
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#columns #ty_generics> for ::std::vec::Vec<#name #ty_generics> #where_clause {
+            fn from(mut columns: #columns #ty_generics) -> Self {
+                let mut rows = ::std::vec::Vec::with_capacity(columns.len());
+                while let ::std::option::Option::Some(row) = columns.pop() {
+                    rows.push(row);
+                }
+                rows.reverse();
+                rows
+            }
+        }
+    };
+
+    Ok(quote! {
+        #[doc = #columns_doc]
+        #vis struct #columns #impl_generics #where_clause {
+            #( #column_fields )*
+        }
+
+        impl #impl_generics ::std::default::Default for #columns #ty_generics #where_clause {
+            fn default() -> Self {
+                #columns {
+                    #( #members : ::std::vec::Vec::new(), )*
+                }
+            }
+        }
+
+        #methods
+
+        #clone_methods
+
+        #conversions
+    })
+}
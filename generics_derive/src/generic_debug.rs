@@ -0,0 +1,40 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error};
+
+use crate::generic::{combine_where_clause, constructors, redacted_repr};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+
+    let ty_predicates = constructors
+        .iter()
+        .flat_map(|con| con.unredacted_field_tys())
+        .map(|field_ty| quote! { #field_ty : ::std::fmt::Debug })
+        .collect::<Vec<_>>();
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    let body = if constructors.is_empty() {
+        quote! { match *self {} }
+    } else {
+        let this = redacted_repr(&data, &constructors, quote! { self });
+        quote! { ::generics::ops::GDebug::fmt_repr(&#this, f) }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::std::fmt::Debug for #name #ty_generics #combined_where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                #body
+            }
+        }
+    })
+}
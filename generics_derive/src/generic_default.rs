@@ -0,0 +1,101 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput, Error};
+
+use crate::generic::{combine_where_clause, constructors, inject};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+
+    // The first variant, unless another is marked `#[generic(default)]`.
+    let defaults = constructors
+        .iter()
+        .enumerate()
+        .filter(|(_, con)| con.default)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let i = match defaults.as_slice() {
+        [] if constructors.is_empty() => {
+            return Err(Error::new(
+                name.span(),
+                "`GenericDefault` cannot be derived for enums with no variants",
+            ))
+        }
+        [] => 0,
+        [i] => *i,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "only one variant may be `#[generic(default)]`",
+            ))
+        }
+    };
+    let con_ty = &constructors[i].ty;
+
+    let ty_predicates = constructors
+        .iter()
+        .flat_map(|con| con.generic_field_tys())
+        .map(|field_ty| quote! { #field_ty : ::generics::Generic })
+        .chain(Some(quote! { #con_ty : ::generics::ops::GDefault }))
+        .collect::<Vec<_>>();
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    if constructors
+        .iter()
+        .enumerate()
+        .any(|(j, con)| j != i && con.field_defaults.iter().any(Option::is_some))
+    {
+        return Err(Error::new(
+            Span::call_site(),
+            "only fields of the default variant may be `#[generic(default = \"...\")]`",
+        ));
+    }
+
+    let con = &constructors[i];
+    let repr = quote! { <#con_ty as ::generics::ops::GDefault>::gdefault() };
+    let body = if con.field_defaults.iter().any(Option::is_some) {
+        // Fields with their own default replace the default of their representation.
+        let pattern = &con.repr;
+        let value = &con.value;
+        let fields = con
+            .value_conversions()
+            .into_iter()
+            .zip(&con.ordinals)
+            .zip(&con.field_defaults)
+            .map(
+                |((conversion, ordinal), field_default)| match field_default {
+                    Some(field_default) => quote! { let #ordinal = #field_default; },
+                    None => conversion,
+                },
+            )
+            .collect::<Vec<_>>();
+        quote! {
+            let #pattern = #repr;
+            #( #fields )*
+            #value
+        }
+    } else {
+        let repr = match data {
+            Data::Enum(_) => inject(i, &repr),
+            _ => repr,
+        };
+        quote! { ::generics::Generic::from_repr(#repr) }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::std::default::Default for #name #ty_generics #combined_where_clause {
+            fn default() -> Self {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,44 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error};
+
+use crate::generic::{borrowed_repr, check_fieldless_enum, constructors};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    check_fieldless_enum(&name, &data, "GenericDisplay")?;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+
+    let body = if constructors.is_empty() {
+        quote! { match *self {} }
+    } else {
+        let this = borrowed_repr(&data, &constructors, quote! { self });
+        quote! { ::generics::ops::GVariantName::variant_name(&#this) }
+    };
+    let doc = format!("The name of the variant of `{}`.", name);
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #doc]
+            #[allow(dead_code)]
+            #vis fn as_str(&self) -> &'static str {
+                #body
+            }
+        }
+
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.pad(self.as_str())
+            }
+        }
+    })
+}
@@ -0,0 +1,65 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error};
+
+use crate::generic::{combine_where_clause, compared_repr, constructors};
+
+pub fn derive_partial_eq(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+
+    let ty_predicates = constructors
+        .iter()
+        .flat_map(|con| con.compared_field_tys())
+        .map(|field_ty| quote! { #field_ty : ::std::cmp::PartialEq })
+        .collect::<Vec<_>>();
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    let body = if constructors.is_empty() {
+        quote! { match *self {} }
+    } else {
+        let this = compared_repr(&data, &constructors, quote! { self });
+        let other = compared_repr(&data, &constructors, quote! { other });
+        quote! { ::generics::ops::GPartialEq::geq(&#this, &#other) }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::std::cmp::PartialEq for #name #ty_generics #combined_where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                #body
+            }
+        }
+    })
+}
+
+pub fn derive_eq(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+
+    let ty_predicates = constructors
+        .iter()
+        .flat_map(|con| con.compared_field_tys())
+        .map(|field_ty| quote! { #field_ty : ::std::cmp::Eq })
+        .collect::<Vec<_>>();
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    Ok(quote! {
+        impl #impl_generics ::std::cmp::Eq for #name #ty_generics #combined_where_clause {}
+    })
+}
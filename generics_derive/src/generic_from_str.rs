@@ -0,0 +1,42 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error};
+
+use crate::attr::ContainerOptions;
+use crate::generic::{check_fieldless_enum, combine_where_clause, constructors};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        attrs,
+        generics,
+        data,
+    } = input;
+
+    check_fieldless_enum(&name, &data, "GenericFromStr")?;
+    let options = ContainerOptions::parse(&attrs)?;
+    let case_insensitive = options.case_insensitive;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    // Validates variant options, even though the metadata itself comes from the `Generic` impl.
+    constructors(&name, &vis, &data)?;
+
+    let combined_where_clause = combine_where_clause(
+        &[
+            quote! { Self: ::generics::Generic },
+            quote! { <Self as ::generics::Generic>::Repr: ::generics::ops::GFromName },
+        ],
+        where_clause,
+    );
+
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #combined_where_clause {
+            type Err = ::generics::ops::ParseVariantError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                ::generics::ops::from_name(s, #case_insensitive)
+            }
+        }
+    })
+}
@@ -0,0 +1,112 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput, Error, GenericParam, Lifetime, LifetimeDef};
+
+use crate::generic::{combine_where_clause, constructors, opaque_repr};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let fields = match &data {
+        Data::Struct(data) if data.fields.iter().next().is_some() => &data.fields,
+        _ => {
+            return Err(Error::new(
+                name.span(),
+                "`GenericIter` can only be derived for structs with at least one field",
+            ))
+        }
+    };
+    let item = &fields.iter().next().expect("at least one field").ty;
+    let item_str = quote!(#item).to_string();
+    if let Some(field) = fields.iter().find(|field| {
+        let ty = &field.ty;
+        quote!(#ty).to_string() != item_str
+    }) {
+        let ty = &field.ty;
+        return Err(Error::new(
+            field
+                .ident
+                .as_ref()
+                .map_or_else(Span::call_site, |ident| ident.span()),
+            format!(
+                "`GenericIter` requires every field to have the same type, but found `{}` and `{}`",
+                item_str,
+                quote!(#ty)
+            ),
+        ));
+    }
+    let len = fields.iter().count();
+
+    // The lifetime of the borrow, for `IntoIterator` impls on references.
+    let lifetime = Lifetime::new("'__generic", Span::call_site());
+    let mut ref_generics = generics.clone();
+    ref_generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeDef::new(lifetime.clone())),
+    );
+    let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ref_where_clause = combine_where_clause(&[quote! { #item : #lifetime }], where_clause);
+    let constructors = constructors(&name, &vis, &data)?;
+
+    // Each field is visited into its slot of an array, so the iterators don't allocate.
+    let repr = opaque_repr(&data, &constructors, quote! { self });
+    let leaves = quote! {
+            let mut leaves = [(); #len].map(|()| ::std::option::Option::None);
+            let mut index = 0;
+            ::generics::ops::GUniform::gfor_each(#repr, &mut |leaf| {
+                leaves[index] = ::std::option::Option::Some(leaf);
+                index += 1;
+            });
+            ::std::iter::IntoIterator::into_iter(
+                leaves.map(|leaf| leaf.expect("every field is visited")),
+            )
+    };
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Iterates over a reference to each field, in order.
+            #vis fn iter(&self) -> ::std::array::IntoIter<&#item, #len> {
+                #leaves
+            }
+
+            /// Iterates over a mutable reference to each field, in order.
+            #vis fn iter_mut(&mut self) -> ::std::array::IntoIter<&mut #item, #len> {
+                #leaves
+            }
+        }
+
+        impl #impl_generics ::std::iter::IntoIterator for #name #ty_generics #where_clause {
+            type Item = #item;
+            type IntoIter = ::std::array::IntoIter<#item, #len>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                #leaves
+            }
+        }
+
+        impl #ref_impl_generics ::std::iter::IntoIterator for &#lifetime #name #ty_generics #ref_where_clause {
+            type Item = &#lifetime #item;
+            type IntoIter = ::std::array::IntoIter<&#lifetime #item, #len>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl #ref_impl_generics ::std::iter::IntoIterator for &#lifetime mut #name #ty_generics #ref_where_clause {
+            type Item = &#lifetime mut #item;
+            type IntoIter = ::std::array::IntoIter<&#lifetime mut #item, #len>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+    })
+}
@@ -0,0 +1,48 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error};
+
+use crate::generic::constructors;
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    match data {
+        Data::Struct(_) => {}
+        _ => {
+            return Err(Error::new(
+                name.span(),
+                "`GenericLayout` can only be derived for structs",
+            ))
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+    let borrowed_ty = &constructors[0].borrowed_ty;
+    let members = &constructors[0].members;
+    let doc = format!(
+        "The size, alignment, and padding of each field of `{}`.",
+        name
+    );
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #doc]
+            #[allow(dead_code)]
+            #vis fn layout() -> ::generics::ops::LayoutReport {
+                <#borrowed_ty as ::generics::ops::GLayout>::layout(
+                    ::std::mem::size_of::<Self>(),
+                    ::std::mem::align_of::<Self>(),
+                    &[#( ::std::mem::offset_of!(Self, #members) ),*],
+                )
+            }
+        }
+    })
+}
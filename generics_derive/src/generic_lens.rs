@@ -0,0 +1,59 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error};
+
+use crate::generic::{constructors, Constructor};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    match data {
+        Data::Struct(_) => {}
+        _ => {
+            return Err(Error::new(
+                name.span(),
+                "`GenericLens` can only be derived for structs",
+            ))
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+    let Constructor {
+        members,
+        selectors,
+        field_tys,
+        ..
+    } = &constructors[0];
+
+    let impls = members
+        .iter()
+        .zip(selectors)
+        .zip(field_tys)
+        .map(|((member, selector), field_ty)| {
+            quote! {
+                impl #impl_generics ::generics::HasField<#selector> for #name #ty_generics #where_clause {
+                    type Type = #field_ty;
+
+                    fn get_field(&self) -> &Self::Type {
+                        &self.#member
+                    }
+
+                    fn get_field_mut(&mut self) -> &mut Self::Type {
+                        &mut self.#member
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(quote! {
+        #( #impls )*
+    })
+}
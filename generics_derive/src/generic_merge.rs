@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error};
+
+use crate::generic::{combine_where_clause, constructors, inject};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+
+    let ty_predicates = constructors
+        .iter()
+        .flat_map(|con| con.field_tys.iter().zip(&con.strategies))
+        .map(
+            |(field_ty, strategy)| quote! { #strategy : ::generics::ops::MergeStrategy<#field_ty> },
+        )
+        .collect::<Vec<_>>();
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    // Each field is wrapped in its strategy, forming a representation which is merged with `GMerge`.
+    let (ty, patterns) = match &data {
+        Data::Enum(_) => {
+            let ty = constructors
+                .iter()
+                .rev()
+                .fold(quote! { ::generics::Void }, |acc, con| {
+                    let merge_ty = &con.merge_ty;
+                    quote! { ::generics::Sum<#merge_ty, #acc> }
+                });
+            let patterns = constructors
+                .iter()
+                .enumerate()
+                .map(|(i, con)| inject(i, &con.repr))
+                .collect::<Vec<_>>();
+            (ty, patterns)
+        }
+        _ => (
+            constructors[0].merge_ty.clone(),
+            vec![constructors[0].repr.clone()],
+        ),
+    };
+    let mut wrap_arms = Vec::new();
+    let mut unwrap_arms = Vec::new();
+    for (con, pattern) in constructors.iter().zip(&patterns) {
+        let value = &con.value;
+        let wraps = con
+            .ordinals
+            .iter()
+            .map(|ordinal| quote! { let #ordinal = ::generics::ops::WithStrategy::new(#ordinal); })
+            .collect::<Vec<_>>();
+        let unwraps = con
+            .ordinals
+            .iter()
+            .map(|ordinal| quote! { let ::generics::ops::WithStrategy(#ordinal, _) = #ordinal; })
+            .collect::<Vec<_>>();
+        wrap_arms.push(quote! {
+            #value => {
+                #( #wraps )*
+                #pattern
+            }
+        });
+        unwrap_arms.push(quote! {
+            #pattern => {
+                #( #unwraps )*
+                #value
+            }
+        });
+    }
+    let body = if constructors.is_empty() {
+        quote! { match self {} }
+    } else {
+        let void = match &data {
+            Data::Enum(_) => {
+                let void = (0..constructors.len()).fold(quote! { void }, |acc, _| {
+                    quote! { ::generics::Sum::Right(#acc) }
+                });
+                quote! { #void => match void {}, }
+            }
+            _ => quote! {},
+        };
+        let wrap_arms = &wrap_arms;
+        quote! {
+            let old: #ty = match self {
+                #( #wrap_arms )*
+            };
+            let newer: #ty = match newer {
+                #( #wrap_arms )*
+            };
+            match ::generics::ops::GMerge::gmerge(old, newer) {
+                #( #unwrap_arms )*
+                #void
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::generics::ops::Merge for #name #ty_generics #combined_where_clause {
+            fn merge(self, newer: Self) -> Self {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,98 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput, Error, GenericParam, Lifetime, LifetimeDef};
+
+use crate::generic::{combine_where_clause, constructors, inject};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    // The lifetime of the borrow, which the mutable representation is parameterized by.
+    let lifetime = Lifetime::new("'__generic", Span::call_site());
+    let mut impl_generics = generics.clone();
+    impl_generics.params.insert(
+        0,
+        GenericParam::Lifetime(LifetimeDef::new(lifetime.clone())),
+    );
+    let (impl_generics, _, _) = impl_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+
+    let ty_predicates = constructors
+        .iter()
+        .flat_map(|con| &con.field_tys)
+        .map(|field_ty| quote! { #field_ty : #lifetime })
+        .chain(
+            constructors
+                .iter()
+                .flat_map(|con| con.generic_field_tys())
+                .map(|field_ty| quote! { #field_ty : ::generics::GenericMut<#lifetime> }),
+        )
+        .collect::<Vec<_>>();
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    let (ty, arms) = match &data {
+        Data::Enum(_) => {
+            // Each variant `i` is represented as `Right^i(Left(..))`, ending in `Void`, as in `Generic`.
+            let ty = constructors
+                .iter()
+                .rev()
+                .fold(quote! { ::generics::Void }, |acc, con| {
+                    let mut_ty = &con.mut_ty;
+                    quote! { ::generics::Sum<#mut_ty, #acc> }
+                });
+            let arms = constructors
+                .iter()
+                .enumerate()
+                .map(|(i, con)| {
+                    let value = &con.value;
+                    let conversions = con.mut_conversions();
+                    let injection = inject(i, &con.repr);
+                    quote! {
+                        #value => {
+                            #( #conversions )*
+                            #injection
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+            (ty, arms)
+        }
+        _ => {
+            let con = &constructors[0];
+            let (value, repr) = (&con.value, &con.repr);
+            let conversions = con.mut_conversions();
+            let arm = quote! {
+                #value => {
+                    #( #conversions )*
+                    #repr
+                }
+            };
+            (con.mut_ty.clone(), vec![arm])
+        }
+    };
+    let body = if arms.is_empty() {
+        quote! { match *self {} }
+    } else {
+        quote! {
+            match self {
+                #( #arms )*
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::generics::GenericMut<#lifetime> for #name #ty_generics #combined_where_clause {
+            type ReprMut = #ty;
+            fn as_repr_mut(&#lifetime mut self) -> Self::ReprMut {
+                #body
+            }
+        }
+    })
+}
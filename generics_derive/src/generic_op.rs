@@ -0,0 +1,187 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    Error, Expr, FnArg, Ident, ItemTrait, Lit, Meta, MetaNameValue, NestedMeta, Pat, PatIdent,
+    TraitItem, TraitItemMethod,
+};
+
+pub fn generic_op(mut item: ItemTrait) -> Result<TokenStream, Error> {
+    let trait_name = &item.ident;
+    let (_, ty_generics, _) = item.generics.split_for_impl();
+    let params = &item.generics.params.iter().collect::<Vec<_>>();
+    let predicates = &item
+        .generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| where_clause.predicates.iter())
+        .collect::<Vec<_>>();
+
+    let mut meta_methods = Vec::new();
+    let mut con_methods = Vec::new();
+    let mut void_methods = Vec::new();
+    let mut unit_methods = Vec::new();
+    for trait_item in &mut item.items {
+        let method = match trait_item {
+            TraitItem::Method(method) => method,
+            _ => {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "`#[generic_op]` only supports traits containing methods",
+                ))
+            }
+        };
+        let unit = take_unit_attr(method)?;
+        if method.default.is_some() {
+            continue;
+        }
+
+        let sig = &method.sig;
+        let ident = &sig.ident;
+        let mut self_deref = None;
+        let mut arg_names = Vec::new();
+        let mut meta_sig = sig.clone();
+        for (i, arg) in meta_sig.decl.inputs.iter_mut().enumerate() {
+            match arg {
+                FnArg::SelfRef(_) => self_deref = Some(quote! { *self }),
+                FnArg::SelfValue(_) => self_deref = Some(quote! { self }),
+                FnArg::Captured(captured) => {
+                    let name = match &captured.pat {
+                        Pat::Ident(PatIdent {
+                            by_ref: None,
+                            subpat: None,
+                            ident,
+                            ..
+                        }) => ident.clone(),
+                        _ => Ident::new(&format!("__arg{}", i), Span::call_site()),
+                    };
+                    captured.pat = syn::parse_quote!(#name);
+                    arg_names.push(name);
+                }
+                _ => {
+                    return Err(Error::new(
+                        Span::call_site(),
+                        "`#[generic_op]` requires method arguments to have explicit types",
+                    ))
+                }
+            }
+        }
+        let self_deref = match self_deref {
+            Some(self_deref) => self_deref,
+            None => {
+                return Err(Error::new(
+                    ident.span(),
+                    "`#[generic_op]` requires every method to take `self`",
+                ))
+            }
+        };
+
+        let arg_names = &arg_names;
+        meta_methods.push(quote! {
+            #meta_sig {
+                let ::generics::Meta(inner, _) = self;
+                #trait_name::#ident(inner, #(#arg_names),*)
+            }
+        });
+
+        con_methods.push(quote! {
+            #meta_sig {
+                let ::generics::Con(inner, _) = self;
+                #trait_name::#ident(inner, #(#arg_names),*)
+            }
+        });
+
+        void_methods.push(quote! {
+            #[allow(unused_variables)]
+            #sig {
+                match #self_deref {}
+            }
+        });
+
+        let unit = unit.unwrap_or_else(|| syn::parse_quote!(::std::default::Default::default()));
+        unit_methods.push(quote! {
+            #[allow(unused_variables)]
+            #sig {
+                #unit
+            }
+        });
+    }
+
+    Ok(quote! {
+        #item
+
+        impl<#(#params,)* __I, __M> #trait_name #ty_generics for ::generics::Meta<__I, __M>
+        where
+            __I: #trait_name #ty_generics,
+            __M: ::generics::Singleton,
+            #(#predicates,)*
+        {
+            #(#meta_methods)*
+        }
+
+        impl<#(#params,)* __I, __C> #trait_name #ty_generics for ::generics::Con<__I, __C>
+        where
+            __I: #trait_name #ty_generics,
+            __C: ::generics::Singleton,
+            #(#predicates,)*
+        {
+            #(#con_methods)*
+        }
+
+        impl<#(#params),*> #trait_name #ty_generics for ::generics::Void
+        where
+            #(#predicates,)*
+        {
+            #(#void_methods)*
+        }
+
+        impl<#(#params),*> #trait_name #ty_generics for ::generics::Unit
+        where
+            #(#predicates,)*
+        {
+            #(#unit_methods)*
+        }
+    })
+}
+
+/// Removes `#[generic_op(unit = "...")]` from a method, returning the parsed expression.
+fn take_unit_attr(method: &mut TraitItemMethod) -> Result<Option<Expr>, Error> {
+    let mut unit = None;
+    let mut attrs = Vec::new();
+    for attr in method.attrs.drain(..) {
+        if !attr.path.is_ident("generic_op") {
+            attrs.push(attr);
+            continue;
+        }
+        let nested = match attr.parse_meta()? {
+            Meta::List(list) => list.nested,
+            _ => {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "expected `#[generic_op(unit = \"...\")]`",
+                ))
+            }
+        };
+        for nested in nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    ident,
+                    lit: Lit::Str(lit),
+                    ..
+                })) => {
+                    if ident != "unit" {
+                        return Err(Error::new(ident.span(), "unknown `#[generic_op]` option"));
+                    }
+                    unit = Some(lit.parse()?);
+                }
+                _ => {
+                    return Err(Error::new(
+                        Span::call_site(),
+                        "expected `#[generic_op(unit = \"...\")]`",
+                    ))
+                }
+            }
+        }
+    }
+    method.attrs = attrs;
+    Ok(unit)
+}
@@ -0,0 +1,77 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error};
+
+use crate::generic::{combine_where_clause, compared_repr, constructors};
+
+pub fn derive_partial_ord(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+
+    let ty_predicates = constructors
+        .iter()
+        .flat_map(|con| con.compared_field_tys())
+        .map(|field_ty| quote! { #field_ty : ::std::cmp::PartialOrd })
+        .collect::<Vec<_>>();
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    let body = if constructors.is_empty() {
+        quote! { match *self {} }
+    } else {
+        let this = compared_repr(&data, &constructors, quote! { self });
+        let other = compared_repr(&data, &constructors, quote! { other });
+        quote! { ::generics::ops::GPartialOrd::gpartial_cmp(&#this, &#other) }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::std::cmp::PartialOrd for #name #ty_generics #combined_where_clause {
+            fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                #body
+            }
+        }
+    })
+}
+
+pub fn derive_ord(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+
+    let ty_predicates = constructors
+        .iter()
+        .flat_map(|con| con.compared_field_tys())
+        .map(|field_ty| quote! { #field_ty : ::std::cmp::Ord })
+        .collect::<Vec<_>>();
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    let body = if constructors.is_empty() {
+        quote! { match *self {} }
+    } else {
+        let this = compared_repr(&data, &constructors, quote! { self });
+        let other = compared_repr(&data, &constructors, quote! { other });
+        quote! { ::generics::ops::GOrd::gcmp(&#this, &#other) }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::std::cmp::Ord for #name #ty_generics #combined_where_clause {
+            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                #body
+            }
+        }
+    })
+}
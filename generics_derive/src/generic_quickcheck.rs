@@ -0,0 +1,32 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error};
+
+use crate::generic::combine_where_clause;
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        generics,
+        ..
+    } = input;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let ty_predicates = vec![
+        quote! { Self: ::generics::Generic + ::std::clone::Clone + 'static },
+        quote! { <Self as ::generics::Generic>::Repr: ::generics::ops::GQuickcheck + 'static },
+    ];
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    Ok(quote! {
+        impl #impl_generics ::quickcheck::Arbitrary for #name #ty_generics #combined_where_clause {
+            fn arbitrary(g: &mut ::quickcheck::Gen) -> Self {
+                ::generics::ops::gquickcheck(g)
+            }
+
+            fn shrink(&self) -> ::std::boxed::Box<dyn ::std::iter::Iterator<Item = Self>> {
+                ::generics::ops::gshrink(self)
+            }
+        }
+    })
+}
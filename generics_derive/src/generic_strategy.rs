@@ -0,0 +1,106 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error};
+
+use crate::generic::{combine_where_clause, constructors, inject};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        vis,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let constructors = constructors(&name, &vis, &data)?;
+
+    // Fields with their own strategy are generated by it, in place of their representation.
+    let field_strategy_impls = constructors
+        .iter()
+        .flat_map(|con| {
+            con.selectors
+                .iter()
+                .zip(&con.field_tys)
+                .zip(&con.field_strategies)
+        })
+        .filter_map(|((selector, field_ty), field_strategy)| {
+            let field_strategy = field_strategy.as_ref()?;
+            Some(quote! {
+                impl #impl_generics ::generics::ops::FieldStrategy<#selector> for #name #ty_generics #where_clause {
+                    type Value = #field_ty;
+
+                    fn field_strategy() -> ::proptest::strategy::BoxedStrategy<#field_ty> {
+                        ::proptest::strategy::Strategy::boxed(#field_strategy)
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let (ty, patterns) = match &data {
+        Data::Enum(_) => {
+            let ty = constructors
+                .iter()
+                .rev()
+                .fold(quote! { ::generics::Void }, |acc, con| {
+                    let strategy_ty = &con.strategy_ty;
+                    quote! { ::generics::Sum<#strategy_ty, #acc> }
+                });
+            let patterns = constructors
+                .iter()
+                .enumerate()
+                .map(|(i, con)| inject(i, &con.repr))
+                .collect::<Vec<_>>();
+            (ty, patterns)
+        }
+        _ => (
+            constructors[0].strategy_ty.clone(),
+            vec![constructors[0].repr.clone()],
+        ),
+    };
+
+    let ty_predicates = vec![quote! { #ty: ::generics::ops::GStrategy }];
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    let arms = constructors
+        .iter()
+        .zip(&patterns)
+        .map(|(con, pattern)| {
+            let value = &con.value;
+            let conversions = con.strategy_conversions();
+            quote! {
+                #pattern => {
+                    #( #conversions )*
+                    ::std::result::Result::Ok(#value)
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    let void = match &data {
+        Data::Enum(_) => {
+            let void = (0..constructors.len()).fold(quote! { void }, |acc, _| {
+                quote! { ::generics::Sum::Right(#acc) }
+            });
+            quote! { #void => match void {}, }
+        }
+        _ => quote! {},
+    };
+
+    Ok(quote! {
+        #( #field_strategy_impls )*
+
+        impl #impl_generics ::proptest::arbitrary::Arbitrary for #name #ty_generics #combined_where_clause {
+            type Parameters = ();
+            type Strategy = ::generics::ops::ReprStrategy<Self, #ty>;
+
+            fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                ::generics::ops::ReprStrategy::new(|repr: #ty| match repr {
+                    #( #arms )*
+                    #void
+                })
+            }
+        }
+    })
+}
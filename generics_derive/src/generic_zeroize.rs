@@ -0,0 +1,32 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error};
+
+use crate::generic::combine_where_clause;
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let DeriveInput {
+        ident: name,
+        generics,
+        ..
+    } = input;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // The mutable representation of every borrow of `Self` must be erasable.
+    let ty_predicates = vec![
+        quote! { Self: for<'__generic> ::generics::GenericMut<'__generic> },
+        quote! {
+            for<'__generic> <Self as ::generics::GenericMut<'__generic>>::ReprMut: ::generics::ops::GZeroize
+        },
+    ];
+    let combined_where_clause = combine_where_clause(&ty_predicates, where_clause);
+
+    Ok(quote! {
+        impl #impl_generics ::generics::ops::Zeroize for #name #ty_generics #combined_where_clause {
+            fn zeroize(&mut self) {
+                ::generics::ops::zeroize(self)
+            }
+        }
+    })
+}
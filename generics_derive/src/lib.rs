@@ -1,111 +1,459 @@
 extern crate proc_macro;
 
+mod attr;
+mod generic;
+mod generic_approx_eq;
+mod generic_arbitrary;
+mod generic_builder;
+mod generic_clone;
+mod generic_columns;
+mod generic_ct_eq;
+mod generic_debug;
+mod generic_default;
+mod generic_display;
+mod generic_eq;
+mod generic_from_str;
+mod generic_hash;
+mod generic_heap_size;
+mod generic_iter;
+mod generic_layout;
+mod generic_lens;
+mod generic_merge;
+mod generic_mut;
+mod generic_op;
+mod generic_ord;
+mod generic_quickcheck;
+mod generic_sanitize;
+mod generic_strategy;
+mod generic_zeroize;
+
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::quote;
-use syn::{Data, DataEnum, DataStruct, DeriveInput, Ident, IntSuffix, LitInt, WhereClause};
 
-#[proc_macro_derive(Generic)]
+#[proc_macro_derive(Generic, attributes(generic))]
 pub fn generic_macro_derive(input: TokenStream) -> TokenStream {
-    let DeriveInput {
-        ident: name,
-        vis: _,
-        attrs: _,
-        generics,
-        data,
-    } = syn::parse(input).unwrap();
-
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-    let ty;
-    let ty_predicates;
-    let into;
-    let from;
-    match data {
-        Data::Struct(DataStruct { fields, .. }) => {
-            ty = fields
-                .iter()
-                .fold(quote! { ::generics::Unit }, |acc, field| {
-                    let field_ty = &field.ty;
-                    quote! { ::generics::Prod<#acc, <#field_ty as ::generics::Generic>::Repr> }
-                });
-            ty_predicates = fields
-                .iter()
-                .map(|field| {
-                    let field_ty = &field.ty;
-                    quote! { #field_ty : ::generics::Generic }
-                })
-                .collect::<Vec<_>>();
-            let ref self_fields = fields
-                .iter()
-                .enumerate()
-                .map(|(i, field)| match &field.ident {
-                    Some(ident) => quote! { #ident },
-                    None => {
-                        let lit = LitInt::new(i as u64, IntSuffix::None, Span::call_site());
-                        quote! { #lit }
-                    }
-                })
-                .collect::<Vec<_>>();
-            let ref ordinals = fields
-                .iter()
-                .enumerate()
-                .map(|(i, _)| Ident::new(&format!("_{}", i), Span::call_site()))
-                .collect::<Vec<_>>();
-            let repr_structure =
-                ordinals
-                    .iter()
-                    .fold(quote! { ::generics::Unit }, |acc, ordinal| {
-                        quote! { ::generics::Prod(#acc, #ordinal) }
-                    });
-            let into_conversions = ordinals.iter().map(|ordinal| {
-                quote! { let #ordinal = ::generics::Generic::into_repr(#ordinal); }
-            });
-            let from_conversions = ordinals.iter().map(|ordinal| {
-                quote! { let #ordinal = ::generics::Generic::from_repr(#ordinal); }
-            });
-            into = quote! {
-                let Self { #(#self_fields : #ordinals),* } = self;
-                #( #into_conversions )*
-                #repr_structure
-            };
-            from = quote! {
-                let #repr_structure = repr;
-                #( #from_conversions )*
-                Self { #(#self_fields : #ordinals),* }
-            };
-        }
-        Data::Enum(DataEnum { variants, .. }) => {
-            unimplemented!();
-        }
-        Data::Union(_) => panic!("`Generic` cannot be derived for unions"),
-    };
-
-    let combined_where_clause = match where_clause {
-        Some(WhereClause {
-            where_token: _,
-            predicates,
-        }) => {
-            quote! {
-                where #(#ty_predicates ,)* #predicates
-            }
-        }
-        None => {
-            quote! {
-                where #(#ty_predicates ,)*
-            }
-        }
-    };
-
-    TokenStream::from(quote! {
-        impl #impl_generics Generic for #name #ty_generics #combined_where_clause {
-            type Repr = #ty;
-            fn into_repr(self: Self) -> Self::Repr {
-                #into
-            }
-            fn from_repr(repr: Self::Repr) -> Self {
-                #from
-            }
-        }
-    })
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `GenericMut`, borrowing a value as its mutable representation.
+///
+/// Requires `#[derive(Generic)]` on the same type, whose `#[generic(opaque)]` fields are respected.
+#[proc_macro_derive(GenericMut, attributes(generic))]
+pub fn generic_mut_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_mut::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `ApproxEq` by comparing a representation of the fields with `GApproxEq`.
+///
+/// Requires `#[derive(Generic)]` on the same type, and `ApproxEq` for each field.
+#[proc_macro_derive(GenericApproxEq)]
+pub fn generic_approx_eq_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_approx_eq::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `arbitrary::Arbitrary` by generating a representation with `GArbitrary`.
+///
+/// Requires `#[derive(Generic)]` on the same type, the `arbitrary` feature of `generics`,
+/// and a dependency on `arbitrary`.
+#[proc_macro_derive(GenericArbitrary)]
+pub fn generic_arbitrary_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_arbitrary::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `quickcheck::Arbitrary` by generating and shrinking a representation with `GQuickcheck`.
+///
+/// Requires `#[derive(Generic)]` and `Clone` on the same type, the `quickcheck` feature of `generics`,
+/// and a dependency on `quickcheck`.
+#[proc_macro_derive(GenericQuickcheck)]
+pub fn generic_quickcheck_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_quickcheck::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `proptest::arbitrary::Arbitrary` by generating a representation with `GStrategy`.
+///
+/// Fields use the strategy given by `#[generic(strategy = "...")]`, if any, and otherwise `proptest::arbitrary::any`.
+/// Requires `#[derive(Generic)]` and `Debug` on the same type, the `proptest` feature of `generics`,
+/// and a dependency on `proptest`.
+#[proc_macro_derive(GenericStrategy)]
+pub fn generic_strategy_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_strategy::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Generates a builder for a struct with named fields, whose fields are each made optional with `Partial`.
+///
+/// The builder is named after the struct, e.g. `FooBuilder` for `Foo`, and has a setter for each field.
+/// Its `build` method completes the fields with `GBuild`, reporting every field which hasn't been set.
+/// Requires `#[derive(Generic)]` on the same type.
+#[proc_macro_derive(GenericBuilder)]
+pub fn generic_builder_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_builder::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Generates a columnar companion for a struct with named fields, whose fields are each made a column with `Columns`.
+///
+/// The companion is named after the struct, e.g. `FooColumns` for `Foo`, and has a public `Vec` for each field.
+/// Its `push` and `pop` methods move rows in and out of the columns with `GColumns`, and it converts to and from `Vec<Foo>`.
+/// Requires `#[derive(Generic)]` on the same type.
+#[proc_macro_derive(GenericColumns)]
+pub fn generic_columns_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_columns::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `Clone` by cloning a representation of the fields with `GClone`.
+///
+/// Requires `#[derive(Generic)]` on the same type, and `Clone` for each field.
+#[proc_macro_derive(GenericClone)]
+pub fn generic_clone_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_clone::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `Default` by constructing a default representation with `GDefault`.
+///
+/// Enums use their first variant, unless another is marked `#[generic(default)]`.
+/// Requires `#[derive(Generic)]` on the same type.
+#[proc_macro_derive(GenericDefault, attributes(generic))]
+pub fn generic_default_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_default::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `ConstantTimeEq` by comparing representations of the fields with `GConstantTimeEq`.
+///
+/// Requires `#[derive(Generic)]` on the same type.
+#[proc_macro_derive(GenericConstantTimeEq)]
+pub fn generic_constant_time_eq_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_ct_eq::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `Debug` by formatting a borrowed representation with `GDebug`,
+/// with each `#[generic(sensitive)]` field redacted as `***`.
+///
+/// Requires `#[derive(Generic)]` on the same type.
+#[proc_macro_derive(GenericDebug, attributes(generic))]
+pub fn generic_debug_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_debug::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `Display` and an inherent `as_str` method for a fieldless enum, using variant names from `GVariantName`.
+///
+/// Requires `#[derive(Generic)]` on the same type.
+#[proc_macro_derive(GenericDisplay)]
+pub fn generic_display_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_display::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `PartialEq` by comparing representations of the fields with `GPartialEq`.
+///
+/// Requires `#[derive(Generic)]` on the same type, and `PartialEq` for each field.
+#[proc_macro_derive(GenericPartialEq)]
+pub fn generic_partial_eq_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_eq::derive_partial_eq(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `Eq`, to go along with `#[derive(GenericPartialEq)]`.
+///
+/// Requires `Eq` for each field.
+#[proc_macro_derive(GenericEq)]
+pub fn generic_eq_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_eq::derive_eq(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `FromStr` for a fieldless enum, matching variant names from `GFromName`.
+///
+/// Names are matched regardless of ASCII case with `#[generic(case_insensitive)]` on the enum.
+/// Requires `#[derive(Generic)]` on the same type.
+#[proc_macro_derive(GenericFromStr, attributes(generic))]
+pub fn generic_from_str_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_from_str::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `Hash` by hashing a representation of the fields with `GHash`.
+///
+/// Requires `#[derive(Generic)]` on the same type, and `Hash` for each field.
+#[proc_macro_derive(GenericHash)]
+pub fn generic_hash_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_hash::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `HeapSize` by summing the heap usage of the fields with `GHeapSize`.
+///
+/// Requires `#[derive(Generic)]` on the same type, and `HeapSize` for each field.
+#[proc_macro_derive(GenericHeapSize)]
+pub fn generic_heap_size_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_heap_size::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `iter`, `iter_mut` and `IntoIterator`, by value and by reference, for a struct whose fields
+/// all have the same type, visiting each field with `GUniform`.
+///
+/// Requires `#[derive(Generic)]` on the same type.
+#[proc_macro_derive(GenericIter)]
+pub fn generic_iter_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_iter::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements an inherent `layout` function for a struct, reporting the layout of each field with `GLayout`.
+///
+/// Requires `#[derive(Generic)]` on the same type.
+#[proc_macro_derive(GenericLayout)]
+pub fn generic_layout_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_layout::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `HasField` for each field of a struct, keyed by its selector, so it can be focused on with `lens`.
+///
+/// Requires `#[derive(Generic)]` on the same type.
+#[proc_macro_derive(GenericLens)]
+pub fn generic_lens_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_lens::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `Merge` by merging representations of the fields with `GMerge`.
+///
+/// Each field is merged with the strategy given by `#[generic(merge = "...")]`: `"newer"` (the default),
+/// `"non_default"`, `"concat"`, `"deep"`, or the path to a type implementing `MergeStrategy`.
+/// Requires `#[derive(Generic)]` on the same type.
+#[proc_macro_derive(GenericMerge, attributes(generic))]
+pub fn generic_merge_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_merge::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `PartialOrd` by comparing representations of the fields with `GPartialOrd`.
+///
+/// Requires `#[derive(Generic)]` on the same type, and `PartialOrd` for each field.
+#[proc_macro_derive(GenericPartialOrd)]
+pub fn generic_partial_ord_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_ord::derive_partial_ord(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `Ord` by comparing representations of the fields with `GOrd`.
+///
+/// Requires `#[derive(Generic)]` on the same type, and `Ord` for each field.
+#[proc_macro_derive(GenericOrd)]
+pub fn generic_ord_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_ord::derive_ord(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements a generic operation's trait for `Meta`, `Con`, `Unit` and `Void`.
+///
+/// `Meta` and `Con` impls forward each method to the inner value, discarding metadata.
+/// `Unit` impls return `Default::default()`, or the expression given by
+/// `#[generic_op(unit = "...")]` on that method, which may refer to the method's arguments.
+/// `Void` impls are unreachable, since it has no values.
+///
+/// Every method must take `self`, `&self` or `&mut self`. Methods with default bodies are left alone.
+#[proc_macro_attribute]
+pub fn generic_op(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return TokenStream::from(
+            syn::Error::new(Span::call_site(), "`#[generic_op]` takes no arguments")
+                .to_compile_error(),
+        );
+    }
+    let item = match syn::parse(item) {
+        Ok(item) => item,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_op::generic_op(item) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `Sanitize` by sanitizing each field with its own `Sanitize` impl, then with the rules
+/// given by `#[generic(sanitize = "...")]` on it, with `GSanitize`.
+///
+/// Requires `#[derive(Generic)]` on the same type.
+#[proc_macro_derive(GenericSanitize, attributes(generic))]
+pub fn generic_sanitize_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_sanitize::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+/// Implements `Zeroize` by erasing every leaf of the mutable representation with `GZeroize`.
+///
+/// Requires `#[derive(Generic, GenericMut)]` on the same type.
+#[proc_macro_derive(GenericZeroize)]
+pub fn generic_zeroize_macro_derive(input: TokenStream) -> TokenStream {
+    let input = match syn::parse(input) {
+        Ok(input) => input,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+    match generic_zeroize::derive(input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
 }
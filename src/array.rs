@@ -0,0 +1,34 @@
+use std::convert::TryInto;
+
+use crate::{Generic, GenericMut, ReprError};
+
+impl<T, const N: usize> Generic for [T; N]
+where
+    T: Generic,
+{
+    type Repr = [T::Repr; N];
+    fn into_repr(self) -> Self::Repr {
+        self.map(T::into_repr)
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr.map(T::from_repr)
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let items = IntoIterator::into_iter(repr)
+            .map(T::checked_from_repr)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(items
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("converted `N` items")))
+    }
+}
+
+impl<'a, T, const N: usize> GenericMut<'a> for [T; N]
+where
+    T: GenericMut<'a>,
+{
+    type ReprMut = [T::ReprMut; N];
+    fn as_repr_mut(&'a mut self) -> Self::ReprMut {
+        self.each_mut().map(T::as_repr_mut)
+    }
+}
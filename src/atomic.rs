@@ -0,0 +1,42 @@
+#[cfg(target_has_atomic = "8")]
+use std::sync::atomic::{AtomicBool, AtomicI8, AtomicU8};
+#[cfg(target_has_atomic = "16")]
+use std::sync::atomic::{AtomicI16, AtomicU16};
+#[cfg(target_has_atomic = "32")]
+use std::sync::atomic::{AtomicI32, AtomicU32};
+#[cfg(target_has_atomic = "64")]
+use std::sync::atomic::{AtomicI64, AtomicU64};
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::atomic::{AtomicIsize, AtomicUsize};
+
+use crate::Generic;
+
+macro_rules! impl_atomic {
+    ( $( $width:literal: $( $ty:ident => $int:ty ),+ ; )+ ) => {
+        $( $(
+            /// Represented as the underlying value.
+            ///
+            /// Conversion takes ownership of the atomic, so no other thread can access it,
+            /// and no memory ordering is needed. To snapshot an atomic shared between threads,
+            /// `load` it with the desired ordering and convert the result instead.
+            #[cfg(target_has_atomic = $width)]
+            impl Generic for $ty {
+                type Repr = $int;
+                fn into_repr(self) -> Self::Repr {
+                    self.into_inner()
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    $ty::new(repr)
+                }
+            }
+        )+ )+
+    };
+}
+
+impl_atomic! {
+    "8": AtomicBool => bool, AtomicU8 => u8, AtomicI8 => i8;
+    "16": AtomicU16 => u16, AtomicI16 => i16;
+    "32": AtomicU32 => u32, AtomicI32 => i32;
+    "64": AtomicU64 => u64, AtomicI64 => i64;
+    "ptr": AtomicUsize => usize, AtomicIsize => isize;
+}
@@ -0,0 +1,40 @@
+use std::cell::{Cell, RefCell};
+use std::sync::{Mutex, PoisonError, RwLock};
+
+use crate::{Generic, ReprError};
+
+macro_rules! impl_cell {
+    ( $( $ty:ident => |$x:ident| $into_inner:expr ),+ $(,)? ) => {
+        $(
+            /// Represented as the inner value, which conversion moves out of the wrapper.
+            ///
+            /// To convert through a shared reference instead, borrow or lock the wrapper
+            /// and convert a clone of its contents, e.g. `mutex.lock().unwrap().clone().into_repr()`.
+            impl<T> Generic for $ty<T>
+            where
+                T: Generic,
+            {
+                type Repr = T::Repr;
+                fn into_repr(self) -> Self::Repr {
+                    let $x = self;
+                    let inner: T = $into_inner;
+                    inner.into_repr()
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    $ty::new(T::from_repr(repr))
+                }
+                fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+                    T::checked_from_repr(repr).map($ty::new)
+                }
+            }
+        )+
+    };
+}
+
+impl_cell!(
+    Cell => |x| x.into_inner(),
+    RefCell => |x| x.into_inner(),
+    // A poisoned lock still holds a value, which is taken as-is.
+    Mutex => |x| x.into_inner().unwrap_or_else(PoisonError::into_inner),
+    RwLock => |x| x.into_inner().unwrap_or_else(PoisonError::into_inner),
+);
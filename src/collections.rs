@@ -0,0 +1,34 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::{Generic, Leaf};
+
+/// Declares maps and sets as leaves.
+///
+/// Unlike sequences, whose `List` representation preserves their order, maps and sets are kept opaque:
+/// they're their own representation, and their elements aren't converted.
+/// Operations can still treat them elementwise with an impl for the collection type itself.
+macro_rules! impl_collection_leaf {
+    ( $( $ty:ident < $( $param:ident ),+ > ),+ $(,)? ) => {
+        $(
+            /// Maps and sets are leaves, so their elements aren't converted.
+            impl<$( $param ),+> Generic for $ty<$( $param ),+> {
+                type Repr = Self;
+                fn into_repr(self) -> Self::Repr {
+                    self
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    repr
+                }
+            }
+
+            impl<$( $param ),+> Leaf for $ty<$( $param ),+> {}
+        )+
+    };
+}
+
+impl_collection_leaf!(
+    HashMap<K, V, S>,
+    BTreeMap<K, V>,
+    HashSet<T, S>,
+    BTreeSet<T>,
+);
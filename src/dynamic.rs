@@ -0,0 +1,552 @@
+//! A dynamically-typed representation.
+//!
+//! `DynRepr` mirrors the structure of a static representation as an owned tree,
+//! so values can be inspected and built at runtime without knowing their type.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use generics::dynamic::{from_dyn, to_dyn, DynPrim, DynRepr};
+//! use generics::Generic;
+//!
+//! #[derive(Generic, Debug, PartialEq)]
+//! struct Foo {
+//!     a: u8,
+//! }
+//!
+//! let repr = to_dyn(Foo { a: 1 });
+//! assert_eq!(
+//!     repr,
+//!     DynRepr::Con(
+//!         "Foo",
+//!         Box::new(DynRepr::Prod(
+//!             Box::new(DynRepr::Meta("a", Box::new(DynRepr::Prim(DynPrim::U8(1))))),
+//!             Box::new(DynRepr::Unit),
+//!         )),
+//!     )
+//! );
+//!
+//! assert_eq!(from_dyn::<Foo>(repr), Ok(Foo { a: 1 }));
+//! ```
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, ReprError, Singleton, Sum, Unit, Void};
+
+/// An owned, dynamically-typed representation.
+///
+/// Each variant corresponds to one of the types making up a static representation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynRepr {
+    /// Corresponds to `Unit`.
+    Unit,
+    /// Corresponds to `Prod`.
+    Prod(Box<DynRepr>, Box<DynRepr>),
+    /// Corresponds to `Sum::Left`.
+    Left(Box<DynRepr>),
+    /// Corresponds to `Sum::Right`.
+    Right(Box<DynRepr>),
+    /// Corresponds to `Meta`, holding the field name.
+    Meta(&'static str, Box<DynRepr>),
+    /// Corresponds to `Con`, holding the constructor name.
+    Con(&'static str, Box<DynRepr>),
+    /// Corresponds to `List`, holding each element.
+    List(Vec<DynRepr>),
+    /// Corresponds to an array, holding each element.
+    Array(Vec<DynRepr>),
+    /// Corresponds to a `Leaf`.
+    Prim(DynPrim),
+}
+
+impl DynRepr {
+    /// A short description of this node, e.g. `"Prod"` or `"u8"`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DynRepr::Unit => "Unit",
+            DynRepr::Prod(..) => "Prod",
+            DynRepr::Left(_) => "Left",
+            DynRepr::Right(_) => "Right",
+            DynRepr::Meta(..) => "Meta",
+            DynRepr::Con(..) => "Con",
+            DynRepr::List(_) => "List",
+            DynRepr::Array(_) => "Array",
+            DynRepr::Prim(prim) => prim.kind(),
+        }
+    }
+}
+
+/// A dynamically-typed leaf.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(missing_docs)]
+pub enum DynPrim {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    Isize(isize),
+    F32(f32),
+    F64(f64),
+    Bool(bool),
+    Char(char),
+    String(String),
+}
+
+/// A `Leaf` which can be converted to and from a `DynPrim`.
+pub trait DynLeaf: Leaf + Sized {
+    /// The name of this type, as returned by `DynPrim::kind`.
+    const KIND: &'static str;
+
+    /// Converts `self` into a `DynPrim`.
+    fn into_prim(self) -> DynPrim;
+
+    /// Converts a `DynPrim` into `Self`, returning it unchanged if it holds a different type.
+    fn from_prim(prim: DynPrim) -> Result<Self, DynPrim>;
+}
+
+macro_rules! impl_dyn_leaf {
+    ( $( $ty:ty => $variant:ident ),+ $(,)? ) => {
+        impl DynPrim {
+            /// The name of the type held by this leaf, e.g. `"u8"`.
+            pub fn kind(&self) -> &'static str {
+                match self {
+                    $( DynPrim::$variant(_) => stringify!($ty), )+
+                }
+            }
+        }
+
+        $(
+            impl DynLeaf for $ty {
+                const KIND: &'static str = stringify!($ty);
+
+                fn into_prim(self) -> DynPrim {
+                    DynPrim::$variant(self)
+                }
+
+                fn from_prim(prim: DynPrim) -> Result<Self, DynPrim> {
+                    match prim {
+                        DynPrim::$variant(x) => Ok(x),
+                        prim => Err(prim),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+#[rustfmt::skip]
+impl_dyn_leaf!(
+    u8 => U8, u16 => U16, u32 => U32, u64 => U64, u128 => U128, usize => Usize,
+    i8 => I8, i16 => I16, i32 => I32, i64 => I64, i128 => I128, isize => Isize,
+    f32 => F32, f64 => F64,
+    bool => Bool, char => Char,
+    String => String,
+);
+
+/// Held as a `DynPrim::String`.
+impl DynLeaf for Box<str> {
+    const KIND: &'static str = "String";
+
+    fn into_prim(self) -> DynPrim {
+        DynPrim::String(self.into())
+    }
+
+    fn from_prim(prim: DynPrim) -> Result<Self, DynPrim> {
+        match prim {
+            DynPrim::String(x) => Ok(x.into_boxed_str()),
+            prim => Err(prim),
+        }
+    }
+}
+
+/// An error converting a `DynRepr` into a static representation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FromDynError {
+    /// A node had the wrong shape, e.g. a `Prod` was expected but a `u8` was found.
+    Shape {
+        /// The kind of node expected.
+        expected: &'static str,
+        /// The kind of node found.
+        found: &'static str,
+    },
+    /// A field or constructor had the wrong name.
+    Name {
+        /// The name expected.
+        expected: &'static str,
+        /// The name found.
+        found: &'static str,
+    },
+    /// An array had the wrong number of elements.
+    Length {
+        /// The number of elements expected.
+        expected: usize,
+        /// The number of elements found.
+        found: usize,
+    },
+    /// The value was invalid for its type, e.g. a `NonZeroU8` of zero.
+    Repr(ReprError),
+}
+
+impl Display for FromDynError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromDynError::Shape { expected, found } => {
+                write!(f, "expected {}, found {}", expected, found)
+            }
+            FromDynError::Name { expected, found } => {
+                write!(f, "expected name `{}`, found `{}`", expected, found)
+            }
+            FromDynError::Length { expected, found } => {
+                write!(f, "expected {} elements, found {}", expected, found)
+            }
+            FromDynError::Repr(error) => write!(f, "invalid value: {}", error),
+        }
+    }
+}
+
+impl Error for FromDynError {}
+
+/// Conversion of a static representation into a `DynRepr`.
+pub trait ToDyn {
+    /// Converts `self` into a `DynRepr`.
+    fn to_dyn(self) -> DynRepr;
+}
+
+/// Conversion of a `DynRepr` into a static representation.
+pub trait FromDyn: Sized {
+    /// Converts a `DynRepr` into `Self`, failing if its structure doesn't match.
+    fn from_dyn(repr: DynRepr) -> Result<Self, FromDynError>;
+}
+
+fn shape<T>(expected: &'static str, found: &DynRepr) -> Result<T, FromDynError> {
+    Err(FromDynError::Shape {
+        expected,
+        found: found.kind(),
+    })
+}
+
+fn name(expected: &'static str, found: &'static str) -> Result<(), FromDynError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(FromDynError::Name { expected, found })
+    }
+}
+
+impl ToDyn for Unit {
+    fn to_dyn(self) -> DynRepr {
+        DynRepr::Unit
+    }
+}
+
+impl FromDyn for Unit {
+    fn from_dyn(repr: DynRepr) -> Result<Self, FromDynError> {
+        match repr {
+            DynRepr::Unit => Ok(Unit),
+            repr => shape("Unit", &repr),
+        }
+    }
+}
+
+impl<A, B> ToDyn for Prod<A, B>
+where
+    A: ToDyn,
+    B: ToDyn,
+{
+    fn to_dyn(self) -> DynRepr {
+        let Prod(a, b) = self;
+        DynRepr::Prod(Box::new(a.to_dyn()), Box::new(b.to_dyn()))
+    }
+}
+
+impl<A, B> FromDyn for Prod<A, B>
+where
+    A: FromDyn,
+    B: FromDyn,
+{
+    fn from_dyn(repr: DynRepr) -> Result<Self, FromDynError> {
+        match repr {
+            DynRepr::Prod(a, b) => Ok(Prod(A::from_dyn(*a)?, B::from_dyn(*b)?)),
+            repr => shape("Prod", &repr),
+        }
+    }
+}
+
+impl<L, R> ToDyn for Sum<L, R>
+where
+    L: ToDyn,
+    R: ToDyn,
+{
+    fn to_dyn(self) -> DynRepr {
+        match self {
+            Sum::Left(l) => DynRepr::Left(Box::new(l.to_dyn())),
+            Sum::Right(r) => DynRepr::Right(Box::new(r.to_dyn())),
+        }
+    }
+}
+
+impl<L, R> FromDyn for Sum<L, R>
+where
+    L: FromDyn,
+    R: FromDyn,
+{
+    fn from_dyn(repr: DynRepr) -> Result<Self, FromDynError> {
+        match repr {
+            DynRepr::Left(l) => Ok(Sum::Left(L::from_dyn(*l)?)),
+            DynRepr::Right(r) => Ok(Sum::Right(R::from_dyn(*r)?)),
+            repr => shape("Sum", &repr),
+        }
+    }
+}
+
+impl ToDyn for Void {
+    fn to_dyn(self) -> DynRepr {
+        match self {}
+    }
+}
+
+impl FromDyn for Void {
+    fn from_dyn(repr: DynRepr) -> Result<Self, FromDynError> {
+        shape("Void", &repr)
+    }
+}
+
+impl<I, M> ToDyn for Meta<I, M>
+where
+    I: ToDyn,
+    M: Singleton<T = &'static str>,
+{
+    fn to_dyn(self) -> DynRepr {
+        let Meta(inner, _) = self;
+        DynRepr::Meta(M::get(), Box::new(inner.to_dyn()))
+    }
+}
+
+impl<I, M> FromDyn for Meta<I, M>
+where
+    I: FromDyn,
+    M: Singleton<T = &'static str>,
+{
+    fn from_dyn(repr: DynRepr) -> Result<Self, FromDynError> {
+        match repr {
+            DynRepr::Meta(found, inner) => {
+                name(M::get(), found)?;
+                Ok(Meta(I::from_dyn(*inner)?, PhantomData))
+            }
+            repr => shape("Meta", &repr),
+        }
+    }
+}
+
+impl<I, C> ToDyn for Con<I, C>
+where
+    I: ToDyn,
+    C: Singleton<T = &'static str>,
+{
+    fn to_dyn(self) -> DynRepr {
+        let Con(inner, _) = self;
+        DynRepr::Con(C::get(), Box::new(inner.to_dyn()))
+    }
+}
+
+impl<I, C> FromDyn for Con<I, C>
+where
+    I: FromDyn,
+    C: Singleton<T = &'static str>,
+{
+    fn from_dyn(repr: DynRepr) -> Result<Self, FromDynError> {
+        match repr {
+            DynRepr::Con(found, inner) => {
+                name(C::get(), found)?;
+                Ok(Con(I::from_dyn(*inner)?, PhantomData))
+            }
+            repr => shape("Con", &repr),
+        }
+    }
+}
+
+impl<A> ToDyn for List<A>
+where
+    A: ToDyn,
+{
+    fn to_dyn(self) -> DynRepr {
+        let List(items) = self;
+        DynRepr::List(items.into_iter().map(A::to_dyn).collect())
+    }
+}
+
+impl<A> FromDyn for List<A>
+where
+    A: FromDyn,
+{
+    fn from_dyn(repr: DynRepr) -> Result<Self, FromDynError> {
+        match repr {
+            DynRepr::List(items) => items
+                .into_iter()
+                .map(A::from_dyn)
+                .collect::<Result<_, _>>()
+                .map(List),
+            repr => shape("List", &repr),
+        }
+    }
+}
+
+impl<A, const N: usize> ToDyn for [A; N]
+where
+    A: ToDyn,
+{
+    fn to_dyn(self) -> DynRepr {
+        DynRepr::Array(IntoIterator::into_iter(self).map(A::to_dyn).collect())
+    }
+}
+
+impl<A, const N: usize> FromDyn for [A; N]
+where
+    A: FromDyn,
+{
+    fn from_dyn(repr: DynRepr) -> Result<Self, FromDynError> {
+        match repr {
+            DynRepr::Array(items) => {
+                if items.len() != N {
+                    return Err(FromDynError::Length {
+                        expected: N,
+                        found: items.len(),
+                    });
+                }
+                let items = items
+                    .into_iter()
+                    .map(A::from_dyn)
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(<[A; N]>::try_from(items).unwrap_or_else(|_| unreachable!()))
+            }
+            repr => shape("Array", &repr),
+        }
+    }
+}
+
+impl<T> ToDyn for T
+where
+    T: DynLeaf,
+{
+    fn to_dyn(self) -> DynRepr {
+        DynRepr::Prim(self.into_prim())
+    }
+}
+
+impl<T> FromDyn for T
+where
+    T: DynLeaf,
+{
+    fn from_dyn(repr: DynRepr) -> Result<Self, FromDynError> {
+        match repr {
+            DynRepr::Prim(prim) => T::from_prim(prim).map_err(|prim| FromDynError::Shape {
+                expected: T::KIND,
+                found: prim.kind(),
+            }),
+            repr => shape(T::KIND, &repr),
+        }
+    }
+}
+
+/// Held as a `DynPrim::String`. Since the result is owned, there is no corresponding `FromDyn` impl.
+impl ToDyn for &str {
+    fn to_dyn(self) -> DynRepr {
+        DynRepr::Prim(DynPrim::String(self.to_owned()))
+    }
+}
+
+/// Converts `x` into a `DynRepr`.
+pub fn to_dyn<T>(x: T) -> DynRepr
+where
+    T: Generic,
+    T::Repr: ToDyn,
+{
+    x.into_repr().to_dyn()
+}
+
+/// Converts a `DynRepr` into a `T`, failing if its structure doesn't match.
+pub fn from_dyn<T>(repr: DynRepr) -> Result<T, FromDynError>
+where
+    T: Generic,
+    T::Repr: FromDyn,
+{
+    let repr = T::Repr::from_dyn(repr)?;
+    T::checked_from_repr(repr).map_err(FromDynError::Repr)
+}
+
+/// An object-safe companion to `Generic`, for values whose type isn't known statically.
+///
+/// This is implemented for every `Generic` type which is `Clone` and whose representation
+/// can be converted to and from a `DynRepr`, so it can be used to build heterogeneous collections.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::dynamic::{DynRepr, Reflect};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Clone)]
+/// struct Foo(u8);
+///
+/// #[derive(Generic, Clone)]
+/// struct Bar;
+///
+/// let values: Vec<Box<dyn Reflect>> = vec![Box::new(Foo(1)), Box::new(Bar)];
+///
+/// let names = values
+///     .iter()
+///     .map(|value| match value.to_dyn() {
+///         DynRepr::Con(name, _) => name,
+///         _ => unreachable!(),
+///     })
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(names, ["Foo", "Bar"]);
+/// ```
+pub trait Reflect {
+    /// The name of the underlying type, as returned by `std::any::type_name`.
+    fn type_name(&self) -> &'static str;
+
+    /// Converts a copy of `self` into a `DynRepr`.
+    fn to_dyn(&self) -> DynRepr;
+
+    /// Converts `self` into a `DynRepr`.
+    fn into_dyn(self: Box<Self>) -> DynRepr;
+
+    /// Replaces `self` with the value represented by a `DynRepr`, failing if its structure doesn't match.
+    ///
+    /// On failure, `self` is left unchanged.
+    fn set_dyn(&mut self, repr: DynRepr) -> Result<(), FromDynError>;
+}
+
+impl<T> Reflect for T
+where
+    T: Generic + Clone,
+    T::Repr: ToDyn + FromDyn,
+{
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    fn to_dyn(&self) -> DynRepr {
+        to_dyn(self.clone())
+    }
+
+    fn into_dyn(self: Box<Self>) -> DynRepr {
+        to_dyn(*self)
+    }
+
+    fn set_dyn(&mut self, repr: DynRepr) -> Result<(), FromDynError> {
+        *self = from_dyn(repr)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+//! Impls for types from other crates, each behind the feature of the same name.
+//!
+//! These follow the same policy as their std counterparts:
+//! sequences are represented as a `List`, while strings, maps, sets, and other values are leaves.
+
+// Which of these are used depends on the enabled features.
+#[allow(unused_imports)]
+use crate::{Generic, Leaf, List, ReprError, TryFromRepr};
+
+#[allow(unused_macros)]
+macro_rules! impl_leaf {
+    ( $( $ty:ty ),+ $(,)? ) => {
+        $(
+            impl Generic for $ty {
+                type Repr = Self;
+                fn into_repr(self) -> Self::Repr {
+                    self
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    repr
+                }
+            }
+
+            impl Leaf for $ty {}
+        )+
+    };
+}
+
+#[cfg(feature = "uuid")]
+impl_leaf!(uuid::Uuid);
+
+#[cfg(feature = "chrono")]
+impl_leaf!(
+    chrono::NaiveDate,
+    chrono::NaiveTime,
+    chrono::NaiveDateTime,
+    chrono::TimeDelta,
+    chrono::FixedOffset,
+    chrono::Utc,
+);
+
+#[cfg(feature = "chrono")]
+impl<Tz> Generic for chrono::DateTime<Tz>
+where
+    Tz: chrono::TimeZone,
+{
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> Leaf for chrono::DateTime<Tz> where Tz: chrono::TimeZone {}
+
+#[cfg(feature = "time")]
+impl_leaf!(
+    time::Date,
+    time::Time,
+    time::PrimitiveDateTime,
+    time::OffsetDateTime,
+    time::UtcOffset,
+    time::Duration,
+);
+
+#[cfg(feature = "rust_decimal")]
+impl_leaf!(rust_decimal::Decimal);
+
+#[cfg(feature = "smallvec")]
+impl<A> Generic for smallvec::SmallVec<A>
+where
+    A: smallvec::Array,
+    A::Item: Generic,
+{
+    type Repr = List<<A::Item as Generic>::Repr>;
+    fn into_repr(self) -> Self::Repr {
+        List(self.into_iter().map(A::Item::into_repr).collect())
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let List(items) = repr;
+        items.into_iter().map(A::Item::from_repr).collect()
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let List(items) = repr;
+        items.into_iter().map(A::Item::checked_from_repr).collect()
+    }
+}
+
+/// # Panics
+///
+/// `from_repr` panics if the representation has more than `CAP` elements, and `checked_from_repr` fails.
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> Generic for arrayvec::ArrayVec<T, CAP>
+where
+    T: Generic,
+{
+    type Repr = List<T::Repr>;
+    fn into_repr(self) -> Self::Repr {
+        List(self.into_iter().map(T::into_repr).collect())
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        match Self::checked_from_repr(repr) {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let List(items) = repr;
+        if items.len() > CAP {
+            return Err(ReprError::new(format_args!(
+                "`ArrayVec` represented with {} elements, more than its capacity of {}",
+                items.len(),
+                CAP
+            )));
+        }
+        items.into_iter().map(T::checked_from_repr).collect()
+    }
+}
+
+/// Fails if the representation has more than `CAP` elements.
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> TryFromRepr for arrayvec::ArrayVec<T, CAP>
+where
+    T: Generic,
+{
+    type Error = ReprError;
+    fn try_from_repr(repr: Self::Repr) -> Result<Self, Self::Error> {
+        Self::checked_from_repr(repr)
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> Generic for arrayvec::ArrayString<CAP> {
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<const CAP: usize> Leaf for arrayvec::ArrayString<CAP> {}
+
+/// Represented as a `List` of bytes, like `Vec<u8>`.
+#[cfg(feature = "bytes")]
+impl Generic for bytes::Bytes {
+    type Repr = List<u8>;
+    fn into_repr(self) -> Self::Repr {
+        List(self.into())
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let List(items) = repr;
+        items.into()
+    }
+}
+
+/// Represented as a `List` of bytes, like `Vec<u8>`.
+#[cfg(feature = "bytes")]
+impl Generic for bytes::BytesMut {
+    type Repr = List<u8>;
+    fn into_repr(self) -> Self::Repr {
+        List(self.to_vec())
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let List(items) = repr;
+        items.as_slice().into()
+    }
+}
+
+/// Maps and sets are leaves, so their elements aren't converted.
+#[cfg(feature = "indexmap")]
+impl<K, V, S> Generic for indexmap::IndexMap<K, V, S> {
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> Leaf for indexmap::IndexMap<K, V, S> {}
+
+/// Maps and sets are leaves, so their elements aren't converted.
+#[cfg(feature = "indexmap")]
+impl<T, S> Generic for indexmap::IndexSet<T, S> {
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> Leaf for indexmap::IndexSet<T, S> {}
+
+/// # Panics
+///
+/// `from_repr` panics if the representation has more than `N` elements, and `checked_from_repr` fails.
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> Generic for heapless::Vec<T, N>
+where
+    T: Generic,
+{
+    type Repr = List<T::Repr>;
+    fn into_repr(self) -> Self::Repr {
+        List(self.into_iter().map(T::into_repr).collect())
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        match Self::checked_from_repr(repr) {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let List(items) = repr;
+        if items.len() > N {
+            return Err(ReprError::new(format_args!(
+                "`heapless::Vec` represented with {} elements, more than its capacity of {}",
+                items.len(),
+                N
+            )));
+        }
+        items.into_iter().map(T::checked_from_repr).collect()
+    }
+}
+
+/// Fails if the representation has more than `N` elements.
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> TryFromRepr for heapless::Vec<T, N>
+where
+    T: Generic,
+{
+    type Error = ReprError;
+    fn try_from_repr(repr: Self::Repr) -> Result<Self, Self::Error> {
+        Self::checked_from_repr(repr)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> Generic for heapless::String<N> {
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> Leaf for heapless::String<N> {}
+
+/// Maps are leaves, so their elements aren't converted. This includes `FnvIndexMap`.
+#[cfg(feature = "heapless")]
+impl<K, V, S, const N: usize> Generic for heapless::IndexMap<K, V, S, N> {
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<K, V, S, const N: usize> Leaf for heapless::IndexMap<K, V, S, N> {}
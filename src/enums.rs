@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt::Alignment;
+use std::marker::PhantomData;
+use std::net::Shutdown;
+use std::num::FpCategory;
+
+use crate::markers::*;
+use crate::{Con, Generic, Singleton, Sum, Unit, Void};
+
+impl Generic for Infallible {
+    type Repr = Void;
+    fn into_repr(self) -> Self::Repr {
+        match self {}
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        match repr {}
+    }
+}
+
+/// A `Sum` chain of constructors without fields, as derived for enums with only unit variants.
+trait UnitSum {
+    /// Constructs the variant at `index`.
+    fn from_index(index: usize) -> Self;
+
+    /// The index of this variant.
+    fn index(&self) -> usize;
+}
+
+impl UnitSum for Void {
+    fn from_index(_index: usize) -> Self {
+        unreachable!("variant index out of range")
+    }
+
+    fn index(&self) -> usize {
+        match *self {}
+    }
+}
+
+impl<C, R> UnitSum for Sum<Con<Unit, C>, R>
+where
+    C: Singleton,
+    R: UnitSum,
+{
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => Sum::Left(Con(Unit, PhantomData)),
+            index => Sum::Right(R::from_index(index - 1)),
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            Sum::Left(_) => 0,
+            Sum::Right(r) => 1 + r.index(),
+        }
+    }
+}
+
+macro_rules! unit_sum {
+    () => { Void };
+    ($name:ident $( $rest:ident )*) => { Sum<Con<Unit, $name>, unit_sum!($( $rest )*)> };
+}
+
+/// Implements `Generic` for enums with only unit variants, as if they were derived.
+macro_rules! impl_unit_enum {
+    ( $( $ty:ident { $( $variant:ident => $name:ident ),+ $(,)? } )+ ) => {
+        $(
+            impl Generic for $ty {
+                type Repr = unit_sum!($( $name )+);
+                fn into_repr(self) -> Self::Repr {
+                    const VARIANTS: &[$ty] = &[$( $ty::$variant ),+];
+                    let index = VARIANTS.iter().position(|variant| *variant == self);
+                    UnitSum::from_index(index.expect("every variant is listed"))
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    const VARIANTS: &[$ty] = &[$( $ty::$variant ),+];
+                    VARIANTS[repr.index()]
+                }
+            }
+        )+
+    };
+}
+
+impl_unit_enum! {
+    Ordering {
+        Less => Ordering_Less_Name,
+        Equal => Ordering_Equal_Name,
+        Greater => Ordering_Greater_Name,
+    }
+    FpCategory {
+        Nan => FpCategory_Nan_Name,
+        Infinite => FpCategory_Infinite_Name,
+        Zero => FpCategory_Zero_Name,
+        Subnormal => FpCategory_Subnormal_Name,
+        Normal => FpCategory_Normal_Name,
+    }
+    Alignment {
+        Left => Alignment_Left_Name,
+        Right => Alignment_Right_Name,
+        Center => Alignment_Center_Name,
+    }
+    Shutdown {
+        Read => Shutdown_Read_Name,
+        Write => Shutdown_Write_Name,
+        Both => Shutdown_Both_Name,
+    }
+}
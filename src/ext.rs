@@ -0,0 +1,59 @@
+use crate::Generic;
+
+/// Convenience methods for all `Generic` types.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::{Field, Generic, GenericExt};
+///
+/// #[derive(Generic)]
+/// struct Foo {
+///     a: u8,
+///     b: u8,
+/// }
+///
+/// let foo = Foo { a: 1, b: 2 }.modify_repr(|repr| *Field::<Foo_b, _>::field_mut(repr) += 40);
+///
+/// assert_eq!(foo.a, 1);
+/// assert_eq!(foo.b, 42);
+/// ```
+pub trait GenericExt: Generic + Sized {
+    /// Converts `self` into its generic representation.
+    ///
+    /// Equivalent to `Generic::into_repr`.
+    fn repr(self) -> Self::Repr {
+        self.into_repr()
+    }
+
+    /// Round-trips `self` through its representation, applying `f` along the way.
+    fn map_repr<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Self::Repr) -> Self::Repr,
+    {
+        Self::from_repr(f(self.into_repr()))
+    }
+
+    /// Round-trips `self` through its representation, modifying it in place with `f`.
+    fn modify_repr<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut Self::Repr),
+    {
+        let mut repr = self.into_repr();
+        f(&mut repr);
+        Self::from_repr(repr)
+    }
+
+    /// Converts `self` into another type with the same representation.
+    ///
+    /// Note that representations include metadata, such as field and constructor names,
+    /// so this only succeeds for types which agree on those as well.
+    fn convert<B>(self) -> B
+    where
+        B: Generic<Repr = Self::Repr>,
+    {
+        B::from_repr(self.into_repr())
+    }
+}
+
+impl<T> GenericExt for T where T: Generic {}
@@ -0,0 +1,103 @@
+use crate::{Con, Meta, Prod, Singleton, S, Z};
+
+/// Type-level lookup of a field by its selector.
+///
+/// `#[derive(Generic)]` wraps each field in a `Meta` whose metadata is a selector named `{Type}_{field}`,
+/// e.g. `Foo_bar` for the field `bar` of `Foo` (or `Foo_0` for the first field of a tuple struct).
+/// `Field<Foo_bar, _>` finds that field in a chain of `Prod`s, resolving to its representation.
+///
+/// `Index` is the position of the field, as used by `At`. It should be left for the compiler to infer.
+///
+/// `Con` is transparent to lookup, so the representation of a struct can be searched directly.
+///
+/// Looking up a selector which isn't present is a compile error.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::{Field, Generic};
+///
+/// #[derive(Generic)]
+/// struct Foo {
+///     a: u8,
+///     b: u32,
+/// }
+///
+/// let mut repr = Foo { a: 1, b: 2 }.into_repr();
+///
+/// assert_eq!(*Field::<Foo_b, _>::field(&repr), 2);
+///
+/// *Field::<Foo_a, _>::field_mut(&mut repr) = 42;
+/// assert_eq!(Foo::from_repr(repr).a, 42);
+/// ```
+pub trait Field<Name, Index> {
+    /// The representation of the field.
+    type Output;
+
+    /// Borrows the field.
+    fn field(&self) -> &Self::Output;
+
+    /// Mutably borrows the field.
+    fn field_mut(&mut self) -> &mut Self::Output;
+
+    /// Moves out the field, discarding the rest.
+    fn into_field(self) -> Self::Output;
+}
+
+impl<Name, A, B> Field<Name, Z> for Prod<Meta<A, Name>, B>
+where
+    Name: Singleton,
+{
+    type Output = A;
+
+    fn field(&self) -> &Self::Output {
+        &(self.0).0
+    }
+
+    fn field_mut(&mut self) -> &mut Self::Output {
+        &mut (self.0).0
+    }
+
+    fn into_field(self) -> Self::Output {
+        (self.0).0
+    }
+}
+
+impl<Name, A, B, N> Field<Name, S<N>> for Prod<A, B>
+where
+    B: Field<Name, N>,
+{
+    type Output = B::Output;
+
+    fn field(&self) -> &Self::Output {
+        self.1.field()
+    }
+
+    fn field_mut(&mut self) -> &mut Self::Output {
+        self.1.field_mut()
+    }
+
+    fn into_field(self) -> Self::Output {
+        self.1.into_field()
+    }
+}
+
+impl<Name, I, C, N> Field<Name, N> for Con<I, C>
+where
+    I: Field<Name, N>,
+    C: Singleton,
+{
+    type Output = I::Output;
+
+    fn field(&self) -> &Self::Output {
+        self.0.field()
+    }
+
+    fn field_mut(&mut self) -> &mut Self::Output {
+        self.0.field_mut()
+    }
+
+    fn into_field(self) -> Self::Output {
+        self.0.into_field()
+    }
+}
@@ -0,0 +1,141 @@
+//! Aliases matching the names used by `GHC.Generics`.
+//!
+//! These allow generic code written in Haskell to be ported one-to-one.
+//!
+//! | Haskell              | Rust                                |
+//! |----------------------|-------------------------------------|
+//! | `V1`                 | `V1` (`Void`)                       |
+//! | `U1`                 | `U1` (`Unit`)                       |
+//! | `K1`, `Rec0`         | `K1<A>`, `Rec0<A>` (`A`)            |
+//! | `:*:`                | `Product<F, G>` (`Prod`)            |
+//! | `:+:`, `L1`, `R1`    | `Sum<F, G>`, `L1`, `R1` (`Sum`)     |
+//! | `M1 S`, `S1`         | `M1<S, F>`, `S1<S, F>` (`Meta`)     |
+//! | `M1 C`, `C1`         | `C1<C, F>` (`Con`)                  |
+//! | `Rep a`              | `Rep<A>`                            |
+//! | `from`, `to`         | `from`, `to`                        |
+//! | `selName`, `conName` | `sel_name`, `con_name`              |
+//! | `datatypeName`       | `datatype_name`                     |
+//!
+//! Note that leaves are not wrapped in `K1`, so it is transparent,
+//! and datatype metadata is attached to each constructor with `Datatype`, so `D1` has no equivalent;
+//! instances for it can be dropped, and `datatypeName` taken from a `C1` instead.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use generics::ghc::{from, Product, Rec0, C1, S1, U1};
+//! use generics::Singleton;
+//!
+//! trait Encode {
+//!     fn encode(self, out: &mut Vec<u8>);
+//! }
+//!
+//! impl Encode for u8 {
+//!     fn encode(self, out: &mut Vec<u8>) {
+//!         out.push(self);
+//!     }
+//! }
+//!
+//! impl Encode for U1 {
+//!     fn encode(self, _out: &mut Vec<u8>) {}
+//! }
+//!
+//! impl<F, G> Encode for Product<F, G> where F: Encode, G: Encode {
+//!     fn encode(self, out: &mut Vec<u8>) {
+//!         let generics::Prod(f, g) = self;
+//!         f.encode(out);
+//!         g.encode(out);
+//!     }
+//! }
+//!
+//! impl<S, F> Encode for S1<S, F> where S: Singleton, F: Encode {
+//!     fn encode(self, out: &mut Vec<u8>) {
+//!         self.0.encode(out);
+//!     }
+//! }
+//!
+//! impl<C, F> Encode for C1<C, F> where C: Singleton, F: Encode {
+//!     fn encode(self, out: &mut Vec<u8>) {
+//!         self.0.encode(out);
+//!     }
+//! }
+//!
+//! #[derive(generics::Generic)]
+//! struct Foo(u8, u8);
+//!
+//! let mut out = vec![];
+//! from(Foo(1, 2)).encode(&mut out);
+//! assert_eq!(out, [1, 2]);
+//! ```
+
+use crate::{Con, Datatype, Generic, Meta, Prod, Singleton, Unit, Void};
+
+pub use crate::Sum;
+pub use crate::Sum::{Left as L1, Right as R1};
+
+/// Corresponds to `V1`, the representation of a type with no constructors.
+pub type V1 = Void;
+
+/// Corresponds to `U1`, the representation of a constructor with no fields.
+pub type U1 = Unit;
+
+/// Corresponds to `K1`. Leaves are not wrapped, so this is transparent.
+pub type K1<A> = A;
+
+/// Corresponds to `Rec0`. Leaves are not wrapped, so this is transparent.
+pub type Rec0<A> = A;
+
+/// Corresponds to `:*:`, the representation of a product.
+pub type Product<F, G> = Prod<F, G>;
+
+/// Corresponds to `M1` applied to selector metadata.
+pub type M1<S, F> = Meta<F, S>;
+
+/// Corresponds to `S1`, selector (field) metadata.
+pub type S1<S, F> = Meta<F, S>;
+
+/// Corresponds to `C1`, constructor metadata.
+pub type C1<C, F> = Con<F, C>;
+
+/// Corresponds to `Rep a`, the representation of `A`.
+pub type Rep<A> = <A as Generic>::Repr;
+
+/// Corresponds to `from`, converting a value into its representation.
+pub fn from<A>(x: A) -> Rep<A>
+where
+    A: Generic,
+{
+    x.into_repr()
+}
+
+/// Corresponds to `to`, converting a representation into a value.
+pub fn to<A>(rep: Rep<A>) -> A
+where
+    A: Generic,
+{
+    A::from_repr(rep)
+}
+
+/// Corresponds to `selName`, the name of a field.
+pub fn sel_name<S, F>(_: &S1<S, F>) -> &'static str
+where
+    S: Singleton<T = &'static str>,
+{
+    S::get()
+}
+
+/// Corresponds to `conName`, the name of a constructor.
+pub fn con_name<C, F>(_: &C1<C, F>) -> &'static str
+where
+    C: Singleton<T = &'static str>,
+{
+    C::get()
+}
+
+/// Corresponds to `datatypeName`, the name of the type a constructor belongs to.
+pub fn datatype_name<C, F>(_: &C1<C, F>) -> &'static str
+where
+    C: Singleton<T = &'static str> + Datatype,
+{
+    C::datatype_name()
+}
@@ -0,0 +1,104 @@
+use std::marker::PhantomData;
+
+use crate::{Con, Prod, Singleton};
+
+/// The type-level natural number zero.
+///
+/// Used as an index into a chain of `Prod`s, see `At`.
+pub struct Z;
+
+/// The type-level successor of the natural number `N`.
+///
+/// Used as an index into a chain of `Prod`s, see `At`.
+pub struct S<N>(PhantomData<N>);
+
+/// Type-level indexing into a chain of `Prod`s.
+///
+/// A chain of `Prod`s, as produced by `#[derive(Generic)]`, looks like `Prod<A, Prod<B, Prod<C, Unit>>>`.
+/// `At<Z>` resolves to `A`, `At<S<Z>>` resolves to `B`, and so on.
+///
+/// `Con` is transparent to indexing, so the representation of a struct can be indexed directly.
+///
+/// Indexing past the end of the chain is a compile error.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::{At, Prod, Unit, S, Z};
+///
+/// let mut repr = Prod(1u8, Prod("two", Prod(3u32, Unit)));
+///
+/// assert_eq!(*At::<Z>::at(&repr), 1u8);
+/// assert_eq!(*At::<S<Z>>::at(&repr), "two");
+///
+/// *At::<S<S<Z>>>::at_mut(&mut repr) += 39;
+/// assert_eq!(At::<S<S<Z>>>::into_at(repr), 42u32);
+/// ```
+pub trait At<N> {
+    /// The type of the element at index `N`.
+    type Output;
+
+    /// Borrows the element at index `N`.
+    fn at(&self) -> &Self::Output;
+
+    /// Mutably borrows the element at index `N`.
+    fn at_mut(&mut self) -> &mut Self::Output;
+
+    /// Moves out the element at index `N`, discarding the rest.
+    fn into_at(self) -> Self::Output;
+}
+
+impl<A, B> At<Z> for Prod<A, B> {
+    type Output = A;
+
+    fn at(&self) -> &Self::Output {
+        &self.0
+    }
+
+    fn at_mut(&mut self) -> &mut Self::Output {
+        &mut self.0
+    }
+
+    fn into_at(self) -> Self::Output {
+        self.0
+    }
+}
+
+impl<A, B, N> At<S<N>> for Prod<A, B>
+where
+    B: At<N>,
+{
+    type Output = B::Output;
+
+    fn at(&self) -> &Self::Output {
+        self.1.at()
+    }
+
+    fn at_mut(&mut self) -> &mut Self::Output {
+        self.1.at_mut()
+    }
+
+    fn into_at(self) -> Self::Output {
+        self.1.into_at()
+    }
+}
+
+impl<I, C, N> At<N> for Con<I, C>
+where
+    I: At<N>,
+    C: Singleton,
+{
+    type Output = I::Output;
+
+    fn at(&self) -> &Self::Output {
+        self.0.at()
+    }
+
+    fn at_mut(&mut self) -> &mut Self::Output {
+        self.0.at_mut()
+    }
+
+    fn into_at(self) -> Self::Output {
+        self.0.into_at()
+    }
+}
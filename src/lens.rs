@@ -0,0 +1,150 @@
+use std::marker::PhantomData;
+
+/// Access to the field of a struct marked by the selector `Name`, e.g. `Foo_bar` for the field `bar` of `Foo`.
+///
+/// `#[derive(GenericLens)]` implements this for each field, alongside `#[derive(Generic)]`,
+/// which declares the selectors.
+pub trait HasField<Name> {
+    /// The type of the field.
+    type Type;
+
+    /// Borrows the field.
+    fn get_field(&self) -> &Self::Type;
+
+    /// Mutably borrows the field.
+    fn get_field_mut(&mut self) -> &mut Self::Type;
+}
+
+/// A first-class reference to a part of `S`, which can be read, replaced, or modified in place.
+///
+/// `FieldLens` and `Compose` compose with `then`, to focus on a part of a part.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::{lens, Generic, GenericLens, Lens};
+///
+/// #[derive(Generic, GenericLens)]
+/// struct Inner {
+///     c: u8,
+/// }
+///
+/// #[derive(Generic, GenericLens)]
+/// struct Foo {
+///     a: u8,
+///     inner: Inner,
+/// }
+///
+/// let c = lens::<Foo_inner>().then(lens::<Inner_c>());
+///
+/// let foo = Foo { a: 1, inner: Inner { c: 2 } };
+/// assert_eq!(*c.get(&foo), 2);
+///
+/// let foo = c.set(foo, 3);
+/// let foo = c.modify(foo, |c| *c *= 2);
+/// assert_eq!(foo.inner.c, 6);
+/// ```
+pub trait Lens<S> {
+    /// The type of the part of `S` this focuses on.
+    type Target;
+
+    /// Borrows the part of `s`.
+    fn get<'a>(&self, s: &'a S) -> &'a Self::Target;
+
+    /// Mutably borrows the part of `s`.
+    fn get_mut<'a>(&self, s: &'a mut S) -> &'a mut Self::Target;
+
+    /// Replaces the part of `s` with `value`.
+    fn set(&self, mut s: S, value: Self::Target) -> S {
+        *self.get_mut(&mut s) = value;
+        s
+    }
+
+    /// Modifies the part of `s` in place with `f`.
+    fn modify<F>(&self, mut s: S, f: F) -> S
+    where
+        F: FnOnce(&mut Self::Target),
+    {
+        f(self.get_mut(&mut s));
+        s
+    }
+}
+
+/// The lens focusing on the field marked by the selector `Name`.
+pub struct FieldLens<Name>(PhantomData<Name>);
+
+impl<Name> FieldLens<Name> {
+    /// Creates the lens.
+    pub const fn new() -> Self {
+        FieldLens(PhantomData)
+    }
+
+    /// Composes this lens with `next`, which focuses on a part of this lens's target.
+    pub fn then<L>(self, next: L) -> Compose<Self, L> {
+        Compose(self, next)
+    }
+}
+
+impl<Name> Default for FieldLens<Name> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Name> Clone for FieldLens<Name> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Name> Copy for FieldLens<Name> {}
+
+impl<S, Name> Lens<S> for FieldLens<Name>
+where
+    S: HasField<Name>,
+{
+    type Target = S::Type;
+
+    fn get<'a>(&self, s: &'a S) -> &'a Self::Target {
+        s.get_field()
+    }
+
+    fn get_mut<'a>(&self, s: &'a mut S) -> &'a mut Self::Target {
+        s.get_field_mut()
+    }
+}
+
+/// The composition of two lenses, focusing on the part `B` of the part `A` of a value.
+///
+/// The intermediate part must be `'static`, i.e. not borrow from elsewhere.
+#[derive(Clone, Copy, Default)]
+pub struct Compose<A, B>(pub A, pub B);
+
+impl<A, B> Compose<A, B> {
+    /// Composes this lens with `next`, which focuses on a part of this lens's target.
+    pub fn then<L>(self, next: L) -> Compose<Self, L> {
+        Compose(self, next)
+    }
+}
+
+impl<S, A, B> Lens<S> for Compose<A, B>
+where
+    A: Lens<S>,
+    A::Target: 'static,
+    B: Lens<A::Target>,
+{
+    type Target = B::Target;
+
+    fn get<'a>(&self, s: &'a S) -> &'a Self::Target {
+        self.1.get(self.0.get(s))
+    }
+
+    fn get_mut<'a>(&self, s: &'a mut S) -> &'a mut Self::Target {
+        self.1.get_mut(self.0.get_mut(s))
+    }
+}
+
+/// The lens focusing on the field marked by the selector `Name`, e.g. `lens::<Foo_bar>()`.
+pub const fn lens<Name>() -> FieldLens<Name> {
+    FieldLens::new()
+}
@@ -19,18 +19,122 @@ use std::marker::PhantomData;
 #[doc(hidden)]
 pub use generics_derive::Generic;
 
+#[cfg(feature = "generics_derive")]
+pub use generics_derive::generic_op;
+
+#[cfg(feature = "generics_derive")]
+#[doc(hidden)]
+pub use generics_derive::GenericMut;
+
+#[cfg(feature = "generics_derive")]
+#[doc(hidden)]
+pub use generics_derive::GenericLens;
+
+mod array;
+mod atomic;
+#[cfg(feature = "interior_mutability")]
+mod cell;
+mod collections;
+pub mod dynamic;
+#[cfg(any(
+    feature = "arrayvec",
+    feature = "bytes",
+    feature = "chrono",
+    feature = "heapless",
+    feature = "indexmap",
+    feature = "rust_decimal",
+    feature = "smallvec",
+    feature = "time",
+    feature = "uuid"
+))]
+mod ecosystem;
+mod enums;
+mod ext;
+mod field;
+#[cfg(feature = "ghc")]
+pub mod ghc;
+mod index;
+mod lens;
+mod list;
+mod macros;
+mod map;
+pub mod markers;
+mod net;
+mod num;
+mod opaque;
+pub mod ops;
+mod option;
+mod path;
+mod phantom;
+mod pointer;
 mod prim;
+mod range;
+mod result;
+mod sensitive;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod time;
+mod tuple;
+mod visit;
+pub mod zipper;
+
+pub use crate::ext::GenericExt;
+pub use crate::field::Field;
+pub use crate::index::{At, S, Z};
+pub use crate::lens::{lens, Compose, FieldLens, HasField, Lens};
+pub use crate::map::{MapLeaves, TypeFn};
+pub use crate::opaque::Opaque;
+pub use crate::path::FieldPath;
+pub use crate::sensitive::Sensitive;
+pub use crate::visit::{visit, visit_mut, GenericVisitor, GenericVisitorMut, Visit, VisitMut};
 
 /// A bidirectional conversion between a type and its `Repr`.
 ///
 /// This trait should not be implemented by hand; use `#[derive(Generic)]` instead.
 ///
+/// # Derive attributes
+///
+/// The derive can be configured with `#[generic(...)]` attributes.
+///
+/// On the type:
+///
+/// - `#[generic(const_fn)]`: also emit inherent `into_repr_const` and `from_repr_const` methods,
+///   which can be used in `const` contexts. Every field must be a primitive scalar,
+///   an opaque field, or a type which also has `#[generic(const_fn)]`.
+/// - `#[generic(case_insensitive)]`: ignore ASCII case when parsing variant names with `#[derive(GenericFromStr)]`.
+///
+/// On fields:
+///
+/// - `#[generic(opaque)]`: represent the field as an `Opaque` leaf holding its value,
+///   instead of its own representation. The field's type need not implement `Generic`.
+/// - `#[generic(sensitive)]`: represent the field as a `Sensitive` leaf holding its value,
+///   which is formatted as `***` by `gdebug` and `#[derive(GenericDebug)]`. The field's type need not implement `Generic`.
+/// - `#[generic(ignore_eq)]`: skip the field in `#[derive(GenericPartialEq)]`, and in `#[derive(GenericEq)]`,
+///   `#[derive(GenericHash)]`, `#[derive(GenericPartialOrd)]` and `#[derive(GenericOrd)]` so they agree with it.
+///   The field's type need not implement the corresponding traits.
+/// - `#[generic(default = "...")]`: use the given expression as the field's value in `#[derive(GenericDefault)]`,
+///   instead of the default of its representation.
+/// - `#[generic(merge = "...")]`: merge the field with the given strategy in `#[derive(GenericMerge)]`:
+///   `"newer"` (the default), `"non_default"`, `"concat"`, `"deep"`, or the path to a type implementing `MergeStrategy`.
+/// - `#[generic(strategy = "...")]`: generate the field with the given proptest strategy expression
+///   in `#[derive(GenericStrategy)]`, instead of `proptest::arbitrary::any`.
+/// - `#[generic(sanitize = "...")]`: normalize the field with the given comma-separated rules in `#[derive(GenericSanitize)]`:
+///   `"trim"`, `"lowercase"`, `"nfc"`, `"clamp(min, max)"`, `"dedup"`, or an expression evaluating to a `SanitizeRule`.
+/// - `#[generic(env_default = "...")]`: parse the given text as the field's value when its environment variable is unset
+///   in `ops::from_env`. It's attached to the field's metadata with `FieldMeta`.
+///
+/// On enum variants:
+///
+/// - `#[generic(default)]`: use the variant for `#[derive(GenericDefault)]`, instead of the first variant.
+/// - `#[generic(rename = "...")]`: name the variant differently in its metadata,
+///   e.g. when displayed with `#[derive(GenericDisplay)]` or parsed with `#[derive(GenericFromStr)]`.
+///
 /// # Examples
 ///
 /// Accumulate the sum of all fields. For simplicity, only supports `u64`.
 ///
 /// ```rust
-/// use generics::{Generic, Meta, Prod, Singleton, Unit};
+/// use generics::{Con, Generic, Meta, Prod, Singleton, Unit};
 ///
 /// trait Accumulate {
 ///     fn acc(self) -> u64;
@@ -62,6 +166,13 @@ mod prim;
 ///     }
 /// }
 ///
+/// impl<I, C> Accumulate for Con<I, C> where I: Accumulate, C: Singleton {
+///     fn acc(self) -> u64 {
+///         let Con(inner, _) = self;
+///         inner.acc()
+///     }
+/// }
+///
 /// fn accumulate<T>(x: T) -> u64 where T: Generic, T::Repr: Accumulate {
 ///     x.into_repr().acc()
 /// }
@@ -83,15 +194,146 @@ mod prim;
 pub trait Generic {
     /// This type's generic representation.
     ///
-    /// Composed of four main types: `Unit`, `Prod`, `Sum`, `Meta`,
+    /// Composed of seven main types: `Unit`, `Prod`, `Sum`, `Void`, `Meta`, `Con`, `List`,
+    /// fixed-size arrays `[T; N]` of representations,
     /// and primitive types which can't be defined in terms of the former.
     type Repr;
 
     /// Converts `Self` into its generic representation.
-    fn into_repr(self: Self) -> Self::Repr;
+    fn into_repr(self) -> Self::Repr;
 
     /// Constructs `Self` from its generic representation.
     fn from_repr(repr: Self::Repr) -> Self;
+
+    /// Constructs `Self` from its generic representation, or fails if it's invalid for `Self` or any type within it.
+    ///
+    /// Some types can't be constructed from every value of their representation, e.g. a `NonZeroU8` represented as zero,
+    /// and their `from_repr` panics instead. Decoders build representations from untrusted input, so they use this,
+    /// and report the error. By default, this calls `from_repr`, so only types which can reject their representation,
+    /// and types built from other types, like `Vec<T>` or those with `#[derive(Generic)]`, need to override it.
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::from_repr(repr))
+    }
+}
+
+/// A primitive type, which can't be defined in terms of `Unit`, `Prod`, `Sum`, `Void`, `Meta`, `Con`, and `List`.
+///
+/// Leaves are their own representation, and form the leaves of a representation's tree.
+///
+/// Generic operations can be implemented once for all leaves with a blanket impl
+/// over `T: Leaf`, alongside the impls for `Unit`, `Prod`, `Sum`, `Void`, `Meta`, `Con`, `List`, and arrays.
+pub trait Leaf: Generic<Repr = Self> {}
+
+/// A fallible conversion from a type's representation, which validates the value it constructs.
+///
+/// `Generic::from_repr` accepts any representation, so values built generically, e.g. by deserializing or parsing,
+/// may break invariants which the type's own constructors uphold. Implementing this allows such operations
+/// to reject those values, e.g. `ops::try_deserialize_generic`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::{Generic, TryFromRepr};
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Percent {
+///     value: u8,
+/// }
+///
+/// impl TryFromRepr for Percent {
+///     type Error = String;
+///     fn try_from_repr(repr: Self::Repr) -> Result<Self, Self::Error> {
+///         let percent = Percent::from_repr(repr);
+///         if percent.value > 100 {
+///             return Err(format!("{}% is more than 100%", percent.value));
+///         }
+///         Ok(percent)
+///     }
+/// }
+///
+/// let repr = Percent { value: 50 }.into_repr();
+/// assert_eq!(Percent::try_from_repr(repr), Ok(Percent { value: 50 }));
+/// let repr = Percent { value: 150 }.into_repr();
+/// assert_eq!(Percent::try_from_repr(repr), Err("150% is more than 100%".to_string()));
+/// ```
+pub trait TryFromRepr: Generic + Sized {
+    /// The error returned if the representation is invalid.
+    type Error: std::fmt::Display;
+
+    /// Constructs `Self` from its generic representation, or fails if it's invalid.
+    fn try_from_repr(repr: Self::Repr) -> Result<Self, Self::Error>;
+}
+
+/// An error constructing a value from an invalid representation, as returned by `Generic::checked_from_repr`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::num::NonZeroU8;
+///
+/// use generics::{Generic, ReprError};
+///
+/// assert_eq!(NonZeroU8::checked_from_repr(1), Ok(NonZeroU8::new(1).unwrap()));
+/// assert_eq!(NonZeroU8::checked_from_repr(0), Err(ReprError::new("`NonZeroU8` represented as zero")));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReprError {
+    message: String,
+}
+
+impl ReprError {
+    /// Creates an error with a message describing why the representation is invalid.
+    pub fn new(message: impl std::fmt::Display) -> Self {
+        ReprError {
+            message: message.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for ReprError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.message.fmt(f)
+    }
+}
+
+impl std::error::Error for ReprError {}
+
+/// Mutable access to a value via its representation, without consuming it.
+///
+/// The mutable representation has the same shape as `Generic::Repr`, but each leaf `T` is replaced by `&'a mut T`,
+/// so a value can be updated in place, without being deconstructed and reconstructed.
+/// Opaque and sensitive fields are represented as `Opaque<&'a mut T>` and `Sensitive<&'a mut T>`.
+///
+/// Unlike `Generic`, `Box<T>` has no mutable representation, since it would overlap with the impl for leaves;
+/// boxed fields can be marked `#[generic(opaque)]` instead.
+///
+/// This should not be implemented by hand; use `#[derive(GenericMut)]` alongside `#[derive(Generic)]` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::{Generic, GenericMut, Meta, Prod};
+///
+/// #[derive(Generic, GenericMut)]
+/// struct Foo {
+///     a: u8,
+///     b: Option<String>,
+/// }
+///
+/// let mut foo = Foo { a: 1, b: None };
+/// let Prod(Meta(a, _), _) = foo.as_repr_mut().0;
+/// *a += 1;
+/// assert_eq!(foo.a, 2);
+/// ```
+pub trait GenericMut<'a> {
+    /// This type's mutable representation.
+    type ReprMut;
+
+    /// Borrows `self` as its mutable representation.
+    fn as_repr_mut(&'a mut self) -> Self::ReprMut;
 }
 
 /// Represents a unit type.
@@ -127,14 +369,21 @@ pub struct Unit;
 /// That is, a constructor with two arguments, e.g. a struct with at least two fields.
 /// Structs with more than two fields are represented as nested `Prod`s.
 ///
+/// `#[derive(Generic)]` nests products to the right, ending in `Unit`: the fields `a`, `b`, and `c` are
+/// `Prod<A, Prod<B, Prod<C, Unit>>>`, with each field also wrapped in `Meta`. Earlier versions nested them
+/// to the left, as `Prod<Prod<Prod<Unit, A>, B>, C>`, so impls written by hand against that shape must be updated.
+/// The first field is always the head of the chain, and the rest of the fields its tail, so ops can recurse
+/// over fields like over a list, as with `Prod<Meta<A, M>, B>` where `B` is the remaining fields,
+/// and `At<N>` can find the `N`th field by counting from the head.
+///
 /// # Examples
 ///
-/// A simplified implementation of `Generic` for a struct, ignoring `Meta`.
+/// A simplified implementation of `Generic` for a struct, ignoring `Meta` and `Con`.
 ///
 /// This is incomplete and should not be written by hand; use `#[derive(Generic)]` instead.
 ///
 /// ```rust
-/// use generics::{Generic, Prod};
+/// use generics::{Generic, Prod, Unit};
 ///
 /// struct Three {
 ///     one: u8,
@@ -143,12 +392,12 @@ pub struct Unit;
 /// };
 ///
 /// impl Generic for Three {
-///     type Repr = Prod<u8, Prod<u16, u32>>;
+///     type Repr = Prod<u8, Prod<u16, Prod<u32, Unit>>>;
 ///     fn into_repr(self) -> Self::Repr {
-///         Prod(self.one, Prod(self.two, self.three))
+///         Prod(self.one, Prod(self.two, Prod(self.three, Unit)))
 ///     }
 ///     fn from_repr(repr: Self::Repr) -> Self {
-///         let Prod(one, Prod(two, three)) = repr;
+///         let Prod(one, Prod(two, Prod(three, Unit))) = repr;
 ///         Three { one, two, three }
 ///     }
 /// }
@@ -160,6 +409,10 @@ pub struct Prod<A, B>(pub A, pub B);
 /// That is, a constructor taking one of two types, e.g. an enum with at least two variants.
 /// Enums with more than two variants are represented as nested `Sum`s.
 ///
+/// The `Generic` custom derive wraps each variant in a `Con`, delimiting its fields,
+/// and terminates the chain with `Void`, e.g. `Sum<Con<A, Foo_A_Name>, Sum<Con<B, Foo_B_Name>, Void>>`.
+/// Metadata for variants is prefixed by the enum's name, e.g. `Foo_B_Name` and `Foo_B_a`.
+///
 /// # Examples
 ///
 /// A simplified implementation of `Generic` for an enum, ignoring `Meta`.
@@ -200,33 +453,83 @@ pub enum Sum<L, R> {
     Right(R),
 }
 
-/// Additional metadata related to a type.
+/// Represents an uninhabited type.
+///
+/// That is, a type with no constructors, e.g. an enum with no variants.
+/// Since no value of this type can exist, generic operations can implement it trivially.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::Void;
+///
+/// trait Accumulate {
+///     fn acc(self) -> u64;
+/// }
+///
+/// impl Accumulate for Void {
+///     fn acc(self) -> u64 {
+///         match self {}
+///     }
+/// }
+/// ```
+pub enum Void {}
+
+/// Represents a variable-length sequence.
 ///
-/// That is, constructor names, field names, etc.
+/// That is, a collection such as `Vec`, `VecDeque`, `LinkedList`, or `Box<[T]>`,
+/// holding the representation of each element in order.
+///
+/// To treat a collection as a single leaf instead, wrap it in `Opaque`, or mark the field `#[generic(opaque)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::{Generic, List};
+///
+/// let List(items) = vec![1u8, 2, 3].into_repr();
+/// assert_eq!(items, [1, 2, 3]);
+/// ```
+pub struct List<R>(pub Vec<R>);
+
+/// Additional metadata related to a field.
+///
+/// That is, field names, etc.
 ///
 /// Metadata added by the `Generic` custom derive is represented as a zero-sized `Singleton`,
 /// so it adds no runtime overhead.
 ///
 /// # Examples
 ///
-/// A theoretical implementation of `Generic` for a unit struct, including metadata.
+/// A theoretical implementation of `Generic` for a struct with one field, including metadata.
 ///
 /// This should not be written by hand; use `#[derive(Generic)]` instead.
 ///
 /// ```rust
 /// # use std::marker::PhantomData;
-/// use generics::{Generic, Meta, Singleton, Unit};
+/// use generics::{Con, Generic, Meta, Prod, Singleton, Unit};
 ///
-/// struct Foo;
+/// struct Foo {
+///     a: u8,
+/// }
 ///
 /// impl Generic for Foo {
-///     type Repr = Meta<Unit, Foo_Name>;
+///     type Repr = Con<Prod<Meta<u8, Foo_a>, Unit>, Foo_Name>;
 ///     fn into_repr(self) -> Self::Repr {
-///         Meta(Unit, PhantomData)
+///         Con(Prod(Meta(self.a, PhantomData), Unit), PhantomData)
 ///     }
 ///     fn from_repr(repr: Self::Repr) -> Self {
-///         let Meta(Unit, _) = repr;
-///         Foo
+///         let Con(Prod(Meta(a, _), Unit), _) = repr;
+///         Foo { a }
+///     }
+/// }
+///
+/// struct Foo_a;
+///
+/// impl Singleton for Foo_a {
+///     type T = &'static str;
+///     fn get() -> Self::T {
+///         "a"
 ///     }
 /// }
 ///
@@ -261,9 +564,205 @@ pub struct Meta<I, M>(pub I, pub PhantomData<M>)
 where
     M: Singleton;
 
+/// Represents a constructor.
+///
+/// That is, a struct, or a single variant of an enum, along with metadata such as its name.
+///
+/// Like `Meta`, the metadata is a zero-sized `Singleton`, so it adds no runtime overhead.
+///
+/// # Examples
+///
+/// A theoretical implementation of `Generic` for a unit struct, including metadata.
+///
+/// This should not be written by hand; use `#[derive(Generic)]` instead.
+///
+/// ```rust
+/// # use std::marker::PhantomData;
+/// use generics::{Con, Generic, Singleton, Unit};
+///
+/// struct Foo;
+///
+/// impl Generic for Foo {
+///     type Repr = Con<Unit, Foo_Name>;
+///     fn into_repr(self) -> Self::Repr {
+///         Con(Unit, PhantomData)
+///     }
+///     fn from_repr(repr: Self::Repr) -> Self {
+///         let Con(Unit, _) = repr;
+///         Foo
+///     }
+/// }
+///
+/// struct Foo_Name;
+///
+/// impl Singleton for Foo_Name {
+///     type T = &'static str;
+///     fn get() -> Self::T {
+///         "Foo"
+///     }
+/// }
+/// ```
+pub struct Con<I, C>(pub I, pub PhantomData<C>)
+where
+    C: Singleton;
+
+/// The name of the type a constructor belongs to.
+///
+/// The `Generic` custom derive implements this for the `Singleton` naming each `Con`, alongside `Singleton::get`,
+/// e.g. for `enum Foo { A, B }`, both `Foo_A_Name` and `Foo_B_Name` give `"Foo"`, while their names are `"A"` and `"B"`.
+/// For a struct, it's the same as the constructor's name.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::{Con, Datatype, Generic, Singleton, Sum};
+///
+/// #[derive(Generic)]
+/// enum Foo {
+///     A,
+///     B,
+/// }
+///
+/// fn names<I, C, R>(_: &Sum<Con<I, C>, R>) -> (&'static str, &'static str)
+/// where
+///     C: Singleton<T = &'static str> + Datatype,
+/// {
+///     (C::datatype_name(), C::get())
+/// }
+///
+/// assert_eq!(names(&Foo::A.into_repr()), ("Foo", "A"));
+/// ```
+pub trait Datatype {
+    /// Get the name of the type.
+    fn datatype_name() -> &'static str;
+
+    /// Get the names of the type's constructors, in order, e.g. `["A", "B"]` for `enum Foo { A, B }`.
+    fn constructor_names() -> &'static [&'static str];
+}
+
+/// How a constructor's fields are declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConKind {
+    /// No fields and no delimiters, e.g. `struct Foo;` or `Foo::A`.
+    Unit,
+    /// Positional fields in parentheses, e.g. `struct Foo(u8);` or `Foo::B()`.
+    Tuple,
+    /// Named fields in braces, e.g. `struct Foo { a: u8 }` or `Foo::C {}`.
+    Braced,
+}
+
+/// How a constructor's fields are declared, attached to the `Singleton` naming it, like GHC's `conIsRecord`.
+///
+/// `#[derive(Generic)]` implements this for the metadata of each constructor, and it distinguishes constructors
+/// whose representations are otherwise the same, e.g. `struct A;`, `struct B();`, and `struct C {}`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::{Con, ConKind, Constructor, Generic, Singleton};
+///
+/// #[derive(Generic)]
+/// struct Unit;
+///
+/// #[derive(Generic)]
+/// struct Tuple();
+///
+/// #[derive(Generic)]
+/// struct Braced {}
+///
+/// fn kind<I, C>(_: &Con<I, C>) -> ConKind
+/// where
+///     C: Singleton + Constructor,
+/// {
+///     C::con_kind()
+/// }
+///
+/// assert_eq!(kind(&Unit.into_repr()), ConKind::Unit);
+/// assert_eq!(kind(&Tuple().into_repr()), ConKind::Tuple);
+/// assert_eq!(kind(&Braced {}.into_repr()), ConKind::Braced);
+/// ```
+pub trait Constructor {
+    /// Get how the constructor's fields are declared.
+    fn con_kind() -> ConKind;
+
+    /// Get the names of the constructor's fields, in order, which are `"0"`, `"1"`, etc. for tuple constructors.
+    fn field_names() -> &'static [&'static str];
+}
+
+/// The doc comment of a constructor or field, attached to the `Singleton` naming it.
+///
+/// `#[derive(Generic)]` implements this for the metadata of each constructor and field,
+/// where a struct's constructor has the struct's doc comment. Standard library types have no doc comments.
+///
+/// ```rust
+/// use generics::{Con, Documented, Generic, Meta, Prod, Singleton};
+///
+/// /// A point on the screen.
+/// #[derive(Generic)]
+/// struct Point {
+///     /// Pixels from the left.
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// fn docs<A, M, B, C>(_: &Con<Prod<Meta<A, M>, B>, C>) -> (&'static str, &'static str)
+/// where
+///     M: Singleton + Documented,
+///     C: Singleton + Documented,
+/// {
+///     (C::doc(), M::doc())
+/// }
+///
+/// let repr = Point { x: 1, y: 2 }.into_repr();
+/// assert_eq!(docs(&repr), ("A point on the screen.", "Pixels from the left."));
+/// ```
+pub trait Documented {
+    /// Get the doc comment, with its lines joined by newlines, or `""` if there is none.
+    fn doc() -> &'static str;
+}
+
+/// Metadata from the declaration of a field, including its attributes, attached to the `Singleton` naming it.
+///
+/// `#[derive(Generic)]` implements this for the metadata of each field. Unlike `std::any::type_name`
+/// of the field's representation, which may be a long chain of `Sum`s and `Prod`s, its type is as written.
+/// Attributes which a field doesn't have take their defaults, so operations can rely on every field implementing this.
+///
+/// ```rust
+/// use generics::{Con, FieldMeta, Generic, Meta, Prod, Singleton, Unit};
+///
+/// #[derive(Generic)]
+/// struct Server {
+///     host: String,
+///     #[generic(env_default = "8080")]
+///     port: Option<u16>,
+/// }
+///
+/// fn fields<A, M, B, N, C>(_: &Con<Prod<Meta<A, M>, Prod<Meta<B, N>, Unit>>, C>) -> [(&'static str, Option<&'static str>); 2]
+/// where
+///     M: Singleton + FieldMeta,
+///     N: Singleton + FieldMeta,
+///     C: Singleton,
+/// {
+///     [(M::type_name(), M::env_default()), (N::type_name(), N::env_default())]
+/// }
+///
+/// let repr = Server { host: "localhost".to_string(), port: None }.into_repr();
+/// assert_eq!(fields(&repr), [("String", None), ("Option<u16>", Some("8080"))]);
+/// ```
+pub trait FieldMeta {
+    /// Get the type of the field, as written in its declaration, e.g. `"Option<u16>"`.
+    fn type_name() -> &'static str;
+
+    /// Get the text the field takes when its environment variable is unset, from `#[generic(env_default = "...")]`,
+    /// or `None` if there is none.
+    fn env_default() -> Option<&'static str> {
+        None
+    }
+}
+
 /// A zero-sized singleton associated with some data.
 ///
-/// Allows `Meta` to hold additional information about a type without carrying it around at runtime.
+/// Allows `Meta` and `Con` to hold additional information about a type without carrying it around at runtime.
 pub trait Singleton {
     /// Type of the associated data.
     type T;
@@ -0,0 +1,91 @@
+use std::collections::{LinkedList, VecDeque};
+
+use crate::{Generic, GenericMut, List, ReprError};
+
+impl<T> Generic for Vec<T>
+where
+    T: Generic,
+{
+    type Repr = List<T::Repr>;
+    fn into_repr(self) -> Self::Repr {
+        List(self.into_iter().map(T::into_repr).collect())
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let List(items) = repr;
+        items.into_iter().map(T::from_repr).collect()
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let List(items) = repr;
+        items.into_iter().map(T::checked_from_repr).collect()
+    }
+}
+
+impl<T> Generic for Box<[T]>
+where
+    T: Generic,
+{
+    type Repr = List<T::Repr>;
+    fn into_repr(self) -> Self::Repr {
+        self.into_vec().into_repr()
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        Vec::from_repr(repr).into_boxed_slice()
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        Vec::checked_from_repr(repr).map(Vec::into_boxed_slice)
+    }
+}
+
+impl<T> Generic for VecDeque<T>
+where
+    T: Generic,
+{
+    type Repr = List<T::Repr>;
+    fn into_repr(self) -> Self::Repr {
+        List(self.into_iter().map(T::into_repr).collect())
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let List(items) = repr;
+        items.into_iter().map(T::from_repr).collect()
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let List(items) = repr;
+        items.into_iter().map(T::checked_from_repr).collect()
+    }
+}
+
+impl<T> Generic for LinkedList<T>
+where
+    T: Generic,
+{
+    type Repr = List<T::Repr>;
+    fn into_repr(self) -> Self::Repr {
+        List(self.into_iter().map(T::into_repr).collect())
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let List(items) = repr;
+        items.into_iter().map(T::from_repr).collect()
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let List(items) = repr;
+        items.into_iter().map(T::checked_from_repr).collect()
+    }
+}
+
+macro_rules! impl_list_mut {
+    ( $( $ty:ty ),+ $(,)? ) => {
+        $(
+            impl<'a, T> GenericMut<'a> for $ty
+            where
+                T: GenericMut<'a>,
+            {
+                type ReprMut = List<T::ReprMut>;
+                fn as_repr_mut(&'a mut self) -> Self::ReprMut {
+                    List(self.iter_mut().map(T::as_repr_mut).collect())
+                }
+            }
+        )+
+    };
+}
+
+impl_list_mut!(Vec<T>, Box<[T]>, VecDeque<T>, LinkedList<T>);
@@ -0,0 +1,309 @@
+/// Defines a generic operation from a compact specification.
+///
+/// Generates a trait with a single method taking `self` by value,
+/// impls of that trait for `Unit`, `Prod`, `Sum`, `Void`, `Meta`, `Con`, each listed leaf type,
+/// and optionally `List` and arrays,
+/// and a wrapper function which applies the operation to any `Generic` type.
+///
+/// Each case binds the results of applying the operation to its children:
+///
+/// - `unit => ...` has no children.
+/// - `prod(a, b) => ...` binds the results for both halves of the product.
+/// - `sum(x) => ...` binds the result for whichever variant is present.
+///   `Void` has no values, so it needs no case.
+/// - `meta(x, M) => ...` binds the result for the inner value, and names the metadata type `M`,
+///   a `Singleton` holding a name, as produced by `#[derive(Generic)]`.
+/// - `con(x, C) => ...` likewise binds the result for the inner value, and names the metadata type `C`.
+///   This case is optional; if omitted, constructors pass through the result for their fields.
+/// - `list(xs) => ...` binds a `Vec` of the results for each element.
+///   This case is optional; if omitted, the operation does not support `List`.
+/// - `array(xs) => ...` binds an array of the results for each element.
+///   This case is optional; if omitted, the operation does not support arrays.
+/// - `leaf(x: T) => ...` binds the leaf value itself; one case is required per leaf type.
+///
+/// # Examples
+///
+/// Accumulate the sum of all fields, as in the example for `Generic`.
+///
+/// ```rust
+/// use generics::{define_generic_op, Generic};
+///
+/// define_generic_op! {
+///     /// Sums all integer fields.
+///     pub trait Accumulate {
+///         fn acc(self) -> u64;
+///     }
+///
+///     /// Sums all integer fields of `x`.
+///     pub fn accumulate;
+///
+///     unit => 0,
+///     prod(a, b) => a + b,
+///     sum(x) => x,
+///     meta(x, M) => x,
+///     leaf(x: u64) => x,
+///     leaf(x: u32) => u64::from(x),
+/// }
+///
+/// #[derive(Generic)]
+/// struct Foo { a: u64, b: u32 }
+///
+/// fn main() {
+///     assert_eq!(accumulate(Foo { a: 19, b: 23 }), 42);
+/// }
+/// ```
+///
+/// The metadata type can be used to access names:
+///
+/// ```rust
+/// use generics::{define_generic_op, Generic};
+///
+/// define_generic_op! {
+///     trait Names {
+///         fn names(self) -> Vec<&'static str>;
+///     }
+///
+///     fn names;
+///
+///     unit => vec![],
+///     prod(a, b) => a.into_iter().chain(b).collect(),
+///     sum(x) => x,
+///     meta(x, M) => {
+///         let mut names = vec![M::get()];
+///         names.extend(x);
+///         names
+///     },
+///     leaf(_x: u8) => vec![],
+/// }
+///
+/// #[derive(Generic)]
+/// struct Foo { a: u8, b: u8 }
+///
+/// fn main() {
+///     assert_eq!(names(Foo { a: 1, b: 2 }), ["a", "b"]);
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_generic_op {
+    (@con $trait:ident, $method:ident, $output:ty; $con_inner:ident, $con:ident => $con_body:expr) => {
+        impl<I, $con> $trait for $crate::Con<I, $con>
+        where
+            I: $trait,
+            $con: $crate::Singleton<T = &'static str>,
+        {
+            fn $method(self) -> $output {
+                let $crate::Con(inner, _) = self;
+                let $con_inner = $trait::$method(inner);
+                $con_body
+            }
+        }
+    };
+    (@con $trait:ident, $method:ident, $output:ty;) => {
+        impl<I, C> $trait for $crate::Con<I, C>
+        where
+            I: $trait,
+            C: $crate::Singleton,
+        {
+            fn $method(self) -> $output {
+                let $crate::Con(inner, _) = self;
+                $trait::$method(inner)
+            }
+        }
+    };
+    (
+        $(#[$trait_attr:meta])*
+        $trait_vis:vis trait $trait:ident {
+            fn $method:ident(self) -> $output:ty;
+        }
+
+        $(#[$fn_attr:meta])*
+        $fn_vis:vis fn $function:ident;
+
+        unit => $unit:expr,
+        prod($a:ident, $b:ident) => $prod:expr,
+        sum($x:ident) => $sum:expr,
+        meta($inner:ident, $meta:ident) => $meta_body:expr,
+        $( con($con_inner:ident, $con:ident) => $con_body:expr, )?
+        $( list($list_xs:ident) => $list:expr, )?
+        $( array($xs:ident) => $array:expr, )?
+        $( leaf($leaf:ident : $leaf_ty:ty) => $leaf_body:expr ),* $(,)?
+    ) => {
+        $(#[$trait_attr])*
+        $trait_vis trait $trait {
+            #[allow(missing_docs)]
+            fn $method(self) -> $output;
+        }
+
+        impl $trait for $crate::Unit {
+            fn $method(self) -> $output {
+                let $crate::Unit = self;
+                $unit
+            }
+        }
+
+        impl<A, B> $trait for $crate::Prod<A, B>
+        where
+            A: $trait,
+            B: $trait,
+        {
+            fn $method(self) -> $output {
+                let $crate::Prod(a, b) = self;
+                let $a = $trait::$method(a);
+                let $b = $trait::$method(b);
+                $prod
+            }
+        }
+
+        impl<L, R> $trait for $crate::Sum<L, R>
+        where
+            L: $trait,
+            R: $trait,
+        {
+            fn $method(self) -> $output {
+                let $x = match self {
+                    $crate::Sum::Left(l) => $trait::$method(l),
+                    $crate::Sum::Right(r) => $trait::$method(r),
+                };
+                $sum
+            }
+        }
+
+        impl $trait for $crate::Void {
+            fn $method(self) -> $output {
+                match self {}
+            }
+        }
+
+        impl<I, $meta> $trait for $crate::Meta<I, $meta>
+        where
+            I: $trait,
+            $meta: $crate::Singleton<T = &'static str>,
+        {
+            fn $method(self) -> $output {
+                let $crate::Meta(inner, _) = self;
+                let $inner = $trait::$method(inner);
+                $meta_body
+            }
+        }
+
+        $crate::define_generic_op! {
+            @con $trait, $method, $output; $( $con_inner, $con => $con_body )?
+        }
+
+        $(
+            impl<E> $trait for $crate::List<E>
+            where
+                E: $trait,
+            {
+                fn $method(self) -> $output {
+                    let $crate::List(items) = self;
+                    let $list_xs = items.into_iter().map($trait::$method).collect::<Vec<_>>();
+                    $list
+                }
+            }
+        )?
+
+        $(
+            impl<E, const N: usize> $trait for [E; N]
+            where
+                E: $trait,
+            {
+                fn $method(self) -> $output {
+                    let $xs = self.map($trait::$method);
+                    $array
+                }
+            }
+        )?
+
+        $(
+            impl $trait for $leaf_ty {
+                fn $method(self) -> $output {
+                    let $leaf = self;
+                    $leaf_body
+                }
+            }
+        )*
+
+        $(#[$fn_attr])*
+        $fn_vis fn $function<T>(x: T) -> $output
+        where
+            T: $crate::Generic,
+            T::Repr: $trait,
+        {
+            $trait::$method($crate::Generic::into_repr(x))
+        }
+    };
+}
+
+/// Asserts that two values are equal with `PartialEq`, like `assert_eq!`, but on failure reports
+/// only the leaves which differ, by their field paths, as found by `ops::diff`.
+///
+/// Both values must implement `Generic`, `Clone` and `PartialEq`, and their representation `GDiff`.
+/// They're only cloned if they're unequal. Like `assert_eq!`, a custom message may follow the values.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::{assert_generic_eq, Generic};
+///
+/// #[derive(Generic, Clone, PartialEq, Debug)]
+/// struct Config {
+///     name: String,
+///     ports: Vec<u16>,
+///     limits: Limits,
+/// }
+///
+/// #[derive(Generic, Clone, PartialEq, Debug)]
+/// struct Limits {
+///     memory: u32,
+///     cpus: u8,
+/// }
+///
+/// let config = Config {
+///     name: "api".to_string(),
+///     ports: vec![80, 443],
+///     limits: Limits { memory: 512, cpus: 2 },
+/// };
+/// assert_generic_eq!(config, config.clone());
+///
+/// let changed = Config { ports: vec![80], limits: Limits { cpus: 4, ..config.limits }, ..config.clone() };
+/// let message = std::panic::catch_unwind(|| assert_generic_eq!(config, changed, "config {}", 1))
+///     .unwrap_err()
+///     .downcast::<String>()
+///     .unwrap();
+/// assert_eq!(
+///     *message,
+///     "assertion `left == right` failed: config 1
+///   at ports[1]:
+///      left: 443
+///     right: <missing>
+///   at limits.cpus:
+///      left: 2
+///     right: 4"
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_generic_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    $crate::ops::assert_generic_eq_failed(left, right, ::core::option::Option::None);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left == *right) {
+                    $crate::ops::assert_generic_eq_failed(
+                        left,
+                        right,
+                        ::core::option::Option::Some(::core::format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+}
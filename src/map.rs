@@ -0,0 +1,122 @@
+use crate::{Con, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+/// A type-level function, mapping `T` to `Self::Output`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::TypeFn;
+///
+/// struct ToOption;
+///
+/// impl<T> TypeFn<T> for ToOption {
+///     type Output = Option<T>;
+/// }
+///
+/// let x: <ToOption as TypeFn<u8>>::Output = Some(42);
+/// ```
+pub trait TypeFn<T> {
+    /// The result of applying this function to `T`.
+    type Output;
+}
+
+/// Maps every `Leaf` of a representation with the type-level function `F`, preserving its structure.
+///
+/// `Unit`, `Prod`, `Sum`, `Void`, `Meta`, `Con`, `List`, and arrays are left in place; each leaf `T` becomes `<F as TypeFn<T>>::Output`.
+///
+/// This can be used to define mirror types of a representation, e.g. one where every field is optional.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::marker::PhantomData;
+/// use generics::{Con, Generic, MapLeaves, Meta, Prod, TypeFn, Unit};
+///
+/// struct ToOption;
+///
+/// impl<T> TypeFn<T> for ToOption {
+///     type Output = Option<T>;
+/// }
+///
+/// #[derive(Generic)]
+/// struct Foo {
+///     a: u8,
+///     b: u32,
+/// }
+///
+/// type PartialFoo = <<Foo as Generic>::Repr as MapLeaves<ToOption>>::Output;
+///
+/// let partial: PartialFoo = Con(
+///     Prod(
+///         Meta(Some(1u8), PhantomData),
+///         Prod(Meta(None::<u32>, PhantomData), Unit),
+///     ),
+///     PhantomData,
+/// );
+/// ```
+pub trait MapLeaves<F> {
+    /// The representation with every leaf mapped.
+    type Output;
+}
+
+impl<F> MapLeaves<F> for Unit {
+    type Output = Unit;
+}
+
+impl<F, A, B> MapLeaves<F> for Prod<A, B>
+where
+    A: MapLeaves<F>,
+    B: MapLeaves<F>,
+{
+    type Output = Prod<A::Output, B::Output>;
+}
+
+impl<F, L, R> MapLeaves<F> for Sum<L, R>
+where
+    L: MapLeaves<F>,
+    R: MapLeaves<F>,
+{
+    type Output = Sum<L::Output, R::Output>;
+}
+
+impl<F> MapLeaves<F> for Void {
+    type Output = Void;
+}
+
+impl<F, I, M> MapLeaves<F> for Meta<I, M>
+where
+    I: MapLeaves<F>,
+    M: Singleton,
+{
+    type Output = Meta<I::Output, M>;
+}
+
+impl<F, I, C> MapLeaves<F> for Con<I, C>
+where
+    I: MapLeaves<F>,
+    C: Singleton,
+{
+    type Output = Con<I::Output, C>;
+}
+
+impl<F, A> MapLeaves<F> for List<A>
+where
+    A: MapLeaves<F>,
+{
+    type Output = List<A::Output>;
+}
+
+impl<F, A, const N: usize> MapLeaves<F> for [A; N]
+where
+    A: MapLeaves<F>,
+{
+    type Output = [A::Output; N];
+}
+
+impl<F, T> MapLeaves<F> for T
+where
+    T: Leaf,
+    F: TypeFn<T>,
+{
+    type Output = F::Output;
+}
@@ -0,0 +1,276 @@
+//! Metadata for the representations of standard library types.
+//!
+//! These are named as `#[derive(Generic)]` would name them, so e.g. `Option<T>` is represented
+//! exactly as if it were defined locally with the derive:
+//!
+//! ```text
+//! Sum<Con<Unit, Option_None_Name>, Sum<Con<Prod<Meta<T::Repr, Option_Some_0>, Unit>, Option_Some_Name>, Void>>
+//! ```
+
+#![allow(non_camel_case_types)]
+
+use crate::{ConKind, Constructor, Datatype, Documented, FieldMeta, Singleton};
+
+/// Declares zero-sized `Singleton`s holding names, without doc comments or defaults.
+macro_rules! markers {
+    ( $( $(#[$attr:meta])* $ident:ident => $value:expr, )+ ) => {
+        $(
+            $(#[$attr])*
+            pub struct $ident;
+
+            impl $ident {
+                const NAME: &'static str = $value;
+            }
+
+            impl Singleton for $ident {
+                type T = &'static str;
+                fn get() -> Self::T {
+                    $value
+                }
+            }
+
+            impl Documented for $ident {
+                fn doc() -> &'static str {
+                    ""
+                }
+            }
+        )+
+    };
+}
+
+/// Implements `Datatype` for the `Singleton`s naming the constructors of each type.
+macro_rules! datatypes {
+    ( $( $datatype:expr => [ $( $ident:ident ),+ $(,)? ], )+ ) => {
+        $(
+            datatypes!(@each $datatype, [$( $ident ),+], [$( $ident ),+]);
+        )+
+    };
+    (@each $datatype:expr, [ $( $ident:ident ),+ ], $names:tt) => {
+        $(
+            datatypes!(@impl $datatype, $ident, $names);
+        )+
+    };
+    (@impl $datatype:expr, $ident:ident, [ $( $name:ident ),+ ]) => {
+        impl Datatype for $ident {
+            fn datatype_name() -> &'static str {
+                $datatype
+            }
+
+            fn constructor_names() -> &'static [&'static str] {
+                &[$( $name::NAME ),+]
+            }
+        }
+    };
+}
+
+/// Implements `Constructor` for the `Singleton`s naming the constructors of each kind, given their fields' selectors,
+/// and `FieldMeta` for those selectors, given the fields' types as declared in the standard library.
+macro_rules! constructors {
+    ( $( $kind:ident => [ $( $ident:ident ( $( $field:ident: $ty:ty ),* ) ),+ $(,)? ], )+ ) => {
+        $(
+            $(
+                impl Constructor for $ident {
+                    fn con_kind() -> ConKind {
+                        ConKind::$kind
+                    }
+
+                    fn field_names() -> &'static [&'static str] {
+                        &[$( $field::NAME ),*]
+                    }
+                }
+
+                $(
+                    impl FieldMeta for $field {
+                        fn type_name() -> &'static str {
+                            stringify!($ty)
+                        }
+                    }
+                )*
+            )+
+        )+
+    };
+}
+
+markers! {
+    /// Name of the `Option::None` constructor.
+    Option_None_Name => "None",
+    /// Name of the `Option::Some` constructor.
+    Option_Some_Name => "Some",
+    /// Selector for the `0` field of `Option::Some`.
+    Option_Some_0 => "0",
+}
+
+markers! {
+    /// Name of the `Result::Ok` constructor.
+    Result_Ok_Name => "Ok",
+    /// Selector for the `0` field of `Result::Ok`.
+    Result_Ok_0 => "0",
+    /// Name of the `Result::Err` constructor.
+    Result_Err_Name => "Err",
+    /// Selector for the `0` field of `Result::Err`.
+    Result_Err_0 => "0",
+}
+
+markers! {
+    /// Name of the `Duration` constructor.
+    Duration_Name => "Duration",
+    /// Selector for the `secs` field of `Duration`.
+    Duration_secs => "secs",
+    /// Selector for the `nanos` field of `Duration`.
+    Duration_nanos => "nanos",
+}
+
+markers! {
+    /// Name of the `IpAddr::V4` constructor.
+    IpAddr_V4_Name => "V4",
+    /// Selector for the `0` field of `IpAddr::V4`.
+    IpAddr_V4_0 => "0",
+    /// Name of the `IpAddr::V6` constructor.
+    IpAddr_V6_Name => "V6",
+    /// Selector for the `0` field of `IpAddr::V6`.
+    IpAddr_V6_0 => "0",
+    /// Name of the `SocketAddr::V4` constructor.
+    SocketAddr_V4_Name => "V4",
+    /// Selector for the `0` field of `SocketAddr::V4`.
+    SocketAddr_V4_0 => "0",
+    /// Name of the `SocketAddr::V6` constructor.
+    SocketAddr_V6_Name => "V6",
+    /// Selector for the `0` field of `SocketAddr::V6`.
+    SocketAddr_V6_0 => "0",
+}
+
+markers! {
+    /// Name of the `Ordering::Less` constructor.
+    Ordering_Less_Name => "Less",
+    /// Name of the `Ordering::Equal` constructor.
+    Ordering_Equal_Name => "Equal",
+    /// Name of the `Ordering::Greater` constructor.
+    Ordering_Greater_Name => "Greater",
+    /// Name of the `FpCategory::Nan` constructor.
+    FpCategory_Nan_Name => "Nan",
+    /// Name of the `FpCategory::Infinite` constructor.
+    FpCategory_Infinite_Name => "Infinite",
+    /// Name of the `FpCategory::Zero` constructor.
+    FpCategory_Zero_Name => "Zero",
+    /// Name of the `FpCategory::Subnormal` constructor.
+    FpCategory_Subnormal_Name => "Subnormal",
+    /// Name of the `FpCategory::Normal` constructor.
+    FpCategory_Normal_Name => "Normal",
+    /// Name of the `Alignment::Left` constructor.
+    Alignment_Left_Name => "Left",
+    /// Name of the `Alignment::Right` constructor.
+    Alignment_Right_Name => "Right",
+    /// Name of the `Alignment::Center` constructor.
+    Alignment_Center_Name => "Center",
+    /// Name of the `Shutdown::Read` constructor.
+    Shutdown_Read_Name => "Read",
+    /// Name of the `Shutdown::Write` constructor.
+    Shutdown_Write_Name => "Write",
+    /// Name of the `Shutdown::Both` constructor.
+    Shutdown_Both_Name => "Both",
+}
+
+markers! {
+    /// Name of the `Range` constructor.
+    Range_Name => "Range",
+    /// Selector for the `start` field of `Range`.
+    Range_start => "start",
+    /// Selector for the `end` field of `Range`.
+    Range_end => "end",
+    /// Name of the `RangeInclusive` constructor.
+    RangeInclusive_Name => "RangeInclusive",
+    /// Selector for the `start` field of `RangeInclusive`.
+    RangeInclusive_start => "start",
+    /// Selector for the `end` field of `RangeInclusive`.
+    RangeInclusive_end => "end",
+    /// Name of the `RangeFrom` constructor.
+    RangeFrom_Name => "RangeFrom",
+    /// Selector for the `start` field of `RangeFrom`.
+    RangeFrom_start => "start",
+    /// Name of the `RangeTo` constructor.
+    RangeTo_Name => "RangeTo",
+    /// Selector for the `end` field of `RangeTo`.
+    RangeTo_end => "end",
+    /// Name of the `RangeToInclusive` constructor.
+    RangeToInclusive_Name => "RangeToInclusive",
+    /// Selector for the `end` field of `RangeToInclusive`.
+    RangeToInclusive_end => "end",
+    /// Name of the `RangeFull` constructor.
+    RangeFull_Name => "RangeFull",
+    /// Name of the `Bound::Included` constructor.
+    Bound_Included_Name => "Included",
+    /// Selector for the `0` field of `Bound::Included`.
+    Bound_Included_0 => "0",
+    /// Name of the `Bound::Excluded` constructor.
+    Bound_Excluded_Name => "Excluded",
+    /// Selector for the `0` field of `Bound::Excluded`.
+    Bound_Excluded_0 => "0",
+    /// Name of the `Bound::Unbounded` constructor.
+    Bound_Unbounded_Name => "Unbounded",
+}
+
+datatypes! {
+    "Option" => [Option_None_Name, Option_Some_Name],
+    "Result" => [Result_Ok_Name, Result_Err_Name],
+    "Duration" => [Duration_Name],
+    "IpAddr" => [IpAddr_V4_Name, IpAddr_V6_Name],
+    "SocketAddr" => [SocketAddr_V4_Name, SocketAddr_V6_Name],
+    "Ordering" => [Ordering_Less_Name, Ordering_Equal_Name, Ordering_Greater_Name],
+    "FpCategory" => [
+        FpCategory_Nan_Name,
+        FpCategory_Infinite_Name,
+        FpCategory_Zero_Name,
+        FpCategory_Subnormal_Name,
+        FpCategory_Normal_Name,
+    ],
+    "Alignment" => [Alignment_Left_Name, Alignment_Right_Name, Alignment_Center_Name],
+    "Shutdown" => [Shutdown_Read_Name, Shutdown_Write_Name, Shutdown_Both_Name],
+    "Range" => [Range_Name],
+    "RangeInclusive" => [RangeInclusive_Name],
+    "RangeFrom" => [RangeFrom_Name],
+    "RangeTo" => [RangeTo_Name],
+    "RangeToInclusive" => [RangeToInclusive_Name],
+    "RangeFull" => [RangeFull_Name],
+    "Bound" => [Bound_Included_Name, Bound_Excluded_Name, Bound_Unbounded_Name],
+}
+
+constructors! {
+    Unit => [
+        Option_None_Name(),
+        Ordering_Less_Name(),
+        Ordering_Equal_Name(),
+        Ordering_Greater_Name(),
+        FpCategory_Nan_Name(),
+        FpCategory_Infinite_Name(),
+        FpCategory_Zero_Name(),
+        FpCategory_Subnormal_Name(),
+        FpCategory_Normal_Name(),
+        Alignment_Left_Name(),
+        Alignment_Right_Name(),
+        Alignment_Center_Name(),
+        Shutdown_Read_Name(),
+        Shutdown_Write_Name(),
+        Shutdown_Both_Name(),
+        RangeFull_Name(),
+        Bound_Unbounded_Name(),
+    ],
+    Tuple => [
+        Option_Some_Name(Option_Some_0: T),
+        Result_Ok_Name(Result_Ok_0: T),
+        Result_Err_Name(Result_Err_0: E),
+        IpAddr_V4_Name(IpAddr_V4_0: Ipv4Addr),
+        IpAddr_V6_Name(IpAddr_V6_0: Ipv6Addr),
+        SocketAddr_V4_Name(SocketAddr_V4_0: SocketAddrV4),
+        SocketAddr_V6_Name(SocketAddr_V6_0: SocketAddrV6),
+        Bound_Included_Name(Bound_Included_0: T),
+        Bound_Excluded_Name(Bound_Excluded_0: T),
+    ],
+    Braced => [
+        Duration_Name(Duration_secs: u64, Duration_nanos: u32),
+        Range_Name(Range_start: Idx, Range_end: Idx),
+        RangeInclusive_Name(RangeInclusive_start: Idx, RangeInclusive_end: Idx),
+        RangeFrom_Name(RangeFrom_start: Idx),
+        RangeTo_Name(RangeTo_end: Idx),
+        RangeToInclusive_Name(RangeToInclusive_end: Idx),
+    ],
+}
@@ -0,0 +1,73 @@
+use std::marker::PhantomData;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::markers::{
+    IpAddr_V4_0, IpAddr_V4_Name, IpAddr_V6_0, IpAddr_V6_Name, SocketAddr_V4_0, SocketAddr_V4_Name,
+    SocketAddr_V6_0, SocketAddr_V6_Name,
+};
+use crate::{Con, Generic, Leaf, Meta, Prod, Sum, Unit, Void};
+
+/// Addresses are leaves, since their fields are private.
+macro_rules! impl_addr_leaf {
+    ( $( $ty:ty ),+ $(,)? ) => {
+        $(
+            impl Generic for $ty {
+                type Repr = Self;
+                fn into_repr(self) -> Self::Repr {
+                    self
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    repr
+                }
+            }
+
+            impl Leaf for $ty {}
+        )+
+    };
+}
+
+impl_addr_leaf!(Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6);
+
+/// Enums of a V4 and a V6 address are represented as if they were derived.
+macro_rules! impl_addr_enum {
+    ( $( $ty:ident {
+        V4($v4:ty, $v4_0:ident, $v4_name:ident),
+        V6($v6:ty, $v6_0:ident, $v6_name:ident),
+    } )+ ) => {
+        $(
+            impl Generic for $ty {
+                type Repr = Sum<
+                    Con<Prod<Meta<$v4, $v4_0>, Unit>, $v4_name>,
+                    Sum<Con<Prod<Meta<$v6, $v6_0>, Unit>, $v6_name>, Void>,
+                >;
+                fn into_repr(self) -> Self::Repr {
+                    match self {
+                        $ty::V4(x) => Sum::Left(Con(Prod(Meta(x, PhantomData), Unit), PhantomData)),
+                        $ty::V6(x) => Sum::Right(Sum::Left(Con(
+                            Prod(Meta(x, PhantomData), Unit),
+                            PhantomData,
+                        ))),
+                    }
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    match repr {
+                        Sum::Left(Con(Prod(Meta(x, _), Unit), _)) => $ty::V4(x),
+                        Sum::Right(Sum::Left(Con(Prod(Meta(x, _), Unit), _))) => $ty::V6(x),
+                        Sum::Right(Sum::Right(void)) => match void {},
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_addr_enum! {
+    IpAddr {
+        V4(Ipv4Addr, IpAddr_V4_0, IpAddr_V4_Name),
+        V6(Ipv6Addr, IpAddr_V6_0, IpAddr_V6_Name),
+    }
+    SocketAddr {
+        V4(SocketAddrV4, SocketAddr_V4_0, SocketAddr_V4_Name),
+        V6(SocketAddrV6, SocketAddr_V6_0, SocketAddr_V6_Name),
+    }
+}
@@ -0,0 +1,85 @@
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+};
+
+use crate::{Generic, ReprError, TryFromRepr};
+
+macro_rules! impl_non_zero {
+    ( $( $ty:ident => $int:ty ),+ $(,)? ) => {
+        $(
+            /// Represented as the underlying integer.
+            ///
+            /// # Panics
+            ///
+            /// `from_repr` panics if the representation is zero, and `checked_from_repr` fails.
+            impl Generic for $ty {
+                type Repr = $int;
+                fn into_repr(self) -> Self::Repr {
+                    self.get()
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    match Self::checked_from_repr(repr) {
+                        Ok(x) => x,
+                        Err(e) => panic!("{}", e),
+                    }
+                }
+                fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+                    $ty::new(repr).ok_or_else(|| {
+                        ReprError::new(concat!("`", stringify!($ty), "` represented as zero"))
+                    })
+                }
+            }
+
+            /// Fails if the representation is zero.
+            impl TryFromRepr for $ty {
+                type Error = ReprError;
+                fn try_from_repr(repr: Self::Repr) -> Result<Self, Self::Error> {
+                    Self::checked_from_repr(repr)
+                }
+            }
+        )+
+    };
+}
+
+#[rustfmt::skip]
+impl_non_zero!(
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32,
+    NonZeroU64 => u64, NonZeroU128 => u128, NonZeroUsize => usize,
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32,
+    NonZeroI64 => i64, NonZeroI128 => i128, NonZeroIsize => isize,
+);
+
+/// Represented as the wrapped value.
+impl<T> Generic for Wrapping<T>
+where
+    T: Generic,
+{
+    type Repr = T::Repr;
+    fn into_repr(self) -> Self::Repr {
+        self.0.into_repr()
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        Wrapping(T::from_repr(repr))
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        T::checked_from_repr(repr).map(Wrapping)
+    }
+}
+
+/// Represented as the wrapped value.
+impl<T> Generic for Saturating<T>
+where
+    T: Generic,
+{
+    type Repr = T::Repr;
+    fn into_repr(self) -> Self::Repr {
+        self.0.into_repr()
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        Saturating(T::from_repr(repr))
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        T::checked_from_repr(repr).map(Saturating)
+    }
+}
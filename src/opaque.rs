@@ -0,0 +1,50 @@
+use std::fmt::{self, Debug};
+
+use crate::{Generic, Leaf};
+
+/// A leaf wrapping any value, so it's left as-is instead of being converted to its representation.
+///
+/// This can be used to opt out of structural treatment, e.g. so a `Vec<u8>` is handled as a single value
+/// by an operation which supports it natively, instead of as a `List` of bytes.
+///
+/// Fields of a type deriving `Generic` can be wrapped automatically with `#[generic(opaque)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::{Generic, Meta, Opaque};
+///
+/// #[derive(Generic)]
+/// struct Foo {
+///     #[generic(opaque)]
+///     a: Vec<u8>,
+/// }
+///
+/// let repr = Foo { a: vec![1, 2] }.into_repr();
+/// let Meta(Opaque(a), _) = (repr.0).0;
+/// assert_eq!(a, [1, 2]);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Opaque<T>(pub T);
+
+/// Formats the wrapped value alone, so `Opaque` is transparent in debug output.
+impl<T> Debug for Opaque<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T> Generic for Opaque<T> {
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+impl<T> Leaf for Opaque<T> {}
@@ -0,0 +1,266 @@
+use crate::{Con, Generic, Leaf, List, Meta, Opaque, Prod, Sensitive, Singleton, Sum, Unit, Void};
+
+use super::Borrowed;
+
+/// Equality within a tolerance, for floats, and exact equality for other values.
+///
+/// Implement this for your own leaf types, or derive it with `#[derive(GenericApproxEq)]`.
+pub trait ApproxEq {
+    /// Whether `self` and `other` are equal, allowing floats to differ by at most `epsilon`.
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool;
+}
+
+macro_rules! impl_approx_eq_float {
+    ( $( $ty:ty ),+ ) => {
+        $(
+            /// Equal if within `epsilon` of each other, or if identical, e.g. both infinite. `NaN` is never equal.
+            impl ApproxEq for $ty {
+                fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+                    self == other || (f64::from(*self) - f64::from(*other)).abs() <= epsilon
+                }
+            }
+        )+
+    };
+}
+
+impl_approx_eq_float!(f32, f64);
+
+macro_rules! impl_approx_eq_exact {
+    ( $( $ty:ty ),+ $(,)? ) => {
+        $(
+            impl ApproxEq for $ty {
+                fn approx_eq(&self, other: &Self, _epsilon: f64) -> bool {
+                    self == other
+                }
+            }
+        )+
+    };
+}
+
+#[rustfmt::skip]
+impl_approx_eq_exact!(
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    bool, char, str, String, (),
+);
+
+impl<T> ApproxEq for [T]
+where
+    T: ApproxEq,
+{
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.approx_eq(b, epsilon))
+    }
+}
+
+impl<T, const N: usize> ApproxEq for [T; N]
+where
+    T: ApproxEq,
+{
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self[..].approx_eq(&other[..], epsilon)
+    }
+}
+
+impl<T> ApproxEq for Vec<T>
+where
+    T: ApproxEq,
+{
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self[..].approx_eq(&other[..], epsilon)
+    }
+}
+
+impl<T> ApproxEq for Option<T>
+where
+    T: ApproxEq,
+{
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.approx_eq(b, epsilon),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T> ApproxEq for &T
+where
+    T: ApproxEq + ?Sized,
+{
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        (**self).approx_eq(*other, epsilon)
+    }
+}
+
+impl<T> ApproxEq for Box<T>
+where
+    T: ApproxEq + ?Sized,
+{
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        (**self).approx_eq(&**other, epsilon)
+    }
+}
+
+impl<T> ApproxEq for Opaque<T>
+where
+    T: ApproxEq,
+{
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.approx_eq(&other.0, epsilon)
+    }
+}
+
+impl<T> ApproxEq for Sensitive<T>
+where
+    T: ApproxEq,
+{
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.approx_eq(&other.0, epsilon)
+    }
+}
+
+/// Compares representations for equality, node by node, allowing float leaves to differ by at most `epsilon`.
+///
+/// Products are equal if all their fields are, and sums if they hold the same variant with equal payloads.
+/// Lists and arrays must have the same length. Leaves are compared with `ApproxEq`,
+/// which applies the tolerance to floats and compares other leaves exactly.
+///
+/// `#[derive(GenericApproxEq)]` uses this to implement `ApproxEq`, alongside `#[derive(Generic)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{approx_eq, ApproxEq, GenericApproxEq};
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericApproxEq)]
+/// struct Body {
+///     mass: f64,
+///     position: [f32; 2],
+///     id: u32,
+/// }
+///
+/// let body = Body { mass: 1.0, position: [0.5, 0.25], id: 7 };
+/// let moved = Body { mass: 1.0 + 1e-12, position: [0.5, 0.25 + 1e-7], id: 7 };
+/// assert!(body.approx_eq(&moved, 1e-6));
+/// assert!(!body.approx_eq(&Body { id: 8, ..moved }, 1e-6));
+///
+/// assert!(approx_eq((0.1 + 0.2, "a"), (0.3, "a"), 1e-9));
+/// assert!(!approx_eq((0.1 + 0.2, "a"), (0.3, "b"), 1e-9));
+/// ```
+pub trait GApproxEq {
+    /// Whether `self` and `other` are equal, allowing float leaves to differ by at most `epsilon`.
+    fn gapprox_eq(&self, other: &Self, epsilon: f64) -> bool;
+}
+
+impl GApproxEq for Unit {
+    fn gapprox_eq(&self, _other: &Self, _epsilon: f64) -> bool {
+        true
+    }
+}
+
+impl<A, B> GApproxEq for Prod<A, B>
+where
+    A: GApproxEq,
+    B: GApproxEq,
+{
+    fn gapprox_eq(&self, other: &Self, epsilon: f64) -> bool {
+        let (Prod(a, b), Prod(other_a, other_b)) = (self, other);
+        a.gapprox_eq(other_a, epsilon) && b.gapprox_eq(other_b, epsilon)
+    }
+}
+
+impl<L, R> GApproxEq for Sum<L, R>
+where
+    L: GApproxEq,
+    R: GApproxEq,
+{
+    fn gapprox_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (Sum::Left(l), Sum::Left(other)) => l.gapprox_eq(other, epsilon),
+            (Sum::Right(r), Sum::Right(other)) => r.gapprox_eq(other, epsilon),
+            _ => false,
+        }
+    }
+}
+
+impl GApproxEq for Void {
+    fn gapprox_eq(&self, _other: &Self, _epsilon: f64) -> bool {
+        match *self {}
+    }
+}
+
+impl<I, M> GApproxEq for Meta<I, M>
+where
+    I: GApproxEq,
+    M: Singleton,
+{
+    fn gapprox_eq(&self, other: &Self, epsilon: f64) -> bool {
+        let (Meta(inner, _), Meta(other, _)) = (self, other);
+        inner.gapprox_eq(other, epsilon)
+    }
+}
+
+impl<I, C> GApproxEq for Con<I, C>
+where
+    I: GApproxEq,
+    C: Singleton,
+{
+    fn gapprox_eq(&self, other: &Self, epsilon: f64) -> bool {
+        let (Con(inner, _), Con(other, _)) = (self, other);
+        inner.gapprox_eq(other, epsilon)
+    }
+}
+
+impl<A> GApproxEq for List<A>
+where
+    A: GApproxEq,
+{
+    fn gapprox_eq(&self, other: &Self, epsilon: f64) -> bool {
+        let (List(items), List(other)) = (self, other);
+        items.len() == other.len()
+            && items
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.gapprox_eq(b, epsilon))
+    }
+}
+
+impl<A, const N: usize> GApproxEq for [A; N]
+where
+    A: GApproxEq,
+{
+    fn gapprox_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(a, b)| a.gapprox_eq(b, epsilon))
+    }
+}
+
+impl<T> GApproxEq for Borrowed<'_, T>
+where
+    T: ApproxEq,
+{
+    fn gapprox_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.approx_eq(other.0, epsilon)
+    }
+}
+
+impl<T> GApproxEq for T
+where
+    T: Leaf + ApproxEq,
+{
+    fn gapprox_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.approx_eq(other, epsilon)
+    }
+}
+
+/// Whether `a` and `b` are equal, allowing their float leaves to differ by at most `epsilon`.
+pub fn approx_eq<T>(a: T, b: T, epsilon: f64) -> bool
+where
+    T: Generic,
+    T::Repr: GApproxEq,
+{
+    a.into_repr().gapprox_eq(&b.into_repr(), epsilon)
+}
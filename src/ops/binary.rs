@@ -0,0 +1,303 @@
+use std::convert::{Infallible, TryFrom, TryInto};
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use crate::{Generic, Opaque, ReprError};
+
+use super::bytes::{take, Format, FormatLeaf, GDecodeBytes, GEncodeBytes};
+
+/// A leaf which can be encoded as bytes, like GHC's `Binary` class.
+///
+/// Implement this for your own `Leaf` types to encode and decode them with `GEncode` and `GDecode`.
+pub trait Binary: Sized {
+    /// Appends the encoding of `self` to `out`.
+    fn put(self, out: &mut Vec<u8>);
+
+    /// Decodes a value from the start of `input`, advancing it past the bytes read.
+    fn get(input: &mut &[u8]) -> Result<Self, DecodeBinaryError>;
+}
+
+/// Decodes a length, which is encoded as a `u64`.
+fn get_len(input: &mut &[u8]) -> Result<usize, DecodeBinaryError> {
+    let len = u64::get(input)?;
+    usize::try_from(len).map_err(|_| DecodeBinaryError::Invalid("length"))
+}
+
+macro_rules! impl_binary_le {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            /// Little-endian.
+            impl Binary for $ty {
+                fn put(self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn get(input: &mut &[u8]) -> Result<Self, DecodeBinaryError> {
+                    let bytes = take::<BinaryFormat>(input, std::mem::size_of::<$ty>())?;
+                    Ok($ty::from_le_bytes(bytes.try_into().expect("took the size of the type")))
+                }
+            }
+        )+
+    }
+}
+
+#[rustfmt::skip]
+impl_binary_le!(
+    u8, u16, u32, u64, u128,
+    i8, i16, i32, i64, i128,
+    f32, f64,
+);
+
+/// As a `u64`, so encodings are portable.
+impl Binary for usize {
+    fn put(self, out: &mut Vec<u8>) {
+        (self as u64).put(out);
+    }
+
+    fn get(input: &mut &[u8]) -> Result<Self, DecodeBinaryError> {
+        usize::try_from(u64::get(input)?).map_err(|_| DecodeBinaryError::Invalid("usize"))
+    }
+}
+
+/// As an `i64`, so encodings are portable.
+impl Binary for isize {
+    fn put(self, out: &mut Vec<u8>) {
+        (self as i64).put(out);
+    }
+
+    fn get(input: &mut &[u8]) -> Result<Self, DecodeBinaryError> {
+        isize::try_from(i64::get(input)?).map_err(|_| DecodeBinaryError::Invalid("isize"))
+    }
+}
+
+/// As one byte, which must be 0 or 1.
+impl Binary for bool {
+    fn put(self, out: &mut Vec<u8>) {
+        out.push(self as u8);
+    }
+
+    fn get(input: &mut &[u8]) -> Result<Self, DecodeBinaryError> {
+        match u8::get(input)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(DecodeBinaryError::Invalid("bool")),
+        }
+    }
+}
+
+/// As a `u32` scalar value.
+impl Binary for char {
+    fn put(self, out: &mut Vec<u8>) {
+        (self as u32).put(out);
+    }
+
+    fn get(input: &mut &[u8]) -> Result<Self, DecodeBinaryError> {
+        char::from_u32(u32::get(input)?).ok_or(DecodeBinaryError::Invalid("char"))
+    }
+}
+
+/// As its length in bytes, then its UTF-8 bytes.
+impl Binary for String {
+    fn put(self, out: &mut Vec<u8>) {
+        self.len().put(out);
+        out.extend_from_slice(self.as_bytes());
+    }
+
+    fn get(input: &mut &[u8]) -> Result<Self, DecodeBinaryError> {
+        let len = get_len(input)?;
+        let bytes = take::<BinaryFormat>(input, len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| DecodeBinaryError::Invalid("string"))
+    }
+}
+
+impl<T> Binary for Opaque<T>
+where
+    T: Binary,
+{
+    fn put(self, out: &mut Vec<u8>) {
+        self.0.put(out);
+    }
+
+    fn get(input: &mut &[u8]) -> Result<Self, DecodeBinaryError> {
+        T::get(input).map(Opaque)
+    }
+}
+
+/// Encodes a representation as compact bytes, like bincode, node by node.
+///
+/// Products are their fields in order, with no names or lengths, and sums are the index of their variant
+/// as a `u32`, then its fields. Lists are their length as a `u64`, then their items, arrays are just their items,
+/// and leaves are encoded with `Binary`, e.g. numbers as little-endian bytes.
+/// The encoding has no metadata, so it is only compatible between types with the same shape.
+///
+/// When decoding, a list can't have more items than there are bytes left, or if its items are zero-sized,
+/// more than 65536 of them, so a corrupt length fails quickly.
+///
+/// `encode_binary` and `decode_binary` use this and `GDecode` to encode and decode any `Generic` type,
+/// without any other derives, like GHC's generic `Binary` instances.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{decode_binary, encode_binary, DecodeBinaryError};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Packet {
+///     seq: u16,
+///     body: Body,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum Body {
+///     Ping,
+///     Data(Vec<u8>),
+/// }
+///
+/// let bytes = encode_binary(Packet { seq: 1, body: Body::Data(vec![7, 8]) });
+/// assert_eq!(bytes, [1, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 7, 8]);
+/// assert_eq!(decode_binary::<Packet>(&bytes), Ok(Packet { seq: 1, body: Body::Data(vec![7, 8]) }));
+///
+/// assert_eq!(decode_binary::<Packet>(&[1, 0, 2, 0, 0, 0]), Err(DecodeBinaryError::Variant(2)));
+/// assert_eq!(decode_binary::<Packet>(&bytes[..15]), Err(DecodeBinaryError::Eof));
+/// ```
+pub trait GEncode {
+    /// Appends the encoding of `self` to `out`.
+    fn gencode(self, out: &mut Vec<u8>);
+}
+
+/// Decodes a representation from bytes, node by node.
+///
+/// This is the inverse of `GEncode`.
+pub trait GDecode: Sized {
+    /// Decodes a representation from the start of `input`, advancing it past the bytes read.
+    fn gdecode(input: &mut &[u8]) -> Result<Self, DecodeBinaryError>;
+}
+
+/// An error decoding a representation from bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeBinaryError {
+    /// The input ended before the value did.
+    Eof,
+    /// The index of a variant was out of range for its sum.
+    Variant(u32),
+    /// A leaf of the given type had an invalid encoding, e.g. a `bool` other than 0 or 1.
+    Invalid(&'static str),
+    /// The given number of bytes were left over after the value.
+    Trailing(usize),
+    /// The value was invalid for its type, e.g. a `NonZeroU8` of zero.
+    Repr(ReprError),
+}
+
+impl Display for DecodeBinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeBinaryError::Eof => write!(f, "unexpected end of input"),
+            DecodeBinaryError::Variant(index) => write!(f, "invalid variant index {}", index),
+            DecodeBinaryError::Invalid(ty) => write!(f, "invalid encoding of {}", ty),
+            DecodeBinaryError::Trailing(len) => write!(f, "{} trailing bytes", len),
+            DecodeBinaryError::Repr(error) => write!(f, "invalid value: {}", error),
+        }
+    }
+}
+
+impl Error for DecodeBinaryError {}
+
+/// The format of `GEncode`.
+#[derive(Debug)]
+pub enum BinaryFormat {}
+
+impl Format for BinaryFormat {
+    type Out = Vec<u8>;
+    type EncodeError = Infallible;
+    type Error = DecodeBinaryError;
+
+    fn put_index(index: u32, out: &mut Vec<u8>) -> Result<(), Infallible> {
+        index.put(out);
+        Ok(())
+    }
+
+    fn put_len(len: usize, out: &mut Vec<u8>) -> Result<(), Infallible> {
+        len.put(out);
+        Ok(())
+    }
+
+    fn get_index(input: &mut &[u8]) -> Result<u32, DecodeBinaryError> {
+        u32::get(input)
+    }
+
+    fn get_len(input: &mut &[u8]) -> Result<usize, DecodeBinaryError> {
+        get_len(input)
+    }
+
+    fn eof() -> DecodeBinaryError {
+        DecodeBinaryError::Eof
+    }
+
+    fn variant(index: u32) -> DecodeBinaryError {
+        DecodeBinaryError::Variant(index)
+    }
+
+    fn invalid(ty: &'static str) -> DecodeBinaryError {
+        DecodeBinaryError::Invalid(ty)
+    }
+}
+
+impl<T> FormatLeaf<BinaryFormat> for T
+where
+    T: Binary,
+{
+    fn put_leaf(self, out: &mut Vec<u8>) -> Result<(), Infallible> {
+        self.put(out);
+        Ok(())
+    }
+
+    fn get_leaf(input: &mut &[u8]) -> Result<Self, DecodeBinaryError> {
+        T::get(input)
+    }
+}
+
+impl<T> GEncode for T
+where
+    T: GEncodeBytes<BinaryFormat>,
+{
+    fn gencode(self, out: &mut Vec<u8>) {
+        match self.gencode_bytes(out) {
+            Ok(()) => {}
+            Err(never) => match never {},
+        }
+    }
+}
+
+impl<T> GDecode for T
+where
+    T: GDecodeBytes<BinaryFormat>,
+{
+    fn gdecode(input: &mut &[u8]) -> Result<Self, DecodeBinaryError> {
+        T::gdecode_bytes(input)
+    }
+}
+
+/// Encodes `x` as bytes.
+pub fn encode_binary<T>(x: T) -> Vec<u8>
+where
+    T: Generic,
+    T::Repr: GEncode,
+{
+    let mut out = Vec::new();
+    x.into_repr().gencode(&mut out);
+    out
+}
+
+/// Decodes a `T` from `bytes`, which must hold exactly one encoded value.
+pub fn decode_binary<T>(mut bytes: &[u8]) -> Result<T, DecodeBinaryError>
+where
+    T: Generic,
+    T::Repr: GDecode,
+{
+    let repr = T::Repr::gdecode(&mut bytes)?;
+    if !bytes.is_empty() {
+        return Err(DecodeBinaryError::Trailing(bytes.len()));
+    }
+    T::checked_from_repr(repr).map_err(DecodeBinaryError::Repr)
+}
@@ -0,0 +1,250 @@
+use std::marker::PhantomData;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::{Con, Generic, Leaf, Meta, Opaque, Prod, ReprError, Singleton, Sum, Unit, Void};
+
+/// A leaf with a minimum and maximum value.
+///
+/// Implement this for your own leaf types to use them with `GBounded`.
+pub trait Bounded {
+    /// The smallest value.
+    fn min_value() -> Self;
+
+    /// The largest value.
+    fn max_value() -> Self;
+}
+
+macro_rules! impl_bounded {
+    ( $( $ty:ty ),+ $(,)? ) => {
+        $(
+            impl Bounded for $ty {
+                fn min_value() -> Self {
+                    <$ty>::MIN
+                }
+
+                fn max_value() -> Self {
+                    <$ty>::MAX
+                }
+            }
+        )+
+    }
+}
+
+#[rustfmt::skip]
+impl_bounded!(
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64,
+    char,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+);
+
+impl Bounded for bool {
+    fn min_value() -> Self {
+        false
+    }
+
+    fn max_value() -> Self {
+        true
+    }
+}
+
+impl<T> Bounded for Opaque<T>
+where
+    T: Bounded,
+{
+    fn min_value() -> Self {
+        Opaque(T::min_value())
+    }
+
+    fn max_value() -> Self {
+        Opaque(T::max_value())
+    }
+}
+
+/// Constructs the minimum and maximum representations, node by node.
+///
+/// Products take the minimum or maximum of each field, and sums take the minimum of their first variant,
+/// or the maximum of their last, matching the order of `#[derive(Ord)]`.
+/// Leaves use `Bounded`. Lists have no impl, since they have no maximum.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{max_value, min_value};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Range {
+///     start: u8,
+///     end: Option<i16>,
+/// }
+///
+/// assert_eq!(min_value::<Range>(), Ok(Range { start: 0, end: None }));
+/// assert_eq!(max_value::<Range>(), Ok(Range { start: 255, end: Some(i16::MAX) }));
+/// ```
+pub trait GBounded {
+    /// Constructs the minimum representation.
+    fn gmin() -> Self;
+
+    /// Constructs the maximum representation.
+    fn gmax() -> Self;
+}
+
+/// Constructs the maximum of the last variant in a `Sum` chain, if there is one.
+pub trait GBoundedVariant: Sized {
+    /// Constructs the maximum of the last variant, or `None` for `Void`.
+    fn gmax_variant() -> Option<Self>;
+}
+
+impl GBounded for Unit {
+    fn gmin() -> Self {
+        Unit
+    }
+
+    fn gmax() -> Self {
+        Unit
+    }
+}
+
+impl<A, B> GBounded for Prod<A, B>
+where
+    A: GBounded,
+    B: GBounded,
+{
+    fn gmin() -> Self {
+        Prod(A::gmin(), B::gmin())
+    }
+
+    fn gmax() -> Self {
+        Prod(A::gmax(), B::gmax())
+    }
+}
+
+impl<L, R> GBounded for Sum<L, R>
+where
+    L: GBounded,
+    R: GBoundedVariant,
+{
+    fn gmin() -> Self {
+        Sum::Left(L::gmin())
+    }
+
+    fn gmax() -> Self {
+        match R::gmax_variant() {
+            Some(r) => Sum::Right(r),
+            None => Sum::Left(L::gmax()),
+        }
+    }
+}
+
+impl GBoundedVariant for Void {
+    fn gmax_variant() -> Option<Self> {
+        None
+    }
+}
+
+impl<L, R> GBoundedVariant for Sum<L, R>
+where
+    L: GBounded,
+    R: GBoundedVariant,
+{
+    fn gmax_variant() -> Option<Self> {
+        Some(Self::gmax())
+    }
+}
+
+impl<I, M> GBounded for Meta<I, M>
+where
+    I: GBounded,
+    M: Singleton,
+{
+    fn gmin() -> Self {
+        Meta(I::gmin(), PhantomData)
+    }
+
+    fn gmax() -> Self {
+        Meta(I::gmax(), PhantomData)
+    }
+}
+
+impl<I, C> GBounded for Con<I, C>
+where
+    I: GBounded,
+    C: Singleton,
+{
+    fn gmin() -> Self {
+        Con(I::gmin(), PhantomData)
+    }
+
+    fn gmax() -> Self {
+        Con(I::gmax(), PhantomData)
+    }
+}
+
+impl<A, const N: usize> GBounded for [A; N]
+where
+    A: GBounded,
+{
+    fn gmin() -> Self {
+        [(); N].map(|()| A::gmin())
+    }
+
+    fn gmax() -> Self {
+        [(); N].map(|()| A::gmax())
+    }
+}
+
+impl<T> GBounded for T
+where
+    T: Leaf + Bounded,
+{
+    fn gmin() -> Self {
+        T::min_value()
+    }
+
+    fn gmax() -> Self {
+        T::max_value()
+    }
+}
+
+/// Constructs the minimum value of `T`, from the minimum representation.
+///
+/// This fails if `T` rejects the minimum representation, e.g. `NonZeroU8`, which is represented by `u8`.
+/// Wrap such fields in `#[generic(opaque)]` to use their own `Bounded` impl instead.
+///
+/// ```rust
+/// use generics::ops::min_value;
+/// use std::num::NonZeroU8;
+///
+/// assert_eq!(min_value::<(i8, [bool; 2], Result<u8, char>)>(), Ok((-128, [false; 2], Ok(0))));
+/// assert!(min_value::<NonZeroU8>().is_err());
+/// ```
+pub fn min_value<T>() -> Result<T, ReprError>
+where
+    T: Generic,
+    T::Repr: GBounded,
+{
+    T::checked_from_repr(T::Repr::gmin())
+}
+
+/// Constructs the maximum value of `T`, from the maximum representation.
+///
+/// Like `min_value`, this fails if `T` rejects the maximum representation.
+///
+/// ```rust
+/// use generics::ops::max_value;
+///
+/// assert_eq!(max_value::<(i8, [bool; 2], Result<u8, char>)>(), Ok((127, [true; 2], Err(char::MAX))));
+/// ```
+pub fn max_value<T>() -> Result<T, ReprError>
+where
+    T: Generic,
+    T::Repr: GBounded,
+{
+    T::checked_from_repr(T::Repr::gmax())
+}
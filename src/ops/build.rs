@@ -0,0 +1,118 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+
+use crate::{Con, Meta, Prod, Singleton, TypeFn, Unit};
+
+/// The type-level function making a field optional, i.e. mapping `T` to `Option<T>`.
+///
+/// `#[derive(GenericBuilder)]` maps each field with this to define the fields of the builder.
+pub struct Partial;
+
+impl<T> TypeFn<T> for Partial {
+    type Output = Option<T>;
+}
+
+/// Completes a representation whose fields are each mapped by `Partial`, if every field is present.
+///
+/// `#[derive(GenericBuilder)]` uses this to implement `build` on a struct's builder, alongside `#[derive(Generic)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{GenericBuilder, MissingFields};
+/// use generics::Generic;
+///
+/// #[derive(Debug, PartialEq, Generic, GenericBuilder)]
+/// struct Foo {
+///     a: u8,
+///     b: String,
+///     c: bool,
+/// }
+///
+/// let foo = Foo::builder().a(1).b("b".to_string()).c(true).build();
+/// assert_eq!(foo, Ok(Foo { a: 1, b: "b".to_string(), c: true }));
+///
+/// let err = Foo::builder().b("b".to_string()).build().unwrap_err();
+/// assert_eq!(err.fields, ["a", "c"]);
+/// assert_eq!(err.to_string(), "missing fields of `Foo`: `a`, `c`");
+/// ```
+pub trait GBuild {
+    /// The representation with every field present.
+    type Output;
+
+    /// Unwraps every field, or appends the names of the missing fields to `missing`.
+    fn gbuild(self, missing: &mut Vec<&'static str>) -> Option<Self::Output>;
+}
+
+impl GBuild for Unit {
+    type Output = Unit;
+    fn gbuild(self, _missing: &mut Vec<&'static str>) -> Option<Self::Output> {
+        Some(Unit)
+    }
+}
+
+impl<A, B> GBuild for Prod<A, B>
+where
+    A: GBuild,
+    B: GBuild,
+{
+    type Output = Prod<A::Output, B::Output>;
+    fn gbuild(self, missing: &mut Vec<&'static str>) -> Option<Self::Output> {
+        let Prod(a, b) = self;
+        // Both sides are built, so every missing field is reported.
+        let a = a.gbuild(missing);
+        let b = b.gbuild(missing);
+        Some(Prod(a?, b?))
+    }
+}
+
+impl<T, M> GBuild for Meta<Option<T>, M>
+where
+    M: Singleton<T = &'static str>,
+{
+    type Output = Meta<T, M>;
+    fn gbuild(self, missing: &mut Vec<&'static str>) -> Option<Self::Output> {
+        let Meta(inner, _) = self;
+        if inner.is_none() {
+            missing.push(M::get());
+        }
+        Some(Meta(inner?, PhantomData))
+    }
+}
+
+impl<I, C> GBuild for Con<I, C>
+where
+    I: GBuild,
+    C: Singleton,
+{
+    type Output = Con<I::Output, C>;
+    fn gbuild(self, missing: &mut Vec<&'static str>) -> Option<Self::Output> {
+        let Con(inner, _) = self;
+        Some(Con(inner.gbuild(missing)?, PhantomData))
+    }
+}
+
+/// An error building a value whose builder is missing some fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingFields {
+    /// The name of the type being built.
+    pub name: &'static str,
+    /// The names of the missing fields, in declaration order.
+    pub fields: Vec<&'static str>,
+}
+
+impl Display for MissingFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing fields of `{}`: ", self.name)?;
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{}`", field)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for MissingFields {}
@@ -0,0 +1,373 @@
+use std::convert::TryInto;
+use std::marker::PhantomData;
+
+use crate::{Con, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+/// A compact byte format without metadata, shared by `GEncode` and `GEncodeWire`.
+///
+/// Products are their fields in order, with no names or lengths, and sums are the index of their variant,
+/// then its fields. Lists are their length, then their items, and arrays are just their items.
+/// Formats differ only in how they encode leaves, indexes, and lengths, where they write, and how they fail.
+pub trait Format {
+    /// Where encodings are written.
+    type Out: ?Sized;
+
+    /// An error writing to `Out`.
+    type EncodeError;
+
+    /// An error decoding.
+    type Error;
+
+    /// Writes the index of a variant.
+    fn put_index(index: u32, out: &mut Self::Out) -> Result<(), Self::EncodeError>;
+
+    /// Writes the length of a list.
+    fn put_len(len: usize, out: &mut Self::Out) -> Result<(), Self::EncodeError>;
+
+    /// Decodes the index of a variant.
+    fn get_index(input: &mut &[u8]) -> Result<u32, Self::Error>;
+
+    /// Decodes the length of a list.
+    fn get_len(input: &mut &[u8]) -> Result<usize, Self::Error>;
+
+    /// The error for input which ended before the value did.
+    fn eof() -> Self::Error;
+
+    /// The error for a variant index out of range for its sum.
+    fn variant(index: u32) -> Self::Error;
+
+    /// The error for an invalid encoding of the given type.
+    fn invalid(ty: &'static str) -> Self::Error;
+}
+
+/// A leaf which can be encoded in the format `F`.
+pub trait FormatLeaf<F: Format>: Sized {
+    /// Writes the encoding of `self` to `out`.
+    fn put_leaf(self, out: &mut F::Out) -> Result<(), F::EncodeError>;
+
+    /// Decodes a value from the start of `input`, advancing it past the bytes read.
+    fn get_leaf(input: &mut &[u8]) -> Result<Self, F::Error>;
+}
+
+/// Splits the first `len` bytes from `input`.
+pub fn take<'a, F>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], F::Error>
+where
+    F: Format,
+{
+    if input.len() < len {
+        return Err(F::eof());
+    }
+    let (bytes, rest) = input.split_at(len);
+    *input = rest;
+    Ok(bytes)
+}
+
+/// The most items a list of zero-sized items may have, since they may be encoded in no bytes at all.
+const MAX_ZERO_SIZED_ITEMS: usize = 1 << 16;
+
+/// Checks the length of a list of `A` before decoding its items, so a corrupt length fails fast,
+/// rather than exhausting memory or looping for a long time.
+///
+/// Items with a size are encoded in at least one byte, so there can't be more of them than bytes left.
+/// Zero-sized items, like `()`, may be encoded in no bytes, so their number is capped instead.
+fn check_len<F, A>(len: usize, input: &[u8]) -> Result<(), F::Error>
+where
+    F: Format,
+{
+    if std::mem::size_of::<A>() != 0 {
+        if len > input.len() {
+            return Err(F::eof());
+        }
+    } else if len > input.len().max(MAX_ZERO_SIZED_ITEMS) {
+        return Err(F::invalid("length"));
+    }
+    Ok(())
+}
+
+/// Encodes a representation in the format `F`, node by node.
+pub trait GEncodeBytes<F: Format> {
+    /// Writes the encoding of `self` to `out`.
+    fn gencode_bytes(self, out: &mut F::Out) -> Result<(), F::EncodeError>;
+}
+
+/// Encodes whichever variant of a `Sum` chain is held, after its index.
+pub trait GEncodeBytesVariant<F: Format> {
+    /// Writes the index of the held variant, offset by `index`, then its fields to `out`.
+    fn gencode_bytes_variant(self, index: u32, out: &mut F::Out) -> Result<(), F::EncodeError>;
+}
+
+/// Decodes a representation from the format `F`, node by node.
+pub trait GDecodeBytes<F: Format>: Sized {
+    /// Decodes a representation from the start of `input`, advancing it past the bytes read.
+    fn gdecode_bytes(input: &mut &[u8]) -> Result<Self, F::Error>;
+}
+
+/// Decodes a variant of a `Sum` chain by index.
+pub trait GDecodeBytesVariant<F: Format>: Sized {
+    /// The number of variants.
+    const VARIANTS: u32;
+
+    /// Decodes the fields of the variant at `index`, which must be less than `VARIANTS`.
+    fn gdecode_bytes_variant(index: u32, input: &mut &[u8]) -> Result<Self, F::Error>;
+}
+
+impl<F> GEncodeBytes<F> for Unit
+where
+    F: Format,
+{
+    fn gencode_bytes(self, _out: &mut F::Out) -> Result<(), F::EncodeError> {
+        Ok(())
+    }
+}
+
+impl<F, A, B> GEncodeBytes<F> for Prod<A, B>
+where
+    F: Format,
+    A: GEncodeBytes<F>,
+    B: GEncodeBytes<F>,
+{
+    fn gencode_bytes(self, out: &mut F::Out) -> Result<(), F::EncodeError> {
+        let Prod(a, b) = self;
+        a.gencode_bytes(out)?;
+        b.gencode_bytes(out)
+    }
+}
+
+impl<F, L, R> GEncodeBytes<F> for Sum<L, R>
+where
+    F: Format,
+    Self: GEncodeBytesVariant<F>,
+{
+    fn gencode_bytes(self, out: &mut F::Out) -> Result<(), F::EncodeError> {
+        self.gencode_bytes_variant(0, out)
+    }
+}
+
+impl<F, L, R> GEncodeBytesVariant<F> for Sum<L, R>
+where
+    F: Format,
+    L: GEncodeBytes<F>,
+    R: GEncodeBytesVariant<F>,
+{
+    fn gencode_bytes_variant(self, index: u32, out: &mut F::Out) -> Result<(), F::EncodeError> {
+        match self {
+            Sum::Left(l) => {
+                F::put_index(index, out)?;
+                l.gencode_bytes(out)
+            }
+            Sum::Right(r) => r.gencode_bytes_variant(index + 1, out),
+        }
+    }
+}
+
+impl<F> GEncodeBytesVariant<F> for Void
+where
+    F: Format,
+{
+    fn gencode_bytes_variant(self, _index: u32, _out: &mut F::Out) -> Result<(), F::EncodeError> {
+        match self {}
+    }
+}
+
+impl<F> GEncodeBytes<F> for Void
+where
+    F: Format,
+{
+    fn gencode_bytes(self, _out: &mut F::Out) -> Result<(), F::EncodeError> {
+        match self {}
+    }
+}
+
+impl<F, I, M> GEncodeBytes<F> for Meta<I, M>
+where
+    F: Format,
+    I: GEncodeBytes<F>,
+    M: Singleton,
+{
+    fn gencode_bytes(self, out: &mut F::Out) -> Result<(), F::EncodeError> {
+        let Meta(inner, _) = self;
+        inner.gencode_bytes(out)
+    }
+}
+
+impl<F, I, C> GEncodeBytes<F> for Con<I, C>
+where
+    F: Format,
+    I: GEncodeBytes<F>,
+    C: Singleton,
+{
+    fn gencode_bytes(self, out: &mut F::Out) -> Result<(), F::EncodeError> {
+        let Con(inner, _) = self;
+        inner.gencode_bytes(out)
+    }
+}
+
+impl<F, A> GEncodeBytes<F> for List<A>
+where
+    F: Format,
+    A: GEncodeBytes<F>,
+{
+    fn gencode_bytes(self, out: &mut F::Out) -> Result<(), F::EncodeError> {
+        let List(items) = self;
+        F::put_len(items.len(), out)?;
+        items
+            .into_iter()
+            .try_for_each(|item| item.gencode_bytes(out))
+    }
+}
+
+impl<F, A, const N: usize> GEncodeBytes<F> for [A; N]
+where
+    F: Format,
+    A: GEncodeBytes<F>,
+{
+    fn gencode_bytes(self, out: &mut F::Out) -> Result<(), F::EncodeError> {
+        IntoIterator::into_iter(self).try_for_each(|item| item.gencode_bytes(out))
+    }
+}
+
+impl<F, T> GEncodeBytes<F> for T
+where
+    F: Format,
+    T: Leaf + FormatLeaf<F>,
+{
+    fn gencode_bytes(self, out: &mut F::Out) -> Result<(), F::EncodeError> {
+        self.put_leaf(out)
+    }
+}
+
+impl<F> GDecodeBytes<F> for Unit
+where
+    F: Format,
+{
+    fn gdecode_bytes(_input: &mut &[u8]) -> Result<Self, F::Error> {
+        Ok(Unit)
+    }
+}
+
+impl<F, A, B> GDecodeBytes<F> for Prod<A, B>
+where
+    F: Format,
+    A: GDecodeBytes<F>,
+    B: GDecodeBytes<F>,
+{
+    fn gdecode_bytes(input: &mut &[u8]) -> Result<Self, F::Error> {
+        let a = A::gdecode_bytes(input)?;
+        Ok(Prod(a, B::gdecode_bytes(input)?))
+    }
+}
+
+impl<F, L, R> GDecodeBytes<F> for Sum<L, R>
+where
+    F: Format,
+    Self: GDecodeBytesVariant<F>,
+{
+    fn gdecode_bytes(input: &mut &[u8]) -> Result<Self, F::Error> {
+        let index = F::get_index(input)?;
+        if index >= Self::VARIANTS {
+            return Err(F::variant(index));
+        }
+        Self::gdecode_bytes_variant(index, input)
+    }
+}
+
+impl<F, L, R> GDecodeBytesVariant<F> for Sum<L, R>
+where
+    F: Format,
+    L: GDecodeBytes<F>,
+    R: GDecodeBytesVariant<F>,
+{
+    const VARIANTS: u32 = 1 + R::VARIANTS;
+
+    fn gdecode_bytes_variant(index: u32, input: &mut &[u8]) -> Result<Self, F::Error> {
+        match index {
+            0 => L::gdecode_bytes(input).map(Sum::Left),
+            _ => R::gdecode_bytes_variant(index - 1, input).map(Sum::Right),
+        }
+    }
+}
+
+impl<F> GDecodeBytesVariant<F> for Void
+where
+    F: Format,
+{
+    const VARIANTS: u32 = 0;
+
+    fn gdecode_bytes_variant(_index: u32, _input: &mut &[u8]) -> Result<Self, F::Error> {
+        unreachable!("the index is less than `VARIANTS`")
+    }
+}
+
+/// Fails, since `Void` has no values.
+impl<F> GDecodeBytes<F> for Void
+where
+    F: Format,
+{
+    fn gdecode_bytes(_input: &mut &[u8]) -> Result<Self, F::Error> {
+        Err(F::invalid("empty enum"))
+    }
+}
+
+impl<F, I, M> GDecodeBytes<F> for Meta<I, M>
+where
+    F: Format,
+    I: GDecodeBytes<F>,
+    M: Singleton,
+{
+    fn gdecode_bytes(input: &mut &[u8]) -> Result<Self, F::Error> {
+        I::gdecode_bytes(input).map(|inner| Meta(inner, PhantomData))
+    }
+}
+
+impl<F, I, C> GDecodeBytes<F> for Con<I, C>
+where
+    F: Format,
+    I: GDecodeBytes<F>,
+    C: Singleton,
+{
+    fn gdecode_bytes(input: &mut &[u8]) -> Result<Self, F::Error> {
+        I::gdecode_bytes(input).map(|inner| Con(inner, PhantomData))
+    }
+}
+
+impl<F, A> GDecodeBytes<F> for List<A>
+where
+    F: Format,
+    A: GDecodeBytes<F>,
+{
+    fn gdecode_bytes(input: &mut &[u8]) -> Result<Self, F::Error> {
+        let len = F::get_len(input)?;
+        check_len::<F, A>(len, input)?;
+        let mut items = Vec::with_capacity(len);
+        for _ in 0..len {
+            items.push(A::gdecode_bytes(input)?);
+        }
+        Ok(List(items))
+    }
+}
+
+impl<F, A, const N: usize> GDecodeBytes<F> for [A; N]
+where
+    F: Format,
+    A: GDecodeBytes<F>,
+{
+    fn gdecode_bytes(input: &mut &[u8]) -> Result<Self, F::Error> {
+        let mut items = Vec::with_capacity(N);
+        for _ in 0..N {
+            items.push(A::gdecode_bytes(input)?);
+        }
+        Ok(items
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("decoded `N` items")))
+    }
+}
+
+impl<F, T> GDecodeBytes<F> for T
+where
+    F: Format,
+    T: Leaf + FormatLeaf<F>,
+{
+    fn gdecode_bytes(input: &mut &[u8]) -> Result<Self, F::Error> {
+        T::get_leaf(input)
+    }
+}
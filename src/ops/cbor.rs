@@ -0,0 +1,707 @@
+use std::convert::{Infallible, TryFrom, TryInto};
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use crate::{Generic, Opaque, ReprError};
+
+use super::tree::{FromTreeLeaf, GFromTree, GToTree, ToTreeLeaf, Tree};
+
+/// A CBOR data item, as read and written by `decode_cbor` and `encode_cbor`.
+///
+/// Only definite-length items are supported. Floats are written in the shortest of single or double precision
+/// which holds their value exactly, and half precision floats are read as well.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cbor {
+    /// `null`, or `undefined` when read.
+    Null,
+    /// `true` or `false`.
+    Bool(bool),
+    /// An unsigned integer.
+    Unsigned(u64),
+    /// A negative integer, `-1 - n`.
+    Negative(u64),
+    /// A float.
+    Float(f64),
+    /// A byte string.
+    Bytes(Vec<u8>),
+    /// A text string.
+    Text(String),
+    /// An array.
+    Array(Vec<Cbor>),
+    /// A map, as pairs of keys and values.
+    Map(Vec<(Cbor, Cbor)>),
+    /// A tagged item, e.g. tag 2 for a bignum.
+    Tag(u64, Box<Cbor>),
+}
+
+impl Cbor {
+    /// Appends the encoding of `self` to `out`.
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            Cbor::Null => out.push(0xf6),
+            Cbor::Bool(b) => out.push(if *b { 0xf5 } else { 0xf4 }),
+            Cbor::Unsigned(n) => head(out, 0, *n),
+            Cbor::Negative(n) => head(out, 1, *n),
+            Cbor::Float(f) => {
+                let single = *f as f32;
+                if f64::from(single) == *f || f.is_nan() {
+                    out.push(0xfa);
+                    out.extend_from_slice(&single.to_be_bytes());
+                } else {
+                    out.push(0xfb);
+                    out.extend_from_slice(&f.to_be_bytes());
+                }
+            }
+            Cbor::Bytes(bytes) => {
+                head(out, 2, bytes.len() as u64);
+                out.extend_from_slice(bytes);
+            }
+            Cbor::Text(s) => {
+                head(out, 3, s.len() as u64);
+                out.extend_from_slice(s.as_bytes());
+            }
+            Cbor::Array(items) => {
+                head(out, 4, items.len() as u64);
+                for item in items {
+                    item.encode(out);
+                }
+            }
+            Cbor::Map(pairs) => {
+                head(out, 5, pairs.len() as u64);
+                for (key, value) in pairs {
+                    key.encode(out);
+                    value.encode(out);
+                }
+            }
+            Cbor::Tag(tag, item) => {
+                head(out, 6, *tag);
+                item.encode(out);
+            }
+        }
+    }
+
+    /// Decodes a single item, which must span all of `bytes`.
+    ///
+    /// Arrays, maps, and tags may be nested at most 128 deep.
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeCborError> {
+        let mut parser = Parser {
+            bytes,
+            pos: 0,
+            depth: 0,
+        };
+        let cbor = parser.item()?;
+        if parser.pos < bytes.len() {
+            return Err(DecodeCborError::Syntax(parser.pos));
+        }
+        Ok(cbor)
+    }
+}
+
+/// Writes the initial bytes of an item with major type `major` and argument `n`, in the shortest form.
+fn head(out: &mut Vec<u8>, major: u8, n: u64) {
+    let major = major << 5;
+    if n < 24 {
+        out.push(major | n as u8);
+    } else if let Ok(n) = u8::try_from(n) {
+        out.extend_from_slice(&[major | 24, n]);
+    } else if let Ok(n) = u16::try_from(n) {
+        out.push(major | 25);
+        out.extend_from_slice(&n.to_be_bytes());
+    } else if let Ok(n) = u32::try_from(n) {
+        out.push(major | 26);
+        out.extend_from_slice(&n.to_be_bytes());
+    } else {
+        out.push(major | 27);
+        out.extend_from_slice(&n.to_be_bytes());
+    }
+}
+
+/// The deepest arrays, maps, and tags may be nested, so malicious input can't overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+/// A recursive descent parser over bytes.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    /// The number of arrays, maps, and tags the parser is inside.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error<T>(&self) -> Result<T, DecodeCborError> {
+        Err(DecodeCborError::Syntax(self.pos))
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeCborError> {
+        match self.bytes.get(self.pos..).and_then(|rest| rest.get(..len)) {
+            Some(bytes) => {
+                self.pos += len;
+                Ok(bytes)
+            }
+            None => Err(DecodeCborError::Syntax(self.bytes.len())),
+        }
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], DecodeCborError> {
+        let bytes = self.take(N)?;
+        Ok(bytes.try_into().expect("took `N` bytes"))
+    }
+
+    /// Reads the argument of an item, given the low 5 bits of its initial byte.
+    fn argument(&mut self, info: u8) -> Result<u64, DecodeCborError> {
+        match info {
+            0..=23 => Ok(u64::from(info)),
+            24 => Ok(u64::from(self.take(1)?[0])),
+            25 => Ok(u64::from(u16::from_be_bytes(self.take_array()?))),
+            26 => Ok(u64::from(u32::from_be_bytes(self.take_array()?))),
+            27 => Ok(u64::from_be_bytes(self.take_array()?)),
+            _ => self.error(),
+        }
+    }
+
+    /// Reads the length of an item, just after its initial byte, which can't be longer than the rest of the input,
+    /// since each element takes at least a byte.
+    fn len(&mut self, info: u8) -> Result<usize, DecodeCborError> {
+        let start = self.pos - 1;
+        let len = self.argument(info)?;
+        match usize::try_from(len) {
+            Ok(len) if len <= self.bytes.len() - self.pos => Ok(len),
+            _ => Err(DecodeCborError::Syntax(start)),
+        }
+    }
+
+    fn item(&mut self) -> Result<Cbor, DecodeCborError> {
+        let start = self.pos;
+        let initial = self.take(1)?[0];
+        let info = initial & 0x1f;
+        if let 4..=6 = initial >> 5 {
+            if self.depth == MAX_DEPTH {
+                return Err(DecodeCborError::Depth(start));
+            }
+            self.depth += 1;
+            let item = self.nested(initial >> 5, info);
+            self.depth -= 1;
+            return item;
+        }
+        match initial >> 5 {
+            0 => self.argument(info).map(Cbor::Unsigned),
+            1 => self.argument(info).map(Cbor::Negative),
+            2 => {
+                let len = self.len(info)?;
+                Ok(Cbor::Bytes(self.take(len)?.to_vec()))
+            }
+            3 => {
+                let len = self.len(info)?;
+                let bytes = self.take(len)?;
+                match std::str::from_utf8(bytes) {
+                    Ok(s) => Ok(Cbor::Text(s.to_string())),
+                    Err(_) => Err(DecodeCborError::Syntax(start)),
+                }
+            }
+            _ => match info {
+                20 => Ok(Cbor::Bool(false)),
+                21 => Ok(Cbor::Bool(true)),
+                22 | 23 => Ok(Cbor::Null),
+                25 => Ok(Cbor::Float(half_to_f64(u16::from_be_bytes(
+                    self.take_array()?,
+                )))),
+                26 => Ok(Cbor::Float(f64::from(f32::from_be_bytes(
+                    self.take_array()?,
+                )))),
+                27 => Ok(Cbor::Float(f64::from_be_bytes(self.take_array()?))),
+                _ => Err(DecodeCborError::Syntax(start)),
+            },
+        }
+    }
+
+    /// Reads an array, map, or tag, given its major type and the low 5 bits of its initial byte.
+    fn nested(&mut self, major: u8, info: u8) -> Result<Cbor, DecodeCborError> {
+        match major {
+            4 => {
+                let len = self.len(info)?;
+                let items = (0..len).map(|_| self.item()).collect::<Result<_, _>>()?;
+                Ok(Cbor::Array(items))
+            }
+            5 => {
+                let len = self.len(info)?;
+                let pairs = (0..len)
+                    .map(|_| Ok((self.item()?, self.item()?)))
+                    .collect::<Result<_, _>>()?;
+                Ok(Cbor::Map(pairs))
+            }
+            _ => {
+                let tag = self.argument(info)?;
+                Ok(Cbor::Tag(tag, Box::new(self.item()?)))
+            }
+        }
+    }
+}
+
+/// Converts a half precision float to an `f64`.
+fn half_to_f64(half: u16) -> f64 {
+    let sign = if half & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exp = i32::from((half >> 10) & 0x1f);
+    let mant = f64::from(half & 0x3ff);
+    sign * match exp {
+        0 => mant * 2f64.powi(-24),
+        31 if mant == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        _ => (mant + 1024.0) * 2f64.powi(exp - 25),
+    }
+}
+
+/// A leaf which can be converted to and from CBOR.
+///
+/// Implement this for your own leaf types to encode and decode them with `GToCbor` and `GFromCbor`.
+pub trait CborLeaf: Sized {
+    /// A description of the CBOR this is decoded from, for errors, e.g. `"a u8"`.
+    const EXPECTED: &'static str;
+
+    /// Converts `self` to CBOR.
+    fn to_cbor(self) -> Cbor;
+
+    /// Converts `cbor` to `Self`, or returns `None` if it's invalid.
+    fn from_cbor(cbor: Cbor) -> Option<Self>;
+}
+
+/// Splits an integer into whether it's negative, and its argument, i.e. `n` or `-1 - n`.
+/// Bignums, i.e. tags 2 and 3, are supported up to 128 bits.
+fn integer(cbor: Cbor) -> Option<(bool, u128)> {
+    match cbor {
+        Cbor::Unsigned(n) => Some((false, u128::from(n))),
+        Cbor::Negative(n) => Some((true, u128::from(n))),
+        Cbor::Tag(tag @ (2 | 3), item) => match *item {
+            Cbor::Bytes(bytes) => {
+                let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+                if bytes.len() - start > 16 {
+                    return None;
+                }
+                let n = bytes[start..]
+                    .iter()
+                    .fold(0u128, |n, &b| (n << 8) | u128::from(b));
+                Some((tag == 3, n))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Converts an integer's sign and argument to CBOR, as a bignum if it doesn't fit in 64 bits.
+fn from_integer(negative: bool, n: u128) -> Cbor {
+    match u64::try_from(n) {
+        Ok(n) if negative => Cbor::Negative(n),
+        Ok(n) => Cbor::Unsigned(n),
+        Err(_) => {
+            let bytes = n.to_be_bytes();
+            let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+            let tag = if negative { 3 } else { 2 };
+            Cbor::Tag(tag, Box::new(Cbor::Bytes(bytes[start..].to_vec())))
+        }
+    }
+}
+
+macro_rules! impl_cbor_leaf_unsigned {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            impl CborLeaf for $ty {
+                const EXPECTED: &'static str = concat!("a ", stringify!($ty));
+
+                fn to_cbor(self) -> Cbor {
+                    from_integer(false, self as u128)
+                }
+
+                fn from_cbor(cbor: Cbor) -> Option<Self> {
+                    match integer(cbor)? {
+                        (false, n) => $ty::try_from(n).ok(),
+                        (true, _) => None,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+impl_cbor_leaf_unsigned!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_cbor_leaf_signed {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            impl CborLeaf for $ty {
+                const EXPECTED: &'static str = concat!("an ", stringify!($ty));
+
+                fn to_cbor(self) -> Cbor {
+                    let n = self as i128;
+                    if n < 0 {
+                        from_integer(true, !n as u128)
+                    } else {
+                        from_integer(false, n as u128)
+                    }
+                }
+
+                fn from_cbor(cbor: Cbor) -> Option<Self> {
+                    match integer(cbor)? {
+                        (false, n) => $ty::try_from(n).ok(),
+                        (true, n) => $ty::try_from(!i128::try_from(n).ok()?).ok(),
+                    }
+                }
+            }
+        )+
+    }
+}
+
+impl_cbor_leaf_signed!(i8, i16, i32, i64, i128, isize);
+
+impl CborLeaf for f32 {
+    const EXPECTED: &'static str = "an f32";
+
+    fn to_cbor(self) -> Cbor {
+        Cbor::Float(f64::from(self))
+    }
+
+    fn from_cbor(cbor: Cbor) -> Option<Self> {
+        match cbor {
+            Cbor::Float(f) => Some(f as f32),
+            _ => None,
+        }
+    }
+}
+
+impl CborLeaf for f64 {
+    const EXPECTED: &'static str = "an f64";
+
+    fn to_cbor(self) -> Cbor {
+        Cbor::Float(self)
+    }
+
+    fn from_cbor(cbor: Cbor) -> Option<Self> {
+        match cbor {
+            Cbor::Float(f) => Some(f),
+            _ => None,
+        }
+    }
+}
+
+impl CborLeaf for bool {
+    const EXPECTED: &'static str = "a boolean";
+
+    fn to_cbor(self) -> Cbor {
+        Cbor::Bool(self)
+    }
+
+    fn from_cbor(cbor: Cbor) -> Option<Self> {
+        match cbor {
+            Cbor::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+}
+
+impl CborLeaf for char {
+    const EXPECTED: &'static str = "a text string of one character";
+
+    fn to_cbor(self) -> Cbor {
+        Cbor::Text(self.to_string())
+    }
+
+    fn from_cbor(cbor: Cbor) -> Option<Self> {
+        match cbor {
+            Cbor::Text(s) => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Some(c),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl CborLeaf for String {
+    const EXPECTED: &'static str = "a text string";
+
+    fn to_cbor(self) -> Cbor {
+        Cbor::Text(self)
+    }
+
+    fn from_cbor(cbor: Cbor) -> Option<Self> {
+        match cbor {
+            Cbor::Text(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl<T> CborLeaf for Opaque<T>
+where
+    T: CborLeaf,
+{
+    const EXPECTED: &'static str = T::EXPECTED;
+
+    fn to_cbor(self) -> Cbor {
+        self.0.to_cbor()
+    }
+
+    fn from_cbor(cbor: Cbor) -> Option<Self> {
+        T::from_cbor(cbor).map(Opaque)
+    }
+}
+
+/// Converts a representation to CBOR, using its metadata.
+///
+/// This follows the layout of serde's derive: structs are maps keyed by the text of their field names,
+/// tuple structs are arrays, or their only field if they have one, and unit structs are `null`.
+/// Unit variants are the text of their name, and others are maps with their name as the only key.
+/// `Option` is `null` or its value, and tuples, arrays, and lists are arrays. Leaves are converted with `CborLeaf`:
+/// integers wider than 64 bits are bignums, i.e. tags 2 and 3, when they don't fit in an ordinary integer.
+///
+/// `encode_cbor` and `decode_cbor` use this and `GFromCbor` to encode and decode `Generic` types as CBOR,
+/// e.g. as the payloads of constrained devices, or of COSE messages. Only the `cbor` feature is required,
+/// which has no dependencies.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{decode_cbor, encode_cbor, Cbor, DecodeCborError};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Sample {
+///     id: u8,
+///     temp: Option<f32>,
+///     unit: Unit,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum Unit {
+///     Celsius,
+///     Custom(String),
+/// }
+///
+/// let bytes = encode_cbor(Sample { id: 1, temp: None, unit: Unit::Celsius });
+/// assert_eq!(
+///     bytes,
+///     [
+///         0xa3, // map of 3 pairs
+///         0x62, b'i', b'd', 0x01,
+///         0x64, b't', b'e', b'm', b'p', 0xf6,
+///         0x64, b'u', b'n', b'i', b't', 0x67, b'C', b'e', b'l', b's', b'i', b'u', b's',
+///     ]
+/// );
+/// assert_eq!(decode_cbor::<Sample>(&bytes), Ok(Sample { id: 1, temp: None, unit: Unit::Celsius }));
+///
+/// let mut bytes = Vec::new();
+/// Cbor::Map(vec![(Cbor::Text("id".to_string()), Cbor::Negative(0))]).encode(&mut bytes);
+/// let err = decode_cbor::<Sample>(&bytes).unwrap_err();
+/// assert_eq!(err, DecodeCborError::Invalid { path: "id".to_string(), expected: "a u8".to_string() });
+/// ```
+pub trait GToCbor {
+    /// Converts `self` to CBOR.
+    fn gto_cbor(self) -> Cbor;
+}
+
+/// Converts CBOR to a representation, using its metadata, without serde.
+///
+/// This is the inverse of `GToCbor`. Like serde's derive, structs can also be decoded from arrays,
+/// unknown fields are ignored, and missing fields are an error, unless they can be decoded from `null`, e.g. `Option`s.
+pub trait GFromCbor: Sized {
+    /// Converts `cbor`, found at `path`, to `Self`.
+    fn gfrom_cbor(cbor: Cbor, path: &mut String) -> Result<Self, DecodeCborError>;
+}
+
+/// An error decoding a representation from CBOR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeCborError {
+    /// The bytes weren't valid CBOR, at the given offset.
+    Syntax(usize),
+    /// Arrays, maps, and tags were nested more than 128 deep, at the given offset.
+    Depth(usize),
+    /// There was no value for the field with the given path.
+    Missing(String),
+    /// The value had the wrong type or length, or a leaf was invalid.
+    Invalid {
+        /// The path of the value.
+        path: String,
+        /// A description of the expected value, e.g. `"an array of length 2"`.
+        expected: String,
+    },
+    /// The name of a variant matched none of the variants of its sum.
+    Variant {
+        /// The path of the sum.
+        path: String,
+        /// The unknown variant name.
+        name: String,
+    },
+    /// The value was invalid for its type, e.g. a `NonZeroU8` of zero.
+    Repr(ReprError),
+}
+
+impl Display for DecodeCborError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeCborError::Syntax(pos) => write!(f, "invalid CBOR at byte {}", pos),
+            DecodeCborError::Depth(pos) => write!(f, "CBOR nested too deeply at byte {}", pos),
+            DecodeCborError::Missing(path) => write!(f, "missing value for `{}`", path),
+            DecodeCborError::Invalid { path, expected } => {
+                write!(f, "expected {} for `{}`", expected, path)
+            }
+            DecodeCborError::Variant { path, name } => {
+                write!(f, "unknown variant `{}` for `{}`", name, path)
+            }
+            DecodeCborError::Repr(error) => write!(f, "invalid value: {}", error),
+        }
+    }
+}
+
+impl Error for DecodeCborError {}
+
+impl Tree for Cbor {
+    type Object = Vec<(Cbor, Cbor)>;
+    type EncodeError = Infallible;
+    type Error = DecodeCborError;
+
+    const STRING: &'static str = "a text string";
+    const OBJECT: &'static str = "a map";
+
+    fn null() -> Self {
+        Cbor::Null
+    }
+
+    fn string(s: &str) -> Self {
+        Cbor::Text(s.to_string())
+    }
+
+    fn array(items: Vec<Self>) -> Self {
+        Cbor::Array(items)
+    }
+
+    fn object(fields: Vec<(&'static str, Self)>) -> Self {
+        Cbor::Map(
+            fields
+                .into_iter()
+                .map(|(name, cbor)| (Cbor::Text(name.to_string()), cbor))
+                .collect(),
+        )
+    }
+
+    fn is_null(&self) -> bool {
+        *self == Cbor::Null
+    }
+
+    fn into_array(self) -> Result<Vec<Self>, Self> {
+        match self {
+            Cbor::Array(items) => Ok(items),
+            cbor => Err(cbor),
+        }
+    }
+
+    fn into_string(self) -> Result<String, Self> {
+        match self {
+            Cbor::Text(s) => Ok(s),
+            cbor => Err(cbor),
+        }
+    }
+
+    fn into_object(self) -> Result<Vec<(Cbor, Cbor)>, Self> {
+        match self {
+            Cbor::Map(pairs) => Ok(pairs),
+            cbor => Err(cbor),
+        }
+    }
+
+    fn remove(pairs: &mut Vec<(Cbor, Cbor)>, name: &str) -> Option<Self> {
+        let i = pairs
+            .iter()
+            .position(|(key, _)| matches!(key, Cbor::Text(key) if key == name))?;
+        Some(pairs.swap_remove(i).1)
+    }
+
+    fn into_entry(pairs: Vec<(Cbor, Cbor)>) -> Option<(String, Self)> {
+        match <[_; 1]>::try_from(pairs) {
+            Ok([(Cbor::Text(name), cbor)]) => Some((name, cbor)),
+            _ => None,
+        }
+    }
+
+    fn missing(path: &str) -> DecodeCborError {
+        DecodeCborError::Missing(path.to_string())
+    }
+
+    fn invalid(path: &str, expected: &dyn Display) -> DecodeCborError {
+        DecodeCborError::Invalid {
+            path: path.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    fn variant(path: &str, name: String) -> DecodeCborError {
+        DecodeCborError::Variant {
+            path: path.to_string(),
+            name,
+        }
+    }
+}
+
+impl<T> ToTreeLeaf<Cbor> for T
+where
+    T: CborLeaf,
+{
+    fn to_tree(self) -> Result<Cbor, Infallible> {
+        Ok(self.to_cbor())
+    }
+}
+
+impl<T> FromTreeLeaf<Cbor> for T
+where
+    T: CborLeaf,
+{
+    fn from_tree(cbor: Cbor, path: &str) -> Result<Self, DecodeCborError> {
+        T::from_cbor(cbor).ok_or_else(|| Cbor::invalid(path, &T::EXPECTED))
+    }
+}
+
+impl<T> GToCbor for T
+where
+    T: GToTree<Cbor>,
+{
+    fn gto_cbor(self) -> Cbor {
+        match self.gto_tree() {
+            Ok(cbor) => cbor,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl<T> GFromCbor for T
+where
+    T: GFromTree<Cbor>,
+{
+    fn gfrom_cbor(cbor: Cbor, path: &mut String) -> Result<Self, DecodeCborError> {
+        T::gfrom_tree(cbor, path)
+    }
+}
+
+/// Encodes `x` as CBOR, as `serialize_generic` with a CBOR serializer would.
+pub fn encode_cbor<T>(x: T) -> Vec<u8>
+where
+    T: Generic,
+    T::Repr: GToCbor,
+{
+    let mut out = Vec::new();
+    x.into_repr().gto_cbor().encode(&mut out);
+    out
+}
+
+/// Decodes a `T` from CBOR, which must span all of `bytes`.
+///
+/// Errors name the path of the value which failed to decode, as produced by `flatten`.
+pub fn decode_cbor<T>(bytes: &[u8]) -> Result<T, DecodeCborError>
+where
+    T: Generic,
+    T::Repr: GFromCbor,
+{
+    let cbor = Cbor::decode(bytes)?;
+    let repr = T::Repr::gfrom_cbor(cbor, &mut String::new())?;
+    T::checked_from_repr(repr).map_err(DecodeCborError::Repr)
+}
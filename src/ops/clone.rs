@@ -0,0 +1,151 @@
+use std::marker::PhantomData;
+
+use crate::{Con, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+/// Clones a representation node by node.
+///
+/// Leaves are cloned with their own `Clone` impl, and `Borrowed` leaves are cloned into the value they borrow,
+/// so a representation of borrowed fields can be cloned into a representation of owned fields.
+///
+/// `#[derive(GenericClone)]` uses this to implement `Clone`, alongside `#[derive(Generic)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::GenericClone;
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericClone, Debug, PartialEq)]
+/// enum Foo {
+///     A { a: u8, b: String },
+///     B(Vec<u8>),
+/// }
+///
+/// let foo = Foo::A { a: 1, b: "x".to_string() };
+/// assert_eq!(foo.clone(), foo);
+/// ```
+pub trait GClone {
+    /// The type of the clone.
+    type Owned;
+
+    /// Clones `self`.
+    fn gclone(&self) -> Self::Owned;
+}
+
+/// A leaf borrowing a value, which `GClone` clones into the value itself.
+pub struct Borrowed<'a, T>(pub &'a T);
+
+impl GClone for Unit {
+    type Owned = Unit;
+
+    fn gclone(&self) -> Self::Owned {
+        Unit
+    }
+}
+
+impl<A, B> GClone for Prod<A, B>
+where
+    A: GClone,
+    B: GClone,
+{
+    type Owned = Prod<A::Owned, B::Owned>;
+
+    fn gclone(&self) -> Self::Owned {
+        let Prod(a, b) = self;
+        Prod(a.gclone(), b.gclone())
+    }
+}
+
+impl<L, R> GClone for Sum<L, R>
+where
+    L: GClone,
+    R: GClone,
+{
+    type Owned = Sum<L::Owned, R::Owned>;
+
+    fn gclone(&self) -> Self::Owned {
+        match self {
+            Sum::Left(l) => Sum::Left(l.gclone()),
+            Sum::Right(r) => Sum::Right(r.gclone()),
+        }
+    }
+}
+
+impl GClone for Void {
+    type Owned = Void;
+
+    fn gclone(&self) -> Self::Owned {
+        match *self {}
+    }
+}
+
+impl<I, M> GClone for Meta<I, M>
+where
+    I: GClone,
+    M: Singleton,
+{
+    type Owned = Meta<I::Owned, M>;
+
+    fn gclone(&self) -> Self::Owned {
+        let Meta(inner, _) = self;
+        Meta(inner.gclone(), PhantomData)
+    }
+}
+
+impl<I, C> GClone for Con<I, C>
+where
+    I: GClone,
+    C: Singleton,
+{
+    type Owned = Con<I::Owned, C>;
+
+    fn gclone(&self) -> Self::Owned {
+        let Con(inner, _) = self;
+        Con(inner.gclone(), PhantomData)
+    }
+}
+
+impl<A> GClone for List<A>
+where
+    A: GClone,
+{
+    type Owned = List<A::Owned>;
+
+    fn gclone(&self) -> Self::Owned {
+        let List(items) = self;
+        List(items.iter().map(GClone::gclone).collect())
+    }
+}
+
+impl<A, const N: usize> GClone for [A; N]
+where
+    A: GClone,
+{
+    type Owned = [A::Owned; N];
+
+    fn gclone(&self) -> Self::Owned {
+        self.each_ref().map(GClone::gclone)
+    }
+}
+
+impl<T> GClone for Borrowed<'_, T>
+where
+    T: Clone,
+{
+    type Owned = T;
+
+    fn gclone(&self) -> Self::Owned {
+        self.0.clone()
+    }
+}
+
+impl<T> GClone for T
+where
+    T: Leaf + Clone,
+{
+    type Owned = T;
+
+    fn gclone(&self) -> Self::Owned {
+        self.clone()
+    }
+}
@@ -0,0 +1,147 @@
+use std::any::Any;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+/// Collects every leaf of type `T` in a representation, in order.
+///
+/// Leaves are matched by their exact type via `Any`, so only leaf types can be collected, e.g. `String` or `u32`,
+/// but not types which are represented structurally, e.g. a `Vec` or a type deriving `Generic`.
+/// Leaves within an `Opaque` aren't collected, unless `T` is the `Opaque` itself.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::collect;
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct User {
+///     id: u32,
+///     name: String,
+///     friends: Vec<Friend>,
+/// }
+///
+/// #[derive(Generic)]
+/// struct Friend {
+///     id: u32,
+///     nickname: Option<String>,
+/// }
+///
+/// let user = User {
+///     id: 1,
+///     name: "a".to_string(),
+///     friends: vec![
+///         Friend { id: 2, nickname: None },
+///         Friend { id: 3, nickname: Some("c".to_string()) },
+///     ],
+/// };
+/// assert_eq!(collect::<u32, _>(user), [1, 2, 3]);
+/// ```
+pub trait GCollect<T> {
+    /// Appends every leaf of type `T` in `self` to `out`.
+    fn gcollect(self, out: &mut Vec<T>);
+}
+
+impl<T> GCollect<T> for Unit {
+    fn gcollect(self, _out: &mut Vec<T>) {}
+}
+
+impl<T, A, B> GCollect<T> for Prod<A, B>
+where
+    A: GCollect<T>,
+    B: GCollect<T>,
+{
+    fn gcollect(self, out: &mut Vec<T>) {
+        let Prod(a, b) = self;
+        a.gcollect(out);
+        b.gcollect(out);
+    }
+}
+
+impl<T, L, R> GCollect<T> for Sum<L, R>
+where
+    L: GCollect<T>,
+    R: GCollect<T>,
+{
+    fn gcollect(self, out: &mut Vec<T>) {
+        match self {
+            Sum::Left(l) => l.gcollect(out),
+            Sum::Right(r) => r.gcollect(out),
+        }
+    }
+}
+
+impl<T> GCollect<T> for Void {
+    fn gcollect(self, _out: &mut Vec<T>) {
+        match self {}
+    }
+}
+
+impl<T, I, M> GCollect<T> for Meta<I, M>
+where
+    I: GCollect<T>,
+    M: Singleton,
+{
+    fn gcollect(self, out: &mut Vec<T>) {
+        let Meta(inner, _) = self;
+        inner.gcollect(out);
+    }
+}
+
+impl<T, I, C> GCollect<T> for Con<I, C>
+where
+    I: GCollect<T>,
+    C: Singleton,
+{
+    fn gcollect(self, out: &mut Vec<T>) {
+        let Con(inner, _) = self;
+        inner.gcollect(out);
+    }
+}
+
+impl<T, A> GCollect<T> for List<A>
+where
+    A: GCollect<T>,
+{
+    fn gcollect(self, out: &mut Vec<T>) {
+        let List(items) = self;
+        for item in items {
+            item.gcollect(out);
+        }
+    }
+}
+
+impl<T, A, const N: usize> GCollect<T> for [A; N]
+where
+    A: GCollect<T>,
+{
+    fn gcollect(self, out: &mut Vec<T>) {
+        for item in IntoIterator::into_iter(self) {
+            item.gcollect(out);
+        }
+    }
+}
+
+impl<T, L> GCollect<T> for L
+where
+    T: 'static,
+    L: Leaf + 'static,
+{
+    fn gcollect(self, out: &mut Vec<T>) {
+        let mut leaf = Some(self);
+        if let Some(leaf) = (&mut leaf as &mut dyn Any).downcast_mut::<Option<T>>() {
+            out.extend(leaf.take());
+        }
+    }
+}
+
+/// Collects every leaf of type `T` in the representation of `x`, in order.
+pub fn collect<T, X>(x: X) -> Vec<T>
+where
+    X: Generic,
+    X::Repr: GCollect<T>,
+{
+    let mut out = Vec::new();
+    x.into_repr().gcollect(&mut out);
+    out
+}
@@ -0,0 +1,131 @@
+use std::marker::PhantomData;
+
+use crate::{Con, Meta, Prod, Singleton, TypeFn, Unit};
+
+/// The type-level function making a field a column, i.e. mapping `T` to `Vec<T>`.
+///
+/// `#[derive(GenericColumns)]` maps each field with this to define the fields of the struct's columnar companion.
+pub struct Columns;
+
+impl<T> TypeFn<T> for Columns {
+    type Output = Vec<T>;
+}
+
+/// Pushes and pops rows of a representation whose fields are each mapped by `Columns`.
+///
+/// Every column holds the same number of values, so that the `i`th row is made up of the `i`th value of each column.
+///
+/// `#[derive(GenericColumns)]` uses this to implement `push` and `pop` on a struct's columnar companion,
+/// e.g. `FooColumns` for `Foo`, alongside `#[derive(Generic)]`. The companion has a public `Vec` for each field,
+/// and converts to and from `Vec<Foo>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::GenericColumns;
+/// use generics::Generic;
+///
+/// #[derive(Debug, Clone, PartialEq, Generic, GenericColumns)]
+/// struct Particle {
+///     x: f32,
+///     y: f32,
+///     alive: bool,
+/// }
+///
+/// let mut particles = ParticleColumns::from(vec![
+///     Particle { x: 0.0, y: 1.0, alive: true },
+///     Particle { x: 2.0, y: 3.0, alive: false },
+/// ]);
+/// particles.push(Particle { x: 4.0, y: 5.0, alive: true });
+///
+/// assert_eq!(particles.len(), 3);
+/// assert_eq!(particles.x, [0.0, 2.0, 4.0]);
+/// assert_eq!(particles.alive, [true, false, true]);
+/// assert_eq!(particles.get(1), Some(Particle { x: 2.0, y: 3.0, alive: false }));
+///
+/// let alive = particles.iter().filter(|p| p.alive).count();
+/// assert_eq!(alive, 2);
+///
+/// let rows = Vec::from(particles);
+/// assert_eq!(rows[2], Particle { x: 4.0, y: 5.0, alive: true });
+/// ```
+pub trait GColumns {
+    /// The representation of a single row.
+    type Row;
+
+    /// Appends `row` to the end of each column.
+    fn gpush(&mut self, row: Self::Row);
+
+    /// Removes the last row, if any.
+    fn gpop(&mut self) -> Option<Self::Row>;
+}
+
+impl GColumns for Unit {
+    type Row = Unit;
+
+    fn gpush(&mut self, _row: Self::Row) {}
+
+    fn gpop(&mut self) -> Option<Self::Row> {
+        Some(Unit)
+    }
+}
+
+impl<A, B> GColumns for Prod<A, B>
+where
+    A: GColumns,
+    B: GColumns,
+{
+    type Row = Prod<A::Row, B::Row>;
+
+    fn gpush(&mut self, row: Self::Row) {
+        let Prod(a, b) = self;
+        let Prod(row_a, row_b) = row;
+        a.gpush(row_a);
+        b.gpush(row_b);
+    }
+
+    fn gpop(&mut self) -> Option<Self::Row> {
+        let Prod(a, b) = self;
+        // Both sides are popped, so the columns stay the same length.
+        let a = a.gpop();
+        let b = b.gpop();
+        Some(Prod(a?, b?))
+    }
+}
+
+impl<T, M> GColumns for Meta<Vec<T>, M>
+where
+    M: Singleton,
+{
+    type Row = Meta<T, M>;
+
+    fn gpush(&mut self, row: Self::Row) {
+        let Meta(column, _) = self;
+        let Meta(value, _) = row;
+        column.push(value);
+    }
+
+    fn gpop(&mut self) -> Option<Self::Row> {
+        let Meta(column, _) = self;
+        Some(Meta(column.pop()?, PhantomData))
+    }
+}
+
+impl<I, C> GColumns for Con<I, C>
+where
+    I: GColumns,
+    C: Singleton,
+{
+    type Row = Con<I::Row, C>;
+
+    fn gpush(&mut self, row: Self::Row) {
+        let Con(inner, _) = self;
+        let Con(row, _) = row;
+        inner.gpush(row);
+    }
+
+    fn gpop(&mut self) -> Option<Self::Row> {
+        let Con(inner, _) = self;
+        Some(Con(inner.gpop()?, PhantomData))
+    }
+}
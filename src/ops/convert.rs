@@ -0,0 +1,359 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+/// Converts a representation into one of the same shape, converting each leaf with `From`.
+///
+/// This maps between parallel types, e.g. a DTO and its domain type, whose fields and variants line up
+/// by position, but whose leaves differ, e.g. `&str` and `String`, or `u32` and a newtype ID.
+/// Names of fields and constructors aren't compared, only the shape.
+///
+/// A user-defined leaf, i.e. a type implementing `Generic` with `Repr = Self` and `Leaf`,
+/// is converted as a whole, so newtypes wrapping a leaf can be converted to and from it.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::convert;
+/// use generics::{Generic, Leaf};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct UserId(u32);
+///
+/// impl From<u32> for UserId {
+///     fn from(id: u32) -> Self {
+///         UserId(id)
+///     }
+/// }
+///
+/// impl Generic for UserId {
+///     type Repr = Self;
+///     fn into_repr(self) -> Self::Repr {
+///         self
+///     }
+///     fn from_repr(repr: Self::Repr) -> Self {
+///         repr
+///     }
+/// }
+///
+/// impl Leaf for UserId {}
+///
+/// #[derive(Generic)]
+/// struct UserDto<'a> {
+///     id: u32,
+///     name: &'a str,
+///     tags: Vec<&'a str>,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct User {
+///     id: UserId,
+///     name: String,
+///     tags: Vec<String>,
+/// }
+///
+/// let dto = UserDto { id: 7, name: "alice", tags: vec!["admin"] };
+/// assert_eq!(
+///     convert::<_, User>(dto),
+///     User { id: UserId(7), name: "alice".to_string(), tags: vec!["admin".to_string()] }
+/// );
+/// ```
+pub trait GConvert<T> {
+    /// Converts `self` into `T`.
+    fn gconvert(self) -> T;
+}
+
+impl GConvert<Unit> for Unit {
+    fn gconvert(self) -> Unit {
+        Unit
+    }
+}
+
+impl<A, B, A2, B2> GConvert<Prod<A2, B2>> for Prod<A, B>
+where
+    A: GConvert<A2>,
+    B: GConvert<B2>,
+{
+    fn gconvert(self) -> Prod<A2, B2> {
+        let Prod(a, b) = self;
+        Prod(a.gconvert(), b.gconvert())
+    }
+}
+
+impl<L, R, L2, R2> GConvert<Sum<L2, R2>> for Sum<L, R>
+where
+    L: GConvert<L2>,
+    R: GConvert<R2>,
+{
+    fn gconvert(self) -> Sum<L2, R2> {
+        match self {
+            Sum::Left(l) => Sum::Left(l.gconvert()),
+            Sum::Right(r) => Sum::Right(r.gconvert()),
+        }
+    }
+}
+
+impl GConvert<Void> for Void {
+    fn gconvert(self) -> Void {
+        match self {}
+    }
+}
+
+impl<I, M, I2, M2> GConvert<Meta<I2, M2>> for Meta<I, M>
+where
+    I: GConvert<I2>,
+    M: Singleton,
+    M2: Singleton,
+{
+    fn gconvert(self) -> Meta<I2, M2> {
+        let Meta(inner, _) = self;
+        Meta(inner.gconvert(), PhantomData)
+    }
+}
+
+impl<I, C, I2, C2> GConvert<Con<I2, C2>> for Con<I, C>
+where
+    I: GConvert<I2>,
+    C: Singleton,
+    C2: Singleton,
+{
+    fn gconvert(self) -> Con<I2, C2> {
+        let Con(inner, _) = self;
+        Con(inner.gconvert(), PhantomData)
+    }
+}
+
+impl<A, A2> GConvert<List<A2>> for List<A>
+where
+    A: GConvert<A2>,
+{
+    fn gconvert(self) -> List<A2> {
+        let List(items) = self;
+        List(items.into_iter().map(GConvert::gconvert).collect())
+    }
+}
+
+impl<A, A2, const N: usize> GConvert<[A2; N]> for [A; N]
+where
+    A: GConvert<A2>,
+{
+    fn gconvert(self) -> [A2; N] {
+        self.map(GConvert::gconvert)
+    }
+}
+
+impl<T, U> GConvert<U> for T
+where
+    T: Leaf,
+    U: From<T>,
+{
+    fn gconvert(self) -> U {
+        U::from(self)
+    }
+}
+
+/// Converts `from` into a `T` of the same shape, converting each leaf with `From`.
+pub fn convert<F, T>(from: F) -> T
+where
+    F: Generic,
+    T: Generic,
+    F::Repr: GConvert<T::Repr>,
+{
+    T::from_repr(from.into_repr().gconvert())
+}
+
+/// Converts a representation into one of the same shape, converting each leaf with `TryFrom`.
+///
+/// Like `GConvert`, but stops at the first leaf which fails to convert, returning its error
+/// along with the names of the fields leading to it.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::try_convert;
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct Wide {
+///     port: u32,
+///     retries: i64,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Narrow {
+///     port: u16,
+///     retries: u8,
+/// }
+///
+/// assert_eq!(
+///     try_convert::<_, Narrow>(Wide { port: 8080, retries: 3 }).ok(),
+///     Some(Narrow { port: 8080, retries: 3 })
+/// );
+///
+/// let err = try_convert::<_, Narrow>(Wide { port: 8080, retries: -1 }).unwrap_err();
+/// assert_eq!(err.path, ["retries"]);
+/// assert_eq!(
+///     err.to_string(),
+///     "failed to convert field `retries`: out of range integral type conversion attempted"
+/// );
+/// ```
+pub trait GTryConvert<T> {
+    /// Converts `self` into `T`.
+    fn gtry_convert(self) -> Result<T, ConvertError>;
+}
+
+/// A leaf which failed to convert.
+#[derive(Debug)]
+pub struct ConvertError {
+    /// The names of the fields leading to the leaf, outermost first, e.g. `["address", "zip"]`.
+    pub path: Vec<&'static str>,
+    /// The leaf's conversion error.
+    pub source: Box<dyn Error + Send + Sync>,
+}
+
+impl Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "failed to convert: {}", self.source)
+        } else {
+            write!(
+                f,
+                "failed to convert field `{}`: {}",
+                self.path.join("."),
+                self.source
+            )
+        }
+    }
+}
+
+impl Error for ConvertError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&*self.source)
+    }
+}
+
+impl GTryConvert<Unit> for Unit {
+    fn gtry_convert(self) -> Result<Unit, ConvertError> {
+        Ok(Unit)
+    }
+}
+
+impl<A, B, A2, B2> GTryConvert<Prod<A2, B2>> for Prod<A, B>
+where
+    A: GTryConvert<A2>,
+    B: GTryConvert<B2>,
+{
+    fn gtry_convert(self) -> Result<Prod<A2, B2>, ConvertError> {
+        let Prod(a, b) = self;
+        Ok(Prod(a.gtry_convert()?, b.gtry_convert()?))
+    }
+}
+
+impl<L, R, L2, R2> GTryConvert<Sum<L2, R2>> for Sum<L, R>
+where
+    L: GTryConvert<L2>,
+    R: GTryConvert<R2>,
+{
+    fn gtry_convert(self) -> Result<Sum<L2, R2>, ConvertError> {
+        match self {
+            Sum::Left(l) => Ok(Sum::Left(l.gtry_convert()?)),
+            Sum::Right(r) => Ok(Sum::Right(r.gtry_convert()?)),
+        }
+    }
+}
+
+impl GTryConvert<Void> for Void {
+    fn gtry_convert(self) -> Result<Void, ConvertError> {
+        match self {}
+    }
+}
+
+impl<I, M, I2, M2> GTryConvert<Meta<I2, M2>> for Meta<I, M>
+where
+    I: GTryConvert<I2>,
+    M: Singleton<T = &'static str>,
+    M2: Singleton,
+{
+    fn gtry_convert(self) -> Result<Meta<I2, M2>, ConvertError> {
+        let Meta(inner, _) = self;
+        match inner.gtry_convert() {
+            Ok(inner) => Ok(Meta(inner, PhantomData)),
+            Err(mut e) => {
+                e.path.insert(0, M::get());
+                Err(e)
+            }
+        }
+    }
+}
+
+impl<I, C, I2, C2> GTryConvert<Con<I2, C2>> for Con<I, C>
+where
+    I: GTryConvert<I2>,
+    C: Singleton,
+    C2: Singleton,
+{
+    fn gtry_convert(self) -> Result<Con<I2, C2>, ConvertError> {
+        let Con(inner, _) = self;
+        Ok(Con(inner.gtry_convert()?, PhantomData))
+    }
+}
+
+impl<A, A2> GTryConvert<List<A2>> for List<A>
+where
+    A: GTryConvert<A2>,
+{
+    fn gtry_convert(self) -> Result<List<A2>, ConvertError> {
+        let List(items) = self;
+        let items = items
+            .into_iter()
+            .map(GTryConvert::gtry_convert)
+            .collect::<Result<_, _>>()?;
+        Ok(List(items))
+    }
+}
+
+impl<A, A2, const N: usize> GTryConvert<[A2; N]> for [A; N]
+where
+    A: GTryConvert<A2>,
+{
+    fn gtry_convert(self) -> Result<[A2; N], ConvertError> {
+        let items = IntoIterator::into_iter(self)
+            .map(GTryConvert::gtry_convert)
+            .collect::<Result<Vec<_>, _>>()?;
+        match <[A2; N]>::try_from(items) {
+            Ok(items) => Ok(items),
+            Err(_) => unreachable!("converted every item of the array"),
+        }
+    }
+}
+
+impl<T, U> GTryConvert<U> for T
+where
+    T: Leaf,
+    U: TryFrom<T>,
+    U::Error: Error + Send + Sync + 'static,
+{
+    fn gtry_convert(self) -> Result<U, ConvertError> {
+        U::try_from(self).map_err(|e| ConvertError {
+            path: Vec::new(),
+            source: Box::new(e),
+        })
+    }
+}
+
+/// Converts `from` into a `T` of the same shape, converting each leaf with `TryFrom`.
+pub fn try_convert<F, T>(from: F) -> Result<T, ConvertError>
+where
+    F: Generic,
+    T: Generic,
+    F::Repr: GTryConvert<T::Repr>,
+{
+    let repr = from.into_repr().gtry_convert()?;
+    T::checked_from_repr(repr).map_err(|e| ConvertError {
+        path: Vec::new(),
+        source: Box::new(e),
+    })
+}
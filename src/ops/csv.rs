@@ -0,0 +1,674 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::markers::{Option_None_Name, Option_Some_0, Option_Some_Name};
+use crate::{Con, Generic, Leaf, Meta, Prod, ReprError, Singleton, Sum, Unit, Void};
+
+use super::flatten::push_segment;
+
+/// A record of a CSV file, whose cells are looked up by the names of their columns.
+#[derive(Debug, Clone, Copy)]
+pub struct CsvRecord<'a> {
+    line: usize,
+    columns: &'a HashMap<String, usize>,
+    cells: &'a [String],
+}
+
+impl<'a> CsvRecord<'a> {
+    /// The line the record starts on, where the header is on line 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The cell in the column named `column`, or `None` if there is no such column.
+    pub fn get(&self, column: &str) -> Option<&'a str> {
+        let i = *self.columns.get(column)?;
+        self.cells.get(i).map(String::as_str)
+    }
+}
+
+/// Converts a flat representation to and from a record of a CSV file, using its metadata.
+///
+/// Each leaf is a column named by the path of field names leading to it, as produced by `flatten`,
+/// so nested structs are flattened into columns like `pos.x`, and tuples and arrays into columns like `pair.0`.
+/// Leaves are formatted with `Display` and parsed with `FromStr`, enums whose variants have no fields are the
+/// name of their variant, and `Option` is an empty cell for `None`, so `Some` of an empty string reads as `None`.
+/// Lists and enums with fields have no impl, since they aren't flat.
+///
+/// `encode_csv` and `decode_csv` use this to write and read CSV files whose header names the column of each leaf.
+/// Records are matched to columns by those names, so columns may be in any order, and unknown columns are ignored.
+/// Errors name the line and column of the offending cell. Only the `csv` feature is required, which has no dependencies.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{decode_csv, encode_csv, CsvError};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Trade {
+///     symbol: String,
+///     price: f64,
+///     side: Side,
+///     note: Option<String>,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum Side {
+///     Buy,
+///     Sell,
+/// }
+///
+/// let trades = vec![
+///     Trade { symbol: "ACME".to_string(), price: 1.5, side: Side::Buy, note: None },
+///     Trade { symbol: "X, Y".to_string(), price: 2.0, side: Side::Sell, note: Some("late".to_string()) },
+/// ];
+/// let text = encode_csv(trades);
+/// assert_eq!(text, "symbol,price,side,note\nACME,1.5,Buy,\n\"X, Y\",2,Sell,late\n");
+///
+/// let trades = decode_csv::<Trade>("side,price,symbol,note\nSell,3,ACME,\n").unwrap();
+/// assert_eq!(trades, [Trade { symbol: "ACME".to_string(), price: 3.0, side: Side::Sell, note: None }]);
+///
+/// let err = decode_csv::<Trade>("symbol,price,side,note\nACME,cheap,Buy,\n").unwrap_err();
+/// assert_eq!(err.to_string(), "invalid value `cheap` for column `price` on line 2");
+/// ```
+pub trait GCsv: Sized {
+    /// Appends the name of the column of each leaf, beginning with `path`.
+    fn gheader(path: &mut String, header: &mut Vec<String>);
+
+    /// Appends the cell of each leaf.
+    fn gto_record(self, cells: &mut Vec<String>);
+
+    /// Converts the cells of `record` in the columns beginning with `path` to `Self`.
+    fn gfrom_record(path: &mut String, record: &CsvRecord<'_>) -> Result<Self, CsvError>;
+}
+
+/// Converts the elements of a `Prod` chain to and from the cells of a record.
+pub trait GCsvElements: Sized {
+    /// Appends the name of the column of each leaf of each element, beginning with `path` and its index from `index`.
+    fn gheader_elements(index: usize, path: &mut String, header: &mut Vec<String>);
+
+    /// Appends the cell of each leaf of each element.
+    fn gto_elements(self, cells: &mut Vec<String>);
+
+    /// Converts each element from the columns beginning with `path` and its index, from `index`.
+    fn gfrom_elements(
+        index: usize,
+        path: &mut String,
+        record: &CsvRecord<'_>,
+    ) -> Result<Self, CsvError>;
+}
+
+/// Converts the fields of a constructor, i.e. a `Prod` chain of `Meta`s, to and from the cells of a record.
+pub trait GCsvFields: Sized {
+    /// Appends the name of the column of each leaf of each field, beginning with `path` and the field's name.
+    fn gheader_fields(path: &mut String, header: &mut Vec<String>);
+
+    /// Appends the cell of each leaf of each field.
+    fn gto_fields(self, cells: &mut Vec<String>);
+
+    /// Converts each field from the columns beginning with `path` and the field's name.
+    fn gfrom_fields(path: &mut String, record: &CsvRecord<'_>) -> Result<Self, CsvError>;
+}
+
+/// Converts a representation to and from a single cell.
+pub trait GCsvCell: Sized {
+    /// Converts `self` to the text of a cell.
+    fn gto_cell(self) -> String;
+
+    /// Converts the text of a cell to `Self`, or returns `None` if it's invalid.
+    fn gfrom_cell(cell: &str) -> Option<Self>;
+}
+
+/// Converts whichever variant of a `Sum` chain is held to and from a cell.
+pub trait GCsvVariant: Sized {
+    /// Converts the held variant to the text of a cell.
+    fn gto_variant(self) -> String;
+
+    /// Converts the text of a cell to the variant it names, or returns `None` if it names none.
+    fn gfrom_variant(cell: &str) -> Option<Self>;
+}
+
+/// An error reading a CSV file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvError {
+    /// The text wasn't valid CSV, on the given line.
+    Syntax(usize),
+    /// A record had a different number of cells than the header.
+    Length {
+        /// The line the record starts on.
+        line: usize,
+        /// The number of columns in the header.
+        expected: usize,
+        /// The number of cells in the record.
+        found: usize,
+    },
+    /// The header had no column with the given name.
+    Missing(String),
+    /// The cell of a leaf couldn't be parsed.
+    Invalid {
+        /// The line the record starts on.
+        line: usize,
+        /// The name of the column.
+        column: String,
+        /// The text of the cell.
+        value: String,
+    },
+    /// The record was invalid for its type, e.g. a `NonZeroU8` of zero.
+    Repr {
+        /// The line the record starts on.
+        line: usize,
+        /// Why the record was invalid.
+        error: ReprError,
+    },
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Syntax(line) => write!(f, "invalid CSV on line {}", line),
+            CsvError::Length {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {} cells on line {}, found {}",
+                expected, line, found
+            ),
+            CsvError::Missing(column) => write!(f, "missing column `{}`", column),
+            CsvError::Invalid {
+                line,
+                column,
+                value,
+            } => write!(
+                f,
+                "invalid value `{}` for column `{}` on line {}",
+                value, column, line
+            ),
+            CsvError::Repr { line, error } => {
+                write!(f, "invalid record on line {}: {}", line, error)
+            }
+        }
+    }
+}
+
+impl Error for CsvError {}
+
+/// Appends `cell` to `out`, quoted if it holds a comma, quote, or line break.
+fn write_cell(out: &mut String, cell: &str) {
+    if cell.contains(&[',', '"', '\n', '\r'][..]) {
+        out.push('"');
+        out.push_str(&cell.replace('"', "\"\""));
+        out.push('"');
+    } else {
+        out.push_str(cell);
+    }
+}
+
+fn write_record(out: &mut String, cells: &[String]) {
+    for (i, cell) in cells.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_cell(out, cell);
+    }
+    out.push('\n');
+}
+
+/// Splits `text` into records of cells, with the line each starts on.
+///
+/// Records end with `\n` or `\r\n`, and cells may be quoted, with quotes inside doubled.
+fn parse(text: &str) -> Result<Vec<(usize, Vec<String>)>, CsvError> {
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+    let mut records = Vec::new();
+    while chars.peek().is_some() {
+        let start = line;
+        let mut cells = Vec::new();
+        loop {
+            let mut cell = String::new();
+            if chars.next_if_eq(&'"').is_some() {
+                loop {
+                    match chars.next() {
+                        None => return Err(CsvError::Syntax(start)),
+                        Some('"') if chars.next_if_eq(&'"').is_some() => cell.push('"'),
+                        Some('"') => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            cell.push(c);
+                        }
+                    }
+                }
+            } else {
+                while let Some(c) = chars.next_if(|&c| !matches!(c, ',' | '\n' | '\r')) {
+                    if c == '"' {
+                        return Err(CsvError::Syntax(line));
+                    }
+                    cell.push(c);
+                }
+            }
+            cells.push(cell);
+            match chars.next() {
+                Some(',') => {}
+                Some('\r') if chars.next_if_eq(&'\n').is_some() => {
+                    line += 1;
+                    break;
+                }
+                Some('\n') => {
+                    line += 1;
+                    break;
+                }
+                None => break,
+                Some(_) => return Err(CsvError::Syntax(line)),
+            }
+        }
+        records.push((start, cells));
+    }
+    Ok(records)
+}
+
+impl GCsv for Unit {
+    fn gheader(_path: &mut String, _header: &mut Vec<String>) {}
+
+    fn gto_record(self, _cells: &mut Vec<String>) {}
+
+    fn gfrom_record(_path: &mut String, _record: &CsvRecord<'_>) -> Result<Self, CsvError> {
+        Ok(Unit)
+    }
+}
+
+impl<A, B> GCsv for Prod<A, B>
+where
+    Self: GCsvElements,
+{
+    fn gheader(path: &mut String, header: &mut Vec<String>) {
+        Self::gheader_elements(0, path, header)
+    }
+
+    fn gto_record(self, cells: &mut Vec<String>) {
+        self.gto_elements(cells)
+    }
+
+    fn gfrom_record(path: &mut String, record: &CsvRecord<'_>) -> Result<Self, CsvError> {
+        Self::gfrom_elements(0, path, record)
+    }
+}
+
+impl GCsvElements for Unit {
+    fn gheader_elements(_index: usize, _path: &mut String, _header: &mut Vec<String>) {}
+
+    fn gto_elements(self, _cells: &mut Vec<String>) {}
+
+    fn gfrom_elements(
+        _index: usize,
+        _path: &mut String,
+        _record: &CsvRecord<'_>,
+    ) -> Result<Self, CsvError> {
+        Ok(Unit)
+    }
+}
+
+impl<A, B> GCsvElements for Prod<A, B>
+where
+    A: GCsv,
+    B: GCsvElements,
+{
+    fn gheader_elements(index: usize, path: &mut String, header: &mut Vec<String>) {
+        let len = push_segment(path, &index);
+        A::gheader(path, header);
+        path.truncate(len);
+        B::gheader_elements(index + 1, path, header);
+    }
+
+    fn gto_elements(self, cells: &mut Vec<String>) {
+        let Prod(a, b) = self;
+        a.gto_record(cells);
+        b.gto_elements(cells);
+    }
+
+    fn gfrom_elements(
+        index: usize,
+        path: &mut String,
+        record: &CsvRecord<'_>,
+    ) -> Result<Self, CsvError> {
+        let len = push_segment(path, &index);
+        let a = A::gfrom_record(path, record);
+        path.truncate(len);
+        Ok(Prod(a?, B::gfrom_elements(index + 1, path, record)?))
+    }
+}
+
+impl<I, M> GCsv for Meta<I, M>
+where
+    I: GCsv,
+    M: Singleton,
+{
+    fn gheader(path: &mut String, header: &mut Vec<String>) {
+        I::gheader(path, header)
+    }
+
+    fn gto_record(self, cells: &mut Vec<String>) {
+        let Meta(inner, _) = self;
+        inner.gto_record(cells)
+    }
+
+    fn gfrom_record(path: &mut String, record: &CsvRecord<'_>) -> Result<Self, CsvError> {
+        I::gfrom_record(path, record).map(|inner| Meta(inner, PhantomData))
+    }
+}
+
+impl GCsvFields for Unit {
+    fn gheader_fields(_path: &mut String, _header: &mut Vec<String>) {}
+
+    fn gto_fields(self, _cells: &mut Vec<String>) {}
+
+    fn gfrom_fields(_path: &mut String, _record: &CsvRecord<'_>) -> Result<Self, CsvError> {
+        Ok(Unit)
+    }
+}
+
+impl<A, M, B> GCsvFields for Prod<Meta<A, M>, B>
+where
+    A: GCsv,
+    M: Singleton<T = &'static str>,
+    B: GCsvFields,
+{
+    fn gheader_fields(path: &mut String, header: &mut Vec<String>) {
+        let len = push_segment(path, &M::get());
+        A::gheader(path, header);
+        path.truncate(len);
+        B::gheader_fields(path, header);
+    }
+
+    fn gto_fields(self, cells: &mut Vec<String>) {
+        let Prod(Meta(a, _), b) = self;
+        a.gto_record(cells);
+        b.gto_fields(cells);
+    }
+
+    fn gfrom_fields(path: &mut String, record: &CsvRecord<'_>) -> Result<Self, CsvError> {
+        let len = push_segment(path, &M::get());
+        let a = A::gfrom_record(path, record);
+        path.truncate(len);
+        Ok(Prod(Meta(a?, PhantomData), B::gfrom_fields(path, record)?))
+    }
+}
+
+impl<I, C> GCsv for Con<I, C>
+where
+    I: GCsvFields,
+    C: Singleton,
+{
+    fn gheader(path: &mut String, header: &mut Vec<String>) {
+        I::gheader_fields(path, header)
+    }
+
+    fn gto_record(self, cells: &mut Vec<String>) {
+        let Con(inner, _) = self;
+        inner.gto_fields(cells)
+    }
+
+    fn gfrom_record(path: &mut String, record: &CsvRecord<'_>) -> Result<Self, CsvError> {
+        I::gfrom_fields(path, record).map(|inner| Con(inner, PhantomData))
+    }
+}
+
+impl<A, const N: usize> GCsv for [A; N]
+where
+    A: GCsv,
+{
+    fn gheader(path: &mut String, header: &mut Vec<String>) {
+        for i in 0..N {
+            let len = push_segment(path, &i);
+            A::gheader(path, header);
+            path.truncate(len);
+        }
+    }
+
+    fn gto_record(self, cells: &mut Vec<String>) {
+        for item in self {
+            item.gto_record(cells);
+        }
+    }
+
+    fn gfrom_record(path: &mut String, record: &CsvRecord<'_>) -> Result<Self, CsvError> {
+        let items = (0..N)
+            .map(|i| {
+                let len = push_segment(path, &i);
+                let item = A::gfrom_record(path, record);
+                path.truncate(len);
+                item
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(items
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("decoded `N` items")))
+    }
+}
+
+impl<L, R> GCsv for Sum<L, R>
+where
+    Self: GCsvCell,
+{
+    fn gheader(path: &mut String, header: &mut Vec<String>) {
+        header.push(path.clone());
+    }
+
+    fn gto_record(self, cells: &mut Vec<String>) {
+        cells.push(self.gto_cell());
+    }
+
+    fn gfrom_record(path: &mut String, record: &CsvRecord<'_>) -> Result<Self, CsvError> {
+        cell_from_record(path, record)
+    }
+}
+
+impl<L, R> GCsvCell for Sum<L, R>
+where
+    Self: GCsvVariant,
+{
+    fn gto_cell(self) -> String {
+        self.gto_variant()
+    }
+
+    fn gfrom_cell(cell: &str) -> Option<Self> {
+        Self::gfrom_variant(cell)
+    }
+}
+
+/// A variant with no fields is the cell of its name, except for `None`, which is an empty cell.
+impl<C, R> GCsvVariant for Sum<Con<Unit, C>, R>
+where
+    C: Singleton<T = &'static str> + 'static,
+    R: GCsvVariant,
+{
+    fn gto_variant(self) -> String {
+        match self {
+            Sum::Left(_) if TypeId::of::<C>() == TypeId::of::<Option_None_Name>() => String::new(),
+            Sum::Left(_) => C::get().to_string(),
+            Sum::Right(r) => r.gto_variant(),
+        }
+    }
+
+    fn gfrom_variant(cell: &str) -> Option<Self> {
+        let name = if TypeId::of::<C>() == TypeId::of::<Option_None_Name>() {
+            ""
+        } else {
+            C::get()
+        };
+        if cell == name {
+            return Some(Sum::Left(Con(Unit, PhantomData)));
+        }
+        R::gfrom_variant(cell).map(Sum::Right)
+    }
+}
+
+/// `Some` is the cell of its value.
+impl<A> GCsvVariant for Sum<Con<Prod<Meta<A, Option_Some_0>, Unit>, Option_Some_Name>, Void>
+where
+    A: GCsvCell,
+{
+    fn gto_variant(self) -> String {
+        match self {
+            Sum::Left(Con(Prod(Meta(a, _), Unit), _)) => a.gto_cell(),
+            Sum::Right(void) => match void {},
+        }
+    }
+
+    fn gfrom_variant(cell: &str) -> Option<Self> {
+        let a = A::gfrom_cell(cell)?;
+        Some(Sum::Left(Con(
+            Prod(Meta(a, PhantomData), Unit),
+            PhantomData,
+        )))
+    }
+}
+
+impl GCsvVariant for Void {
+    fn gto_variant(self) -> String {
+        match self {}
+    }
+
+    fn gfrom_variant(_cell: &str) -> Option<Self> {
+        None
+    }
+}
+
+impl<T> GCsvCell for T
+where
+    T: Leaf + Display + FromStr,
+{
+    fn gto_cell(self) -> String {
+        self.to_string()
+    }
+
+    fn gfrom_cell(cell: &str) -> Option<Self> {
+        cell.parse().ok()
+    }
+}
+
+impl<T> GCsv for T
+where
+    T: Leaf + Display + FromStr,
+{
+    fn gheader(path: &mut String, header: &mut Vec<String>) {
+        header.push(path.clone());
+    }
+
+    fn gto_record(self, cells: &mut Vec<String>) {
+        cells.push(self.gto_cell());
+    }
+
+    fn gfrom_record(path: &mut String, record: &CsvRecord<'_>) -> Result<Self, CsvError> {
+        cell_from_record(path, record)
+    }
+}
+
+fn cell_from_record<T>(path: &str, record: &CsvRecord<'_>) -> Result<T, CsvError>
+where
+    T: GCsvCell,
+{
+    let cell = record
+        .get(path)
+        .ok_or_else(|| CsvError::Missing(path.to_string()))?;
+    T::gfrom_cell(cell).ok_or_else(|| CsvError::Invalid {
+        line: record.line,
+        column: path.to_string(),
+        value: cell.to_string(),
+    })
+}
+
+/// The names of the columns of `T`, in the order `encode_csv` writes them.
+///
+/// ```rust
+/// use generics::ops::csv_header;
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct Sample {
+///     at: u64,
+///     pos: (f32, f32),
+///     tags: [char; 2],
+/// }
+///
+/// assert_eq!(csv_header::<Sample>(), ["at", "pos.0", "pos.1", "tags.0", "tags.1"]);
+/// ```
+pub fn csv_header<T>() -> Vec<String>
+where
+    T: Generic,
+    T::Repr: GCsv,
+{
+    let mut header = Vec::new();
+    T::Repr::gheader(&mut String::new(), &mut header);
+    header
+}
+
+/// Writes `rows` as CSV, after a header naming the column of each leaf, with each record ending in `\n`.
+pub fn encode_csv<T, I>(rows: I) -> String
+where
+    T: Generic,
+    T::Repr: GCsv,
+    I: IntoIterator<Item = T>,
+{
+    let mut out = String::new();
+    write_record(&mut out, &csv_header::<T>());
+    let mut cells = Vec::new();
+    for row in rows {
+        row.into_repr().gto_record(&mut cells);
+        write_record(&mut out, &cells);
+        cells.clear();
+    }
+    out
+}
+
+/// Reads the records of CSV text as `T`s, after a header which must name the column of each leaf of `T`.
+///
+/// Empty text has no records.
+pub fn decode_csv<T>(text: &str) -> Result<Vec<T>, CsvError>
+where
+    T: Generic,
+    T::Repr: GCsv,
+{
+    let mut records = parse(text)?.into_iter();
+    let header = match records.next() {
+        Some((_, header)) => header,
+        None => return Ok(Vec::new()),
+    };
+    let columns = header
+        .iter()
+        .enumerate()
+        .map(|(i, column)| (column.clone(), i))
+        .collect::<HashMap<_, _>>();
+    if let Some(column) = csv_header::<T>()
+        .into_iter()
+        .find(|column| !columns.contains_key(column))
+    {
+        return Err(CsvError::Missing(column));
+    }
+    records
+        .map(|(line, cells)| {
+            if cells.len() != header.len() {
+                return Err(CsvError::Length {
+                    line,
+                    expected: header.len(),
+                    found: cells.len(),
+                });
+            }
+            let record = CsvRecord {
+                line,
+                columns: &columns,
+                cells: &cells,
+            };
+            let repr = T::Repr::gfrom_record(&mut String::new(), &record)?;
+            T::checked_from_repr(repr).map_err(|error| CsvError::Repr { line, error })
+        })
+        .collect()
+}
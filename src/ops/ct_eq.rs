@@ -0,0 +1,315 @@
+use std::hint::black_box;
+use std::ops::{BitAnd, BitAndAssign, Not};
+
+use crate::{Con, Leaf, List, Meta, Opaque, Prod, Sensitive, Singleton, Sum, Unit, Void};
+
+use super::Borrowed;
+
+/// The result of a constant-time comparison, which is `1` if true or `0` if false.
+///
+/// Combining `Choice`s doesn't branch, so no information is leaked until it's converted to a `bool`.
+#[derive(Debug, Clone, Copy)]
+pub struct Choice(u8);
+
+impl Choice {
+    /// Constructs a `Choice` from `bit`, which must be `0` or `1`.
+    ///
+    /// The value passes through an optimization barrier, so the compiler can't introduce a branch on it.
+    pub fn from_bit(bit: u8) -> Self {
+        debug_assert!(bit <= 1, "`Choice` must be 0 or 1");
+        Choice(black_box(bit))
+    }
+
+    /// The underlying bit, `0` or `1`.
+    pub fn unwrap_u8(self) -> u8 {
+        self.0
+    }
+}
+
+impl From<Choice> for bool {
+    fn from(choice: Choice) -> Self {
+        choice.0 == 1
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Choice;
+
+    fn bitand(self, rhs: Choice) -> Self::Output {
+        Choice::from_bit(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for Choice {
+    fn bitand_assign(&mut self, rhs: Choice) {
+        *self = *self & rhs;
+    }
+}
+
+impl Not for Choice {
+    type Output = Choice;
+
+    fn not(self) -> Self::Output {
+        Choice::from_bit(self.0 ^ 1)
+    }
+}
+
+/// Equality of secret values which takes the same time regardless of where they differ.
+///
+/// Implement this for your own leaf types, or derive it with `#[derive(GenericConstantTimeEq)]`.
+pub trait ConstantTimeEq {
+    /// Whether `self` and `other` are equal, in constant time.
+    fn ct_eq(&self, other: &Self) -> Choice;
+}
+
+macro_rules! impl_ct_eq_int {
+    ( $( $ty:ty => $unsigned:ty ),+ $(,)? ) => {
+        $(
+            impl ConstantTimeEq for $ty {
+                fn ct_eq(&self, other: &Self) -> Choice {
+                    // The top bit of `x | -x` is set iff `x` is nonzero.
+                    let x = (*self ^ *other) as $unsigned;
+                    let nonzero = (x | x.wrapping_neg()) >> (<$unsigned>::BITS - 1);
+                    !Choice::from_bit(nonzero as u8)
+                }
+            }
+        )+
+    }
+}
+
+#[rustfmt::skip]
+impl_ct_eq_int!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+);
+
+impl ConstantTimeEq for bool {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        (*self as u8).ct_eq(&(*other as u8))
+    }
+}
+
+impl ConstantTimeEq for char {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        (*self as u32).ct_eq(&(*other as u32))
+    }
+}
+
+/// Slices of different lengths are unequal, which takes less time; only the contents are compared in constant time.
+impl<T> ConstantTimeEq for [T]
+where
+    T: ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        if self.len() != other.len() {
+            return Choice::from_bit(0);
+        }
+        let mut eq = Choice::from_bit(1);
+        for (a, b) in self.iter().zip(other) {
+            eq &= a.ct_eq(b);
+        }
+        eq
+    }
+}
+
+impl<T, const N: usize> ConstantTimeEq for [T; N]
+where
+    T: ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self[..].ct_eq(&other[..])
+    }
+}
+
+impl<T> ConstantTimeEq for Vec<T>
+where
+    T: ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self[..].ct_eq(&other[..])
+    }
+}
+
+impl ConstantTimeEq for str {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_bytes().ct_eq(other.as_bytes())
+    }
+}
+
+impl ConstantTimeEq for String {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_bytes().ct_eq(other.as_bytes())
+    }
+}
+
+impl<T> ConstantTimeEq for &T
+where
+    T: ConstantTimeEq + ?Sized,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        (**self).ct_eq(*other)
+    }
+}
+
+impl<T> ConstantTimeEq for Box<T>
+where
+    T: ConstantTimeEq + ?Sized,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        (**self).ct_eq(&**other)
+    }
+}
+
+impl<T> ConstantTimeEq for Opaque<T>
+where
+    T: ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<T> ConstantTimeEq for Sensitive<T>
+where
+    T: ConstantTimeEq,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+/// Compares representations for equality in constant time, node by node.
+///
+/// Products compare every field, without stopping at the first difference, and leaves use `ConstantTimeEq`.
+/// Only the variants of sums and the lengths of lists are compared in variable time,
+/// so they must not be secret.
+///
+/// `#[derive(GenericConstantTimeEq)]` uses this to implement `ConstantTimeEq`, alongside `#[derive(Generic)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{ConstantTimeEq, GenericConstantTimeEq};
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericConstantTimeEq)]
+/// struct Key {
+///     id: u32,
+///     bytes: [u8; 4],
+/// }
+///
+/// let key = Key { id: 1, bytes: [1, 2, 3, 4] };
+/// assert!(bool::from(key.ct_eq(&Key { id: 1, bytes: [1, 2, 3, 4] })));
+/// assert!(!bool::from(key.ct_eq(&Key { id: 1, bytes: [1, 2, 3, 5] })));
+/// ```
+pub trait GConstantTimeEq {
+    /// Whether `self` and `other` are equal, in constant time.
+    fn gct_eq(&self, other: &Self) -> Choice;
+}
+
+impl GConstantTimeEq for Unit {
+    fn gct_eq(&self, _other: &Self) -> Choice {
+        Choice::from_bit(1)
+    }
+}
+
+impl<A, B> GConstantTimeEq for Prod<A, B>
+where
+    A: GConstantTimeEq,
+    B: GConstantTimeEq,
+{
+    fn gct_eq(&self, other: &Self) -> Choice {
+        let (Prod(a, b), Prod(other_a, other_b)) = (self, other);
+        a.gct_eq(other_a) & b.gct_eq(other_b)
+    }
+}
+
+impl<L, R> GConstantTimeEq for Sum<L, R>
+where
+    L: GConstantTimeEq,
+    R: GConstantTimeEq,
+{
+    fn gct_eq(&self, other: &Self) -> Choice {
+        match (self, other) {
+            (Sum::Left(l), Sum::Left(other)) => l.gct_eq(other),
+            (Sum::Right(r), Sum::Right(other)) => r.gct_eq(other),
+            _ => Choice::from_bit(0),
+        }
+    }
+}
+
+impl GConstantTimeEq for Void {
+    fn gct_eq(&self, _other: &Self) -> Choice {
+        match *self {}
+    }
+}
+
+impl<I, M> GConstantTimeEq for Meta<I, M>
+where
+    I: GConstantTimeEq,
+    M: Singleton,
+{
+    fn gct_eq(&self, other: &Self) -> Choice {
+        let (Meta(inner, _), Meta(other, _)) = (self, other);
+        inner.gct_eq(other)
+    }
+}
+
+impl<I, C> GConstantTimeEq for Con<I, C>
+where
+    I: GConstantTimeEq,
+    C: Singleton,
+{
+    fn gct_eq(&self, other: &Self) -> Choice {
+        let (Con(inner, _), Con(other, _)) = (self, other);
+        inner.gct_eq(other)
+    }
+}
+
+impl<A> GConstantTimeEq for List<A>
+where
+    A: GConstantTimeEq,
+{
+    fn gct_eq(&self, other: &Self) -> Choice {
+        let (List(items), List(other)) = (self, other);
+        if items.len() != other.len() {
+            return Choice::from_bit(0);
+        }
+        let mut eq = Choice::from_bit(1);
+        for (a, b) in items.iter().zip(other) {
+            eq &= a.gct_eq(b);
+        }
+        eq
+    }
+}
+
+impl<A, const N: usize> GConstantTimeEq for [A; N]
+where
+    A: GConstantTimeEq,
+{
+    fn gct_eq(&self, other: &Self) -> Choice {
+        let mut eq = Choice::from_bit(1);
+        for (a, b) in self.iter().zip(other) {
+            eq &= a.gct_eq(b);
+        }
+        eq
+    }
+}
+
+impl<T> GConstantTimeEq for Borrowed<'_, T>
+where
+    T: ConstantTimeEq,
+{
+    fn gct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(other.0)
+    }
+}
+
+impl<T> GConstantTimeEq for T
+where
+    T: Leaf + ConstantTimeEq,
+{
+    fn gct_eq(&self, other: &Self) -> Choice {
+        self.ct_eq(other)
+    }
+}
@@ -0,0 +1,243 @@
+use std::fmt::{self, Debug};
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+use super::Borrowed;
+
+/// Formats a representation like `#[derive(Debug)]` would format the original value.
+///
+/// Constructors are formatted with their name, and their fields as a struct if named, or a tuple otherwise.
+/// Leaves are formatted with their own `Debug` impl, and lists and arrays as lists.
+/// `Sensitive` leaves are formatted as `***`, so their values never appear in the output.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::gdebug;
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct Inner(u8, bool);
+///
+/// #[derive(Generic)]
+/// enum Foo {
+///     A { a: u8, inner: Inner },
+///     B,
+/// }
+///
+/// let foo = Foo::A { a: 1, inner: Inner(2, true) };
+/// assert_eq!(format!("{:?}", gdebug(foo)), "A { a: 1, inner: Inner(2, true) }");
+/// assert_eq!(format!("{:?}", gdebug(Foo::B)), "B");
+/// ```
+pub trait GDebug {
+    /// Formats `self`.
+    fn fmt_repr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// The name of the field holding `self`, if `self` is a `Meta`.
+    fn field_name(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Collects the elements of a `Prod` chain.
+pub trait GDebugFields {
+    /// Appends each element of `self` to `fields`.
+    fn collect_fields<'a>(&'a self, fields: &mut Vec<&'a dyn GDebug>);
+}
+
+/// Adapts a `GDebug` to `Debug`.
+struct Adapter<'a>(&'a dyn GDebug);
+
+impl Debug for Adapter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_repr(f)
+    }
+}
+
+impl GDebugFields for Unit {
+    fn collect_fields<'a>(&'a self, _fields: &mut Vec<&'a dyn GDebug>) {}
+}
+
+impl<A, B> GDebugFields for Prod<A, B>
+where
+    A: GDebug,
+    B: GDebugFields,
+{
+    fn collect_fields<'a>(&'a self, fields: &mut Vec<&'a dyn GDebug>) {
+        let Prod(a, b) = self;
+        fields.push(a);
+        b.collect_fields(fields);
+    }
+}
+
+/// Formats `fields` as a struct if they're named, or a tuple otherwise.
+fn fmt_fields(name: &str, fields: &[&dyn GDebug], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    // Tuple fields are named by their index.
+    let is_named = |field: &&dyn GDebug| match field.field_name() {
+        Some(name) => name.parse::<usize>().is_err(),
+        None => false,
+    };
+    if fields.is_empty() {
+        f.write_str(name)
+    } else if fields.iter().all(is_named) {
+        let mut builder = f.debug_struct(name);
+        for field in fields {
+            builder.field(field.field_name().unwrap_or_default(), &Adapter(*field));
+        }
+        builder.finish()
+    } else {
+        let mut builder = f.debug_tuple(name);
+        for field in fields {
+            builder.field(&Adapter(*field));
+        }
+        builder.finish()
+    }
+}
+
+/// A bare `Unit` is the representation of `()`.
+impl GDebug for Unit {
+    fn fmt_repr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("()")
+    }
+}
+
+/// A bare `Prod` is the representation of a tuple.
+impl<A, B> GDebug for Prod<A, B>
+where
+    Self: GDebugFields,
+{
+    fn fmt_repr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut fields = Vec::new();
+        self.collect_fields(&mut fields);
+        fmt_fields("", &fields, f)
+    }
+}
+
+impl<L, R> GDebug for Sum<L, R>
+where
+    L: GDebug,
+    R: GDebug,
+{
+    fn fmt_repr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sum::Left(l) => l.fmt_repr(f),
+            Sum::Right(r) => r.fmt_repr(f),
+        }
+    }
+}
+
+impl GDebug for Void {
+    fn fmt_repr(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {}
+    }
+}
+
+impl<I, M> GDebug for Meta<I, M>
+where
+    I: GDebug,
+    M: Singleton<T = &'static str>,
+{
+    fn fmt_repr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Meta(inner, _) = self;
+        inner.fmt_repr(f)
+    }
+
+    fn field_name(&self) -> Option<&'static str> {
+        Some(M::get())
+    }
+}
+
+impl<I, C> GDebug for Con<I, C>
+where
+    I: GDebugFields,
+    C: Singleton<T = &'static str>,
+{
+    fn fmt_repr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Con(inner, _) = self;
+        let mut fields = Vec::new();
+        inner.collect_fields(&mut fields);
+        fmt_fields(C::get(), &fields, f)
+    }
+}
+
+impl<A> GDebug for List<A>
+where
+    A: GDebug,
+{
+    fn fmt_repr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let List(items) = self;
+        f.debug_list()
+            .entries(items.iter().map(|item| Adapter(item)))
+            .finish()
+    }
+}
+
+impl<A, const N: usize> GDebug for [A; N]
+where
+    A: GDebug,
+{
+    fn fmt_repr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(|item| Adapter(item)))
+            .finish()
+    }
+}
+
+impl<T> GDebug for T
+where
+    T: Leaf + Debug,
+{
+    fn fmt_repr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl<T> GDebug for Borrowed<'_, T>
+where
+    T: Debug,
+{
+    fn fmt_repr(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.0, f)
+    }
+}
+
+/// Converts `x` into its representation, which can be formatted with `{:?}` like `#[derive(Debug)]` would format `x`.
+///
+/// This can be used to implement `Debug` via `Generic`:
+///
+/// ```rust
+/// use std::fmt;
+/// use generics::ops::gdebug;
+/// use generics::Generic;
+///
+/// #[derive(Generic, Clone)]
+/// struct Foo {
+///     a: u8,
+/// }
+///
+/// impl fmt::Debug for Foo {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         gdebug(self.clone()).fmt(f)
+///     }
+/// }
+///
+/// assert_eq!(format!("{:?}", Foo { a: 1 }), "Foo { a: 1 }");
+/// ```
+pub fn gdebug<T>(x: T) -> impl Debug
+where
+    T: Generic,
+    T::Repr: GDebug,
+{
+    struct Repr<R>(R);
+
+    impl<R> Debug for Repr<R>
+    where
+        R: GDebug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt_repr(f)
+        }
+    }
+
+    Repr(x.into_repr())
+}
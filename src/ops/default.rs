@@ -0,0 +1,126 @@
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, ReprError, Singleton, Sum, Unit};
+
+/// Constructs a default representation, node by node.
+///
+/// Products default each of their fields, and sums default their first variant.
+/// Lists are empty, and leaves use their own `Default` impl. `Void` has no default, since it has no values.
+///
+/// `#[derive(GenericDefault)]` uses this to implement `Default`, alongside `#[derive(Generic)]`.
+/// The default variant of an enum can be chosen with `#[generic(default)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::GenericDefault;
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericDefault, Debug, PartialEq)]
+/// enum Foo {
+///     A(u8),
+///     #[generic(default)]
+///     B { b: String, c: Option<u8> },
+/// }
+///
+/// assert_eq!(Foo::default(), Foo::B { b: String::new(), c: None });
+/// ```
+pub trait GDefault {
+    /// Constructs the default representation.
+    fn gdefault() -> Self;
+}
+
+impl GDefault for Unit {
+    fn gdefault() -> Self {
+        Unit
+    }
+}
+
+impl<A, B> GDefault for Prod<A, B>
+where
+    A: GDefault,
+    B: GDefault,
+{
+    fn gdefault() -> Self {
+        Prod(A::gdefault(), B::gdefault())
+    }
+}
+
+impl<L, R> GDefault for Sum<L, R>
+where
+    L: GDefault,
+{
+    fn gdefault() -> Self {
+        Sum::Left(L::gdefault())
+    }
+}
+
+impl<I, M> GDefault for Meta<I, M>
+where
+    I: GDefault,
+    M: Singleton,
+{
+    fn gdefault() -> Self {
+        Meta(I::gdefault(), PhantomData)
+    }
+}
+
+impl<I, C> GDefault for Con<I, C>
+where
+    I: GDefault,
+    C: Singleton,
+{
+    fn gdefault() -> Self {
+        Con(I::gdefault(), PhantomData)
+    }
+}
+
+impl<A> GDefault for List<A> {
+    fn gdefault() -> Self {
+        List(Vec::new())
+    }
+}
+
+impl<A, const N: usize> GDefault for [A; N]
+where
+    A: GDefault,
+{
+    fn gdefault() -> Self {
+        [(); N].map(|()| A::gdefault())
+    }
+}
+
+impl<T> GDefault for T
+where
+    T: Leaf + Default,
+{
+    fn gdefault() -> Self {
+        T::default()
+    }
+}
+
+/// Constructs a default value from the default representation.
+///
+/// This fails if `T` rejects the default representation, e.g. `NonZeroU8`, whose representation defaults to zero.
+///
+/// ```rust
+/// use generics::ops::gdefault;
+/// use generics::Generic;
+/// use std::num::NonZeroU8;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Foo {
+///     a: u8,
+///     b: Vec<String>,
+/// }
+///
+/// assert_eq!(gdefault::<Foo>(), Ok(Foo { a: 0, b: vec![] }));
+/// assert!(gdefault::<NonZeroU8>().is_err());
+/// ```
+pub fn gdefault<T>() -> Result<T, ReprError>
+where
+    T: Generic,
+    T::Repr: GDefault,
+{
+    T::checked_from_repr(T::Repr::gdefault())
+}
@@ -0,0 +1,77 @@
+use serde::de;
+use serde::{Deserialize, Deserializer};
+
+use crate::{Generic, TryFromRepr};
+
+/// Deserializes a `T` from `deserializer`, as `#[derive(Deserialize)]` would.
+///
+/// This deserializes the representation with its `Deserialize` impl from `generics::serde`, which makes the same calls
+/// to a `Deserializer` as serde's derive on the original type, so it accepts the output of `serialize_generic`,
+/// or of serde's derive: structs and enums are deserialized by the names of their constructors and fields, taken from their metadata,
+/// tuple structs and variants as tuples, or newtypes if they have one field, and `Option` as an option.
+/// Like serde's derive, structs can also be deserialized from sequences, and unknown fields are ignored.
+///
+/// `try_deserialize_generic` additionally validates the value with its `TryFromRepr` impl,
+/// so `#[derive(Generic)]` covers both directions of serde.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{deserialize_generic, try_deserialize_generic};
+/// use generics::{Generic, TryFromRepr};
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Window {
+///     title: String,
+///     size: Size,
+///     state: State,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Size(u16, u16);
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum State {
+///     Hidden,
+///     Docked(Option<u8>),
+/// }
+///
+/// impl TryFromRepr for Window {
+///     type Error = &'static str;
+///     fn try_from_repr(repr: Self::Repr) -> Result<Self, Self::Error> {
+///         let window = Window::from_repr(repr);
+///         if window.title.is_empty() {
+///             return Err("empty title");
+///         }
+///         Ok(window)
+///     }
+/// }
+///
+/// let json = r#"{"title":"main","size":[640,480],"state":{"Docked":null}}"#;
+/// let window: Window = deserialize_generic(&mut serde_json::Deserializer::from_str(json)).unwrap();
+/// assert_eq!(window, Window { title: "main".to_string(), size: Size(640, 480), state: State::Docked(None) });
+///
+/// let json = r#"{"title":"","size":[0,0],"state":"Hidden"}"#;
+/// let err = try_deserialize_generic::<Window, _>(&mut serde_json::Deserializer::from_str(json)).unwrap_err();
+/// assert_eq!(err.to_string(), "empty title");
+/// ```
+pub fn deserialize_generic<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Generic,
+    T::Repr: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let repr = T::Repr::deserialize(deserializer)?;
+    T::checked_from_repr(repr).map_err(de::Error::custom)
+}
+
+/// Deserializes a `T` from `deserializer`, as `#[derive(Deserialize)]` would, then validates it with `TryFromRepr`.
+pub fn try_deserialize_generic<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: TryFromRepr,
+    T::Repr: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let repr = T::Repr::deserialize(deserializer)?;
+    T::try_from_repr(repr).map_err(de::Error::custom)
+}
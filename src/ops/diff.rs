@@ -0,0 +1,364 @@
+use std::fmt::{self, Debug, Display};
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+use super::GDebug;
+
+/// Compares two representations of the same type, producing a `Diff` tree.
+///
+/// Leaves are compared with their own `PartialEq` impl, and formatted with `Debug` if they changed.
+/// Constructors and lists are diffed child by child, and collapse to `Diff::Unchanged` if none of their children changed.
+/// If the representations hold different variants, the whole values are recorded with `GDebug`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{diff, Diff};
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct Inner {
+///     b: u8,
+///     c: Vec<u8>,
+/// }
+///
+/// #[derive(Generic)]
+/// struct Foo {
+///     a: Option<u8>,
+///     inner: Inner,
+/// }
+///
+/// let old = Foo { a: Some(1), inner: Inner { b: 2, c: vec![3] } };
+/// let new = Foo { a: None, inner: Inner { b: 4, c: vec![3, 5] } };
+/// assert_eq!(
+///     diff(old, new).to_string(),
+///     "a: Some(1) -> None\ninner.b: 2 -> 4\ninner.c[1]: added 5",
+/// );
+///
+/// let same = Foo { a: None, inner: Inner { b: 0, c: vec![] } };
+/// let also_same = Foo { a: None, inner: Inner { b: 0, c: vec![] } };
+/// assert_eq!(diff(same, also_same), Diff::Unchanged);
+/// ```
+pub trait GDiff: Sized {
+    /// Diffs `self`, the old value, against `other`, the new value.
+    fn gdiff(self, other: Self) -> Diff;
+
+    /// The name of the field holding values of this type, if this is a `Meta`.
+    fn field_name() -> Option<&'static str> {
+        None
+    }
+}
+
+/// Diffs the elements of a `Prod` chain.
+pub trait GDiffFields: Sized {
+    /// Appends the diff of each element of `self` against `other` to `fields`.
+    fn gdiff_fields(self, other: Self, fields: &mut Vec<(Segment, Diff)>);
+}
+
+/// The difference between two values, as produced by `GDiff`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diff {
+    /// The values are equal.
+    Unchanged,
+    /// A leaf changed, formatted with `Debug`.
+    Leaf {
+        /// The old value.
+        old: String,
+        /// The new value.
+        new: String,
+    },
+    /// The values hold different variants, formatted with `GDebug`.
+    Variant {
+        /// The old value.
+        old: String,
+        /// The new value.
+        new: String,
+    },
+    /// An item was added to the end of a list.
+    Added(String),
+    /// An item was removed from the end of a list.
+    Removed(String),
+    /// Some fields of a constructor, or items of a list or array, changed.
+    ///
+    /// Every child is listed, including those which are `Unchanged`.
+    Fields(Vec<(Segment, Diff)>),
+}
+
+/// A step in the path to a change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segment {
+    /// A named field of a constructor, including the numbered fields of tuple structs.
+    Field(&'static str),
+    /// An item of a list or array, or an element of a tuple.
+    Index(usize),
+}
+
+impl Diff {
+    /// Whether the values were equal.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Diff::Unchanged)
+    }
+
+    /// Every change in the tree, other than `Diff::Fields`, along with its path, e.g. `a.b[2]`.
+    ///
+    /// The path of a change to the whole value is empty.
+    pub fn changes(&self) -> Vec<(String, &Diff)> {
+        let mut changes = Vec::new();
+        self.collect_changes(String::new(), &mut changes);
+        changes
+    }
+
+    fn collect_changes<'a>(&'a self, path: String, changes: &mut Vec<(String, &'a Diff)>) {
+        match self {
+            Diff::Unchanged => {}
+            Diff::Fields(fields) => {
+                for (segment, diff) in fields {
+                    let path = match segment {
+                        Segment::Field(name) if path.is_empty() => name.to_string(),
+                        Segment::Field(name) => format!("{}.{}", path, name),
+                        Segment::Index(i) => format!("{}[{}]", path, i),
+                    };
+                    diff.collect_changes(path, changes);
+                }
+            }
+            _ => changes.push((path, self)),
+        }
+    }
+
+    /// Collapses `fields` to `Diff::Unchanged` if none of them changed.
+    fn fields(fields: Vec<(Segment, Diff)>) -> Self {
+        if fields.iter().all(|(_, diff)| diff.is_unchanged()) {
+            Diff::Unchanged
+        } else {
+            Diff::Fields(fields)
+        }
+    }
+}
+
+impl Display for Diff {
+    /// Formats each change on its own line, prefixed by its path.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (path, diff)) in self.changes().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            if !path.is_empty() {
+                write!(f, "{}: ", path)?;
+            }
+            match diff {
+                Diff::Leaf { old, new } | Diff::Variant { old, new } => {
+                    write!(f, "{} -> {}", old, new)?
+                }
+                Diff::Added(new) => write!(f, "added {}", new)?,
+                Diff::Removed(old) => write!(f, "removed {}", old)?,
+                Diff::Unchanged | Diff::Fields(_) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Panics with the differences between `left` and `right`, for `assert_generic_eq!`.
+///
+/// Each change is listed by its path, with the differing values on the left and right,
+/// instead of formatting both values in full.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_generic_eq_failed<T>(left: &T, right: &T, args: Option<fmt::Arguments<'_>>) -> !
+where
+    T: Generic + Clone,
+    T::Repr: GDiff,
+{
+    let mut message = String::from("assertion `left == right` failed");
+    if let Some(args) = args {
+        message.push_str(&format!(": {}", args));
+    }
+    let diff = diff(left.clone(), right.clone());
+    if diff.is_unchanged() {
+        message.push_str("\n  the values are unequal, but every leaf is equal");
+    }
+    for (path, change) in diff.changes() {
+        let path = if path.is_empty() { "<root>" } else { &path };
+        let (left, right) = match change {
+            Diff::Leaf { old, new } | Diff::Variant { old, new } => (old.as_str(), new.as_str()),
+            Diff::Added(new) => ("<missing>", new.as_str()),
+            Diff::Removed(old) => (old.as_str(), "<missing>"),
+            Diff::Unchanged | Diff::Fields(_) => continue,
+        };
+        message.push_str(&format!(
+            "\n  at {}:\n     left: {}\n    right: {}",
+            path, left, right
+        ));
+    }
+    panic!("{}", message)
+}
+
+/// Formats a representation with `GDebug`.
+fn debug_string<R>(repr: &R) -> String
+where
+    R: GDebug,
+{
+    struct Adapter<'a, R>(&'a R);
+
+    impl<R> Debug for Adapter<'_, R>
+    where
+        R: GDebug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt_repr(f)
+        }
+    }
+
+    format!("{:?}", Adapter(repr))
+}
+
+impl GDiffFields for Unit {
+    fn gdiff_fields(self, _other: Self, _fields: &mut Vec<(Segment, Diff)>) {}
+}
+
+impl<A, B> GDiffFields for Prod<A, B>
+where
+    A: GDiff,
+    B: GDiffFields,
+{
+    fn gdiff_fields(self, other: Self, fields: &mut Vec<(Segment, Diff)>) {
+        let (Prod(a, b), Prod(other_a, other_b)) = (self, other);
+        let segment = match A::field_name() {
+            Some(name) => Segment::Field(name),
+            None => Segment::Index(fields.len()),
+        };
+        fields.push((segment, a.gdiff(other_a)));
+        b.gdiff_fields(other_b, fields);
+    }
+}
+
+/// A bare `Unit` is the representation of `()`.
+impl GDiff for Unit {
+    fn gdiff(self, _other: Self) -> Diff {
+        Diff::Unchanged
+    }
+}
+
+/// A bare `Prod` is the representation of a tuple.
+impl<A, B> GDiff for Prod<A, B>
+where
+    Self: GDiffFields,
+{
+    fn gdiff(self, other: Self) -> Diff {
+        let mut fields = Vec::new();
+        self.gdiff_fields(other, &mut fields);
+        Diff::fields(fields)
+    }
+}
+
+impl<L, R> GDiff for Sum<L, R>
+where
+    L: GDiff + GDebug,
+    R: GDiff + GDebug,
+{
+    fn gdiff(self, other: Self) -> Diff {
+        match (self, other) {
+            (Sum::Left(l), Sum::Left(other)) => l.gdiff(other),
+            (Sum::Right(r), Sum::Right(other)) => r.gdiff(other),
+            (old, new) => Diff::Variant {
+                old: debug_string(&old),
+                new: debug_string(&new),
+            },
+        }
+    }
+}
+
+impl GDiff for Void {
+    fn gdiff(self, _other: Self) -> Diff {
+        match self {}
+    }
+}
+
+impl<I, M> GDiff for Meta<I, M>
+where
+    I: GDiff,
+    M: Singleton<T = &'static str>,
+{
+    fn gdiff(self, other: Self) -> Diff {
+        let (Meta(inner, _), Meta(other, _)) = (self, other);
+        inner.gdiff(other)
+    }
+
+    fn field_name() -> Option<&'static str> {
+        Some(M::get())
+    }
+}
+
+impl<I, C> GDiff for Con<I, C>
+where
+    I: GDiffFields,
+    C: Singleton,
+{
+    fn gdiff(self, other: Self) -> Diff {
+        let (Con(inner, _), Con(other, _)) = (self, other);
+        let mut fields = Vec::new();
+        inner.gdiff_fields(other, &mut fields);
+        Diff::fields(fields)
+    }
+}
+
+/// Items are diffed by index, so items added or removed from the end of the list are recorded as such.
+impl<A> GDiff for List<A>
+where
+    A: GDiff + GDebug,
+{
+    fn gdiff(self, other: Self) -> Diff {
+        let (List(items), List(other)) = (self, other);
+        let (mut items, mut other) = (items.into_iter(), other.into_iter());
+        let mut fields = Vec::new();
+        loop {
+            let diff = match (items.next(), other.next()) {
+                (Some(old), Some(new)) => old.gdiff(new),
+                (Some(old), None) => Diff::Removed(debug_string(&old)),
+                (None, Some(new)) => Diff::Added(debug_string(&new)),
+                (None, None) => break,
+            };
+            fields.push((Segment::Index(fields.len()), diff));
+        }
+        Diff::fields(fields)
+    }
+}
+
+impl<A, const N: usize> GDiff for [A; N]
+where
+    A: GDiff,
+{
+    fn gdiff(self, other: Self) -> Diff {
+        let fields = IntoIterator::into_iter(self)
+            .zip(IntoIterator::into_iter(other))
+            .enumerate()
+            .map(|(i, (old, new))| (Segment::Index(i), old.gdiff(new)))
+            .collect();
+        Diff::fields(fields)
+    }
+}
+
+impl<T> GDiff for T
+where
+    T: Leaf + PartialEq + Debug,
+{
+    fn gdiff(self, other: Self) -> Diff {
+        if self == other {
+            Diff::Unchanged
+        } else {
+            Diff::Leaf {
+                old: format!("{:?}", self),
+                new: format!("{:?}", other),
+            }
+        }
+    }
+}
+
+/// Diffs the representation of `old` against that of `new`.
+pub fn diff<T>(old: T, new: T) -> Diff
+where
+    T: Generic,
+    T::Repr: GDiff,
+{
+    old.into_repr().gdiff(new.into_repr())
+}
@@ -0,0 +1,536 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::env;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::markers::{Option_None_Name, Option_Some_0, Option_Some_Name};
+use crate::{
+    Con, FieldMeta, Generic, Leaf, List, Meta, Prod, ReprError, Singleton, Sum, Unit, Void,
+};
+
+/// Reads a representation from environment variables, using its metadata.
+///
+/// Each leaf is read from the variable named by the path of field names leading to it, uppercased
+/// and joined by `_` after a prefix, so `db.max_conns` with the prefix `APP` is read from `APP_DB_MAX_CONNS`,
+/// and tuples and arrays from variables like `APP_PAIR_0`.
+/// Leaves are parsed with `FromStr`, enums whose variants have no fields by the name of their variant,
+/// and lists as comma-separated elements, where an empty variable is an empty list.
+/// `Option` is `None` when its variable is unset. Enums with fields have no impl, since they aren't flat.
+///
+/// When a field's variable is unset, its `#[generic(env_default = "...")]` is parsed instead, via `FieldMeta`.
+/// Every missing or invalid variable is collected into a single `EnvError`, rather than stopping at the first.
+/// Only the `env` feature is required, which has no dependencies.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{from_vars, EnvError};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Config {
+///     #[generic(env_default = "8080")]
+///     port: u16,
+///     log: Level,
+///     db: Db,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum Level {
+///     Info,
+///     Debug,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Db {
+///     url: String,
+///     max_conns: Option<u32>,
+/// }
+///
+/// let vars = vec![
+///     ("APP_LOG".to_string(), "Debug".to_string()),
+///     ("APP_DB_URL".to_string(), "postgres://localhost".to_string()),
+/// ];
+/// let config = from_vars::<Config, _>("APP", vars).unwrap();
+/// assert_eq!(
+///     config,
+///     Config {
+///         port: 8080,
+///         log: Level::Debug,
+///         db: Db { url: "postgres://localhost".to_string(), max_conns: None },
+///     }
+/// );
+///
+/// let vars = vec![("APP_PORT".to_string(), "http".to_string())];
+/// let err = from_vars::<Config, _>("APP", vars).unwrap_err();
+/// assert_eq!(
+///     err,
+///     EnvError {
+///         missing: vec!["APP_LOG".to_string(), "APP_DB_URL".to_string()],
+///         invalid: vec!["APP_PORT".to_string()],
+///         repr: None,
+///     }
+/// );
+/// assert_eq!(
+///     err.to_string(),
+///     "missing environment variables `APP_LOG`, `APP_DB_URL`; invalid environment variables `APP_PORT`"
+/// );
+/// ```
+pub trait GEnv: Sized {
+    /// Reads `Self` from the variables whose names begin with `name`, falling back to `default` if a leaf's is unset.
+    ///
+    /// Returns `None` if any variable was missing or invalid, after recording it in `error`.
+    fn gfrom_env(
+        name: &mut String,
+        vars: &HashMap<String, String>,
+        default: Option<&'static str>,
+        error: &mut EnvError,
+    ) -> Option<Self>;
+}
+
+/// Reads the elements of a `Prod` chain from environment variables.
+pub trait GEnvElements: Sized {
+    /// Reads each element from the variables whose names begin with `name` and its index, from `index`.
+    fn gfrom_env_elements(
+        index: usize,
+        name: &mut String,
+        vars: &HashMap<String, String>,
+        error: &mut EnvError,
+    ) -> Option<Self>;
+}
+
+/// Reads the fields of a constructor, i.e. a `Prod` chain of `Meta`s, from environment variables.
+pub trait GEnvFields: Sized {
+    /// Reads each field from the variables whose names begin with `name` and the field's name.
+    fn gfrom_env_fields(
+        name: &mut String,
+        vars: &HashMap<String, String>,
+        error: &mut EnvError,
+    ) -> Option<Self>;
+}
+
+/// Parses a representation from the value of a single environment variable.
+pub trait GEnvValue: Sized {
+    /// Parses the value of a variable, or returns `None` if it's invalid.
+    fn gfrom_value(value: &str) -> Option<Self>;
+
+    /// The value when the variable is unset, e.g. `None` for `Option`, or `None` if it's required.
+    fn gabsent() -> Option<Self> {
+        None
+    }
+}
+
+/// Parses whichever variant of a `Sum` chain is named by the value of an environment variable.
+pub trait GEnvVariant: Sized {
+    /// Parses the value of a variable as the variant it names, or returns `None` if it names none.
+    fn gfrom_variant(value: &str) -> Option<Self>;
+
+    /// The variant when the variable is unset, i.e. `None` for `Option`, or `None` if there is none.
+    fn gabsent_variant() -> Option<Self>;
+}
+
+/// The environment variables which were missing or invalid when reading a value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnvError {
+    /// The names of the variables which were unset, and had no default.
+    pub missing: Vec<String>,
+    /// The names of the variables whose values couldn't be parsed.
+    ///
+    /// Their values aren't included, since environment variables often hold secrets.
+    pub invalid: Vec<String>,
+    /// Why the variables, though each parsed, made a value invalid for its type, e.g. a `NonZeroU8` of zero.
+    pub repr: Option<ReprError>,
+}
+
+impl Display for EnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups = [("missing", &self.missing), ("invalid", &self.invalid)];
+        let groups = groups.iter().filter(|(_, names)| !names.is_empty());
+        for (i, (kind, names)) in groups.enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{} environment variables ", kind)?;
+            for (j, name) in names.iter().enumerate() {
+                if j > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "`{}`", name)?;
+            }
+        }
+        if let Some(error) = &self.repr {
+            write!(f, "invalid value: {}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for EnvError {}
+
+/// Appends `segment` to `name`, uppercased and after a `_`, returning the previous length of `name`.
+fn push_segment(name: &mut String, segment: &str) -> usize {
+    let len = name.len();
+    if !name.is_empty() {
+        name.push('_');
+    }
+    name.push_str(&segment.to_uppercase());
+    len
+}
+
+/// Reads a `GEnvValue` from the variable `name`, or `default` if it's unset.
+fn value_from_env<T>(
+    name: &str,
+    vars: &HashMap<String, String>,
+    default: Option<&'static str>,
+    error: &mut EnvError,
+) -> Option<T>
+where
+    T: GEnvValue,
+{
+    match vars.get(name).map(String::as_str).or(default) {
+        Some(value) => {
+            let value = T::gfrom_value(value);
+            if value.is_none() {
+                error.invalid.push(name.to_string());
+            }
+            value
+        }
+        None => {
+            let value = T::gabsent();
+            if value.is_none() {
+                error.missing.push(name.to_string());
+            }
+            value
+        }
+    }
+}
+
+impl GEnv for Unit {
+    fn gfrom_env(
+        _name: &mut String,
+        _vars: &HashMap<String, String>,
+        _default: Option<&'static str>,
+        _error: &mut EnvError,
+    ) -> Option<Self> {
+        Some(Unit)
+    }
+}
+
+impl<A, B> GEnv for Prod<A, B>
+where
+    Self: GEnvElements,
+{
+    fn gfrom_env(
+        name: &mut String,
+        vars: &HashMap<String, String>,
+        _default: Option<&'static str>,
+        error: &mut EnvError,
+    ) -> Option<Self> {
+        Self::gfrom_env_elements(0, name, vars, error)
+    }
+}
+
+impl GEnvElements for Unit {
+    fn gfrom_env_elements(
+        _index: usize,
+        _name: &mut String,
+        _vars: &HashMap<String, String>,
+        _error: &mut EnvError,
+    ) -> Option<Self> {
+        Some(Unit)
+    }
+}
+
+impl<A, B> GEnvElements for Prod<A, B>
+where
+    A: GEnv,
+    B: GEnvElements,
+{
+    fn gfrom_env_elements(
+        index: usize,
+        name: &mut String,
+        vars: &HashMap<String, String>,
+        error: &mut EnvError,
+    ) -> Option<Self> {
+        let len = push_segment(name, &index.to_string());
+        let a = A::gfrom_env(name, vars, None, error);
+        name.truncate(len);
+        // Read the rest even if this element failed, to report every bad variable.
+        let b = B::gfrom_env_elements(index + 1, name, vars, error);
+        Some(Prod(a?, b?))
+    }
+}
+
+impl<I, M> GEnv for Meta<I, M>
+where
+    I: GEnv,
+    M: Singleton,
+{
+    fn gfrom_env(
+        name: &mut String,
+        vars: &HashMap<String, String>,
+        default: Option<&'static str>,
+        error: &mut EnvError,
+    ) -> Option<Self> {
+        I::gfrom_env(name, vars, default, error).map(|inner| Meta(inner, PhantomData))
+    }
+}
+
+impl GEnvFields for Unit {
+    fn gfrom_env_fields(
+        _name: &mut String,
+        _vars: &HashMap<String, String>,
+        _error: &mut EnvError,
+    ) -> Option<Self> {
+        Some(Unit)
+    }
+}
+
+impl<A, M, B> GEnvFields for Prod<Meta<A, M>, B>
+where
+    A: GEnv,
+    M: Singleton<T = &'static str> + FieldMeta,
+    B: GEnvFields,
+{
+    fn gfrom_env_fields(
+        name: &mut String,
+        vars: &HashMap<String, String>,
+        error: &mut EnvError,
+    ) -> Option<Self> {
+        let len = push_segment(name, M::get());
+        let a = A::gfrom_env(name, vars, M::env_default(), error);
+        name.truncate(len);
+        let b = B::gfrom_env_fields(name, vars, error);
+        Some(Prod(Meta(a?, PhantomData), b?))
+    }
+}
+
+impl<I, C> GEnv for Con<I, C>
+where
+    I: GEnvFields,
+    C: Singleton,
+{
+    fn gfrom_env(
+        name: &mut String,
+        vars: &HashMap<String, String>,
+        _default: Option<&'static str>,
+        error: &mut EnvError,
+    ) -> Option<Self> {
+        I::gfrom_env_fields(name, vars, error).map(|inner| Con(inner, PhantomData))
+    }
+}
+
+impl<A, const N: usize> GEnv for [A; N]
+where
+    A: GEnv,
+{
+    fn gfrom_env(
+        name: &mut String,
+        vars: &HashMap<String, String>,
+        _default: Option<&'static str>,
+        error: &mut EnvError,
+    ) -> Option<Self> {
+        let items = (0..N)
+            .map(|i| {
+                let len = push_segment(name, &i.to_string());
+                let item = A::gfrom_env(name, vars, None, error);
+                name.truncate(len);
+                item
+            })
+            .collect::<Vec<_>>();
+        let items = items.into_iter().collect::<Option<Vec<_>>>()?;
+        Some(
+            items
+                .try_into()
+                .unwrap_or_else(|_| unreachable!("read `N` items")),
+        )
+    }
+}
+
+impl<L, R> GEnv for Sum<L, R>
+where
+    Self: GEnvValue,
+{
+    fn gfrom_env(
+        name: &mut String,
+        vars: &HashMap<String, String>,
+        default: Option<&'static str>,
+        error: &mut EnvError,
+    ) -> Option<Self> {
+        value_from_env(name, vars, default, error)
+    }
+}
+
+impl<L, R> GEnvValue for Sum<L, R>
+where
+    Self: GEnvVariant,
+{
+    fn gfrom_value(value: &str) -> Option<Self> {
+        Self::gfrom_variant(value)
+    }
+
+    fn gabsent() -> Option<Self> {
+        Self::gabsent_variant()
+    }
+}
+
+/// A variant with no fields is named by its name, except for `None`, which is never named, but is the absent variant.
+impl<C, R> GEnvVariant for Sum<Con<Unit, C>, R>
+where
+    C: Singleton<T = &'static str> + 'static,
+    R: GEnvVariant,
+{
+    fn gfrom_variant(value: &str) -> Option<Self> {
+        if TypeId::of::<C>() != TypeId::of::<Option_None_Name>() && value == C::get() {
+            return Some(Sum::Left(Con(Unit, PhantomData)));
+        }
+        R::gfrom_variant(value).map(Sum::Right)
+    }
+
+    fn gabsent_variant() -> Option<Self> {
+        if TypeId::of::<C>() == TypeId::of::<Option_None_Name>() {
+            return Some(Sum::Left(Con(Unit, PhantomData)));
+        }
+        R::gabsent_variant().map(Sum::Right)
+    }
+}
+
+/// `Some` is parsed from the value of the variable.
+impl<A> GEnvVariant for Sum<Con<Prod<Meta<A, Option_Some_0>, Unit>, Option_Some_Name>, Void>
+where
+    A: GEnvValue,
+{
+    fn gfrom_variant(value: &str) -> Option<Self> {
+        let a = A::gfrom_value(value)?;
+        Some(Sum::Left(Con(
+            Prod(Meta(a, PhantomData), Unit),
+            PhantomData,
+        )))
+    }
+
+    fn gabsent_variant() -> Option<Self> {
+        None
+    }
+}
+
+impl GEnvVariant for Void {
+    fn gfrom_variant(_value: &str) -> Option<Self> {
+        None
+    }
+
+    fn gabsent_variant() -> Option<Self> {
+        None
+    }
+}
+
+impl<A> GEnv for List<A>
+where
+    A: GEnvValue,
+{
+    fn gfrom_env(
+        name: &mut String,
+        vars: &HashMap<String, String>,
+        default: Option<&'static str>,
+        error: &mut EnvError,
+    ) -> Option<Self> {
+        value_from_env(name, vars, default, error)
+    }
+}
+
+impl<A> GEnvValue for List<A>
+where
+    A: GEnvValue,
+{
+    fn gfrom_value(value: &str) -> Option<Self> {
+        if value.is_empty() {
+            return Some(List(Vec::new()));
+        }
+        value
+            .split(',')
+            .map(A::gfrom_value)
+            .collect::<Option<_>>()
+            .map(List)
+    }
+}
+
+impl<T> GEnvValue for T
+where
+    T: Leaf + FromStr,
+{
+    fn gfrom_value(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+}
+
+impl<T> GEnv for T
+where
+    T: Leaf + FromStr,
+{
+    fn gfrom_env(
+        name: &mut String,
+        vars: &HashMap<String, String>,
+        default: Option<&'static str>,
+        error: &mut EnvError,
+    ) -> Option<Self> {
+        value_from_env(name, vars, default, error)
+    }
+}
+
+/// Reads a `T` from the environment variables of the process, named by `prefix` and the path to each leaf.
+///
+/// If `prefix` is empty, the names have no prefix, e.g. `DB_URL` rather than `APP_DB_URL`.
+/// Variables whose names aren't Unicode are ignored, and values which aren't Unicode are converted lossily.
+///
+/// ```rust
+/// use generics::ops::from_env;
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Limits {
+///     #[generic(env_default = "4")]
+///     workers: usize,
+///     hosts: Vec<String>,
+/// }
+///
+/// std::env::set_var("LIMITS_DOCTEST_HOSTS", "a.example,b.example");
+/// let limits = from_env::<Limits>("LIMITS_DOCTEST").unwrap();
+/// assert_eq!(limits.workers, 4);
+/// assert_eq!(limits.hosts, ["a.example", "b.example"]);
+/// ```
+pub fn from_env<T>(prefix: &str) -> Result<T, EnvError>
+where
+    T: Generic,
+    T::Repr: GEnv,
+{
+    let vars = env::vars_os().filter_map(|(name, value)| {
+        let value = value.to_string_lossy().into_owned();
+        name.into_string().ok().map(|name| (name, value))
+    });
+    from_vars(prefix, vars)
+}
+
+/// Reads a `T` from pairs of variable names and values, as `from_env` does from the environment of the process.
+///
+/// Variables which don't correspond to any leaf of `T` are ignored.
+pub fn from_vars<T, I>(prefix: &str, vars: I) -> Result<T, EnvError>
+where
+    T: Generic,
+    T::Repr: GEnv,
+    I: IntoIterator<Item = (String, String)>,
+{
+    let vars = vars.into_iter().collect();
+    let mut error = EnvError::default();
+    match T::Repr::gfrom_env(&mut prefix.to_string(), &vars, None, &mut error) {
+        Some(repr) if error.missing.is_empty() && error.invalid.is_empty() => {
+            T::checked_from_repr(repr).map_err(|repr| EnvError {
+                repr: Some(repr),
+                ..error
+            })
+        }
+        _ => Err(error),
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{Con, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+use super::Borrowed;
+
+/// Compares representations for equality, node by node.
+///
+/// Products are equal if all their fields are equal, and sums if they hold the same variant with equal payloads.
+/// Leaves are compared with their own `PartialEq` impl.
+///
+/// `#[derive(GenericPartialEq)]` uses this to implement `PartialEq`, alongside `#[derive(Generic)]`,
+/// and `#[derive(GenericEq)]` implements `Eq` to match.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{GenericEq, GenericPartialEq};
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericPartialEq, GenericEq)]
+/// enum Foo {
+///     A { a: u8, b: String },
+///     B(Vec<u8>),
+/// }
+///
+/// assert!(Foo::B(vec![1]) == Foo::B(vec![1]));
+/// assert!(Foo::B(vec![1]) != Foo::B(vec![2]));
+/// assert!(Foo::A { a: 1, b: String::new() } != Foo::B(vec![]));
+/// ```
+pub trait GPartialEq {
+    /// Whether `self` and `other` are equal.
+    fn geq(&self, other: &Self) -> bool;
+
+    /// Whether `self` and `other` are not equal.
+    fn gne(&self, other: &Self) -> bool {
+        !self.geq(other)
+    }
+}
+
+/// Marks a `GPartialEq` as an equivalence relation, like `Eq`.
+pub trait GEq: GPartialEq {}
+
+impl GPartialEq for Unit {
+    fn geq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl GEq for Unit {}
+
+impl<A, B> GPartialEq for Prod<A, B>
+where
+    A: GPartialEq,
+    B: GPartialEq,
+{
+    fn geq(&self, other: &Self) -> bool {
+        let (Prod(a, b), Prod(other_a, other_b)) = (self, other);
+        a.geq(other_a) && b.geq(other_b)
+    }
+}
+
+impl<A, B> GEq for Prod<A, B>
+where
+    A: GEq,
+    B: GEq,
+{
+}
+
+impl<L, R> GPartialEq for Sum<L, R>
+where
+    L: GPartialEq,
+    R: GPartialEq,
+{
+    fn geq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Sum::Left(l), Sum::Left(other)) => l.geq(other),
+            (Sum::Right(r), Sum::Right(other)) => r.geq(other),
+            _ => false,
+        }
+    }
+}
+
+impl<L, R> GEq for Sum<L, R>
+where
+    L: GEq,
+    R: GEq,
+{
+}
+
+impl GPartialEq for Void {
+    fn geq(&self, _other: &Self) -> bool {
+        match *self {}
+    }
+}
+
+impl GEq for Void {}
+
+impl<I, M> GPartialEq for Meta<I, M>
+where
+    I: GPartialEq,
+    M: Singleton,
+{
+    fn geq(&self, other: &Self) -> bool {
+        let (Meta(inner, _), Meta(other, _)) = (self, other);
+        inner.geq(other)
+    }
+}
+
+impl<I, M> GEq for Meta<I, M>
+where
+    I: GEq,
+    M: Singleton,
+{
+}
+
+impl<I, C> GPartialEq for Con<I, C>
+where
+    I: GPartialEq,
+    C: Singleton,
+{
+    fn geq(&self, other: &Self) -> bool {
+        let (Con(inner, _), Con(other, _)) = (self, other);
+        inner.geq(other)
+    }
+}
+
+impl<I, C> GEq for Con<I, C>
+where
+    I: GEq,
+    C: Singleton,
+{
+}
+
+impl<A> GPartialEq for List<A>
+where
+    A: GPartialEq,
+{
+    fn geq(&self, other: &Self) -> bool {
+        let (List(items), List(other)) = (self, other);
+        items.len() == other.len() && items.iter().zip(other).all(|(a, b)| a.geq(b))
+    }
+}
+
+impl<A> GEq for List<A> where A: GEq {}
+
+impl<A, const N: usize> GPartialEq for [A; N]
+where
+    A: GPartialEq,
+{
+    fn geq(&self, other: &Self) -> bool {
+        self.iter().zip(other).all(|(a, b)| a.geq(b))
+    }
+}
+
+impl<A, const N: usize> GEq for [A; N] where A: GEq {}
+
+impl<T> GPartialEq for Borrowed<'_, T>
+where
+    T: PartialEq,
+{
+    fn geq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> GEq for Borrowed<'_, T> where T: Eq {}
+
+/// A leaf which is skipped by comparisons and hashing, i.e. always equal to any other.
+///
+/// `#[derive(GenericPartialEq)]`, and the derives which must agree with it, wrap each `#[generic(ignore_eq)]` field in this,
+/// so nondeterministic fields like timestamps or generated IDs don't affect equality.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::GenericPartialEq;
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericPartialEq, Debug)]
+/// struct Order {
+///     item: String,
+///     #[generic(ignore_eq)]
+///     created_at: u64,
+/// }
+///
+/// assert_eq!(
+///     Order { item: "tea".to_string(), created_at: 1 },
+///     Order { item: "tea".to_string(), created_at: 2 },
+/// );
+/// ```
+pub struct Ignored<T>(pub T);
+
+impl<T> GPartialEq for Ignored<T> {
+    fn geq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> GEq for Ignored<T> {}
+
+impl<T> GPartialEq for T
+where
+    T: Leaf + PartialEq,
+{
+    fn geq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl<T> GEq for T where T: Leaf + Eq {}
@@ -0,0 +1,166 @@
+use std::any::Any;
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+/// Applies a function to every leaf of type `T` in a representation, leaving other leaves as-is.
+///
+/// Like `GCollect`, leaves are matched by their exact type via `Any`, so only leaf types can be mapped.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::everywhere;
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct User {
+///     name: String,
+///     emails: Vec<String>,
+///     age: u8,
+/// }
+///
+/// let user = User {
+///     name: "Alice".to_string(),
+///     emails: vec!["ALICE@EXAMPLE.COM".to_string()],
+///     age: 30,
+/// };
+/// let user = everywhere(user, |s: String| s.to_lowercase());
+/// assert_eq!(user.name, "alice");
+/// assert_eq!(user.emails, ["alice@example.com"]);
+/// ```
+pub trait GEverywhere<T> {
+    /// Replaces every leaf `x` of type `T` in `self` with `f(x)`.
+    fn geverywhere<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(T) -> T;
+}
+
+impl<T> GEverywhere<T> for Unit {
+    fn geverywhere<F>(self, _f: &mut F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        Unit
+    }
+}
+
+impl<T, A, B> GEverywhere<T> for Prod<A, B>
+where
+    A: GEverywhere<T>,
+    B: GEverywhere<T>,
+{
+    fn geverywhere<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        let Prod(a, b) = self;
+        let a = a.geverywhere(f);
+        Prod(a, b.geverywhere(f))
+    }
+}
+
+impl<T, L, R> GEverywhere<T> for Sum<L, R>
+where
+    L: GEverywhere<T>,
+    R: GEverywhere<T>,
+{
+    fn geverywhere<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        match self {
+            Sum::Left(l) => Sum::Left(l.geverywhere(f)),
+            Sum::Right(r) => Sum::Right(r.geverywhere(f)),
+        }
+    }
+}
+
+impl<T> GEverywhere<T> for Void {
+    fn geverywhere<F>(self, _f: &mut F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        match self {}
+    }
+}
+
+impl<T, I, M> GEverywhere<T> for Meta<I, M>
+where
+    I: GEverywhere<T>,
+    M: Singleton,
+{
+    fn geverywhere<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        let Meta(inner, _) = self;
+        Meta(inner.geverywhere(f), PhantomData)
+    }
+}
+
+impl<T, I, C> GEverywhere<T> for Con<I, C>
+where
+    I: GEverywhere<T>,
+    C: Singleton,
+{
+    fn geverywhere<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        let Con(inner, _) = self;
+        Con(inner.geverywhere(f), PhantomData)
+    }
+}
+
+impl<T, A> GEverywhere<T> for List<A>
+where
+    A: GEverywhere<T>,
+{
+    fn geverywhere<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        let List(items) = self;
+        List(items.into_iter().map(|item| item.geverywhere(f)).collect())
+    }
+}
+
+impl<T, A, const N: usize> GEverywhere<T> for [A; N]
+where
+    A: GEverywhere<T>,
+{
+    fn geverywhere<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        self.map(|item| item.geverywhere(f))
+    }
+}
+
+impl<T, L> GEverywhere<T> for L
+where
+    T: 'static,
+    L: Leaf + 'static,
+{
+    fn geverywhere<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(T) -> T,
+    {
+        let mut leaf = Some(self);
+        if let Some(slot) = (&mut leaf as &mut dyn Any).downcast_mut::<Option<T>>() {
+            *slot = slot.take().map(f);
+        }
+        leaf.unwrap()
+    }
+}
+
+/// Replaces every leaf `x` of type `T` in the representation of `x` with `f(x)`, in order.
+pub fn everywhere<T, X, F>(x: X, mut f: F) -> X
+where
+    X: Generic,
+    X::Repr: GEverywhere<T>,
+    F: FnMut(T) -> T,
+{
+    X::from_repr(x.into_repr().geverywhere(&mut f))
+}
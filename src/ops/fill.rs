@@ -0,0 +1,278 @@
+use std::any::{self, Any};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, ReprError, Singleton, Sum, Unit, Void};
+
+/// A source of leaves, from which `GFill` builds a representation.
+///
+/// Sums read the index of their variant with `next_variant`, and lists read their length with `next_len`.
+/// By default, both are read as `usize` leaves.
+///
+/// This is implemented for iterators of `Box<dyn Any>`, which yield each leaf in turn.
+pub trait LeafSource {
+    /// Reads the next leaf, which must be a `T`.
+    fn next_leaf<T>(&mut self) -> Result<T, FillError>
+    where
+        T: Leaf + 'static;
+
+    /// Reads the index of the next variant, e.g. `1` for `Some` in `Option`.
+    fn next_variant(&mut self) -> Result<usize, FillError> {
+        self.next_leaf()
+    }
+
+    /// Reads the length of the next list.
+    fn next_len(&mut self) -> Result<usize, FillError> {
+        self.next_leaf()
+    }
+}
+
+impl<I> LeafSource for I
+where
+    I: Iterator<Item = Box<dyn Any>>,
+{
+    fn next_leaf<T>(&mut self) -> Result<T, FillError>
+    where
+        T: Leaf + 'static,
+    {
+        let leaf = self.next().ok_or(FillError::Exhausted)?;
+        match leaf.downcast() {
+            Ok(leaf) => Ok(*leaf),
+            Err(_) => Err(FillError::Mismatch {
+                expected: any::type_name::<T>(),
+            }),
+        }
+    }
+}
+
+/// An error building a representation from a `LeafSource`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FillError {
+    /// The source ran out of leaves.
+    Exhausted,
+    /// The next leaf had the wrong type.
+    Mismatch {
+        /// The name of the expected type.
+        expected: &'static str,
+    },
+    /// The index of a variant was out of range.
+    Variant(usize),
+    /// The leaves made a value which was invalid for its type, e.g. a `NonZeroU8` of zero.
+    Repr(ReprError),
+}
+
+impl Display for FillError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FillError::Exhausted => f.write_str("ran out of leaves"),
+            FillError::Mismatch { expected } => write!(f, "expected a leaf of type `{}`", expected),
+            FillError::Variant(index) => write!(f, "no variant with index {}", index),
+            FillError::Repr(error) => write!(f, "invalid value: {}", error),
+        }
+    }
+}
+
+impl Error for FillError {}
+
+/// Builds a representation by pulling its leaves, in order, from a `LeafSource`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::any::Any;
+/// use generics::ops::{fill, FillError};
+/// use generics::Generic;
+///
+/// #[derive(Debug, PartialEq, Generic)]
+/// struct Row {
+///     id: u32,
+///     name: String,
+///     score: Option<f64>,
+/// }
+///
+/// let leaves: Vec<Box<dyn Any>> = vec![
+///     Box::new(1u32),
+///     Box::new("a".to_string()),
+///     Box::new(1usize), // `Some`
+///     Box::new(0.5f64),
+/// ];
+/// let row: Row = fill(&mut leaves.into_iter()).unwrap();
+/// assert_eq!(row, Row { id: 1, name: "a".to_string(), score: Some(0.5) });
+///
+/// let leaves: Vec<Box<dyn Any>> = vec![Box::new(1u32)];
+/// assert_eq!(fill::<Row, _>(&mut leaves.into_iter()), Err(FillError::Exhausted));
+/// ```
+pub trait GFill: Sized {
+    /// Builds `Self` from the leaves of `source`.
+    fn gfill<S>(source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource;
+}
+
+/// Builds the `index`th variant of a `Sum` chain.
+pub trait GFillVariant: Sized {
+    /// Builds the `index`th variant of `Self` from the leaves of `source`.
+    fn gfill_variant<S>(index: usize, source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource;
+}
+
+impl GFill for Unit {
+    fn gfill<S>(_source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource,
+    {
+        Ok(Unit)
+    }
+}
+
+impl<A, B> GFill for Prod<A, B>
+where
+    A: GFill,
+    B: GFill,
+{
+    fn gfill<S>(source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource,
+    {
+        let a = A::gfill(source)?;
+        let b = B::gfill(source)?;
+        Ok(Prod(a, b))
+    }
+}
+
+impl<L, R> GFill for Sum<L, R>
+where
+    Self: GFillVariant,
+{
+    fn gfill<S>(source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource,
+    {
+        let index = source.next_variant()?;
+        Self::gfill_variant(index, source).map_err(|e| match e {
+            FillError::Variant(_) => FillError::Variant(index),
+            e => e,
+        })
+    }
+}
+
+impl<L, R> GFillVariant for Sum<L, R>
+where
+    L: GFill,
+    R: GFillVariant,
+{
+    fn gfill_variant<S>(index: usize, source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource,
+    {
+        match index {
+            0 => L::gfill(source).map(Sum::Left),
+            _ => R::gfill_variant(index - 1, source).map(Sum::Right),
+        }
+    }
+}
+
+impl GFillVariant for Void {
+    fn gfill_variant<S>(index: usize, _source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource,
+    {
+        Err(FillError::Variant(index))
+    }
+}
+
+impl GFill for Void {
+    fn gfill<S>(source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource,
+    {
+        Err(FillError::Variant(source.next_variant()?))
+    }
+}
+
+impl<I, M> GFill for Meta<I, M>
+where
+    I: GFill,
+    M: Singleton,
+{
+    fn gfill<S>(source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource,
+    {
+        Ok(Meta(I::gfill(source)?, PhantomData))
+    }
+}
+
+impl<I, C> GFill for Con<I, C>
+where
+    I: GFill,
+    C: Singleton,
+{
+    fn gfill<S>(source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource,
+    {
+        Ok(Con(I::gfill(source)?, PhantomData))
+    }
+}
+
+impl<A> GFill for List<A>
+where
+    A: GFill,
+{
+    fn gfill<S>(source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource,
+    {
+        let len = source.next_len()?;
+        (0..len)
+            .map(|_| A::gfill(source))
+            .collect::<Result<_, _>>()
+            .map(List)
+    }
+}
+
+impl<A, const N: usize> GFill for [A; N]
+where
+    A: GFill,
+{
+    fn gfill<S>(source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource,
+    {
+        let mut error = None;
+        let items = [(); N].map(|()| match error {
+            Some(_) => None,
+            None => A::gfill(source).map_err(|e| error = Some(e)).ok(),
+        });
+        match error {
+            Some(e) => Err(e),
+            None => Ok(items.map(|item| item.unwrap())),
+        }
+    }
+}
+
+impl<T> GFill for T
+where
+    T: Leaf + 'static,
+{
+    fn gfill<S>(source: &mut S) -> Result<Self, FillError>
+    where
+        S: LeafSource,
+    {
+        source.next_leaf()
+    }
+}
+
+/// Builds a `T` from the leaves of `source`, in the order they appear in its representation.
+pub fn fill<T, S>(source: &mut S) -> Result<T, FillError>
+where
+    T: Generic,
+    T::Repr: GFill,
+    S: LeafSource,
+{
+    let repr = T::Repr::gfill(source)?;
+    T::checked_from_repr(repr).map_err(FillError::Repr)
+}
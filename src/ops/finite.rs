@@ -0,0 +1,235 @@
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Leaf, Meta, Opaque, Prod, Singleton, Sum, Unit, Void};
+
+/// A leaf with finitely many values, which can be enumerated by index.
+///
+/// Implement this for your own leaf types to enumerate them with `GFinite`.
+pub trait Finite: Sized {
+    /// The number of values.
+    fn cardinality() -> usize;
+
+    /// The value at `index`, which must be less than `cardinality()`.
+    fn nth(index: usize) -> Self;
+}
+
+impl Finite for bool {
+    fn cardinality() -> usize {
+        2
+    }
+
+    fn nth(index: usize) -> Self {
+        index != 0
+    }
+}
+
+macro_rules! impl_finite_int {
+    ( $( $ty:ty ),+ $(,)? ) => {
+        $(
+            impl Finite for $ty {
+                fn cardinality() -> usize {
+                    1 << <$ty>::BITS
+                }
+
+                fn nth(index: usize) -> Self {
+                    <$ty>::MIN.wrapping_add(index as $ty)
+                }
+            }
+        )+
+    }
+}
+
+impl_finite_int!(u8, i8);
+
+impl<T> Finite for Opaque<T>
+where
+    T: Finite,
+{
+    fn cardinality() -> usize {
+        T::cardinality()
+    }
+
+    fn nth(index: usize) -> Self {
+        Opaque(T::nth(index))
+    }
+}
+
+/// Enumerates every value of a representation, node by node, by index.
+///
+/// Sums enumerate each of their variants in order, and products enumerate every combination of their fields,
+/// varying the last field fastest, so values are produced in the order `#[derive(Ord)]` would sort them.
+/// Leaves are enumerated with `Finite`. Lists have no impl, since they have unboundedly many values.
+///
+/// # Panics
+///
+/// `cardinality` panics if the number of values overflows `usize`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::all_values;
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum Light {
+///     Off,
+///     On { dimmed: bool },
+/// }
+///
+/// let lights = all_values::<Light>().collect::<Vec<_>>();
+/// assert_eq!(lights, [Light::Off, Light::On { dimmed: false }, Light::On { dimmed: true }]);
+/// ```
+pub trait GFinite: Sized {
+    /// The number of values.
+    fn cardinality() -> usize;
+
+    /// The value at `index`, which must be less than `cardinality()`.
+    fn nth(index: usize) -> Self;
+}
+
+impl GFinite for Unit {
+    fn cardinality() -> usize {
+        1
+    }
+
+    fn nth(_index: usize) -> Self {
+        Unit
+    }
+}
+
+impl<A, B> GFinite for Prod<A, B>
+where
+    A: GFinite,
+    B: GFinite,
+{
+    fn cardinality() -> usize {
+        A::cardinality()
+            .checked_mul(B::cardinality())
+            .expect("too many values to enumerate")
+    }
+
+    fn nth(index: usize) -> Self {
+        let b = B::cardinality();
+        Prod(A::nth(index / b), B::nth(index % b))
+    }
+}
+
+impl<L, R> GFinite for Sum<L, R>
+where
+    L: GFinite,
+    R: GFinite,
+{
+    fn cardinality() -> usize {
+        L::cardinality()
+            .checked_add(R::cardinality())
+            .expect("too many values to enumerate")
+    }
+
+    fn nth(index: usize) -> Self {
+        let l = L::cardinality();
+        if index < l {
+            Sum::Left(L::nth(index))
+        } else {
+            Sum::Right(R::nth(index - l))
+        }
+    }
+}
+
+impl GFinite for Void {
+    fn cardinality() -> usize {
+        0
+    }
+
+    fn nth(_index: usize) -> Self {
+        unreachable!("`Void` has no values")
+    }
+}
+
+impl<I, M> GFinite for Meta<I, M>
+where
+    I: GFinite,
+    M: Singleton,
+{
+    fn cardinality() -> usize {
+        I::cardinality()
+    }
+
+    fn nth(index: usize) -> Self {
+        Meta(I::nth(index), PhantomData)
+    }
+}
+
+impl<I, C> GFinite for Con<I, C>
+where
+    I: GFinite,
+    C: Singleton,
+{
+    fn cardinality() -> usize {
+        I::cardinality()
+    }
+
+    fn nth(index: usize) -> Self {
+        Con(I::nth(index), PhantomData)
+    }
+}
+
+impl<A, const N: usize> GFinite for [A; N]
+where
+    A: GFinite,
+{
+    fn cardinality() -> usize {
+        A::cardinality()
+            .checked_pow(N as u32)
+            .expect("too many values to enumerate")
+    }
+
+    fn nth(index: usize) -> Self {
+        let a = A::cardinality();
+        let mut place = Self::cardinality();
+        [(); N].map(|()| {
+            place /= a;
+            A::nth(index / place % a)
+        })
+    }
+}
+
+impl<T> GFinite for T
+where
+    T: Leaf + Finite,
+{
+    fn cardinality() -> usize {
+        T::cardinality()
+    }
+
+    fn nth(index: usize) -> Self {
+        T::nth(index)
+    }
+}
+
+/// Iterates over every value of `T`, in the order described by `GFinite`.
+///
+/// Representations which `T` rejects are skipped, so e.g. `NonZeroU8` yields 255 values rather than 256.
+///
+/// This can be used to exhaustively test a state machine, or to generate a table of every state:
+///
+/// ```rust
+/// use generics::ops::all_values;
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug)]
+/// struct Flags {
+///     read: bool,
+///     write: bool,
+///     mode: Option<bool>,
+/// }
+///
+/// assert_eq!(all_values::<Flags>().count(), 12);
+/// assert!(all_values::<Flags>().any(|flags| flags.read && !flags.write && flags.mode == Some(false)));
+/// ```
+pub fn all_values<T>() -> impl Iterator<Item = T>
+where
+    T: Generic,
+    T::Repr: GFinite,
+{
+    (0..T::Repr::cardinality()).filter_map(|index| T::checked_from_repr(T::Repr::nth(index)).ok())
+}
@@ -0,0 +1,621 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, ReprError, Singleton, Sum, Unit, Void};
+
+use super::GVariantName;
+
+/// Flattens a representation into pairs of dotted paths and stringified leaves.
+///
+/// Each leaf is formatted with `Display`, under the path of field names, and list and tuple indices, leading to it,
+/// e.g. `"servers.0.port"`. Sums additionally emit the name of their variant under their own path,
+/// so the value can be rebuilt with `GUnflatten`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::flatten;
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct Server {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// #[derive(Generic)]
+/// struct Config {
+///     servers: Vec<Server>,
+///     timeout: Option<u32>,
+/// }
+///
+/// let config = Config {
+///     servers: vec![Server { host: "localhost".to_string(), port: 80 }],
+///     timeout: Some(30),
+/// };
+/// let pairs = flatten(config);
+/// let pairs = pairs.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect::<Vec<_>>();
+/// assert_eq!(
+///     pairs,
+///     [
+///         ("servers.0.host", "localhost"),
+///         ("servers.0.port", "80"),
+///         ("timeout", "Some"),
+///         ("timeout.0", "30"),
+///     ]
+/// );
+/// ```
+pub trait GFlatten {
+    /// Appends a pair for each leaf of `self`, and each variant name, with paths beginning with `path`.
+    fn gflatten(self, path: &mut String, pairs: &mut Vec<(String, String)>);
+}
+
+/// Flattens the elements of a `Prod` chain, under their indices.
+pub trait GFlattenElements {
+    /// Appends a pair for each leaf of each element, with paths beginning with `path` and the element's index,
+    /// from `index`.
+    fn gflatten_elements(self, index: usize, path: &mut String, pairs: &mut Vec<(String, String)>);
+}
+
+/// Flattens the fields of a constructor, i.e. a `Prod` chain of `Meta`s, under their names.
+pub trait GFlattenFields {
+    /// Appends a pair for each leaf of each field, with paths beginning with `path` and the field's name.
+    fn gflatten_fields(self, path: &mut String, pairs: &mut Vec<(String, String)>);
+}
+
+/// Flattens the fields of whichever variant of a `Sum` chain is held, without its name.
+pub trait GFlattenVariant {
+    /// Appends a pair for each leaf of the held variant, with paths beginning with `path`.
+    fn gflatten_variant(self, path: &mut String, pairs: &mut Vec<(String, String)>);
+}
+
+/// Rebuilds a representation from pairs of dotted paths and stringified leaves, as produced by `GFlatten`.
+///
+/// Each leaf is parsed with `FromStr`. Lists take elements at consecutive indices until one is missing,
+/// so a list of elements with no leaves always rebuilds as empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{flatten, unflatten, UnflattenError};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Light {
+///     mode: Mode,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum Mode {
+///     Off,
+///     On { level: u8 },
+/// }
+///
+/// let pairs = vec![
+///     ("mode".to_string(), "On".to_string()),
+///     ("mode.level".to_string(), "3".to_string()),
+/// ];
+/// let light = Light { mode: Mode::On { level: 3 } };
+/// assert_eq!(unflatten::<Light, _>(pairs), Ok(light));
+/// assert_eq!(unflatten::<Mode, _>(flatten(Mode::Off)), Ok(Mode::Off));
+///
+/// let pairs = vec![("mode".to_string(), "Dim".to_string())];
+/// let err = unflatten::<Light, _>(pairs).unwrap_err();
+/// assert_eq!(err.to_string(), "unknown variant `Dim` for `mode`");
+/// ```
+pub trait GUnflatten: Sized {
+    /// Rebuilds the representation whose paths begin with `path`.
+    fn gunflatten(
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError>;
+}
+
+/// Rebuilds the elements of a `Prod` chain, from under their indices.
+pub trait GUnflattenElements: Sized {
+    /// Rebuilds each element from the paths beginning with `path` and the element's index, from `index`.
+    fn gunflatten_elements(
+        index: usize,
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError>;
+}
+
+/// Rebuilds the fields of a constructor, i.e. a `Prod` chain of `Meta`s, from under their names.
+pub trait GUnflattenFields: Sized {
+    /// Rebuilds each field from the paths beginning with `path` and the field's name.
+    fn gunflatten_fields(
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError>;
+}
+
+/// Rebuilds the variant of a `Sum` chain with a given name.
+pub trait GUnflattenVariant: Sized {
+    /// Rebuilds the variant named `name`, or returns `None` if there is no such variant.
+    fn gunflatten_variant(
+        name: &str,
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Option<Result<Self, UnflattenError>>;
+}
+
+/// An error rebuilding a representation from flattened pairs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnflattenError {
+    /// There was no pair with the given path.
+    Missing(String),
+    /// The value of a leaf couldn't be parsed.
+    Invalid {
+        /// The path of the leaf.
+        path: String,
+        /// The value which failed to parse.
+        value: String,
+    },
+    /// The name of a variant matched none of the variants of its sum.
+    Variant {
+        /// The path of the sum.
+        path: String,
+        /// The unknown variant name.
+        name: String,
+    },
+    /// The value was invalid for its type, e.g. a `NonZeroU8` of zero.
+    Repr(ReprError),
+}
+
+impl Display for UnflattenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnflattenError::Missing(path) => write!(f, "missing value for `{}`", path),
+            UnflattenError::Invalid { path, value } => {
+                write!(f, "invalid value `{}` for `{}`", value, path)
+            }
+            UnflattenError::Variant { path, name } => {
+                write!(f, "unknown variant `{}` for `{}`", name, path)
+            }
+            UnflattenError::Repr(error) => write!(f, "invalid value: {}", error),
+        }
+    }
+}
+
+impl Error for UnflattenError {}
+
+/// Appends `segment` to `path`, returning the previous length of `path` to truncate it back to.
+pub(super) fn push_segment(path: &mut String, segment: &dyn Display) -> usize {
+    use std::fmt::Write;
+
+    let len = path.len();
+    if !path.is_empty() {
+        path.push('.');
+    }
+    write!(path, "{}", segment).expect("writing to a `String` cannot fail");
+    len
+}
+
+/// Whether any pair has `path` as its path, or as a prefix of its path.
+fn contains_path(pairs: &HashMap<String, String>, path: &str) -> bool {
+    pairs
+        .keys()
+        .any(|key| key == path || (key.starts_with(path) && key[path.len()..].starts_with('.')))
+}
+
+impl GFlatten for Unit {
+    fn gflatten(self, _path: &mut String, _pairs: &mut Vec<(String, String)>) {}
+}
+
+/// A bare `Prod` is the representation of a tuple, whose elements are named by index.
+impl<A, B> GFlatten for Prod<A, B>
+where
+    Self: GFlattenElements,
+{
+    fn gflatten(self, path: &mut String, pairs: &mut Vec<(String, String)>) {
+        self.gflatten_elements(0, path, pairs);
+    }
+}
+
+impl GFlattenElements for Unit {
+    fn gflatten_elements(
+        self,
+        _index: usize,
+        _path: &mut String,
+        _pairs: &mut Vec<(String, String)>,
+    ) {
+    }
+}
+
+impl<A, B> GFlattenElements for Prod<A, B>
+where
+    A: GFlatten,
+    B: GFlattenElements,
+{
+    fn gflatten_elements(self, index: usize, path: &mut String, pairs: &mut Vec<(String, String)>) {
+        let Prod(a, b) = self;
+        let len = push_segment(path, &index);
+        a.gflatten(path, pairs);
+        path.truncate(len);
+        b.gflatten_elements(index + 1, path, pairs);
+    }
+}
+
+impl GFlattenFields for Unit {
+    fn gflatten_fields(self, _path: &mut String, _pairs: &mut Vec<(String, String)>) {}
+}
+
+impl<A, B> GFlattenFields for Prod<A, B>
+where
+    A: GFlatten,
+    B: GFlattenFields,
+{
+    fn gflatten_fields(self, path: &mut String, pairs: &mut Vec<(String, String)>) {
+        let Prod(a, b) = self;
+        a.gflatten(path, pairs);
+        b.gflatten_fields(path, pairs);
+    }
+}
+
+impl<L, R> GFlatten for Sum<L, R>
+where
+    Self: GVariantName + GFlattenVariant,
+{
+    fn gflatten(self, path: &mut String, pairs: &mut Vec<(String, String)>) {
+        pairs.push((path.clone(), self.variant_name().to_string()));
+        self.gflatten_variant(path, pairs);
+    }
+}
+
+impl<L, R> GFlattenVariant for Sum<L, R>
+where
+    L: GFlatten,
+    R: GFlattenVariant,
+{
+    fn gflatten_variant(self, path: &mut String, pairs: &mut Vec<(String, String)>) {
+        match self {
+            Sum::Left(l) => l.gflatten(path, pairs),
+            Sum::Right(r) => r.gflatten_variant(path, pairs),
+        }
+    }
+}
+
+impl GFlatten for Void {
+    fn gflatten(self, _path: &mut String, _pairs: &mut Vec<(String, String)>) {
+        match self {}
+    }
+}
+
+impl GFlattenVariant for Void {
+    fn gflatten_variant(self, _path: &mut String, _pairs: &mut Vec<(String, String)>) {
+        match self {}
+    }
+}
+
+impl<I, M> GFlatten for Meta<I, M>
+where
+    I: GFlatten,
+    M: Singleton<T = &'static str>,
+{
+    fn gflatten(self, path: &mut String, pairs: &mut Vec<(String, String)>) {
+        let Meta(inner, _) = self;
+        let len = push_segment(path, &M::get());
+        inner.gflatten(path, pairs);
+        path.truncate(len);
+    }
+}
+
+impl<I, C> GFlatten for Con<I, C>
+where
+    I: GFlattenFields,
+    C: Singleton,
+{
+    fn gflatten(self, path: &mut String, pairs: &mut Vec<(String, String)>) {
+        let Con(inner, _) = self;
+        inner.gflatten_fields(path, pairs);
+    }
+}
+
+impl<A> GFlatten for List<A>
+where
+    A: GFlatten,
+{
+    fn gflatten(self, path: &mut String, pairs: &mut Vec<(String, String)>) {
+        let List(items) = self;
+        for (i, item) in items.into_iter().enumerate() {
+            let len = push_segment(path, &i);
+            item.gflatten(path, pairs);
+            path.truncate(len);
+        }
+    }
+}
+
+impl<A, const N: usize> GFlatten for [A; N]
+where
+    A: GFlatten,
+{
+    fn gflatten(self, path: &mut String, pairs: &mut Vec<(String, String)>) {
+        List(Vec::from(self)).gflatten(path, pairs);
+    }
+}
+
+impl<T> GFlatten for T
+where
+    T: Leaf + Display,
+{
+    fn gflatten(self, path: &mut String, pairs: &mut Vec<(String, String)>) {
+        pairs.push((path.clone(), self.to_string()));
+    }
+}
+
+impl GUnflatten for Unit {
+    fn gunflatten(
+        _path: &mut String,
+        _pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError> {
+        Ok(Unit)
+    }
+}
+
+impl<A, B> GUnflatten for Prod<A, B>
+where
+    Self: GUnflattenElements,
+{
+    fn gunflatten(
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError> {
+        Self::gunflatten_elements(0, path, pairs)
+    }
+}
+
+impl GUnflattenElements for Unit {
+    fn gunflatten_elements(
+        _index: usize,
+        _path: &mut String,
+        _pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError> {
+        Ok(Unit)
+    }
+}
+
+impl<A, B> GUnflattenElements for Prod<A, B>
+where
+    A: GUnflatten,
+    B: GUnflattenElements,
+{
+    fn gunflatten_elements(
+        index: usize,
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError> {
+        let len = push_segment(path, &index);
+        let a = A::gunflatten(path, pairs);
+        path.truncate(len);
+        Ok(Prod(a?, B::gunflatten_elements(index + 1, path, pairs)?))
+    }
+}
+
+impl GUnflattenFields for Unit {
+    fn gunflatten_fields(
+        _path: &mut String,
+        _pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError> {
+        Ok(Unit)
+    }
+}
+
+impl<A, B> GUnflattenFields for Prod<A, B>
+where
+    A: GUnflatten,
+    B: GUnflattenFields,
+{
+    fn gunflatten_fields(
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError> {
+        Ok(Prod(
+            A::gunflatten(path, pairs)?,
+            B::gunflatten_fields(path, pairs)?,
+        ))
+    }
+}
+
+impl<L, R> GUnflatten for Sum<L, R>
+where
+    Self: GUnflattenVariant,
+{
+    fn gunflatten(
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError> {
+        let name = pairs
+            .get(path.as_str())
+            .ok_or_else(|| UnflattenError::Missing(path.clone()))?;
+        Self::gunflatten_variant(name, path, pairs).unwrap_or_else(|| {
+            Err(UnflattenError::Variant {
+                path: path.clone(),
+                name: name.clone(),
+            })
+        })
+    }
+}
+
+impl<I, C, R> GUnflattenVariant for Sum<Con<I, C>, R>
+where
+    I: GUnflattenFields,
+    C: Singleton<T = &'static str>,
+    R: GUnflattenVariant,
+{
+    fn gunflatten_variant(
+        name: &str,
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Option<Result<Self, UnflattenError>> {
+        if C::get() == name {
+            Some(Con::gunflatten(path, pairs).map(Sum::Left))
+        } else {
+            R::gunflatten_variant(name, path, pairs).map(|r| r.map(Sum::Right))
+        }
+    }
+}
+
+impl GUnflattenVariant for Void {
+    fn gunflatten_variant(
+        _name: &str,
+        _path: &mut String,
+        _pairs: &HashMap<String, String>,
+    ) -> Option<Result<Self, UnflattenError>> {
+        None
+    }
+}
+
+impl<I, M> GUnflatten for Meta<I, M>
+where
+    I: GUnflatten,
+    M: Singleton<T = &'static str>,
+{
+    fn gunflatten(
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError> {
+        let len = push_segment(path, &M::get());
+        let inner = I::gunflatten(path, pairs);
+        path.truncate(len);
+        Ok(Meta(inner?, PhantomData))
+    }
+}
+
+impl<I, C> GUnflatten for Con<I, C>
+where
+    I: GUnflattenFields,
+    C: Singleton,
+{
+    fn gunflatten(
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError> {
+        Ok(Con(I::gunflatten_fields(path, pairs)?, PhantomData))
+    }
+}
+
+impl<A> GUnflatten for List<A>
+where
+    A: GUnflatten,
+{
+    fn gunflatten(
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError> {
+        let mut items = Vec::new();
+        loop {
+            let len = push_segment(path, &items.len());
+            let item = if contains_path(pairs, path) {
+                Some(A::gunflatten(path, pairs))
+            } else {
+                None
+            };
+            path.truncate(len);
+            match item {
+                Some(item) => items.push(item?),
+                None => return Ok(List(items)),
+            }
+        }
+    }
+}
+
+impl<A, const N: usize> GUnflatten for [A; N]
+where
+    A: GUnflatten,
+{
+    fn gunflatten(
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError> {
+        let mut i = 0;
+        let mut error = None;
+        let items = [(); N].map(|()| {
+            let len = push_segment(path, &i);
+            i += 1;
+            let item = match error {
+                Some(_) => None,
+                None => A::gunflatten(path, pairs).map_err(|e| error = Some(e)).ok(),
+            };
+            path.truncate(len);
+            item
+        });
+        match error {
+            Some(e) => Err(e),
+            None => Ok(items.map(|item| item.unwrap())),
+        }
+    }
+}
+
+impl<T> GUnflatten for T
+where
+    T: Leaf + FromStr,
+{
+    fn gunflatten(
+        path: &mut String,
+        pairs: &HashMap<String, String>,
+    ) -> Result<Self, UnflattenError> {
+        let value = pairs
+            .get(path.as_str())
+            .ok_or_else(|| UnflattenError::Missing(path.clone()))?;
+        value.parse().map_err(|_| UnflattenError::Invalid {
+            path: path.clone(),
+            value: value.clone(),
+        })
+    }
+}
+
+/// Flattens `x` into pairs of dotted paths and stringified leaves, in the order of its fields.
+///
+/// ```rust
+/// use generics::ops::flatten;
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct Foo(u8, [bool; 2]);
+///
+/// let pairs = flatten(Foo(1, [true, false]));
+/// assert_eq!(pairs[0], ("0".to_string(), "1".to_string()));
+/// assert_eq!(pairs[2], ("1.1".to_string(), "false".to_string()));
+/// ```
+pub fn flatten<T>(x: T) -> Vec<(String, String)>
+where
+    T: Generic,
+    T::Repr: GFlatten,
+{
+    let mut pairs = Vec::new();
+    x.into_repr().gflatten(&mut String::new(), &mut pairs);
+    pairs
+}
+
+/// Rebuilds a `T` from pairs of dotted paths and stringified leaves, as produced by `flatten`.
+///
+/// Pairs which don't correspond to any leaf of `T` are ignored.
+///
+/// ```rust
+/// use generics::ops::{unflatten, UnflattenError};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Foo<T>(u8, T);
+///
+/// let pairs = vec![("0".to_string(), "1".to_string()), ("1".to_string(), "x".to_string())];
+/// assert_eq!(unflatten::<Foo<char>, _>(pairs.clone()), Ok(Foo(1, 'x')));
+/// assert_eq!(
+///     unflatten::<Foo<u8>, _>(pairs).unwrap_err(),
+///     UnflattenError::Invalid { path: "1".to_string(), value: "x".to_string() },
+/// );
+/// ```
+pub fn unflatten<T, I>(pairs: I) -> Result<T, UnflattenError>
+where
+    T: Generic,
+    T::Repr: GUnflatten,
+    I: IntoIterator<Item = (String, String)>,
+{
+    let pairs = pairs.into_iter().collect();
+    let repr = T::Repr::gunflatten(&mut String::new(), &pairs)?;
+    T::checked_from_repr(repr).map_err(UnflattenError::Repr)
+}
@@ -0,0 +1,151 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Singleton, Sum, Unit, Void};
+
+/// Constructs the representation of a fieldless constructor from its name, as stored in its metadata.
+///
+/// `#[derive(GenericFromStr)]` uses this to implement `FromStr` for fieldless enums, alongside `#[derive(Generic)]`.
+/// Variants can be renamed with `#[generic(rename = "...")]`,
+/// and names matched regardless of ASCII case with `#[generic(case_insensitive)]` on the enum.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{GenericDisplay, GenericFromStr};
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericDisplay, GenericFromStr, Debug, PartialEq)]
+/// #[generic(case_insensitive)]
+/// enum Color {
+///     Red,
+///     #[generic(rename = "green")]
+///     Green,
+/// }
+///
+/// assert_eq!("RED".parse::<Color>(), Ok(Color::Red));
+/// assert_eq!("Green".parse::<Color>(), Ok(Color::Green));
+/// assert_eq!(Color::Green.to_string(), "green");
+///
+/// let err = "blue".parse::<Color>().unwrap_err();
+/// assert_eq!(err.to_string(), "unknown variant `blue`, expected one of `Red`, `green`");
+/// ```
+pub trait GFromName: Sized {
+    /// Constructs the first constructor whose name satisfies `matches`.
+    fn from_name<F>(matches: &F) -> Option<Self>
+    where
+        F: Fn(&'static str) -> bool;
+
+    /// Appends the name of each constructor to `names`.
+    fn names(names: &mut Vec<&'static str>);
+}
+
+/// An error parsing a fieldless enum from a name which matches none of its variants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVariantError {
+    /// The name which failed to parse.
+    pub name: String,
+    /// The names of each variant.
+    pub expected: Vec<&'static str>,
+}
+
+impl Display for ParseVariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown variant `{}`", self.name)?;
+        for (i, expected) in self.expected.iter().enumerate() {
+            let sep = if i == 0 { ", expected one of" } else { "," };
+            write!(f, "{} `{}`", sep, expected)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseVariantError {}
+
+impl<L, R> GFromName for Sum<L, R>
+where
+    L: GFromName,
+    R: GFromName,
+{
+    fn from_name<F>(matches: &F) -> Option<Self>
+    where
+        F: Fn(&'static str) -> bool,
+    {
+        match L::from_name(matches) {
+            Some(l) => Some(Sum::Left(l)),
+            None => R::from_name(matches).map(Sum::Right),
+        }
+    }
+
+    fn names(names: &mut Vec<&'static str>) {
+        L::names(names);
+        R::names(names);
+    }
+}
+
+impl GFromName for Void {
+    fn from_name<F>(_matches: &F) -> Option<Self>
+    where
+        F: Fn(&'static str) -> bool,
+    {
+        None
+    }
+
+    fn names(_names: &mut Vec<&'static str>) {}
+}
+
+impl<C> GFromName for Con<Unit, C>
+where
+    C: Singleton<T = &'static str>,
+{
+    fn from_name<F>(matches: &F) -> Option<Self>
+    where
+        F: Fn(&'static str) -> bool,
+    {
+        if matches(C::get()) {
+            Some(Con(Unit, PhantomData))
+        } else {
+            None
+        }
+    }
+
+    fn names(names: &mut Vec<&'static str>) {
+        names.push(C::get());
+    }
+}
+
+/// Parses a fieldless enum from the name of one of its variants, optionally ignoring ASCII case.
+///
+/// ```rust
+/// use std::cmp::Ordering;
+/// use generics::ops::from_name;
+///
+/// assert_eq!(from_name::<Ordering>("Less", false), Ok(Ordering::Less));
+/// assert_eq!(from_name::<Ordering>("greater", true), Ok(Ordering::Greater));
+/// assert!(from_name::<Ordering>("greater", false).is_err());
+/// ```
+pub fn from_name<T>(name: &str, case_insensitive: bool) -> Result<T, ParseVariantError>
+where
+    T: Generic,
+    T::Repr: GFromName,
+{
+    let matches = |variant: &'static str| {
+        if case_insensitive {
+            variant.eq_ignore_ascii_case(name)
+        } else {
+            variant == name
+        }
+    };
+    match T::Repr::from_name(&matches) {
+        Some(repr) => Ok(T::from_repr(repr)),
+        None => {
+            let mut expected = Vec::new();
+            T::Repr::names(&mut expected);
+            Err(ParseVariantError {
+                name: name.to_string(),
+                expected,
+            })
+        }
+    }
+}
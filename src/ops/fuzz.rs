@@ -0,0 +1,181 @@
+use std::marker::PhantomData;
+
+use arbitrary::{Arbitrary, Unstructured};
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+/// Generates a representation from unstructured fuzzer input, node by node.
+///
+/// Sums consume an index to choose their variant, lists consume a flag before each element to decide whether to continue,
+/// and leaves use their own `Arbitrary` impl. Once the input runs out, further leaves are generated from zeroes.
+///
+/// `#[derive(GenericArbitrary)]` uses this to implement `arbitrary::Arbitrary`, alongside `#[derive(Generic)]`,
+/// so the type can be used as the input of a fuzz target.
+///
+/// # Examples
+///
+/// ```rust
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use generics::ops::GenericArbitrary;
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericArbitrary, Debug, PartialEq)]
+/// enum Command {
+///     Stop,
+///     Move { x: i8, y: i8 },
+/// }
+///
+/// let mut u = Unstructured::new(&[1, 0xff, 2]);
+/// assert_eq!(Command::arbitrary(&mut u), Ok(Command::Move { x: -1, y: 2 }));
+/// ```
+pub trait GArbitrary<'a>: Sized {
+    /// Generates a representation from `u`.
+    fn garbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self>;
+}
+
+/// Generates a variant of a `Sum` chain by index.
+pub trait GArbitraryVariant<'a>: Sized {
+    /// The number of variants.
+    fn variant_count() -> usize;
+
+    /// Generates the variant at `index`, which must be less than `variant_count()`.
+    fn garbitrary_variant(index: usize, u: &mut Unstructured<'a>) -> arbitrary::Result<Self>;
+}
+
+impl<'a> GArbitrary<'a> for Unit {
+    fn garbitrary(_u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Unit)
+    }
+}
+
+impl<'a, A, B> GArbitrary<'a> for Prod<A, B>
+where
+    A: GArbitrary<'a>,
+    B: GArbitrary<'a>,
+{
+    fn garbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Prod(A::garbitrary(u)?, B::garbitrary(u)?))
+    }
+}
+
+impl<'a, L, R> GArbitrary<'a> for Sum<L, R>
+where
+    Self: GArbitraryVariant<'a>,
+{
+    fn garbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let index = u.choose_index(Self::variant_count())?;
+        Self::garbitrary_variant(index, u)
+    }
+}
+
+impl<'a, L, R> GArbitraryVariant<'a> for Sum<L, R>
+where
+    L: GArbitrary<'a>,
+    R: GArbitraryVariant<'a>,
+{
+    fn variant_count() -> usize {
+        1 + R::variant_count()
+    }
+
+    fn garbitrary_variant(index: usize, u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        match index {
+            0 => L::garbitrary(u).map(Sum::Left),
+            index => R::garbitrary_variant(index - 1, u).map(Sum::Right),
+        }
+    }
+}
+
+/// Always fails with `EmptyChoose`, since `Void` has no values.
+impl<'a> GArbitrary<'a> for Void {
+    fn garbitrary(_u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Err(arbitrary::Error::EmptyChoose)
+    }
+}
+
+impl<'a> GArbitraryVariant<'a> for Void {
+    fn variant_count() -> usize {
+        0
+    }
+
+    fn garbitrary_variant(_index: usize, _u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Err(arbitrary::Error::EmptyChoose)
+    }
+}
+
+impl<'a, I, M> GArbitrary<'a> for Meta<I, M>
+where
+    I: GArbitrary<'a>,
+    M: Singleton,
+{
+    fn garbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Meta(I::garbitrary(u)?, PhantomData))
+    }
+}
+
+impl<'a, I, C> GArbitrary<'a> for Con<I, C>
+where
+    I: GArbitrary<'a>,
+    C: Singleton,
+{
+    fn garbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Con(I::garbitrary(u)?, PhantomData))
+    }
+}
+
+impl<'a, A> GArbitrary<'a> for List<A>
+where
+    A: GArbitrary<'a>,
+{
+    fn garbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut items = Vec::new();
+        // An exhausted input reads as `false`, so this always terminates.
+        while bool::arbitrary(u)? {
+            items.push(A::garbitrary(u)?);
+        }
+        Ok(List(items))
+    }
+}
+
+impl<'a, A, const N: usize> GArbitrary<'a> for [A; N]
+where
+    A: GArbitrary<'a>,
+{
+    fn garbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut error = None;
+        let items = [(); N].map(|()| match error {
+            Some(_) => None,
+            None => A::garbitrary(u).map_err(|e| error = Some(e)).ok(),
+        });
+        match error {
+            Some(e) => Err(e),
+            None => Ok(items.map(|item| item.unwrap())),
+        }
+    }
+}
+
+impl<'a, T> GArbitrary<'a> for T
+where
+    T: Leaf + Arbitrary<'a>,
+{
+    fn garbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        T::arbitrary(u)
+    }
+}
+
+/// Generates a `T` from unstructured fuzzer input, via its representation.
+///
+/// ```rust
+/// use arbitrary::Unstructured;
+/// use generics::ops::garbitrary;
+///
+/// let mut u = Unstructured::new(&[1, 1, 7, 1, 8, 0]);
+/// assert_eq!(garbitrary::<(u8, Vec<u8>)>(&mut u), Ok((1, vec![7, 8])));
+/// ```
+pub fn garbitrary<'a, T>(u: &mut Unstructured<'a>) -> arbitrary::Result<T>
+where
+    T: Generic,
+    T::Repr: GArbitrary<'a>,
+{
+    let repr = T::Repr::garbitrary(u)?;
+    T::checked_from_repr(repr).map_err(|_| arbitrary::Error::IncorrectFormat)
+}
@@ -0,0 +1,180 @@
+use std::hash::{Hash, Hasher};
+
+use crate::{Con, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+use super::{Borrowed, Ignored};
+
+/// Feeds a representation into a `Hasher`, node by node.
+///
+/// Each `Sum` hashes a tag for the side it holds before its payload, so different constructors hash differently
+/// even if their fields are equal. Lists hash their length before their items, and leaves use their own `Hash` impl.
+///
+/// Equal representations (per `GPartialEq`) hash equally, as long as their leaves' `Hash` and `PartialEq` impls agree.
+/// `#[derive(GenericHash)]` uses this to implement `Hash`, alongside `#[derive(Generic)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use generics::ops::{GenericEq, GenericHash, GenericPartialEq};
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericPartialEq, GenericEq, GenericHash)]
+/// enum Foo {
+///     A(u8),
+///     B(u8),
+/// }
+///
+/// let set = [Foo::A(1), Foo::B(1), Foo::A(1)].iter().collect::<HashSet<_>>();
+/// assert_eq!(set.len(), 2);
+/// ```
+pub trait GHash {
+    /// Feeds `self` into `state`.
+    fn ghash<H>(&self, state: &mut H)
+    where
+        H: Hasher;
+}
+
+impl GHash for Unit {
+    fn ghash<H>(&self, _state: &mut H)
+    where
+        H: Hasher,
+    {
+    }
+}
+
+impl<A, B> GHash for Prod<A, B>
+where
+    A: GHash,
+    B: GHash,
+{
+    fn ghash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        let Prod(a, b) = self;
+        a.ghash(state);
+        b.ghash(state);
+    }
+}
+
+impl<L, R> GHash for Sum<L, R>
+where
+    L: GHash,
+    R: GHash,
+{
+    fn ghash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        match self {
+            Sum::Left(l) => {
+                state.write_u8(0);
+                l.ghash(state);
+            }
+            Sum::Right(r) => {
+                state.write_u8(1);
+                r.ghash(state);
+            }
+        }
+    }
+}
+
+impl GHash for Void {
+    fn ghash<H>(&self, _state: &mut H)
+    where
+        H: Hasher,
+    {
+        match *self {}
+    }
+}
+
+impl<I, M> GHash for Meta<I, M>
+where
+    I: GHash,
+    M: Singleton,
+{
+    fn ghash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        let Meta(inner, _) = self;
+        inner.ghash(state);
+    }
+}
+
+impl<I, C> GHash for Con<I, C>
+where
+    I: GHash,
+    C: Singleton,
+{
+    fn ghash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        let Con(inner, _) = self;
+        inner.ghash(state);
+    }
+}
+
+impl<A> GHash for List<A>
+where
+    A: GHash,
+{
+    fn ghash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        let List(items) = self;
+        state.write_usize(items.len());
+        for item in items {
+            item.ghash(state);
+        }
+    }
+}
+
+impl<A, const N: usize> GHash for [A; N]
+where
+    A: GHash,
+{
+    fn ghash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        for item in self {
+            item.ghash(state);
+        }
+    }
+}
+
+impl<T> GHash for Borrowed<'_, T>
+where
+    T: Hash,
+{
+    fn ghash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.0.hash(state);
+    }
+}
+
+impl<T> GHash for Ignored<T> {
+    fn ghash<H>(&self, _state: &mut H)
+    where
+        H: Hasher,
+    {
+    }
+}
+
+impl<T> GHash for T
+where
+    T: Leaf + Hash,
+{
+    fn ghash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.hash(state);
+    }
+}
@@ -0,0 +1,360 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque};
+use std::ffi::{CString, OsString};
+use std::mem;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::{Con, Leaf, List, Meta, Opaque, Prod, Singleton, Sum, Unit, Void};
+
+use super::Borrowed;
+
+/// The number of bytes a value owns on the heap, excluding its own inline size.
+///
+/// This is an estimate: allocator overhead and the bookkeeping of hash tables and trees aren't counted,
+/// and values behind `Rc` or `Arc` are counted once per pointer.
+///
+/// Implement this for your own leaf types, or derive it with `#[derive(GenericHeapSize)]`.
+pub trait HeapSize {
+    /// The number of bytes `self` owns on the heap.
+    fn heap_size(&self) -> usize;
+
+    /// The total number of bytes `self` occupies, inline and on the heap.
+    fn deep_size_of(&self) -> usize
+    where
+        Self: Sized,
+    {
+        mem::size_of_val(self) + self.heap_size()
+    }
+}
+
+macro_rules! impl_no_heap {
+    ( $( $ty:ty ),+ $(,)? ) => {
+        $(
+            impl HeapSize for $ty {
+                fn heap_size(&self) -> usize {
+                    0
+                }
+            }
+        )+
+    };
+}
+
+impl_no_heap!(u8, u16, u32, u64, u128, usize);
+impl_no_heap!(i8, i16, i32, i64, i128, isize);
+impl_no_heap!(f32, f64, bool, char, ());
+
+/// References don't own their referent.
+impl<T> HeapSize for &T
+where
+    T: ?Sized,
+{
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl HeapSize for String {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl HeapSize for Box<str> {
+    fn heap_size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl HeapSize for OsString {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl HeapSize for PathBuf {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl HeapSize for CString {
+    fn heap_size(&self) -> usize {
+        self.as_bytes_with_nul().len()
+    }
+}
+
+impl<T> HeapSize for Box<T>
+where
+    T: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        (**self).deep_size_of()
+    }
+}
+
+impl<T> HeapSize for Rc<T>
+where
+    T: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        (**self).deep_size_of()
+    }
+}
+
+impl<T> HeapSize for Arc<T>
+where
+    T: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        (**self).deep_size_of()
+    }
+}
+
+impl<T> HeapSize for Option<T>
+where
+    T: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, T::heap_size)
+    }
+}
+
+impl<T, E> HeapSize for Result<T, E>
+where
+    T: HeapSize,
+    E: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        match self {
+            Ok(x) => x.heap_size(),
+            Err(e) => e.heap_size(),
+        }
+    }
+}
+
+impl<T, const N: usize> HeapSize for [T; N]
+where
+    T: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        self.iter().map(T::heap_size).sum()
+    }
+}
+
+impl<T> HeapSize for Vec<T>
+where
+    T: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        self.capacity() * mem::size_of::<T>() + self.iter().map(T::heap_size).sum::<usize>()
+    }
+}
+
+impl<T> HeapSize for Box<[T]>
+where
+    T: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        self.len() * mem::size_of::<T>() + self.iter().map(T::heap_size).sum::<usize>()
+    }
+}
+
+impl<T> HeapSize for VecDeque<T>
+where
+    T: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        self.capacity() * mem::size_of::<T>() + self.iter().map(T::heap_size).sum::<usize>()
+    }
+}
+
+/// Each node is counted as its element, without the links between nodes.
+impl<T> HeapSize for LinkedList<T>
+where
+    T: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        self.iter().map(T::deep_size_of).sum()
+    }
+}
+
+impl<K, V, S> HeapSize for HashMap<K, V, S>
+where
+    K: HeapSize,
+    V: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        self.capacity() * mem::size_of::<(K, V)>()
+            + self
+                .iter()
+                .map(|(k, v)| k.heap_size() + v.heap_size())
+                .sum::<usize>()
+    }
+}
+
+impl<T, S> HeapSize for HashSet<T, S>
+where
+    T: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        self.capacity() * mem::size_of::<T>() + self.iter().map(T::heap_size).sum::<usize>()
+    }
+}
+
+impl<K, V> HeapSize for BTreeMap<K, V>
+where
+    K: HeapSize,
+    V: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        self.iter()
+            .map(|(k, v)| k.deep_size_of() + v.deep_size_of())
+            .sum()
+    }
+}
+
+impl<T> HeapSize for BTreeSet<T>
+where
+    T: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        self.iter().map(T::deep_size_of).sum()
+    }
+}
+
+impl<T> HeapSize for Opaque<T>
+where
+    T: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+}
+
+/// Sums the heap usage of the leaves of a representation, with `HeapSize`.
+///
+/// Lists and arrays sum the heap usage of their items, but not the storage for the items themselves,
+/// since that depends on the collection they were converted from.
+/// `#[derive(GenericHeapSize)]` uses this to implement `HeapSize`, alongside `#[derive(Generic)]`.
+/// It walks a view of borrowed fields, so each field's storage is counted by the field's own `HeapSize` impl.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{GenericHeapSize, HeapSize};
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericHeapSize)]
+/// struct Foo {
+///     a: u8,
+///     b: String,
+///     c: Vec<u32>,
+/// }
+///
+/// let foo = Foo {
+///     a: 1,
+///     b: String::with_capacity(10),
+///     c: Vec::with_capacity(4),
+/// };
+/// assert_eq!(foo.heap_size(), 10 + 4 * 4);
+/// ```
+pub trait GHeapSize {
+    /// The number of bytes the leaves of `self` own on the heap.
+    fn gheap_size(&self) -> usize;
+}
+
+impl GHeapSize for Unit {
+    fn gheap_size(&self) -> usize {
+        0
+    }
+}
+
+impl<A, B> GHeapSize for Prod<A, B>
+where
+    A: GHeapSize,
+    B: GHeapSize,
+{
+    fn gheap_size(&self) -> usize {
+        let Prod(a, b) = self;
+        a.gheap_size() + b.gheap_size()
+    }
+}
+
+impl<L, R> GHeapSize for Sum<L, R>
+where
+    L: GHeapSize,
+    R: GHeapSize,
+{
+    fn gheap_size(&self) -> usize {
+        match self {
+            Sum::Left(l) => l.gheap_size(),
+            Sum::Right(r) => r.gheap_size(),
+        }
+    }
+}
+
+impl GHeapSize for Void {
+    fn gheap_size(&self) -> usize {
+        match *self {}
+    }
+}
+
+impl<I, M> GHeapSize for Meta<I, M>
+where
+    I: GHeapSize,
+    M: Singleton,
+{
+    fn gheap_size(&self) -> usize {
+        let Meta(inner, _) = self;
+        inner.gheap_size()
+    }
+}
+
+impl<I, C> GHeapSize for Con<I, C>
+where
+    I: GHeapSize,
+    C: Singleton,
+{
+    fn gheap_size(&self) -> usize {
+        let Con(inner, _) = self;
+        inner.gheap_size()
+    }
+}
+
+impl<A> GHeapSize for List<A>
+where
+    A: GHeapSize,
+{
+    fn gheap_size(&self) -> usize {
+        let List(items) = self;
+        items.iter().map(A::gheap_size).sum()
+    }
+}
+
+impl<A, const N: usize> GHeapSize for [A; N]
+where
+    A: GHeapSize,
+{
+    fn gheap_size(&self) -> usize {
+        self.iter().map(A::gheap_size).sum()
+    }
+}
+
+impl<T> GHeapSize for Borrowed<'_, T>
+where
+    T: HeapSize,
+{
+    fn gheap_size(&self) -> usize {
+        self.0.heap_size()
+    }
+}
+
+impl<T> GHeapSize for T
+where
+    T: Leaf + HeapSize,
+{
+    fn gheap_size(&self) -> usize {
+        self.heap_size()
+    }
+}
@@ -0,0 +1,265 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{Generic, ReprError};
+
+use super::tree::{FromTreeLeaf, GFromTree, GToTree, ToTreeLeaf, Tree};
+
+/// Converts a representation directly to a JSON value, using its metadata.
+///
+/// The value is the same as `serialize_generic` with `serde_json` would produce, without a pass through a serializer:
+/// structs are objects keyed by field name, tuple structs are arrays, or their only field if they have one,
+/// and unit structs are `null`. Unit variants are their name, and others are objects
+/// with their name as the only key. `Option` is `null` or its value, tuples, arrays, and lists are arrays,
+/// and leaves are converted with their `Serialize` impls.
+///
+/// `to_value` and `from_value` use this and `GFromValue` to convert `Generic` types to and from `serde_json::Value`,
+/// e.g. to merge layers of configuration, or edit a value by path, before converting it back.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{from_value, to_value};
+/// use generics::Generic;
+/// use serde_json::json;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Config {
+///     name: String,
+///     port: Option<u16>,
+///     log: Log,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum Log {
+///     Off,
+///     File { path: String },
+/// }
+///
+/// let config = Config { name: "app".to_string(), port: None, log: Log::Off };
+/// let mut value = to_value(config).unwrap();
+/// assert_eq!(value, json!({ "name": "app", "port": null, "log": "Off" }));
+///
+/// value["port"] = json!(8080);
+/// value["log"] = json!({ "File": { "path": "app.log" } });
+/// let config = from_value::<Config>(value).unwrap();
+/// assert_eq!(config.port, Some(8080));
+/// assert_eq!(config.log, Log::File { path: "app.log".to_string() });
+///
+/// let err = from_value::<Config>(json!({ "name": "app", "log": "On" })).unwrap_err();
+/// assert_eq!(err.to_string(), "unknown variant `On` for `log`");
+/// ```
+pub trait GToValue {
+    /// Converts `self` to a JSON value.
+    fn gto_value(self) -> Result<Value, serde_json::Error>;
+}
+
+/// Converts a JSON value directly to a representation, using its metadata.
+///
+/// This is the inverse of `GToValue`. Like serde's derive, structs can also be converted from arrays,
+/// unknown fields are ignored, and missing fields are an error, unless their value can be converted from `null`,
+/// e.g. `Option`s. Leaves are converted with their `Deserialize` impls.
+pub trait GFromValue: Sized {
+    /// Converts `value`, found at `path`, to `Self`.
+    fn gfrom_value(value: Value, path: &mut String) -> Result<Self, FromValueError>;
+}
+
+/// An error converting a representation from a JSON value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromValueError {
+    /// There was no value for the field with the given path.
+    Missing(String),
+    /// The value had the wrong type or length.
+    Invalid {
+        /// The path of the value.
+        path: String,
+        /// A description of the expected value, e.g. `"an array of length 2"`.
+        expected: String,
+    },
+    /// The name of a variant matched none of the variants of its sum.
+    Variant {
+        /// The path of the sum.
+        path: String,
+        /// The unknown variant name.
+        name: String,
+    },
+    /// The value of a leaf couldn't be deserialized.
+    Leaf {
+        /// The path of the leaf.
+        path: String,
+        /// The error from deserializing the leaf.
+        message: String,
+    },
+    /// The value was invalid for its type, e.g. a `NonZeroU8` of zero.
+    Repr(ReprError),
+}
+
+impl Display for FromValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromValueError::Missing(path) => write!(f, "missing value for `{}`", path),
+            FromValueError::Invalid { path, expected } => {
+                write!(f, "expected {} for `{}`", expected, path)
+            }
+            FromValueError::Variant { path, name } => {
+                write!(f, "unknown variant `{}` for `{}`", name, path)
+            }
+            FromValueError::Leaf { path, message } => {
+                write!(f, "invalid value for `{}`: {}", path, message)
+            }
+            FromValueError::Repr(error) => write!(f, "invalid value: {}", error),
+        }
+    }
+}
+
+impl Error for FromValueError {}
+
+impl Tree for Value {
+    type Object = Map<String, Value>;
+    type EncodeError = serde_json::Error;
+    type Error = FromValueError;
+
+    const STRING: &'static str = "a string";
+    const OBJECT: &'static str = "an object";
+
+    fn null() -> Self {
+        Value::Null
+    }
+
+    fn string(s: &str) -> Self {
+        Value::String(s.to_string())
+    }
+
+    fn array(items: Vec<Self>) -> Self {
+        Value::Array(items)
+    }
+
+    fn object(fields: Vec<(&'static str, Self)>) -> Self {
+        Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        )
+    }
+
+    fn is_null(&self) -> bool {
+        self.is_null()
+    }
+
+    fn into_array(self) -> Result<Vec<Self>, Self> {
+        match self {
+            Value::Array(values) => Ok(values),
+            value => Err(value),
+        }
+    }
+
+    fn into_string(self) -> Result<String, Self> {
+        match self {
+            Value::String(s) => Ok(s),
+            value => Err(value),
+        }
+    }
+
+    fn into_object(self) -> Result<Map<String, Value>, Self> {
+        match self {
+            Value::Object(object) => Ok(object),
+            value => Err(value),
+        }
+    }
+
+    fn remove(object: &mut Map<String, Value>, name: &str) -> Option<Self> {
+        object.remove(name)
+    }
+
+    fn into_entry(object: Map<String, Value>) -> Option<(String, Self)> {
+        match object.len() {
+            1 => object.into_iter().next(),
+            _ => None,
+        }
+    }
+
+    fn missing(path: &str) -> FromValueError {
+        FromValueError::Missing(path.to_string())
+    }
+
+    fn invalid(path: &str, expected: &dyn Display) -> FromValueError {
+        FromValueError::Invalid {
+            path: path.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    fn variant(path: &str, name: String) -> FromValueError {
+        FromValueError::Variant {
+            path: path.to_string(),
+            name,
+        }
+    }
+}
+
+impl<T> ToTreeLeaf<Value> for T
+where
+    T: Serialize,
+{
+    fn to_tree(self) -> Result<Value, serde_json::Error> {
+        serde_json::to_value(self)
+    }
+}
+
+impl<T> FromTreeLeaf<Value> for T
+where
+    T: DeserializeOwned,
+{
+    fn from_tree(value: Value, path: &str) -> Result<Self, FromValueError> {
+        serde_json::from_value(value).map_err(|e| FromValueError::Leaf {
+            path: path.to_string(),
+            message: e.to_string(),
+        })
+    }
+}
+
+impl<T> GToValue for T
+where
+    T: GToTree<Value>,
+{
+    fn gto_value(self) -> Result<Value, serde_json::Error> {
+        self.gto_tree()
+    }
+}
+
+impl<T> GFromValue for T
+where
+    T: GFromTree<Value>,
+{
+    fn gfrom_value(value: Value, path: &mut String) -> Result<Self, FromValueError> {
+        T::gfrom_tree(value, path)
+    }
+}
+
+/// Converts `x` to a JSON value, as `serialize_generic` with `serde_json` would.
+///
+/// Fails only if a leaf fails to serialize, e.g. a map with non-string keys.
+pub fn to_value<T>(x: T) -> Result<Value, serde_json::Error>
+where
+    T: Generic,
+    T::Repr: GToValue,
+{
+    x.into_repr().gto_value()
+}
+
+/// Converts a JSON value to a `T`, as `deserialize_generic` with `serde_json` would.
+///
+/// Errors name the path of the value which failed to convert, as produced by `flatten`.
+pub fn from_value<T>(value: Value) -> Result<T, FromValueError>
+where
+    T: Generic,
+    T::Repr: GFromValue,
+{
+    let repr = T::Repr::gfrom_value(value, &mut String::new())?;
+    T::checked_from_repr(repr).map_err(FromValueError::Repr)
+}
@@ -0,0 +1,702 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt::{self, Display, Write};
+use std::str::FromStr;
+
+use crate::{Generic, Opaque, ReprError};
+
+use super::tree::{FromTreeLeaf, GFromTree, GToTree, ToTreeLeaf, Tree};
+
+/// A JSON value, as read and written by `decode_json` and `encode_json`.
+///
+/// Numbers keep their text, so integers of any size round-trip exactly, and objects keep the order of their keys.
+/// This is written with `Display`, and read with `FromStr`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Json {
+    /// `null`.
+    Null,
+    /// `true` or `false`.
+    Bool(bool),
+    /// A number, as written.
+    Number(String),
+    /// A string, without escapes.
+    String(String),
+    /// An array.
+    Array(Vec<Json>),
+    /// An object, as pairs of keys and values.
+    Object(Vec<(String, Json)>),
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Number(n) => f.write_str(n),
+            Json::String(s) => write_string(f, s),
+            Json::Array(items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                f.write_char(']')
+            }
+            Json::Object(pairs) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c < ' ' => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl FromStr for Json {
+    type Err = DecodeJsonError;
+
+    /// Parses a single JSON value, surrounded by optional whitespace.
+    ///
+    /// Arrays and objects may be nested at most 128 deep.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            s,
+            pos: 0,
+            depth: 0,
+        };
+        let json = parser.value()?;
+        parser.whitespace();
+        if parser.pos < s.len() {
+            return Err(DecodeJsonError::Syntax(parser.pos));
+        }
+        Ok(json)
+    }
+}
+
+/// The deepest arrays and objects may be nested, so malicious input can't overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+/// A recursive descent parser over the bytes of a string.
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+    /// The number of arrays and objects the parser is inside.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.s.as_bytes().get(self.pos).copied()
+    }
+
+    fn error<T>(&self) -> Result<T, DecodeJsonError> {
+        Err(DecodeJsonError::Syntax(self.pos))
+    }
+
+    fn whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        self.whitespace();
+        let found = self.peek() == Some(byte);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn keyword(&mut self, keyword: &str, json: Json) -> Result<Json, DecodeJsonError> {
+        if !self.s[self.pos..].starts_with(keyword) {
+            return self.error();
+        }
+        self.pos += keyword.len();
+        Ok(json)
+    }
+
+    fn value(&mut self) -> Result<Json, DecodeJsonError> {
+        self.whitespace();
+        match self.peek() {
+            Some(b'n') => self.keyword("null", Json::Null),
+            Some(b't') => self.keyword("true", Json::Bool(true)),
+            Some(b'f') => self.keyword("false", Json::Bool(false)),
+            Some(b'"') => self.string().map(Json::String),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(b'[' | b'{') => {
+                if self.depth == MAX_DEPTH {
+                    return Err(DecodeJsonError::Depth(self.pos));
+                }
+                self.depth += 1;
+                let json = self.nested();
+                self.depth -= 1;
+                json
+            }
+            _ => self.error(),
+        }
+    }
+
+    /// Parses an array or object, starting at its opening bracket.
+    fn nested(&mut self) -> Result<Json, DecodeJsonError> {
+        match self.peek() {
+            Some(b'[') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                if !self.eat(b']') {
+                    loop {
+                        items.push(self.value()?);
+                        if self.eat(b']') {
+                            break;
+                        }
+                        if !self.eat(b',') {
+                            return self.error();
+                        }
+                    }
+                }
+                Ok(Json::Array(items))
+            }
+            _ => {
+                self.pos += 1;
+                let mut pairs = Vec::new();
+                if !self.eat(b'}') {
+                    loop {
+                        self.whitespace();
+                        if self.peek() != Some(b'"') {
+                            return self.error();
+                        }
+                        let key = self.string()?;
+                        if !self.eat(b':') {
+                            return self.error();
+                        }
+                        pairs.push((key, self.value()?));
+                        if self.eat(b'}') {
+                            break;
+                        }
+                        if !self.eat(b',') {
+                            return self.error();
+                        }
+                    }
+                }
+                Ok(Json::Object(pairs))
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, DecodeJsonError> {
+        let start = self.pos;
+        let digits = |p: &mut Self| {
+            let start = p.pos;
+            while let Some(b'0'..=b'9') = p.peek() {
+                p.pos += 1;
+            }
+            p.pos > start
+        };
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        // The integer part has no leading zeros.
+        if self.peek() == Some(b'0') {
+            self.pos += 1;
+            if let Some(b'0'..=b'9') = self.peek() {
+                return self.error();
+            }
+        } else if !digits(self) {
+            return self.error();
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if !digits(self) {
+                return self.error();
+            }
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if !digits(self) {
+                return self.error();
+            }
+        }
+        Ok(Json::Number(self.s[start..self.pos].to_string()))
+    }
+
+    fn string(&mut self) -> Result<String, DecodeJsonError> {
+        self.pos += 1;
+        let mut out = String::new();
+        loop {
+            let rest = &self.s[self.pos..];
+            let end = match rest.find(|c| matches!(c, '"' | '\\' | '\0'..='\u{1f}')) {
+                Some(end) => end,
+                None => return Err(DecodeJsonError::Syntax(self.s.len())),
+            };
+            out.push_str(&rest[..end]);
+            // Control characters must be escaped.
+            if rest.as_bytes()[end] < b' ' {
+                self.pos += end;
+                return self.error();
+            }
+            self.pos += end + 1;
+            if rest.as_bytes()[end] == b'"' {
+                return Ok(out);
+            }
+            let c = match self.peek() {
+                Some(b'"') => '"',
+                Some(b'\\') => '\\',
+                Some(b'/') => '/',
+                Some(b'b') => '\u{8}',
+                Some(b'f') => '\u{c}',
+                Some(b'n') => '\n',
+                Some(b'r') => '\r',
+                Some(b't') => '\t',
+                Some(b'u') => {
+                    self.pos += 1;
+                    let high = self.hex()?;
+                    let code = if (0xd800..0xdc00).contains(&high) {
+                        if !self.s[self.pos..].starts_with("\\u") {
+                            return self.error();
+                        }
+                        self.pos += 2;
+                        let low = self.hex()?;
+                        if !(0xdc00..0xe000).contains(&low) {
+                            return self.error();
+                        }
+                        0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+                    } else {
+                        high
+                    };
+                    match char::from_u32(code) {
+                        Some(c) => out.push(c),
+                        None => return self.error(),
+                    }
+                    continue;
+                }
+                _ => return self.error(),
+            };
+            out.push(c);
+            self.pos += 1;
+        }
+    }
+
+    fn hex(&mut self) -> Result<u32, DecodeJsonError> {
+        let code = self
+            .s
+            .get(self.pos..self.pos + 4)
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok());
+        match code {
+            Some(code) => {
+                self.pos += 4;
+                Ok(code)
+            }
+            None => self.error(),
+        }
+    }
+}
+
+/// A leaf which can be converted to and from JSON, without serde.
+///
+/// Implement this for your own leaf types to encode and decode them with `GToJson` and `GFromJson`.
+pub trait JsonLeaf: Sized {
+    /// A description of the JSON this is decoded from, for errors, e.g. `"a u8"`.
+    const EXPECTED: &'static str;
+
+    /// Converts `self` to JSON.
+    fn to_json(self) -> Json;
+
+    /// Converts `json` to `Self`, or returns `None` if it's invalid.
+    fn from_json(json: Json) -> Option<Self>;
+}
+
+macro_rules! impl_json_leaf_number {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            impl JsonLeaf for $ty {
+                const EXPECTED: &'static str = concat!("a ", stringify!($ty));
+
+                fn to_json(self) -> Json {
+                    Json::Number(self.to_string())
+                }
+
+                fn from_json(json: Json) -> Option<Self> {
+                    match json {
+                        Json::Number(n) => n.parse().ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+#[rustfmt::skip]
+impl_json_leaf_number!(
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+);
+
+macro_rules! impl_json_leaf_float {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            /// Non-finite values are encoded as `null`, and decoded as NaN.
+            impl JsonLeaf for $ty {
+                const EXPECTED: &'static str = concat!("an ", stringify!($ty));
+
+                fn to_json(self) -> Json {
+                    if !self.is_finite() {
+                        return Json::Null;
+                    }
+                    let mut n = self.to_string();
+                    if !n.contains(['.', 'e']) {
+                        n.push_str(".0");
+                    }
+                    Json::Number(n)
+                }
+
+                fn from_json(json: Json) -> Option<Self> {
+                    match json {
+                        Json::Number(n) => n.parse().ok(),
+                        Json::Null => Some($ty::NAN),
+                        _ => None,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+impl_json_leaf_float!(f32, f64);
+
+impl JsonLeaf for bool {
+    const EXPECTED: &'static str = "a boolean";
+
+    fn to_json(self) -> Json {
+        Json::Bool(self)
+    }
+
+    fn from_json(json: Json) -> Option<Self> {
+        match json {
+            Json::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+}
+
+impl JsonLeaf for char {
+    const EXPECTED: &'static str = "a string of one character";
+
+    fn to_json(self) -> Json {
+        Json::String(self.to_string())
+    }
+
+    fn from_json(json: Json) -> Option<Self> {
+        match json {
+            Json::String(s) => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Some(c),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl JsonLeaf for String {
+    const EXPECTED: &'static str = "a string";
+
+    fn to_json(self) -> Json {
+        Json::String(self)
+    }
+
+    fn from_json(json: Json) -> Option<Self> {
+        match json {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl<T> JsonLeaf for Opaque<T>
+where
+    T: JsonLeaf,
+{
+    const EXPECTED: &'static str = T::EXPECTED;
+
+    fn to_json(self) -> Json {
+        self.0.to_json()
+    }
+
+    fn from_json(json: Json) -> Option<Self> {
+        T::from_json(json).map(Opaque)
+    }
+}
+
+/// Converts a representation to JSON, using its metadata, without serde.
+///
+/// The JSON is the same as `serialize_generic` with `serde_json` would produce, for the leaves implementing `JsonLeaf`:
+/// structs are objects keyed by field name, tuple structs are arrays, or their only field if they have one,
+/// and unit structs are `null`. Unit variants are their name, and others are objects
+/// with their name as the only key. `Option` is `null` or its value, and tuples, arrays, and lists are arrays.
+///
+/// `encode_json` and `decode_json` use this and `GFromJson` to write and read `Generic` types as JSON text,
+/// for users who don't otherwise depend on serde. Only the `json` feature is required, which has no dependencies.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{decode_json, encode_json, DecodeJsonError};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Point {
+///     label: Option<String>,
+///     coords: (i32, i32),
+///     style: Style,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum Style {
+///     Dot,
+///     Circle { radius: f64 },
+/// }
+///
+/// let point = Point { label: None, coords: (1, -2), style: Style::Circle { radius: 1.5 } };
+/// let json = encode_json(point);
+/// assert_eq!(json, r#"{"label":null,"coords":[1,-2],"style":{"Circle":{"radius":1.5}}}"#);
+///
+/// let point = decode_json::<Point>(r#"{ "label": "A", "coords": [0, 0], "style": "Dot" }"#).unwrap();
+/// assert_eq!(point, Point { label: Some("A".to_string()), coords: (0, 0), style: Style::Dot });
+///
+/// let err = decode_json::<Point>(r#"{ "label": 1 }"#).unwrap_err();
+/// assert_eq!(err, DecodeJsonError::Invalid { path: "label.0".to_string(), expected: "a string".to_string() });
+/// ```
+pub trait GToJson {
+    /// Converts `self` to JSON.
+    fn gto_json(self) -> Json;
+}
+
+/// Converts JSON to a representation, using its metadata, without serde.
+///
+/// This is the inverse of `GToJson`. Like serde's derive, structs can also be decoded from arrays,
+/// unknown fields are ignored, and missing fields are an error, unless they can be decoded from `null`, e.g. `Option`s.
+pub trait GFromJson: Sized {
+    /// Converts `json`, found at `path`, to `Self`.
+    fn gfrom_json(json: Json, path: &mut String) -> Result<Self, DecodeJsonError>;
+}
+
+/// An error decoding a representation from JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeJsonError {
+    /// The text wasn't valid JSON, at the given byte offset.
+    Syntax(usize),
+    /// Arrays and objects were nested more than 128 deep, at the given byte offset.
+    Depth(usize),
+    /// There was no value for the field with the given path.
+    Missing(String),
+    /// The value had the wrong type or length, or a leaf was invalid.
+    Invalid {
+        /// The path of the value.
+        path: String,
+        /// A description of the expected value, e.g. `"an array of length 2"`.
+        expected: String,
+    },
+    /// The name of a variant matched none of the variants of its sum.
+    Variant {
+        /// The path of the sum.
+        path: String,
+        /// The unknown variant name.
+        name: String,
+    },
+    /// The value was invalid for its type, e.g. a `NonZeroU8` of zero.
+    Repr(ReprError),
+}
+
+impl Display for DecodeJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeJsonError::Syntax(pos) => write!(f, "invalid JSON at byte {}", pos),
+            DecodeJsonError::Depth(pos) => write!(f, "JSON nested too deeply at byte {}", pos),
+            DecodeJsonError::Missing(path) => write!(f, "missing value for `{}`", path),
+            DecodeJsonError::Invalid { path, expected } => {
+                write!(f, "expected {} for `{}`", expected, path)
+            }
+            DecodeJsonError::Variant { path, name } => {
+                write!(f, "unknown variant `{}` for `{}`", name, path)
+            }
+            DecodeJsonError::Repr(error) => write!(f, "invalid value: {}", error),
+        }
+    }
+}
+
+impl Error for DecodeJsonError {}
+
+impl Tree for Json {
+    type Object = Vec<(String, Json)>;
+    type EncodeError = Infallible;
+    type Error = DecodeJsonError;
+
+    const STRING: &'static str = "a string";
+    const OBJECT: &'static str = "an object";
+
+    fn null() -> Self {
+        Json::Null
+    }
+
+    fn string(s: &str) -> Self {
+        Json::String(s.to_string())
+    }
+
+    fn array(items: Vec<Self>) -> Self {
+        Json::Array(items)
+    }
+
+    fn object(fields: Vec<(&'static str, Self)>) -> Self {
+        Json::Object(
+            fields
+                .into_iter()
+                .map(|(name, json)| (name.to_string(), json))
+                .collect(),
+        )
+    }
+
+    fn is_null(&self) -> bool {
+        *self == Json::Null
+    }
+
+    fn into_array(self) -> Result<Vec<Self>, Self> {
+        match self {
+            Json::Array(items) => Ok(items),
+            json => Err(json),
+        }
+    }
+
+    fn into_string(self) -> Result<String, Self> {
+        match self {
+            Json::String(s) => Ok(s),
+            json => Err(json),
+        }
+    }
+
+    fn into_object(self) -> Result<Vec<(String, Json)>, Self> {
+        match self {
+            Json::Object(pairs) => Ok(pairs),
+            json => Err(json),
+        }
+    }
+
+    fn remove(pairs: &mut Vec<(String, Json)>, name: &str) -> Option<Self> {
+        let i = pairs.iter().position(|(key, _)| key == name)?;
+        Some(pairs.swap_remove(i).1)
+    }
+
+    fn into_entry(pairs: Vec<(String, Json)>) -> Option<(String, Self)> {
+        match pairs.len() {
+            1 => pairs.into_iter().next(),
+            _ => None,
+        }
+    }
+
+    fn missing(path: &str) -> DecodeJsonError {
+        DecodeJsonError::Missing(path.to_string())
+    }
+
+    fn invalid(path: &str, expected: &dyn Display) -> DecodeJsonError {
+        DecodeJsonError::Invalid {
+            path: path.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    fn variant(path: &str, name: String) -> DecodeJsonError {
+        DecodeJsonError::Variant {
+            path: path.to_string(),
+            name,
+        }
+    }
+}
+
+impl<T> ToTreeLeaf<Json> for T
+where
+    T: JsonLeaf,
+{
+    fn to_tree(self) -> Result<Json, Infallible> {
+        Ok(self.to_json())
+    }
+}
+
+impl<T> FromTreeLeaf<Json> for T
+where
+    T: JsonLeaf,
+{
+    fn from_tree(json: Json, path: &str) -> Result<Self, DecodeJsonError> {
+        T::from_json(json).ok_or_else(|| Json::invalid(path, &T::EXPECTED))
+    }
+}
+
+impl<T> GToJson for T
+where
+    T: GToTree<Json>,
+{
+    fn gto_json(self) -> Json {
+        match self.gto_tree() {
+            Ok(json) => json,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl<T> GFromJson for T
+where
+    T: GFromTree<Json>,
+{
+    fn gfrom_json(json: Json, path: &mut String) -> Result<Self, DecodeJsonError> {
+        T::gfrom_tree(json, path)
+    }
+}
+
+/// Encodes `x` as JSON text, as `serialize_generic` with `serde_json` would.
+pub fn encode_json<T>(x: T) -> String
+where
+    T: Generic,
+    T::Repr: GToJson,
+{
+    x.into_repr().gto_json().to_string()
+}
+
+/// Decodes a `T` from JSON text, as `deserialize_generic` with `serde_json` would.
+///
+/// Errors name the path of the value which failed to decode, as produced by `flatten`.
+pub fn decode_json<T>(s: &str) -> Result<T, DecodeJsonError>
+where
+    T: Generic,
+    T::Repr: GFromJson,
+{
+    let json = s.parse::<Json>()?;
+    let repr = T::Repr::gfrom_json(json, &mut String::new())?;
+    T::checked_from_repr(repr).map_err(DecodeJsonError::Repr)
+}
@@ -0,0 +1,457 @@
+use std::any::TypeId;
+
+use crate::markers::Option_None_Name;
+use crate::{Con, Documented, Generic, List, Meta, Opaque, Prod, Singleton, Sum, Unit, Void};
+
+use super::json_codec::Json;
+
+/// The dialect of the schemas produced by `json_schema`.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// A leaf whose JSON can be described by a JSON Schema.
+///
+/// Implement this for your own leaf types to describe them with `GJsonSchema`,
+/// matching their `JsonLeaf` impl.
+pub trait JsonSchemaLeaf {
+    /// A schema accepting the JSON of every value of `Self`.
+    fn json_schema() -> Json;
+}
+
+/// Creates an object from its pairs of keys and values.
+fn object<I>(pairs: I) -> Json
+where
+    I: IntoIterator<Item = (&'static str, Json)>,
+{
+    Json::Object(
+        pairs
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+}
+
+fn string(s: &str) -> Json {
+    Json::String(s.to_string())
+}
+
+fn number(n: impl ToString) -> Json {
+    Json::Number(n.to_string())
+}
+
+/// Adds a `description` to `schema`, unless `doc` is empty.
+fn describe(schema: Json, doc: &str) -> Json {
+    match schema {
+        Json::Object(mut pairs) if !doc.is_empty() => {
+            pairs.push(("description".to_string(), string(doc)));
+            Json::Object(pairs)
+        }
+        // `true` and `false` can't hold keywords.
+        schema => schema,
+    }
+}
+
+macro_rules! impl_json_schema_leaf_int {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            impl JsonSchemaLeaf for $ty {
+                fn json_schema() -> Json {
+                    object(vec![
+                        ("type", string("integer")),
+                        ("minimum", number($ty::MIN)),
+                        ("maximum", number($ty::MAX)),
+                    ])
+                }
+            }
+        )+
+    }
+}
+
+#[rustfmt::skip]
+impl_json_schema_leaf_int!(
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+);
+
+macro_rules! impl_json_schema_leaf_float {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            /// A number, or `null` for non-finite values.
+            impl JsonSchemaLeaf for $ty {
+                fn json_schema() -> Json {
+                    object(vec![(
+                        "type",
+                        Json::Array(vec![string("number"), string("null")]),
+                    )])
+                }
+            }
+        )+
+    }
+}
+
+impl_json_schema_leaf_float!(f32, f64);
+
+impl JsonSchemaLeaf for bool {
+    fn json_schema() -> Json {
+        object(vec![("type", string("boolean"))])
+    }
+}
+
+impl JsonSchemaLeaf for char {
+    fn json_schema() -> Json {
+        object(vec![
+            ("type", string("string")),
+            ("minLength", number(1)),
+            ("maxLength", number(1)),
+        ])
+    }
+}
+
+impl JsonSchemaLeaf for String {
+    fn json_schema() -> Json {
+        object(vec![("type", string("string"))])
+    }
+}
+
+impl<T> JsonSchemaLeaf for Opaque<T>
+where
+    T: JsonSchemaLeaf,
+{
+    fn json_schema() -> Json {
+        T::json_schema()
+    }
+}
+
+/// Describes the JSON of a representation as a JSON Schema, using its metadata, without needing a value.
+///
+/// The schema accepts the JSON written by `GToJson`, as described there: structs are objects whose properties
+/// are their fields, tuple structs are arrays, or their only field if they have one, and those with no fields are `null`.
+/// Variants with no fields are their name, and others are objects with their name as the only key. `Option` is `null`
+/// or its value, and fields of an `Option` aren't required. Unknown properties are allowed, as `GFromJson` ignores them.
+/// The doc comments of constructors and fields, from `Documented`, become their `description`.
+///
+/// `json_schema` uses this to produce a schema for a `Generic` type, e.g. to validate configuration files,
+/// or to give editors completions and hover text for them. Recursive types aren't supported,
+/// since their schemas are generated inline. Only the `json` feature is required.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::json_schema;
+/// use generics::Generic;
+///
+/// /// Settings for the server.
+/// #[derive(Generic)]
+/// struct Config {
+///     /// The port to listen on.
+///     port: u16,
+///     log: Option<Level>,
+/// }
+///
+/// #[derive(Generic)]
+/// enum Level {
+///     Info,
+///     /// Log everything.
+///     Debug,
+/// }
+///
+/// assert_eq!(
+///     json_schema::<Config>().to_string(),
+///     r#"{"$schema":"https://json-schema.org/draft/2020-12/schema","#.to_string()
+///         + r#""type":"object","properties":{"#
+///         + r#""port":{"type":"integer","minimum":0,"maximum":65535,"description":"The port to listen on."},"#
+///         + r#""log":{"anyOf":[{"type":"null"},{"oneOf":[{"const":"Info"},{"const":"Debug","description":"Log everything."}]}]}"#
+///         + r#"},"required":["port"],"description":"Settings for the server."}"#,
+/// );
+/// ```
+pub trait GJsonSchema {
+    /// A schema accepting the JSON of every value of `Self`.
+    fn json_schema() -> Json;
+
+    /// Whether `null` is a value of `Self`, so it may be left out as a field.
+    fn nullable() -> bool {
+        false
+    }
+}
+
+/// Describes the elements of a `Prod` chain.
+pub trait GJsonSchemaElements {
+    /// Appends the schema of each element to `items`.
+    fn schema_elements(items: &mut Vec<Json>);
+}
+
+/// Describes the fields of a constructor.
+pub trait GJsonSchemaFields {
+    /// The number of fields.
+    const LEN: usize;
+
+    /// Appends the name, schema, and nullability of each field to `fields`.
+    fn schema_fields(fields: &mut Vec<(&'static str, Json, bool)>);
+}
+
+/// Describes the variants of a `Sum` chain.
+pub trait GJsonSchemaVariants {
+    /// Whether this is the representation of `Option`.
+    fn is_option() -> bool;
+
+    /// Appends the name, doc comment, and schema of the fields, if there are any, of each variant to `variants`.
+    fn schema_variants(variants: &mut Vec<(&'static str, &'static str, Option<Json>)>);
+}
+
+/// Describes the fields of a constructor, or `None` if it has no fields.
+fn fields_schema(fields: Vec<(&'static str, Json, bool)>) -> Option<Json> {
+    match fields.first() {
+        None => None,
+        Some(&("0", _, _)) if fields.len() == 1 => fields.into_iter().next().map(|(_, s, _)| s),
+        Some(&("0", _, _)) => {
+            let len = fields.len();
+            Some(object(vec![
+                ("type", string("array")),
+                (
+                    "prefixItems",
+                    Json::Array(fields.into_iter().map(|(_, s, _)| s).collect()),
+                ),
+                ("minItems", number(len)),
+                ("maxItems", number(len)),
+            ]))
+        }
+        Some(_) => {
+            let required = fields
+                .iter()
+                .filter(|&&(_, _, nullable)| !nullable)
+                .map(|&(name, _, _)| string(name))
+                .collect();
+            let properties = fields
+                .into_iter()
+                .map(|(name, s, _)| (name.to_string(), s))
+                .collect();
+            Some(object(vec![
+                ("type", string("object")),
+                ("properties", Json::Object(properties)),
+                ("required", Json::Array(required)),
+            ]))
+        }
+    }
+}
+
+fn null() -> Json {
+    object(vec![("type", string("null"))])
+}
+
+impl GJsonSchema for Unit {
+    fn json_schema() -> Json {
+        null()
+    }
+
+    fn nullable() -> bool {
+        true
+    }
+}
+
+impl<A, B> GJsonSchema for Prod<A, B>
+where
+    Self: GJsonSchemaElements,
+{
+    fn json_schema() -> Json {
+        let mut items = Vec::new();
+        Self::schema_elements(&mut items);
+        let len = items.len();
+        object(vec![
+            ("type", string("array")),
+            ("prefixItems", Json::Array(items)),
+            ("minItems", number(len)),
+            ("maxItems", number(len)),
+        ])
+    }
+}
+
+impl GJsonSchemaElements for Unit {
+    fn schema_elements(_items: &mut Vec<Json>) {}
+}
+
+impl<A, B> GJsonSchemaElements for Prod<A, B>
+where
+    A: GJsonSchema,
+    B: GJsonSchemaElements,
+{
+    fn schema_elements(items: &mut Vec<Json>) {
+        items.push(A::json_schema());
+        B::schema_elements(items);
+    }
+}
+
+impl GJsonSchemaFields for Unit {
+    const LEN: usize = 0;
+
+    fn schema_fields(_fields: &mut Vec<(&'static str, Json, bool)>) {}
+}
+
+impl<A, M, B> GJsonSchemaFields for Prod<Meta<A, M>, B>
+where
+    A: GJsonSchema,
+    M: Singleton<T = &'static str> + Documented,
+    B: GJsonSchemaFields,
+{
+    const LEN: usize = 1 + B::LEN;
+
+    fn schema_fields(fields: &mut Vec<(&'static str, Json, bool)>) {
+        fields.push((
+            M::get(),
+            describe(A::json_schema(), M::doc()),
+            A::nullable(),
+        ));
+        B::schema_fields(fields);
+    }
+}
+
+impl<L, R> GJsonSchema for Sum<L, R>
+where
+    Self: GJsonSchemaVariants,
+{
+    fn json_schema() -> Json {
+        let mut variants = Vec::new();
+        Self::schema_variants(&mut variants);
+        if Self::is_option() {
+            let some = variants.pop().and_then(|(_, _, s)| s).unwrap_or_else(null);
+            return object(vec![("anyOf", Json::Array(vec![null(), some]))]);
+        }
+        let variants = variants
+            .into_iter()
+            .map(|(name, doc, schema)| {
+                let schema = match schema {
+                    None => object(vec![("const", string(name))]),
+                    Some(schema) => object(vec![
+                        ("type", string("object")),
+                        ("properties", Json::Object(vec![(name.to_string(), schema)])),
+                        ("required", Json::Array(vec![string(name)])),
+                        ("additionalProperties", Json::Bool(false)),
+                    ]),
+                };
+                describe(schema, doc)
+            })
+            .collect();
+        object(vec![("oneOf", Json::Array(variants))])
+    }
+
+    fn nullable() -> bool {
+        Self::is_option()
+    }
+}
+
+impl<I, C, R> GJsonSchemaVariants for Sum<Con<I, C>, R>
+where
+    I: GJsonSchemaFields,
+    C: Singleton<T = &'static str> + Documented + 'static,
+    R: GJsonSchemaVariants,
+{
+    fn is_option() -> bool {
+        TypeId::of::<C>() == TypeId::of::<Option_None_Name>()
+    }
+
+    fn schema_variants(variants: &mut Vec<(&'static str, &'static str, Option<Json>)>) {
+        let mut fields = Vec::new();
+        I::schema_fields(&mut fields);
+        variants.push((C::get(), C::doc(), fields_schema(fields)));
+        R::schema_variants(variants);
+    }
+}
+
+impl GJsonSchemaVariants for Void {
+    fn is_option() -> bool {
+        false
+    }
+
+    fn schema_variants(_variants: &mut Vec<(&'static str, &'static str, Option<Json>)>) {}
+}
+
+/// No JSON is a value of an empty enum.
+impl GJsonSchema for Void {
+    fn json_schema() -> Json {
+        Json::Bool(false)
+    }
+}
+
+impl<I, M> GJsonSchema for Meta<I, M>
+where
+    I: GJsonSchema,
+    M: Singleton,
+{
+    fn json_schema() -> Json {
+        I::json_schema()
+    }
+
+    fn nullable() -> bool {
+        I::nullable()
+    }
+}
+
+impl<I, C> GJsonSchema for Con<I, C>
+where
+    I: GJsonSchemaFields,
+    C: Singleton + Documented,
+{
+    fn json_schema() -> Json {
+        let mut fields = Vec::new();
+        I::schema_fields(&mut fields);
+        describe(fields_schema(fields).unwrap_or_else(null), C::doc())
+    }
+
+    fn nullable() -> bool {
+        I::LEN == 0
+    }
+}
+
+impl<A> GJsonSchema for List<A>
+where
+    A: GJsonSchema,
+{
+    fn json_schema() -> Json {
+        object(vec![("type", string("array")), ("items", A::json_schema())])
+    }
+}
+
+impl<A, const N: usize> GJsonSchema for [A; N]
+where
+    A: GJsonSchema,
+{
+    fn json_schema() -> Json {
+        object(vec![
+            ("type", string("array")),
+            ("items", A::json_schema()),
+            ("minItems", number(N)),
+            ("maxItems", number(N)),
+        ])
+    }
+}
+
+impl<T> GJsonSchema for T
+where
+    T: JsonSchemaLeaf,
+{
+    fn json_schema() -> Json {
+        T::json_schema()
+    }
+}
+
+/// A JSON Schema, in the 2020-12 dialect, accepting the JSON of every `T` as written by `encode_json`.
+///
+/// ```rust
+/// use generics::ops::json_schema;
+///
+/// assert_eq!(
+///     json_schema::<Vec<bool>>().to_string(),
+///     r#"{"$schema":"https://json-schema.org/draft/2020-12/schema","type":"array","items":{"type":"boolean"}}"#,
+/// );
+/// ```
+pub fn json_schema<T>() -> Json
+where
+    T: Generic,
+    T::Repr: GJsonSchema,
+{
+    match T::Repr::json_schema() {
+        Json::Object(mut pairs) => {
+            pairs.insert(0, ("$schema".to_string(), string(JSON_SCHEMA_DIALECT)));
+            Json::Object(pairs)
+        }
+        schema => schema,
+    }
+}
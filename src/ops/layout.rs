@@ -0,0 +1,164 @@
+use std::fmt::{self, Display};
+use std::mem;
+
+use crate::{Con, Meta, Prod, Singleton, Unit};
+
+use super::Borrowed;
+
+/// Reports the size, alignment, and padding of each field of a struct, labelled by the field names in its metadata.
+///
+/// This is implemented over representations of `Borrowed` fields, since the representation of a field
+/// may not have the same layout as the field itself. `#[derive(GenericLayout)]` uses this to implement
+/// an inherent `layout` function, alongside `#[derive(Generic)]`, passing the offset of each field from `offset_of!`,
+/// since the compiler may reorder the fields of structs without `#[repr(C)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::GenericLayout;
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericLayout)]
+/// #[repr(C)]
+/// struct Foo {
+///     a: u8,
+///     b: u32,
+///     c: u8,
+/// }
+///
+/// let layout = Foo::layout();
+/// assert_eq!(layout.name, "Foo");
+/// assert_eq!((layout.size, layout.align), (12, 4));
+/// assert_eq!(layout.fields[1].name, "b");
+/// assert_eq!((layout.fields[1].offset, layout.fields[1].padding), (4, 3));
+/// assert_eq!(layout.padding(), 6);
+/// ```
+pub trait GLayout {
+    /// The layout of a constructor whose value has the given size and alignment,
+    /// and whose fields have the given offsets, in declaration order.
+    fn layout(size: usize, align: usize, offsets: &[usize]) -> LayoutReport;
+}
+
+/// Collects the layouts of the elements of a `Prod` chain.
+pub trait GLayoutFields {
+    /// Appends the layout of each field to `fields`, without its offset or padding.
+    fn field_layouts(fields: &mut Vec<FieldLayout>);
+}
+
+/// The layout of a struct, as reported by `GLayout`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutReport {
+    /// The name of the struct.
+    pub name: &'static str,
+    /// The size of the struct, in bytes.
+    pub size: usize,
+    /// The alignment of the struct, in bytes.
+    pub align: usize,
+    /// The layout of each field, in declaration order.
+    pub fields: Vec<FieldLayout>,
+}
+
+/// The layout of a single field, as reported by `GLayout`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldLayout {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The size of the field, in bytes.
+    pub size: usize,
+    /// The alignment of the field, in bytes.
+    pub align: usize,
+    /// The offset of the field from the start of the struct, in bytes.
+    pub offset: usize,
+    /// The padding inserted before the field, in bytes, i.e. between it and the field before it in memory,
+    /// which may not be the field before it in declaration order.
+    pub padding: usize,
+}
+
+impl LayoutReport {
+    /// The total padding in the struct, in bytes, i.e. its size less the sizes of its fields.
+    ///
+    /// This includes any padding after the last field in memory, which no field reports.
+    pub fn padding(&self) -> usize {
+        self.size - self.fields.iter().map(|field| field.size).sum::<usize>()
+    }
+}
+
+impl Display for LayoutReport {
+    /// Formats a line for the struct, followed by an indented line for each field.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: size {}, align {}, padding {}",
+            self.name,
+            self.size,
+            self.align,
+            self.padding()
+        )?;
+        for field in &self.fields {
+            write!(
+                f,
+                "\n  {}: offset {}, size {}, align {}, padding {}",
+                field.name, field.offset, field.size, field.align, field.padding
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<I, C> GLayout for Con<I, C>
+where
+    I: GLayoutFields,
+    C: Singleton<T = &'static str>,
+{
+    fn layout(size: usize, align: usize, offsets: &[usize]) -> LayoutReport {
+        let mut fields = Vec::new();
+        I::field_layouts(&mut fields);
+        for (field, &offset) in fields.iter_mut().zip(offsets) {
+            field.offset = offset;
+        }
+        // Zero-sized fields may share an offset with the next field, so they're ordered first.
+        let mut in_memory = fields.iter_mut().collect::<Vec<_>>();
+        in_memory.sort_by_key(|field| (field.offset, field.size));
+        let mut end = 0;
+        for field in in_memory {
+            field.padding = field.offset - end;
+            end = field.offset + field.size;
+        }
+        LayoutReport {
+            name: C::get(),
+            size,
+            align,
+            fields,
+        }
+    }
+}
+
+impl GLayoutFields for Unit {
+    fn field_layouts(_fields: &mut Vec<FieldLayout>) {}
+}
+
+impl<A, B> GLayoutFields for Prod<A, B>
+where
+    A: GLayoutFields,
+    B: GLayoutFields,
+{
+    fn field_layouts(fields: &mut Vec<FieldLayout>) {
+        A::field_layouts(fields);
+        B::field_layouts(fields);
+    }
+}
+
+impl<'a, T, M> GLayoutFields for Meta<Borrowed<'a, T>, M>
+where
+    M: Singleton<T = &'static str>,
+{
+    fn field_layouts(fields: &mut Vec<FieldLayout>) {
+        fields.push(FieldLayout {
+            name: M::get(),
+            size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+            offset: 0,
+            padding: 0,
+        });
+    }
+}
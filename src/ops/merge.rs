@@ -0,0 +1,247 @@
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+use super::Semigroup;
+
+/// Combines an older value with a newer one, e.g. layers of configuration.
+///
+/// `#[derive(GenericMerge)]` implements this field by field, with the strategy given by
+/// `#[generic(merge = "...")]` on each field.
+pub trait Merge {
+    /// Merges `newer` into `self`.
+    fn merge(self, newer: Self) -> Self;
+}
+
+/// `None` never replaces a value, and two values are merged.
+impl<T> Merge for Option<T>
+where
+    T: Merge,
+{
+    fn merge(self, newer: Self) -> Self {
+        match (self, newer) {
+            (Some(old), Some(new)) => Some(old.merge(new)),
+            (old, None) => old,
+            (None, new) => new,
+        }
+    }
+}
+
+/// A way of merging two values of a field, chosen with `#[generic(merge = "...")]`.
+///
+/// Implement this for your own types to use them as strategies, e.g. `#[generic(merge = "my_mod::Max")]`.
+pub trait MergeStrategy<T> {
+    /// Merges `newer` into `old`.
+    fn merge(old: T, newer: T) -> T;
+}
+
+/// Takes the newer value. This is the default strategy, or `#[generic(merge = "newer")]`.
+pub struct TakeNewer;
+
+impl<T> MergeStrategy<T> for TakeNewer {
+    fn merge(_old: T, newer: T) -> T {
+        newer
+    }
+}
+
+/// Takes the newer value, unless it's the default. This is `#[generic(merge = "non_default")]`.
+pub struct TakeNonDefault;
+
+impl<T> MergeStrategy<T> for TakeNonDefault
+where
+    T: Default + PartialEq,
+{
+    fn merge(old: T, newer: T) -> T {
+        if newer == T::default() {
+            old
+        } else {
+            newer
+        }
+    }
+}
+
+/// Appends the newer value to the old one with `Semigroup`, e.g. concatenating `Vec`s. This is `#[generic(merge = "concat")]`.
+pub struct Concat;
+
+impl<T> MergeStrategy<T> for Concat
+where
+    T: Semigroup,
+{
+    fn merge(old: T, newer: T) -> T {
+        old.append(newer)
+    }
+}
+
+/// Merges the values with their own `Merge` impl, e.g. for nested configuration. This is `#[generic(merge = "deep")]`.
+pub struct Deep;
+
+impl<T> MergeStrategy<T> for Deep
+where
+    T: Merge,
+{
+    fn merge(old: T, newer: T) -> T {
+        old.merge(newer)
+    }
+}
+
+/// A leaf wrapping a field along with the strategy used to merge it, which `GMerge` applies.
+pub struct WithStrategy<S, T>(pub T, pub PhantomData<S>);
+
+impl<S, T> WithStrategy<S, T> {
+    /// Wraps `value`.
+    pub fn new(value: T) -> Self {
+        WithStrategy(value, PhantomData)
+    }
+}
+
+/// Merges two representations of the same type, preferring the newer.
+///
+/// Constructors are merged field by field, and values holding the same variant are merged variant by variant,
+/// while values holding different variants take the newer variant. Arrays are merged item by item,
+/// and lists and leaves take the newer value, unless wrapped in `WithStrategy`.
+///
+/// `#[derive(GenericMerge)]` uses this to implement `Merge`, alongside `#[derive(Generic)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{GenericMerge, Merge};
+/// use generics::Generic;
+///
+/// #[derive(Debug, PartialEq, Default, Generic, GenericMerge)]
+/// struct Server {
+///     #[generic(merge = "non_default")]
+///     port: u16,
+///     #[generic(merge = "concat")]
+///     hosts: Vec<String>,
+/// }
+///
+/// #[derive(Debug, PartialEq, Generic, GenericMerge)]
+/// struct Config {
+///     name: String,
+///     #[generic(merge = "deep")]
+///     server: Server,
+/// }
+///
+/// let base = Config {
+///     name: "base".to_string(),
+///     server: Server { port: 80, hosts: vec!["a".to_string()] },
+/// };
+/// let layer = Config {
+///     name: "layer".to_string(),
+///     server: Server { port: 0, hosts: vec!["b".to_string()] },
+/// };
+/// assert_eq!(
+///     base.merge(layer),
+///     Config {
+///         name: "layer".to_string(),
+///         server: Server { port: 80, hosts: vec!["a".to_string(), "b".to_string()] },
+///     },
+/// );
+/// ```
+pub trait GMerge {
+    /// Merges `newer` into `self`.
+    fn gmerge(self, newer: Self) -> Self;
+}
+
+impl GMerge for Unit {
+    fn gmerge(self, _newer: Self) -> Self {
+        Unit
+    }
+}
+
+impl<A, B> GMerge for Prod<A, B>
+where
+    A: GMerge,
+    B: GMerge,
+{
+    fn gmerge(self, newer: Self) -> Self {
+        let (Prod(a, b), Prod(newer_a, newer_b)) = (self, newer);
+        Prod(a.gmerge(newer_a), b.gmerge(newer_b))
+    }
+}
+
+impl<L, R> GMerge for Sum<L, R>
+where
+    L: GMerge,
+    R: GMerge,
+{
+    fn gmerge(self, newer: Self) -> Self {
+        match (self, newer) {
+            (Sum::Left(l), Sum::Left(newer)) => Sum::Left(l.gmerge(newer)),
+            (Sum::Right(r), Sum::Right(newer)) => Sum::Right(r.gmerge(newer)),
+            (_, newer) => newer,
+        }
+    }
+}
+
+impl GMerge for Void {
+    fn gmerge(self, _newer: Self) -> Self {
+        match self {}
+    }
+}
+
+impl<I, M> GMerge for Meta<I, M>
+where
+    I: GMerge,
+    M: Singleton,
+{
+    fn gmerge(self, newer: Self) -> Self {
+        let (Meta(inner, _), Meta(newer, _)) = (self, newer);
+        Meta(inner.gmerge(newer), PhantomData)
+    }
+}
+
+impl<I, C> GMerge for Con<I, C>
+where
+    I: GMerge,
+    C: Singleton,
+{
+    fn gmerge(self, newer: Self) -> Self {
+        let (Con(inner, _), Con(newer, _)) = (self, newer);
+        Con(inner.gmerge(newer), PhantomData)
+    }
+}
+
+impl<A> GMerge for List<A> {
+    fn gmerge(self, newer: Self) -> Self {
+        newer
+    }
+}
+
+impl<A, const N: usize> GMerge for [A; N]
+where
+    A: GMerge,
+{
+    fn gmerge(self, newer: Self) -> Self {
+        let mut newer = IntoIterator::into_iter(newer);
+        self.map(|item| item.gmerge(newer.next().unwrap()))
+    }
+}
+
+impl<S, T> GMerge for WithStrategy<S, T>
+where
+    S: MergeStrategy<T>,
+{
+    fn gmerge(self, newer: Self) -> Self {
+        WithStrategy::new(S::merge(self.0, newer.0))
+    }
+}
+
+impl<T> GMerge for T
+where
+    T: Leaf,
+{
+    fn gmerge(self, newer: Self) -> Self {
+        newer
+    }
+}
+
+/// Merges the representation of `newer` into that of `old`, preferring the newer, and converts the result back.
+pub fn gmerge<T>(old: T, newer: T) -> T
+where
+    T: Generic,
+    T::Repr: GMerge,
+{
+    T::from_repr(old.into_repr().gmerge(newer.into_repr()))
+}
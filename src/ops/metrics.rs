@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+use super::flatten::push_segment;
+use super::GNumbers;
+
+/// Emits every numeric leaf of a representation to a `MetricSink`, named by its path.
+///
+/// Numeric leaves are those visited by `GNumbers`, i.e. of the primitive integer and float types, as an `f64`.
+/// Names are paths as produced by `flatten`, i.e. the field names, and list and tuple indices, leading to a leaf,
+/// joined with `.`, e.g. `"requests.ok"`. Sums only emit the fields of the variant they hold.
+///
+/// `emit_metrics` uses this to report a snapshot of a stats struct, under a prefix.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{emit_metrics, MetricSink};
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct ServerStats {
+///     uptime_secs: u64,
+///     requests: Requests,
+///     load: [f32; 2],
+///     version: String,
+/// }
+///
+/// #[derive(Generic)]
+/// struct Requests {
+///     ok: u32,
+///     failed: u32,
+/// }
+///
+/// struct Gauges(Vec<String>);
+///
+/// impl MetricSink for Gauges {
+///     fn record(&mut self, name: &str, value: f64) {
+///         self.0.push(format!("{}:{}|g", name, value));
+///     }
+/// }
+///
+/// let stats = ServerStats {
+///     uptime_secs: 3600,
+///     requests: Requests { ok: 120, failed: 3 },
+///     load: [0.5, 0.25],
+///     version: "1.2".to_string(),
+/// };
+/// let mut gauges = Gauges(Vec::new());
+/// emit_metrics("server", stats, &mut gauges);
+/// assert_eq!(
+///     gauges.0,
+///     [
+///         "server.uptime_secs:3600|g",
+///         "server.requests.ok:120|g",
+///         "server.requests.failed:3|g",
+///         "server.load.0:0.5|g",
+///         "server.load.1:0.25|g",
+///     ]
+/// );
+/// ```
+pub trait GMetrics {
+    /// Records every numeric leaf of `self` in `sink`, with names beginning with `path`.
+    fn gmetrics<S>(self, path: &mut String, sink: &mut S)
+    where
+        S: MetricSink + ?Sized;
+}
+
+/// Emits the numeric leaves of the elements of a `Prod` chain, under their indices.
+pub trait GMetricsElements {
+    /// Records every numeric leaf of each element, with names beginning with `path` and the element's index,
+    /// from `index`.
+    fn gmetrics_elements<S>(self, index: usize, path: &mut String, sink: &mut S)
+    where
+        S: MetricSink + ?Sized;
+}
+
+/// Emits the numeric leaves of the fields of a constructor, i.e. a `Prod` chain of `Meta`s, under their names.
+pub trait GMetricsFields {
+    /// Records every numeric leaf of each field, with names beginning with `path` and the field's name.
+    fn gmetrics_fields<S>(self, path: &mut String, sink: &mut S)
+    where
+        S: MetricSink + ?Sized;
+}
+
+/// A destination for metrics emitted by `GMetrics`, e.g. a client for a metrics service.
+pub trait MetricSink {
+    /// Records the metric `name` with `value`.
+    fn record(&mut self, name: &str, value: f64);
+}
+
+impl MetricSink for Vec<(String, f64)> {
+    fn record(&mut self, name: &str, value: f64) {
+        self.push((name.to_string(), value));
+    }
+}
+
+impl MetricSink for HashMap<String, f64> {
+    fn record(&mut self, name: &str, value: f64) {
+        self.insert(name.to_string(), value);
+    }
+}
+
+impl GMetrics for Unit {
+    fn gmetrics<S>(self, _path: &mut String, _sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+    }
+}
+
+/// A bare `Prod` is the representation of a tuple, whose elements are named by index.
+impl<A, B> GMetrics for Prod<A, B>
+where
+    Self: GMetricsElements,
+{
+    fn gmetrics<S>(self, path: &mut String, sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+        self.gmetrics_elements(0, path, sink);
+    }
+}
+
+impl GMetricsElements for Unit {
+    fn gmetrics_elements<S>(self, _index: usize, _path: &mut String, _sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+    }
+}
+
+impl<A, B> GMetricsElements for Prod<A, B>
+where
+    A: GMetrics,
+    B: GMetricsElements,
+{
+    fn gmetrics_elements<S>(self, index: usize, path: &mut String, sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+        let Prod(a, b) = self;
+        let len = push_segment(path, &index);
+        a.gmetrics(path, sink);
+        path.truncate(len);
+        b.gmetrics_elements(index + 1, path, sink);
+    }
+}
+
+impl GMetricsFields for Unit {
+    fn gmetrics_fields<S>(self, _path: &mut String, _sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+    }
+}
+
+impl<A, B> GMetricsFields for Prod<A, B>
+where
+    A: GMetrics,
+    B: GMetricsFields,
+{
+    fn gmetrics_fields<S>(self, path: &mut String, sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+        let Prod(a, b) = self;
+        a.gmetrics(path, sink);
+        b.gmetrics_fields(path, sink);
+    }
+}
+
+impl<L, R> GMetrics for Sum<L, R>
+where
+    L: GMetrics,
+    R: GMetrics,
+{
+    fn gmetrics<S>(self, path: &mut String, sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+        match self {
+            Sum::Left(l) => l.gmetrics(path, sink),
+            Sum::Right(r) => r.gmetrics(path, sink),
+        }
+    }
+}
+
+impl GMetrics for Void {
+    fn gmetrics<S>(self, _path: &mut String, _sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+        match self {}
+    }
+}
+
+impl<I, M> GMetrics for Meta<I, M>
+where
+    I: GMetrics,
+    M: Singleton<T = &'static str>,
+{
+    fn gmetrics<S>(self, path: &mut String, sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+        let Meta(inner, _) = self;
+        let len = push_segment(path, &M::get());
+        inner.gmetrics(path, sink);
+        path.truncate(len);
+    }
+}
+
+impl<I, C> GMetrics for Con<I, C>
+where
+    I: GMetricsFields,
+    C: Singleton,
+{
+    fn gmetrics<S>(self, path: &mut String, sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+        let Con(inner, _) = self;
+        inner.gmetrics_fields(path, sink);
+    }
+}
+
+impl<A> GMetrics for List<A>
+where
+    A: GMetrics,
+{
+    fn gmetrics<S>(self, path: &mut String, sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+        let List(items) = self;
+        for (i, item) in items.into_iter().enumerate() {
+            let len = push_segment(path, &i);
+            item.gmetrics(path, sink);
+            path.truncate(len);
+        }
+    }
+}
+
+impl<A, const N: usize> GMetrics for [A; N]
+where
+    A: GMetrics,
+{
+    fn gmetrics<S>(self, path: &mut String, sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+        List(Vec::from(self)).gmetrics(path, sink);
+    }
+}
+
+impl<L> GMetrics for L
+where
+    L: Leaf + 'static,
+{
+    fn gmetrics<S>(self, path: &mut String, sink: &mut S)
+    where
+        S: MetricSink + ?Sized,
+    {
+        self.gnumbers(&mut |n| sink.record(path, n));
+    }
+}
+
+/// Records every numeric leaf in the representation of `x` in `sink`, named by its path after `prefix`, in order.
+///
+/// If `prefix` is empty, names are just the paths of the leaves.
+pub fn emit_metrics<X, S>(prefix: &str, x: X, sink: &mut S)
+where
+    X: Generic,
+    X::Repr: GMetrics,
+    S: MetricSink + ?Sized,
+{
+    x.into_repr().gmetrics(&mut prefix.to_string(), sink);
+}
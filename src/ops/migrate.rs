@@ -0,0 +1,210 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use crate::{Con, FieldMeta, Generic, Meta, Prod, ReprError, Singleton, Unit};
+
+/// The fields of a struct, by name, each holding its declared type, from `FieldMeta`, and its representation.
+pub type MigratedFields = HashMap<&'static str, (&'static str, Box<dyn Any>)>;
+
+/// Takes the fields of a struct's representation, by name, to migrate them into another struct with `GMigrate`.
+pub trait GMigrateFields {
+    /// Moves each field of `self` into `fields`.
+    fn gmigrate_fields(self, fields: &mut MigratedFields);
+}
+
+/// Fills the fields of a struct's representation with the fields of the same name taken by `GMigrateFields`.
+///
+/// Fields are matched by name, including the fields of tuple structs, which are named by position, e.g. `0`.
+/// Fields with no counterpart keep their existing value, and fields with a counterpart of a different type are an error.
+/// Types are compared as declared, as well as by representation, so e.g. a `Vec<T>` doesn't migrate into a `VecDeque<T>`,
+/// and the same type must be spelled the same way in both versions.
+///
+/// `migrate` uses this to convert between versions of a struct, starting from the target's `Default`,
+/// so added fields take their default values, which can be set per field with `#[derive(GenericDefault)]`
+/// and `#[generic(default = "...")]`. Removed fields are dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{migrate, GenericDefault, MigrateError};
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct ConfigV1 {
+///     name: String,
+///     verbose: bool,
+/// }
+///
+/// #[derive(Generic, GenericDefault, Debug, PartialEq)]
+/// struct ConfigV2 {
+///     name: String,
+///     #[generic(default = "8080")]
+///     port: u16,
+///     retries: u8,
+/// }
+///
+/// let v1 = ConfigV1 { name: "app".to_string(), verbose: true };
+/// assert_eq!(
+///     migrate::<_, ConfigV2>(v1),
+///     Ok(ConfigV2 { name: "app".to_string(), port: 8080, retries: 0 })
+/// );
+///
+/// #[derive(Generic)]
+/// struct ConfigV0 {
+///     port: String,
+/// }
+///
+/// let v0 = ConfigV0 { port: "80".to_string() };
+/// assert_eq!(
+///     migrate::<_, ConfigV2>(v0),
+///     Err(MigrateError::Type { field: "port", expected: "u16", found: "String" })
+/// );
+/// ```
+pub trait GMigrate {
+    /// Replaces each field of `self` with the field of the same name in `fields`, if any.
+    fn gmigrate(&mut self, fields: &mut MigratedFields) -> Result<(), MigrateError>;
+}
+
+/// An error migrating between versions of a struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrateError {
+    /// A field whose type differs between versions.
+    Type {
+        /// The name of the field.
+        field: &'static str,
+        /// The type of the field in the target, as declared, from `FieldMeta`.
+        expected: &'static str,
+        /// The type of the field in the source, as declared.
+        found: &'static str,
+    },
+    /// The migrated fields were invalid for the target, e.g. a `NonZeroU8` of zero.
+    Repr(ReprError),
+}
+
+impl Display for MigrateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrateError::Type {
+                field,
+                expected,
+                found,
+            } => write!(
+                f,
+                "field `{}` has type `{}`, expected `{}`",
+                field, found, expected
+            ),
+            MigrateError::Repr(error) => write!(f, "invalid value: {}", error),
+        }
+    }
+}
+
+impl Error for MigrateError {}
+
+impl GMigrateFields for Unit {
+    fn gmigrate_fields(self, _fields: &mut MigratedFields) {}
+}
+
+impl GMigrate for Unit {
+    fn gmigrate(&mut self, _fields: &mut MigratedFields) -> Result<(), MigrateError> {
+        Ok(())
+    }
+}
+
+impl<A, B> GMigrateFields for Prod<A, B>
+where
+    A: GMigrateFields,
+    B: GMigrateFields,
+{
+    fn gmigrate_fields(self, fields: &mut MigratedFields) {
+        let Prod(a, b) = self;
+        a.gmigrate_fields(fields);
+        b.gmigrate_fields(fields);
+    }
+}
+
+impl<A, B> GMigrate for Prod<A, B>
+where
+    A: GMigrate,
+    B: GMigrate,
+{
+    fn gmigrate(&mut self, fields: &mut MigratedFields) -> Result<(), MigrateError> {
+        let Prod(a, b) = self;
+        a.gmigrate(fields)?;
+        b.gmigrate(fields)
+    }
+}
+
+impl<I, M> GMigrateFields for Meta<I, M>
+where
+    I: Any,
+    M: Singleton<T = &'static str> + FieldMeta,
+{
+    fn gmigrate_fields(self, fields: &mut MigratedFields) {
+        let Meta(inner, _) = self;
+        fields.insert(M::get(), (M::type_name(), Box::new(inner)));
+    }
+}
+
+impl<I, M> GMigrate for Meta<I, M>
+where
+    I: Any,
+    M: Singleton<T = &'static str> + FieldMeta,
+{
+    fn gmigrate(&mut self, fields: &mut MigratedFields) -> Result<(), MigrateError> {
+        let Meta(inner, _) = self;
+        if let Some((found, field)) = fields.remove(M::get()) {
+            match field.downcast() {
+                Ok(field) if found == M::type_name() => *inner = *field,
+                _ => {
+                    return Err(MigrateError::Type {
+                        field: M::get(),
+                        expected: M::type_name(),
+                        found,
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I, C> GMigrateFields for Con<I, C>
+where
+    I: GMigrateFields,
+    C: Singleton,
+{
+    fn gmigrate_fields(self, fields: &mut MigratedFields) {
+        let Con(inner, _) = self;
+        inner.gmigrate_fields(fields);
+    }
+}
+
+impl<I, C> GMigrate for Con<I, C>
+where
+    I: GMigrate,
+    C: Singleton,
+{
+    fn gmigrate(&mut self, fields: &mut MigratedFields) -> Result<(), MigrateError> {
+        let Con(inner, _) = self;
+        inner.gmigrate(fields)
+    }
+}
+
+/// Converts `from` into a `T`, carrying over each field with the same name and type, starting from `T::default()`.
+///
+/// This fails if a field's type differs, or if `T` rejects the migrated fields.
+pub fn migrate<F, T>(from: F) -> Result<T, MigrateError>
+where
+    F: Generic,
+    F::Repr: GMigrateFields,
+    T: Generic + Default,
+    T::Repr: GMigrate,
+{
+    let mut fields = MigratedFields::new();
+    from.into_repr().gmigrate_fields(&mut fields);
+    let mut repr = T::default().into_repr();
+    repr.gmigrate(&mut fields)?;
+    T::checked_from_repr(repr).map_err(MigrateError::Repr)
+}
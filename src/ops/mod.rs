@@ -0,0 +1,202 @@
+//! Built-in generic operations.
+//!
+//! Each operation is a trait implemented over representations, along with a function or derive applying it to `Generic` types.
+
+mod approx;
+mod binary;
+mod bounded;
+mod build;
+mod bytes;
+#[cfg(feature = "cbor")]
+mod cbor;
+mod clone;
+mod collect;
+mod columns;
+mod convert;
+#[cfg(feature = "csv")]
+mod csv;
+mod ct_eq;
+mod debug;
+mod default;
+#[cfg(feature = "serde")]
+mod deserialize;
+mod diff;
+#[cfg(feature = "env")]
+mod env;
+mod eq;
+mod everywhere;
+mod fill;
+mod finite;
+mod flatten;
+mod from_str;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+mod hash;
+mod heap_size;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(feature = "json")]
+mod json_codec;
+#[cfg(feature = "json")]
+mod json_schema;
+mod layout;
+mod merge;
+mod metrics;
+mod migrate;
+mod monoid;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+mod numbers;
+mod ord;
+mod patch;
+mod pretty;
+#[cfg(feature = "protobuf")]
+mod protobuf;
+#[cfg(feature = "rand")]
+mod random;
+mod rewrite;
+mod sanitize;
+#[cfg(feature = "serde")]
+mod serialize;
+mod shape;
+#[cfg(feature = "quickcheck")]
+mod shrink;
+#[cfg(feature = "proptest")]
+mod strategy;
+mod strip;
+mod template;
+#[cfg(any(
+    feature = "cbor",
+    feature = "json",
+    feature = "msgpack",
+    feature = "serde_json"
+))]
+mod tree;
+mod tuple;
+mod uniform;
+#[cfg(feature = "urlencoded")]
+mod urlencoded;
+mod variant;
+mod wire;
+mod zeroize;
+mod zip;
+
+pub use self::approx::{approx_eq, ApproxEq, GApproxEq};
+pub use self::binary::{decode_binary, encode_binary, Binary, DecodeBinaryError, GDecode, GEncode};
+pub use self::bounded::{max_value, min_value, Bounded, GBounded, GBoundedVariant};
+pub use self::build::{GBuild, MissingFields, Partial};
+#[cfg(feature = "cbor")]
+pub use self::cbor::{
+    decode_cbor, encode_cbor, Cbor, CborLeaf, DecodeCborError, GFromCbor, GToCbor,
+};
+pub use self::clone::{Borrowed, GClone};
+pub use self::collect::{collect, GCollect};
+pub use self::columns::{Columns, GColumns};
+pub use self::convert::{convert, try_convert, ConvertError, GConvert, GTryConvert};
+#[cfg(feature = "csv")]
+pub use self::csv::{
+    csv_header, decode_csv, encode_csv, CsvError, CsvRecord, GCsv, GCsvCell, GCsvElements,
+    GCsvFields, GCsvVariant,
+};
+pub use self::ct_eq::{Choice, ConstantTimeEq, GConstantTimeEq};
+pub use self::debug::{gdebug, GDebug};
+pub use self::default::{gdefault, GDefault};
+#[cfg(feature = "serde")]
+pub use self::deserialize::{deserialize_generic, try_deserialize_generic};
+pub use self::diff::{assert_generic_eq_failed, diff, Diff, GDiff, GDiffFields, Segment};
+#[cfg(feature = "env")]
+pub use self::env::{
+    from_env, from_vars, EnvError, GEnv, GEnvElements, GEnvFields, GEnvValue, GEnvVariant,
+};
+pub use self::eq::{GEq, GPartialEq, Ignored};
+pub use self::everywhere::{everywhere, GEverywhere};
+pub use self::fill::{fill, FillError, GFill, GFillVariant, LeafSource};
+pub use self::finite::{all_values, Finite, GFinite};
+pub use self::flatten::{
+    flatten, unflatten, GFlatten, GFlattenElements, GFlattenFields, GFlattenVariant, GUnflatten,
+    GUnflattenElements, GUnflattenFields, GUnflattenVariant, UnflattenError,
+};
+pub use self::from_str::{from_name, GFromName, ParseVariantError};
+#[cfg(feature = "arbitrary")]
+pub use self::fuzz::{garbitrary, GArbitrary, GArbitraryVariant};
+pub use self::hash::GHash;
+pub use self::heap_size::{GHeapSize, HeapSize};
+#[cfg(feature = "serde_json")]
+pub use self::json::{from_value, to_value, FromValueError, GFromValue, GToValue};
+#[cfg(feature = "json")]
+pub use self::json_codec::{
+    decode_json, encode_json, DecodeJsonError, GFromJson, GToJson, Json, JsonLeaf,
+};
+#[cfg(feature = "json")]
+pub use self::json_schema::{
+    json_schema, GJsonSchema, GJsonSchemaElements, GJsonSchemaFields, GJsonSchemaVariants,
+    JsonSchemaLeaf, JSON_SCHEMA_DIALECT,
+};
+pub use self::layout::{FieldLayout, GLayout, GLayoutFields, LayoutReport};
+pub use self::merge::{
+    gmerge, Concat, Deep, GMerge, Merge, MergeStrategy, TakeNewer, TakeNonDefault, WithStrategy,
+};
+pub use self::metrics::{emit_metrics, GMetrics, GMetricsElements, GMetricsFields, MetricSink};
+pub use self::migrate::{migrate, GMigrate, GMigrateFields, MigrateError, MigratedFields};
+pub use self::monoid::{gappend, gempty, GMonoid, GSemigroup, Monoid, Semigroup};
+#[cfg(feature = "msgpack")]
+pub use self::msgpack::{
+    decode_msgpack, encode_msgpack, DecodeMsgPackError, GFromMsgPack, GToMsgPack, MsgPack,
+    MsgPackLeaf,
+};
+pub use self::numbers::{
+    count_numbers, max_number, mean_number, min_number, number_stats, sum_numbers, GNumbers,
+    NumberStats,
+};
+pub use self::ord::{GOrd, GPartialOrd};
+pub use self::patch::{patch, GPatch, Patch};
+pub use self::pretty::{pretty, GPretty, GPrettyFields, PrettyOptions, Printer};
+#[cfg(feature = "protobuf")]
+pub use self::protobuf::{
+    decode_proto, encode_proto, proto_file, DecodeProtoError, GProto, GProtoElements, GProtoFields,
+    GProtoMessage, GProtoVariants, Label, ProtoField, ProtoFile, ProtoLeaf, ProtoMessage,
+    ProtoType, ProtoValue, Scalar,
+};
+#[cfg(feature = "rand")]
+pub use self::random::{gen_random, GRandom, GRandomVariant, GStandard, Random, MAX_RANDOM_LEN};
+pub use self::rewrite::{clone_with, rewrite, GRewrite, Hooks};
+#[cfg(feature = "unicode-normalization")]
+pub use self::sanitize::Nfc;
+pub use self::sanitize::{Clamp, Dedup, GSanitize, Lowercase, Rules, Sanitize, SanitizeRule, Trim};
+#[cfg(feature = "serde")]
+pub use self::serialize::serialize_generic;
+pub use self::shape::{fingerprint, shape, GShape, GShapeFields, GShapeVariants, Shape};
+#[cfg(feature = "quickcheck")]
+pub use self::shrink::{gquickcheck, gshrink, GQuickcheck, GQuickcheckVariant};
+#[cfg(feature = "proptest")]
+pub use self::strategy::{
+    generic_strategy, ArrayTree, FieldStrategy, GStrategy, GStrategyVariant, GValueTree, LeafTree,
+    ListTree, Override, OverrideTree, ProdTree, ReprStrategy, ReprTree, SumTree, WrapperTree,
+    MAX_STRATEGY_LEN,
+};
+pub use self::strip::{strip, structural_convert, structural_eq, unstrip, GStrip};
+pub use self::template::{render, TemplateError};
+pub use self::tuple::{from_tuple, into_tuple, GTuple};
+pub use self::uniform::{uniform_leaves, GUniform};
+#[cfg(feature = "urlencoded")]
+pub use self::urlencoded::{decode_query, encode_query, QueryError};
+pub use self::variant::{variant_name, GVariantName};
+pub use self::wire::{
+    decode_wire, encode_wire, encode_wire_to_slice, GDecodeWire, GEncodeWire, SliceWriter, Wire,
+    WireError, WireWrite,
+};
+pub use self::zeroize::{zeroize, GZeroize, Zeroize, Zeroizing};
+pub use self::zip::{gzip, GZip, ZipError};
+#[cfg(all(feature = "generics_derive", feature = "arbitrary"))]
+pub use generics_derive::GenericArbitrary;
+#[cfg(all(feature = "generics_derive", feature = "quickcheck"))]
+pub use generics_derive::GenericQuickcheck;
+#[cfg(all(feature = "generics_derive", feature = "proptest"))]
+pub use generics_derive::GenericStrategy;
+#[cfg(feature = "generics_derive")]
+pub use generics_derive::{
+    GenericApproxEq, GenericBuilder, GenericClone, GenericColumns, GenericConstantTimeEq,
+    GenericDebug, GenericDefault, GenericDisplay, GenericEq, GenericFromStr, GenericHash,
+    GenericHeapSize, GenericIter, GenericLayout, GenericMerge, GenericOrd, GenericPartialEq,
+    GenericPartialOrd, GenericSanitize, GenericZeroize,
+};
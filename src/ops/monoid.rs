@@ -0,0 +1,370 @@
+use std::collections::{BTreeSet, HashSet};
+use std::ffi::OsString;
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Leaf, List, Meta, Opaque, Prod, ReprError, Singleton, Sum, Unit, Void};
+
+/// An associative operation combining two leaves, e.g. addition for numbers.
+///
+/// Implement this for your own leaf types (or wrap them in `Opaque`) to use them with `GSemigroup`.
+pub trait Semigroup {
+    /// Combines `self` with `other`.
+    fn append(self, other: Self) -> Self;
+}
+
+/// A `Semigroup` with an identity, e.g. zero for addition.
+pub trait Monoid: Semigroup {
+    /// The identity of `append`.
+    fn empty() -> Self;
+}
+
+macro_rules! impl_add {
+    ( $( $ty:ty ),+ $(,)? ) => {
+        $(
+            impl Semigroup for $ty {
+                fn append(self, other: Self) -> Self {
+                    self + other
+                }
+            }
+
+            impl Monoid for $ty {
+                fn empty() -> Self {
+                    0 as $ty
+                }
+            }
+        )+
+    };
+}
+
+impl_add!(u8, u16, u32, u64, u128, usize);
+impl_add!(i8, i16, i32, i64, i128, isize);
+impl_add!(f32, f64);
+
+impl Semigroup for String {
+    fn append(mut self, other: Self) -> Self {
+        self.push_str(&other);
+        self
+    }
+}
+
+impl Monoid for String {
+    fn empty() -> Self {
+        String::new()
+    }
+}
+
+impl Semigroup for OsString {
+    fn append(mut self, other: Self) -> Self {
+        self.push(other);
+        self
+    }
+}
+
+impl Monoid for OsString {
+    fn empty() -> Self {
+        OsString::new()
+    }
+}
+
+/// `Vec` is usually represented as a `List`, but this supports opaque `Vec`s.
+impl<T> Semigroup for Vec<T> {
+    fn append(mut self, other: Self) -> Self {
+        self.extend(other);
+        self
+    }
+}
+
+impl<T> Monoid for Vec<T> {
+    fn empty() -> Self {
+        Vec::new()
+    }
+}
+
+impl<T> Semigroup for BTreeSet<T>
+where
+    T: Ord,
+{
+    fn append(mut self, other: Self) -> Self {
+        self.extend(other);
+        self
+    }
+}
+
+impl<T> Monoid for BTreeSet<T>
+where
+    T: Ord,
+{
+    fn empty() -> Self {
+        BTreeSet::new()
+    }
+}
+
+impl<T, S> Semigroup for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn append(mut self, other: Self) -> Self {
+        self.extend(other);
+        self
+    }
+}
+
+impl<T, S> Monoid for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn empty() -> Self {
+        HashSet::default()
+    }
+}
+
+impl<T> Semigroup for Opaque<T>
+where
+    T: Semigroup,
+{
+    fn append(self, other: Self) -> Self {
+        Opaque(self.0.append(other.0))
+    }
+}
+
+impl<T> Monoid for Opaque<T>
+where
+    T: Monoid,
+{
+    fn empty() -> Self {
+        Opaque(T::empty())
+    }
+}
+
+/// Combines representations, node by node.
+///
+/// Products combine each of their fields, and lists concatenate. Arrays combine their elements pairwise.
+/// Sums holding the same variant combine their payloads; otherwise, the later variant wins,
+/// so e.g. `None` combined with `Some(x)` is `Some(x)`. Leaves are combined with their `Semigroup` impl.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::gappend;
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Stats {
+///     count: u64,
+///     log: String,
+///     max: Option<u8>,
+/// }
+///
+/// let a = Stats { count: 1, log: "a".to_string(), max: None };
+/// let b = Stats { count: 2, log: "b".to_string(), max: Some(3) };
+/// assert_eq!(gappend(a, b), Stats { count: 3, log: "ab".to_string(), max: Some(3) });
+/// ```
+pub trait GSemigroup {
+    /// Combines `self` with `other`.
+    fn gappend(self, other: Self) -> Self;
+}
+
+/// A `GSemigroup` with an identity, node by node.
+///
+/// Products are the identity of each field, sums are the identity of their first variant, and lists are empty.
+/// Leaves use their `Monoid` impl.
+pub trait GMonoid: GSemigroup {
+    /// The identity of `gappend`.
+    fn gempty() -> Self;
+}
+
+impl GSemigroup for Unit {
+    fn gappend(self, _other: Self) -> Self {
+        Unit
+    }
+}
+
+impl GMonoid for Unit {
+    fn gempty() -> Self {
+        Unit
+    }
+}
+
+impl<A, B> GSemigroup for Prod<A, B>
+where
+    A: GSemigroup,
+    B: GSemigroup,
+{
+    fn gappend(self, other: Self) -> Self {
+        let (Prod(a, b), Prod(other_a, other_b)) = (self, other);
+        Prod(a.gappend(other_a), b.gappend(other_b))
+    }
+}
+
+impl<A, B> GMonoid for Prod<A, B>
+where
+    A: GMonoid,
+    B: GMonoid,
+{
+    fn gempty() -> Self {
+        Prod(A::gempty(), B::gempty())
+    }
+}
+
+impl<L, R> GSemigroup for Sum<L, R>
+where
+    L: GSemigroup,
+    R: GSemigroup,
+{
+    fn gappend(self, other: Self) -> Self {
+        match (self, other) {
+            (Sum::Left(l), Sum::Left(other)) => Sum::Left(l.gappend(other)),
+            (Sum::Right(r), Sum::Right(other)) => Sum::Right(r.gappend(other)),
+            (Sum::Left(_), other @ Sum::Right(_)) => other,
+            (this @ Sum::Right(_), Sum::Left(_)) => this,
+        }
+    }
+}
+
+impl<L, R> GMonoid for Sum<L, R>
+where
+    L: GMonoid,
+    R: GSemigroup,
+{
+    fn gempty() -> Self {
+        Sum::Left(L::gempty())
+    }
+}
+
+impl GSemigroup for Void {
+    fn gappend(self, _other: Self) -> Self {
+        match self {}
+    }
+}
+
+impl<I, M> GSemigroup for Meta<I, M>
+where
+    I: GSemigroup,
+    M: Singleton,
+{
+    fn gappend(self, other: Self) -> Self {
+        let (Meta(inner, _), Meta(other, _)) = (self, other);
+        Meta(inner.gappend(other), PhantomData)
+    }
+}
+
+impl<I, M> GMonoid for Meta<I, M>
+where
+    I: GMonoid,
+    M: Singleton,
+{
+    fn gempty() -> Self {
+        Meta(I::gempty(), PhantomData)
+    }
+}
+
+impl<I, C> GSemigroup for Con<I, C>
+where
+    I: GSemigroup,
+    C: Singleton,
+{
+    fn gappend(self, other: Self) -> Self {
+        let (Con(inner, _), Con(other, _)) = (self, other);
+        Con(inner.gappend(other), PhantomData)
+    }
+}
+
+impl<I, C> GMonoid for Con<I, C>
+where
+    I: GMonoid,
+    C: Singleton,
+{
+    fn gempty() -> Self {
+        Con(I::gempty(), PhantomData)
+    }
+}
+
+impl<A> GSemigroup for List<A> {
+    fn gappend(self, other: Self) -> Self {
+        let (List(mut items), List(other)) = (self, other);
+        items.extend(other);
+        List(items)
+    }
+}
+
+impl<A> GMonoid for List<A> {
+    fn gempty() -> Self {
+        List(Vec::new())
+    }
+}
+
+impl<A, const N: usize> GSemigroup for [A; N]
+where
+    A: GSemigroup,
+{
+    fn gappend(self, other: Self) -> Self {
+        let mut other = IntoIterator::into_iter(other);
+        self.map(|item| item.gappend(other.next().unwrap()))
+    }
+}
+
+impl<A, const N: usize> GMonoid for [A; N]
+where
+    A: GMonoid,
+{
+    fn gempty() -> Self {
+        [(); N].map(|()| A::gempty())
+    }
+}
+
+impl<T> GSemigroup for T
+where
+    T: Leaf + Semigroup,
+{
+    fn gappend(self, other: Self) -> Self {
+        self.append(other)
+    }
+}
+
+impl<T> GMonoid for T
+where
+    T: Leaf + Monoid,
+{
+    fn gempty() -> Self {
+        T::empty()
+    }
+}
+
+/// Combines `a` with `b`, via their representations.
+pub fn gappend<T>(a: T, b: T) -> T
+where
+    T: Generic,
+    T::Repr: GSemigroup,
+{
+    T::from_repr(a.into_repr().gappend(b.into_repr()))
+}
+
+/// The identity of `gappend`, via the representation.
+///
+/// This fails if `T` rejects the empty representation, e.g. `NonZeroU32`, whose representation is empty at zero.
+///
+/// ```rust
+/// use generics::ops::{gappend, gempty};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Counters {
+///     hits: u32,
+///     misses: u32,
+/// }
+///
+/// let total = vec![Counters { hits: 1, misses: 0 }, Counters { hits: 2, misses: 1 }]
+///     .into_iter()
+///     .fold(gempty().unwrap(), gappend);
+/// assert_eq!(total, Counters { hits: 3, misses: 1 });
+/// ```
+pub fn gempty<T>() -> Result<T, ReprError>
+where
+    T: Generic,
+    T::Repr: GMonoid,
+{
+    T::checked_from_repr(T::Repr::gempty())
+}
@@ -0,0 +1,774 @@
+use std::convert::{Infallible, TryFrom, TryInto};
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use crate::{Generic, Opaque, ReprError};
+
+use super::tree::{FromTreeLeaf, GFromTree, GToTree, ToTreeLeaf, Tree};
+
+/// A MessagePack object, as read and written by `decode_msgpack` and `encode_msgpack`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MsgPack {
+    /// `nil`.
+    Nil,
+    /// `true` or `false`.
+    Bool(bool),
+    /// A non-negative integer.
+    UInt(u64),
+    /// A negative integer.
+    Int(i64),
+    /// A single precision float.
+    Float32(f32),
+    /// A double precision float.
+    Float64(f64),
+    /// A binary string.
+    Bin(Vec<u8>),
+    /// A UTF-8 string.
+    Str(String),
+    /// An array.
+    Array(Vec<MsgPack>),
+    /// A map, as pairs of keys and values.
+    Map(Vec<(MsgPack, MsgPack)>),
+    /// An extension, with its type and data.
+    Ext(i8, Vec<u8>),
+}
+
+impl MsgPack {
+    /// Appends the encoding of `self` to `out`, using the shortest format for each object.
+    ///
+    /// Panics if a string, array, map, or extension is longer than 32 bits can represent.
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            MsgPack::Nil => out.push(0xc0),
+            MsgPack::Bool(b) => out.push(if *b { 0xc3 } else { 0xc2 }),
+            MsgPack::UInt(n) => match *n {
+                0..=0x7f => out.push(*n as u8),
+                n => {
+                    if let Ok(n) = u8::try_from(n) {
+                        out.extend_from_slice(&[0xcc, n]);
+                    } else if let Ok(n) = u16::try_from(n) {
+                        out.push(0xcd);
+                        out.extend_from_slice(&n.to_be_bytes());
+                    } else if let Ok(n) = u32::try_from(n) {
+                        out.push(0xce);
+                        out.extend_from_slice(&n.to_be_bytes());
+                    } else {
+                        out.push(0xcf);
+                        out.extend_from_slice(&n.to_be_bytes());
+                    }
+                }
+            },
+            MsgPack::Int(n) => match *n {
+                0.. => MsgPack::UInt(*n as u64).encode(out),
+                -32..=-1 => out.push(*n as u8),
+                n => {
+                    if let Ok(n) = i8::try_from(n) {
+                        out.extend_from_slice(&[0xd0, n as u8]);
+                    } else if let Ok(n) = i16::try_from(n) {
+                        out.push(0xd1);
+                        out.extend_from_slice(&n.to_be_bytes());
+                    } else if let Ok(n) = i32::try_from(n) {
+                        out.push(0xd2);
+                        out.extend_from_slice(&n.to_be_bytes());
+                    } else {
+                        out.push(0xd3);
+                        out.extend_from_slice(&n.to_be_bytes());
+                    }
+                }
+            },
+            MsgPack::Float32(f) => {
+                out.push(0xca);
+                out.extend_from_slice(&f.to_be_bytes());
+            }
+            MsgPack::Float64(f) => {
+                out.push(0xcb);
+                out.extend_from_slice(&f.to_be_bytes());
+            }
+            MsgPack::Bin(bytes) => {
+                head(out, None, Some(0xc4), 0xc5, 0xc6, bytes.len());
+                out.extend_from_slice(bytes);
+            }
+            MsgPack::Str(s) => {
+                head(out, Some((0xa0, 32)), Some(0xd9), 0xda, 0xdb, s.len());
+                out.extend_from_slice(s.as_bytes());
+            }
+            MsgPack::Array(items) => {
+                head(out, Some((0x90, 16)), None, 0xdc, 0xdd, items.len());
+                for item in items {
+                    item.encode(out);
+                }
+            }
+            MsgPack::Map(pairs) => {
+                head(out, Some((0x80, 16)), None, 0xde, 0xdf, pairs.len());
+                for (key, value) in pairs {
+                    key.encode(out);
+                    value.encode(out);
+                }
+            }
+            MsgPack::Ext(ty, data) => {
+                match data.len() {
+                    1 => out.push(0xd4),
+                    2 => out.push(0xd5),
+                    4 => out.push(0xd6),
+                    8 => out.push(0xd7),
+                    16 => out.push(0xd8),
+                    len => head(out, None, Some(0xc7), 0xc8, 0xc9, len),
+                }
+                out.push(*ty as u8);
+                out.extend_from_slice(data);
+            }
+        }
+    }
+
+    /// Decodes a single object, which must span all of `bytes`.
+    ///
+    /// Arrays and maps may be nested at most 128 deep.
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeMsgPackError> {
+        let mut parser = Parser {
+            bytes,
+            pos: 0,
+            depth: 0,
+        };
+        let msgpack = parser.object()?;
+        if parser.pos < bytes.len() {
+            return Err(DecodeMsgPackError::Syntax(parser.pos));
+        }
+        Ok(msgpack)
+    }
+}
+
+/// Writes the marker and length of an object of length `len`, in the shortest format:
+/// a fix format with marker `fix.0` if `len` is less than `fix.1`, or else the marker with an 8, 16, or 32 bit length.
+fn head(out: &mut Vec<u8>, fix: Option<(u8, usize)>, m8: Option<u8>, m16: u8, m32: u8, len: usize) {
+    if let Some((marker, limit)) = fix {
+        if len < limit {
+            out.push(marker | len as u8);
+            return;
+        }
+    }
+    if let (Some(m8), Ok(len)) = (m8, u8::try_from(len)) {
+        out.extend_from_slice(&[m8, len]);
+    } else if let Ok(len) = u16::try_from(len) {
+        out.push(m16);
+        out.extend_from_slice(&len.to_be_bytes());
+    } else {
+        let len = u32::try_from(len).expect("MessagePack lengths are at most 32 bits");
+        out.push(m32);
+        out.extend_from_slice(&len.to_be_bytes());
+    }
+}
+
+/// The deepest arrays and maps may be nested, so malicious input can't overflow the stack.
+const MAX_DEPTH: usize = 128;
+
+/// A recursive descent parser over bytes.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    /// The number of arrays and maps the parser is inside.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeMsgPackError> {
+        match self.bytes.get(self.pos..).and_then(|rest| rest.get(..len)) {
+            Some(bytes) => {
+                self.pos += len;
+                Ok(bytes)
+            }
+            None => Err(DecodeMsgPackError::Syntax(self.bytes.len())),
+        }
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], DecodeMsgPackError> {
+        let bytes = self.take(N)?;
+        Ok(bytes.try_into().expect("took `N` bytes"))
+    }
+
+    /// Reads a big-endian length of `size` bytes, which can't be longer than the rest of the input,
+    /// since each element takes at least a byte.
+    fn len(&mut self, start: usize, size: usize) -> Result<usize, DecodeMsgPackError> {
+        let len = match size {
+            1 => usize::from(self.take(1)?[0]),
+            2 => usize::from(u16::from_be_bytes(self.take_array()?)),
+            _ => usize::try_from(u32::from_be_bytes(self.take_array()?))
+                .map_err(|_| DecodeMsgPackError::Syntax(start))?,
+        };
+        if len > self.bytes.len() - self.pos {
+            return Err(DecodeMsgPackError::Syntax(start));
+        }
+        Ok(len)
+    }
+
+    fn str(&mut self, start: usize, len: usize) -> Result<MsgPack, DecodeMsgPackError> {
+        match std::str::from_utf8(self.take(len)?) {
+            Ok(s) => Ok(MsgPack::Str(s.to_string())),
+            Err(_) => Err(DecodeMsgPackError::Syntax(start)),
+        }
+    }
+
+    /// Parses the contents of the array or map starting at `start` with `parse`, one level deeper.
+    fn nested(
+        &mut self,
+        start: usize,
+        parse: impl FnOnce(&mut Self) -> Result<MsgPack, DecodeMsgPackError>,
+    ) -> Result<MsgPack, DecodeMsgPackError> {
+        if self.depth == MAX_DEPTH {
+            return Err(DecodeMsgPackError::Depth(start));
+        }
+        self.depth += 1;
+        let msgpack = parse(self);
+        self.depth -= 1;
+        msgpack
+    }
+
+    fn array(&mut self, start: usize, len: usize) -> Result<MsgPack, DecodeMsgPackError> {
+        self.nested(start, |parser| {
+            let items = (0..len)
+                .map(|_| parser.object())
+                .collect::<Result<_, _>>()?;
+            Ok(MsgPack::Array(items))
+        })
+    }
+
+    fn map(&mut self, start: usize, len: usize) -> Result<MsgPack, DecodeMsgPackError> {
+        self.nested(start, |parser| {
+            let pairs = (0..len)
+                .map(|_| Ok((parser.object()?, parser.object()?)))
+                .collect::<Result<_, _>>()?;
+            Ok(MsgPack::Map(pairs))
+        })
+    }
+
+    fn ext(&mut self, len: usize) -> Result<MsgPack, DecodeMsgPackError> {
+        let ty = self.take(1)?[0] as i8;
+        Ok(MsgPack::Ext(ty, self.take(len)?.to_vec()))
+    }
+
+    fn object(&mut self) -> Result<MsgPack, DecodeMsgPackError> {
+        let start = self.pos;
+        let marker = self.take(1)?[0];
+        match marker {
+            0x00..=0x7f => Ok(MsgPack::UInt(u64::from(marker))),
+            0x80..=0x8f => self.map(start, usize::from(marker & 0x0f)),
+            0x90..=0x9f => self.array(start, usize::from(marker & 0x0f)),
+            0xa0..=0xbf => self.str(start, usize::from(marker & 0x1f)),
+            0xc0 => Ok(MsgPack::Nil),
+            0xc2 => Ok(MsgPack::Bool(false)),
+            0xc3 => Ok(MsgPack::Bool(true)),
+            0xc4..=0xc6 => {
+                let len = self.len(start, 1 << (marker - 0xc4))?;
+                Ok(MsgPack::Bin(self.take(len)?.to_vec()))
+            }
+            0xc7..=0xc9 => {
+                let len = self.len(start, 1 << (marker - 0xc7))?;
+                self.ext(len)
+            }
+            0xca => Ok(MsgPack::Float32(f32::from_be_bytes(self.take_array()?))),
+            0xcb => Ok(MsgPack::Float64(f64::from_be_bytes(self.take_array()?))),
+            0xcc => Ok(MsgPack::UInt(u64::from(self.take(1)?[0]))),
+            0xcd => Ok(MsgPack::UInt(u64::from(u16::from_be_bytes(
+                self.take_array()?,
+            )))),
+            0xce => Ok(MsgPack::UInt(u64::from(u32::from_be_bytes(
+                self.take_array()?,
+            )))),
+            0xcf => Ok(MsgPack::UInt(u64::from_be_bytes(self.take_array()?))),
+            0xd0 => Ok(int(i64::from(self.take(1)?[0] as i8))),
+            0xd1 => Ok(int(i64::from(i16::from_be_bytes(self.take_array()?)))),
+            0xd2 => Ok(int(i64::from(i32::from_be_bytes(self.take_array()?)))),
+            0xd3 => Ok(int(i64::from_be_bytes(self.take_array()?))),
+            0xd4..=0xd8 => self.ext(1 << (marker - 0xd4)),
+            0xd9..=0xdb => {
+                let len = self.len(start, 1 << (marker - 0xd9))?;
+                self.str(start, len)
+            }
+            0xdc | 0xdd => {
+                let len = self.len(start, 2 << (marker - 0xdc))?;
+                self.array(start, len)
+            }
+            0xde | 0xdf => {
+                let len = self.len(start, 2 << (marker - 0xde))?;
+                self.map(start, len)
+            }
+            0xe0..=0xff => Ok(MsgPack::Int(i64::from(marker as i8))),
+            _ => Err(DecodeMsgPackError::Syntax(start)),
+        }
+    }
+}
+
+/// Converts a signed integer to an object, as `UInt` if it's non-negative, since encoders may use either.
+fn int(n: i64) -> MsgPack {
+    match u64::try_from(n) {
+        Ok(n) => MsgPack::UInt(n),
+        Err(_) => MsgPack::Int(n),
+    }
+}
+
+/// A leaf which can be converted to and from MessagePack.
+///
+/// Implement this for your own leaf types to encode and decode them with `GToMsgPack` and `GFromMsgPack`.
+pub trait MsgPackLeaf: Sized {
+    /// A description of the MessagePack this is decoded from, for errors, e.g. `"a u8"`.
+    const EXPECTED: &'static str;
+
+    /// Converts `self` to MessagePack.
+    fn to_msgpack(self) -> MsgPack;
+
+    /// Converts `msgpack` to `Self`, or returns `None` if it's invalid.
+    fn from_msgpack(msgpack: MsgPack) -> Option<Self>;
+}
+
+/// Converts an integer object to an `i128`. Integers wider than 64 bits are 16 big-endian bytes of binary.
+fn integer(msgpack: MsgPack) -> Option<i128> {
+    match msgpack {
+        MsgPack::UInt(n) => Some(i128::from(n)),
+        MsgPack::Int(n) => Some(i128::from(n)),
+        _ => None,
+    }
+}
+
+macro_rules! impl_msgpack_leaf_int {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            impl MsgPackLeaf for $ty {
+                const EXPECTED: &'static str = concat!("a ", stringify!($ty));
+
+                fn to_msgpack(self) -> MsgPack {
+                    int(i64::try_from(self).expect("fits in 64 bits"))
+                }
+
+                fn from_msgpack(msgpack: MsgPack) -> Option<Self> {
+                    $ty::try_from(integer(msgpack)?).ok()
+                }
+            }
+        )+
+    }
+}
+
+impl_msgpack_leaf_int!(u8, u16, u32, i8, i16, i32, i64);
+
+/// As `UInt`.
+impl MsgPackLeaf for u64 {
+    const EXPECTED: &'static str = "a u64";
+
+    fn to_msgpack(self) -> MsgPack {
+        MsgPack::UInt(self)
+    }
+
+    fn from_msgpack(msgpack: MsgPack) -> Option<Self> {
+        u64::try_from(integer(msgpack)?).ok()
+    }
+}
+
+/// As a `u64`.
+impl MsgPackLeaf for usize {
+    const EXPECTED: &'static str = "a usize";
+
+    fn to_msgpack(self) -> MsgPack {
+        (self as u64).to_msgpack()
+    }
+
+    fn from_msgpack(msgpack: MsgPack) -> Option<Self> {
+        usize::try_from(integer(msgpack)?).ok()
+    }
+}
+
+/// As an `i64`.
+impl MsgPackLeaf for isize {
+    const EXPECTED: &'static str = "an isize";
+
+    fn to_msgpack(self) -> MsgPack {
+        (self as i64).to_msgpack()
+    }
+
+    fn from_msgpack(msgpack: MsgPack) -> Option<Self> {
+        isize::try_from(integer(msgpack)?).ok()
+    }
+}
+
+macro_rules! impl_msgpack_leaf_wide {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            /// As an integer if it fits in 64 bits, or else as 16 big-endian bytes of binary.
+            impl MsgPackLeaf for $ty {
+                const EXPECTED: &'static str = concat!("a ", stringify!($ty));
+
+                fn to_msgpack(self) -> MsgPack {
+                    if let Ok(n) = u64::try_from(self) {
+                        return MsgPack::UInt(n);
+                    }
+                    if let Ok(n) = i64::try_from(self) {
+                        return MsgPack::Int(n);
+                    }
+                    MsgPack::Bin(self.to_be_bytes().to_vec())
+                }
+
+                fn from_msgpack(msgpack: MsgPack) -> Option<Self> {
+                    match msgpack {
+                        MsgPack::Bin(bytes) => Some($ty::from_be_bytes(bytes.try_into().ok()?)),
+                        msgpack => $ty::try_from(integer(msgpack)?).ok(),
+                    }
+                }
+            }
+        )+
+    }
+}
+
+impl_msgpack_leaf_wide!(u128, i128);
+
+impl MsgPackLeaf for f32 {
+    const EXPECTED: &'static str = "an f32";
+
+    fn to_msgpack(self) -> MsgPack {
+        MsgPack::Float32(self)
+    }
+
+    fn from_msgpack(msgpack: MsgPack) -> Option<Self> {
+        match msgpack {
+            MsgPack::Float32(f) => Some(f),
+            MsgPack::Float64(f) => Some(f as f32),
+            _ => None,
+        }
+    }
+}
+
+impl MsgPackLeaf for f64 {
+    const EXPECTED: &'static str = "an f64";
+
+    fn to_msgpack(self) -> MsgPack {
+        MsgPack::Float64(self)
+    }
+
+    fn from_msgpack(msgpack: MsgPack) -> Option<Self> {
+        match msgpack {
+            MsgPack::Float32(f) => Some(f64::from(f)),
+            MsgPack::Float64(f) => Some(f),
+            _ => None,
+        }
+    }
+}
+
+impl MsgPackLeaf for bool {
+    const EXPECTED: &'static str = "a boolean";
+
+    fn to_msgpack(self) -> MsgPack {
+        MsgPack::Bool(self)
+    }
+
+    fn from_msgpack(msgpack: MsgPack) -> Option<Self> {
+        match msgpack {
+            MsgPack::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+}
+
+impl MsgPackLeaf for char {
+    const EXPECTED: &'static str = "a string of one character";
+
+    fn to_msgpack(self) -> MsgPack {
+        MsgPack::Str(self.to_string())
+    }
+
+    fn from_msgpack(msgpack: MsgPack) -> Option<Self> {
+        match msgpack {
+            MsgPack::Str(s) => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Some(c),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl MsgPackLeaf for String {
+    const EXPECTED: &'static str = "a string";
+
+    fn to_msgpack(self) -> MsgPack {
+        MsgPack::Str(self)
+    }
+
+    fn from_msgpack(msgpack: MsgPack) -> Option<Self> {
+        match msgpack {
+            MsgPack::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl<T> MsgPackLeaf for Opaque<T>
+where
+    T: MsgPackLeaf,
+{
+    const EXPECTED: &'static str = T::EXPECTED;
+
+    fn to_msgpack(self) -> MsgPack {
+        self.0.to_msgpack()
+    }
+
+    fn from_msgpack(msgpack: MsgPack) -> Option<Self> {
+        T::from_msgpack(msgpack).map(Opaque)
+    }
+}
+
+/// Converts a representation to MessagePack, using its metadata.
+///
+/// Like `GToCbor`, this follows the layout of serde's derive, as rmp-serde does with named structs:
+/// structs are maps keyed by the strings of their field names, tuple structs are arrays,
+/// or their only field if they have one, and unit structs are `nil`. Unit variants
+/// are the string of their name, and others are maps with their name as the only key. `Option` is `nil` or its value,
+/// and tuples, arrays, and lists are arrays. Leaves are converted with `MsgPackLeaf`.
+///
+/// `encode_msgpack` and `decode_msgpack` use this and `GFromMsgPack` to encode and decode `Generic` types
+/// as MessagePack, e.g. as the entries of Redis streams, or the messages of msgpack-RPC.
+/// Only the `msgpack` feature is required, which has no dependencies.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{decode_msgpack, encode_msgpack, DecodeMsgPackError, MsgPack};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Job {
+///     id: u32,
+///     state: State,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum State {
+///     Queued,
+///     Failed(i8),
+/// }
+///
+/// let bytes = encode_msgpack(Job { id: 7, state: State::Failed(-1) });
+/// assert_eq!(
+///     bytes,
+///     [
+///         0x82, // map of 2 pairs
+///         0xa2, b'i', b'd', 0x07,
+///         0xa5, b's', b't', b'a', b't', b'e', 0x81, 0xa6, b'F', b'a', b'i', b'l', b'e', b'd', 0xff,
+///     ]
+/// );
+/// assert_eq!(decode_msgpack::<Job>(&bytes), Ok(Job { id: 7, state: State::Failed(-1) }));
+///
+/// let mut bytes = Vec::new();
+/// MsgPack::Map(vec![(MsgPack::Str("id".to_string()), MsgPack::Int(-1))]).encode(&mut bytes);
+/// let err = decode_msgpack::<Job>(&bytes).unwrap_err();
+/// assert_eq!(err, DecodeMsgPackError::Invalid { path: "id".to_string(), expected: "a u32".to_string() });
+/// ```
+pub trait GToMsgPack {
+    /// Converts `self` to MessagePack.
+    fn gto_msgpack(self) -> MsgPack;
+}
+
+/// Converts MessagePack to a representation, using its metadata, without serde.
+///
+/// This is the inverse of `GToMsgPack`. Like serde's derive, structs can also be decoded from arrays,
+/// unknown fields are ignored, and missing fields are an error, unless they can be decoded from `nil`, e.g. `Option`s.
+pub trait GFromMsgPack: Sized {
+    /// Converts `msgpack`, found at `path`, to `Self`.
+    fn gfrom_msgpack(msgpack: MsgPack, path: &mut String) -> Result<Self, DecodeMsgPackError>;
+}
+
+/// An error decoding a representation from MessagePack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeMsgPackError {
+    /// The bytes weren't valid MessagePack, at the given offset.
+    Syntax(usize),
+    /// Arrays and maps were nested more than 128 deep, at the given offset.
+    Depth(usize),
+    /// There was no value for the field with the given path.
+    Missing(String),
+    /// The value had the wrong type or length, or a leaf was invalid.
+    Invalid {
+        /// The path of the value.
+        path: String,
+        /// A description of the expected value, e.g. `"an array of length 2"`.
+        expected: String,
+    },
+    /// The name of a variant matched none of the variants of its sum.
+    Variant {
+        /// The path of the sum.
+        path: String,
+        /// The unknown variant name.
+        name: String,
+    },
+    /// The value was invalid for its type, e.g. a `NonZeroU8` of zero.
+    Repr(ReprError),
+}
+
+impl Display for DecodeMsgPackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeMsgPackError::Syntax(pos) => write!(f, "invalid MessagePack at byte {}", pos),
+            DecodeMsgPackError::Depth(pos) => {
+                write!(f, "MessagePack nested too deeply at byte {}", pos)
+            }
+            DecodeMsgPackError::Missing(path) => write!(f, "missing value for `{}`", path),
+            DecodeMsgPackError::Invalid { path, expected } => {
+                write!(f, "expected {} for `{}`", expected, path)
+            }
+            DecodeMsgPackError::Variant { path, name } => {
+                write!(f, "unknown variant `{}` for `{}`", name, path)
+            }
+            DecodeMsgPackError::Repr(error) => write!(f, "invalid value: {}", error),
+        }
+    }
+}
+
+impl Error for DecodeMsgPackError {}
+
+impl Tree for MsgPack {
+    type Object = Vec<(MsgPack, MsgPack)>;
+    type EncodeError = Infallible;
+    type Error = DecodeMsgPackError;
+
+    const STRING: &'static str = "a string";
+    const OBJECT: &'static str = "a map";
+
+    fn null() -> Self {
+        MsgPack::Nil
+    }
+
+    fn string(s: &str) -> Self {
+        MsgPack::Str(s.to_string())
+    }
+
+    fn array(items: Vec<Self>) -> Self {
+        MsgPack::Array(items)
+    }
+
+    fn object(fields: Vec<(&'static str, Self)>) -> Self {
+        MsgPack::Map(
+            fields
+                .into_iter()
+                .map(|(name, msgpack)| (MsgPack::Str(name.to_string()), msgpack))
+                .collect(),
+        )
+    }
+
+    fn is_null(&self) -> bool {
+        *self == MsgPack::Nil
+    }
+
+    fn into_array(self) -> Result<Vec<Self>, Self> {
+        match self {
+            MsgPack::Array(items) => Ok(items),
+            msgpack => Err(msgpack),
+        }
+    }
+
+    fn into_string(self) -> Result<String, Self> {
+        match self {
+            MsgPack::Str(s) => Ok(s),
+            msgpack => Err(msgpack),
+        }
+    }
+
+    fn into_object(self) -> Result<Vec<(MsgPack, MsgPack)>, Self> {
+        match self {
+            MsgPack::Map(pairs) => Ok(pairs),
+            msgpack => Err(msgpack),
+        }
+    }
+
+    fn remove(pairs: &mut Vec<(MsgPack, MsgPack)>, name: &str) -> Option<Self> {
+        let i = pairs
+            .iter()
+            .position(|(key, _)| matches!(key, MsgPack::Str(key) if key == name))?;
+        Some(pairs.swap_remove(i).1)
+    }
+
+    fn into_entry(pairs: Vec<(MsgPack, MsgPack)>) -> Option<(String, Self)> {
+        match <[_; 1]>::try_from(pairs) {
+            Ok([(MsgPack::Str(name), msgpack)]) => Some((name, msgpack)),
+            _ => None,
+        }
+    }
+
+    fn missing(path: &str) -> DecodeMsgPackError {
+        DecodeMsgPackError::Missing(path.to_string())
+    }
+
+    fn invalid(path: &str, expected: &dyn Display) -> DecodeMsgPackError {
+        DecodeMsgPackError::Invalid {
+            path: path.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    fn variant(path: &str, name: String) -> DecodeMsgPackError {
+        DecodeMsgPackError::Variant {
+            path: path.to_string(),
+            name,
+        }
+    }
+}
+
+impl<T> ToTreeLeaf<MsgPack> for T
+where
+    T: MsgPackLeaf,
+{
+    fn to_tree(self) -> Result<MsgPack, Infallible> {
+        Ok(self.to_msgpack())
+    }
+}
+
+impl<T> FromTreeLeaf<MsgPack> for T
+where
+    T: MsgPackLeaf,
+{
+    fn from_tree(msgpack: MsgPack, path: &str) -> Result<Self, DecodeMsgPackError> {
+        T::from_msgpack(msgpack).ok_or_else(|| MsgPack::invalid(path, &T::EXPECTED))
+    }
+}
+
+impl<T> GToMsgPack for T
+where
+    T: GToTree<MsgPack>,
+{
+    fn gto_msgpack(self) -> MsgPack {
+        match self.gto_tree() {
+            Ok(msgpack) => msgpack,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl<T> GFromMsgPack for T
+where
+    T: GFromTree<MsgPack>,
+{
+    fn gfrom_msgpack(msgpack: MsgPack, path: &mut String) -> Result<Self, DecodeMsgPackError> {
+        T::gfrom_tree(msgpack, path)
+    }
+}
+
+/// Encodes `x` as MessagePack, as `serialize_generic` with a MessagePack serializer would.
+pub fn encode_msgpack<T>(x: T) -> Vec<u8>
+where
+    T: Generic,
+    T::Repr: GToMsgPack,
+{
+    let mut out = Vec::new();
+    x.into_repr().gto_msgpack().encode(&mut out);
+    out
+}
+
+/// Decodes a `T` from MessagePack, which must span all of `bytes`.
+///
+/// Errors name the path of the value which failed to decode, as produced by `flatten`.
+pub fn decode_msgpack<T>(bytes: &[u8]) -> Result<T, DecodeMsgPackError>
+where
+    T: Generic,
+    T::Repr: GFromMsgPack,
+{
+    let msgpack = MsgPack::decode(bytes)?;
+    let repr = T::Repr::gfrom_msgpack(msgpack, &mut String::new())?;
+    T::checked_from_repr(repr).map_err(DecodeMsgPackError::Repr)
+}
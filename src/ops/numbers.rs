@@ -0,0 +1,284 @@
+use std::any::Any;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+/// Visits every numeric leaf of a representation, in order, as an `f64`.
+///
+/// Numeric leaves are those of the primitive integer and float types, matched by their exact type via `Any`.
+/// Other leaves are skipped, including numbers within an `Opaque` or `Sensitive` leaf.
+/// Metadata is transparent, and sums only visit the variant they hold.
+///
+/// Integers larger than 2<sup>53</sup> in magnitude are rounded to the nearest `f64`.
+///
+/// `number_stats` uses this to compute the count, sum, minimum and maximum of every numeric leaf in one pass,
+/// and `count_numbers`, `sum_numbers`, `min_number`, `max_number` and `mean_number` compute each alone.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{count_numbers, max_number, mean_number, min_number, number_stats, sum_numbers};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Clone)]
+/// struct Order {
+///     id: String,
+///     quantity: u32,
+///     prices: Vec<f64>,
+///     discount: Discount,
+/// }
+///
+/// #[derive(Generic, Clone)]
+/// enum Discount {
+///     None,
+///     Percent(u8),
+///     Fixed { amount: f32, code: String },
+/// }
+///
+/// let order = Order {
+///     id: "a1".to_string(),
+///     quantity: 2,
+///     prices: vec![1.5, 3.5],
+///     discount: Discount::Percent(10),
+/// };
+/// assert_eq!(count_numbers(order.clone()), 4);
+/// assert_eq!(sum_numbers(order.clone()), 17.0);
+/// assert_eq!(min_number(order.clone()), Some(1.5));
+/// assert_eq!(max_number(order.clone()), Some(10.0));
+/// assert_eq!(mean_number(order.clone()), Some(4.25));
+///
+/// let stats = number_stats(Order { discount: Discount::None, ..order });
+/// assert_eq!((stats.count, stats.sum), (3, 7.0));
+///
+/// assert_eq!(mean_number(("no numbers",)), None);
+/// ```
+pub trait GNumbers {
+    /// Calls `f` with every numeric leaf of `self`, in order.
+    fn gnumbers<F>(self, f: &mut F)
+    where
+        F: FnMut(f64);
+}
+
+impl GNumbers for Unit {
+    fn gnumbers<F>(self, _f: &mut F)
+    where
+        F: FnMut(f64),
+    {
+    }
+}
+
+impl<A, B> GNumbers for Prod<A, B>
+where
+    A: GNumbers,
+    B: GNumbers,
+{
+    fn gnumbers<F>(self, f: &mut F)
+    where
+        F: FnMut(f64),
+    {
+        let Prod(a, b) = self;
+        a.gnumbers(f);
+        b.gnumbers(f);
+    }
+}
+
+impl<L, R> GNumbers for Sum<L, R>
+where
+    L: GNumbers,
+    R: GNumbers,
+{
+    fn gnumbers<F>(self, f: &mut F)
+    where
+        F: FnMut(f64),
+    {
+        match self {
+            Sum::Left(l) => l.gnumbers(f),
+            Sum::Right(r) => r.gnumbers(f),
+        }
+    }
+}
+
+impl GNumbers for Void {
+    fn gnumbers<F>(self, _f: &mut F)
+    where
+        F: FnMut(f64),
+    {
+        match self {}
+    }
+}
+
+impl<I, M> GNumbers for Meta<I, M>
+where
+    I: GNumbers,
+    M: Singleton,
+{
+    fn gnumbers<F>(self, f: &mut F)
+    where
+        F: FnMut(f64),
+    {
+        let Meta(inner, _) = self;
+        inner.gnumbers(f);
+    }
+}
+
+impl<I, C> GNumbers for Con<I, C>
+where
+    I: GNumbers,
+    C: Singleton,
+{
+    fn gnumbers<F>(self, f: &mut F)
+    where
+        F: FnMut(f64),
+    {
+        let Con(inner, _) = self;
+        inner.gnumbers(f);
+    }
+}
+
+impl<A> GNumbers for List<A>
+where
+    A: GNumbers,
+{
+    fn gnumbers<F>(self, f: &mut F)
+    where
+        F: FnMut(f64),
+    {
+        let List(items) = self;
+        for item in items {
+            item.gnumbers(f);
+        }
+    }
+}
+
+impl<A, const N: usize> GNumbers for [A; N]
+where
+    A: GNumbers,
+{
+    fn gnumbers<F>(self, f: &mut F)
+    where
+        F: FnMut(f64),
+    {
+        for item in IntoIterator::into_iter(self) {
+            item.gnumbers(f);
+        }
+    }
+}
+
+impl<L> GNumbers for L
+where
+    L: Leaf + 'static,
+{
+    fn gnumbers<F>(self, f: &mut F)
+    where
+        F: FnMut(f64),
+    {
+        let leaf = &self as &dyn Any;
+        macro_rules! visit_numbers {
+            ( $( $ty:ty ),+ $(,)? ) => {
+                $(
+                    if let Some(&x) = leaf.downcast_ref::<$ty>() {
+                        f(x as f64);
+                        return;
+                    }
+                )+
+            };
+        }
+        #[rustfmt::skip]
+        visit_numbers!(
+            u8, u16, u32, u64, u128, usize,
+            i8, i16, i32, i64, i128, isize,
+            f32, f64,
+        );
+    }
+}
+
+/// The count, sum, minimum and maximum of the numeric leaves of a value, as computed by `number_stats`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberStats {
+    /// The number of numeric leaves.
+    pub count: usize,
+    /// The sum of the numeric leaves, or `0.0` if there are none.
+    pub sum: f64,
+    /// The smallest numeric leaf, ignoring `NaN`, or `None` if there are none, or all are `NaN`.
+    pub min: Option<f64>,
+    /// The largest numeric leaf, ignoring `NaN`, or `None` if there are none, or all are `NaN`.
+    pub max: Option<f64>,
+}
+
+impl NumberStats {
+    /// The mean of the numeric leaves, or `None` if there are none.
+    pub fn mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.sum / self.count as f64)
+        }
+    }
+}
+
+/// Computes the count, sum, minimum and maximum of the numeric leaves in the representation of `x`.
+pub fn number_stats<X>(x: X) -> NumberStats
+where
+    X: Generic,
+    X::Repr: GNumbers,
+{
+    let mut stats = NumberStats {
+        count: 0,
+        sum: 0.0,
+        min: None,
+        max: None,
+    };
+    x.into_repr().gnumbers(&mut |n| {
+        stats.count += 1;
+        stats.sum += n;
+        if !n.is_nan() {
+            stats.min = Some(stats.min.map_or(n, |min| min.min(n)));
+            stats.max = Some(stats.max.map_or(n, |max| max.max(n)));
+        }
+    });
+    stats
+}
+
+/// Counts the numeric leaves in the representation of `x`.
+pub fn count_numbers<X>(x: X) -> usize
+where
+    X: Generic,
+    X::Repr: GNumbers,
+{
+    number_stats(x).count
+}
+
+/// Sums the numeric leaves in the representation of `x`, or `0.0` if there are none.
+pub fn sum_numbers<X>(x: X) -> f64
+where
+    X: Generic,
+    X::Repr: GNumbers,
+{
+    number_stats(x).sum
+}
+
+/// The smallest numeric leaf in the representation of `x`, ignoring `NaN`.
+pub fn min_number<X>(x: X) -> Option<f64>
+where
+    X: Generic,
+    X::Repr: GNumbers,
+{
+    number_stats(x).min
+}
+
+/// The largest numeric leaf in the representation of `x`, ignoring `NaN`.
+pub fn max_number<X>(x: X) -> Option<f64>
+where
+    X: Generic,
+    X::Repr: GNumbers,
+{
+    number_stats(x).max
+}
+
+/// The mean of the numeric leaves in the representation of `x`, or `None` if there are none.
+pub fn mean_number<X>(x: X) -> Option<f64>
+where
+    X: Generic,
+    X::Repr: GNumbers,
+{
+    number_stats(x).mean()
+}
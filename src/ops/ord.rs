@@ -0,0 +1,281 @@
+use std::cmp::Ordering;
+
+use crate::{Con, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+use super::{Borrowed, GEq, GPartialEq, Ignored};
+
+/// Compares representations, node by node.
+///
+/// Products are compared lexicographically, in field order.
+/// Sums are compared by variant first, in declaration order, and then by payload.
+/// Lists and arrays are compared lexicographically, and leaves with their own `PartialOrd` impl.
+///
+/// This matches the ordering of `#[derive(PartialOrd)]`.
+/// `#[derive(GenericPartialOrd)]` uses this to implement `PartialOrd`, alongside `#[derive(Generic)]`,
+/// and `#[derive(GenericOrd)]` implements `Ord` via `GOrd`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{GenericEq, GenericOrd, GenericPartialEq, GenericPartialOrd};
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericPartialEq, GenericEq, GenericPartialOrd, GenericOrd)]
+/// enum Foo {
+///     A { a: u8, b: String },
+///     B(Vec<u8>),
+/// }
+///
+/// assert!(Foo::A { a: 1, b: "z".to_string() } < Foo::A { a: 2, b: "a".to_string() });
+/// assert!(Foo::A { a: 9, b: String::new() } < Foo::B(vec![]));
+/// assert!(Foo::B(vec![1]) < Foo::B(vec![1, 0]));
+/// ```
+pub trait GPartialOrd: GPartialEq {
+    /// Compares `self` to `other`, if they're comparable.
+    fn gpartial_cmp(&self, other: &Self) -> Option<Ordering>;
+}
+
+/// Compares representations with a total order, node by node, like `GPartialOrd`.
+pub trait GOrd: GEq + GPartialOrd {
+    /// Compares `self` to `other`.
+    fn gcmp(&self, other: &Self) -> Ordering;
+}
+
+/// Compares pairs of items lexicographically, where a shorter sequence is less than a longer one it's a prefix of.
+fn partial_cmp_seq<'a, A, I>(items: I, len: usize, other_len: usize) -> Option<Ordering>
+where
+    A: GPartialOrd + 'a,
+    I: Iterator<Item = (&'a A, &'a A)>,
+{
+    for (a, b) in items {
+        match a.gpartial_cmp(b) {
+            Some(Ordering::Equal) => {}
+            ordering => return ordering,
+        }
+    }
+    Some(len.cmp(&other_len))
+}
+
+/// Compares pairs of items lexicographically, like `partial_cmp_seq`.
+fn cmp_seq<'a, A, I>(items: I, len: usize, other_len: usize) -> Ordering
+where
+    A: GOrd + 'a,
+    I: Iterator<Item = (&'a A, &'a A)>,
+{
+    for (a, b) in items {
+        match a.gcmp(b) {
+            Ordering::Equal => {}
+            ordering => return ordering,
+        }
+    }
+    len.cmp(&other_len)
+}
+
+impl GPartialOrd for Unit {
+    fn gpartial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+impl GOrd for Unit {
+    fn gcmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<A, B> GPartialOrd for Prod<A, B>
+where
+    A: GPartialOrd,
+    B: GPartialOrd,
+{
+    fn gpartial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (Prod(a, b), Prod(other_a, other_b)) = (self, other);
+        match a.gpartial_cmp(other_a) {
+            Some(Ordering::Equal) => b.gpartial_cmp(other_b),
+            ordering => ordering,
+        }
+    }
+}
+
+impl<A, B> GOrd for Prod<A, B>
+where
+    A: GOrd,
+    B: GOrd,
+{
+    fn gcmp(&self, other: &Self) -> Ordering {
+        let (Prod(a, b), Prod(other_a, other_b)) = (self, other);
+        a.gcmp(other_a).then_with(|| b.gcmp(other_b))
+    }
+}
+
+impl<L, R> GPartialOrd for Sum<L, R>
+where
+    L: GPartialOrd,
+    R: GPartialOrd,
+{
+    fn gpartial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Sum::Left(l), Sum::Left(other)) => l.gpartial_cmp(other),
+            (Sum::Right(r), Sum::Right(other)) => r.gpartial_cmp(other),
+            (Sum::Left(_), Sum::Right(_)) => Some(Ordering::Less),
+            (Sum::Right(_), Sum::Left(_)) => Some(Ordering::Greater),
+        }
+    }
+}
+
+impl<L, R> GOrd for Sum<L, R>
+where
+    L: GOrd,
+    R: GOrd,
+{
+    fn gcmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Sum::Left(l), Sum::Left(other)) => l.gcmp(other),
+            (Sum::Right(r), Sum::Right(other)) => r.gcmp(other),
+            (Sum::Left(_), Sum::Right(_)) => Ordering::Less,
+            (Sum::Right(_), Sum::Left(_)) => Ordering::Greater,
+        }
+    }
+}
+
+impl GPartialOrd for Void {
+    fn gpartial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        match *self {}
+    }
+}
+
+impl GOrd for Void {
+    fn gcmp(&self, _other: &Self) -> Ordering {
+        match *self {}
+    }
+}
+
+impl<I, M> GPartialOrd for Meta<I, M>
+where
+    I: GPartialOrd,
+    M: Singleton,
+{
+    fn gpartial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (Meta(inner, _), Meta(other, _)) = (self, other);
+        inner.gpartial_cmp(other)
+    }
+}
+
+impl<I, M> GOrd for Meta<I, M>
+where
+    I: GOrd,
+    M: Singleton,
+{
+    fn gcmp(&self, other: &Self) -> Ordering {
+        let (Meta(inner, _), Meta(other, _)) = (self, other);
+        inner.gcmp(other)
+    }
+}
+
+impl<I, C> GPartialOrd for Con<I, C>
+where
+    I: GPartialOrd,
+    C: Singleton,
+{
+    fn gpartial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (Con(inner, _), Con(other, _)) = (self, other);
+        inner.gpartial_cmp(other)
+    }
+}
+
+impl<I, C> GOrd for Con<I, C>
+where
+    I: GOrd,
+    C: Singleton,
+{
+    fn gcmp(&self, other: &Self) -> Ordering {
+        let (Con(inner, _), Con(other, _)) = (self, other);
+        inner.gcmp(other)
+    }
+}
+
+impl<A> GPartialOrd for List<A>
+where
+    A: GPartialOrd,
+{
+    fn gpartial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (List(items), List(other)) = (self, other);
+        partial_cmp_seq(items.iter().zip(other), items.len(), other.len())
+    }
+}
+
+impl<A> GOrd for List<A>
+where
+    A: GOrd,
+{
+    fn gcmp(&self, other: &Self) -> Ordering {
+        let (List(items), List(other)) = (self, other);
+        cmp_seq(items.iter().zip(other), items.len(), other.len())
+    }
+}
+
+impl<A, const N: usize> GPartialOrd for [A; N]
+where
+    A: GPartialOrd,
+{
+    fn gpartial_cmp(&self, other: &Self) -> Option<Ordering> {
+        partial_cmp_seq(self.iter().zip(other), N, N)
+    }
+}
+
+impl<A, const N: usize> GOrd for [A; N]
+where
+    A: GOrd,
+{
+    fn gcmp(&self, other: &Self) -> Ordering {
+        cmp_seq(self.iter().zip(other), N, N)
+    }
+}
+
+impl<T> GPartialOrd for Borrowed<'_, T>
+where
+    T: PartialOrd,
+{
+    fn gpartial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(other.0)
+    }
+}
+
+impl<T> GOrd for Borrowed<'_, T>
+where
+    T: Ord,
+{
+    fn gcmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(other.0)
+    }
+}
+
+impl<T> GPartialOrd for Ignored<T> {
+    fn gpartial_cmp(&self, _other: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+impl<T> GOrd for Ignored<T> {
+    fn gcmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<T> GPartialOrd for T
+where
+    T: Leaf + PartialOrd,
+{
+    fn gpartial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.partial_cmp(other)
+    }
+}
+
+impl<T> GOrd for T
+where
+    T: Leaf + Ord,
+{
+    fn gcmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
@@ -0,0 +1,167 @@
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Leaf, List, MapLeaves, Meta, Prod, Singleton, Sum, Unit, Void};
+
+use super::Partial;
+
+/// The patch of `T`: its representation with every leaf made optional with `Partial`.
+pub type Patch<T> = <<T as Generic>::Repr as MapLeaves<Partial>>::Output;
+
+/// Applies a patch to a representation, overriding each leaf which is `Some` in the patch
+/// and keeping each leaf which is `None`.
+///
+/// Patches mirror the whole representation, so nested structs are patched leaf by leaf.
+/// A patch holding a different variant than the value is ignored, and lists are patched item by item,
+/// ignoring any items past the end of the value.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::marker::PhantomData;
+/// use generics::ops::{patch, Patch};
+/// use generics::{Con, Generic, Meta, Prod, Unit};
+///
+/// #[derive(Debug, PartialEq, Generic)]
+/// struct Inner {
+///     b: u8,
+///     c: String,
+/// }
+///
+/// #[derive(Debug, PartialEq, Generic)]
+/// struct Foo {
+///     a: u8,
+///     inner: Inner,
+/// }
+///
+/// let foo = Foo { a: 1, inner: Inner { b: 2, c: "c".to_string() } };
+/// let p: Patch<Foo> = Con(
+///     Prod(
+///         Meta(None, PhantomData),
+///         Prod(
+///             Meta(
+///                 Con(
+///                     Prod(
+///                         Meta(Some(3), PhantomData),
+///                         Prod(Meta(None, PhantomData), Unit),
+///                     ),
+///                     PhantomData,
+///                 ),
+///                 PhantomData,
+///             ),
+///             Unit,
+///         ),
+///     ),
+///     PhantomData,
+/// );
+/// assert_eq!(patch(foo, p), Foo { a: 1, inner: Inner { b: 3, c: "c".to_string() } });
+/// ```
+pub trait GPatch: MapLeaves<Partial> + Sized {
+    /// Applies `patch` to `self`.
+    fn gpatch(self, patch: Self::Output) -> Self;
+}
+
+impl GPatch for Unit {
+    fn gpatch(self, _patch: Self::Output) -> Self {
+        Unit
+    }
+}
+
+impl<A, B> GPatch for Prod<A, B>
+where
+    A: GPatch,
+    B: GPatch,
+{
+    fn gpatch(self, patch: Self::Output) -> Self {
+        let (Prod(a, b), Prod(patch_a, patch_b)) = (self, patch);
+        Prod(a.gpatch(patch_a), b.gpatch(patch_b))
+    }
+}
+
+impl<L, R> GPatch for Sum<L, R>
+where
+    L: GPatch,
+    R: GPatch,
+{
+    fn gpatch(self, patch: Self::Output) -> Self {
+        match (self, patch) {
+            (Sum::Left(l), Sum::Left(patch)) => Sum::Left(l.gpatch(patch)),
+            (Sum::Right(r), Sum::Right(patch)) => Sum::Right(r.gpatch(patch)),
+            (this, _) => this,
+        }
+    }
+}
+
+impl GPatch for Void {
+    fn gpatch(self, _patch: Self::Output) -> Self {
+        match self {}
+    }
+}
+
+impl<I, M> GPatch for Meta<I, M>
+where
+    I: GPatch,
+    M: Singleton,
+{
+    fn gpatch(self, patch: Self::Output) -> Self {
+        let (Meta(inner, _), Meta(patch, _)) = (self, patch);
+        Meta(inner.gpatch(patch), PhantomData)
+    }
+}
+
+impl<I, C> GPatch for Con<I, C>
+where
+    I: GPatch,
+    C: Singleton,
+{
+    fn gpatch(self, patch: Self::Output) -> Self {
+        let (Con(inner, _), Con(patch, _)) = (self, patch);
+        Con(inner.gpatch(patch), PhantomData)
+    }
+}
+
+impl<A> GPatch for List<A>
+where
+    A: GPatch,
+{
+    fn gpatch(self, patch: Self::Output) -> Self {
+        let (List(items), List(patch)) = (self, patch);
+        let mut patch = patch.into_iter();
+        List(
+            items
+                .into_iter()
+                .map(|item| match patch.next() {
+                    Some(patch) => item.gpatch(patch),
+                    None => item,
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<A, const N: usize> GPatch for [A; N]
+where
+    A: GPatch,
+{
+    fn gpatch(self, patch: Self::Output) -> Self {
+        let mut patch = IntoIterator::into_iter(patch);
+        self.map(|item| item.gpatch(patch.next().unwrap()))
+    }
+}
+
+impl<T> GPatch for T
+where
+    T: Leaf,
+{
+    fn gpatch(self, patch: Option<T>) -> Self {
+        patch.unwrap_or(self)
+    }
+}
+
+/// Applies `patch` to the representation of `x`, and converts the result back.
+pub fn patch<T>(x: T, patch: Patch<T>) -> T
+where
+    T: Generic,
+    T::Repr: GPatch,
+{
+    T::from_repr(x.into_repr().gpatch(patch))
+}
@@ -0,0 +1,380 @@
+use std::fmt::{self, Debug, Display};
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+/// Options for rendering a representation with `GPretty`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrettyOptions {
+    /// The number of spaces to indent each level by, when expanded.
+    pub indent: usize,
+    /// The number of nested constructors and collections to render, beyond which they are elided as `..`.
+    pub max_depth: Option<usize>,
+    /// The number of elements of each list or array to render, beyond which the rest are counted.
+    pub max_items: Option<usize>,
+    /// Whether to render everything on one line, instead of one field or element per line.
+    pub compact: bool,
+}
+
+/// Expanded, with an indent of 4 and no limits, matching `{:#?}`.
+impl Default for PrettyOptions {
+    fn default() -> Self {
+        PrettyOptions {
+            indent: 4,
+            max_depth: None,
+            max_items: None,
+            compact: false,
+        }
+    }
+}
+
+/// Renders representations according to `PrettyOptions`, tracking the current depth.
+pub struct Printer<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    options: &'a PrettyOptions,
+    depth: usize,
+}
+
+/// Delimiters of a sequence rendered by `Printer`.
+#[derive(Clone, Copy)]
+enum Delims {
+    Braces,
+    Parens,
+    Brackets,
+}
+
+impl Printer<'_, '_> {
+    /// Writes a leaf.
+    fn leaf(&mut self, leaf: &dyn Debug) -> fmt::Result {
+        write!(self.f, "{:?}", leaf)
+    }
+
+    /// Writes the fields of a constructor named `name`, as a struct if they're named, or a tuple otherwise.
+    fn fields(&mut self, name: &str, fields: &[&dyn GPretty]) -> fmt::Result {
+        // Tuple fields are named by their index.
+        let is_named = |field: &&dyn GPretty| match field.field_name() {
+            Some(name) => name.parse::<usize>().is_err(),
+            None => false,
+        };
+        if fields.is_empty() {
+            self.f.write_str(name)
+        } else if fields.iter().all(is_named) {
+            self.seq(name, Delims::Braces, fields, true, 0)
+        } else {
+            self.seq(name, Delims::Parens, fields, false, 0)
+        }
+    }
+
+    /// Writes the elements of a list or array, truncated to `max_items`.
+    fn items(&mut self, items: &[&dyn GPretty]) -> fmt::Result {
+        let shown = match self.options.max_items {
+            Some(max) => items.len().min(max),
+            None => items.len(),
+        };
+        self.seq(
+            "",
+            Delims::Brackets,
+            &items[..shown],
+            false,
+            items.len() - shown,
+        )
+    }
+
+    /// Writes a delimited sequence of `entries`, with their field names if `named`, followed by a count of `omitted` entries.
+    fn seq(
+        &mut self,
+        name: &str,
+        delims: Delims,
+        entries: &[&dyn GPretty],
+        named: bool,
+        omitted: usize,
+    ) -> fmt::Result {
+        let (open, close, pad) = match delims {
+            Delims::Braces => ("{", "}", " "),
+            Delims::Parens => ("(", ")", ""),
+            Delims::Brackets => ("[", "]", ""),
+        };
+        self.f.write_str(name)?;
+        if !name.is_empty() && pad == " " {
+            self.f.write_str(" ")?;
+        }
+        self.f.write_str(open)?;
+        if entries.is_empty() && omitted == 0 {
+            return self.f.write_str(close);
+        }
+        if self.options.max_depth.is_some_and(|max| self.depth >= max) {
+            return write!(self.f, "{}..{}{}", pad, pad, close);
+        }
+
+        self.depth += 1;
+        let result = if self.options.compact {
+            self.compact_entries(entries, named, omitted, pad)
+        } else {
+            self.expanded_entries(entries, named, omitted)
+        };
+        self.depth -= 1;
+        result?;
+
+        if !self.options.compact {
+            self.indent()?;
+        }
+        self.f.write_str(close)
+    }
+
+    /// Writes `entries` on one line, separated by commas.
+    fn compact_entries(
+        &mut self,
+        entries: &[&dyn GPretty],
+        named: bool,
+        omitted: usize,
+        pad: &str,
+    ) -> fmt::Result {
+        self.f.write_str(pad)?;
+        for (i, entry) in entries.iter().enumerate() {
+            if i > 0 {
+                self.f.write_str(", ")?;
+            }
+            self.entry(*entry, named)?;
+        }
+        if omitted > 0 {
+            let sep = if entries.is_empty() { "" } else { ", " };
+            write!(self.f, "{}... {} more", sep, omitted)?;
+        }
+        self.f.write_str(pad)
+    }
+
+    /// Writes `entries` one per line, each followed by a comma.
+    fn expanded_entries(
+        &mut self,
+        entries: &[&dyn GPretty],
+        named: bool,
+        omitted: usize,
+    ) -> fmt::Result {
+        self.f.write_str("\n")?;
+        for entry in entries {
+            self.indent()?;
+            self.entry(*entry, named)?;
+            self.f.write_str(",\n")?;
+        }
+        if omitted > 0 {
+            self.indent()?;
+            writeln!(self.f, "... {} more", omitted)?;
+        }
+        Ok(())
+    }
+
+    /// Writes an entry, preceded by its field name if `named`.
+    fn entry(&mut self, entry: &dyn GPretty, named: bool) -> fmt::Result {
+        if let (true, Some(name)) = (named, entry.field_name()) {
+            write!(self.f, "{}: ", name)?;
+        }
+        entry.fmt_pretty(self)
+    }
+
+    /// Writes the indentation of the current depth.
+    fn indent(&mut self) -> fmt::Result {
+        let width = self.depth * self.options.indent;
+        write!(self.f, "{:width$}", "", width = width)
+    }
+}
+
+/// Renders a representation readably, according to `PrettyOptions`.
+///
+/// With the default options, this matches `{:#?}` of `#[derive(Debug)]`. Options can render everything on one line,
+/// change the indent, elide constructors and collections nested beyond a maximum depth as `..`,
+/// or truncate lists and arrays to a maximum number of elements.
+/// Leaves are formatted with their own `Debug` impl.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{pretty, PrettyOptions};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Clone)]
+/// struct Inner {
+///     values: Vec<u8>,
+/// }
+///
+/// #[derive(Generic, Clone)]
+/// struct Foo {
+///     name: String,
+///     inner: Inner,
+/// }
+///
+/// let foo = Foo { name: "foo".to_string(), inner: Inner { values: vec![1, 2, 3, 4] } };
+///
+/// let options = PrettyOptions { indent: 2, max_items: Some(2), ..PrettyOptions::default() };
+/// assert_eq!(
+///     pretty(foo.clone(), &options).to_string(),
+///     "Foo {\n  name: \"foo\",\n  inner: Inner {\n    values: [\n      1,\n      2,\n      ... 2 more\n    ],\n  },\n}",
+/// );
+///
+/// let options = PrettyOptions { compact: true, max_depth: Some(1), ..PrettyOptions::default() };
+/// assert_eq!(pretty(foo, &options).to_string(), "Foo { name: \"foo\", inner: Inner { .. } }");
+/// ```
+pub trait GPretty {
+    /// Renders `self` with `printer`.
+    fn fmt_pretty(&self, printer: &mut Printer<'_, '_>) -> fmt::Result;
+
+    /// The name of the field holding `self`, if `self` is a `Meta`.
+    fn field_name(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Collects the elements of a `Prod` chain.
+pub trait GPrettyFields {
+    /// Appends each element of `self` to `fields`.
+    fn collect_fields<'a>(&'a self, fields: &mut Vec<&'a dyn GPretty>);
+}
+
+impl GPrettyFields for Unit {
+    fn collect_fields<'a>(&'a self, _fields: &mut Vec<&'a dyn GPretty>) {}
+}
+
+impl<A, B> GPrettyFields for Prod<A, B>
+where
+    A: GPretty,
+    B: GPrettyFields,
+{
+    fn collect_fields<'a>(&'a self, fields: &mut Vec<&'a dyn GPretty>) {
+        let Prod(a, b) = self;
+        fields.push(a);
+        b.collect_fields(fields);
+    }
+}
+
+/// A bare `Unit` is the representation of `()`.
+impl GPretty for Unit {
+    fn fmt_pretty(&self, printer: &mut Printer<'_, '_>) -> fmt::Result {
+        printer.f.write_str("()")
+    }
+}
+
+/// A bare `Prod` is the representation of a tuple.
+impl<A, B> GPretty for Prod<A, B>
+where
+    Self: GPrettyFields,
+{
+    fn fmt_pretty(&self, printer: &mut Printer<'_, '_>) -> fmt::Result {
+        let mut fields = Vec::new();
+        self.collect_fields(&mut fields);
+        printer.fields("", &fields)
+    }
+}
+
+impl<L, R> GPretty for Sum<L, R>
+where
+    L: GPretty,
+    R: GPretty,
+{
+    fn fmt_pretty(&self, printer: &mut Printer<'_, '_>) -> fmt::Result {
+        match self {
+            Sum::Left(l) => l.fmt_pretty(printer),
+            Sum::Right(r) => r.fmt_pretty(printer),
+        }
+    }
+}
+
+impl GPretty for Void {
+    fn fmt_pretty(&self, _printer: &mut Printer<'_, '_>) -> fmt::Result {
+        match *self {}
+    }
+}
+
+impl<I, M> GPretty for Meta<I, M>
+where
+    I: GPretty,
+    M: Singleton<T = &'static str>,
+{
+    fn fmt_pretty(&self, printer: &mut Printer<'_, '_>) -> fmt::Result {
+        let Meta(inner, _) = self;
+        inner.fmt_pretty(printer)
+    }
+
+    fn field_name(&self) -> Option<&'static str> {
+        Some(M::get())
+    }
+}
+
+impl<I, C> GPretty for Con<I, C>
+where
+    I: GPrettyFields,
+    C: Singleton<T = &'static str>,
+{
+    fn fmt_pretty(&self, printer: &mut Printer<'_, '_>) -> fmt::Result {
+        let Con(inner, _) = self;
+        let mut fields = Vec::new();
+        inner.collect_fields(&mut fields);
+        printer.fields(C::get(), &fields)
+    }
+}
+
+impl<A> GPretty for List<A>
+where
+    A: GPretty,
+{
+    fn fmt_pretty(&self, printer: &mut Printer<'_, '_>) -> fmt::Result {
+        let List(items) = self;
+        let items = items
+            .iter()
+            .map(|item| item as &dyn GPretty)
+            .collect::<Vec<_>>();
+        printer.items(&items)
+    }
+}
+
+impl<A, const N: usize> GPretty for [A; N]
+where
+    A: GPretty,
+{
+    fn fmt_pretty(&self, printer: &mut Printer<'_, '_>) -> fmt::Result {
+        let items = self
+            .iter()
+            .map(|item| item as &dyn GPretty)
+            .collect::<Vec<_>>();
+        printer.items(&items)
+    }
+}
+
+impl<T> GPretty for T
+where
+    T: Leaf + Debug,
+{
+    fn fmt_pretty(&self, printer: &mut Printer<'_, '_>) -> fmt::Result {
+        printer.leaf(self)
+    }
+}
+
+/// Converts `x` into its representation, which can be rendered with `{}` according to `options`.
+///
+/// ```rust
+/// use generics::ops::{pretty, PrettyOptions};
+///
+/// let options = PrettyOptions { compact: true, max_items: Some(3), ..PrettyOptions::default() };
+/// assert_eq!(pretty((1..10).collect::<Vec<u8>>(), &options).to_string(), "[1, 2, 3, ... 6 more]");
+/// assert_eq!(pretty(Some(vec![(); 0]), &options).to_string(), "Some([])");
+/// ```
+pub fn pretty<'a, T>(x: T, options: &'a PrettyOptions) -> impl Display + 'a
+where
+    T: Generic,
+    T::Repr: GPretty + 'a,
+{
+    struct Repr<'a, R>(R, &'a PrettyOptions);
+
+    impl<R> Display for Repr<'_, R>
+    where
+        R: GPretty,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let mut printer = Printer {
+                f,
+                options: self.1,
+                depth: 0,
+            };
+            self.0.fmt_pretty(&mut printer)
+        }
+    }
+
+    Repr(x.into_repr(), options)
+}
@@ -0,0 +1,1955 @@
+use std::any::TypeId;
+use std::convert::{TryFrom, TryInto};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::mem;
+
+use crate::markers::Option_None_Name;
+use crate::{
+    Con, Datatype, Documented, Generic, List, Meta, Opaque, Prod, ReprError, Singleton, Sum, Unit,
+    Void,
+};
+
+use super::flatten::push_segment;
+
+/// A protobuf scalar type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scalar {
+    /// `double`.
+    Double,
+    /// `float`.
+    Float,
+    /// `int32`.
+    Int32,
+    /// `int64`.
+    Int64,
+    /// `uint32`.
+    Uint32,
+    /// `uint64`.
+    Uint64,
+    /// `sint32`, a zigzag varint.
+    Sint32,
+    /// `sint64`, a zigzag varint.
+    Sint64,
+    /// `fixed32`.
+    Fixed32,
+    /// `fixed64`.
+    Fixed64,
+    /// `sfixed32`.
+    Sfixed32,
+    /// `sfixed64`.
+    Sfixed64,
+    /// `bool`.
+    Bool,
+    /// `string`.
+    String,
+    /// `bytes`.
+    Bytes,
+}
+
+impl Scalar {
+    /// The name of the type in `.proto` files, e.g. `"sint32"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Scalar::Double => "double",
+            Scalar::Float => "float",
+            Scalar::Int32 => "int32",
+            Scalar::Int64 => "int64",
+            Scalar::Uint32 => "uint32",
+            Scalar::Uint64 => "uint64",
+            Scalar::Sint32 => "sint32",
+            Scalar::Sint64 => "sint64",
+            Scalar::Fixed32 => "fixed32",
+            Scalar::Fixed64 => "fixed64",
+            Scalar::Sfixed32 => "sfixed32",
+            Scalar::Sfixed64 => "sfixed64",
+            Scalar::Bool => "bool",
+            Scalar::String => "string",
+            Scalar::Bytes => "bytes",
+        }
+    }
+
+    /// The value of the type in `FieldDescriptorProto.Type`.
+    fn descriptor_type(self) -> u64 {
+        match self {
+            Scalar::Double => 1,
+            Scalar::Float => 2,
+            Scalar::Int64 => 3,
+            Scalar::Uint64 => 4,
+            Scalar::Int32 => 5,
+            Scalar::Fixed64 => 6,
+            Scalar::Fixed32 => 7,
+            Scalar::Bool => 8,
+            Scalar::String => 9,
+            Scalar::Bytes => 12,
+            Scalar::Uint32 => 13,
+            Scalar::Sfixed32 => 15,
+            Scalar::Sfixed64 => 16,
+            Scalar::Sint32 => 17,
+            Scalar::Sint64 => 18,
+        }
+    }
+
+    /// The default value of the type, i.e. zero or empty, which a missing field has.
+    pub fn default_value(self) -> ProtoValue {
+        match self {
+            Scalar::Double | Scalar::Fixed64 | Scalar::Sfixed64 => ProtoValue::I64(0),
+            Scalar::Float | Scalar::Fixed32 | Scalar::Sfixed32 => ProtoValue::I32(0),
+            Scalar::String | Scalar::Bytes => ProtoValue::Len(Vec::new()),
+            _ => ProtoValue::Varint(0),
+        }
+    }
+
+    /// Whether repeated fields of the type are packed, i.e. it isn't length-delimited.
+    fn is_packable(self) -> bool {
+        !matches!(self.default_value(), ProtoValue::Len(_))
+    }
+}
+
+/// The value of a protobuf field, by its wire type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtoValue {
+    /// A varint.
+    Varint(u64),
+    /// Eight bytes, e.g. a `double` or `fixed64`.
+    I64(u64),
+    /// Length-delimited bytes, e.g. a string, a message, or a packed repeated field.
+    Len(Vec<u8>),
+    /// Four bytes, e.g. a `float` or `fixed32`.
+    I32(u32),
+}
+
+impl ProtoValue {
+    fn wire_type(&self) -> u64 {
+        match self {
+            ProtoValue::Varint(_) => 0,
+            ProtoValue::I64(_) => 1,
+            ProtoValue::Len(_) => 2,
+            ProtoValue::I32(_) => 5,
+        }
+    }
+
+    /// Whether this is zero or empty, so it's left out of fields without explicit presence.
+    fn is_default(&self) -> bool {
+        match self {
+            ProtoValue::Varint(n) | ProtoValue::I64(n) => *n == 0,
+            ProtoValue::Len(bytes) => bytes.is_empty(),
+            ProtoValue::I32(n) => *n == 0,
+        }
+    }
+
+    /// Appends the field numbered `number` holding `self` to `out`.
+    pub fn encode(&self, number: u32, out: &mut Vec<u8>) {
+        put_varint(out, u64::from(number) << 3 | self.wire_type());
+        self.encode_payload(out);
+    }
+
+    fn encode_payload(&self, out: &mut Vec<u8>) {
+        match self {
+            ProtoValue::Varint(n) => put_varint(out, *n),
+            ProtoValue::I64(n) => out.extend_from_slice(&n.to_le_bytes()),
+            ProtoValue::Len(bytes) => {
+                put_varint(out, bytes.len() as u64);
+                out.extend_from_slice(bytes);
+            }
+            ProtoValue::I32(n) => out.extend_from_slice(&n.to_le_bytes()),
+        }
+    }
+
+    /// Decodes the numbers and values of the fields of a message, in order, which must span all of `bytes`.
+    pub fn decode_message(bytes: &[u8]) -> Result<Vec<(u32, ProtoValue)>, DecodeProtoError> {
+        let mut parser = Parser { bytes, pos: 0 };
+        let mut fields = Vec::new();
+        while parser.pos < bytes.len() {
+            fields.push(parser.field()?);
+        }
+        Ok(fields)
+    }
+}
+
+/// Writes `n` as a varint, i.e. 7 bits per byte, least significant first.
+fn put_varint(out: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        out.push(n as u8 | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+/// A parser over the bytes of a message.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], DecodeProtoError> {
+        match self.bytes.get(self.pos..).and_then(|rest| rest.get(..len)) {
+            Some(bytes) => {
+                self.pos += len;
+                Ok(bytes)
+            }
+            None => Err(DecodeProtoError::Syntax(self.bytes.len())),
+        }
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], DecodeProtoError> {
+        let bytes = self.take(N)?;
+        Ok(bytes.try_into().expect("took `N` bytes"))
+    }
+
+    fn varint(&mut self) -> Result<u64, DecodeProtoError> {
+        let start = self.pos;
+        let mut n = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            n |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(n);
+            }
+        }
+        Err(DecodeProtoError::Syntax(start))
+    }
+
+    /// Reads a value of the wire type `wire_type`, starting at `start`.
+    fn value(&mut self, start: usize, wire_type: u64) -> Result<ProtoValue, DecodeProtoError> {
+        match wire_type {
+            0 => Ok(ProtoValue::Varint(self.varint()?)),
+            1 => Ok(ProtoValue::I64(u64::from_le_bytes(self.take_array()?))),
+            2 => {
+                let len =
+                    usize::try_from(self.varint()?).map_err(|_| DecodeProtoError::Syntax(start))?;
+                Ok(ProtoValue::Len(self.take(len)?.to_vec()))
+            }
+            5 => Ok(ProtoValue::I32(u32::from_le_bytes(self.take_array()?))),
+            // Groups are deprecated, and not supported.
+            _ => Err(DecodeProtoError::Syntax(start)),
+        }
+    }
+
+    fn field(&mut self) -> Result<(u32, ProtoValue), DecodeProtoError> {
+        let start = self.pos;
+        let key = self.varint()?;
+        let number = match u32::try_from(key >> 3) {
+            Ok(number) if number != 0 => number,
+            _ => return Err(DecodeProtoError::Syntax(start)),
+        };
+        Ok((number, self.value(start, key & 0x7)?))
+    }
+}
+
+/// Splits the payload of a packed repeated field into the values of its elements.
+fn unpack(bytes: &[u8], scalar: Scalar) -> Result<Vec<ProtoValue>, DecodeProtoError> {
+    let wire_type = scalar.default_value().wire_type();
+    let mut parser = Parser { bytes, pos: 0 };
+    let mut values = Vec::new();
+    while parser.pos < bytes.len() {
+        values.push(parser.value(parser.pos, wire_type)?);
+    }
+    Ok(values)
+}
+
+/// Whether a field is singular, optional, or repeated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Label {
+    /// A singular field, which has a default value when missing.
+    Singular,
+    /// An `optional` field, which may be missing.
+    Optional,
+    /// A `repeated` field.
+    Repeated,
+}
+
+/// The type of a field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtoType {
+    /// A scalar type.
+    Scalar(Scalar),
+    /// A message, by its full name relative to the package, e.g. `Outer.Inner`.
+    Message(String),
+}
+
+/// The definition of a field of a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtoField {
+    /// The name of the field.
+    pub name: String,
+    /// The number of the field.
+    pub number: u32,
+    /// Whether the field is singular, optional, or repeated.
+    pub label: Label,
+    /// The type of the field.
+    pub ty: ProtoType,
+    /// The index of the `oneof` in the message which the field is part of, if any.
+    pub oneof: Option<usize>,
+    /// The doc comment of the field, or `""` if there is none.
+    pub doc: String,
+}
+
+/// The definition of a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtoMessage {
+    /// The name of the message, without the names of the messages it's nested in.
+    pub name: String,
+    /// The doc comment of the message, or `""` if there is none.
+    pub doc: String,
+    /// The fields of the message, in order.
+    pub fields: Vec<ProtoField>,
+    /// The names of the `oneof`s of the message.
+    pub oneofs: Vec<String>,
+    /// The messages nested in the message.
+    pub nested: Vec<ProtoMessage>,
+}
+
+impl ProtoMessage {
+    fn new(name: String, doc: String) -> Self {
+        ProtoMessage {
+            name,
+            doc,
+            fields: Vec::new(),
+            oneofs: Vec::new(),
+            nested: Vec::new(),
+        }
+    }
+}
+
+/// The definitions of messages, in a `.proto` file using the `proto3` syntax, as produced by `proto_file`.
+///
+/// Its `Display` impl writes the source of the file, and `encode_descriptor` encodes it as a `FileDescriptorProto`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtoFile {
+    /// The name of the file, e.g. `"service.proto"`, or `""` if it has none.
+    pub name: String,
+    /// The package of the file, or `""` if it has none.
+    pub package: String,
+    /// The top-level messages of the file.
+    pub messages: Vec<ProtoMessage>,
+}
+
+impl ProtoFile {
+    /// Adds the definition of the named message `M` to the file, unless it's already there, and returns its name.
+    ///
+    /// Names are suffixed with a number if a different message, e.g. another instantiation of a generic type,
+    /// already has them.
+    fn add_message<M>(&mut self, name: &str) -> String
+    where
+        M: GProtoMessage,
+    {
+        for i in 1.. {
+            let candidate = match i {
+                1 => name.to_string(),
+                i => format!("{}{}", name, i),
+            };
+            let mut message = ProtoMessage::new(candidate.clone(), String::new());
+            M::describe_message(self, &candidate, &mut message);
+            match self.messages.iter().find(|m| m.name == candidate) {
+                None => {
+                    self.messages.push(message);
+                    return candidate;
+                }
+                Some(existing) if *existing == message => return candidate,
+                Some(_) => {}
+            }
+        }
+        unreachable!("some name is free")
+    }
+
+    /// Encodes the file as a `google.protobuf.FileDescriptorProto`,
+    /// e.g. to include in a `FileDescriptorSet`, or to serve with gRPC server reflection.
+    ///
+    /// Optional fields are part of synthetic `oneof`s, as `protoc` generates for them.
+    pub fn encode_descriptor(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        if !self.name.is_empty() {
+            string(&self.name).encode(1, &mut out);
+        }
+        if !self.package.is_empty() {
+            string(&self.package).encode(2, &mut out);
+        }
+        for message in &self.messages {
+            ProtoValue::Len(self.message_descriptor(message)).encode(4, &mut out);
+        }
+        string("proto3").encode(12, &mut out);
+        out
+    }
+
+    /// Encodes a `DescriptorProto`.
+    fn message_descriptor(&self, message: &ProtoMessage) -> Vec<u8> {
+        let mut out = Vec::new();
+        string(&message.name).encode(1, &mut out);
+        let mut synthetic = Vec::new();
+        for field in &message.fields {
+            let mut descriptor = Vec::new();
+            string(&field.name).encode(1, &mut descriptor);
+            ProtoValue::Varint(u64::from(field.number)).encode(3, &mut descriptor);
+            let label = match field.label {
+                Label::Repeated => 3,
+                Label::Singular | Label::Optional => 1,
+            };
+            ProtoValue::Varint(label).encode(4, &mut descriptor);
+            match &field.ty {
+                ProtoType::Scalar(scalar) => {
+                    ProtoValue::Varint(scalar.descriptor_type()).encode(5, &mut descriptor);
+                }
+                ProtoType::Message(name) => {
+                    ProtoValue::Varint(11).encode(5, &mut descriptor);
+                    let name = match self.package.as_str() {
+                        "" => format!(".{}", name),
+                        package => format!(".{}.{}", package, name),
+                    };
+                    string(&name).encode(6, &mut descriptor);
+                }
+            }
+            let oneof = match field.oneof {
+                Some(index) => Some(index),
+                None if field.label == Label::Optional => {
+                    synthetic.push(format!("_{}", field.name));
+                    Some(message.oneofs.len() + synthetic.len() - 1)
+                }
+                None => None,
+            };
+            if let Some(index) = oneof {
+                ProtoValue::Varint(index as u64).encode(9, &mut descriptor);
+            }
+            if field.label == Label::Optional {
+                ProtoValue::Varint(1).encode(17, &mut descriptor);
+            }
+            ProtoValue::Len(descriptor).encode(2, &mut out);
+        }
+        for nested in &message.nested {
+            ProtoValue::Len(self.message_descriptor(nested)).encode(3, &mut out);
+        }
+        for oneof in message.oneofs.iter().chain(&synthetic) {
+            let mut descriptor = Vec::new();
+            string(oneof).encode(1, &mut descriptor);
+            ProtoValue::Len(descriptor).encode(8, &mut out);
+        }
+        out
+    }
+}
+
+fn string(s: &str) -> ProtoValue {
+    ProtoValue::Len(s.as_bytes().to_vec())
+}
+
+/// Writes a doc comment as `//` comments, indented by `indent`.
+fn write_doc(f: &mut fmt::Formatter<'_>, doc: &str, indent: &str) -> fmt::Result {
+    if doc.is_empty() {
+        return Ok(());
+    }
+    for line in doc.lines() {
+        match line {
+            "" => writeln!(f, "{}//", indent)?,
+            line => writeln!(f, "{}// {}", indent, line)?,
+        }
+    }
+    Ok(())
+}
+
+fn write_field(f: &mut fmt::Formatter<'_>, field: &ProtoField, indent: &str) -> fmt::Result {
+    write_doc(f, &field.doc, indent)?;
+    let label = match field.label {
+        Label::Singular => "",
+        Label::Optional => "optional ",
+        Label::Repeated => "repeated ",
+    };
+    let ty = match &field.ty {
+        ProtoType::Scalar(scalar) => scalar.name(),
+        ProtoType::Message(name) => name,
+    };
+    writeln!(
+        f,
+        "{}{}{} {} = {};",
+        indent, label, ty, field.name, field.number
+    )
+}
+
+fn write_message(f: &mut fmt::Formatter<'_>, message: &ProtoMessage, indent: &str) -> fmt::Result {
+    write_doc(f, &message.doc, indent)?;
+    if message.fields.is_empty() && message.nested.is_empty() {
+        return writeln!(f, "{}message {} {{}}", indent, message.name);
+    }
+    writeln!(f, "{}message {} {{", indent, message.name)?;
+    let inner = format!("{}  ", indent);
+    for field in message.fields.iter().filter(|field| field.oneof.is_none()) {
+        write_field(f, field, &inner)?;
+    }
+    for (i, oneof) in message.oneofs.iter().enumerate() {
+        writeln!(f, "{}oneof {} {{", inner, oneof)?;
+        for field in message.fields.iter().filter(|field| field.oneof == Some(i)) {
+            write_field(f, field, &format!("{}  ", inner))?;
+        }
+        writeln!(f, "{}}}", inner)?;
+    }
+    for nested in &message.nested {
+        writeln!(f)?;
+        write_message(f, nested, &inner)?;
+    }
+    writeln!(f, "{}}}", indent)
+}
+
+impl Display for ProtoFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "syntax = \"proto3\";")?;
+        if !self.package.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "package {};", self.package)?;
+        }
+        for message in &self.messages {
+            writeln!(f)?;
+            write_message(f, message, "")?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a name to `PascalCase`, for the names of nested messages, e.g. `field_0` to `Field0`.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect()
+}
+
+/// Converts a name to `snake_case`, for the names of fields holding variants, e.g. `NotFound` to `not_found`.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+/// The name of a field, where the fields of tuples and tuple structs are named like `field_0`.
+fn field_name(name: &str) -> String {
+    match name.chars().next() {
+        Some(c) if c.is_ascii_digit() => format!("field_{}", name),
+        _ => name.to_string(),
+    }
+}
+
+/// A leaf which can be converted to and from a protobuf scalar.
+///
+/// Implement this for your own leaf types to encode and decode them with `GProto`.
+pub trait ProtoLeaf: Sized {
+    /// The scalar type of fields holding the leaf.
+    const SCALAR: Scalar;
+
+    /// A description of the value this is decoded from, for errors, e.g. `"a u8"`.
+    const EXPECTED: &'static str;
+
+    /// Converts `self` to a value of the wire type of `SCALAR`.
+    fn to_proto(self) -> ProtoValue;
+
+    /// Converts `value` to `Self`, or returns `None` if it's invalid.
+    fn from_proto(value: ProtoValue) -> Option<Self>;
+}
+
+fn zigzag(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)) as u64
+}
+
+fn unzigzag(n: u64) -> i64 {
+    (n >> 1) as i64 ^ -((n & 1) as i64)
+}
+
+macro_rules! impl_proto_leaf_unsigned {
+    ( $( $ty:ident => $scalar:ident ),+ $(,)? ) => {
+        $(
+            impl ProtoLeaf for $ty {
+                const SCALAR: Scalar = Scalar::$scalar;
+                const EXPECTED: &'static str = concat!("a ", stringify!($ty));
+
+                fn to_proto(self) -> ProtoValue {
+                    ProtoValue::Varint(self as u64)
+                }
+
+                fn from_proto(value: ProtoValue) -> Option<Self> {
+                    match value {
+                        ProtoValue::Varint(n) => $ty::try_from(n).ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+impl_proto_leaf_unsigned!(
+    u8 => Uint32,
+    u16 => Uint32,
+    u32 => Uint32,
+    u64 => Uint64,
+    usize => Uint64,
+);
+
+macro_rules! impl_proto_leaf_signed {
+    ( $( $ty:ident => $scalar:ident ),+ $(,)? ) => {
+        $(
+            /// As a zigzag varint, so small negative numbers are short too.
+            impl ProtoLeaf for $ty {
+                const SCALAR: Scalar = Scalar::$scalar;
+                const EXPECTED: &'static str = concat!("a ", stringify!($ty));
+
+                fn to_proto(self) -> ProtoValue {
+                    ProtoValue::Varint(zigzag(self as i64))
+                }
+
+                fn from_proto(value: ProtoValue) -> Option<Self> {
+                    match value {
+                        ProtoValue::Varint(n) => $ty::try_from(unzigzag(n)).ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+impl_proto_leaf_signed!(
+    i8 => Sint32,
+    i16 => Sint32,
+    i32 => Sint32,
+    i64 => Sint64,
+    isize => Sint64,
+);
+
+macro_rules! impl_proto_leaf_wide {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            /// As 16 big-endian `bytes`, or none for zero, the default value.
+            impl ProtoLeaf for $ty {
+                const SCALAR: Scalar = Scalar::Bytes;
+                const EXPECTED: &'static str = concat!("a ", stringify!($ty));
+
+                fn to_proto(self) -> ProtoValue {
+                    match self {
+                        0 => ProtoValue::Len(Vec::new()),
+                        n => ProtoValue::Len(n.to_be_bytes().to_vec()),
+                    }
+                }
+
+                fn from_proto(value: ProtoValue) -> Option<Self> {
+                    match value {
+                        ProtoValue::Len(bytes) if bytes.is_empty() => Some(0),
+                        ProtoValue::Len(bytes) => Some($ty::from_be_bytes(bytes.try_into().ok()?)),
+                        _ => None,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+impl_proto_leaf_wide!(u128, i128);
+
+impl ProtoLeaf for f32 {
+    const SCALAR: Scalar = Scalar::Float;
+    const EXPECTED: &'static str = "an f32";
+
+    fn to_proto(self) -> ProtoValue {
+        ProtoValue::I32(self.to_bits())
+    }
+
+    fn from_proto(value: ProtoValue) -> Option<Self> {
+        match value {
+            ProtoValue::I32(n) => Some(f32::from_bits(n)),
+            _ => None,
+        }
+    }
+}
+
+impl ProtoLeaf for f64 {
+    const SCALAR: Scalar = Scalar::Double;
+    const EXPECTED: &'static str = "an f64";
+
+    fn to_proto(self) -> ProtoValue {
+        ProtoValue::I64(self.to_bits())
+    }
+
+    fn from_proto(value: ProtoValue) -> Option<Self> {
+        match value {
+            ProtoValue::I64(n) => Some(f64::from_bits(n)),
+            _ => None,
+        }
+    }
+}
+
+impl ProtoLeaf for bool {
+    const SCALAR: Scalar = Scalar::Bool;
+    const EXPECTED: &'static str = "a boolean";
+
+    fn to_proto(self) -> ProtoValue {
+        ProtoValue::Varint(u64::from(self))
+    }
+
+    fn from_proto(value: ProtoValue) -> Option<Self> {
+        match value {
+            ProtoValue::Varint(n) => Some(n != 0),
+            _ => None,
+        }
+    }
+}
+
+/// As a `string` of one character, or an empty string for `'\0'`, the default value.
+impl ProtoLeaf for char {
+    const SCALAR: Scalar = Scalar::String;
+    const EXPECTED: &'static str = "a string of at most one character";
+
+    fn to_proto(self) -> ProtoValue {
+        match self {
+            '\0' => string(""),
+            c => string(c.encode_utf8(&mut [0; 4])),
+        }
+    }
+
+    fn from_proto(value: ProtoValue) -> Option<Self> {
+        let s = String::from_proto(value)?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => Some('\0'),
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    }
+}
+
+impl ProtoLeaf for String {
+    const SCALAR: Scalar = Scalar::String;
+    const EXPECTED: &'static str = "a string";
+
+    fn to_proto(self) -> ProtoValue {
+        ProtoValue::Len(self.into_bytes())
+    }
+
+    fn from_proto(value: ProtoValue) -> Option<Self> {
+        match value {
+            ProtoValue::Len(bytes) => String::from_utf8(bytes).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl<T> ProtoLeaf for Opaque<T>
+where
+    T: ProtoLeaf,
+{
+    const SCALAR: Scalar = T::SCALAR;
+    const EXPECTED: &'static str = T::EXPECTED;
+
+    fn to_proto(self) -> ProtoValue {
+        self.0.to_proto()
+    }
+
+    fn from_proto(value: ProtoValue) -> Option<Self> {
+        T::from_proto(value).map(Opaque)
+    }
+}
+
+/// Converts a representation to and from the values of a protobuf field, and describes that field, using its metadata.
+///
+/// Structs are messages whose fields are numbered from 1 in order, and tuples and tuple structs
+/// are messages with fields named `field_0`, `field_1`, and so on. Enums are messages with a `oneof`
+/// holding a field for each variant, numbered from 1, whose type is a nested message of the variant's fields.
+/// `Option` is an `optional` field, lists and arrays are `repeated` fields, packed if they hold numbers,
+/// and an `Option` or list inside another is wrapped in a nested message with its only field named `value`.
+/// Leaves are scalars, converted with `ProtoLeaf`: unsigned integers are `uint32` or `uint64`, signed integers
+/// are `sint32` or `sint64`, `u128` and `i128` are 16 big-endian `bytes`, and `char` is a `string`.
+///
+/// Messages of structs and enums are named after their type, as top-level messages, and those of tuples
+/// are nested in the message holding them, named after their field. Like any `proto3` message,
+/// fields equal to their default value aren't encoded, and missing fields are decoded as their default value,
+/// except that a missing variant is an error, as an enum must hold one.
+///
+/// `proto_file` uses this to describe the messages of a `Generic` type, and `encode_proto` and `decode_proto`
+/// to encode and decode its values, e.g. to exchange them with services using generated protobuf code.
+/// Recursive types aren't supported, since their messages are described inline.
+/// Only the `protobuf` feature is required, which has no dependencies.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{decode_proto, encode_proto, proto_file};
+/// use generics::Generic;
+///
+/// /// A unit of work.
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Job {
+///     id: u32,
+///     tags: Vec<String>,
+///     state: State,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum State {
+///     Queued,
+///     Failed(i8),
+/// }
+///
+/// let file = proto_file::<Job>();
+/// assert_eq!(
+///     file.to_string(),
+///     "\
+/// syntax = \"proto3\";
+///
+/// // A unit of work.
+/// message Job {
+///   uint32 id = 1;
+///   repeated string tags = 2;
+///   State state = 3;
+/// }
+///
+/// message State {
+///   oneof variant {
+///     State.Queued queued = 1;
+///     State.Failed failed = 2;
+///   }
+///
+///   message Queued {}
+///
+///   message Failed {
+///     sint32 field_0 = 1;
+///   }
+/// }
+/// ",
+/// );
+///
+/// let job = Job { id: 7, tags: vec!["a".to_string()], state: State::Failed(-1) };
+/// let bytes = encode_proto(job);
+/// assert_eq!(
+///     bytes,
+///     [
+///         0x08, 0x07, // id
+///         0x12, 0x01, b'a', // tags
+///         0x1a, 0x04, 0x12, 0x02, 0x08, 0x01, // state, holding `Failed`, holding `-1`
+///     ]
+/// );
+/// assert_eq!(
+///     decode_proto::<Job>(&bytes),
+///     Ok(Job { id: 7, tags: vec!["a".to_string()], state: State::Failed(-1) })
+/// );
+/// ```
+pub trait GProto: Sized {
+    /// Whether fields holding `Self` are singular, optional, or repeated.
+    fn label() -> Label {
+        Label::Singular
+    }
+
+    /// The scalar type of `Self`, if it's a leaf, so default values are left out and repeated fields are packed.
+    fn scalar() -> Option<Scalar> {
+        None
+    }
+
+    /// Describes the type of a field named `name`, of the message with the full name `scope`.
+    ///
+    /// Messages of named types are added to `file`, and others to `nested`.
+    fn describe(
+        file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType;
+
+    /// Appends the values of a field holding `self`.
+    fn gto_proto(self, values: &mut Vec<ProtoValue>);
+
+    /// Converts the values of a field, found at `path`, to `Self`.
+    fn gfrom_proto(values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError>;
+}
+
+/// Converts a representation to and from the fields of a message, and describes that message.
+pub trait GProtoMessage: Sized {
+    /// The name of the message, for named types, or `None` for tuples.
+    fn message_name() -> Option<&'static str>;
+
+    /// Adds the fields, `oneof`s, and nested messages of `Self` to `message`, whose full name is `scope`.
+    fn describe_message(file: &mut ProtoFile, scope: &str, message: &mut ProtoMessage);
+
+    /// Appends the fields of `self`.
+    fn gto_message(self, out: &mut Vec<u8>);
+
+    /// Converts the fields of a message, found at `path`, to `Self`.
+    fn gfrom_message(
+        fields: &mut Vec<(u32, ProtoValue)>,
+        path: &mut String,
+    ) -> Result<Self, DecodeProtoError>;
+}
+
+/// Converts the elements of a `Prod` chain to and from fields.
+pub trait GProtoElements: Sized {
+    /// Describes each element as a field, numbered from `number`.
+    fn describe_elements(
+        file: &mut ProtoFile,
+        scope: &str,
+        message: &mut ProtoMessage,
+        number: u32,
+    );
+
+    /// Appends each element as a field, numbered from `number`.
+    fn gto_elements(self, number: u32, out: &mut Vec<u8>);
+
+    /// Converts each element from the field numbered from `number`.
+    fn gfrom_elements(
+        fields: &mut Vec<(u32, ProtoValue)>,
+        number: u32,
+        path: &mut String,
+    ) -> Result<Self, DecodeProtoError>;
+}
+
+/// Converts the fields of a constructor, i.e. a `Prod` chain of `Meta`s, to and from fields.
+pub trait GProtoFields: Sized {
+    /// The number of fields.
+    const LEN: usize;
+
+    /// Describes each field, numbered from `number`.
+    fn describe_fields(file: &mut ProtoFile, scope: &str, message: &mut ProtoMessage, number: u32);
+
+    /// Appends each field, numbered from `number`.
+    fn gto_fields(self, number: u32, out: &mut Vec<u8>);
+
+    /// Converts each field from the field numbered from `number`.
+    fn gfrom_fields(
+        fields: &mut Vec<(u32, ProtoValue)>,
+        number: u32,
+        path: &mut String,
+    ) -> Result<Self, DecodeProtoError>;
+
+    /// Describes the element type of an optional field holding the first field, for `Option`.
+    fn describe_first(
+        file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType;
+
+    /// Appends the values of an optional field holding the first field, if there is one, for `Option`.
+    fn gto_first(self, values: &mut Vec<ProtoValue>);
+
+    /// Converts the first field from the values of an optional field, and the others from nothing, for `Option`.
+    fn gfrom_first(values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError>;
+}
+
+/// Converts whichever variant of a `Sum` chain is held to and from a field of a `oneof`.
+pub trait GProtoVariants: Sized {
+    /// The number of variants.
+    const LEN: usize;
+
+    /// Whether the chain is the representation of an `Option`.
+    fn is_option() -> bool;
+
+    /// The name of the type of the chain.
+    fn datatype_name() -> &'static str;
+
+    /// Describes each variant as a field of the first `oneof` of `message`, numbered from `number`.
+    fn describe_variants(
+        file: &mut ProtoFile,
+        scope: &str,
+        message: &mut ProtoMessage,
+        number: u32,
+    );
+
+    /// Appends the held variant as a field, where the variants are numbered from `number`.
+    fn gto_variant(self, number: u32, out: &mut Vec<u8>);
+
+    /// Converts the variant numbered `target` from the values of its field,
+    /// where the variants are numbered from `number`.
+    fn gfrom_variant(
+        target: u32,
+        number: u32,
+        values: Vec<ProtoValue>,
+        path: &mut String,
+    ) -> Result<Self, DecodeProtoError>;
+
+    /// Describes the element type of an `Option`.
+    fn describe_some(
+        file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType;
+
+    /// Appends the values of an `Option`.
+    fn gto_some(self, values: &mut Vec<ProtoValue>);
+
+    /// Converts the values of an `Option`, which are empty for `None`.
+    fn gfrom_some(values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError>;
+}
+
+/// An error decoding a representation from protobuf.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeProtoError {
+    /// The bytes weren't a valid message, at the given offset.
+    Syntax(usize),
+    /// There was no variant for the enum with the given path.
+    Missing(String),
+    /// The value had the wrong wire type or length, or a leaf was invalid.
+    Invalid {
+        /// The path of the value.
+        path: String,
+        /// A description of the expected value, e.g. `"a message"`.
+        expected: String,
+    },
+    /// The value was invalid for its type, e.g. a `NonZeroU8` of zero.
+    Repr(ReprError),
+}
+
+impl Display for DecodeProtoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeProtoError::Syntax(pos) => write!(f, "invalid protobuf at byte {}", pos),
+            DecodeProtoError::Missing(path) => write!(f, "missing variant for `{}`", path),
+            DecodeProtoError::Invalid { path, expected } => {
+                write!(f, "expected {} for `{}`", expected, path)
+            }
+            DecodeProtoError::Repr(error) => write!(f, "invalid value: {}", error),
+        }
+    }
+}
+
+impl Error for DecodeProtoError {}
+
+fn invalid(path: &str, expected: impl Display) -> DecodeProtoError {
+    DecodeProtoError::Invalid {
+        path: path.to_string(),
+        expected: expected.to_string(),
+    }
+}
+
+/// Removes the values of the field numbered `number` from `fields`.
+fn take(fields: &mut Vec<(u32, ProtoValue)>, number: u32) -> Vec<ProtoValue> {
+    let (taken, rest): (Vec<_>, Vec<_>) = mem::take(fields)
+        .into_iter()
+        .partition(|&(n, _)| n == number);
+    *fields = rest;
+    taken.into_iter().map(|(_, value)| value).collect()
+}
+
+/// Decodes the fields of a message from the values of a field, which are merged by concatenating them.
+fn message_fields(
+    values: Vec<ProtoValue>,
+    path: &str,
+) -> Result<Vec<(u32, ProtoValue)>, DecodeProtoError> {
+    let mut bytes = Vec::new();
+    for value in values {
+        match value {
+            ProtoValue::Len(value) => bytes.extend(value),
+            _ => return Err(invalid(path, "a message")),
+        }
+    }
+    ProtoValue::decode_message(&bytes).map_err(|_| invalid(path, "a message"))
+}
+
+/// Describes the message `M` as the type of a field named `name`.
+fn message_type<M>(
+    file: &mut ProtoFile,
+    scope: &str,
+    nested: &mut Vec<ProtoMessage>,
+    name: &str,
+) -> ProtoType
+where
+    M: GProtoMessage,
+{
+    if let Some(name) = M::message_name() {
+        return ProtoType::Message(file.add_message::<M>(name));
+    }
+    let name = pascal_case(name);
+    let full_name = format!("{}.{}", scope, name);
+    let mut message = ProtoMessage::new(name, String::new());
+    M::describe_message(file, &full_name, &mut message);
+    nested.push(message);
+    ProtoType::Message(full_name)
+}
+
+fn message_values<M>(x: M, values: &mut Vec<ProtoValue>)
+where
+    M: GProtoMessage,
+{
+    let mut out = Vec::new();
+    x.gto_message(&mut out);
+    values.push(ProtoValue::Len(out));
+}
+
+fn message_from_values<M>(values: Vec<ProtoValue>, path: &mut String) -> Result<M, DecodeProtoError>
+where
+    M: GProtoMessage,
+{
+    let mut fields = message_fields(values, path)?;
+    M::gfrom_message(&mut fields, path)
+}
+
+/// Whether values of `A` must be wrapped in a message to be the element of an optional or repeated field,
+/// since those can't be nested.
+fn is_wrapped<A>() -> bool
+where
+    A: GProto,
+{
+    A::label() != Label::Singular
+}
+
+/// Describes the type of the elements of an optional or repeated field holding `A`.
+fn describe_element<A>(
+    file: &mut ProtoFile,
+    scope: &str,
+    nested: &mut Vec<ProtoMessage>,
+    name: &str,
+) -> ProtoType
+where
+    A: GProto,
+{
+    if !is_wrapped::<A>() {
+        return A::describe(file, scope, nested, name);
+    }
+    let name = pascal_case(name);
+    let full_name = format!("{}.{}", scope, name);
+    let mut message = ProtoMessage::new(name, String::new());
+    let ty = A::describe(file, &full_name, &mut message.nested, "value");
+    message.fields.push(ProtoField {
+        name: "value".to_string(),
+        number: 1,
+        label: A::label(),
+        ty,
+        oneof: None,
+        doc: String::new(),
+    });
+    nested.push(message);
+    ProtoType::Message(full_name)
+}
+
+fn encode_element<A>(x: A, values: &mut Vec<ProtoValue>)
+where
+    A: GProto,
+{
+    if !is_wrapped::<A>() {
+        return x.gto_proto(values);
+    }
+    let mut out = Vec::new();
+    encode_field(x, 1, &mut out);
+    values.push(ProtoValue::Len(out));
+}
+
+fn decode_element<A>(values: Vec<ProtoValue>, path: &mut String) -> Result<A, DecodeProtoError>
+where
+    A: GProto,
+{
+    if !is_wrapped::<A>() {
+        return A::gfrom_proto(values, path);
+    }
+    let mut fields = message_fields(values, path)?;
+    A::gfrom_proto(take(&mut fields, 1), path)
+}
+
+fn describe_field<A>(
+    file: &mut ProtoFile,
+    scope: &str,
+    message: &mut ProtoMessage,
+    name: String,
+    number: u32,
+    doc: &str,
+) where
+    A: GProto,
+{
+    let ty = A::describe(file, scope, &mut message.nested, &name);
+    message.fields.push(ProtoField {
+        name,
+        number,
+        label: A::label(),
+        ty,
+        oneof: None,
+        doc: doc.to_string(),
+    });
+}
+
+fn encode_field<A>(x: A, number: u32, out: &mut Vec<u8>)
+where
+    A: GProto,
+{
+    let mut values = Vec::new();
+    x.gto_proto(&mut values);
+    // Singular scalars have no presence, so default values are left out.
+    let implicit = A::label() == Label::Singular && A::scalar().is_some();
+    for value in values {
+        if !(implicit && value.is_default()) {
+            value.encode(number, out);
+        }
+    }
+}
+
+fn decode_field<A>(
+    fields: &mut Vec<(u32, ProtoValue)>,
+    number: u32,
+    segment: &dyn Display,
+    path: &mut String,
+) -> Result<A, DecodeProtoError>
+where
+    A: GProto,
+{
+    let values = take(fields, number);
+    let len = push_segment(path, segment);
+    let x = A::gfrom_proto(values, path);
+    path.truncate(len);
+    x
+}
+
+impl GProto for Unit {
+    fn describe(
+        file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType {
+        message_type::<Self>(file, scope, nested, name)
+    }
+
+    fn gto_proto(self, values: &mut Vec<ProtoValue>) {
+        message_values(self, values)
+    }
+
+    fn gfrom_proto(values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError> {
+        message_from_values(values, path)
+    }
+}
+
+impl GProtoMessage for Unit {
+    fn message_name() -> Option<&'static str> {
+        None
+    }
+
+    fn describe_message(_file: &mut ProtoFile, _scope: &str, _message: &mut ProtoMessage) {}
+
+    fn gto_message(self, _out: &mut Vec<u8>) {}
+
+    fn gfrom_message(
+        _fields: &mut Vec<(u32, ProtoValue)>,
+        _path: &mut String,
+    ) -> Result<Self, DecodeProtoError> {
+        Ok(Unit)
+    }
+}
+
+impl<A, B> GProto for Prod<A, B>
+where
+    Self: GProtoElements,
+{
+    fn describe(
+        file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType {
+        message_type::<Self>(file, scope, nested, name)
+    }
+
+    fn gto_proto(self, values: &mut Vec<ProtoValue>) {
+        message_values(self, values)
+    }
+
+    fn gfrom_proto(values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError> {
+        message_from_values(values, path)
+    }
+}
+
+impl<A, B> GProtoMessage for Prod<A, B>
+where
+    Self: GProtoElements,
+{
+    fn message_name() -> Option<&'static str> {
+        None
+    }
+
+    fn describe_message(file: &mut ProtoFile, scope: &str, message: &mut ProtoMessage) {
+        Self::describe_elements(file, scope, message, 1)
+    }
+
+    fn gto_message(self, out: &mut Vec<u8>) {
+        self.gto_elements(1, out)
+    }
+
+    fn gfrom_message(
+        fields: &mut Vec<(u32, ProtoValue)>,
+        path: &mut String,
+    ) -> Result<Self, DecodeProtoError> {
+        Self::gfrom_elements(fields, 1, path)
+    }
+}
+
+impl GProtoElements for Unit {
+    fn describe_elements(
+        _file: &mut ProtoFile,
+        _scope: &str,
+        _message: &mut ProtoMessage,
+        _number: u32,
+    ) {
+    }
+
+    fn gto_elements(self, _number: u32, _out: &mut Vec<u8>) {}
+
+    fn gfrom_elements(
+        _fields: &mut Vec<(u32, ProtoValue)>,
+        _number: u32,
+        _path: &mut String,
+    ) -> Result<Self, DecodeProtoError> {
+        Ok(Unit)
+    }
+}
+
+impl<A, B> GProtoElements for Prod<A, B>
+where
+    A: GProto,
+    B: GProtoElements,
+{
+    fn describe_elements(
+        file: &mut ProtoFile,
+        scope: &str,
+        message: &mut ProtoMessage,
+        number: u32,
+    ) {
+        let name = field_name(&(number - 1).to_string());
+        describe_field::<A>(file, scope, message, name, number, "");
+        B::describe_elements(file, scope, message, number + 1);
+    }
+
+    fn gto_elements(self, number: u32, out: &mut Vec<u8>) {
+        let Prod(a, b) = self;
+        encode_field(a, number, out);
+        b.gto_elements(number + 1, out);
+    }
+
+    fn gfrom_elements(
+        fields: &mut Vec<(u32, ProtoValue)>,
+        number: u32,
+        path: &mut String,
+    ) -> Result<Self, DecodeProtoError> {
+        let a = decode_field(fields, number, &(number - 1), path)?;
+        Ok(Prod(a, B::gfrom_elements(fields, number + 1, path)?))
+    }
+}
+
+impl GProtoFields for Unit {
+    const LEN: usize = 0;
+
+    fn describe_fields(
+        _file: &mut ProtoFile,
+        _scope: &str,
+        _message: &mut ProtoMessage,
+        _number: u32,
+    ) {
+    }
+
+    fn gto_fields(self, _number: u32, _out: &mut Vec<u8>) {}
+
+    fn gfrom_fields(
+        _fields: &mut Vec<(u32, ProtoValue)>,
+        _number: u32,
+        _path: &mut String,
+    ) -> Result<Self, DecodeProtoError> {
+        Ok(Unit)
+    }
+
+    fn describe_first(
+        _file: &mut ProtoFile,
+        _scope: &str,
+        _nested: &mut Vec<ProtoMessage>,
+        _name: &str,
+    ) -> ProtoType {
+        unreachable!("only called for constructors with one field")
+    }
+
+    fn gto_first(self, _values: &mut Vec<ProtoValue>) {}
+
+    fn gfrom_first(_values: Vec<ProtoValue>, _path: &mut String) -> Result<Self, DecodeProtoError> {
+        Ok(Unit)
+    }
+}
+
+impl<A, M, B> GProtoFields for Prod<Meta<A, M>, B>
+where
+    A: GProto,
+    M: Singleton<T = &'static str> + Documented,
+    B: GProtoFields,
+{
+    const LEN: usize = 1 + B::LEN;
+
+    fn describe_fields(file: &mut ProtoFile, scope: &str, message: &mut ProtoMessage, number: u32) {
+        describe_field::<A>(file, scope, message, field_name(M::get()), number, M::doc());
+        B::describe_fields(file, scope, message, number + 1);
+    }
+
+    fn gto_fields(self, number: u32, out: &mut Vec<u8>) {
+        let Prod(Meta(a, _), b) = self;
+        encode_field(a, number, out);
+        b.gto_fields(number + 1, out);
+    }
+
+    fn gfrom_fields(
+        fields: &mut Vec<(u32, ProtoValue)>,
+        number: u32,
+        path: &mut String,
+    ) -> Result<Self, DecodeProtoError> {
+        let a = decode_field(fields, number, &M::get(), path)?;
+        Ok(Prod(
+            Meta(a, PhantomData),
+            B::gfrom_fields(fields, number + 1, path)?,
+        ))
+    }
+
+    fn describe_first(
+        file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType {
+        describe_element::<A>(file, scope, nested, name)
+    }
+
+    fn gto_first(self, values: &mut Vec<ProtoValue>) {
+        let Prod(Meta(a, _), _) = self;
+        encode_element(a, values);
+    }
+
+    fn gfrom_first(values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError> {
+        let len = push_segment(path, &M::get());
+        let a = decode_element(values, path);
+        path.truncate(len);
+        Ok(Prod(
+            Meta(a?, PhantomData),
+            B::gfrom_fields(&mut Vec::new(), 2, path)?,
+        ))
+    }
+}
+
+impl<L, R> GProto for Sum<L, R>
+where
+    Self: GProtoVariants,
+{
+    fn label() -> Label {
+        if Self::is_option() {
+            Label::Optional
+        } else {
+            Label::Singular
+        }
+    }
+
+    fn describe(
+        file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType {
+        if Self::is_option() {
+            return Self::describe_some(file, scope, nested, name);
+        }
+        message_type::<Self>(file, scope, nested, name)
+    }
+
+    fn gto_proto(self, values: &mut Vec<ProtoValue>) {
+        if Self::is_option() {
+            return self.gto_some(values);
+        }
+        message_values(self, values)
+    }
+
+    fn gfrom_proto(values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError> {
+        if Self::is_option() {
+            return Self::gfrom_some(values, path);
+        }
+        message_from_values(values, path)
+    }
+}
+
+impl<L, R> GProtoMessage for Sum<L, R>
+where
+    Self: GProtoVariants,
+{
+    fn message_name() -> Option<&'static str> {
+        Some(Self::datatype_name())
+    }
+
+    fn describe_message(file: &mut ProtoFile, scope: &str, message: &mut ProtoMessage) {
+        message.oneofs.push("variant".to_string());
+        Self::describe_variants(file, scope, message, 1);
+    }
+
+    fn gto_message(self, out: &mut Vec<u8>) {
+        self.gto_variant(1, out)
+    }
+
+    fn gfrom_message(
+        fields: &mut Vec<(u32, ProtoValue)>,
+        path: &mut String,
+    ) -> Result<Self, DecodeProtoError> {
+        // As with any `oneof`, the last variant wins.
+        let target = fields
+            .iter()
+            .rev()
+            .map(|&(number, _)| number)
+            .find(|&number| number as usize <= Self::LEN)
+            .ok_or_else(|| DecodeProtoError::Missing(path.clone()))?;
+        let values = take(fields, target);
+        Self::gfrom_variant(target, 1, values, path)
+    }
+}
+
+impl<I, C, R> GProtoVariants for Sum<Con<I, C>, R>
+where
+    I: GProtoFields,
+    C: Singleton<T = &'static str> + Datatype + Documented + 'static,
+    R: GProtoVariants,
+{
+    const LEN: usize = 1 + R::LEN;
+
+    fn is_option() -> bool {
+        TypeId::of::<C>() == TypeId::of::<Option_None_Name>()
+    }
+
+    fn datatype_name() -> &'static str {
+        C::datatype_name()
+    }
+
+    fn describe_variants(
+        file: &mut ProtoFile,
+        scope: &str,
+        message: &mut ProtoMessage,
+        number: u32,
+    ) {
+        let full_name = format!("{}.{}", scope, C::get());
+        let mut variant = ProtoMessage::new(C::get().to_string(), C::doc().to_string());
+        I::describe_fields(file, &full_name, &mut variant, 1);
+        message.nested.push(variant);
+        message.fields.push(ProtoField {
+            name: snake_case(C::get()),
+            number,
+            label: Label::Singular,
+            ty: ProtoType::Message(full_name),
+            oneof: Some(0),
+            doc: String::new(),
+        });
+        R::describe_variants(file, scope, message, number + 1);
+    }
+
+    fn gto_variant(self, number: u32, out: &mut Vec<u8>) {
+        match self {
+            Sum::Left(Con(inner, _)) => {
+                let mut fields = Vec::new();
+                inner.gto_fields(1, &mut fields);
+                ProtoValue::Len(fields).encode(number, out);
+            }
+            Sum::Right(r) => r.gto_variant(number + 1, out),
+        }
+    }
+
+    fn gfrom_variant(
+        target: u32,
+        number: u32,
+        values: Vec<ProtoValue>,
+        path: &mut String,
+    ) -> Result<Self, DecodeProtoError> {
+        if target != number {
+            return R::gfrom_variant(target, number + 1, values, path).map(Sum::Right);
+        }
+        let mut fields = message_fields(values, path)?;
+        let inner = I::gfrom_fields(&mut fields, 1, path)?;
+        Ok(Sum::Left(Con(inner, PhantomData)))
+    }
+
+    fn describe_some(
+        file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType {
+        if I::LEN == 1 {
+            return I::describe_first(file, scope, nested, name);
+        }
+        R::describe_some(file, scope, nested, name)
+    }
+
+    fn gto_some(self, values: &mut Vec<ProtoValue>) {
+        match self {
+            Sum::Left(Con(inner, _)) => inner.gto_first(values),
+            Sum::Right(r) => r.gto_some(values),
+        }
+    }
+
+    fn gfrom_some(values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError> {
+        // `None` has no fields, and `Some` has one.
+        if values.is_empty() == (I::LEN == 0) {
+            let inner = I::gfrom_first(values, path)?;
+            return Ok(Sum::Left(Con(inner, PhantomData)));
+        }
+        R::gfrom_some(values, path).map(Sum::Right)
+    }
+}
+
+impl GProtoVariants for Void {
+    const LEN: usize = 0;
+
+    fn is_option() -> bool {
+        false
+    }
+
+    fn datatype_name() -> &'static str {
+        unreachable!("only called for sums with a variant")
+    }
+
+    fn describe_variants(
+        _file: &mut ProtoFile,
+        _scope: &str,
+        _message: &mut ProtoMessage,
+        _number: u32,
+    ) {
+    }
+
+    fn gto_variant(self, _number: u32, _out: &mut Vec<u8>) {
+        match self {}
+    }
+
+    fn gfrom_variant(
+        _target: u32,
+        _number: u32,
+        _values: Vec<ProtoValue>,
+        path: &mut String,
+    ) -> Result<Self, DecodeProtoError> {
+        Err(DecodeProtoError::Missing(path.clone()))
+    }
+
+    fn describe_some(
+        _file: &mut ProtoFile,
+        _scope: &str,
+        _nested: &mut Vec<ProtoMessage>,
+        _name: &str,
+    ) -> ProtoType {
+        unreachable!("`Option` has a variant with one field")
+    }
+
+    fn gto_some(self, _values: &mut Vec<ProtoValue>) {
+        match self {}
+    }
+
+    fn gfrom_some(_values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError> {
+        Err(DecodeProtoError::Missing(path.clone()))
+    }
+}
+
+/// An empty enum is a message with no fields, which can't be decoded.
+impl GProto for Void {
+    fn describe(
+        _file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType {
+        let name = pascal_case(name);
+        let full_name = format!("{}.{}", scope, name);
+        nested.push(ProtoMessage::new(name, String::new()));
+        ProtoType::Message(full_name)
+    }
+
+    fn gto_proto(self, _values: &mut Vec<ProtoValue>) {
+        match self {}
+    }
+
+    fn gfrom_proto(_values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError> {
+        Err(DecodeProtoError::Missing(path.clone()))
+    }
+}
+
+impl<I, M> GProto for Meta<I, M>
+where
+    I: GProto,
+    M: Singleton,
+{
+    fn label() -> Label {
+        I::label()
+    }
+
+    fn scalar() -> Option<Scalar> {
+        I::scalar()
+    }
+
+    fn describe(
+        file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType {
+        I::describe(file, scope, nested, name)
+    }
+
+    fn gto_proto(self, values: &mut Vec<ProtoValue>) {
+        let Meta(inner, _) = self;
+        inner.gto_proto(values)
+    }
+
+    fn gfrom_proto(values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError> {
+        I::gfrom_proto(values, path).map(|inner| Meta(inner, PhantomData))
+    }
+}
+
+impl<I, C> GProto for Con<I, C>
+where
+    I: GProtoFields,
+    C: Singleton + Datatype + Documented,
+{
+    fn describe(
+        file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType {
+        message_type::<Self>(file, scope, nested, name)
+    }
+
+    fn gto_proto(self, values: &mut Vec<ProtoValue>) {
+        message_values(self, values)
+    }
+
+    fn gfrom_proto(values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError> {
+        message_from_values(values, path)
+    }
+}
+
+impl<I, C> GProtoMessage for Con<I, C>
+where
+    I: GProtoFields,
+    C: Singleton + Datatype + Documented,
+{
+    fn message_name() -> Option<&'static str> {
+        Some(C::datatype_name())
+    }
+
+    fn describe_message(file: &mut ProtoFile, scope: &str, message: &mut ProtoMessage) {
+        message.doc = C::doc().to_string();
+        I::describe_fields(file, scope, message, 1);
+    }
+
+    fn gto_message(self, out: &mut Vec<u8>) {
+        let Con(inner, _) = self;
+        inner.gto_fields(1, out)
+    }
+
+    fn gfrom_message(
+        fields: &mut Vec<(u32, ProtoValue)>,
+        path: &mut String,
+    ) -> Result<Self, DecodeProtoError> {
+        I::gfrom_fields(fields, 1, path).map(|inner| Con(inner, PhantomData))
+    }
+}
+
+impl<A> GProto for List<A>
+where
+    A: GProto,
+{
+    fn label() -> Label {
+        Label::Repeated
+    }
+
+    fn describe(
+        file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType {
+        describe_element::<A>(file, scope, nested, name)
+    }
+
+    fn gto_proto(self, values: &mut Vec<ProtoValue>) {
+        let List(items) = self;
+        match A::scalar() {
+            Some(scalar) if scalar.is_packable() => {
+                let mut elements = Vec::new();
+                for item in items {
+                    item.gto_proto(&mut elements);
+                }
+                let mut packed = Vec::new();
+                for element in elements {
+                    element.encode_payload(&mut packed);
+                }
+                if !packed.is_empty() {
+                    values.push(ProtoValue::Len(packed));
+                }
+            }
+            _ => {
+                for item in items {
+                    encode_element(item, values);
+                }
+            }
+        }
+    }
+
+    fn gfrom_proto(values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError> {
+        // Decoders must accept both packed and unpacked elements.
+        let mut elements = Vec::new();
+        for value in values {
+            match (A::scalar(), value) {
+                (Some(scalar), ProtoValue::Len(bytes)) if scalar.is_packable() => {
+                    let unpacked = unpack(&bytes, scalar).map_err(|_| {
+                        invalid(path, format_args!("packed {} values", scalar.name()))
+                    })?;
+                    elements.extend(unpacked);
+                }
+                (_, value) => elements.push(value),
+            }
+        }
+        let items = elements
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let len = push_segment(path, &i);
+                let item = decode_element(vec![value], path);
+                path.truncate(len);
+                item
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(List(items))
+    }
+}
+
+impl<A, const N: usize> GProto for [A; N]
+where
+    A: GProto,
+{
+    fn label() -> Label {
+        Label::Repeated
+    }
+
+    fn describe(
+        file: &mut ProtoFile,
+        scope: &str,
+        nested: &mut Vec<ProtoMessage>,
+        name: &str,
+    ) -> ProtoType {
+        List::<A>::describe(file, scope, nested, name)
+    }
+
+    fn gto_proto(self, values: &mut Vec<ProtoValue>) {
+        List(Vec::from(self)).gto_proto(values)
+    }
+
+    fn gfrom_proto(values: Vec<ProtoValue>, path: &mut String) -> Result<Self, DecodeProtoError> {
+        let List(items) = List::<A>::gfrom_proto(values, path)?;
+        items
+            .try_into()
+            .map_err(|_| invalid(path, format_args!("{} elements", N)))
+    }
+}
+
+impl<T> GProto for T
+where
+    T: ProtoLeaf,
+{
+    fn scalar() -> Option<Scalar> {
+        Some(T::SCALAR)
+    }
+
+    fn describe(
+        _file: &mut ProtoFile,
+        _scope: &str,
+        _nested: &mut Vec<ProtoMessage>,
+        _name: &str,
+    ) -> ProtoType {
+        ProtoType::Scalar(T::SCALAR)
+    }
+
+    fn gto_proto(self, values: &mut Vec<ProtoValue>) {
+        values.push(self.to_proto());
+    }
+
+    fn gfrom_proto(
+        mut values: Vec<ProtoValue>,
+        path: &mut String,
+    ) -> Result<Self, DecodeProtoError> {
+        // As with any singular field, the last value wins.
+        let value = values.pop().unwrap_or_else(|| T::SCALAR.default_value());
+        T::from_proto(value).ok_or_else(|| invalid(path, T::EXPECTED))
+    }
+}
+
+/// Describes the messages of `T` as a `.proto` file, with the message of `T` first, followed by those it uses.
+///
+/// The file has no name or package, which may be set before writing it. A tuple is a message named `Message`.
+///
+/// ```rust
+/// use generics::ops::proto_file;
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct Point {
+///     /// Pixels from the left.
+///     x: i32,
+///     y: i32,
+///     label: Option<String>,
+/// }
+///
+/// let mut file = proto_file::<Point>();
+/// file.package = "geometry".to_string();
+/// assert_eq!(
+///     file.to_string(),
+///     "\
+/// syntax = \"proto3\";
+///
+/// package geometry;
+///
+/// message Point {
+///   // Pixels from the left.
+///   sint32 x = 1;
+///   sint32 y = 2;
+///   optional string label = 3;
+/// }
+/// ",
+/// );
+/// ```
+pub fn proto_file<T>() -> ProtoFile
+where
+    T: Generic,
+    T::Repr: GProtoMessage,
+{
+    let mut file = ProtoFile {
+        name: String::new(),
+        package: String::new(),
+        messages: Vec::new(),
+    };
+    let name = T::Repr::message_name().unwrap_or("Message");
+    let name = file.add_message::<T::Repr>(name);
+    let i = file
+        .messages
+        .iter()
+        .position(|message| message.name == name)
+        .expect("added the message");
+    let message = file.messages.remove(i);
+    file.messages.insert(0, message);
+    file
+}
+
+/// Encodes `x` as a protobuf message, as described by `proto_file`.
+pub fn encode_proto<T>(x: T) -> Vec<u8>
+where
+    T: Generic,
+    T::Repr: GProtoMessage,
+{
+    let mut out = Vec::new();
+    x.into_repr().gto_message(&mut out);
+    out
+}
+
+/// Decodes a `T` from a protobuf message, which must span all of `bytes`.
+///
+/// Unknown fields are ignored. Errors name the path of the value which failed to decode, as produced by `flatten`.
+pub fn decode_proto<T>(bytes: &[u8]) -> Result<T, DecodeProtoError>
+where
+    T: Generic,
+    T::Repr: GProtoMessage,
+{
+    let mut fields = ProtoValue::decode_message(bytes)?;
+    let repr = T::Repr::gfrom_message(&mut fields, &mut String::new())?;
+    T::checked_from_repr(repr).map_err(DecodeProtoError::Repr)
+}
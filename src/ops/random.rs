@@ -0,0 +1,302 @@
+use std::marker::PhantomData;
+
+use rand::distributions::{Alphanumeric, Distribution};
+use rand::Rng;
+
+use crate::{Con, Generic, Leaf, List, Meta, Opaque, Prod, Singleton, Sum, Unit, Void};
+
+/// The maximum length of a randomly generated list or string.
+pub const MAX_RANDOM_LEN: usize = 4;
+
+/// A leaf which can be generated randomly.
+///
+/// Implement this for your own leaf types to generate them with `GRandom`.
+pub trait Random {
+    /// Generates a random value using `rng`.
+    fn random<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized;
+}
+
+macro_rules! impl_random_standard {
+    ( $( $ty:ty ),+ $(,)? ) => {
+        $(
+            impl Random for $ty {
+                fn random<R>(rng: &mut R) -> Self
+                where
+                    R: Rng + ?Sized,
+                {
+                    rng.gen()
+                }
+            }
+        )+
+    }
+}
+
+#[rustfmt::skip]
+impl_random_standard!(
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64,
+    bool, char,
+);
+
+/// Alphanumeric, with a length up to `MAX_RANDOM_LEN`.
+impl Random for String {
+    fn random<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let len = rng.gen_range(0..=MAX_RANDOM_LEN);
+        (0..len)
+            .map(|_| char::from(rng.sample(Alphanumeric)))
+            .collect()
+    }
+}
+
+impl<T> Random for Opaque<T>
+where
+    T: Random,
+{
+    fn random<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        Opaque(T::random(rng))
+    }
+}
+
+/// Generates a random representation, node by node.
+///
+/// Sums choose one of their variants uniformly, lists have a length up to `MAX_RANDOM_LEN`,
+/// and leaves are generated with `Random`, which samples primitives from `rand`'s `Standard` distribution.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::gen_random;
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug)]
+/// enum Shape {
+///     Circle { radius: f32 },
+///     Polygon(Vec<(i8, i8)>),
+/// }
+///
+/// let shapes = (0..10).map(|_| gen_random::<Shape>()).collect::<Vec<_>>();
+/// for shape in shapes {
+///     match shape {
+///         Shape::Circle { radius } => assert!((0.0..1.0).contains(&radius)),
+///         Shape::Polygon(points) => assert!(points.len() <= 4),
+///     }
+/// }
+/// ```
+pub trait GRandom: Sized {
+    /// Generates a random representation using `rng`.
+    fn grandom<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized;
+}
+
+/// Generates a variant of a `Sum` chain by index.
+pub trait GRandomVariant: Sized {
+    /// The number of variants.
+    fn variant_count() -> usize;
+
+    /// Generates the variant at `index`, which must be less than `variant_count()`.
+    fn grandom_variant<R>(index: usize, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized;
+}
+
+impl GRandom for Unit {
+    fn grandom<R>(_rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        Unit
+    }
+}
+
+impl<A, B> GRandom for Prod<A, B>
+where
+    A: GRandom,
+    B: GRandom,
+{
+    fn grandom<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let a = A::grandom(rng);
+        Prod(a, B::grandom(rng))
+    }
+}
+
+/// Panics if there are no variants, since `Void` has no values.
+impl<L, R> GRandom for Sum<L, R>
+where
+    Self: GRandomVariant,
+{
+    fn grandom<G>(rng: &mut G) -> Self
+    where
+        G: Rng + ?Sized,
+    {
+        let index = rng.gen_range(0..Self::variant_count());
+        Self::grandom_variant(index, rng)
+    }
+}
+
+impl<L, R> GRandomVariant for Sum<L, R>
+where
+    L: GRandom,
+    R: GRandomVariant,
+{
+    fn variant_count() -> usize {
+        1 + R::variant_count()
+    }
+
+    fn grandom_variant<G>(index: usize, rng: &mut G) -> Self
+    where
+        G: Rng + ?Sized,
+    {
+        match index {
+            0 => Sum::Left(L::grandom(rng)),
+            index => Sum::Right(R::grandom_variant(index - 1, rng)),
+        }
+    }
+}
+
+impl GRandomVariant for Void {
+    fn variant_count() -> usize {
+        0
+    }
+
+    fn grandom_variant<R>(_index: usize, _rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        unreachable!("`Void` has no variants")
+    }
+}
+
+impl<I, M> GRandom for Meta<I, M>
+where
+    I: GRandom,
+    M: Singleton,
+{
+    fn grandom<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        Meta(I::grandom(rng), PhantomData)
+    }
+}
+
+impl<I, C> GRandom for Con<I, C>
+where
+    I: GRandom,
+    C: Singleton,
+{
+    fn grandom<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        Con(I::grandom(rng), PhantomData)
+    }
+}
+
+impl<A> GRandom for List<A>
+where
+    A: GRandom,
+{
+    fn grandom<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let len = rng.gen_range(0..=MAX_RANDOM_LEN);
+        List((0..len).map(|_| A::grandom(rng)).collect())
+    }
+}
+
+impl<A, const N: usize> GRandom for [A; N]
+where
+    A: GRandom,
+{
+    fn grandom<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        [(); N].map(|()| A::grandom(rng))
+    }
+}
+
+impl<T> GRandom for T
+where
+    T: Leaf + Random,
+{
+    fn grandom<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        T::random(rng)
+    }
+}
+
+/// A distribution generating any type whose representation implements `GRandom`,
+/// the generic counterpart of `rand`'s `Standard`.
+///
+/// Representations which the type rejects, like zero for `NonZeroU8`, are discarded and generated again,
+/// so sampling a type which rejects every representation never returns.
+///
+/// ```rust
+/// use generics::ops::GStandard;
+/// use rand::rngs::StdRng;
+/// use rand::{Rng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+/// let (a, b): ([u8; 2], Option<bool>) = rng.sample(GStandard);
+/// let pairs = (&mut rng).sample_iter(GStandard).take(3).collect::<Vec<([u8; 2], Option<bool>)>>();
+/// assert_eq!(pairs.len(), 3);
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+/// assert_eq!(rng.sample::<([u8; 2], Option<bool>), _>(GStandard), (a, b));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GStandard;
+
+impl<T> Distribution<T> for GStandard
+where
+    T: Generic,
+    T::Repr: GRandom,
+{
+    fn sample<R>(&self, rng: &mut R) -> T
+    where
+        R: Rng + ?Sized,
+    {
+        loop {
+            if let Ok(x) = T::checked_from_repr(T::Repr::grandom(rng)) {
+                return x;
+            }
+        }
+    }
+}
+
+/// Generates a random `T` using the thread-local generator.
+///
+/// ```rust
+/// use generics::ops::gen_random;
+///
+/// let key = gen_random::<[u8; 16]>();
+/// assert_ne!(key, gen_random::<[u8; 16]>());
+///
+/// if let Err(strings) = gen_random::<Result<char, Vec<String>>>() {
+///     assert!(strings.iter().all(|s| s.chars().all(|c| c.is_ascii_alphanumeric())));
+/// }
+/// ```
+pub fn gen_random<T>() -> T
+where
+    T: Generic,
+    T::Repr: GRandom,
+{
+    rand::thread_rng().sample(GStandard)
+}
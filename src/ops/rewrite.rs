@@ -0,0 +1,261 @@
+use std::any::Any;
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+use super::flatten::push_segment;
+
+/// Rewrites the leaves of a representation with a set of `Hooks`, each intercepting leaves of one type,
+/// optionally only at one path.
+///
+/// Paths are as produced by `flatten`, i.e. the field names and list indices leading to a leaf, joined with `.`,
+/// e.g. `"sessions.0.token"`. Like `everywhere`, leaves are matched by their exact type via `Any`.
+///
+/// `rewrite` applies this to a value, and `clone_with` to a clone of a value, e.g. to copy a record
+/// with fresh IDs and without its credentials.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{clone_with, Hooks};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Clone, Debug, PartialEq)]
+/// struct Account {
+///     id: u64,
+///     name: String,
+///     sessions: Vec<Session>,
+/// }
+///
+/// #[derive(Generic, Clone, Debug, PartialEq)]
+/// struct Session {
+///     id: u64,
+///     token: String,
+/// }
+///
+/// let account = Account {
+///     id: 1,
+///     name: "ada".to_string(),
+///     sessions: vec![Session { id: 2, token: "s3cr3t".to_string() }],
+/// };
+///
+/// let mut next_id = 100;
+/// let copy = clone_with(
+///     &account,
+///     Hooks::new()
+///         .leaf(|_: u64| {
+///             next_id += 1;
+///             next_id
+///         })
+///         .path("sessions.0.token", |_: String| String::new()),
+/// );
+/// assert_eq!(
+///     copy,
+///     Account {
+///         id: 101,
+///         name: "ada".to_string(),
+///         sessions: vec![Session { id: 102, token: String::new() }],
+///     }
+/// );
+/// assert_eq!(account.sessions[0].token, "s3cr3t");
+/// ```
+pub trait GRewrite {
+    /// Applies every matching hook to each leaf of `self`, with paths beginning with `path`.
+    fn grewrite(self, path: &mut String, hooks: &mut Hooks<'_>) -> Self;
+}
+
+/// A set of functions intercepting leaves of a given type, optionally only at a given path, for `GRewrite`.
+///
+/// Hooks are applied in the order they were added, so a leaf matched by several hooks is passed through each in turn.
+#[derive(Default)]
+pub struct Hooks<'a> {
+    hooks: Vec<Hook<'a>>,
+}
+
+/// A hook, called with an `Option` holding a leaf of any type.
+type HookFn<'a> = dyn FnMut(&mut dyn Any) + 'a;
+
+struct Hook<'a> {
+    path: Option<String>,
+    f: Box<HookFn<'a>>,
+}
+
+impl<'a> Hooks<'a> {
+    /// Creates an empty set of hooks, which leaves every leaf as-is.
+    pub fn new() -> Self {
+        Hooks { hooks: Vec::new() }
+    }
+
+    /// Adds a hook replacing every leaf `x` of type `T` with `f(x)`.
+    pub fn leaf<T, F>(self, f: F) -> Self
+    where
+        T: 'static,
+        F: FnMut(T) -> T + 'a,
+    {
+        self.push(None, f)
+    }
+
+    /// Adds a hook replacing the leaf `x` of type `T` at `path` with `f(x)`.
+    ///
+    /// If the leaf at `path` isn't of type `T`, or there is no leaf at `path`, the hook does nothing.
+    pub fn path<T, F>(self, path: &str, f: F) -> Self
+    where
+        T: 'static,
+        F: FnMut(T) -> T + 'a,
+    {
+        self.push(Some(path.to_string()), f)
+    }
+
+    fn push<T, F>(mut self, path: Option<String>, mut f: F) -> Self
+    where
+        T: 'static,
+        F: FnMut(T) -> T + 'a,
+    {
+        let f = move |leaf: &mut dyn Any| {
+            if let Some(slot) = leaf.downcast_mut::<Option<T>>() {
+                *slot = slot.take().map(&mut f);
+            }
+        };
+        self.hooks.push(Hook {
+            path,
+            f: Box::new(f),
+        });
+        self
+    }
+
+    /// Applies each hook matching `path` to `leaf`, which is an `Option` holding the leaf.
+    fn apply(&mut self, path: &str, leaf: &mut dyn Any) {
+        for hook in &mut self.hooks {
+            if hook.path.as_deref().is_none_or(|p| p == path) {
+                (hook.f)(leaf);
+            }
+        }
+    }
+}
+
+impl GRewrite for Unit {
+    fn grewrite(self, _path: &mut String, _hooks: &mut Hooks<'_>) -> Self {
+        Unit
+    }
+}
+
+impl<A, B> GRewrite for Prod<A, B>
+where
+    A: GRewrite,
+    B: GRewrite,
+{
+    fn grewrite(self, path: &mut String, hooks: &mut Hooks<'_>) -> Self {
+        let Prod(a, b) = self;
+        let a = a.grewrite(path, hooks);
+        Prod(a, b.grewrite(path, hooks))
+    }
+}
+
+impl<L, R> GRewrite for Sum<L, R>
+where
+    L: GRewrite,
+    R: GRewrite,
+{
+    fn grewrite(self, path: &mut String, hooks: &mut Hooks<'_>) -> Self {
+        match self {
+            Sum::Left(l) => Sum::Left(l.grewrite(path, hooks)),
+            Sum::Right(r) => Sum::Right(r.grewrite(path, hooks)),
+        }
+    }
+}
+
+impl GRewrite for Void {
+    fn grewrite(self, _path: &mut String, _hooks: &mut Hooks<'_>) -> Self {
+        match self {}
+    }
+}
+
+impl<I, M> GRewrite for Meta<I, M>
+where
+    I: GRewrite,
+    M: Singleton<T = &'static str>,
+{
+    fn grewrite(self, path: &mut String, hooks: &mut Hooks<'_>) -> Self {
+        let Meta(inner, _) = self;
+        let len = push_segment(path, &M::get());
+        let inner = inner.grewrite(path, hooks);
+        path.truncate(len);
+        Meta(inner, PhantomData)
+    }
+}
+
+impl<I, C> GRewrite for Con<I, C>
+where
+    I: GRewrite,
+    C: Singleton,
+{
+    fn grewrite(self, path: &mut String, hooks: &mut Hooks<'_>) -> Self {
+        let Con(inner, _) = self;
+        Con(inner.grewrite(path, hooks), PhantomData)
+    }
+}
+
+impl<A> GRewrite for List<A>
+where
+    A: GRewrite,
+{
+    fn grewrite(self, path: &mut String, hooks: &mut Hooks<'_>) -> Self {
+        let List(items) = self;
+        let items = items
+            .into_iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let len = push_segment(path, &i);
+                let item = item.grewrite(path, hooks);
+                path.truncate(len);
+                item
+            })
+            .collect();
+        List(items)
+    }
+}
+
+impl<A, const N: usize> GRewrite for [A; N]
+where
+    A: GRewrite,
+{
+    fn grewrite(self, path: &mut String, hooks: &mut Hooks<'_>) -> Self {
+        let mut i = 0;
+        self.map(|item| {
+            let len = push_segment(path, &i);
+            let item = item.grewrite(path, hooks);
+            path.truncate(len);
+            i += 1;
+            item
+        })
+    }
+}
+
+impl<L> GRewrite for L
+where
+    L: Leaf + 'static,
+{
+    fn grewrite(self, path: &mut String, hooks: &mut Hooks<'_>) -> Self {
+        let mut leaf = Some(self);
+        hooks.apply(path, &mut leaf);
+        leaf.unwrap()
+    }
+}
+
+/// Applies `hooks` to the leaves in the representation of `x`, in order.
+pub fn rewrite<X>(x: X, mut hooks: Hooks<'_>) -> X
+where
+    X: Generic,
+    X::Repr: GRewrite,
+{
+    X::from_repr(x.into_repr().grewrite(&mut String::new(), &mut hooks))
+}
+
+/// Clones `x`, applying `hooks` to the leaves of the clone, in order.
+pub fn clone_with<X>(x: &X, hooks: Hooks<'_>) -> X
+where
+    X: Generic + Clone,
+    X::Repr: GRewrite,
+{
+    rewrite(x.clone(), hooks)
+}
@@ -0,0 +1,391 @@
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{Con, Meta, Opaque, Prod, Sensitive, Singleton, Sum, Unit, Void};
+
+/// A value which can be normalized in place, e.g. user input cleaned up before validation.
+///
+/// Leaves are left alone, and containers sanitize their contents. Derive this with `#[derive(GenericSanitize)]`
+/// to apply the rules given by `#[generic(sanitize = "...")]` on each field.
+pub trait Sanitize {
+    /// Normalizes `self` in place.
+    fn sanitize(&mut self);
+}
+
+macro_rules! impl_sanitize_leaf {
+    ( $( $ty:ty ),+ $(,)? ) => {
+        $(
+            impl Sanitize for $ty {
+                fn sanitize(&mut self) {}
+            }
+        )+
+    };
+}
+
+#[rustfmt::skip]
+impl_sanitize_leaf!(
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64,
+    bool, char, String, (),
+);
+
+impl<T, const N: usize> Sanitize for [T; N]
+where
+    T: Sanitize,
+{
+    fn sanitize(&mut self) {
+        self.iter_mut().for_each(Sanitize::sanitize);
+    }
+}
+
+impl<T> Sanitize for Vec<T>
+where
+    T: Sanitize,
+{
+    fn sanitize(&mut self) {
+        self.iter_mut().for_each(Sanitize::sanitize);
+    }
+}
+
+impl<T> Sanitize for Option<T>
+where
+    T: Sanitize,
+{
+    fn sanitize(&mut self) {
+        if let Some(x) = self {
+            x.sanitize();
+        }
+    }
+}
+
+impl<T> Sanitize for Box<T>
+where
+    T: Sanitize + ?Sized,
+{
+    fn sanitize(&mut self) {
+        (**self).sanitize();
+    }
+}
+
+/// The contents of an opaque leaf are left alone.
+impl<T> Sanitize for Opaque<T> {
+    fn sanitize(&mut self) {}
+}
+
+impl<T> Sanitize for Sensitive<T>
+where
+    T: Sanitize,
+{
+    fn sanitize(&mut self) {
+        self.0.sanitize();
+    }
+}
+
+/// A way of normalizing a field, chosen with `#[generic(sanitize = "...")]`.
+///
+/// Implement this for your own types to use them as rules, e.g. `#[generic(sanitize = "my_mod::Slug")]`,
+/// where the expression evaluates to the rule.
+pub trait SanitizeRule<T: ?Sized> {
+    /// Normalizes `value` in place.
+    fn apply(&self, value: &mut T);
+}
+
+/// Applies a rule to the value, if there is one.
+macro_rules! impl_sanitize_rule_containers {
+    ( $( $rule:ty ),+ ) => {
+        $(
+            impl<T> SanitizeRule<Option<T>> for $rule
+            where
+                $rule: SanitizeRule<T>,
+            {
+                fn apply(&self, value: &mut Option<T>) {
+                    if let Some(x) = value {
+                        self.apply(x);
+                    }
+                }
+            }
+
+            impl<T> SanitizeRule<Vec<T>> for $rule
+            where
+                $rule: SanitizeRule<T>,
+            {
+                fn apply(&self, value: &mut Vec<T>) {
+                    value.iter_mut().for_each(|x| self.apply(x));
+                }
+            }
+        )+
+    };
+}
+
+/// Removes leading and trailing whitespace from strings. This is `#[generic(sanitize = "trim")]`.
+pub struct Trim;
+
+impl SanitizeRule<String> for Trim {
+    fn apply(&self, value: &mut String) {
+        let end = value.trim_end().len();
+        value.truncate(end);
+        let start = value.len() - value.trim_start().len();
+        value.drain(..start);
+    }
+}
+
+/// Converts strings to lowercase. This is `#[generic(sanitize = "lowercase")]`.
+pub struct Lowercase;
+
+impl SanitizeRule<String> for Lowercase {
+    fn apply(&self, value: &mut String) {
+        if value.chars().any(char::is_uppercase) {
+            *value = value.to_lowercase();
+        }
+    }
+}
+
+/// Converts strings to Unicode Normalization Form C, so that canonically equivalent strings are equal.
+/// This is `#[generic(sanitize = "nfc")]`, and requires the `unicode-normalization` feature.
+#[cfg(feature = "unicode-normalization")]
+pub struct Nfc;
+
+#[cfg(feature = "unicode-normalization")]
+impl SanitizeRule<String> for Nfc {
+    fn apply(&self, value: &mut String) {
+        if !unicode_normalization::is_nfc(value) {
+            *value = value.nfc().collect();
+        }
+    }
+}
+
+/// Clamps numbers to the inclusive range from the first bound to the second.
+/// This is `#[generic(sanitize = "clamp(min, max)")]`.
+pub struct Clamp<T>(pub T, pub T);
+
+impl<T> SanitizeRule<T> for Clamp<T>
+where
+    T: PartialOrd + Clone,
+{
+    fn apply(&self, value: &mut T) {
+        let Clamp(min, max) = self;
+        if *value < *min {
+            *value = min.clone();
+        } else if *value > *max {
+            *value = max.clone();
+        }
+    }
+}
+
+impl<T> SanitizeRule<Option<T>> for Clamp<T>
+where
+    T: PartialOrd + Clone,
+{
+    fn apply(&self, value: &mut Option<T>) {
+        if let Some(x) = value {
+            self.apply(x);
+        }
+    }
+}
+
+impl<T> SanitizeRule<Vec<T>> for Clamp<T>
+where
+    T: PartialOrd + Clone,
+{
+    fn apply(&self, value: &mut Vec<T>) {
+        value.iter_mut().for_each(|x| self.apply(x));
+    }
+}
+
+/// Removes repeated elements from `Vec`s, keeping the first occurrence of each, in order.
+/// This is `#[generic(sanitize = "dedup")]`.
+pub struct Dedup;
+
+impl<T> SanitizeRule<Vec<T>> for Dedup
+where
+    T: PartialEq,
+{
+    fn apply(&self, value: &mut Vec<T>) {
+        let mut i = 0;
+        while i < value.len() {
+            if value[..i].contains(&value[i]) {
+                value.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+impl_sanitize_rule_containers!(Trim, Lowercase);
+#[cfg(feature = "unicode-normalization")]
+impl_sanitize_rule_containers!(Nfc);
+
+/// Applies each rule in turn.
+macro_rules! impl_sanitize_rule_tuple {
+    ( $( ( $( $rule:ident ),* ) ),+ ) => {
+        $(
+            #[allow(non_snake_case)]
+            impl<T, $( $rule ),*> SanitizeRule<T> for ( $( $rule, )* )
+            where
+                T: ?Sized,
+                $( $rule: SanitizeRule<T>, )*
+            {
+                #[allow(unused_variables)]
+                fn apply(&self, value: &mut T) {
+                    let ( $( $rule, )* ) = self;
+                    $( $rule.apply(value); )*
+                }
+            }
+        )+
+    };
+}
+
+impl_sanitize_rule_tuple!(
+    (),
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H)
+);
+
+/// A leaf borrowing a field along with the rules used to sanitize it, which `GSanitize` applies.
+pub struct Rules<'a, T, R>(pub &'a mut T, pub R)
+where
+    T: ?Sized;
+
+/// Sanitizes a representation whose fields are each borrowed in `Rules`, in place.
+///
+/// Each field is sanitized with its own `Sanitize` impl, then with each of its rules in order,
+/// or only with its rules if it's `Opaque`. Sums sanitize the variant they hold.
+///
+/// `#[derive(GenericSanitize)]` uses this to implement `Sanitize`, with the rules given by
+/// `#[generic(sanitize = "...")]` on each field. The built-in rules are `"trim"`, `"lowercase"`, `"nfc"`,
+/// `"clamp(min, max)"` and `"dedup"`, and any other expression is used as a rule itself.
+/// Several rules may be given, separated by commas.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{GenericSanitize, Sanitize};
+/// use generics::Generic;
+///
+/// #[derive(Debug, PartialEq, Generic, GenericSanitize)]
+/// struct Address {
+///     #[generic(sanitize = "trim")]
+///     city: String,
+/// }
+///
+/// #[derive(Debug, PartialEq, Generic, GenericSanitize)]
+/// struct Signup {
+///     #[generic(sanitize = "trim, lowercase")]
+///     email: String,
+///     #[generic(sanitize = "clamp(13, 120)")]
+///     age: u8,
+///     #[generic(sanitize = "trim, dedup")]
+///     interests: Vec<String>,
+///     address: Option<Address>,
+/// }
+///
+/// let mut signup = Signup {
+///     email: "  Alice@Example.COM ".to_string(),
+///     age: 7,
+///     interests: vec!["rust ".to_string(), "chess".to_string(), " rust".to_string()],
+///     address: Some(Address { city: " Montreal\n".to_string() }),
+/// };
+/// signup.sanitize();
+/// assert_eq!(
+///     signup,
+///     Signup {
+///         email: "alice@example.com".to_string(),
+///         age: 13,
+///         interests: vec!["rust".to_string(), "chess".to_string()],
+///         address: Some(Address { city: "Montreal".to_string() }),
+///     }
+/// );
+/// ```
+pub trait GSanitize {
+    /// Sanitizes every field of `self`.
+    fn gsanitize(self);
+}
+
+impl GSanitize for Unit {
+    fn gsanitize(self) {}
+}
+
+impl<A, B> GSanitize for Prod<A, B>
+where
+    A: GSanitize,
+    B: GSanitize,
+{
+    fn gsanitize(self) {
+        let Prod(a, b) = self;
+        a.gsanitize();
+        b.gsanitize();
+    }
+}
+
+impl<L, R> GSanitize for Sum<L, R>
+where
+    L: GSanitize,
+    R: GSanitize,
+{
+    fn gsanitize(self) {
+        match self {
+            Sum::Left(l) => l.gsanitize(),
+            Sum::Right(r) => r.gsanitize(),
+        }
+    }
+}
+
+impl GSanitize for Void {
+    fn gsanitize(self) {
+        match self {}
+    }
+}
+
+impl<I, M> GSanitize for Meta<I, M>
+where
+    I: GSanitize,
+    M: Singleton,
+{
+    fn gsanitize(self) {
+        let Meta(inner, _) = self;
+        inner.gsanitize();
+    }
+}
+
+impl<I, C> GSanitize for Con<I, C>
+where
+    I: GSanitize,
+    C: Singleton,
+{
+    fn gsanitize(self) {
+        let Con(inner, _) = self;
+        inner.gsanitize();
+    }
+}
+
+impl<T, R> GSanitize for Rules<'_, T, R>
+where
+    T: Sanitize + ?Sized,
+    R: SanitizeRule<T>,
+{
+    fn gsanitize(self) {
+        let Rules(value, rules) = self;
+        value.sanitize();
+        rules.apply(value);
+    }
+}
+
+/// Opaque fields are only sanitized with their rules.
+impl<T, R> GSanitize for Opaque<Rules<'_, T, R>>
+where
+    T: ?Sized,
+    R: SanitizeRule<T>,
+{
+    fn gsanitize(self) {
+        let Opaque(Rules(value, rules)) = self;
+        rules.apply(value);
+    }
+}
@@ -0,0 +1,50 @@
+use serde::{Serialize, Serializer};
+
+use crate::Generic;
+
+/// Serializes `x` with `serializer`, as `#[derive(Serialize)]` would.
+///
+/// This serializes the representation with its `Serialize` impl from `generics::serde`, which makes the same calls to
+/// a `Serializer` as serde's derive on the original type: structs and enums are serialized with the names of their type,
+/// constructors, and fields, taken from their metadata, and as unit, newtype, tuple, or braced structs and variants,
+/// as they were declared. `Option` is serialized as an option, tuples and arrays as tuples, lists as sequences,
+/// and leaves with their own impls. Types with `#[generic(rename)]` or serde attributes may differ from serde's derive.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::serialize_generic;
+/// use generics::Generic;
+/// use serde::Serialize;
+///
+/// #[derive(Generic, Serialize, Clone)]
+/// struct Message {
+///     id: u32,
+///     body: Body,
+///     reply_to: Option<u32>,
+/// }
+///
+/// #[derive(Generic, Serialize, Clone)]
+/// enum Body {
+///     Ping,
+///     Text(String),
+///     Move { x: i8, y: i8 },
+/// }
+///
+/// let message = Message { id: 1, body: Body::Move { x: 2, y: -3 }, reply_to: None };
+/// let mut json = Vec::new();
+/// serialize_generic(message.clone(), &mut serde_json::Serializer::new(&mut json)).unwrap();
+/// assert_eq!(String::from_utf8(json).unwrap(), r#"{"id":1,"body":{"Move":{"x":2,"y":-3}},"reply_to":null}"#);
+/// assert_eq!(
+///     serialize_generic(message.clone(), serde_json::value::Serializer).unwrap(),
+///     serde_json::to_value(message).unwrap()
+/// );
+/// ```
+pub fn serialize_generic<T, S>(x: T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Generic,
+    T::Repr: Serialize,
+    S: Serializer,
+{
+    x.into_repr().serialize(serializer)
+}
@@ -0,0 +1,383 @@
+use std::any;
+use std::fmt::{self, Display};
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+/// The structure of a representation, as described by `GShape`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Shape {
+    /// The elements of a `Prod` chain, e.g. the fields of a constructor.
+    Prod(Vec<Shape>),
+    /// The variants of a `Sum` chain, which is empty for `Void`.
+    Sum(Vec<Shape>),
+    /// A field, with its name.
+    Field(&'static str, Box<Shape>),
+    /// A constructor, with its name.
+    Con(&'static str, Box<Shape>),
+    /// A list of elements.
+    List(Box<Shape>),
+    /// An array of elements, with its length.
+    Array(Box<Shape>, usize),
+    /// A leaf, with the full name of its type.
+    Leaf(&'static str),
+}
+
+impl Shape {
+    /// A stable 64-bit hash of the shape, to detect changes to a type's structure between builds,
+    /// e.g. so a cache or wire format can be checked against the type it was written with.
+    ///
+    /// The hash covers the names of constructors and fields, their order, the lengths of arrays,
+    /// and the names of leaf types without their module paths, so moving a type between modules doesn't change it.
+    /// It doesn't depend on the platform or the standard library's hasher, using 64-bit FNV-1a.
+    ///
+    /// ```rust
+    /// use generics::ops::{fingerprint, shape};
+    /// use generics::Generic;
+    ///
+    /// #[derive(Generic)]
+    /// struct V1 {
+    ///     id: u32,
+    /// }
+    ///
+    /// #[derive(Generic)]
+    /// struct V2 {
+    ///     id: u64,
+    /// }
+    ///
+    /// assert_eq!(fingerprint::<V1>(), shape::<V1>().fingerprint());
+    /// assert_ne!(fingerprint::<V1>(), fingerprint::<V2>());
+    /// assert_eq!(fingerprint::<(u8, bool)>(), 0x5b0b_eea7_23a2_f0dc);
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = Fnv1a(0xcbf2_9ce4_8422_2325);
+        self.write_fingerprint(&mut hasher);
+        hasher.0
+    }
+
+    /// Writes a tag for the kind of each node, followed by its contents, with lengths before variable-length data.
+    fn write_fingerprint(&self, hasher: &mut Fnv1a) {
+        match self {
+            Shape::Prod(elements) => {
+                hasher.write(b"P");
+                hasher.write_len(elements.len());
+                elements.iter().for_each(|e| e.write_fingerprint(hasher));
+            }
+            Shape::Sum(variants) => {
+                hasher.write(b"S");
+                hasher.write_len(variants.len());
+                variants.iter().for_each(|v| v.write_fingerprint(hasher));
+            }
+            Shape::Field(name, inner) => {
+                hasher.write(b"F");
+                hasher.write_str(name);
+                inner.write_fingerprint(hasher);
+            }
+            Shape::Con(name, inner) => {
+                hasher.write(b"C");
+                hasher.write_str(name);
+                inner.write_fingerprint(hasher);
+            }
+            Shape::List(element) => {
+                hasher.write(b"L");
+                element.write_fingerprint(hasher);
+            }
+            Shape::Array(element, len) => {
+                hasher.write(b"A");
+                hasher.write_len(*len);
+                element.write_fingerprint(hasher);
+            }
+            Shape::Leaf(name) => {
+                hasher.write(b"T");
+                hasher.write_str(&short_type_name(name));
+            }
+        }
+    }
+}
+
+/// The 64-bit FNV-1a hash, whose output is the same on every platform and version.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    /// Writes `len` as 8 little-endian bytes, regardless of the size of `usize`.
+    fn write_len(&mut self, len: usize) {
+        self.write(&(len as u64).to_le_bytes());
+    }
+
+    fn write_str(&mut self, s: &str) {
+        self.write_len(s.len());
+        self.write(s.as_bytes());
+    }
+}
+
+/// Formats the shape like a Rust type definition, with leaf types named without their module paths,
+/// e.g. `A { a: u8, b: [String] } | B(Option<u8>) | C`.
+impl Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Shape::Prod(elements) => {
+                f.write_str("(")?;
+                fmt_list(elements, ", ", f)?;
+                f.write_str(")")
+            }
+            Shape::Sum(variants) if variants.is_empty() => f.write_str("!"),
+            Shape::Sum(variants) => fmt_list(variants, " | ", f),
+            Shape::Field(name, inner) => write!(f, "{}: {}", name, inner),
+            Shape::Con(name, inner) => match &**inner {
+                Shape::Prod(fields) if fields.is_empty() => f.write_str(name),
+                Shape::Prod(fields) if fields.iter().all(is_named) => {
+                    write!(f, "{} {{ ", name)?;
+                    fmt_list(fields, ", ", f)?;
+                    f.write_str(" }")
+                }
+                Shape::Prod(fields) => {
+                    write!(f, "{}(", name)?;
+                    for (i, field) in fields.iter().enumerate() {
+                        if i > 0 {
+                            f.write_str(", ")?;
+                        }
+                        match field {
+                            Shape::Field(_, inner) => write!(f, "{}", inner)?,
+                            field => write!(f, "{}", field)?,
+                        }
+                    }
+                    f.write_str(")")
+                }
+                inner => write!(f, "{}({})", name, inner),
+            },
+            Shape::List(element) => write!(f, "[{}]", element),
+            Shape::Array(element, len) => write!(f, "[{}; {}]", element, len),
+            Shape::Leaf(name) => f.write_str(&short_type_name(name)),
+        }
+    }
+}
+
+/// Whether `shape` is a field with a name, as opposed to a tuple field named by its index.
+fn is_named(shape: &Shape) -> bool {
+    match shape {
+        Shape::Field(name, _) => name.parse::<usize>().is_err(),
+        _ => false,
+    }
+}
+
+/// Formats each of `shapes`, separated by `sep`.
+fn fmt_list(shapes: &[Shape], sep: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (i, shape) in shapes.iter().enumerate() {
+        if i > 0 {
+            f.write_str(sep)?;
+        }
+        write!(f, "{}", shape)?;
+    }
+    Ok(())
+}
+
+/// Strips the module path from each path in a type name, e.g. `alloc::vec::Vec<alloc::string::String>` to `Vec<String>`.
+fn short_type_name(name: &str) -> String {
+    let mut short = String::with_capacity(name.len());
+    let mut path_start = 0;
+    let mut rest = name;
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("::") {
+            short.truncate(path_start);
+            rest = after;
+            continue;
+        }
+        short.push(c);
+        if !(c.is_alphanumeric() || c == '_') {
+            path_start = short.len();
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    short
+}
+
+/// Describes the structure of a representation type, without needing a value.
+///
+/// Names of constructors and fields come from their metadata, and leaves are described by the name of their type.
+/// This can be used to generate documentation, or to inspect what `#[derive(Generic)]` produced.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::shape;
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[derive(Generic)]
+/// enum Path {
+///     Empty,
+///     Line(Point, Point),
+///     Poly { points: Vec<Point>, closed: bool },
+/// }
+///
+/// assert_eq!(
+///     shape::<Path>().to_string(),
+///     "Empty \
+///      | Line(Point { x: i32, y: i32 }, Point { x: i32, y: i32 }) \
+///      | Poly { points: [Point { x: i32, y: i32 }], closed: bool }",
+/// );
+/// ```
+pub trait GShape {
+    /// Describes the structure of `Self`.
+    fn shape() -> Shape;
+}
+
+/// Describes the elements of a `Prod` chain.
+pub trait GShapeFields {
+    /// Appends the shape of each element to `fields`.
+    fn shape_fields(fields: &mut Vec<Shape>);
+}
+
+/// Describes the variants of a `Sum` chain.
+pub trait GShapeVariants {
+    /// Appends the shape of each variant to `variants`.
+    fn shape_variants(variants: &mut Vec<Shape>);
+}
+
+impl GShapeFields for Unit {
+    fn shape_fields(_fields: &mut Vec<Shape>) {}
+}
+
+impl<A, B> GShapeFields for Prod<A, B>
+where
+    A: GShape,
+    B: GShapeFields,
+{
+    fn shape_fields(fields: &mut Vec<Shape>) {
+        fields.push(A::shape());
+        B::shape_fields(fields);
+    }
+}
+
+impl GShapeVariants for Void {
+    fn shape_variants(_variants: &mut Vec<Shape>) {}
+}
+
+impl<L, R> GShapeVariants for Sum<L, R>
+where
+    L: GShape,
+    R: GShapeVariants,
+{
+    fn shape_variants(variants: &mut Vec<Shape>) {
+        variants.push(L::shape());
+        R::shape_variants(variants);
+    }
+}
+
+impl GShape for Unit {
+    fn shape() -> Shape {
+        Shape::Prod(Vec::new())
+    }
+}
+
+impl<A, B> GShape for Prod<A, B>
+where
+    Self: GShapeFields,
+{
+    fn shape() -> Shape {
+        let mut fields = Vec::new();
+        Self::shape_fields(&mut fields);
+        Shape::Prod(fields)
+    }
+}
+
+impl<L, R> GShape for Sum<L, R>
+where
+    Self: GShapeVariants,
+{
+    fn shape() -> Shape {
+        let mut variants = Vec::new();
+        Self::shape_variants(&mut variants);
+        Shape::Sum(variants)
+    }
+}
+
+impl GShape for Void {
+    fn shape() -> Shape {
+        Shape::Sum(Vec::new())
+    }
+}
+
+impl<I, M> GShape for Meta<I, M>
+where
+    I: GShape,
+    M: Singleton<T = &'static str>,
+{
+    fn shape() -> Shape {
+        Shape::Field(M::get(), Box::new(I::shape()))
+    }
+}
+
+impl<I, C> GShape for Con<I, C>
+where
+    I: GShape,
+    C: Singleton<T = &'static str>,
+{
+    fn shape() -> Shape {
+        Shape::Con(C::get(), Box::new(I::shape()))
+    }
+}
+
+impl<A> GShape for List<A>
+where
+    A: GShape,
+{
+    fn shape() -> Shape {
+        Shape::List(Box::new(A::shape()))
+    }
+}
+
+impl<A, const N: usize> GShape for [A; N]
+where
+    A: GShape,
+{
+    fn shape() -> Shape {
+        Shape::Array(Box::new(A::shape()), N)
+    }
+}
+
+impl<T> GShape for T
+where
+    T: Leaf,
+{
+    fn shape() -> Shape {
+        Shape::Leaf(any::type_name::<T>())
+    }
+}
+
+/// Describes the structure of the representation of `T`.
+///
+/// ```rust
+/// use generics::ops::{shape, Shape};
+///
+/// assert_eq!(shape::<(u8, [bool; 2])>().to_string(), "(u8, [bool; 2])");
+/// assert_eq!(shape::<Option<String>>().to_string(), "None | Some(String)");
+/// assert_eq!(shape::<Vec<u8>>(), Shape::List(Box::new(Shape::Leaf("u8"))));
+/// ```
+pub fn shape<T>() -> Shape
+where
+    T: Generic,
+    T::Repr: GShape,
+{
+    T::Repr::shape()
+}
+
+/// A stable hash of the structure of the representation of `T`, as given by `Shape::fingerprint`.
+pub fn fingerprint<T>() -> u64
+where
+    T: Generic,
+    T::Repr: GShape,
+{
+    shape::<T>().fingerprint()
+}
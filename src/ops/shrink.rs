@@ -0,0 +1,342 @@
+use std::marker::PhantomData;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+/// Generates representations for property tests, and shrinks them structurally towards minimal counterexamples.
+///
+/// Generation chooses the variant of each sum uniformly, and the length of each list up to the size of the `Gen`.
+/// Shrinking tries earlier variants of sums first, then removing elements from lists, then shrinking each field,
+/// element, or leaf in turn. Leaves use their own `quickcheck::Arbitrary` impl.
+///
+/// `#[derive(GenericQuickcheck)]` uses this to implement `quickcheck::Arbitrary`, alongside `#[derive(Generic)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::GenericQuickcheck;
+/// use generics::Generic;
+/// use quickcheck::{Arbitrary, QuickCheck};
+///
+/// #[derive(Generic, GenericQuickcheck, Clone, Debug, PartialEq)]
+/// enum Expr {
+///     Zero,
+///     Sum(Vec<u32>),
+/// }
+///
+/// // Earlier variants are tried first.
+/// assert_eq!(Expr::Sum(vec![7]).shrink().next(), Some(Expr::Zero));
+///
+/// fn prop(expr: Expr) -> bool {
+///     match expr {
+///         Expr::Zero => true,
+///         Expr::Sum(xs) => xs.len() < 100,
+///     }
+/// }
+/// QuickCheck::new().quickcheck(prop as fn(Expr) -> bool);
+/// ```
+pub trait GQuickcheck: Sized {
+    /// Generates a representation with `g`.
+    fn gquickcheck(g: &mut Gen) -> Self;
+
+    /// Candidates which are simpler than `self`, simplest first.
+    fn gshrink(&self) -> Vec<Self>;
+
+    /// Clones `self`, since representations don't implement `Clone`.
+    fn gduplicate(&self) -> Self;
+}
+
+/// Generates a variant of a `Sum` chain by index.
+pub trait GQuickcheckVariant: Sized {
+    /// The number of variants.
+    fn variant_count() -> usize;
+
+    /// Generates the variant at `index`, which must be less than `variant_count()`.
+    fn gquickcheck_variant(index: usize, g: &mut Gen) -> Self;
+}
+
+/// A fixed, small generator, so shrinking to an earlier variant is deterministic.
+fn simple_gen() -> Gen {
+    Gen::from_size_and_seed(1, 0)
+}
+
+impl GQuickcheck for Unit {
+    fn gquickcheck(_g: &mut Gen) -> Self {
+        Unit
+    }
+
+    fn gshrink(&self) -> Vec<Self> {
+        Vec::new()
+    }
+
+    fn gduplicate(&self) -> Self {
+        Unit
+    }
+}
+
+impl<A, B> GQuickcheck for Prod<A, B>
+where
+    A: GQuickcheck,
+    B: GQuickcheck,
+{
+    fn gquickcheck(g: &mut Gen) -> Self {
+        let a = A::gquickcheck(g);
+        Prod(a, B::gquickcheck(g))
+    }
+
+    fn gshrink(&self) -> Vec<Self> {
+        let Prod(a, b) = self;
+        let shrink_a = a.gshrink().into_iter().map(|a| Prod(a, b.gduplicate()));
+        let shrink_b = b.gshrink().into_iter().map(|b| Prod(a.gduplicate(), b));
+        shrink_a.chain(shrink_b).collect()
+    }
+
+    fn gduplicate(&self) -> Self {
+        let Prod(a, b) = self;
+        Prod(a.gduplicate(), b.gduplicate())
+    }
+}
+
+impl<L, R> GQuickcheck for Sum<L, R>
+where
+    L: GQuickcheck,
+    R: GQuickcheck + GQuickcheckVariant,
+{
+    fn gquickcheck(g: &mut Gen) -> Self {
+        let index = usize::arbitrary(g) % Self::variant_count();
+        Self::gquickcheck_variant(index, g)
+    }
+
+    fn gshrink(&self) -> Vec<Self> {
+        match self {
+            Sum::Left(l) => l.gshrink().into_iter().map(Sum::Left).collect(),
+            Sum::Right(r) => {
+                let earlier = Sum::Left(L::gquickcheck(&mut simple_gen()));
+                let shrink_r = r.gshrink().into_iter().map(Sum::Right);
+                Some(earlier).into_iter().chain(shrink_r).collect()
+            }
+        }
+    }
+
+    fn gduplicate(&self) -> Self {
+        match self {
+            Sum::Left(l) => Sum::Left(l.gduplicate()),
+            Sum::Right(r) => Sum::Right(r.gduplicate()),
+        }
+    }
+}
+
+impl<L, R> GQuickcheckVariant for Sum<L, R>
+where
+    L: GQuickcheck,
+    R: GQuickcheckVariant,
+{
+    fn variant_count() -> usize {
+        1 + R::variant_count()
+    }
+
+    fn gquickcheck_variant(index: usize, g: &mut Gen) -> Self {
+        match index {
+            0 => Sum::Left(L::gquickcheck(g)),
+            index => Sum::Right(R::gquickcheck_variant(index - 1, g)),
+        }
+    }
+}
+
+/// Panics when generating, since `Void` has no values.
+impl GQuickcheck for Void {
+    fn gquickcheck(_g: &mut Gen) -> Self {
+        panic!("cannot generate a value of an uninhabited type")
+    }
+
+    fn gshrink(&self) -> Vec<Self> {
+        match *self {}
+    }
+
+    fn gduplicate(&self) -> Self {
+        match *self {}
+    }
+}
+
+impl GQuickcheckVariant for Void {
+    fn variant_count() -> usize {
+        0
+    }
+
+    fn gquickcheck_variant(_index: usize, _g: &mut Gen) -> Self {
+        unreachable!("`Void` has no variants")
+    }
+}
+
+impl<I, M> GQuickcheck for Meta<I, M>
+where
+    I: GQuickcheck,
+    M: Singleton,
+{
+    fn gquickcheck(g: &mut Gen) -> Self {
+        Meta(I::gquickcheck(g), PhantomData)
+    }
+
+    fn gshrink(&self) -> Vec<Self> {
+        let Meta(inner, _) = self;
+        inner
+            .gshrink()
+            .into_iter()
+            .map(|inner| Meta(inner, PhantomData))
+            .collect()
+    }
+
+    fn gduplicate(&self) -> Self {
+        let Meta(inner, _) = self;
+        Meta(inner.gduplicate(), PhantomData)
+    }
+}
+
+impl<I, C> GQuickcheck for Con<I, C>
+where
+    I: GQuickcheck,
+    C: Singleton,
+{
+    fn gquickcheck(g: &mut Gen) -> Self {
+        Con(I::gquickcheck(g), PhantomData)
+    }
+
+    fn gshrink(&self) -> Vec<Self> {
+        let Con(inner, _) = self;
+        inner
+            .gshrink()
+            .into_iter()
+            .map(|inner| Con(inner, PhantomData))
+            .collect()
+    }
+
+    fn gduplicate(&self) -> Self {
+        let Con(inner, _) = self;
+        Con(inner.gduplicate(), PhantomData)
+    }
+}
+
+impl<A> GQuickcheck for List<A>
+where
+    A: GQuickcheck,
+{
+    fn gquickcheck(g: &mut Gen) -> Self {
+        let len = match g.size() {
+            0 => 0,
+            size => usize::arbitrary(g) % size,
+        };
+        List((0..len).map(|_| A::gquickcheck(g)).collect())
+    }
+
+    fn gshrink(&self) -> Vec<Self> {
+        let List(items) = self;
+        let duplicate = |items: &[A]| items.iter().map(A::gduplicate).collect::<Vec<_>>();
+        let mut candidates = Vec::new();
+        if !items.is_empty() {
+            candidates.push(List(Vec::new()));
+        }
+        if items.len() > 1 {
+            for i in 0..items.len() {
+                let mut fewer = duplicate(&items[..i]);
+                fewer.extend(duplicate(&items[i + 1..]));
+                candidates.push(List(fewer));
+            }
+        }
+        for (i, item) in items.iter().enumerate() {
+            for item in item.gshrink() {
+                let mut shrunk = duplicate(items);
+                shrunk[i] = item;
+                candidates.push(List(shrunk));
+            }
+        }
+        candidates
+    }
+
+    fn gduplicate(&self) -> Self {
+        let List(items) = self;
+        List(items.iter().map(A::gduplicate).collect())
+    }
+}
+
+impl<A, const N: usize> GQuickcheck for [A; N]
+where
+    A: GQuickcheck,
+{
+    fn gquickcheck(g: &mut Gen) -> Self {
+        [(); N].map(|()| A::gquickcheck(g))
+    }
+
+    fn gshrink(&self) -> Vec<Self> {
+        let mut candidates = Vec::new();
+        for (i, item) in self.iter().enumerate() {
+            for item in item.gshrink() {
+                let mut shrunk = self.gduplicate();
+                shrunk[i] = item;
+                candidates.push(shrunk);
+            }
+        }
+        candidates
+    }
+
+    fn gduplicate(&self) -> Self {
+        let mut items = self.iter();
+        [(); N].map(|()| items.next().unwrap().gduplicate())
+    }
+}
+
+impl<T> GQuickcheck for T
+where
+    T: Leaf + Arbitrary,
+{
+    fn gquickcheck(g: &mut Gen) -> Self {
+        T::arbitrary(g)
+    }
+
+    fn gshrink(&self) -> Vec<Self> {
+        self.shrink().collect()
+    }
+
+    fn gduplicate(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// Generates a `T` for a property test, via its representation.
+///
+/// Representations which `T` rejects are discarded and generated again, like `GStandard` does.
+pub fn gquickcheck<T>(g: &mut Gen) -> T
+where
+    T: Generic,
+    T::Repr: GQuickcheck,
+{
+    loop {
+        if let Ok(x) = T::checked_from_repr(T::Repr::gquickcheck(g)) {
+            return x;
+        }
+    }
+}
+
+/// Shrinks `x` structurally, yielding simpler candidates, simplest first.
+/// Candidates which `T` rejects are skipped.
+///
+/// ```rust
+/// use generics::ops::gshrink;
+///
+/// let shrunk = gshrink(&(Some(2u8), vec![true])).collect::<Vec<_>>();
+/// assert_eq!(shrunk[0], (None, vec![true]));
+/// assert!(shrunk.contains(&(Some(2), vec![])));
+/// ```
+pub fn gshrink<T>(x: &T) -> Box<dyn Iterator<Item = T>>
+where
+    T: Generic + Clone + 'static,
+    T::Repr: GQuickcheck + 'static,
+{
+    Box::new(
+        x.clone()
+            .into_repr()
+            .gshrink()
+            .into_iter()
+            .filter_map(|repr| T::checked_from_repr(repr).ok()),
+    )
+}
@@ -0,0 +1,777 @@
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::strategy::{BoxedStrategy, NewTree, Strategy, ValueTree};
+use proptest::test_runner::{Reason, TestRunner};
+
+use crate::{
+    Con, Generic, Leaf, List, Meta, Opaque, Prod, ReprError, Sensitive, Singleton, Sum, Unit, Void,
+};
+
+/// The maximum length of generated lists.
+pub const MAX_STRATEGY_LEN: usize = 8;
+
+/// A tree of candidate values, like `proptest::strategy::ValueTree`, but without requiring `Debug`,
+/// since representations don't implement it.
+pub trait GValueTree {
+    /// The type of the values produced.
+    type Value;
+
+    /// The current value.
+    fn current(&self) -> Self::Value;
+
+    /// Attempts to simplify the current value, returning whether it changed.
+    fn simplify(&mut self) -> bool;
+
+    /// Attempts to partially undo the last simplification, returning whether it changed.
+    fn complicate(&mut self) -> bool;
+}
+
+/// Generates representations for property tests, composing the strategies of their leaves.
+///
+/// Generation chooses the variant of each sum uniformly, and the length of each list up to `MAX_STRATEGY_LEN`.
+/// Shrinking tries earlier variants of sums first, then removing elements from lists, then simplifying each field,
+/// element, or leaf in turn. Leaves use `proptest::arbitrary::any`, unless overridden with `FieldStrategy`.
+///
+/// `#[derive(GenericStrategy)]` uses this to implement `proptest::arbitrary::Arbitrary`, alongside `#[derive(Generic)]`.
+/// Each field may override its strategy with `#[generic(strategy = "...")]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::GenericStrategy;
+/// use generics::Generic;
+/// use proptest::prelude::*;
+///
+/// #[derive(Generic, GenericStrategy, Debug)]
+/// enum Shape {
+///     Circle { radius: u8 },
+///     Rect {
+///         #[generic(strategy = "1..10u32")]
+///         width: u32,
+///         height: u32,
+///     },
+/// }
+///
+/// proptest!(|(shape: Shape)| {
+///     if let Shape::Rect { width, .. } = shape {
+///         prop_assert!((1..10).contains(&width));
+///     }
+/// });
+/// ```
+pub trait GStrategy: Sized {
+    /// The tree of candidate representations.
+    type Tree: GValueTree<Value = Self>;
+
+    /// Generates a tree of candidate representations with `runner`.
+    fn gnew_tree(runner: &mut TestRunner) -> Result<Self::Tree, Reason>;
+}
+
+/// Generates a variant of a `Sum` chain by index.
+pub trait GStrategyVariant: GStrategy {
+    /// The number of variants.
+    fn variant_count() -> usize;
+
+    /// Generates the variant at `index`, which must be less than `variant_count()`.
+    fn gnew_variant_tree(index: usize, runner: &mut TestRunner) -> Result<Self::Tree, Reason>;
+}
+
+/// Overrides the strategy for a field, identified by its selector.
+///
+/// `#[derive(GenericStrategy)]` implements this for each field with `#[generic(strategy = "...")]`.
+pub trait FieldStrategy<S> {
+    /// The type of the field.
+    type Value: Debug;
+
+    /// The strategy for the field.
+    fn field_strategy() -> BoxedStrategy<Self::Value>;
+}
+
+/// A field generated by the strategy `T` overrides it with, in place of its representation.
+pub struct Override<T, S>(pub T::Value, pub PhantomData<S>)
+where
+    T: FieldStrategy<S>;
+
+impl GValueTree for Unit {
+    type Value = Unit;
+
+    fn current(&self) -> Self::Value {
+        Unit
+    }
+
+    fn simplify(&mut self) -> bool {
+        false
+    }
+
+    fn complicate(&mut self) -> bool {
+        false
+    }
+}
+
+impl GStrategy for Unit {
+    type Tree = Unit;
+
+    fn gnew_tree(_runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        Ok(Unit)
+    }
+}
+
+/// Simplifies the left side as far as possible, then the right.
+pub struct ProdTree<A, B> {
+    a: A,
+    b: B,
+    shrinking: usize,
+    last: Option<usize>,
+}
+
+impl<A, B> GValueTree for ProdTree<A, B>
+where
+    A: GValueTree,
+    B: GValueTree,
+{
+    type Value = Prod<A::Value, B::Value>;
+
+    fn current(&self) -> Self::Value {
+        Prod(self.a.current(), self.b.current())
+    }
+
+    fn simplify(&mut self) -> bool {
+        while self.shrinking < 2 {
+            let simplified = match self.shrinking {
+                0 => self.a.simplify(),
+                _ => self.b.simplify(),
+            };
+            if simplified {
+                self.last = Some(self.shrinking);
+                return true;
+            }
+            self.shrinking += 1;
+        }
+        false
+    }
+
+    fn complicate(&mut self) -> bool {
+        let complicated = match self.last {
+            Some(0) => self.a.complicate(),
+            Some(_) => self.b.complicate(),
+            None => return false,
+        };
+        if !complicated {
+            self.last = None;
+        }
+        complicated
+    }
+}
+
+impl<A, B> GStrategy for Prod<A, B>
+where
+    A: GStrategy,
+    B: GStrategy,
+{
+    type Tree = ProdTree<A::Tree, B::Tree>;
+
+    fn gnew_tree(runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        Ok(ProdTree {
+            a: A::gnew_tree(runner)?,
+            b: B::gnew_tree(runner)?,
+            shrinking: 0,
+            last: None,
+        })
+    }
+}
+
+/// Holds the chosen variant, and when it isn't the first, a candidate of the first to simplify to.
+pub struct SumTree<L, R> {
+    left: L,
+    right: Option<R>,
+    on_left: bool,
+    switched: bool,
+}
+
+impl<L, R> GValueTree for SumTree<L, R>
+where
+    L: GValueTree,
+    R: GValueTree,
+{
+    type Value = Sum<L::Value, R::Value>;
+
+    fn current(&self) -> Self::Value {
+        match &self.right {
+            Some(right) if !self.on_left => Sum::Right(right.current()),
+            _ => Sum::Left(self.left.current()),
+        }
+    }
+
+    fn simplify(&mut self) -> bool {
+        match &mut self.right {
+            Some(right) if !self.on_left => {
+                if self.switched {
+                    right.simplify()
+                } else {
+                    self.on_left = true;
+                    self.switched = true;
+                    true
+                }
+            }
+            _ => {
+                self.switched = false;
+                self.left.simplify()
+            }
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        match &mut self.right {
+            Some(_) if self.on_left && self.switched => {
+                self.on_left = false;
+                true
+            }
+            Some(right) if !self.on_left => right.complicate(),
+            _ => self.left.complicate(),
+        }
+    }
+}
+
+impl<L, R> GStrategy for Sum<L, R>
+where
+    L: GStrategy,
+    R: GStrategyVariant,
+{
+    type Tree = SumTree<L::Tree, R::Tree>;
+
+    fn gnew_tree(runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        let index = (0..Self::variant_count()).new_tree(runner)?.current();
+        Self::gnew_variant_tree(index, runner)
+    }
+}
+
+impl<L, R> GStrategyVariant for Sum<L, R>
+where
+    L: GStrategy,
+    R: GStrategyVariant,
+{
+    fn variant_count() -> usize {
+        1 + R::variant_count()
+    }
+
+    fn gnew_variant_tree(index: usize, runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        let left = L::gnew_tree(runner)?;
+        let right = match index {
+            0 => None,
+            index => Some(R::gnew_variant_tree(index - 1, runner)?),
+        };
+        Ok(SumTree {
+            on_left: right.is_none(),
+            left,
+            right,
+            switched: false,
+        })
+    }
+}
+
+impl GValueTree for Void {
+    type Value = Void;
+
+    fn current(&self) -> Self::Value {
+        match *self {}
+    }
+
+    fn simplify(&mut self) -> bool {
+        match *self {}
+    }
+
+    fn complicate(&mut self) -> bool {
+        match *self {}
+    }
+}
+
+/// Fails to generate, since `Void` has no values.
+impl GStrategy for Void {
+    type Tree = Void;
+
+    fn gnew_tree(_runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        Err(Reason::from(
+            "cannot generate a value of an uninhabited type",
+        ))
+    }
+}
+
+impl GStrategyVariant for Void {
+    fn variant_count() -> usize {
+        0
+    }
+
+    fn gnew_variant_tree(_index: usize, runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        Self::gnew_tree(runner)
+    }
+}
+
+impl<I, M> GValueTree for Meta<I, M>
+where
+    I: GValueTree,
+    M: Singleton,
+{
+    type Value = Meta<I::Value, M>;
+
+    fn current(&self) -> Self::Value {
+        Meta(self.0.current(), PhantomData)
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.0.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.0.complicate()
+    }
+}
+
+impl<I, M> GStrategy for Meta<I, M>
+where
+    I: GStrategy,
+    M: Singleton,
+{
+    type Tree = Meta<I::Tree, M>;
+
+    fn gnew_tree(runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        Ok(Meta(I::gnew_tree(runner)?, PhantomData))
+    }
+}
+
+impl<I, C> GValueTree for Con<I, C>
+where
+    I: GValueTree,
+    C: Singleton,
+{
+    type Value = Con<I::Value, C>;
+
+    fn current(&self) -> Self::Value {
+        Con(self.0.current(), PhantomData)
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.0.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.0.complicate()
+    }
+}
+
+impl<I, C> GStrategy for Con<I, C>
+where
+    I: GStrategy,
+    C: Singleton,
+{
+    type Tree = Con<I::Tree, C>;
+
+    fn gnew_tree(runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        Ok(Con(I::gnew_tree(runner)?, PhantomData))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum ListShrink {
+    Remove(usize),
+    Simplify(usize),
+}
+
+/// Removes each element in turn, then simplifies each remaining element.
+pub struct ListTree<A> {
+    items: Vec<A>,
+    included: Vec<bool>,
+    shrink: ListShrink,
+    last: Option<ListShrink>,
+}
+
+impl<A> GValueTree for ListTree<A>
+where
+    A: GValueTree,
+{
+    type Value = List<A::Value>;
+
+    fn current(&self) -> Self::Value {
+        let items = self.items.iter().zip(&self.included);
+        List(
+            items
+                .filter(|(_, &included)| included)
+                .map(|(item, _)| item.current())
+                .collect(),
+        )
+    }
+
+    fn simplify(&mut self) -> bool {
+        if let ListShrink::Remove(i) = self.shrink {
+            if i < self.items.len() {
+                self.included[i] = false;
+                self.last = Some(self.shrink);
+                self.shrink = ListShrink::Remove(i + 1);
+                return true;
+            }
+            self.shrink = ListShrink::Simplify(0);
+        }
+        while let ListShrink::Simplify(i) = self.shrink {
+            if i >= self.items.len() {
+                return false;
+            }
+            if self.included[i] && self.items[i].simplify() {
+                self.last = Some(self.shrink);
+                return true;
+            }
+            self.shrink = ListShrink::Simplify(i + 1);
+        }
+        false
+    }
+
+    fn complicate(&mut self) -> bool {
+        match self.last {
+            Some(ListShrink::Remove(i)) => {
+                self.included[i] = true;
+                self.last = None;
+                true
+            }
+            Some(ListShrink::Simplify(i)) => {
+                let complicated = self.items[i].complicate();
+                if !complicated {
+                    self.last = None;
+                }
+                complicated
+            }
+            None => false,
+        }
+    }
+}
+
+impl<A> GStrategy for List<A>
+where
+    A: GStrategy,
+{
+    type Tree = ListTree<A::Tree>;
+
+    fn gnew_tree(runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        let len = (0..=MAX_STRATEGY_LEN).new_tree(runner)?.current();
+        let items = (0..len)
+            .map(|_| A::gnew_tree(runner))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ListTree {
+            included: vec![true; len],
+            items,
+            shrink: ListShrink::Remove(0),
+            last: None,
+        })
+    }
+}
+
+/// Simplifies each item as far as possible, in order.
+pub struct ArrayTree<A, const N: usize> {
+    items: [A; N],
+    shrinking: usize,
+    last: Option<usize>,
+}
+
+impl<A, const N: usize> GValueTree for ArrayTree<A, N>
+where
+    A: GValueTree,
+{
+    type Value = [A::Value; N];
+
+    fn current(&self) -> Self::Value {
+        let mut items = self.items.iter();
+        [(); N].map(|()| items.next().unwrap().current())
+    }
+
+    fn simplify(&mut self) -> bool {
+        while self.shrinking < N {
+            if self.items[self.shrinking].simplify() {
+                self.last = Some(self.shrinking);
+                return true;
+            }
+            self.shrinking += 1;
+        }
+        false
+    }
+
+    fn complicate(&mut self) -> bool {
+        let complicated = match self.last {
+            Some(i) => self.items[i].complicate(),
+            None => return false,
+        };
+        if !complicated {
+            self.last = None;
+        }
+        complicated
+    }
+}
+
+impl<A, const N: usize> GStrategy for [A; N]
+where
+    A: GStrategy,
+{
+    type Tree = ArrayTree<A::Tree, N>;
+
+    fn gnew_tree(runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        let mut error = None;
+        let items = [(); N].map(|()| match A::gnew_tree(runner) {
+            Ok(item) => Some(item),
+            Err(e) => {
+                error.get_or_insert(e);
+                None
+            }
+        });
+        match error {
+            Some(e) => Err(e),
+            None => Ok(ArrayTree {
+                items: items.map(Option::unwrap),
+                shrinking: 0,
+                last: None,
+            }),
+        }
+    }
+}
+
+/// Adapts a `proptest::strategy::ValueTree` for a leaf.
+pub struct LeafTree<V>(V);
+
+impl<V> GValueTree for LeafTree<V>
+where
+    V: ValueTree,
+{
+    type Value = V::Value;
+
+    fn current(&self) -> Self::Value {
+        self.0.current()
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.0.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.0.complicate()
+    }
+}
+
+impl<T> GStrategy for T
+where
+    T: Leaf + Arbitrary,
+{
+    type Tree = LeafTree<<T::Strategy as Strategy>::Tree>;
+
+    fn gnew_tree(runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        any::<T>().new_tree(runner).map(LeafTree)
+    }
+}
+
+/// Adapts a `proptest::strategy::ValueTree` for the value of a wrapping leaf.
+pub struct WrapperTree<V, W>
+where
+    V: ValueTree,
+{
+    tree: V,
+    wrap: fn(V::Value) -> W,
+}
+
+impl<V, W> GValueTree for WrapperTree<V, W>
+where
+    V: ValueTree,
+{
+    type Value = W;
+
+    fn current(&self) -> Self::Value {
+        (self.wrap)(self.tree.current())
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.tree.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.tree.complicate()
+    }
+}
+
+impl<T> GStrategy for Opaque<T>
+where
+    T: Arbitrary,
+{
+    type Tree = WrapperTree<<T::Strategy as Strategy>::Tree, Self>;
+
+    fn gnew_tree(runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        let tree = any::<T>().new_tree(runner)?;
+        Ok(WrapperTree { tree, wrap: Opaque })
+    }
+}
+
+impl<T> GStrategy for Sensitive<T>
+where
+    T: Arbitrary,
+{
+    type Tree = WrapperTree<<T::Strategy as Strategy>::Tree, Self>;
+
+    fn gnew_tree(runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        let tree = any::<T>().new_tree(runner)?;
+        Ok(WrapperTree {
+            tree,
+            wrap: Sensitive,
+        })
+    }
+}
+
+/// Adapts the tree of a field's overriding strategy.
+pub struct OverrideTree<T, S>(Box<dyn ValueTree<Value = T::Value>>, PhantomData<S>)
+where
+    T: FieldStrategy<S>;
+
+impl<T, S> GValueTree for OverrideTree<T, S>
+where
+    T: FieldStrategy<S>,
+{
+    type Value = Override<T, S>;
+
+    fn current(&self) -> Self::Value {
+        Override(self.0.current(), PhantomData)
+    }
+
+    fn simplify(&mut self) -> bool {
+        self.0.simplify()
+    }
+
+    fn complicate(&mut self) -> bool {
+        self.0.complicate()
+    }
+}
+
+impl<T, S> GStrategy for Override<T, S>
+where
+    T: FieldStrategy<S>,
+{
+    type Tree = OverrideTree<T, S>;
+
+    fn gnew_tree(runner: &mut TestRunner) -> Result<Self::Tree, Reason> {
+        Ok(OverrideTree(
+            T::field_strategy().new_tree(runner)?,
+            PhantomData,
+        ))
+    }
+}
+
+/// A `proptest::strategy::Strategy` for `T`, generating representations of type `R` and converting them.
+///
+/// Representations which `convert` rejects are discarded, like `Strategy::prop_filter_map`.
+pub struct ReprStrategy<T, R> {
+    convert: fn(R) -> Result<T, ReprError>,
+}
+
+impl<T, R> ReprStrategy<T, R> {
+    /// Converts each generated representation with `convert`.
+    pub fn new(convert: fn(R) -> Result<T, ReprError>) -> Self {
+        ReprStrategy { convert }
+    }
+}
+
+impl<T, R> Debug for ReprStrategy<T, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReprStrategy").finish_non_exhaustive()
+    }
+}
+
+impl<T, R> Strategy for ReprStrategy<T, R>
+where
+    T: Debug,
+    R: GStrategy,
+{
+    type Tree = ReprTree<T, R::Tree>;
+    type Value = T;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        loop {
+            let tree = R::gnew_tree(runner)?;
+            match (self.convert)(tree.current()) {
+                Ok(_) => {
+                    return Ok(ReprTree {
+                        tree,
+                        convert: self.convert,
+                    })
+                }
+                Err(e) => runner.reject_local(e.to_string())?,
+            }
+        }
+    }
+}
+
+/// The `proptest::strategy::ValueTree` of a `ReprStrategy`.
+pub struct ReprTree<T, V>
+where
+    V: GValueTree,
+{
+    tree: V,
+    convert: fn(V::Value) -> Result<T, ReprError>,
+}
+
+impl<T, V> ReprTree<T, V>
+where
+    V: GValueTree,
+{
+    /// Complicates the tree until its current representation converts, as `prop_filter` does.
+    fn ensure_acceptable(&mut self) {
+        while (self.convert)(self.tree.current()).is_err() {
+            assert!(
+                self.tree.complicate(),
+                "unable to complicate the representation back into an acceptable value"
+            );
+        }
+    }
+}
+
+impl<T, V> ValueTree for ReprTree<T, V>
+where
+    T: Debug,
+    V: GValueTree,
+{
+    type Value = T;
+
+    fn current(&self) -> Self::Value {
+        match (self.convert)(self.tree.current()) {
+            Ok(value) => value,
+            Err(e) => unreachable!("rejected representations are never current: {}", e),
+        }
+    }
+
+    fn simplify(&mut self) -> bool {
+        if self.tree.simplify() {
+            self.ensure_acceptable();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        if self.tree.complicate() {
+            self.ensure_acceptable();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A strategy for `T`, generating its representation with `GStrategy`.
+///
+/// ```rust
+/// use generics::ops::generic_strategy;
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+///
+/// let mut runner = TestRunner::deterministic();
+/// let tree = generic_strategy::<(bool, Vec<u8>)>().new_tree(&mut runner).unwrap();
+/// assert!(tree.current().1.len() <= 8);
+/// ```
+pub fn generic_strategy<T>() -> ReprStrategy<T, T::Repr>
+where
+    T: Generic + Debug,
+    T::Repr: GStrategy,
+{
+    ReprStrategy::new(T::checked_from_repr)
+}
@@ -0,0 +1,245 @@
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+use super::GPartialEq;
+
+/// Erases the metadata of a representation, i.e. every `Meta` and `Con` layer, leaving only its structure.
+///
+/// Two types whose representations differ only in the names of their fields and constructors
+/// have the same stripped representation, e.g. a type and its counterpart generated by another tool.
+/// This is the type-level function `T::Repr` to `<T::Repr as GStrip>::Stripped`, along with its inverse,
+/// which restores the metadata, given the type it came from.
+///
+/// `strip` and `unstrip` apply this to `Generic` types, `structural_convert` converts between types
+/// with the same stripped representation, and `structural_eq` compares them with `GPartialEq`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{structural_convert, structural_eq};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Vec2(i32, i32);
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum Shape {
+///     Dot(Point),
+///     Line { from: Point, to: Point },
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum GeneratedShape {
+///     ShapeDot(Vec2),
+///     ShapeLine(Vec2, Vec2),
+/// }
+///
+/// let line = Shape::Line { from: Point { x: 0, y: 0 }, to: Point { x: 1, y: 2 } };
+/// assert!(structural_eq(line, GeneratedShape::ShapeLine(Vec2(0, 0), Vec2(1, 2))));
+///
+/// let dot = structural_convert::<_, GeneratedShape>(Shape::Dot(Point { x: 3, y: 4 }));
+/// assert_eq!(dot, GeneratedShape::ShapeDot(Vec2(3, 4)));
+/// ```
+pub trait GStrip {
+    /// The representation with its metadata erased.
+    type Stripped;
+
+    /// Erases the metadata of `self`.
+    fn gstrip(self) -> Self::Stripped;
+
+    /// Restores the metadata of `stripped`.
+    fn gunstrip(stripped: Self::Stripped) -> Self;
+}
+
+impl GStrip for Unit {
+    type Stripped = Unit;
+
+    fn gstrip(self) -> Self::Stripped {
+        Unit
+    }
+
+    fn gunstrip(_stripped: Self::Stripped) -> Self {
+        Unit
+    }
+}
+
+impl<A, B> GStrip for Prod<A, B>
+where
+    A: GStrip,
+    B: GStrip,
+{
+    type Stripped = Prod<A::Stripped, B::Stripped>;
+
+    fn gstrip(self) -> Self::Stripped {
+        let Prod(a, b) = self;
+        Prod(a.gstrip(), b.gstrip())
+    }
+
+    fn gunstrip(stripped: Self::Stripped) -> Self {
+        let Prod(a, b) = stripped;
+        Prod(A::gunstrip(a), B::gunstrip(b))
+    }
+}
+
+impl<L, R> GStrip for Sum<L, R>
+where
+    L: GStrip,
+    R: GStrip,
+{
+    type Stripped = Sum<L::Stripped, R::Stripped>;
+
+    fn gstrip(self) -> Self::Stripped {
+        match self {
+            Sum::Left(l) => Sum::Left(l.gstrip()),
+            Sum::Right(r) => Sum::Right(r.gstrip()),
+        }
+    }
+
+    fn gunstrip(stripped: Self::Stripped) -> Self {
+        match stripped {
+            Sum::Left(l) => Sum::Left(L::gunstrip(l)),
+            Sum::Right(r) => Sum::Right(R::gunstrip(r)),
+        }
+    }
+}
+
+impl GStrip for Void {
+    type Stripped = Void;
+
+    fn gstrip(self) -> Self::Stripped {
+        match self {}
+    }
+
+    fn gunstrip(stripped: Self::Stripped) -> Self {
+        match stripped {}
+    }
+}
+
+impl<I, M> GStrip for Meta<I, M>
+where
+    I: GStrip,
+    M: Singleton,
+{
+    type Stripped = I::Stripped;
+
+    fn gstrip(self) -> Self::Stripped {
+        let Meta(inner, _) = self;
+        inner.gstrip()
+    }
+
+    fn gunstrip(stripped: Self::Stripped) -> Self {
+        Meta(I::gunstrip(stripped), PhantomData)
+    }
+}
+
+impl<I, C> GStrip for Con<I, C>
+where
+    I: GStrip,
+    C: Singleton,
+{
+    type Stripped = I::Stripped;
+
+    fn gstrip(self) -> Self::Stripped {
+        let Con(inner, _) = self;
+        inner.gstrip()
+    }
+
+    fn gunstrip(stripped: Self::Stripped) -> Self {
+        Con(I::gunstrip(stripped), PhantomData)
+    }
+}
+
+impl<A> GStrip for List<A>
+where
+    A: GStrip,
+{
+    type Stripped = List<A::Stripped>;
+
+    fn gstrip(self) -> Self::Stripped {
+        let List(items) = self;
+        List(items.into_iter().map(GStrip::gstrip).collect())
+    }
+
+    fn gunstrip(stripped: Self::Stripped) -> Self {
+        let List(items) = stripped;
+        List(items.into_iter().map(A::gunstrip).collect())
+    }
+}
+
+impl<A, const N: usize> GStrip for [A; N]
+where
+    A: GStrip,
+{
+    type Stripped = [A::Stripped; N];
+
+    fn gstrip(self) -> Self::Stripped {
+        self.map(GStrip::gstrip)
+    }
+
+    fn gunstrip(stripped: Self::Stripped) -> Self {
+        stripped.map(A::gunstrip)
+    }
+}
+
+impl<T> GStrip for T
+where
+    T: Leaf,
+{
+    type Stripped = T;
+
+    fn gstrip(self) -> Self::Stripped {
+        self
+    }
+
+    fn gunstrip(stripped: Self::Stripped) -> Self {
+        stripped
+    }
+}
+
+/// Converts `x` into its representation, with its metadata erased.
+pub fn strip<T>(x: T) -> <T::Repr as GStrip>::Stripped
+where
+    T: Generic,
+    T::Repr: GStrip,
+{
+    x.into_repr().gstrip()
+}
+
+/// Constructs a `T` from its representation with its metadata erased.
+pub fn unstrip<T>(stripped: <T::Repr as GStrip>::Stripped) -> T
+where
+    T: Generic,
+    T::Repr: GStrip,
+{
+    T::from_repr(<T::Repr as GStrip>::gunstrip(stripped))
+}
+
+/// Converts `x` into a `U` with the same structure, ignoring the names of fields and constructors.
+pub fn structural_convert<T, U>(x: T) -> U
+where
+    T: Generic,
+    T::Repr: GStrip,
+    U: Generic,
+    U::Repr: GStrip<Stripped = <T::Repr as GStrip>::Stripped>,
+{
+    unstrip(strip(x))
+}
+
+/// Whether `a` and `b`, with the same structure, are equal, ignoring the names of fields and constructors.
+pub fn structural_eq<T, U>(a: T, b: U) -> bool
+where
+    T: Generic,
+    T::Repr: GStrip,
+    U: Generic,
+    U::Repr: GStrip<Stripped = <T::Repr as GStrip>::Stripped>,
+    <T::Repr as GStrip>::Stripped: GPartialEq,
+{
+    strip(a).geq(&strip(b))
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use crate::Generic;
+
+use super::{flatten, GFlatten};
+
+/// An error rendering a template with `render`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    /// A placeholder named no field of the value.
+    Unknown(String),
+    /// A `{` wasn't closed by a `}`, starting at the given byte offset into the template.
+    Unclosed(usize),
+    /// A `}` wasn't part of a placeholder or escaped as `}}`, at the given byte offset into the template.
+    Unopened(usize),
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::Unknown(path) => write!(f, "unknown field `{}` in template", path),
+            TemplateError::Unclosed(offset) => write!(f, "unclosed `{{` at offset {}", offset),
+            TemplateError::Unopened(offset) => write!(f, "unmatched `}}` at offset {}", offset),
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+/// Renders `template`, replacing each placeholder, e.g. `{name}`, with the field of `x` it names.
+///
+/// Placeholders are paths as produced by `flatten`, so nested fields are named like `{address.city}`,
+/// list items and tuple elements like `{items.0}`, and the fields of a variant like `{contact.0}`. A placeholder naming a sum,
+/// e.g. an enum or `Option` field, renders the name of its variant. Leaves are formatted with `Display`.
+/// Like `format!`, `{{` and `}}` render literal braces.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{render, TemplateError};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Clone)]
+/// struct User {
+///     name: String,
+///     age: u8,
+///     plan: Plan,
+///     nickname: Option<String>,
+/// }
+///
+/// #[derive(Generic, Clone)]
+/// enum Plan {
+///     Free,
+///     Pro { seats: u32 },
+/// }
+///
+/// let user = User {
+///     name: "Ada".to_string(),
+///     age: 36,
+///     plan: Plan::Pro { seats: 5 },
+///     nickname: Some("ada".to_string()),
+/// };
+/// assert_eq!(
+///     render("Hello {name}, you are {age}", user.clone()).unwrap(),
+///     "Hello Ada, you are 36"
+/// );
+/// assert_eq!(
+///     render("{plan} plan ({plan.seats} seats), aka {nickname.0} {{admin}}", user.clone()).unwrap(),
+///     "Pro plan (5 seats), aka ada {admin}"
+/// );
+/// assert_eq!(render("{email}", user), Err(TemplateError::Unknown("email".to_string())));
+/// ```
+pub fn render<T>(template: &str, x: T) -> Result<String, TemplateError>
+where
+    T: Generic,
+    T::Repr: GFlatten,
+{
+    let fields = flatten(x).into_iter().collect::<HashMap<_, _>>();
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(i) = rest.find(['{', '}']) {
+        let offset = template.len() - rest.len() + i;
+        out.push_str(&rest[..i]);
+        let brace = &rest[i..];
+        if let Some(after) = brace
+            .strip_prefix("{{")
+            .or_else(|| brace.strip_prefix("}}"))
+        {
+            out.push_str(&brace[..1]);
+            rest = after;
+        } else if brace.starts_with('}') {
+            return Err(TemplateError::Unopened(offset));
+        } else {
+            let end = brace.find('}').ok_or(TemplateError::Unclosed(offset))?;
+            let path = brace[1..end].trim();
+            match fields.get(path) {
+                Some(value) => out.push_str(value),
+                None => return Err(TemplateError::Unknown(path.to_string())),
+            }
+            rest = &brace[end + 1..];
+        }
+    }
+    out.push_str(rest);
+    Ok(out)
+}
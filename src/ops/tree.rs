@@ -0,0 +1,649 @@
+use std::any::TypeId;
+use std::convert::TryInto;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::vec;
+
+use crate::markers::{Option_None_Name, Option_Some_Name};
+use crate::{Con, ConKind, Constructor, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+use super::flatten::push_segment;
+
+/// A self-describing tree of values, like a JSON value or a CBOR data item, shared by `GToJson`, `GToCbor`, etc.
+///
+/// Representations are converted to trees in the layout of serde's derive: structs are objects keyed by field name,
+/// tuple structs are arrays, or their only field if they have one, and unit structs are `null`.
+/// Variants are laid out the same, as the only value of an object keyed by their name, or just their name if they're
+/// unit variants. `Option` is `null` or its value, and tuples, arrays, and lists are arrays.
+/// Trees differ only in how they build and take apart these values, how they convert leaves, and how they fail.
+pub trait Tree: Sized {
+    /// The objects of the tree, which fields are removed from by name.
+    type Object;
+
+    /// An error converting a leaf to a tree.
+    type EncodeError;
+
+    /// An error converting a tree to a representation.
+    type Error;
+
+    /// A description of a string, for errors, e.g. `"a string"`.
+    const STRING: &'static str;
+
+    /// A description of an object, for errors, e.g. `"an object"`.
+    const OBJECT: &'static str;
+
+    /// Returns `null`.
+    fn null() -> Self;
+
+    /// Returns the string `s`.
+    fn string(s: &str) -> Self;
+
+    /// Returns an array of `items`.
+    fn array(items: Vec<Self>) -> Self;
+
+    /// Returns an object of `fields`, keyed by their names, in order.
+    fn object(fields: Vec<(&'static str, Self)>) -> Self;
+
+    /// Whether `self` is `null`.
+    fn is_null(&self) -> bool;
+
+    /// Returns the items of `self`, or `self` if it isn't an array.
+    fn into_array(self) -> Result<Vec<Self>, Self>;
+
+    /// Returns the string `self`, or `self` if it isn't a string.
+    fn into_string(self) -> Result<String, Self>;
+
+    /// Returns the object `self`, or `self` if it isn't an object.
+    fn into_object(self) -> Result<Self::Object, Self>;
+
+    /// Removes the value of the field named `name` from `object`.
+    fn remove(object: &mut Self::Object, name: &str) -> Option<Self>;
+
+    /// Returns the only key of `object` and its value, or `None` if it has more or fewer keys, or a key isn't a string.
+    fn into_entry(object: Self::Object) -> Option<(String, Self)>;
+
+    /// The error for a field with no value at `path`.
+    fn missing(path: &str) -> Self::Error;
+
+    /// The error for a value at `path` which wasn't what was expected.
+    fn invalid(path: &str, expected: &dyn Display) -> Self::Error;
+
+    /// The error for a variant at `path` named `name`, which its sum has no variant of.
+    fn variant(path: &str, name: String) -> Self::Error;
+}
+
+/// A leaf which can be converted to the tree `V`.
+pub trait ToTreeLeaf<V: Tree> {
+    /// Converts `self` to a tree.
+    fn to_tree(self) -> Result<V, V::EncodeError>;
+}
+
+/// A leaf which can be converted from the tree `V`.
+pub trait FromTreeLeaf<V: Tree>: Sized {
+    /// Converts `tree`, found at `path`, to `Self`.
+    fn from_tree(tree: V, path: &str) -> Result<Self, V::Error>;
+}
+
+/// Converts a representation to the tree `V`, node by node.
+pub trait GToTree<V: Tree> {
+    /// Converts `self` to a tree.
+    fn gto_tree(self) -> Result<V, V::EncodeError>;
+}
+
+/// Converts the elements of a `Prod` chain to trees.
+pub trait GToTreeElements<V: Tree> {
+    /// Appends the tree of each element, in order.
+    fn gto_elements(self, items: &mut Vec<V>) -> Result<(), V::EncodeError>;
+}
+
+/// Converts the fields of a constructor, i.e. a `Prod` chain of `Meta`s, to trees.
+pub trait GToTreeFields<V: Tree> {
+    /// Appends the name and tree of each field, in order.
+    fn gto_fields(self, fields: &mut Vec<(&'static str, V)>) -> Result<(), V::EncodeError>;
+}
+
+/// Converts whichever variant of a `Sum` chain is held to a tree.
+pub trait GToTreeVariant<V: Tree> {
+    /// Converts the held variant to a tree.
+    fn gto_variant(self) -> Result<V, V::EncodeError>;
+}
+
+/// Converts the tree `V` to a representation, node by node.
+///
+/// Like serde's derive, structs can also be converted from arrays, unknown fields are ignored,
+/// and missing fields are an error, unless they can be converted from `null`, e.g. `Option`s.
+pub trait GFromTree<V: Tree>: Sized {
+    /// Converts `tree`, found at `path`, to `Self`.
+    fn gfrom_tree(tree: V, path: &mut String) -> Result<Self, V::Error>;
+}
+
+/// Converts the elements of a `Prod` chain from trees.
+pub trait GFromTreeElements<V: Tree>: Sized {
+    /// The number of elements.
+    const LEN: usize;
+
+    /// Converts each element from the next of `items`, which holds at least `LEN` trees.
+    fn gfrom_elements(
+        items: &mut vec::IntoIter<V>,
+        index: usize,
+        path: &mut String,
+    ) -> Result<Self, V::Error>;
+}
+
+/// Converts the fields of a constructor, i.e. a `Prod` chain of `Meta`s, from trees.
+pub trait GFromTreeFields<V: Tree>: Sized {
+    /// The number of fields.
+    const LEN: usize;
+
+    /// Converts each field from the value of the same name in `object`.
+    fn gfrom_object(object: &mut V::Object, path: &mut String) -> Result<Self, V::Error>;
+
+    /// Converts each field from the next of `items`, which holds at least `LEN` trees.
+    fn gfrom_array(items: &mut vec::IntoIter<V>, path: &mut String) -> Result<Self, V::Error>;
+
+    /// Converts the only field from `tree`, for newtype structs and variants.
+    fn gfrom_first(tree: V, path: &mut String) -> Result<Self, V::Error>;
+}
+
+/// Converts the variant of a `Sum` chain with a given name from a tree.
+pub trait GFromTreeVariant<V: Tree>: Sized {
+    /// Whether the chain is the representation of an `Option`.
+    fn is_option() -> bool;
+
+    /// Converts the variant named `name` from `tree`, or `None` if it's a unit variant,
+    /// or returns `None` if there is no such variant.
+    fn gfrom_variant(
+        name: &str,
+        tree: Option<V>,
+        path: &mut String,
+    ) -> Option<Result<Self, V::Error>>;
+}
+
+/// Converts the fields of the constructor `C` to a tree, or `None` if it's a unit struct or variant.
+fn fields_tree<V, I, C>(inner: I) -> Result<Option<V>, V::EncodeError>
+where
+    V: Tree,
+    I: GToTreeFields<V>,
+    C: Constructor,
+{
+    let mut fields = Vec::new();
+    inner.gto_fields(&mut fields)?;
+    Ok(match C::con_kind() {
+        ConKind::Unit => None,
+        ConKind::Tuple if fields.len() == 1 => fields.pop().map(|(_, tree)| tree),
+        ConKind::Tuple => Some(V::array(fields.into_iter().map(|(_, tree)| tree).collect())),
+        ConKind::Braced => Some(V::object(fields)),
+    })
+}
+
+/// Converts the fields of the constructor `C` from a tree, or `None` if it's a unit variant.
+fn fields_from_tree<V, I, C>(tree: Option<V>, path: &mut String) -> Result<I, V::Error>
+where
+    V: Tree,
+    I: GFromTreeFields<V>,
+    C: Constructor,
+{
+    let kind = C::con_kind();
+    let tree = match tree {
+        None if kind == ConKind::Unit => return I::gfrom_array(&mut Vec::new().into_iter(), path),
+        None => return Err(V::invalid(path, &"a value")),
+        Some(tree) => tree,
+    };
+    match kind {
+        ConKind::Unit if tree.is_null() => I::gfrom_array(&mut Vec::new().into_iter(), path),
+        ConKind::Unit => Err(V::invalid(path, &"null")),
+        ConKind::Tuple if I::LEN == 1 => I::gfrom_first(tree, path),
+        _ => match tree.into_array() {
+            Ok(items) if items.len() == I::LEN => I::gfrom_array(&mut items.into_iter(), path),
+            Err(tree) if kind == ConKind::Braced => match tree.into_object() {
+                Ok(mut object) => I::gfrom_object(&mut object, path),
+                Err(_) => Err(V::invalid(path, &V::OBJECT)),
+            },
+            _ if kind == ConKind::Braced => Err(V::invalid(path, &V::OBJECT)),
+            _ => Err(V::invalid(
+                path,
+                &format_args!("an array of length {}", I::LEN),
+            )),
+        },
+    }
+}
+
+impl<V> GToTree<V> for Unit
+where
+    V: Tree,
+{
+    fn gto_tree(self) -> Result<V, V::EncodeError> {
+        Ok(V::null())
+    }
+}
+
+impl<V, A, B> GToTree<V> for Prod<A, B>
+where
+    V: Tree,
+    Self: GToTreeElements<V>,
+{
+    fn gto_tree(self) -> Result<V, V::EncodeError> {
+        let mut items = Vec::new();
+        self.gto_elements(&mut items)?;
+        Ok(V::array(items))
+    }
+}
+
+impl<V> GToTreeElements<V> for Unit
+where
+    V: Tree,
+{
+    fn gto_elements(self, _items: &mut Vec<V>) -> Result<(), V::EncodeError> {
+        Ok(())
+    }
+}
+
+impl<V, A, B> GToTreeElements<V> for Prod<A, B>
+where
+    V: Tree,
+    A: GToTree<V>,
+    B: GToTreeElements<V>,
+{
+    fn gto_elements(self, items: &mut Vec<V>) -> Result<(), V::EncodeError> {
+        let Prod(a, b) = self;
+        items.push(a.gto_tree()?);
+        b.gto_elements(items)
+    }
+}
+
+impl<V> GToTreeFields<V> for Unit
+where
+    V: Tree,
+{
+    fn gto_fields(self, _fields: &mut Vec<(&'static str, V)>) -> Result<(), V::EncodeError> {
+        Ok(())
+    }
+}
+
+impl<V, A, M, B> GToTreeFields<V> for Prod<Meta<A, M>, B>
+where
+    V: Tree,
+    A: GToTree<V>,
+    M: Singleton<T = &'static str>,
+    B: GToTreeFields<V>,
+{
+    fn gto_fields(self, fields: &mut Vec<(&'static str, V)>) -> Result<(), V::EncodeError> {
+        let Prod(Meta(a, _), b) = self;
+        fields.push((M::get(), a.gto_tree()?));
+        b.gto_fields(fields)
+    }
+}
+
+impl<V, L, R> GToTree<V> for Sum<L, R>
+where
+    V: Tree,
+    Self: GToTreeVariant<V>,
+{
+    fn gto_tree(self) -> Result<V, V::EncodeError> {
+        self.gto_variant()
+    }
+}
+
+impl<V, I, C, R> GToTreeVariant<V> for Sum<Con<I, C>, R>
+where
+    V: Tree,
+    I: GToTreeFields<V>,
+    C: Singleton<T = &'static str> + Constructor + 'static,
+    R: GToTreeVariant<V>,
+{
+    fn gto_variant(self) -> Result<V, V::EncodeError> {
+        let Con(inner, _) = match self {
+            Sum::Left(con) => con,
+            Sum::Right(r) => return r.gto_variant(),
+        };
+        let tree = fields_tree::<V, I, C>(inner)?;
+        Ok(match tree {
+            _ if TypeId::of::<C>() == TypeId::of::<Option_None_Name>() => V::null(),
+            Some(tree) if TypeId::of::<C>() == TypeId::of::<Option_Some_Name>() => tree,
+            None => V::string(C::get()),
+            Some(tree) => V::object(vec![(C::get(), tree)]),
+        })
+    }
+}
+
+impl<V> GToTreeVariant<V> for Void
+where
+    V: Tree,
+{
+    fn gto_variant(self) -> Result<V, V::EncodeError> {
+        match self {}
+    }
+}
+
+impl<V> GToTree<V> for Void
+where
+    V: Tree,
+{
+    fn gto_tree(self) -> Result<V, V::EncodeError> {
+        match self {}
+    }
+}
+
+impl<V, I, M> GToTree<V> for Meta<I, M>
+where
+    V: Tree,
+    I: GToTree<V>,
+    M: Singleton,
+{
+    fn gto_tree(self) -> Result<V, V::EncodeError> {
+        let Meta(inner, _) = self;
+        inner.gto_tree()
+    }
+}
+
+impl<V, I, C> GToTree<V> for Con<I, C>
+where
+    V: Tree,
+    I: GToTreeFields<V>,
+    C: Singleton + Constructor,
+{
+    fn gto_tree(self) -> Result<V, V::EncodeError> {
+        let Con(inner, _) = self;
+        Ok(fields_tree::<V, I, C>(inner)?.unwrap_or_else(V::null))
+    }
+}
+
+impl<V, A> GToTree<V> for List<A>
+where
+    V: Tree,
+    A: GToTree<V>,
+{
+    fn gto_tree(self) -> Result<V, V::EncodeError> {
+        let List(items) = self;
+        let items = items
+            .into_iter()
+            .map(A::gto_tree)
+            .collect::<Result<_, _>>()?;
+        Ok(V::array(items))
+    }
+}
+
+impl<V, A, const N: usize> GToTree<V> for [A; N]
+where
+    V: Tree,
+    A: GToTree<V>,
+{
+    fn gto_tree(self) -> Result<V, V::EncodeError> {
+        List(Vec::from(self)).gto_tree()
+    }
+}
+
+impl<V, T> GToTree<V> for T
+where
+    V: Tree,
+    T: Leaf + ToTreeLeaf<V>,
+{
+    fn gto_tree(self) -> Result<V, V::EncodeError> {
+        self.to_tree()
+    }
+}
+
+impl<V> GFromTree<V> for Unit
+where
+    V: Tree,
+{
+    fn gfrom_tree(tree: V, path: &mut String) -> Result<Self, V::Error> {
+        match tree.is_null() {
+            true => Ok(Unit),
+            false => Err(V::invalid(path, &"null")),
+        }
+    }
+}
+
+impl<V, A, B> GFromTree<V> for Prod<A, B>
+where
+    V: Tree,
+    Self: GFromTreeElements<V>,
+{
+    fn gfrom_tree(tree: V, path: &mut String) -> Result<Self, V::Error> {
+        match tree.into_array() {
+            Ok(items) if items.len() == Self::LEN => {
+                Self::gfrom_elements(&mut items.into_iter(), 0, path)
+            }
+            _ => Err(V::invalid(
+                path,
+                &format_args!("an array of length {}", Self::LEN),
+            )),
+        }
+    }
+}
+
+impl<V> GFromTreeElements<V> for Unit
+where
+    V: Tree,
+{
+    const LEN: usize = 0;
+
+    fn gfrom_elements(
+        _items: &mut vec::IntoIter<V>,
+        _index: usize,
+        _path: &mut String,
+    ) -> Result<Self, V::Error> {
+        Ok(Unit)
+    }
+}
+
+impl<V, A, B> GFromTreeElements<V> for Prod<A, B>
+where
+    V: Tree,
+    A: GFromTree<V>,
+    B: GFromTreeElements<V>,
+{
+    const LEN: usize = 1 + B::LEN;
+
+    fn gfrom_elements(
+        items: &mut vec::IntoIter<V>,
+        index: usize,
+        path: &mut String,
+    ) -> Result<Self, V::Error> {
+        let tree = items.next().expect("checked the number of items");
+        let len = push_segment(path, &index);
+        let a = A::gfrom_tree(tree, path);
+        path.truncate(len);
+        Ok(Prod(a?, B::gfrom_elements(items, index + 1, path)?))
+    }
+}
+
+impl<V> GFromTreeFields<V> for Unit
+where
+    V: Tree,
+{
+    const LEN: usize = 0;
+
+    fn gfrom_object(_object: &mut V::Object, _path: &mut String) -> Result<Self, V::Error> {
+        Ok(Unit)
+    }
+
+    fn gfrom_array(_items: &mut vec::IntoIter<V>, _path: &mut String) -> Result<Self, V::Error> {
+        Ok(Unit)
+    }
+
+    fn gfrom_first(_tree: V, _path: &mut String) -> Result<Self, V::Error> {
+        Ok(Unit)
+    }
+}
+
+impl<V, A, M, B> GFromTreeFields<V> for Prod<Meta<A, M>, B>
+where
+    V: Tree,
+    A: GFromTree<V>,
+    M: Singleton<T = &'static str>,
+    B: GFromTreeFields<V>,
+{
+    const LEN: usize = 1 + B::LEN;
+
+    fn gfrom_object(object: &mut V::Object, path: &mut String) -> Result<Self, V::Error> {
+        let len = push_segment(path, &M::get());
+        let a = match V::remove(object, M::get()) {
+            Some(tree) => A::gfrom_tree(tree, path),
+            None => A::gfrom_tree(V::null(), path).map_err(|_| V::missing(path)),
+        };
+        path.truncate(len);
+        Ok(Prod(Meta(a?, PhantomData), B::gfrom_object(object, path)?))
+    }
+
+    fn gfrom_array(items: &mut vec::IntoIter<V>, path: &mut String) -> Result<Self, V::Error> {
+        let tree = items.next().expect("checked the number of items");
+        let len = push_segment(path, &M::get());
+        let a = A::gfrom_tree(tree, path);
+        path.truncate(len);
+        Ok(Prod(Meta(a?, PhantomData), B::gfrom_array(items, path)?))
+    }
+
+    fn gfrom_first(tree: V, path: &mut String) -> Result<Self, V::Error> {
+        let len = push_segment(path, &M::get());
+        let a = A::gfrom_tree(tree, path);
+        path.truncate(len);
+        let b = B::gfrom_array(&mut Vec::new().into_iter(), path);
+        Ok(Prod(Meta(a?, PhantomData), b?))
+    }
+}
+
+impl<V, L, R> GFromTree<V> for Sum<L, R>
+where
+    V: Tree,
+    Self: GFromTreeVariant<V>,
+{
+    fn gfrom_tree(tree: V, path: &mut String) -> Result<Self, V::Error> {
+        let (name, tree) = if Self::is_option() {
+            match tree.is_null() {
+                true => ("None".to_string(), None),
+                false => ("Some".to_string(), Some(tree)),
+            }
+        } else {
+            match tree.into_string() {
+                Ok(name) => (name, None),
+                Err(tree) => match tree.into_object().ok().and_then(V::into_entry) {
+                    Some((name, tree)) => (name, Some(tree)),
+                    None => {
+                        return Err(V::invalid(
+                            path,
+                            &format_args!("{} or {} with one key", V::STRING, V::OBJECT),
+                        ))
+                    }
+                },
+            }
+        };
+        Self::gfrom_variant(&name, tree, path).unwrap_or_else(|| Err(V::variant(path, name)))
+    }
+}
+
+impl<V, I, C, R> GFromTreeVariant<V> for Sum<Con<I, C>, R>
+where
+    V: Tree,
+    I: GFromTreeFields<V>,
+    C: Singleton<T = &'static str> + Constructor + 'static,
+    R: GFromTreeVariant<V>,
+{
+    fn is_option() -> bool {
+        TypeId::of::<C>() == TypeId::of::<Option_None_Name>()
+    }
+
+    fn gfrom_variant(
+        name: &str,
+        tree: Option<V>,
+        path: &mut String,
+    ) -> Option<Result<Self, V::Error>> {
+        if name != C::get() {
+            return R::gfrom_variant(name, tree, path).map(|r| r.map(Sum::Right));
+        }
+        let inner = fields_from_tree::<V, I, C>(tree, path);
+        Some(inner.map(|inner| Sum::Left(Con(inner, PhantomData))))
+    }
+}
+
+impl<V> GFromTreeVariant<V> for Void
+where
+    V: Tree,
+{
+    fn is_option() -> bool {
+        false
+    }
+
+    fn gfrom_variant(
+        _name: &str,
+        _tree: Option<V>,
+        _path: &mut String,
+    ) -> Option<Result<Self, V::Error>> {
+        None
+    }
+}
+
+impl<V, I, M> GFromTree<V> for Meta<I, M>
+where
+    V: Tree,
+    I: GFromTree<V>,
+    M: Singleton,
+{
+    fn gfrom_tree(tree: V, path: &mut String) -> Result<Self, V::Error> {
+        I::gfrom_tree(tree, path).map(|inner| Meta(inner, PhantomData))
+    }
+}
+
+impl<V, I, C> GFromTree<V> for Con<I, C>
+where
+    V: Tree,
+    I: GFromTreeFields<V>,
+    C: Singleton + Constructor,
+{
+    fn gfrom_tree(tree: V, path: &mut String) -> Result<Self, V::Error> {
+        fields_from_tree::<V, I, C>(Some(tree), path).map(|inner| Con(inner, PhantomData))
+    }
+}
+
+impl<V, A> GFromTree<V> for List<A>
+where
+    V: Tree,
+    A: GFromTree<V>,
+{
+    fn gfrom_tree(tree: V, path: &mut String) -> Result<Self, V::Error> {
+        let items = match tree.into_array() {
+            Ok(items) => items,
+            Err(_) => return Err(V::invalid(path, &"an array")),
+        };
+        let items = items
+            .into_iter()
+            .enumerate()
+            .map(|(i, tree)| {
+                let len = push_segment(path, &i);
+                let item = A::gfrom_tree(tree, path);
+                path.truncate(len);
+                item
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(List(items))
+    }
+}
+
+impl<V, A, const N: usize> GFromTree<V> for [A; N]
+where
+    V: Tree,
+    A: GFromTree<V>,
+{
+    fn gfrom_tree(tree: V, path: &mut String) -> Result<Self, V::Error> {
+        match tree.into_array() {
+            Ok(items) if items.len() == N => {
+                let List(items) = List::<A>::gfrom_tree(V::array(items), path)?;
+                Ok(items
+                    .try_into()
+                    .unwrap_or_else(|_| unreachable!("checked the number of items")))
+            }
+            _ => Err(V::invalid(path, &format_args!("an array of length {}", N))),
+        }
+    }
+}
+
+impl<V, T> GFromTree<V> for T
+where
+    V: Tree,
+    T: Leaf + FromTreeLeaf<V>,
+{
+    fn gfrom_tree(tree: V, path: &mut String) -> Result<Self, V::Error> {
+        T::from_tree(tree, path)
+    }
+}
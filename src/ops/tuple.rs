@@ -0,0 +1,117 @@
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Meta, Prod, ReprError, Singleton, Unit};
+
+/// Converts the representation of a struct to and from the representation of a tuple of its fields.
+///
+/// A struct is represented as a `Con` of a `Prod` chain of fields, each in a `Meta`,
+/// and a tuple as a bare `Prod` chain, so this strips or adds the `Con` and `Meta`s.
+/// Field names are discarded, so fields are matched by position.
+///
+/// `into_tuple` and `from_tuple` use this to convert between a struct and a tuple of its fields' types,
+/// e.g. to use a struct as a sort key, or with an API which takes tuples. The tuple's type must be given,
+/// and each of its elements must have the same representation as the corresponding field.
+/// Since the types may differ, the conversion fails if the target rejects a value, e.g. `0u8` for a `NonZeroU8` field.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{from_tuple, into_tuple};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq, Clone)]
+/// struct Version {
+///     major: u16,
+///     minor: u16,
+///     label: String,
+/// }
+///
+/// let version = Version { major: 1, minor: 2, label: "beta".to_string() };
+/// let tuple: (u16, u16, String) = into_tuple(version.clone()).unwrap();
+/// assert_eq!(tuple, (1, 2, "beta".to_string()));
+/// assert_eq!(from_tuple::<_, Version>(tuple), Ok(version));
+///
+/// let mut versions = vec![
+///     Version { major: 1, minor: 10, label: "a".to_string() },
+///     Version { major: 1, minor: 9, label: "b".to_string() },
+/// ];
+/// versions.sort_by_key(|v| into_tuple::<_, (u16, u16, String)>(v.clone()).unwrap());
+/// assert_eq!(versions[0].minor, 9);
+/// ```
+pub trait GTuple {
+    /// The representation of the tuple.
+    type Tuple;
+
+    /// Converts `self` into the representation of a tuple.
+    fn ginto_tuple(self) -> Self::Tuple;
+
+    /// Converts the representation of a tuple into `Self`.
+    fn gfrom_tuple(tuple: Self::Tuple) -> Self;
+}
+
+impl GTuple for Unit {
+    type Tuple = Unit;
+
+    fn ginto_tuple(self) -> Self::Tuple {
+        Unit
+    }
+
+    fn gfrom_tuple(_tuple: Self::Tuple) -> Self {
+        Unit
+    }
+}
+
+impl<A, M, B> GTuple for Prod<Meta<A, M>, B>
+where
+    M: Singleton,
+    B: GTuple,
+{
+    type Tuple = Prod<A, B::Tuple>;
+
+    fn ginto_tuple(self) -> Self::Tuple {
+        let Prod(Meta(a, _), b) = self;
+        Prod(a, b.ginto_tuple())
+    }
+
+    fn gfrom_tuple(tuple: Self::Tuple) -> Self {
+        let Prod(a, b) = tuple;
+        Prod(Meta(a, PhantomData), B::gfrom_tuple(b))
+    }
+}
+
+impl<I, C> GTuple for Con<I, C>
+where
+    I: GTuple,
+    C: Singleton,
+{
+    type Tuple = I::Tuple;
+
+    fn ginto_tuple(self) -> Self::Tuple {
+        let Con(inner, _) = self;
+        inner.ginto_tuple()
+    }
+
+    fn gfrom_tuple(tuple: Self::Tuple) -> Self {
+        Con(I::gfrom_tuple(tuple), PhantomData)
+    }
+}
+
+/// Converts the struct `x` into a tuple of its fields, in order.
+pub fn into_tuple<T, U>(x: T) -> Result<U, ReprError>
+where
+    T: Generic,
+    T::Repr: GTuple,
+    U: Generic<Repr = <T::Repr as GTuple>::Tuple>,
+{
+    U::checked_from_repr(x.into_repr().ginto_tuple())
+}
+
+/// Converts a tuple of fields, in order, into the struct `T`.
+pub fn from_tuple<U, T>(tuple: U) -> Result<T, ReprError>
+where
+    T: Generic,
+    T::Repr: GTuple,
+    U: Generic<Repr = <T::Repr as GTuple>::Tuple>,
+{
+    T::checked_from_repr(<T::Repr as GTuple>::gfrom_tuple(tuple.into_repr()))
+}
@@ -0,0 +1,143 @@
+use crate::{Con, Generic, Leaf, Meta, Opaque, Prod, Singleton, Unit};
+
+/// Visits every leaf of a product representation whose leaves all have the type `T`, in order.
+///
+/// This is implemented for constructors, products and arrays, but not sums or lists,
+/// so a value always has the same number of leaves, and for leaves of type `T`, or `Opaque<T>`.
+/// Representations with leaves of any other type don't implement it.
+///
+/// `#[derive(GenericIter)]` uses this to implement `iter`, `iter_mut` and `IntoIterator` for structs whose fields all have
+/// the same type, alongside `#[derive(Generic)]`, so they can be used like small fixed-size collections.
+/// Each field is wrapped in `Opaque`, so fields whose type isn't a leaf can be iterated over too.
+/// The iterators are over arrays, and don't allocate.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{uniform_leaves, GenericIter};
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericIter, Debug, PartialEq)]
+/// struct Rgb {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+/// }
+///
+/// let mut color = Rgb { r: 10, g: 200, b: 30 };
+/// assert_eq!(color.iter().max(), Some(&200));
+///
+/// for channel in color.iter_mut() {
+///     *channel = channel.saturating_add(100);
+/// }
+/// assert_eq!(color, Rgb { r: 110, g: 255, b: 130 });
+///
+/// let total: u32 = color.into_iter().map(u32::from).sum();
+/// assert_eq!(total, 495);
+///
+/// assert_eq!(uniform_leaves((1, 2, 3)), [1, 2, 3]);
+/// ```
+pub trait GUniform<T> {
+    /// Calls `f` with every leaf of `self`, in order.
+    fn gfor_each<F>(self, f: &mut F)
+    where
+        F: FnMut(T);
+}
+
+impl<T> GUniform<T> for Unit {
+    fn gfor_each<F>(self, _f: &mut F)
+    where
+        F: FnMut(T),
+    {
+    }
+}
+
+impl<T, A, B> GUniform<T> for Prod<A, B>
+where
+    A: GUniform<T>,
+    B: GUniform<T>,
+{
+    fn gfor_each<F>(self, f: &mut F)
+    where
+        F: FnMut(T),
+    {
+        let Prod(a, b) = self;
+        a.gfor_each(f);
+        b.gfor_each(f);
+    }
+}
+
+impl<T, I, M> GUniform<T> for Meta<I, M>
+where
+    I: GUniform<T>,
+    M: Singleton,
+{
+    fn gfor_each<F>(self, f: &mut F)
+    where
+        F: FnMut(T),
+    {
+        let Meta(inner, _) = self;
+        inner.gfor_each(f);
+    }
+}
+
+impl<T, I, C> GUniform<T> for Con<I, C>
+where
+    I: GUniform<T>,
+    C: Singleton,
+{
+    fn gfor_each<F>(self, f: &mut F)
+    where
+        F: FnMut(T),
+    {
+        let Con(inner, _) = self;
+        inner.gfor_each(f);
+    }
+}
+
+impl<T, A, const N: usize> GUniform<T> for [A; N]
+where
+    A: GUniform<T>,
+{
+    fn gfor_each<F>(self, f: &mut F)
+    where
+        F: FnMut(T),
+    {
+        for item in IntoIterator::into_iter(self) {
+            item.gfor_each(f);
+        }
+    }
+}
+
+impl<T> GUniform<T> for Opaque<T> {
+    fn gfor_each<F>(self, f: &mut F)
+    where
+        F: FnMut(T),
+    {
+        let Opaque(leaf) = self;
+        f(leaf);
+    }
+}
+
+impl<T> GUniform<T> for T
+where
+    T: Leaf,
+{
+    fn gfor_each<F>(self, f: &mut F)
+    where
+        F: FnMut(T),
+    {
+        f(self);
+    }
+}
+
+/// Collects every leaf of the representation of `x`, all of type `T`, in order.
+pub fn uniform_leaves<T, X>(x: X) -> Vec<T>
+where
+    X: Generic,
+    X::Repr: GUniform<T>,
+{
+    let mut leaves = Vec::new();
+    x.into_repr().gfor_each(&mut |leaf| leaves.push(leaf));
+    leaves
+}
@@ -0,0 +1,167 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use crate::Generic;
+
+use super::flatten::{flatten, unflatten, GFlatten, GUnflatten, UnflattenError};
+
+/// An error decoding a query string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryError {
+    /// A pair wasn't validly percent-encoded, or didn't decode to UTF-8.
+    Encoding(String),
+    /// The decoded pairs couldn't be rebuilt into the value.
+    Unflatten(UnflattenError),
+}
+
+impl Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryError::Encoding(pair) => write!(f, "invalid percent-encoding in `{}`", pair),
+            QueryError::Unflatten(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for QueryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            QueryError::Encoding(_) => None,
+            QueryError::Unflatten(e) => Some(e),
+        }
+    }
+}
+
+impl From<UnflattenError> for QueryError {
+    fn from(e: UnflattenError) -> Self {
+        QueryError::Unflatten(e)
+    }
+}
+
+/// Appends `text` to `out`, form-urlencoded: spaces become `+`, and bytes other than
+/// alphanumerics and `*-._` become `%XX`.
+fn write_encoded(out: &mut String, text: &str) {
+    for &b in text.as_bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'*' | b'-' | b'.' | b'_' => {
+                out.push(b as char)
+            }
+            b' ' => out.push('+'),
+            _ => {
+                const HEX: &[u8; 16] = b"0123456789ABCDEF";
+                out.push('%');
+                out.push(HEX[usize::from(b >> 4)] as char);
+                out.push(HEX[usize::from(b & 0xf)] as char);
+            }
+        }
+    }
+}
+
+/// Decodes form-urlencoded `text`, or returns `None` if it has an invalid escape or isn't UTF-8.
+fn decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&b, tail)) = rest.split_first() {
+        rest = tail;
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = rest.get(..2)?;
+                let hex = std::str::from_utf8(hex).ok()?;
+                if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return None;
+                }
+                bytes.push(u8::from_str_radix(hex, 16).ok()?);
+                rest = &rest[2..];
+            }
+            _ => bytes.push(b),
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Encodes `x` as a form-urlencoded query string, like `a=1&b=two`, without a leading `?`.
+///
+/// The pairs are those of `flatten`, so nested fields are named like `outer.inner`, list elements like `items.0`,
+/// and enums additionally have a pair naming their variant, e.g. `limit=Some&limit.0=10`.
+/// Only the `urlencoded` feature is required, which has no dependencies.
+///
+/// ```rust
+/// use generics::ops::encode_query;
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct Search {
+///     q: String,
+///     page: Page,
+/// }
+///
+/// #[derive(Generic)]
+/// struct Page {
+///     size: u32,
+///     after: char,
+/// }
+///
+/// let search = Search { q: "rust & co".to_string(), page: Page { size: 20, after: 'é' } };
+/// assert_eq!(encode_query(search), "q=rust+%26+co&page.size=20&page.after=%C3%A9");
+/// ```
+pub fn encode_query<T>(x: T) -> String
+where
+    T: Generic,
+    T::Repr: GFlatten,
+{
+    let mut out = String::new();
+    for (i, (key, value)) in flatten(x).iter().enumerate() {
+        if i > 0 {
+            out.push('&');
+        }
+        write_encoded(&mut out, key);
+        out.push('=');
+        write_encoded(&mut out, value);
+    }
+    out
+}
+
+/// Decodes a form-urlencoded query string, like `a=1&b=two`, without a leading `?`, as a `T`.
+///
+/// Pairs are rebuilt with `unflatten`, so they may be in any order, and pairs which don't correspond to
+/// any leaf of `T` are ignored. A pair without `=` has an empty value, and if a key is repeated, the last pair wins.
+///
+/// ```rust
+/// use generics::ops::{decode_query, QueryError};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Login {
+///     user: String,
+///     remember: bool,
+/// }
+///
+/// let login = decode_query::<Login>("remember=true&user=J.+Doe%21&utm=x").unwrap();
+/// assert_eq!(login, Login { user: "J. Doe!".to_string(), remember: true });
+///
+/// let err = decode_query::<Login>("user=j&remember=yes").unwrap_err();
+/// assert_eq!(err.to_string(), "invalid value `yes` for `remember`");
+/// assert_eq!(
+///     decode_query::<Login>("user=%zz"),
+///     Err(QueryError::Encoding("user=%zz".to_string()))
+/// );
+/// ```
+pub fn decode_query<T>(query: &str) -> Result<T, QueryError>
+where
+    T: Generic,
+    T::Repr: GUnflatten,
+{
+    let pairs = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            match (decode(key), decode(value)) {
+                (Some(key), Some(value)) => Ok((key, value)),
+                _ => Err(QueryError::Encoding(pair.to_string())),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(unflatten(pairs)?)
+}
@@ -0,0 +1,72 @@
+use crate::{Con, Generic, Singleton, Sum, Void};
+
+/// Gets the name of the constructor a representation holds, from its metadata.
+///
+/// For enums, this is the name of the variant, e.g. `"B"` for `Foo::B`, and for structs, the name of the struct.
+///
+/// `#[derive(GenericDisplay)]` uses this to implement `Display` and `as_str` for fieldless enums,
+/// alongside `#[derive(Generic)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::GenericDisplay;
+/// use generics::Generic;
+///
+/// #[derive(Generic, GenericDisplay)]
+/// enum Color {
+///     Red,
+///     Green,
+/// }
+///
+/// assert_eq!(Color::Red.as_str(), "Red");
+/// assert_eq!(Color::Green.to_string(), "Green");
+/// ```
+pub trait GVariantName {
+    /// The name of the constructor `self` holds.
+    fn variant_name(&self) -> &'static str;
+}
+
+impl<L, R> GVariantName for Sum<L, R>
+where
+    L: GVariantName,
+    R: GVariantName,
+{
+    fn variant_name(&self) -> &'static str {
+        match self {
+            Sum::Left(l) => l.variant_name(),
+            Sum::Right(r) => r.variant_name(),
+        }
+    }
+}
+
+impl GVariantName for Void {
+    fn variant_name(&self) -> &'static str {
+        match *self {}
+    }
+}
+
+impl<I, C> GVariantName for Con<I, C>
+where
+    C: Singleton<T = &'static str>,
+{
+    fn variant_name(&self) -> &'static str {
+        C::get()
+    }
+}
+
+/// Gets the name of the constructor `x` was built with, e.g. `"Some"` for `Some(1)`.
+///
+/// ```rust
+/// use generics::ops::variant_name;
+///
+/// assert_eq!(variant_name(Some(1)), "Some");
+/// assert_eq!(variant_name(Err::<(), _>(1)), "Err");
+/// ```
+pub fn variant_name<T>(x: T) -> &'static str
+where
+    T: Generic,
+    T::Repr: GVariantName,
+{
+    x.into_repr().variant_name()
+}
@@ -0,0 +1,497 @@
+use std::convert::{TryFrom, TryInto};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+
+use crate::{Generic, Opaque, ReprError};
+
+use super::bytes::{take, Format, FormatLeaf, GDecodeBytes, GEncodeBytes};
+
+/// A destination for the bytes written by `GEncodeWire`.
+///
+/// `SliceWriter` writes into a fixed buffer without allocating, and `Vec<u8>` grows as needed.
+pub trait WireWrite {
+    /// Appends `bytes`, or fails with `WireError::Full` if there isn't room.
+    fn write(&mut self, bytes: &[u8]) -> Result<(), WireError>;
+}
+
+impl<W> WireWrite for &mut W
+where
+    W: WireWrite + ?Sized,
+{
+    fn write(&mut self, bytes: &[u8]) -> Result<(), WireError> {
+        (**self).write(bytes)
+    }
+}
+
+impl WireWrite for Vec<u8> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), WireError> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// Writes into the start of a borrowed buffer, without allocating.
+#[derive(Debug)]
+pub struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    /// Creates a writer which fills `buf` from the start.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        SliceWriter { buf, len: 0 }
+    }
+
+    /// Returns the part of the buffer written so far.
+    pub fn into_written(self) -> &'a mut [u8] {
+        &mut self.buf[..self.len]
+    }
+}
+
+impl WireWrite for SliceWriter<'_> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), WireError> {
+        let end = self.len + bytes.len();
+        if end > self.buf.len() {
+            return Err(WireError::Full);
+        }
+        self.buf[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Writes `n` as an unsigned LEB128 varint, i.e. 7 bits per byte, least significant first.
+fn put_varint<W>(mut n: u128, w: &mut W) -> Result<(), WireError>
+where
+    W: WireWrite + ?Sized,
+{
+    let mut buf = [0; 19];
+    let mut len = 0;
+    loop {
+        let byte = (n & 0x7f) as u8;
+        n >>= 7;
+        if n == 0 {
+            buf[len] = byte;
+            len += 1;
+            break;
+        }
+        buf[len] = byte | 0x80;
+        len += 1;
+    }
+    w.write(&buf[..len])
+}
+
+/// Reads an unsigned LEB128 varint, which must fit in `T`.
+fn get_varint<T>(input: &mut &[u8]) -> Result<T, WireError>
+where
+    T: TryFrom<u128>,
+{
+    let mut n = 0u128;
+    for shift in (0..128).step_by(7) {
+        let (&byte, rest) = input.split_first().ok_or(WireError::Eof)?;
+        *input = rest;
+        if shift == 126 && byte > 0x03 {
+            return Err(WireError::Invalid("varint"));
+        }
+        n |= u128::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return T::try_from(n).map_err(|_| WireError::Invalid("varint"));
+        }
+    }
+    Err(WireError::Invalid("varint"))
+}
+
+/// A leaf which can be encoded in the compact wire format of `GEncodeWire`.
+///
+/// Implement this for your own `Leaf` types to encode and decode them with `GEncodeWire` and `GDecodeWire`.
+pub trait Wire: Sized {
+    /// Writes the encoding of `self` to `w`.
+    fn put<W>(self, w: &mut W) -> Result<(), WireError>
+    where
+        W: WireWrite + ?Sized;
+
+    /// Decodes a value from the start of `input`, advancing it past the bytes read.
+    fn get(input: &mut &[u8]) -> Result<Self, WireError>;
+}
+
+macro_rules! impl_wire_unsigned {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            /// As a varint.
+            impl Wire for $ty {
+                fn put<W>(self, w: &mut W) -> Result<(), WireError>
+                where
+                    W: WireWrite + ?Sized,
+                {
+                    put_varint(self as u128, w)
+                }
+
+                fn get(input: &mut &[u8]) -> Result<Self, WireError> {
+                    get_varint(input)
+                }
+            }
+        )+
+    }
+}
+
+impl_wire_unsigned!(u16, u32, u64, u128, usize);
+
+macro_rules! impl_wire_signed {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            /// As a zigzag varint, so small negative numbers are short too.
+            impl Wire for $ty {
+                fn put<W>(self, w: &mut W) -> Result<(), WireError>
+                where
+                    W: WireWrite + ?Sized,
+                {
+                    let n = self as i128;
+                    put_varint(((n << 1) ^ (n >> 127)) as u128, w)
+                }
+
+                fn get(input: &mut &[u8]) -> Result<Self, WireError> {
+                    let n = get_varint::<u128>(input)?;
+                    let n = ((n >> 1) as i128) ^ -((n & 1) as i128);
+                    $ty::try_from(n).map_err(|_| WireError::Invalid("varint"))
+                }
+            }
+        )+
+    }
+}
+
+impl_wire_signed!(i16, i32, i64, i128, isize);
+
+/// As one byte.
+impl Wire for u8 {
+    fn put<W>(self, w: &mut W) -> Result<(), WireError>
+    where
+        W: WireWrite + ?Sized,
+    {
+        w.write(&[self])
+    }
+
+    fn get(input: &mut &[u8]) -> Result<Self, WireError> {
+        Ok(take::<WireFormat<'_>>(input, 1)?[0])
+    }
+}
+
+/// As one byte.
+impl Wire for i8 {
+    fn put<W>(self, w: &mut W) -> Result<(), WireError>
+    where
+        W: WireWrite + ?Sized,
+    {
+        (self as u8).put(w)
+    }
+
+    fn get(input: &mut &[u8]) -> Result<Self, WireError> {
+        u8::get(input).map(|n| n as i8)
+    }
+}
+
+macro_rules! impl_wire_float {
+    ( $( $ty:ident ),+ $(,)? ) => {
+        $(
+            /// As little-endian bytes.
+            impl Wire for $ty {
+                fn put<W>(self, w: &mut W) -> Result<(), WireError>
+                where
+                    W: WireWrite + ?Sized,
+                {
+                    w.write(&self.to_le_bytes())
+                }
+
+                fn get(input: &mut &[u8]) -> Result<Self, WireError> {
+                    let bytes = take::<WireFormat<'_>>(input, std::mem::size_of::<$ty>())?;
+                    Ok($ty::from_le_bytes(bytes.try_into().expect("took the size of the type")))
+                }
+            }
+        )+
+    }
+}
+
+impl_wire_float!(f32, f64);
+
+/// As one byte, which must be 0 or 1.
+impl Wire for bool {
+    fn put<W>(self, w: &mut W) -> Result<(), WireError>
+    where
+        W: WireWrite + ?Sized,
+    {
+        (self as u8).put(w)
+    }
+
+    fn get(input: &mut &[u8]) -> Result<Self, WireError> {
+        match u8::get(input)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(WireError::Invalid("bool")),
+        }
+    }
+}
+
+/// As its UTF-8 bytes, with their length as a varint.
+impl Wire for char {
+    fn put<W>(self, w: &mut W) -> Result<(), WireError>
+    where
+        W: WireWrite + ?Sized,
+    {
+        put_str(self.encode_utf8(&mut [0; 4]), w)
+    }
+
+    fn get(input: &mut &[u8]) -> Result<Self, WireError> {
+        let len = get_varint::<usize>(input)?;
+        let s = std::str::from_utf8(take::<WireFormat<'_>>(input, len)?)
+            .map_err(|_| WireError::Invalid("char"))?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(WireError::Invalid("char")),
+        }
+    }
+}
+
+/// As its UTF-8 bytes, with their length as a varint.
+impl Wire for String {
+    fn put<W>(self, w: &mut W) -> Result<(), WireError>
+    where
+        W: WireWrite + ?Sized,
+    {
+        put_str(&self, w)
+    }
+
+    fn get(input: &mut &[u8]) -> Result<Self, WireError> {
+        let len = get_varint::<usize>(input)?;
+        let s = std::str::from_utf8(take::<WireFormat<'_>>(input, len)?)
+            .map_err(|_| WireError::Invalid("string"))?;
+        Ok(s.to_string())
+    }
+}
+
+/// Writes `s` as its length as a varint, then its UTF-8 bytes.
+fn put_str<W>(s: &str, w: &mut W) -> Result<(), WireError>
+where
+    W: WireWrite + ?Sized,
+{
+    s.len().put(w)?;
+    w.write(s.as_bytes())
+}
+
+impl<T> Wire for Opaque<T>
+where
+    T: Wire,
+{
+    fn put<W>(self, w: &mut W) -> Result<(), WireError>
+    where
+        W: WireWrite + ?Sized,
+    {
+        self.0.put(w)
+    }
+
+    fn get(input: &mut &[u8]) -> Result<Self, WireError> {
+        T::get(input).map(Opaque)
+    }
+}
+
+/// Encodes a representation in a compact, varint-based wire format, like postcard, node by node.
+///
+/// Products are their fields in order, with no names or lengths, and sums are the index of their variant
+/// as a varint, then its fields. Lists are their length as a varint, then their items, and arrays are just their items.
+/// Leaves are encoded with `Wire`: integers wider than a byte are varints, zigzag-encoded if signed,
+/// floats are little-endian bytes, and strings are their length as a varint, then their UTF-8 bytes.
+/// Like `GEncode`, the encoding has no metadata, so it is only compatible between types with the same shape.
+///
+/// Encoding only writes through `WireWrite`, so with `SliceWriter` it never allocates, e.g. to fill a fixed buffer
+/// in shared memory. Decoding only allocates for the lists and strings it returns. Like the rest of this crate,
+/// it still requires `std`.
+///
+/// When decoding, a list can't have more items than there are bytes left, or if its items are zero-sized,
+/// more than 65536 of them, so a corrupt length fails quickly.
+/// `encode_wire_to_slice`, `encode_wire`, and `decode_wire` use this and `GDecodeWire` to encode and decode
+/// any `Generic` type.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{decode_wire, encode_wire_to_slice, WireError};
+/// use generics::Generic;
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// struct Reading {
+///     sensor: u16,
+///     delta: i32,
+///     status: Status,
+/// }
+///
+/// #[derive(Generic, Debug, PartialEq)]
+/// enum Status {
+///     Ok,
+///     Fault(u8),
+/// }
+///
+/// let mut buf = [0; 8];
+/// let bytes = encode_wire_to_slice(Reading { sensor: 300, delta: -2, status: Status::Fault(7) }, &mut buf).unwrap();
+/// assert_eq!(bytes, [0xac, 0x02, 0x03, 0x01, 0x07]);
+/// assert_eq!(decode_wire::<Reading>(bytes), Ok(Reading { sensor: 300, delta: -2, status: Status::Fault(7) }));
+///
+/// let mut small = [0; 2];
+/// let err = encode_wire_to_slice(Reading { sensor: 300, delta: -2, status: Status::Ok }, &mut small).unwrap_err();
+/// assert_eq!(err, WireError::Full);
+/// ```
+pub trait GEncodeWire {
+    /// Writes the encoding of `self` to `w`.
+    fn gencode_wire<W>(self, w: &mut W) -> Result<(), WireError>
+    where
+        W: WireWrite + ?Sized;
+}
+
+/// Decodes a representation from the wire format of `GEncodeWire`, node by node.
+pub trait GDecodeWire: Sized {
+    /// Decodes a representation from the start of `input`, advancing it past the bytes read.
+    fn gdecode_wire(input: &mut &[u8]) -> Result<Self, WireError>;
+}
+
+/// An error encoding or decoding the wire format of `GEncodeWire`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WireError {
+    /// The buffer being written was full.
+    Full,
+    /// The input ended before the value did.
+    Eof,
+    /// The index of a variant was out of range for its sum.
+    Variant(u32),
+    /// A leaf of the given type had an invalid encoding, e.g. a varint too large for its type.
+    Invalid(&'static str),
+    /// The given number of bytes were left over after the value.
+    Trailing(usize),
+    /// The value was invalid for its type, e.g. a `NonZeroU8` of zero.
+    Repr(ReprError),
+}
+
+impl Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WireError::Full => write!(f, "buffer full"),
+            WireError::Eof => write!(f, "unexpected end of input"),
+            WireError::Variant(index) => write!(f, "invalid variant index {}", index),
+            WireError::Invalid(ty) => write!(f, "invalid encoding of {}", ty),
+            WireError::Trailing(len) => write!(f, "{} trailing bytes", len),
+            WireError::Repr(error) => write!(f, "invalid value: {}", error),
+        }
+    }
+}
+
+impl Error for WireError {}
+
+/// The format of `GEncodeWire`, writing to a `WireWrite` which lives for `'w`.
+#[derive(Debug)]
+pub struct WireFormat<'w>(PhantomData<&'w mut ()>);
+
+impl<'w> Format for WireFormat<'w> {
+    type Out = dyn WireWrite + 'w;
+    type EncodeError = WireError;
+    type Error = WireError;
+
+    fn put_index(index: u32, w: &mut (dyn WireWrite + 'w)) -> Result<(), WireError> {
+        index.put(w)
+    }
+
+    fn put_len(len: usize, w: &mut (dyn WireWrite + 'w)) -> Result<(), WireError> {
+        len.put(w)
+    }
+
+    fn get_index(input: &mut &[u8]) -> Result<u32, WireError> {
+        u32::get(input)
+    }
+
+    fn get_len(input: &mut &[u8]) -> Result<usize, WireError> {
+        usize::get(input)
+    }
+
+    fn eof() -> WireError {
+        WireError::Eof
+    }
+
+    fn variant(index: u32) -> WireError {
+        WireError::Variant(index)
+    }
+
+    fn invalid(ty: &'static str) -> WireError {
+        WireError::Invalid(ty)
+    }
+}
+
+impl<'w, T> FormatLeaf<WireFormat<'w>> for T
+where
+    T: Wire,
+{
+    fn put_leaf(self, w: &mut (dyn WireWrite + 'w)) -> Result<(), WireError> {
+        self.put(w)
+    }
+
+    fn get_leaf(input: &mut &[u8]) -> Result<Self, WireError> {
+        T::get(input)
+    }
+}
+
+impl<T> GEncodeWire for T
+where
+    T: for<'w> GEncodeBytes<WireFormat<'w>>,
+{
+    fn gencode_wire<W>(self, mut w: &mut W) -> Result<(), WireError>
+    where
+        W: WireWrite + ?Sized,
+    {
+        self.gencode_bytes(&mut w)
+    }
+}
+
+impl<T> GDecodeWire for T
+where
+    T: GDecodeBytes<WireFormat<'static>>,
+{
+    fn gdecode_wire(input: &mut &[u8]) -> Result<Self, WireError> {
+        T::gdecode_bytes(input)
+    }
+}
+
+/// Encodes `x` into the start of `buf`, without allocating, and returns the part of `buf` written.
+///
+/// Fails with `WireError::Full` if `buf` is too small.
+pub fn encode_wire_to_slice<T>(x: T, buf: &mut [u8]) -> Result<&mut [u8], WireError>
+where
+    T: Generic,
+    T::Repr: GEncodeWire,
+{
+    let mut w = SliceWriter::new(buf);
+    x.into_repr().gencode_wire(&mut w)?;
+    Ok(w.into_written())
+}
+
+/// Encodes `x` into a new `Vec`.
+pub fn encode_wire<T>(x: T) -> Vec<u8>
+where
+    T: Generic,
+    T::Repr: GEncodeWire,
+{
+    let mut out = Vec::new();
+    x.into_repr()
+        .gencode_wire(&mut out)
+        .expect("writing to a `Vec` cannot fail");
+    out
+}
+
+/// Decodes a `T` from `bytes`, which must hold exactly one encoded value.
+pub fn decode_wire<T>(mut bytes: &[u8]) -> Result<T, WireError>
+where
+    T: Generic,
+    T::Repr: GDecodeWire,
+{
+    let repr = T::Repr::gdecode_wire(&mut bytes)?;
+    if !bytes.is_empty() {
+        return Err(WireError::Trailing(bytes.len()));
+    }
+    T::checked_from_repr(repr).map_err(WireError::Repr)
+}
@@ -0,0 +1,322 @@
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::sync::atomic::{self, Ordering};
+
+use crate::{Con, GenericMut, List, Meta, Opaque, Prod, Sensitive, Singleton, Sum, Unit, Void};
+
+/// Overwrites `dst` with `value`, in a way the compiler won't optimize away even if `dst` is never read again.
+fn volatile_write<T>(dst: &mut T, value: T)
+where
+    T: Copy,
+{
+    // SAFETY: `dst` is a valid, aligned, exclusive reference, and `T: Copy` has no destructor to skip.
+    unsafe { ptr::write_volatile(dst, value) };
+    atomic::compiler_fence(Ordering::SeqCst);
+}
+
+/// A leaf whose contents can be securely erased.
+///
+/// Implement this for your own leaf types, or derive it with `#[derive(GenericZeroize)]`.
+pub trait Zeroize {
+    /// Overwrites the contents of `self` with zeroes, or empties it, in a way which won't be optimized away.
+    fn zeroize(&mut self);
+}
+
+macro_rules! impl_zeroize {
+    ( $( $ty:ty => $zero:expr ),+ $(,)? ) => {
+        $(
+            impl Zeroize for $ty {
+                fn zeroize(&mut self) {
+                    volatile_write(self, $zero);
+                }
+            }
+        )+
+    }
+}
+
+#[rustfmt::skip]
+impl_zeroize!(
+    u8 => 0, u16 => 0, u32 => 0, u64 => 0, u128 => 0, usize => 0,
+    i8 => 0, i16 => 0, i32 => 0, i64 => 0, i128 => 0, isize => 0,
+    f32 => 0.0, f64 => 0.0,
+    bool => false, char => '\0',
+);
+
+impl<T, const N: usize> Zeroize for [T; N]
+where
+    T: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.iter_mut().for_each(Zeroize::zeroize);
+    }
+}
+
+/// Zeroes each element, then empties the `Vec`, keeping its allocation.
+impl<T> Zeroize for Vec<T>
+where
+    T: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.iter_mut().for_each(Zeroize::zeroize);
+        self.clear();
+    }
+}
+
+/// Zeroes the whole allocation, including unused capacity, then empties the `String`, keeping its allocation.
+impl Zeroize for String {
+    fn zeroize(&mut self) {
+        let mut bytes = mem::take(self).into_bytes();
+        // This never reallocates, so the unused capacity is zeroed in place.
+        bytes.resize(bytes.capacity(), 0);
+        bytes.zeroize();
+        *self = String::from_utf8(bytes).expect("an empty `Vec` is valid UTF-8");
+    }
+}
+
+impl<T> Zeroize for Box<T>
+where
+    T: Zeroize,
+{
+    fn zeroize(&mut self) {
+        (**self).zeroize();
+    }
+}
+
+/// Zeroes the value if there is one, then sets it to `None`.
+impl<T> Zeroize for Option<T>
+where
+    T: Zeroize,
+{
+    fn zeroize(&mut self) {
+        if let Some(x) = self {
+            x.zeroize();
+        }
+        *self = None;
+    }
+}
+
+impl<T> Zeroize for Opaque<T>
+where
+    T: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T> Zeroize for Sensitive<T>
+where
+    T: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Erases every leaf of a mutable representation in place, with `Zeroize`.
+///
+/// This is implemented over mutable representations, as returned by `GenericMut::as_repr_mut`.
+/// Sums erase the variant they hold, without changing it, and lists erase each of their elements,
+/// without changing their length.
+///
+/// `#[derive(GenericZeroize)]` uses this to implement `Zeroize`, alongside `#[derive(Generic, GenericMut)]`,
+/// and `Zeroizing` erases a value when it's dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{GenericZeroize, Zeroize};
+/// use generics::{Generic, GenericMut};
+///
+/// #[derive(Generic, GenericMut, GenericZeroize, Debug, PartialEq)]
+/// struct Key {
+///     id: u32,
+///     bytes: Vec<u8>,
+///     #[generic(sensitive)]
+///     passphrase: String,
+/// }
+///
+/// let mut key = Key { id: 7, bytes: vec![1, 2, 3], passphrase: "hunter2".to_string() };
+/// key.zeroize();
+/// assert_eq!(key, Key { id: 0, bytes: vec![0, 0, 0], passphrase: String::new() });
+/// ```
+pub trait GZeroize {
+    /// Erases every leaf of `self`.
+    fn gzeroize(self);
+}
+
+impl GZeroize for Unit {
+    fn gzeroize(self) {}
+}
+
+impl<A, B> GZeroize for Prod<A, B>
+where
+    A: GZeroize,
+    B: GZeroize,
+{
+    fn gzeroize(self) {
+        let Prod(a, b) = self;
+        a.gzeroize();
+        b.gzeroize();
+    }
+}
+
+impl<L, R> GZeroize for Sum<L, R>
+where
+    L: GZeroize,
+    R: GZeroize,
+{
+    fn gzeroize(self) {
+        match self {
+            Sum::Left(l) => l.gzeroize(),
+            Sum::Right(r) => r.gzeroize(),
+        }
+    }
+}
+
+impl GZeroize for Void {
+    fn gzeroize(self) {
+        match self {}
+    }
+}
+
+impl<I, M> GZeroize for Meta<I, M>
+where
+    I: GZeroize,
+    M: Singleton,
+{
+    fn gzeroize(self) {
+        let Meta(inner, _) = self;
+        inner.gzeroize();
+    }
+}
+
+impl<I, C> GZeroize for Con<I, C>
+where
+    I: GZeroize,
+    C: Singleton,
+{
+    fn gzeroize(self) {
+        let Con(inner, _) = self;
+        inner.gzeroize();
+    }
+}
+
+impl<A> GZeroize for List<A>
+where
+    A: GZeroize,
+{
+    fn gzeroize(self) {
+        let List(items) = self;
+        items.into_iter().for_each(GZeroize::gzeroize);
+    }
+}
+
+impl<A, const N: usize> GZeroize for [A; N]
+where
+    A: GZeroize,
+{
+    fn gzeroize(self) {
+        IntoIterator::into_iter(self).for_each(GZeroize::gzeroize);
+    }
+}
+
+impl<T> GZeroize for &mut T
+where
+    T: Zeroize,
+{
+    fn gzeroize(self) {
+        self.zeroize();
+    }
+}
+
+impl<T> GZeroize for Opaque<&mut T>
+where
+    T: Zeroize,
+{
+    fn gzeroize(self) {
+        let Opaque(inner) = self;
+        inner.zeroize();
+    }
+}
+
+impl<T> GZeroize for Sensitive<&mut T>
+where
+    T: Zeroize,
+{
+    fn gzeroize(self) {
+        let Sensitive(inner) = self;
+        inner.zeroize();
+    }
+}
+
+/// Erases every leaf of `x` in place, via its mutable representation.
+///
+/// ```rust
+/// use generics::ops::zeroize;
+///
+/// let mut x = (1u8, [2u16, 3], Some(4i32));
+/// zeroize(&mut x);
+/// assert_eq!(x, (0, [0, 0], Some(0)));
+/// ```
+pub fn zeroize<'a, T>(x: &'a mut T)
+where
+    T: GenericMut<'a>,
+    T::ReprMut: GZeroize,
+{
+    x.as_repr_mut().gzeroize();
+}
+
+/// A wrapper which erases its value with `Zeroize` when it's dropped.
+///
+/// ```rust
+/// use generics::ops::Zeroizing;
+///
+/// let mut key = Zeroizing::new(vec![1u8, 2, 3]);
+/// key.push(4);
+/// assert_eq!(*key, [1, 2, 3, 4]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Zeroizing<T>(T)
+where
+    T: Zeroize;
+
+impl<T> Zeroizing<T>
+where
+    T: Zeroize,
+{
+    /// Wraps `value`, so it's erased when dropped.
+    pub fn new(value: T) -> Self {
+        Zeroizing(value)
+    }
+}
+
+impl<T> Deref for Zeroizing<T>
+where
+    T: Zeroize,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Zeroizing<T>
+where
+    T: Zeroize,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> Drop for Zeroizing<T>
+where
+    T: Zeroize,
+{
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
@@ -0,0 +1,214 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Leaf, List, Meta, Prod, Singleton, Sum, Unit, Void};
+
+use super::GVariantName;
+
+/// Zips two representations of the same type into one representation, whose leaves are pairs.
+///
+/// This is the basis of operations which walk two values in lockstep, e.g. diffing or merging.
+/// Zipping fails if the representations hold different variants, or lists of different lengths.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::{gzip, ZipError};
+/// use generics::{Generic, Meta, Prod, Unit};
+///
+/// #[derive(Generic)]
+/// struct Foo {
+///     a: u8,
+/// }
+///
+/// let zipped = gzip(Foo { a: 1 }, Foo { a: 2 }).unwrap();
+/// let Prod(Meta(a, _), Unit) = zipped.0;
+/// assert_eq!(a, (1, 2));
+///
+/// assert_eq!(
+///     gzip(Some(1), None).err(),
+///     Some(ZipError::Variant { left: "Some", right: "None" }),
+/// );
+/// ```
+pub trait GZip: Sized {
+    /// The zipped representation.
+    type Output;
+
+    /// Zips `self` with `other`.
+    fn gzip(self, other: Self) -> Result<Self::Output, ZipError>;
+}
+
+/// An error zipping two representations with different shapes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ZipError {
+    /// The representations held different variants.
+    Variant {
+        /// The name of the variant on the left.
+        left: &'static str,
+        /// The name of the variant on the right.
+        right: &'static str,
+    },
+    /// The representations held lists of different lengths.
+    Length {
+        /// The length of the list on the left.
+        left: usize,
+        /// The length of the list on the right.
+        right: usize,
+    },
+}
+
+impl Display for ZipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZipError::Variant { left, right } => {
+                write!(f, "mismatched variants `{}` and `{}`", left, right)
+            }
+            ZipError::Length { left, right } => {
+                write!(f, "mismatched lengths {} and {}", left, right)
+            }
+        }
+    }
+}
+
+impl Error for ZipError {}
+
+impl GZip for Unit {
+    type Output = Unit;
+
+    fn gzip(self, _other: Self) -> Result<Self::Output, ZipError> {
+        Ok(Unit)
+    }
+}
+
+impl<A, B> GZip for Prod<A, B>
+where
+    A: GZip,
+    B: GZip,
+{
+    type Output = Prod<A::Output, B::Output>;
+
+    fn gzip(self, other: Self) -> Result<Self::Output, ZipError> {
+        let (Prod(a, b), Prod(other_a, other_b)) = (self, other);
+        Ok(Prod(a.gzip(other_a)?, b.gzip(other_b)?))
+    }
+}
+
+impl<L, R> GZip for Sum<L, R>
+where
+    L: GZip + GVariantName,
+    R: GZip + GVariantName,
+{
+    type Output = Sum<L::Output, R::Output>;
+
+    fn gzip(self, other: Self) -> Result<Self::Output, ZipError> {
+        match (self, other) {
+            (Sum::Left(l), Sum::Left(other)) => Ok(Sum::Left(l.gzip(other)?)),
+            (Sum::Right(r), Sum::Right(other)) => Ok(Sum::Right(r.gzip(other)?)),
+            (left, right) => Err(ZipError::Variant {
+                left: left.variant_name(),
+                right: right.variant_name(),
+            }),
+        }
+    }
+}
+
+impl GZip for Void {
+    type Output = Void;
+
+    fn gzip(self, _other: Self) -> Result<Self::Output, ZipError> {
+        match self {}
+    }
+}
+
+impl<I, M> GZip for Meta<I, M>
+where
+    I: GZip,
+    M: Singleton,
+{
+    type Output = Meta<I::Output, M>;
+
+    fn gzip(self, other: Self) -> Result<Self::Output, ZipError> {
+        let (Meta(inner, _), Meta(other, _)) = (self, other);
+        Ok(Meta(inner.gzip(other)?, PhantomData))
+    }
+}
+
+impl<I, C> GZip for Con<I, C>
+where
+    I: GZip,
+    C: Singleton,
+{
+    type Output = Con<I::Output, C>;
+
+    fn gzip(self, other: Self) -> Result<Self::Output, ZipError> {
+        let (Con(inner, _), Con(other, _)) = (self, other);
+        Ok(Con(inner.gzip(other)?, PhantomData))
+    }
+}
+
+impl<A> GZip for List<A>
+where
+    A: GZip,
+{
+    type Output = List<A::Output>;
+
+    fn gzip(self, other: Self) -> Result<Self::Output, ZipError> {
+        let (List(items), List(other)) = (self, other);
+        if items.len() != other.len() {
+            return Err(ZipError::Length {
+                left: items.len(),
+                right: other.len(),
+            });
+        }
+        items
+            .into_iter()
+            .zip(other)
+            .map(|(a, b)| a.gzip(b))
+            .collect::<Result<_, _>>()
+            .map(List)
+    }
+}
+
+impl<A, const N: usize> GZip for [A; N]
+where
+    A: GZip,
+{
+    type Output = [A::Output; N];
+
+    fn gzip(self, other: Self) -> Result<Self::Output, ZipError> {
+        let mut other = IntoIterator::into_iter(other);
+        let mut error = None;
+        let zipped = self.map(|item| match item.gzip(other.next().unwrap()) {
+            Ok(zipped) => Some(zipped),
+            Err(e) => {
+                error.get_or_insert(e);
+                None
+            }
+        });
+        match error {
+            Some(e) => Err(e),
+            None => Ok(zipped.map(Option::unwrap)),
+        }
+    }
+}
+
+impl<T> GZip for T
+where
+    T: Leaf,
+{
+    type Output = (T, T);
+
+    fn gzip(self, other: Self) -> Result<Self::Output, ZipError> {
+        Ok((self, other))
+    }
+}
+
+/// Zips the representations of `a` and `b`.
+pub fn gzip<T>(a: T, b: T) -> Result<<T::Repr as GZip>::Output, ZipError>
+where
+    T: Generic,
+    T::Repr: GZip,
+{
+    a.into_repr().gzip(b.into_repr())
+}
@@ -0,0 +1,58 @@
+use std::marker::PhantomData;
+
+use crate::markers::{Option_None_Name, Option_Some_0, Option_Some_Name};
+use crate::{Con, Generic, GenericMut, Meta, Prod, ReprError, Sum, Unit, Void};
+
+impl<T> Generic for Option<T>
+where
+    T: Generic,
+{
+    type Repr = Sum<
+        Con<Unit, Option_None_Name>,
+        Sum<Con<Prod<Meta<T::Repr, Option_Some_0>, Unit>, Option_Some_Name>, Void>,
+    >;
+    fn into_repr(self) -> Self::Repr {
+        match self {
+            None => Sum::Left(Con(Unit, PhantomData)),
+            Some(x) => Sum::Right(Sum::Left(Con(
+                Prod(Meta(x.into_repr(), PhantomData), Unit),
+                PhantomData,
+            ))),
+        }
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        match repr {
+            Sum::Left(Con(Unit, _)) => None,
+            Sum::Right(Sum::Left(Con(Prod(Meta(x, _), Unit), _))) => Some(T::from_repr(x)),
+            Sum::Right(Sum::Right(void)) => match void {},
+        }
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        match repr {
+            Sum::Left(Con(Unit, _)) => Ok(None),
+            Sum::Right(Sum::Left(Con(Prod(Meta(x, _), Unit), _))) => {
+                T::checked_from_repr(x).map(Some)
+            }
+            Sum::Right(Sum::Right(void)) => match void {},
+        }
+    }
+}
+
+impl<'a, T> GenericMut<'a> for Option<T>
+where
+    T: GenericMut<'a>,
+{
+    type ReprMut = Sum<
+        Con<Unit, Option_None_Name>,
+        Sum<Con<Prod<Meta<T::ReprMut, Option_Some_0>, Unit>, Option_Some_Name>, Void>,
+    >;
+    fn as_repr_mut(&'a mut self) -> Self::ReprMut {
+        match self {
+            None => Sum::Left(Con(Unit, PhantomData)),
+            Some(x) => Sum::Right(Sum::Left(Con(
+                Prod(Meta(x.as_repr_mut(), PhantomData), Unit),
+                PhantomData,
+            ))),
+        }
+    }
+}
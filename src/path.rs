@@ -0,0 +1,97 @@
+use crate::Field;
+
+/// Type-level lookup of a nested field by a path of selectors, as built by `path!`.
+///
+/// A path is a list of selectors `(A, (B, (C, ())))`, looking up `A` with `Field`,
+/// then `B` in the representation of that field, and so on. The empty path `()` resolves to the value itself.
+///
+/// `Indices` are the positions of each field, as used by `Field`. They should be left for the compiler to infer.
+///
+/// Looking up a path which isn't present is a compile error.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::{path, FieldPath, Generic};
+///
+/// #[derive(Generic)]
+/// struct Inner {
+///     c: u8,
+/// }
+///
+/// #[derive(Generic)]
+/// struct Foo {
+///     a: u8,
+///     inner: Inner,
+/// }
+///
+/// let mut repr = Foo { a: 1, inner: Inner { c: 2 } }.into_repr();
+///
+/// assert_eq!(*FieldPath::<path!(Foo_inner.Inner_c), _>::field_path(&repr), 2);
+///
+/// *FieldPath::<path!(Foo_inner.Inner_c), _>::field_path_mut(&mut repr) = 42;
+/// assert_eq!(Foo::from_repr(repr).inner.c, 42);
+/// ```
+pub trait FieldPath<Path, Indices> {
+    /// The representation of the field at the end of the path.
+    type Output;
+
+    /// Borrows the field.
+    fn field_path(&self) -> &Self::Output;
+
+    /// Mutably borrows the field.
+    fn field_path_mut(&mut self) -> &mut Self::Output;
+
+    /// Moves out the field, discarding the rest.
+    fn into_field_path(self) -> Self::Output;
+}
+
+impl<R> FieldPath<(), ()> for R {
+    type Output = R;
+
+    fn field_path(&self) -> &Self::Output {
+        self
+    }
+
+    fn field_path_mut(&mut self) -> &mut Self::Output {
+        self
+    }
+
+    fn into_field_path(self) -> Self::Output {
+        self
+    }
+}
+
+impl<R, Name, Path, Index, Indices> FieldPath<(Name, Path), (Index, Indices)> for R
+where
+    R: Field<Name, Index>,
+    R::Output: FieldPath<Path, Indices>,
+    Name: 'static,
+    Index: 'static,
+{
+    type Output = <R::Output as FieldPath<Path, Indices>>::Output;
+
+    fn field_path(&self) -> &Self::Output {
+        self.field().field_path()
+    }
+
+    fn field_path_mut(&mut self) -> &mut Self::Output {
+        self.field_mut().field_path_mut()
+    }
+
+    fn into_field_path(self) -> Self::Output {
+        self.into_field().into_field_path()
+    }
+}
+
+/// The type of a path of selectors, for use with `FieldPath`.
+///
+/// `path!(Foo_inner.Inner_c)` expands to `(Foo_inner, (Inner_c, ()))`,
+/// i.e. the field `c` of the field `inner` of `Foo`.
+#[macro_export]
+macro_rules! path {
+    () => { () };
+    ($head:ident $( . $tail:ident )*) => {
+        ($head, $crate::path!($( $tail ).*))
+    };
+}
@@ -0,0 +1,18 @@
+use std::marker::PhantomData;
+
+use crate::{Generic, Unit};
+
+/// `PhantomData` holds no data, so it's represented as `Unit`, regardless of `T`.
+impl<T> Generic for PhantomData<T>
+where
+    T: ?Sized,
+{
+    type Repr = Unit;
+    fn into_repr(self) -> Self::Repr {
+        Unit
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let Unit = repr;
+        PhantomData
+    }
+}
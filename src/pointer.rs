@@ -0,0 +1,77 @@
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::{Generic, ReprError};
+
+impl<T> Generic for Box<T>
+where
+    T: Generic,
+{
+    type Repr = T::Repr;
+    fn into_repr(self) -> Self::Repr {
+        (*self).into_repr()
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        Box::new(T::from_repr(repr))
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        T::checked_from_repr(repr).map(Box::new)
+    }
+}
+
+/// The pointee is moved out if this is the only reference to it, and cloned otherwise.
+impl<T> Generic for Rc<T>
+where
+    T: Generic + Clone,
+{
+    type Repr = T::Repr;
+    fn into_repr(self) -> Self::Repr {
+        Rc::try_unwrap(self)
+            .unwrap_or_else(|rc| T::clone(&rc))
+            .into_repr()
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        Rc::new(T::from_repr(repr))
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        T::checked_from_repr(repr).map(Rc::new)
+    }
+}
+
+/// The pointee is moved out if this is the only reference to it, and cloned otherwise.
+impl<T> Generic for Arc<T>
+where
+    T: Generic + Clone,
+{
+    type Repr = T::Repr;
+    fn into_repr(self) -> Self::Repr {
+        Arc::try_unwrap(self)
+            .unwrap_or_else(|arc| T::clone(&arc))
+            .into_repr()
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        Arc::new(T::from_repr(repr))
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        T::checked_from_repr(repr).map(Arc::new)
+    }
+}
+
+/// Borrowed values are converted into their owned form, and values are always reconstructed as `Cow::Owned`.
+impl<'a, B> Generic for Cow<'a, B>
+where
+    B: ToOwned + ?Sized,
+    B::Owned: Generic,
+{
+    type Repr = <B::Owned as Generic>::Repr;
+    fn into_repr(self) -> Self::Repr {
+        self.into_owned().into_repr()
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        Cow::Owned(B::Owned::from_repr(repr))
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        B::Owned::checked_from_repr(repr).map(Cow::Owned)
+    }
+}
@@ -1,23 +1,77 @@
-use crate::Generic;
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use crate::{Generic, GenericMut, Leaf};
 
 macro_rules! impl_identity {
     ( $( $ty:ty ),+ $(,)? ) => {
         $(
             impl Generic for $ty {
                 type Repr = $ty;
-                fn into_repr(self: Self) -> Self::Repr {
+                fn into_repr(self) -> Self::Repr {
                     self
                 }
                 fn from_repr(repr: Self::Repr) -> Self {
                     repr
                 }
             }
+
+            impl Leaf for $ty {}
         )+
     }
 }
 
 #[rustfmt::skip]
 impl_identity!(
-    u8, u16, u32, u64, u128,
-    i8, i16, i32, i64, i128,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64,
+    bool, char,
+    String, Box<str>,
+    OsString, Box<OsStr>,
+    PathBuf, Box<Path>,
+    CString, Box<CStr>,
 );
+
+/// References are leaves, since the referent can't be moved out of them.
+impl<T> Generic for &T
+where
+    T: ?Sized,
+{
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+impl<T> Leaf for &T where T: ?Sized {}
+
+/// References are leaves, since the referent can't be moved out of them.
+impl<T> Generic for &mut T
+where
+    T: ?Sized,
+{
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+impl<T> Leaf for &mut T where T: ?Sized {}
+
+/// Leaves are borrowed as-is.
+impl<'a, T> GenericMut<'a> for T
+where
+    T: Leaf + 'a,
+{
+    type ReprMut = &'a mut T;
+    fn as_repr_mut(&'a mut self) -> Self::ReprMut {
+        self
+    }
+}
@@ -0,0 +1,181 @@
+use std::marker::PhantomData;
+use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::markers::*;
+use crate::{Con, Generic, Meta, Prod, ReprError, Sum, Unit, Void};
+
+impl<T> Generic for Range<T>
+where
+    T: Generic,
+{
+    type Repr =
+        Con<Prod<Meta<T::Repr, Range_start>, Prod<Meta<T::Repr, Range_end>, Unit>>, Range_Name>;
+    fn into_repr(self) -> Self::Repr {
+        Con(
+            Prod(
+                Meta(self.start.into_repr(), PhantomData),
+                Prod(Meta(self.end.into_repr(), PhantomData), Unit),
+            ),
+            PhantomData,
+        )
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let Con(Prod(Meta(start, _), Prod(Meta(end, _), Unit)), _) = repr;
+        T::from_repr(start)..T::from_repr(end)
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let Con(Prod(Meta(start, _), Prod(Meta(end, _), Unit)), _) = repr;
+        Ok(T::checked_from_repr(start)?..T::checked_from_repr(end)?)
+    }
+}
+
+/// Represented by its endpoints, so whether an iterator over it has been exhausted is not preserved.
+impl<T> Generic for RangeInclusive<T>
+where
+    T: Generic,
+{
+    type Repr = Con<
+        Prod<Meta<T::Repr, RangeInclusive_start>, Prod<Meta<T::Repr, RangeInclusive_end>, Unit>>,
+        RangeInclusive_Name,
+    >;
+    fn into_repr(self) -> Self::Repr {
+        let (start, end) = self.into_inner();
+        Con(
+            Prod(
+                Meta(start.into_repr(), PhantomData),
+                Prod(Meta(end.into_repr(), PhantomData), Unit),
+            ),
+            PhantomData,
+        )
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let Con(Prod(Meta(start, _), Prod(Meta(end, _), Unit)), _) = repr;
+        T::from_repr(start)..=T::from_repr(end)
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let Con(Prod(Meta(start, _), Prod(Meta(end, _), Unit)), _) = repr;
+        Ok(T::checked_from_repr(start)?..=T::checked_from_repr(end)?)
+    }
+}
+
+impl<T> Generic for RangeFrom<T>
+where
+    T: Generic,
+{
+    type Repr = Con<Prod<Meta<T::Repr, RangeFrom_start>, Unit>, RangeFrom_Name>;
+    fn into_repr(self) -> Self::Repr {
+        Con(
+            Prod(Meta(self.start.into_repr(), PhantomData), Unit),
+            PhantomData,
+        )
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let Con(Prod(Meta(start, _), Unit), _) = repr;
+        T::from_repr(start)..
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let Con(Prod(Meta(start, _), Unit), _) = repr;
+        Ok(T::checked_from_repr(start)?..)
+    }
+}
+
+impl<T> Generic for RangeTo<T>
+where
+    T: Generic,
+{
+    type Repr = Con<Prod<Meta<T::Repr, RangeTo_end>, Unit>, RangeTo_Name>;
+    fn into_repr(self) -> Self::Repr {
+        Con(
+            Prod(Meta(self.end.into_repr(), PhantomData), Unit),
+            PhantomData,
+        )
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let Con(Prod(Meta(end, _), Unit), _) = repr;
+        ..T::from_repr(end)
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let Con(Prod(Meta(end, _), Unit), _) = repr;
+        Ok(..T::checked_from_repr(end)?)
+    }
+}
+
+impl<T> Generic for RangeToInclusive<T>
+where
+    T: Generic,
+{
+    type Repr = Con<Prod<Meta<T::Repr, RangeToInclusive_end>, Unit>, RangeToInclusive_Name>;
+    fn into_repr(self) -> Self::Repr {
+        Con(
+            Prod(Meta(self.end.into_repr(), PhantomData), Unit),
+            PhantomData,
+        )
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let Con(Prod(Meta(end, _), Unit), _) = repr;
+        ..=T::from_repr(end)
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let Con(Prod(Meta(end, _), Unit), _) = repr;
+        Ok(..=T::checked_from_repr(end)?)
+    }
+}
+
+impl Generic for RangeFull {
+    type Repr = Con<Unit, RangeFull_Name>;
+    fn into_repr(self) -> Self::Repr {
+        Con(Unit, PhantomData)
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let Con(Unit, _) = repr;
+        ..
+    }
+}
+
+impl<T> Generic for Bound<T>
+where
+    T: Generic,
+{
+    type Repr = Sum<
+        Con<Prod<Meta<T::Repr, Bound_Included_0>, Unit>, Bound_Included_Name>,
+        Sum<
+            Con<Prod<Meta<T::Repr, Bound_Excluded_0>, Unit>, Bound_Excluded_Name>,
+            Sum<Con<Unit, Bound_Unbounded_Name>, Void>,
+        >,
+    >;
+    fn into_repr(self) -> Self::Repr {
+        match self {
+            Bound::Included(x) => Sum::Left(Con(
+                Prod(Meta(x.into_repr(), PhantomData), Unit),
+                PhantomData,
+            )),
+            Bound::Excluded(x) => Sum::Right(Sum::Left(Con(
+                Prod(Meta(x.into_repr(), PhantomData), Unit),
+                PhantomData,
+            ))),
+            Bound::Unbounded => Sum::Right(Sum::Right(Sum::Left(Con(Unit, PhantomData)))),
+        }
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        match repr {
+            Sum::Left(Con(Prod(Meta(x, _), Unit), _)) => Bound::Included(T::from_repr(x)),
+            Sum::Right(Sum::Left(Con(Prod(Meta(x, _), Unit), _))) => {
+                Bound::Excluded(T::from_repr(x))
+            }
+            Sum::Right(Sum::Right(Sum::Left(Con(Unit, _)))) => Bound::Unbounded,
+            Sum::Right(Sum::Right(Sum::Right(void))) => match void {},
+        }
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        match repr {
+            Sum::Left(Con(Prod(Meta(x, _), Unit), _)) => {
+                T::checked_from_repr(x).map(Bound::Included)
+            }
+            Sum::Right(Sum::Left(Con(Prod(Meta(x, _), Unit), _))) => {
+                T::checked_from_repr(x).map(Bound::Excluded)
+            }
+            Sum::Right(Sum::Right(Sum::Left(Con(Unit, _)))) => Ok(Bound::Unbounded),
+            Sum::Right(Sum::Right(Sum::Right(void))) => match void {},
+        }
+    }
+}
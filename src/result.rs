@@ -0,0 +1,66 @@
+use std::marker::PhantomData;
+
+use crate::markers::{Result_Err_0, Result_Err_Name, Result_Ok_0, Result_Ok_Name};
+use crate::{Con, Generic, GenericMut, Meta, Prod, ReprError, Sum, Unit, Void};
+
+impl<T, E> Generic for Result<T, E>
+where
+    T: Generic,
+    E: Generic,
+{
+    type Repr = Sum<
+        Con<Prod<Meta<T::Repr, Result_Ok_0>, Unit>, Result_Ok_Name>,
+        Sum<Con<Prod<Meta<E::Repr, Result_Err_0>, Unit>, Result_Err_Name>, Void>,
+    >;
+    fn into_repr(self) -> Self::Repr {
+        match self {
+            Ok(x) => Sum::Left(Con(
+                Prod(Meta(x.into_repr(), PhantomData), Unit),
+                PhantomData,
+            )),
+            Err(e) => Sum::Right(Sum::Left(Con(
+                Prod(Meta(e.into_repr(), PhantomData), Unit),
+                PhantomData,
+            ))),
+        }
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        match repr {
+            Sum::Left(Con(Prod(Meta(x, _), Unit), _)) => Ok(T::from_repr(x)),
+            Sum::Right(Sum::Left(Con(Prod(Meta(e, _), Unit), _))) => Err(E::from_repr(e)),
+            Sum::Right(Sum::Right(void)) => match void {},
+        }
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        match repr {
+            Sum::Left(Con(Prod(Meta(x, _), Unit), _)) => T::checked_from_repr(x).map(Ok),
+            Sum::Right(Sum::Left(Con(Prod(Meta(e, _), Unit), _))) => {
+                E::checked_from_repr(e).map(Err)
+            }
+            Sum::Right(Sum::Right(void)) => match void {},
+        }
+    }
+}
+
+impl<'a, T, E> GenericMut<'a> for Result<T, E>
+where
+    T: GenericMut<'a>,
+    E: GenericMut<'a>,
+{
+    type ReprMut = Sum<
+        Con<Prod<Meta<T::ReprMut, Result_Ok_0>, Unit>, Result_Ok_Name>,
+        Sum<Con<Prod<Meta<E::ReprMut, Result_Err_0>, Unit>, Result_Err_Name>, Void>,
+    >;
+    fn as_repr_mut(&'a mut self) -> Self::ReprMut {
+        match self {
+            Ok(x) => Sum::Left(Con(
+                Prod(Meta(x.as_repr_mut(), PhantomData), Unit),
+                PhantomData,
+            )),
+            Err(e) => Sum::Right(Sum::Left(Con(
+                Prod(Meta(e.as_repr_mut(), PhantomData), Unit),
+                PhantomData,
+            ))),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt::{self, Debug};
+
+use crate::{Generic, Leaf};
+
+/// A leaf wrapping a secret value, which is redacted as `***` in debug output.
+///
+/// Like `Opaque`, the value is left as-is instead of being converted to its representation,
+/// so no generic formatter can reach the leaves inside it.
+///
+/// Fields of a type deriving `Generic` can be wrapped automatically with `#[generic(sensitive)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use generics::ops::gdebug;
+/// use generics::Generic;
+///
+/// #[derive(Generic)]
+/// struct Login {
+///     user: String,
+///     #[generic(sensitive)]
+///     password: String,
+/// }
+///
+/// let login = Login { user: "admin".to_string(), password: "hunter2".to_string() };
+/// assert_eq!(format!("{:?}", gdebug(login)), r#"Login { user: "admin", password: *** }"#);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Sensitive<T>(pub T);
+
+/// Formats `***` regardless of the wrapped value.
+impl<T> Debug for Sensitive<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl<T> Generic for Sensitive<T> {
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+impl<T> Leaf for Sensitive<T> {}
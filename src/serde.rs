@@ -0,0 +1,1146 @@
+//! Serde support for representations, behind the `serde` feature.
+//!
+//! `Serialize` and `Deserialize` are implemented for the representation types, so any `Generic` type
+//! can be serialized by converting it to its representation, without `#[derive(Serialize, Deserialize)]`.
+//! The format is the same as serde's derive, with the same calls to the `Serializer` and `Deserializer`:
+//!
+//! - Structs (`Con`) are serialized as structs, with field names taken from their `Meta`. How the constructor
+//!   was declared is taken from its `Constructor` impl, so tuple structs are serialized as tuple structs,
+//!   or newtype structs if they have one field, and unit structs as unit structs.
+//! - Enums (`Sum`) are serialized as externally tagged enums, with the names of the type and its variants
+//!   taken from their `Datatype` and `Con`, and each variant serialized like a struct would be. `Option` is
+//!   serialized as an option.
+//! - Tuples (bare `Prod` chains) and arrays are serialized as tuples, `Unit` as a unit, and `List` as a sequence.
+//! - `Meta` and `Opaque` are transparent, and other leaves use their own impls. `Sensitive` isn't serializable.
+//!
+//! When deserializing a struct, it may also be a sequence of its fields, unknown fields are ignored,
+//! and missing fields are an error, except that missing `Option`s are `None`, as with serde's derive.
+//! Types with `#[generic(rename)]` use the new names, and serde's attributes have no effect,
+//! since the derive never sees them.
+//!
+//! `serialize` and `deserialize` convert to and from the representation, so a field of a type implementing `Generic`
+//! can be marked `#[serde(with = "generics::serde")]` in a type deriving serde's traits.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use generics::Generic;
+//!
+//! #[derive(Generic, Debug, PartialEq)]
+//! struct Config {
+//!     name: String,
+//!     retries: Option<u8>,
+//!     mode: Mode,
+//! }
+//!
+//! #[derive(Generic, Debug, PartialEq)]
+//! enum Mode {
+//!     Fast,
+//!     Careful { checks: Vec<String> },
+//! }
+//!
+//! let config = Config {
+//!     name: "app".to_string(),
+//!     retries: None,
+//!     mode: Mode::Careful { checks: vec!["disk".to_string()] },
+//! };
+//! let json = serde_json::to_string(&config.into_repr()).unwrap();
+//! assert_eq!(json, r#"{"name":"app","retries":null,"mode":{"Careful":{"checks":["disk"]}}}"#);
+//!
+//! let json = r#"{"mode":"Fast","retries":3,"name":"cli","extra":true}"#;
+//! let config = Config::from_repr(serde_json::from_str(json).unwrap());
+//! assert_eq!(config, Config { name: "cli".to_string(), retries: Some(3), mode: Mode::Fast });
+//!
+//! let json = r#"{"name":"cli","mode":"Fast"}"#;
+//! let config = Config::from_repr(serde_json::from_str(json).unwrap());
+//! assert_eq!(config.retries, None);
+//! ```
+
+use std::any::TypeId;
+use std::convert::TryFrom;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{
+    self, DeserializeSeed, EnumAccess, Expected, IgnoredAny, MapAccess, SeqAccess, VariantAccess,
+    Visitor,
+};
+use serde::ser::{
+    SerializeStruct, SerializeStructVariant, SerializeTuple, SerializeTupleStruct,
+    SerializeTupleVariant,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::markers::{Option_None_Name, Option_Some_Name};
+use crate::{
+    Con, ConKind, Constructor, Datatype, Generic, List, Meta, Opaque, Prod, Singleton, Sum, Unit,
+    Void,
+};
+
+/// Serializes `x` as its representation, for use with `#[serde(with = "generics::serde")]`.
+pub fn serialize<T, S>(x: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Generic + Clone,
+    T::Repr: Serialize,
+    S: Serializer,
+{
+    x.clone().into_repr().serialize(serializer)
+}
+
+/// Deserializes a `T` from its representation, for use with `#[serde(with = "generics::serde")]`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Generic,
+    T::Repr: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let repr = T::Repr::deserialize(deserializer)?;
+    T::checked_from_repr(repr).map_err(de::Error::custom)
+}
+
+/// A struct or variant being serialized, which its fields are serialized into.
+pub enum Compound<S>
+where
+    S: Serializer,
+{
+    /// A struct with named fields.
+    Struct(S::SerializeStruct),
+    /// A tuple struct.
+    TupleStruct(S::SerializeTupleStruct),
+    /// An enum variant with named fields.
+    StructVariant(S::SerializeStructVariant),
+    /// A tuple enum variant.
+    TupleVariant(S::SerializeTupleVariant),
+}
+
+impl<S> Compound<S>
+where
+    S: Serializer,
+{
+    /// Serializes a field, whose name is ignored unless `self` has named fields.
+    pub fn serialize_field<T>(&mut self, name: &'static str, value: &T) -> Result<(), S::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        match self {
+            Compound::Struct(s) => s.serialize_field(name, value),
+            Compound::TupleStruct(s) => s.serialize_field(value),
+            Compound::StructVariant(s) => s.serialize_field(name, value),
+            Compound::TupleVariant(s) => s.serialize_field(value),
+        }
+    }
+
+    /// Finishes serializing `self`.
+    pub fn end(self) -> Result<S::Ok, S::Error> {
+        match self {
+            Compound::Struct(s) => s.end(),
+            Compound::TupleStruct(s) => s.end(),
+            Compound::StructVariant(s) => s.end(),
+            Compound::TupleVariant(s) => s.end(),
+        }
+    }
+}
+
+/// Serializes the elements of a `Prod` chain, as a tuple.
+pub trait SerializeElements {
+    /// The number of elements.
+    const LEN: usize;
+
+    /// Serializes each element in order.
+    fn serialize_elements<S>(&self, tuple: &mut S) -> Result<(), S::Error>
+    where
+        S: SerializeTuple;
+}
+
+/// Deserializes the elements of a `Prod` chain, from a tuple.
+pub trait DeserializeElements<'de>: Sized {
+    /// The number of elements.
+    const LEN: usize;
+
+    /// Deserializes each element in order, starting at `index`.
+    fn deserialize_elements<A>(
+        seq: &mut A,
+        index: usize,
+        expected: &dyn Expected,
+    ) -> Result<Self, A::Error>
+    where
+        A: SeqAccess<'de>;
+}
+
+/// Serializes the fields of a constructor, i.e. a `Prod` chain of `Meta`s.
+pub trait SerializeFields {
+    /// The number of fields.
+    const LEN: usize;
+
+    /// Serializes each field in order, with its name.
+    fn serialize_fields<S>(&self, compound: &mut Compound<S>) -> Result<(), S::Error>
+    where
+        S: Serializer;
+
+    /// Serializes the first field alone, for newtype structs and variants.
+    fn serialize_first<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+/// Deserializes the fields of a constructor, i.e. a `Prod` chain of `Meta`s, from a map in any order, or a sequence.
+pub trait DeserializeFields<'de>: DeserializeElements<'de> {
+    /// The fields deserialized so far, each an `Option`.
+    type Slots: Default;
+
+    /// Deserializes the value of the field at `index` into `slots`.
+    fn deserialize_field<A>(
+        slots: &mut Self::Slots,
+        index: usize,
+        map: &mut A,
+    ) -> Result<(), A::Error>
+    where
+        A: MapAccess<'de>;
+
+    /// Constructs the fields from `slots`, failing if any are missing, unless they're options, which are `None`.
+    fn from_slots<E>(slots: Self::Slots) -> Result<Self, E>
+    where
+        E: de::Error;
+
+    /// Deserializes the first field alone, for newtype structs and variants, failing if there are others.
+    fn deserialize_first<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// Serializes whichever variant of a `Sum` chain is held.
+pub trait SerializeVariants {
+    /// Serializes the held variant, whose index is `index` plus its position in the chain.
+    fn serialize_variant<S>(&self, index: u32, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+/// Deserializes the variant of a `Sum` chain at a given index.
+pub trait DeserializeVariants<'de>: Sized {
+    /// The name of the type the variants belong to.
+    fn datatype_name() -> &'static str;
+
+    /// The name of each variant, in order.
+    fn variant_names() -> &'static [&'static str];
+
+    /// Whether the chain is the representation of an `Option`.
+    fn is_option() -> bool;
+
+    /// Deserializes the variant at position `index` in the chain.
+    fn deserialize_variant<A>(index: usize, variant: A) -> Result<Self, A::Error>
+    where
+        A: VariantAccess<'de>;
+
+    /// Constructs the variant at position `index` in the chain, which has no fields.
+    fn unit_variant<E>(index: usize) -> Result<Self, E>
+    where
+        E: de::Error;
+
+    /// Deserializes the variant at position `index` in the chain, which has one field, from `deserializer`.
+    fn newtype_variant<D>(index: usize, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// Serializes the fields of a constructor `C` into the compound for its kind.
+fn serialize_con<I, C, S>(inner: &I, serializer: S, variant: Option<u32>) -> Result<S::Ok, S::Error>
+where
+    I: SerializeFields,
+    C: Singleton<T = &'static str> + Constructor + Datatype,
+    S: Serializer,
+{
+    let name = C::get();
+    let len = I::LEN;
+    let mut compound: Compound<S> = match (C::con_kind(), variant) {
+        (ConKind::Unit, None) => return serializer.serialize_unit_struct(name),
+        (ConKind::Unit, Some(index)) => {
+            return serializer.serialize_unit_variant(C::datatype_name(), index, name)
+        }
+        (ConKind::Tuple, None) if len == 1 => {
+            return serializer.serialize_newtype_struct(name, &First(inner))
+        }
+        (ConKind::Tuple, Some(index)) if len == 1 => {
+            return serializer.serialize_newtype_variant(
+                C::datatype_name(),
+                index,
+                name,
+                &First(inner),
+            )
+        }
+        (ConKind::Tuple, None) => {
+            Compound::TupleStruct(serializer.serialize_tuple_struct(name, len)?)
+        }
+        (ConKind::Tuple, Some(index)) => Compound::TupleVariant(
+            serializer.serialize_tuple_variant(C::datatype_name(), index, name, len)?,
+        ),
+        (ConKind::Braced, None) => Compound::Struct(serializer.serialize_struct(name, len)?),
+        (ConKind::Braced, Some(index)) => Compound::StructVariant(
+            serializer.serialize_struct_variant(C::datatype_name(), index, name, len)?,
+        ),
+    };
+    inner.serialize_fields(&mut compound)?;
+    compound.end()
+}
+
+/// Adapts a newtype's fields to `Serialize`, via `SerializeFields::serialize_first`.
+struct First<'a, T>(&'a T);
+
+impl<T> Serialize for First<'_, T>
+where
+    T: SerializeFields,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_first(serializer)
+    }
+}
+
+/// Deserializes the fields of a newtype variant, via `DeserializeFields::deserialize_first`.
+struct FirstSeed<T>(PhantomData<fn() -> T>);
+
+impl<'de, T> DeserializeSeed<'de> for FirstSeed<T>
+where
+    T: DeserializeFields<'de>,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize_first(deserializer)
+    }
+}
+
+/// Stands in for the value of a missing field, like serde's derive: an option deserializes as `None`,
+/// and anything else fails with `missing_field`.
+struct MissingField<E>(&'static str, PhantomData<E>);
+
+impl<'de, E> Deserializer<'de> for MissingField<E>
+where
+    E: de::Error,
+{
+    type Error = E;
+
+    fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        Err(E::missing_field(self.0))
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_none()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Identifies a field or variant by its name or index, returning `None` for unknown fields.
+struct Identifier {
+    names: &'static [&'static str],
+    variant: bool,
+}
+
+impl Identifier {
+    fn index<E>(&self, index: u64) -> Result<Option<usize>, E>
+    where
+        E: de::Error,
+    {
+        match usize::try_from(index) {
+            Ok(index) if index < self.names.len() => Ok(Some(index)),
+            _ if self.variant => Err(E::invalid_value(
+                de::Unexpected::Unsigned(index),
+                &&*format!("variant index 0 <= i < {}", self.names.len()),
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    fn name<E>(&self, name: &str) -> Result<Option<usize>, E>
+    where
+        E: de::Error,
+    {
+        match self.names.iter().position(|&n| n == name) {
+            Some(index) => Ok(Some(index)),
+            None if self.variant => Err(E::unknown_variant(name, self.names)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for Identifier {
+    type Value = Option<usize>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Identifier {
+    type Value = Option<usize>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.variant {
+            f.write_str("variant identifier")
+        } else {
+            f.write_str("field identifier")
+        }
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.index(v)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.name(v)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.name(&String::from_utf8_lossy(v))
+    }
+}
+
+/// Visits the fields of a constructor, named `name`, as a map or sequence.
+struct FieldsVisitor<T> {
+    kind: &'static str,
+    name: &'static str,
+    fields: &'static [&'static str],
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> FieldsVisitor<T> {
+    fn new(kind: &'static str, name: &'static str, fields: &'static [&'static str]) -> Self {
+        FieldsVisitor {
+            kind,
+            name,
+            fields,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T> Visitor<'de> for FieldsVisitor<T>
+where
+    T: DeserializeFields<'de>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.kind, self.name)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        T::deserialize_elements(&mut seq, 0, &self)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut slots = T::Slots::default();
+        let identifier = || Identifier {
+            names: self.fields,
+            variant: false,
+        };
+        while let Some(index) = map.next_key_seed(identifier())? {
+            match index {
+                Some(index) => T::deserialize_field(&mut slots, index, &mut map)?,
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        T::from_slots(slots)
+    }
+}
+
+impl Serialize for Unit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_unit()
+    }
+}
+
+impl<'de> Deserialize<'de> for Unit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <()>::deserialize(deserializer).map(|()| Unit)
+    }
+}
+
+impl<A, B> Serialize for Prod<A, B>
+where
+    Self: SerializeElements,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(Self::LEN)?;
+        self.serialize_elements(&mut tuple)?;
+        tuple.end()
+    }
+}
+
+impl<'de, A, B> Deserialize<'de> for Prod<A, B>
+where
+    Self: DeserializeElements<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ProdVisitor<T>(PhantomData<fn() -> T>);
+
+        impl<'de, T> Visitor<'de> for ProdVisitor<T>
+        where
+            T: DeserializeElements<'de>,
+        {
+            type Value = T;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a tuple of size {}", T::LEN)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                T::deserialize_elements(&mut seq, 0, &self)
+            }
+        }
+
+        deserializer.deserialize_tuple(Self::LEN, ProdVisitor(PhantomData))
+    }
+}
+
+impl SerializeElements for Unit {
+    const LEN: usize = 0;
+
+    fn serialize_elements<S>(&self, _tuple: &mut S) -> Result<(), S::Error>
+    where
+        S: SerializeTuple,
+    {
+        Ok(())
+    }
+}
+
+impl<A, B> SerializeElements for Prod<A, B>
+where
+    A: Serialize,
+    B: SerializeElements,
+{
+    const LEN: usize = 1 + B::LEN;
+
+    fn serialize_elements<S>(&self, tuple: &mut S) -> Result<(), S::Error>
+    where
+        S: SerializeTuple,
+    {
+        let Prod(a, b) = self;
+        tuple.serialize_element(a)?;
+        b.serialize_elements(tuple)
+    }
+}
+
+impl<'de> DeserializeElements<'de> for Unit {
+    const LEN: usize = 0;
+
+    fn deserialize_elements<A>(
+        _seq: &mut A,
+        _index: usize,
+        _expected: &dyn Expected,
+    ) -> Result<Self, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        Ok(Unit)
+    }
+}
+
+impl<'de, A, B> DeserializeElements<'de> for Prod<A, B>
+where
+    A: Deserialize<'de>,
+    B: DeserializeElements<'de>,
+{
+    const LEN: usize = 1 + B::LEN;
+
+    fn deserialize_elements<S>(
+        seq: &mut S,
+        index: usize,
+        expected: &dyn Expected,
+    ) -> Result<Self, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let a = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(index, expected))?;
+        let b = B::deserialize_elements(seq, index + 1, expected)?;
+        Ok(Prod(a, b))
+    }
+}
+
+impl SerializeFields for Unit {
+    const LEN: usize = 0;
+
+    fn serialize_fields<S>(&self, _compound: &mut Compound<S>) -> Result<(), S::Error>
+    where
+        S: Serializer,
+    {
+        Ok(())
+    }
+
+    fn serialize_first<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_unit()
+    }
+}
+
+impl<A, M, B> SerializeFields for Prod<Meta<A, M>, B>
+where
+    A: Serialize,
+    M: Singleton<T = &'static str>,
+    B: SerializeFields,
+{
+    const LEN: usize = 1 + B::LEN;
+
+    fn serialize_fields<S>(&self, compound: &mut Compound<S>) -> Result<(), S::Error>
+    where
+        S: Serializer,
+    {
+        let Prod(Meta(a, _), b) = self;
+        compound.serialize_field(M::get(), a)?;
+        b.serialize_fields(compound)
+    }
+
+    fn serialize_first<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Prod(Meta(a, _), _) = self;
+        a.serialize(serializer)
+    }
+}
+
+impl<'de> DeserializeFields<'de> for Unit {
+    type Slots = ();
+
+    fn deserialize_field<A>(
+        _slots: &mut Self::Slots,
+        _index: usize,
+        _map: &mut A,
+    ) -> Result<(), A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        Ok(())
+    }
+
+    fn from_slots<E>(_slots: Self::Slots) -> Result<Self, E>
+    where
+        E: de::Error,
+    {
+        Ok(Unit)
+    }
+
+    fn deserialize_first<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <()>::deserialize(deserializer).map(|()| Unit)
+    }
+}
+
+impl<'de, A, M, B> DeserializeFields<'de> for Prod<Meta<A, M>, B>
+where
+    A: Deserialize<'de>,
+    M: Singleton<T = &'static str>,
+    B: DeserializeFields<'de>,
+{
+    type Slots = (Option<A>, B::Slots);
+
+    fn deserialize_field<S>(
+        slots: &mut Self::Slots,
+        index: usize,
+        map: &mut S,
+    ) -> Result<(), S::Error>
+    where
+        S: MapAccess<'de>,
+    {
+        if index > 0 {
+            return B::deserialize_field(&mut slots.1, index - 1, map);
+        }
+        if slots.0.is_some() {
+            return Err(de::Error::duplicate_field(M::get()));
+        }
+        slots.0 = Some(map.next_value()?);
+        Ok(())
+    }
+
+    fn from_slots<E>(slots: Self::Slots) -> Result<Self, E>
+    where
+        E: de::Error,
+    {
+        let (a, b) = slots;
+        let a = match a {
+            Some(a) => a,
+            None => A::deserialize(MissingField(M::get(), PhantomData))?,
+        };
+        Ok(Prod(Meta(a, PhantomData), B::from_slots(b)?))
+    }
+
+    fn deserialize_first<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let a = A::deserialize(deserializer)?;
+        let b = B::from_slots(B::Slots::default())?;
+        Ok(Prod(Meta(a, PhantomData), b))
+    }
+}
+
+impl<L, R> Serialize for Sum<L, R>
+where
+    Self: SerializeVariants,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize_variant(0, serializer)
+    }
+}
+
+impl<'de, L, R> Deserialize<'de> for Sum<L, R>
+where
+    Self: DeserializeVariants<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct EnumVisitor<T>(PhantomData<fn() -> T>);
+
+        impl<'de, T> Visitor<'de> for EnumVisitor<T>
+        where
+            T: DeserializeVariants<'de>,
+        {
+            type Value = T;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "enum {}", T::datatype_name())
+            }
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let identifier = Identifier {
+                    names: T::variant_names(),
+                    variant: true,
+                };
+                let (index, variant) = data.variant_seed(identifier)?;
+                let index = index.expect("unknown variants are an error");
+                T::deserialize_variant(index, variant)
+            }
+        }
+
+        struct OptionVisitor<T>(PhantomData<fn() -> T>);
+
+        impl<'de, T> Visitor<'de> for OptionVisitor<T>
+        where
+            T: DeserializeVariants<'de>,
+        {
+            type Value = T;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("option")
+            }
+
+            fn visit_none<E>(self) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                T::unit_variant(0)
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                T::unit_variant(0)
+            }
+
+            fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                T::newtype_variant(1, deserializer)
+            }
+        }
+
+        if Self::is_option() {
+            return deserializer.deserialize_option(OptionVisitor(PhantomData));
+        }
+        deserializer.deserialize_enum(
+            Self::datatype_name(),
+            Self::variant_names(),
+            EnumVisitor(PhantomData),
+        )
+    }
+}
+
+impl<I, C, R> SerializeVariants for Sum<Con<I, C>, R>
+where
+    I: SerializeFields,
+    C: Singleton<T = &'static str> + Constructor + Datatype + 'static,
+    R: SerializeVariants,
+{
+    fn serialize_variant<S>(&self, index: u32, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Con(inner, _) = match self {
+            Sum::Left(con) => con,
+            Sum::Right(r) => return r.serialize_variant(index + 1, serializer),
+        };
+        if TypeId::of::<C>() == TypeId::of::<Option_None_Name>() {
+            return serializer.serialize_none();
+        }
+        if TypeId::of::<C>() == TypeId::of::<Option_Some_Name>() {
+            return serializer.serialize_some(&First(inner));
+        }
+        serialize_con::<I, C, S>(inner, serializer, Some(index))
+    }
+}
+
+impl<'de, I, C, R> DeserializeVariants<'de> for Sum<Con<I, C>, R>
+where
+    I: DeserializeFields<'de>,
+    C: Singleton<T = &'static str> + Constructor + Datatype + 'static,
+    R: DeserializeVariants<'de>,
+{
+    fn datatype_name() -> &'static str {
+        C::datatype_name()
+    }
+
+    fn variant_names() -> &'static [&'static str] {
+        C::constructor_names()
+    }
+
+    fn is_option() -> bool {
+        TypeId::of::<C>() == TypeId::of::<Option_None_Name>()
+    }
+
+    fn deserialize_variant<A>(index: usize, variant: A) -> Result<Self, A::Error>
+    where
+        A: VariantAccess<'de>,
+    {
+        if index > 0 {
+            return R::deserialize_variant(index - 1, variant).map(Sum::Right);
+        }
+        let name = C::get();
+        let inner = match C::con_kind() {
+            ConKind::Unit => {
+                variant.unit_variant()?;
+                I::from_slots(Default::default())?
+            }
+            ConKind::Tuple if I::LEN == 1 => {
+                variant.newtype_variant_seed(FirstSeed(PhantomData))?
+            }
+            ConKind::Tuple => {
+                let visitor = FieldsVisitor::new("tuple variant", name, &[]);
+                variant.tuple_variant(I::LEN, visitor)?
+            }
+            ConKind::Braced => {
+                let fields = C::field_names();
+                let visitor = FieldsVisitor::new("struct variant", name, fields);
+                variant.struct_variant(fields, visitor)?
+            }
+        };
+        Ok(Sum::Left(Con(inner, PhantomData)))
+    }
+
+    fn unit_variant<E>(index: usize) -> Result<Self, E>
+    where
+        E: de::Error,
+    {
+        if index > 0 {
+            return R::unit_variant(index - 1).map(Sum::Right);
+        }
+        let inner = I::from_slots(Default::default())?;
+        Ok(Sum::Left(Con(inner, PhantomData)))
+    }
+
+    fn newtype_variant<D>(index: usize, deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if index > 0 {
+            return R::newtype_variant(index - 1, deserializer).map(Sum::Right);
+        }
+        let inner = I::deserialize_first(deserializer)?;
+        Ok(Sum::Left(Con(inner, PhantomData)))
+    }
+}
+
+impl SerializeVariants for Void {
+    fn serialize_variant<S>(&self, _index: u32, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {}
+    }
+}
+
+impl<'de> DeserializeVariants<'de> for Void {
+    fn datatype_name() -> &'static str {
+        ""
+    }
+
+    fn variant_names() -> &'static [&'static str] {
+        &[]
+    }
+
+    fn is_option() -> bool {
+        false
+    }
+
+    fn deserialize_variant<A>(_index: usize, _variant: A) -> Result<Self, A::Error>
+    where
+        A: VariantAccess<'de>,
+    {
+        unreachable!("unknown variants are an error")
+    }
+
+    fn unit_variant<E>(_index: usize) -> Result<Self, E>
+    where
+        E: de::Error,
+    {
+        unreachable!("`Option` has two variants")
+    }
+
+    fn newtype_variant<D>(_index: usize, _deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        unreachable!("`Option` has two variants")
+    }
+}
+
+impl Serialize for Void {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {}
+    }
+}
+
+impl<'de> Deserialize<'de> for Void {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Err(de::Error::custom(
+            "cannot deserialize an enum with no variants",
+        ))
+    }
+}
+
+impl<I, M> Serialize for Meta<I, M>
+where
+    I: Serialize,
+    M: Singleton,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, I, M> Deserialize<'de> for Meta<I, M>
+where
+    I: Deserialize<'de>,
+    M: Singleton,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        I::deserialize(deserializer).map(|inner| Meta(inner, PhantomData))
+    }
+}
+
+impl<I, C> Serialize for Con<I, C>
+where
+    I: SerializeFields,
+    C: Singleton<T = &'static str> + Constructor + Datatype,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_con::<I, C, S>(&self.0, serializer, None)
+    }
+}
+
+impl<'de, I, C> Deserialize<'de> for Con<I, C>
+where
+    I: DeserializeFields<'de>,
+    C: Singleton<T = &'static str> + Constructor,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct UnitVisitor<I>(&'static str, PhantomData<fn() -> I>);
+
+        impl<'de, I> Visitor<'de> for UnitVisitor<I>
+        where
+            I: DeserializeFields<'de>,
+        {
+            type Value = I;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "unit struct {}", self.0)
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                I::from_slots(Default::default())
+            }
+        }
+
+        struct NewtypeVisitor<I>(&'static str, PhantomData<fn() -> I>);
+
+        impl<'de, I> Visitor<'de> for NewtypeVisitor<I>
+        where
+            I: DeserializeFields<'de>,
+        {
+            type Value = I;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "tuple struct {}", self.0)
+            }
+
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                I::deserialize_first(deserializer)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                I::deserialize_elements(&mut seq, 0, &self)
+            }
+        }
+
+        let name = C::get();
+        let inner = match C::con_kind() {
+            ConKind::Unit => {
+                deserializer.deserialize_unit_struct(name, UnitVisitor(name, PhantomData))?
+            }
+            ConKind::Tuple if I::LEN == 1 => {
+                deserializer.deserialize_newtype_struct(name, NewtypeVisitor(name, PhantomData))?
+            }
+            ConKind::Tuple => {
+                let visitor = FieldsVisitor::new("tuple struct", name, &[]);
+                deserializer.deserialize_tuple_struct(name, I::LEN, visitor)?
+            }
+            ConKind::Braced => {
+                let fields = C::field_names();
+                let visitor = FieldsVisitor::new("struct", name, fields);
+                deserializer.deserialize_struct(name, fields, visitor)?
+            }
+        };
+        Ok(Con(inner, PhantomData))
+    }
+}
+
+impl<A> Serialize for List<A>
+where
+    A: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(&self.0)
+    }
+}
+
+impl<'de, A> Deserialize<'de> for List<A>
+where
+    A: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(List)
+    }
+}
+
+impl<T> Serialize for Opaque<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Opaque<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Opaque)
+    }
+}
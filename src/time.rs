@@ -0,0 +1,72 @@
+use std::marker::PhantomData;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::markers::{Duration_Name, Duration_nanos, Duration_secs};
+use crate::{Con, Generic, Leaf, Meta, Prod, ReprError, TryFromRepr, Unit};
+
+/// Represented as a struct with `secs: u64` and `nanos: u32` fields.
+///
+/// Like `Duration::new`, `nanos` may be a second or more, and carries into `secs`.
+///
+/// # Panics
+///
+/// `from_repr` panics if carrying `nanos` into `secs` overflows, and `checked_from_repr` fails.
+impl Generic for Duration {
+    type Repr =
+        Con<Prod<Meta<u64, Duration_secs>, Prod<Meta<u32, Duration_nanos>, Unit>>, Duration_Name>;
+    fn into_repr(self) -> Self::Repr {
+        Con(
+            Prod(
+                Meta(self.as_secs(), PhantomData),
+                Prod(Meta(self.subsec_nanos(), PhantomData), Unit),
+            ),
+            PhantomData,
+        )
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        match Self::checked_from_repr(repr) {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+        let Con(Prod(Meta(secs, _), Prod(Meta(nanos, _), Unit)), _) = repr;
+        Duration::from_secs(secs)
+            .checked_add(Duration::from_nanos(nanos.into()))
+            .ok_or_else(|| ReprError::new("`Duration` represented as more than `Duration::MAX`"))
+    }
+}
+
+/// Fails if carrying `nanos` into `secs` overflows.
+impl TryFromRepr for Duration {
+    type Error = ReprError;
+    fn try_from_repr(repr: Self::Repr) -> Result<Self, Self::Error> {
+        Self::checked_from_repr(repr)
+    }
+}
+
+/// `SystemTime` is a leaf, since it may not be representable relative to any particular point in time.
+impl Generic for SystemTime {
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+impl Leaf for SystemTime {}
+
+/// `Instant` is a leaf, since it's opaque.
+impl Generic for Instant {
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+impl Leaf for Instant {}
@@ -0,0 +1,87 @@
+use crate::{Generic, GenericMut, Prod, ReprError, Unit};
+
+impl Generic for () {
+    type Repr = Unit;
+    fn into_repr(self) -> Self::Repr {
+        Unit
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        let Unit = repr;
+    }
+}
+
+impl<'a> GenericMut<'a> for () {
+    type ReprMut = Unit;
+    fn as_repr_mut(&'a mut self) -> Self::ReprMut {
+        Unit
+    }
+}
+
+/// Builds a right-nested `Prod` chain ending in `Unit`, as a type, mutable representation type, expression, or pattern.
+macro_rules! prod {
+    (type_mut) => { Unit };
+    (type_mut $head:ident $( $tail:ident )*) => {
+        Prod<<$head as GenericMut<'a>>::ReprMut, prod!(type_mut $( $tail )*)>
+    };
+    (type) => { Unit };
+    (type $head:ident $( $tail:ident )*) => {
+        Prod<<$head as Generic>::Repr, prod!(type $( $tail )*)>
+    };
+    (value) => { Unit };
+    (value $head:ident $( $tail:ident )*) => {
+        Prod($head, prod!(value $( $tail )*))
+    };
+}
+
+macro_rules! impl_tuple {
+    ( $( ( $( $ty:ident $var:ident ),+ ) )+ ) => {
+        $(
+            impl<$( $ty ),+> Generic for ($( $ty, )+)
+            where
+                $( $ty: Generic, )+
+            {
+                type Repr = prod!(type $( $ty )+);
+                fn into_repr(self) -> Self::Repr {
+                    let ($( $var, )+) = self;
+                    $( let $var = $var.into_repr(); )+
+                    prod!(value $( $var )+)
+                }
+                fn from_repr(repr: Self::Repr) -> Self {
+                    let prod!(value $( $var )+) = repr;
+                    ($( $ty::from_repr($var), )+)
+                }
+                fn checked_from_repr(repr: Self::Repr) -> Result<Self, ReprError> {
+                    let prod!(value $( $var )+) = repr;
+                    Ok(($( $ty::checked_from_repr($var)?, )+))
+                }
+            }
+
+            impl<'a, $( $ty ),+> GenericMut<'a> for ($( $ty, )+)
+            where
+                $( $ty: GenericMut<'a>, )+
+            {
+                type ReprMut = prod!(type_mut $( $ty )+);
+                fn as_repr_mut(&'a mut self) -> Self::ReprMut {
+                    let ($( $var, )+) = self;
+                    $( let $var = $var.as_repr_mut(); )+
+                    prod!(value $( $var )+)
+                }
+            }
+        )+
+    };
+}
+
+impl_tuple! {
+    (A a)
+    (A a, B b)
+    (A a, B b, C c)
+    (A a, B b, C c, D d)
+    (A a, B b, C c, D d, E e)
+    (A a, B b, C c, D d, E e, F f)
+    (A a, B b, C c, D d, E e, F f, G g)
+    (A a, B b, C c, D d, E e, F f, G g, H h)
+    (A a, B b, C c, D d, E e, F f, G g, H h, I i)
+    (A a, B b, C c, D d, E e, F f, G g, H h, I i, J j)
+    (A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k)
+    (A a, B b, C c, D d, E e, F f, G g, H h, I i, J j, K k, L l)
+}
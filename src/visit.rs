@@ -0,0 +1,446 @@
+use std::fmt::Debug;
+
+use crate::{
+    Con, Generic, GenericMut, Leaf, List, Meta, Opaque, Prod, Sensitive, Singleton, Sum, Unit, Void,
+};
+
+/// Callbacks invoked while walking a representation with `Visit`.
+///
+/// Each method does nothing by default, so visitors only need to implement the callbacks they care about.
+///
+/// # Examples
+///
+/// Pretty-print the structure of a value.
+///
+/// ```rust
+/// use std::fmt::Debug;
+/// use generics::{visit, Generic, GenericVisitor, Leaf};
+///
+/// #[derive(Default)]
+/// struct Printer(String);
+///
+/// impl GenericVisitor for Printer {
+///     fn enter_constructor(&mut self, name: &'static str) {
+///         self.0 += name;
+///         self.0 += " {";
+///     }
+///
+///     fn leave_constructor(&mut self, _name: &'static str) {
+///         self.0 += " }";
+///     }
+///
+///     fn visit_field(&mut self, name: &'static str) {
+///         self.0 += " ";
+///         self.0 += name;
+///         self.0 += ": ";
+///     }
+///
+///     fn visit_leaf<T>(&mut self, leaf: &T) where T: Leaf + Debug + 'static {
+///         self.0 += &format!("{:?}", leaf);
+///     }
+/// }
+///
+/// #[derive(Generic)]
+/// struct Inner { c: u8 }
+///
+/// #[derive(Generic)]
+/// struct Foo { a: u8, b: Inner }
+///
+/// fn main() {
+///     let mut printer = Printer::default();
+///     visit(Foo { a: 1, b: Inner { c: 2 } }, &mut printer);
+///     assert_eq!(printer.0, "Foo { a: 1 b: Inner { c: 2 } }");
+/// }
+/// ```
+pub trait GenericVisitor {
+    /// Called before visiting the fields of a constructor.
+    fn enter_constructor(&mut self, name: &'static str) {
+        let _ = name;
+    }
+
+    /// Called after visiting the fields of a constructor.
+    fn leave_constructor(&mut self, name: &'static str) {
+        let _ = name;
+    }
+
+    /// Called before visiting the value of a field.
+    fn visit_field(&mut self, name: &'static str) {
+        let _ = name;
+    }
+
+    /// Called for each leaf.
+    ///
+    /// Leaves are `'static`, so they can be downcast via `std::any::Any`.
+    fn visit_leaf<T>(&mut self, leaf: &T)
+    where
+        T: Leaf + Debug + 'static,
+    {
+        let _ = leaf;
+    }
+}
+
+/// Walks a representation, invoking the callbacks of a `GenericVisitor`.
+///
+/// Fields are visited in declaration order, and list and array elements in index order.
+pub trait Visit {
+    /// Walks `self`, invoking the callbacks of `visitor`.
+    fn visit<V>(&self, visitor: &mut V)
+    where
+        V: GenericVisitor;
+}
+
+impl Visit for Unit {
+    fn visit<V>(&self, _visitor: &mut V)
+    where
+        V: GenericVisitor,
+    {
+    }
+}
+
+impl<A, B> Visit for Prod<A, B>
+where
+    A: Visit,
+    B: Visit,
+{
+    fn visit<V>(&self, visitor: &mut V)
+    where
+        V: GenericVisitor,
+    {
+        let Prod(a, b) = self;
+        a.visit(visitor);
+        b.visit(visitor);
+    }
+}
+
+impl<L, R> Visit for Sum<L, R>
+where
+    L: Visit,
+    R: Visit,
+{
+    fn visit<V>(&self, visitor: &mut V)
+    where
+        V: GenericVisitor,
+    {
+        match self {
+            Sum::Left(l) => l.visit(visitor),
+            Sum::Right(r) => r.visit(visitor),
+        }
+    }
+}
+
+impl Visit for Void {
+    fn visit<V>(&self, _visitor: &mut V)
+    where
+        V: GenericVisitor,
+    {
+        match *self {}
+    }
+}
+
+impl<I, M> Visit for Meta<I, M>
+where
+    I: Visit,
+    M: Singleton<T = &'static str>,
+{
+    fn visit<V>(&self, visitor: &mut V)
+    where
+        V: GenericVisitor,
+    {
+        let Meta(inner, _) = self;
+        visitor.visit_field(M::get());
+        inner.visit(visitor);
+    }
+}
+
+impl<I, C> Visit for Con<I, C>
+where
+    I: Visit,
+    C: Singleton<T = &'static str>,
+{
+    fn visit<V>(&self, visitor: &mut V)
+    where
+        V: GenericVisitor,
+    {
+        let Con(inner, _) = self;
+        visitor.enter_constructor(C::get());
+        inner.visit(visitor);
+        visitor.leave_constructor(C::get());
+    }
+}
+
+impl<A> Visit for List<A>
+where
+    A: Visit,
+{
+    fn visit<V>(&self, visitor: &mut V)
+    where
+        V: GenericVisitor,
+    {
+        let List(items) = self;
+        for item in items {
+            item.visit(visitor);
+        }
+    }
+}
+
+impl<A, const N: usize> Visit for [A; N]
+where
+    A: Visit,
+{
+    fn visit<V>(&self, visitor: &mut V)
+    where
+        V: GenericVisitor,
+    {
+        for item in self {
+            item.visit(visitor);
+        }
+    }
+}
+
+impl<T> Visit for T
+where
+    T: Leaf + Debug + 'static,
+{
+    fn visit<V>(&self, visitor: &mut V)
+    where
+        V: GenericVisitor,
+    {
+        visitor.visit_leaf(self);
+    }
+}
+
+/// Walks the representation of `x`, invoking the callbacks of `visitor`.
+pub fn visit<T, V>(x: T, visitor: &mut V)
+where
+    T: Generic,
+    T::Repr: Visit,
+    V: GenericVisitor,
+{
+    x.into_repr().visit(visitor);
+}
+
+/// Callbacks invoked while walking a mutable representation with `VisitMut`.
+///
+/// Like `GenericVisitor`, but leaves are visited by mutable reference, so they can be updated in place.
+///
+/// # Examples
+///
+/// Trim every string in a value.
+///
+/// ```rust
+/// use std::any::Any;
+/// use std::fmt::Debug;
+/// use generics::{visit_mut, Generic, GenericMut, GenericVisitorMut};
+///
+/// struct Trim;
+///
+/// impl GenericVisitorMut for Trim {
+///     fn visit_leaf_mut<T>(&mut self, leaf: &mut T) where T: Debug + 'static {
+///         if let Some(s) = (leaf as &mut dyn Any).downcast_mut::<String>() {
+///             *s = s.trim().to_string();
+///         }
+///     }
+/// }
+///
+/// #[derive(Generic, GenericMut)]
+/// struct Foo { a: String, b: Vec<String>, c: u8 }
+///
+/// fn main() {
+///     let mut foo = Foo { a: " a ".to_string(), b: vec![" b".to_string()], c: 1 };
+///     visit_mut(&mut foo, &mut Trim);
+///     assert_eq!(foo.a, "a");
+///     assert_eq!(foo.b, ["b"]);
+/// }
+/// ```
+pub trait GenericVisitorMut {
+    /// Called before visiting the fields of a constructor.
+    fn enter_constructor(&mut self, name: &'static str) {
+        let _ = name;
+    }
+
+    /// Called after visiting the fields of a constructor.
+    fn leave_constructor(&mut self, name: &'static str) {
+        let _ = name;
+    }
+
+    /// Called before visiting the value of a field.
+    fn visit_field(&mut self, name: &'static str) {
+        let _ = name;
+    }
+
+    /// Called for each leaf, and for the value of each opaque or sensitive field.
+    ///
+    /// Leaves are `'static`, so they can be downcast via `std::any::Any`.
+    fn visit_leaf_mut<T>(&mut self, leaf: &mut T)
+    where
+        T: Debug + 'static,
+    {
+        let _ = leaf;
+    }
+}
+
+/// Walks a mutable representation, invoking the callbacks of a `GenericVisitorMut`.
+///
+/// Fields are visited in declaration order, and list and array elements in index order.
+pub trait VisitMut {
+    /// Walks `self`, invoking the callbacks of `visitor`.
+    fn visit_mut<V>(self, visitor: &mut V)
+    where
+        V: GenericVisitorMut;
+}
+
+impl VisitMut for Unit {
+    fn visit_mut<V>(self, _visitor: &mut V)
+    where
+        V: GenericVisitorMut,
+    {
+    }
+}
+
+impl<A, B> VisitMut for Prod<A, B>
+where
+    A: VisitMut,
+    B: VisitMut,
+{
+    fn visit_mut<V>(self, visitor: &mut V)
+    where
+        V: GenericVisitorMut,
+    {
+        let Prod(a, b) = self;
+        a.visit_mut(visitor);
+        b.visit_mut(visitor);
+    }
+}
+
+impl<L, R> VisitMut for Sum<L, R>
+where
+    L: VisitMut,
+    R: VisitMut,
+{
+    fn visit_mut<V>(self, visitor: &mut V)
+    where
+        V: GenericVisitorMut,
+    {
+        match self {
+            Sum::Left(l) => l.visit_mut(visitor),
+            Sum::Right(r) => r.visit_mut(visitor),
+        }
+    }
+}
+
+impl VisitMut for Void {
+    fn visit_mut<V>(self, _visitor: &mut V)
+    where
+        V: GenericVisitorMut,
+    {
+        match self {}
+    }
+}
+
+impl<I, M> VisitMut for Meta<I, M>
+where
+    I: VisitMut,
+    M: Singleton<T = &'static str>,
+{
+    fn visit_mut<V>(self, visitor: &mut V)
+    where
+        V: GenericVisitorMut,
+    {
+        let Meta(inner, _) = self;
+        visitor.visit_field(M::get());
+        inner.visit_mut(visitor);
+    }
+}
+
+impl<I, C> VisitMut for Con<I, C>
+where
+    I: VisitMut,
+    C: Singleton<T = &'static str>,
+{
+    fn visit_mut<V>(self, visitor: &mut V)
+    where
+        V: GenericVisitorMut,
+    {
+        let Con(inner, _) = self;
+        visitor.enter_constructor(C::get());
+        inner.visit_mut(visitor);
+        visitor.leave_constructor(C::get());
+    }
+}
+
+impl<A> VisitMut for List<A>
+where
+    A: VisitMut,
+{
+    fn visit_mut<V>(self, visitor: &mut V)
+    where
+        V: GenericVisitorMut,
+    {
+        let List(items) = self;
+        for item in items {
+            item.visit_mut(visitor);
+        }
+    }
+}
+
+impl<A, const N: usize> VisitMut for [A; N]
+where
+    A: VisitMut,
+{
+    fn visit_mut<V>(self, visitor: &mut V)
+    where
+        V: GenericVisitorMut,
+    {
+        for item in IntoIterator::into_iter(self) {
+            item.visit_mut(visitor);
+        }
+    }
+}
+
+impl<T> VisitMut for &mut T
+where
+    T: Leaf + Debug + 'static,
+{
+    fn visit_mut<V>(self, visitor: &mut V)
+    where
+        V: GenericVisitorMut,
+    {
+        visitor.visit_leaf_mut(self);
+    }
+}
+
+impl<T> VisitMut for Opaque<&mut T>
+where
+    T: Debug + 'static,
+{
+    fn visit_mut<V>(self, visitor: &mut V)
+    where
+        V: GenericVisitorMut,
+    {
+        let Opaque(inner) = self;
+        visitor.visit_leaf_mut(inner);
+    }
+}
+
+impl<T> VisitMut for Sensitive<&mut T>
+where
+    T: Debug + 'static,
+{
+    fn visit_mut<V>(self, visitor: &mut V)
+    where
+        V: GenericVisitorMut,
+    {
+        let Sensitive(inner) = self;
+        visitor.visit_leaf_mut(inner);
+    }
+}
+
+/// Walks the mutable representation of `x`, invoking the callbacks of `visitor`, which may update its leaves in place.
+pub fn visit_mut<'a, T, V>(x: &'a mut T, visitor: &mut V)
+where
+    T: GenericMut<'a>,
+    T::ReprMut: VisitMut,
+    V: GenericVisitorMut,
+{
+    x.as_repr_mut().visit_mut(visitor);
+}
@@ -0,0 +1,391 @@
+//! A zipper over representations.
+//!
+//! A `Zipper` focuses on a single node of a representation, remembering the path back up to the root,
+//! so the focused node can be read and edited before the whole representation is rebuilt.
+//!
+//! Since each node of a representation may have a different type, each movement changes the type of the zipper,
+//! and invalid movements (e.g. `down` from a leaf) are compile errors.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use generics::zipper::Zipper;
+//! use generics::Generic;
+//!
+//! #[derive(Generic)]
+//! struct Foo {
+//!     a: u8,
+//!     b: u32,
+//! }
+//!
+//! let foo = Foo { a: 1, b: 2 };
+//!
+//! // Con -> Prod -> Prod -> Meta -> u32
+//! let mut zipper = Zipper::new(foo.into_repr()).down().down().right().down().down();
+//! assert_eq!(*zipper.focus(), 2);
+//!
+//! *zipper.focus_mut() = 42;
+//! let foo: Foo = zipper.finish();
+//! assert_eq!(foo.a, 1);
+//! assert_eq!(foo.b, 42);
+//! ```
+
+use std::marker::PhantomData;
+
+use crate::{Con, Generic, Meta, Prod, Singleton, Sum};
+
+/// A focus on a node of type `F` within a representation, along with the path `P` back to the root.
+pub struct Zipper<F, P> {
+    focus: F,
+    path: P,
+}
+
+/// The path from the root of a representation to itself.
+pub struct Top;
+
+/// The path to the left half of a `Prod`, holding the right half.
+pub struct ProdLeft<B, P>(B, P);
+
+/// The path to the right half of a `Prod`, holding the left half.
+pub struct ProdRight<A, P>(A, P);
+
+/// The path to the left variant of a `Sum`.
+pub struct SumLeft<R, P>(PhantomData<R>, P);
+
+/// The path to the right variant of a `Sum`.
+pub struct SumRight<L, P>(PhantomData<L>, P);
+
+/// The path to the inside of a `Meta`.
+pub struct InMeta<M, P>(PhantomData<M>, P);
+
+/// The path to the inside of a `Con`.
+pub struct InCon<C, P>(PhantomData<C>, P);
+
+/// A path from the root of a representation to a node of type `F`.
+pub trait Path<F> {
+    /// The type of the representation at the root.
+    type Root;
+
+    /// Rebuilds the root representation, given the node at the end of the path.
+    fn rebuild(self, focus: F) -> Self::Root;
+}
+
+impl<F> Path<F> for Top {
+    type Root = F;
+
+    fn rebuild(self, focus: F) -> Self::Root {
+        focus
+    }
+}
+
+impl<F, B, P> Path<F> for ProdLeft<B, P>
+where
+    P: Path<Prod<F, B>>,
+{
+    type Root = P::Root;
+
+    fn rebuild(self, focus: F) -> Self::Root {
+        let ProdLeft(b, path) = self;
+        path.rebuild(Prod(focus, b))
+    }
+}
+
+impl<F, A, P> Path<F> for ProdRight<A, P>
+where
+    P: Path<Prod<A, F>>,
+{
+    type Root = P::Root;
+
+    fn rebuild(self, focus: F) -> Self::Root {
+        let ProdRight(a, path) = self;
+        path.rebuild(Prod(a, focus))
+    }
+}
+
+impl<F, R, P> Path<F> for SumLeft<R, P>
+where
+    P: Path<Sum<F, R>>,
+{
+    type Root = P::Root;
+
+    fn rebuild(self, focus: F) -> Self::Root {
+        let SumLeft(_, path) = self;
+        path.rebuild(Sum::Left(focus))
+    }
+}
+
+impl<F, L, P> Path<F> for SumRight<L, P>
+where
+    P: Path<Sum<L, F>>,
+{
+    type Root = P::Root;
+
+    fn rebuild(self, focus: F) -> Self::Root {
+        let SumRight(_, path) = self;
+        path.rebuild(Sum::Right(focus))
+    }
+}
+
+impl<F, M, P> Path<F> for InMeta<M, P>
+where
+    M: Singleton,
+    P: Path<Meta<F, M>>,
+{
+    type Root = P::Root;
+
+    fn rebuild(self, focus: F) -> Self::Root {
+        let InMeta(_, path) = self;
+        path.rebuild(Meta(focus, PhantomData))
+    }
+}
+
+impl<F, C, P> Path<F> for InCon<C, P>
+where
+    C: Singleton,
+    P: Path<Con<F, C>>,
+{
+    type Root = P::Root;
+
+    fn rebuild(self, focus: F) -> Self::Root {
+        let InCon(_, path) = self;
+        path.rebuild(Con(focus, PhantomData))
+    }
+}
+
+impl<F> Zipper<F, Top> {
+    /// Creates a zipper focused on the root of a representation.
+    pub fn new(repr: F) -> Self {
+        Zipper {
+            focus: repr,
+            path: Top,
+        }
+    }
+}
+
+impl<F, P> Zipper<F, P> {
+    /// Borrows the focused node.
+    pub fn focus(&self) -> &F {
+        &self.focus
+    }
+
+    /// Mutably borrows the focused node.
+    pub fn focus_mut(&mut self) -> &mut F {
+        &mut self.focus
+    }
+
+    /// Replaces the focused node with another node of the same type.
+    pub fn replace(self, focus: F) -> (Self, F) {
+        let Zipper { focus: old, path } = self;
+        (Zipper { focus, path }, old)
+    }
+
+    /// Rebuilds the root representation.
+    pub fn into_repr(self) -> P::Root
+    where
+        P: Path<F>,
+    {
+        let Zipper { focus, path } = self;
+        path.rebuild(focus)
+    }
+
+    /// Rebuilds the root representation, then converts it into a value.
+    pub fn finish<T>(self) -> T
+    where
+        P: Path<F>,
+        T: Generic<Repr = P::Root>,
+    {
+        T::from_repr(self.into_repr())
+    }
+}
+
+impl<A, B, P> Zipper<Prod<A, B>, P> {
+    /// Moves the focus to the left half of the `Prod`.
+    pub fn down(self) -> Zipper<A, ProdLeft<B, P>> {
+        let Zipper {
+            focus: Prod(a, b),
+            path,
+        } = self;
+        Zipper {
+            focus: a,
+            path: ProdLeft(b, path),
+        }
+    }
+}
+
+impl<L, R, P> Zipper<Sum<L, R>, P> {
+    /// Moves the focus to the left variant of the `Sum`, if present.
+    pub fn down_left(self) -> Result<Zipper<L, SumLeft<R, P>>, Self> {
+        match self.focus {
+            Sum::Left(l) => Ok(Zipper {
+                focus: l,
+                path: SumLeft(PhantomData, self.path),
+            }),
+            focus => Err(Zipper {
+                focus,
+                path: self.path,
+            }),
+        }
+    }
+
+    /// Moves the focus to the right variant of the `Sum`, if present.
+    pub fn down_right(self) -> Result<Zipper<R, SumRight<L, P>>, Self> {
+        match self.focus {
+            Sum::Right(r) => Ok(Zipper {
+                focus: r,
+                path: SumRight(PhantomData, self.path),
+            }),
+            focus => Err(Zipper {
+                focus,
+                path: self.path,
+            }),
+        }
+    }
+}
+
+impl<I, M, P> Zipper<Meta<I, M>, P>
+where
+    M: Singleton,
+{
+    /// Moves the focus to the inside of the `Meta`.
+    pub fn down(self) -> Zipper<I, InMeta<M, P>> {
+        let Zipper {
+            focus: Meta(inner, _),
+            path,
+        } = self;
+        Zipper {
+            focus: inner,
+            path: InMeta(PhantomData, path),
+        }
+    }
+}
+
+impl<I, C, P> Zipper<Con<I, C>, P>
+where
+    C: Singleton,
+{
+    /// Moves the focus to the inside of the `Con`.
+    pub fn down(self) -> Zipper<I, InCon<C, P>> {
+        let Zipper {
+            focus: Con(inner, _),
+            path,
+        } = self;
+        Zipper {
+            focus: inner,
+            path: InCon(PhantomData, path),
+        }
+    }
+}
+
+impl<F, B, P> Zipper<F, ProdLeft<B, P>> {
+    /// Moves the focus to the right half of the enclosing `Prod`.
+    pub fn right(self) -> Zipper<B, ProdRight<F, P>> {
+        let Zipper {
+            focus,
+            path: ProdLeft(b, path),
+        } = self;
+        Zipper {
+            focus: b,
+            path: ProdRight(focus, path),
+        }
+    }
+
+    /// Moves the focus to the enclosing `Prod`.
+    pub fn up(self) -> Zipper<Prod<F, B>, P> {
+        let Zipper {
+            focus,
+            path: ProdLeft(b, path),
+        } = self;
+        Zipper {
+            focus: Prod(focus, b),
+            path,
+        }
+    }
+}
+
+impl<F, A, P> Zipper<F, ProdRight<A, P>> {
+    /// Moves the focus to the left half of the enclosing `Prod`.
+    pub fn left(self) -> Zipper<A, ProdLeft<F, P>> {
+        let Zipper {
+            focus,
+            path: ProdRight(a, path),
+        } = self;
+        Zipper {
+            focus: a,
+            path: ProdLeft(focus, path),
+        }
+    }
+
+    /// Moves the focus to the enclosing `Prod`.
+    pub fn up(self) -> Zipper<Prod<A, F>, P> {
+        let Zipper {
+            focus,
+            path: ProdRight(a, path),
+        } = self;
+        Zipper {
+            focus: Prod(a, focus),
+            path,
+        }
+    }
+}
+
+impl<F, R, P> Zipper<F, SumLeft<R, P>> {
+    /// Moves the focus to the enclosing `Sum`.
+    pub fn up(self) -> Zipper<Sum<F, R>, P> {
+        let Zipper {
+            focus,
+            path: SumLeft(_, path),
+        } = self;
+        Zipper {
+            focus: Sum::Left(focus),
+            path,
+        }
+    }
+}
+
+impl<F, L, P> Zipper<F, SumRight<L, P>> {
+    /// Moves the focus to the enclosing `Sum`.
+    pub fn up(self) -> Zipper<Sum<L, F>, P> {
+        let Zipper {
+            focus,
+            path: SumRight(_, path),
+        } = self;
+        Zipper {
+            focus: Sum::Right(focus),
+            path,
+        }
+    }
+}
+
+impl<F, M, P> Zipper<F, InMeta<M, P>>
+where
+    M: Singleton,
+{
+    /// Moves the focus to the enclosing `Meta`.
+    pub fn up(self) -> Zipper<Meta<F, M>, P> {
+        let Zipper {
+            focus,
+            path: InMeta(_, path),
+        } = self;
+        Zipper {
+            focus: Meta(focus, PhantomData),
+            path,
+        }
+    }
+}
+
+impl<F, C, P> Zipper<F, InCon<C, P>>
+where
+    C: Singleton,
+{
+    /// Moves the focus to the enclosing `Con`.
+    pub fn up(self) -> Zipper<Con<F, C>, P> {
+        let Zipper {
+            focus,
+            path: InCon(_, path),
+        } = self;
+        Zipper {
+            focus: Con(focus, PhantomData),
+            path,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use generics::ops::{approx_eq, ApproxEq, GenericApproxEq};
+use generics::Generic;
+
+#[derive(Generic, GenericApproxEq, Debug)]
+struct Sample<T> {
+    t: T,
+    values: Vec<f64>,
+    label: String,
+    weight: Option<f32>,
+}
+
+#[derive(Generic, GenericApproxEq, Debug)]
+enum Reading {
+    Missing,
+    Scalar(f64),
+    Nested { sample: Sample<u8> },
+}
+
+#[derive(Generic, GenericApproxEq)]
+enum Never {}
+
+fn assert_approx_eq_impl<T: ApproxEq>() {}
+
+#[test]
+fn approx() {
+    assert_approx_eq_impl::<Never>();
+
+    let sample = |t, x, label: &str| Sample {
+        t,
+        values: vec![1.0, x],
+        label: label.to_string(),
+        weight: Some(0.5),
+    };
+
+    assert!(sample(1u8, 2.0, "a").approx_eq(&sample(1, 2.0 + 1e-9, "a"), 1e-6));
+    assert!(!sample(1u8, 2.0, "a").approx_eq(&sample(1, 2.1, "a"), 1e-6));
+    assert!(!sample(1u8, 2.0, "a").approx_eq(&sample(2, 2.0, "a"), 1e-6));
+    assert!(!sample(1u8, 2.0, "a").approx_eq(&sample(1, 2.0, "b"), 1e-6));
+
+    assert!(Reading::Scalar(1.0).approx_eq(&Reading::Scalar(1.05), 0.1));
+    assert!(!Reading::Scalar(1.0).approx_eq(&Reading::Missing, 0.1));
+    assert!(Reading::Nested {
+        sample: sample(0, 1.0, "x")
+    }
+    .approx_eq(
+        &Reading::Nested {
+            sample: sample(0, 1.0 + 1e-3, "x")
+        },
+        1e-2
+    ));
+
+    // Infinities equal themselves, but `NaN` never does.
+    assert!(f64::INFINITY.approx_eq(&f64::INFINITY, 0.0));
+    assert!(!f64::NAN.approx_eq(&f64::NAN, 1.0));
+
+    assert!(approx_eq(vec![(1.0f32, 'a')], vec![(1.0001, 'a')], 1e-3));
+    assert!(!approx_eq(vec![1.0f32], vec![1.0, 2.0], 1e-3));
+    assert!(approx_eq(&Reading::Missing, &Reading::Missing, 0.0));
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use generics::ops::{garbitrary, GenericArbitrary};
+use generics::Generic;
+
+#[derive(Generic, GenericArbitrary, Debug, PartialEq)]
+struct Packet {
+    kind: Kind,
+    payload: Vec<u8>,
+    checksum: [u8; 2],
+}
+
+#[derive(Generic, GenericArbitrary, Debug, PartialEq)]
+enum Kind {
+    Ping,
+    Data { stream: u16 },
+    Close(Option<bool>),
+}
+
+#[derive(Generic, GenericArbitrary, Debug)]
+enum Never {}
+
+#[test]
+fn arbitrary() {
+    let data = [1, 0x34, 0x12, 1, 7, 1, 8, 0, 9, 10];
+    let mut u = Unstructured::new(&data);
+    assert_eq!(
+        Packet::arbitrary(&mut u),
+        Ok(Packet {
+            kind: Kind::Data { stream: 0x1234 },
+            payload: vec![7, 8],
+            checksum: [9, 10],
+        })
+    );
+
+    let mut u = Unstructured::new(&[]);
+    assert_eq!(
+        garbitrary::<Packet>(&mut u),
+        Ok(Packet {
+            kind: Kind::Ping,
+            payload: vec![],
+            checksum: [0, 0],
+        })
+    );
+
+    let mut u = Unstructured::new(&[2, 1, 1]);
+    assert_eq!(Kind::arbitrary(&mut u), Ok(Kind::Close(Some(true))));
+
+    let mut u = Unstructured::new(&[0; 8]);
+    assert_eq!(
+        Never::arbitrary(&mut u).unwrap_err(),
+        arbitrary::Error::EmptyChoose
+    );
+}
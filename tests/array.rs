@@ -0,0 +1,64 @@
+use generics::dynamic::{from_dyn, to_dyn, DynRepr, FromDyn, FromDynError};
+use generics::{define_generic_op, visit, Generic, GenericVisitor, Leaf};
+
+define_generic_op! {
+    trait Accumulate {
+        fn acc(self) -> u64;
+    }
+
+    fn accumulate;
+
+    unit => 0,
+    prod(a, b) => a + b,
+    sum(x) => x,
+    meta(x, M) => x,
+    con(x, C) => x,
+    array(xs) => xs.iter().sum(),
+    leaf(x: u8) => u64::from(x),
+}
+
+#[derive(Default)]
+struct Count(usize);
+
+impl GenericVisitor for Count {
+    fn visit_leaf<T>(&mut self, _leaf: &T)
+    where
+        T: Leaf,
+    {
+        self.0 += 1;
+    }
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Inner(u8);
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo {
+    a: [u8; 3],
+    b: [Inner; 2],
+}
+
+#[test]
+fn array() {
+    let foo = Foo {
+        a: [1, 2, 3],
+        b: [Inner(4), Inner(5)],
+    };
+
+    assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+    assert_eq!(accumulate(foo.clone()), 15);
+
+    let mut count = Count::default();
+    visit(foo.clone(), &mut count);
+    assert_eq!(count.0, 5);
+
+    assert_eq!(from_dyn::<Foo>(to_dyn(foo.clone())), Ok(foo));
+    assert_eq!(
+        <[u8; 2]>::from_dyn(to_dyn([1u8, 2, 3])),
+        Err(FromDynError::Length {
+            expected: 2,
+            found: 3
+        })
+    );
+    assert_eq!(to_dyn([0u8; 0]), DynRepr::Array(vec![]));
+}
@@ -0,0 +1,98 @@
+use std::panic;
+
+use generics::{assert_generic_eq, Generic};
+
+#[derive(Generic, Clone, PartialEq, Debug)]
+struct Order {
+    id: u32,
+    status: Status,
+    items: Vec<Item>,
+}
+
+#[derive(Generic, Clone, PartialEq, Debug)]
+enum Status {
+    Pending,
+    Shipped { tracking: String },
+}
+
+#[derive(Generic, Clone, PartialEq, Debug)]
+struct Item {
+    sku: String,
+    quantity: u8,
+}
+
+/// Compares equal to everything, unlike its fields.
+#[derive(Generic, Clone, Debug)]
+struct Lenient(u8);
+
+impl PartialEq for Lenient {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// Compares unequal to everything, even with equal fields.
+#[derive(Generic, Clone, Debug)]
+struct Strict(u8);
+
+impl PartialEq for Strict {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+fn failure(f: impl FnOnce() + panic::UnwindSafe) -> String {
+    *panic::catch_unwind(f)
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap()
+}
+
+#[test]
+fn assert_generic_eq_reports_paths() {
+    let order = Order {
+        id: 1,
+        status: Status::Pending,
+        items: vec![Item {
+            sku: "a".to_string(),
+            quantity: 1,
+        }],
+    };
+    assert_generic_eq!(order, order.clone());
+    assert_generic_eq!(order, order.clone(), "unused {}", "message");
+    assert_generic_eq!(Lenient(1), Lenient(2));
+
+    let shipped = Order {
+        status: Status::Shipped {
+            tracking: "x".to_string(),
+        },
+        items: vec![
+            Item {
+                sku: "a".to_string(),
+                quantity: 2,
+            },
+            Item {
+                sku: "b".to_string(),
+                quantity: 1,
+            },
+        ],
+        ..order.clone()
+    };
+    assert_eq!(
+        failure(|| assert_generic_eq!(order, shipped)),
+        "assertion `left == right` failed\n  \
+         at status:\n     left: Pending\n    right: Shipped { tracking: \"x\" }\n  \
+         at items[0].quantity:\n     left: 1\n    right: 2\n  \
+         at items[1]:\n     left: <missing>\n    right: Item { sku: \"b\", quantity: 1 }"
+    );
+
+    assert_eq!(
+        failure(|| assert_generic_eq!(3u8, 4u8, "id of {}", "order")),
+        "assertion `left == right` failed: id of order\n  at <root>:\n     left: 3\n    right: 4"
+    );
+
+    assert_eq!(
+        failure(|| assert_generic_eq!(Strict(1), Strict(1))),
+        "assertion `left == right` failed\n  the values are unequal, but every leaf is equal"
+    );
+}
@@ -0,0 +1,22 @@
+use generics::{At, Generic, S, Z};
+
+#[derive(Generic)]
+struct Foo {
+    a: u8,
+    b: u16,
+    c: u32,
+}
+
+#[test]
+fn at() {
+    let foo = Foo { a: 1, b: 2, c: 3 };
+
+    let mut repr = foo.into_repr();
+    assert_eq!(At::<Z>::at(&repr).0, 1u8);
+    assert_eq!(At::<S<Z>>::at(&repr).0, 2u16);
+    assert_eq!(At::<S<S<Z>>>::at(&repr).0, 3u32);
+
+    At::<S<Z>>::at_mut(&mut repr).0 = 42;
+    let foo = Foo::from_repr(repr);
+    assert_eq!(foo.b, 42);
+}
@@ -0,0 +1,29 @@
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU64, AtomicUsize, Ordering};
+
+use generics::{Field, Generic};
+
+#[derive(Generic, Default)]
+struct Stats {
+    requests: AtomicU64,
+    active: AtomicUsize,
+    balance: AtomicI32,
+    healthy: AtomicBool,
+}
+
+#[test]
+fn atomic() {
+    let stats = Stats::default();
+    stats.requests.fetch_add(3, Ordering::Relaxed);
+    stats.balance.fetch_sub(2, Ordering::Relaxed);
+    stats.healthy.store(true, Ordering::Relaxed);
+
+    let snapshot = stats.into_repr();
+    assert_eq!(*Field::<Stats_requests, _>::field(&snapshot), 3);
+    assert_eq!(*Field::<Stats_active, _>::field(&snapshot), 0);
+    assert_eq!(*Field::<Stats_balance, _>::field(&snapshot), -2);
+    assert!(*Field::<Stats_healthy, _>::field(&snapshot));
+
+    let stats = Stats::from_repr(snapshot);
+    assert_eq!(stats.requests.load(Ordering::Relaxed), 3);
+    assert_eq!(stats.balance.load(Ordering::Relaxed), -2);
+}
@@ -0,0 +1,97 @@
+use generics::ops::{decode_binary, encode_binary, DecodeBinaryError};
+use generics::{Generic, Opaque};
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Frame {
+    id: u32,
+    flag: bool,
+    tag: char,
+    name: String,
+    scale: f64,
+    offset: isize,
+    parts: Vec<Part>,
+    corner: [i16; 2],
+    pair: (u8, Option<u8>),
+    #[generic(opaque)]
+    raw: u64,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Part {
+    Empty,
+    Short(u8),
+    Long { len: u16, data: Vec<u8> },
+}
+
+#[test]
+fn binary() {
+    let frame = Frame {
+        id: 0x0102_0304,
+        flag: true,
+        tag: 'é',
+        name: "ab".to_string(),
+        scale: 1.5,
+        offset: -2,
+        parts: vec![
+            Part::Empty,
+            Part::Short(9),
+            Part::Long {
+                len: 1,
+                data: vec![5],
+            },
+        ],
+        corner: [-1, 1],
+        pair: (3, Some(4)),
+        raw: 7,
+    };
+    let bytes = encode_binary(frame.clone());
+    #[rustfmt::skip]
+    let expected: Vec<u8> = [
+        &[4, 3, 2, 1][..],
+        &[1],
+        &[0xe9, 0, 0, 0],
+        &[2, 0, 0, 0, 0, 0, 0, 0, b'a', b'b'],
+        &1.5f64.to_le_bytes(),
+        &(-2i64).to_le_bytes(),
+        &[3, 0, 0, 0, 0, 0, 0, 0],
+        &[0, 0, 0, 0],
+        &[1, 0, 0, 0, 9],
+        &[2, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 5],
+        &[0xff, 0xff, 1, 0],
+        &[3, 1, 0, 0, 0, 4],
+        &[7, 0, 0, 0, 0, 0, 0, 0],
+    ]
+    .concat();
+    assert_eq!(bytes, expected);
+    assert_eq!(decode_binary::<Frame>(&bytes), Ok(frame));
+
+    assert_eq!(
+        decode_binary::<Opaque<u16>>(&[1, 0, 0]),
+        Err(DecodeBinaryError::Trailing(1))
+    );
+    assert_eq!(
+        decode_binary::<Part>(&[3, 0, 0, 0]),
+        Err(DecodeBinaryError::Variant(3))
+    );
+    assert_eq!(
+        decode_binary::<(bool, char)>(&[2, 0, 0, 0, 0]),
+        Err(DecodeBinaryError::Invalid("bool"))
+    );
+    assert_eq!(
+        decode_binary::<String>(&[1, 0, 0, 0, 0, 0, 0, 0, 0xff]),
+        Err(DecodeBinaryError::Invalid("string"))
+    );
+    assert_eq!(
+        decode_binary::<Vec<u8>>(&[0xff; 8])
+            .unwrap_err()
+            .to_string(),
+        "unexpected end of input"
+    );
+    assert_eq!(
+        decode_binary::<Vec<()>>(&[0xff; 8]),
+        Err(DecodeBinaryError::Invalid("length"))
+    );
+    let units = encode_binary(vec![(); 3]);
+    assert_eq!(units, [3, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(decode_binary::<Vec<()>>(&units), Ok(vec![(); 3]));
+}
@@ -0,0 +1,87 @@
+use std::num::{NonZeroI8, NonZeroU8};
+
+use generics::ops::{max_value, min_value, Bounded};
+use generics::{Generic, Opaque};
+
+#[derive(Generic, Debug, PartialEq)]
+enum Level {
+    Off,
+    Low(u8),
+    High { level: i16, boost: bool },
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct Config {
+    level: Level,
+    ratio: f32,
+    tag: Option<char>,
+    #[generic(opaque)]
+    priority: Priority,
+    limits: [u16; 2],
+}
+
+#[derive(Debug, PartialEq)]
+struct Priority(u8);
+
+impl Bounded for Priority {
+    fn min_value() -> Self {
+        Priority(1)
+    }
+
+    fn max_value() -> Self {
+        Priority(5)
+    }
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct Retries {
+    #[generic(opaque)]
+    count: NonZeroU8,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+enum Single {
+    Only(u8),
+}
+
+#[test]
+fn bounded() {
+    assert_eq!(
+        min_value::<Config>(),
+        Ok(Config {
+            level: Level::Off,
+            ratio: f32::MIN,
+            tag: None,
+            priority: Priority(1),
+            limits: [0, 0],
+        })
+    );
+    assert_eq!(
+        max_value::<Config>(),
+        Ok(Config {
+            level: Level::High {
+                level: i16::MAX,
+                boost: true,
+            },
+            ratio: f32::MAX,
+            tag: Some(char::MAX),
+            priority: Priority(5),
+            limits: [u16::MAX, u16::MAX],
+        })
+    );
+    assert_eq!(min_value::<Single>(), Ok(Single::Only(0)));
+    assert_eq!(max_value::<Single>(), Ok(Single::Only(255)));
+    assert_eq!(max_value::<Opaque<bool>>(), Ok(Opaque(true)));
+    assert_eq!(min_value::<()>(), Ok(()));
+
+    // The minimum representation of a `NonZeroU8` is zero, which it rejects.
+    assert!(min_value::<NonZeroU8>().is_err());
+    assert_eq!(max_value::<NonZeroU8>(), Ok(NonZeroU8::MAX));
+    assert_eq!(min_value::<NonZeroI8>(), Ok(NonZeroI8::MIN));
+    assert_eq!(
+        min_value::<Retries>(),
+        Ok(Retries {
+            count: NonZeroU8::MIN
+        })
+    );
+}
@@ -0,0 +1,44 @@
+use generics::ops::{GenericBuilder, MissingFields};
+use generics::Generic;
+
+#[derive(Debug, PartialEq, Generic, GenericBuilder)]
+struct Request<T> {
+    method: String,
+    body: T,
+    #[generic(opaque)]
+    retries: u8,
+    r#type: bool,
+}
+
+#[derive(Debug, PartialEq, Generic, GenericBuilder)]
+struct Empty {}
+
+#[test]
+fn builder() {
+    let request = Request::builder()
+        .method("GET".to_string())
+        .body(vec![1, 2])
+        .retries(3)
+        .r#type(true)
+        .build();
+    assert_eq!(
+        request,
+        Ok(Request {
+            method: "GET".to_string(),
+            body: vec![1, 2],
+            retries: 3,
+            r#type: true,
+        })
+    );
+
+    let missing = Request::<()>::builder().retries(3).build();
+    assert_eq!(
+        missing,
+        Err(MissingFields {
+            name: "Request",
+            fields: vec!["method", "body", "type"],
+        })
+    );
+
+    assert_eq!(Empty::builder().build(), Ok(Empty {}));
+}
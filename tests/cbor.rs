@@ -0,0 +1,211 @@
+#![cfg(feature = "cbor")]
+
+use generics::ops::{decode_cbor, encode_cbor, Cbor, DecodeCborError};
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Reading {
+    device: String,
+    seq: u32,
+    offset: i64,
+    big: u128,
+    small: i128,
+    value: f64,
+    ratio: f32,
+    ok: bool,
+    mark: char,
+    events: Vec<Event>,
+    pos: Pos,
+    id: Id,
+    marker: Marker,
+    window: [u8; 2],
+    pair: (u16, Option<u16>),
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Event {
+    Boot,
+    Code(u8),
+    Move(i8, i8),
+    Alarm { level: u8, note: Option<String> },
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Pos(i16, i16);
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Id(u64);
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Marker;
+
+fn text(s: &str) -> Cbor {
+    Cbor::Text(s.to_string())
+}
+
+fn encode(cbor: Cbor) -> Vec<u8> {
+    let mut out = Vec::new();
+    cbor.encode(&mut out);
+    out
+}
+
+#[test]
+fn cbor() {
+    let reading = Reading {
+        device: "d".to_string(),
+        seq: 500,
+        offset: -500,
+        big: u128::MAX,
+        small: i128::MIN,
+        value: 0.1,
+        ratio: 0.5,
+        ok: true,
+        mark: 'é',
+        events: vec![
+            Event::Boot,
+            Event::Code(24),
+            Event::Move(-1, 1),
+            Event::Alarm {
+                level: 2,
+                note: None,
+            },
+        ],
+        pos: Pos(3, -3),
+        id: Id(u64::MAX),
+        marker: Marker,
+        window: [0, 255],
+        pair: (1, Some(2)),
+    };
+    let cbor = Cbor::Map(vec![
+        (text("device"), text("d")),
+        (text("seq"), Cbor::Unsigned(500)),
+        (text("offset"), Cbor::Negative(499)),
+        (
+            text("big"),
+            Cbor::Tag(2, Box::new(Cbor::Bytes(vec![0xff; 16]))),
+        ),
+        (
+            text("small"),
+            Cbor::Tag(
+                3,
+                Box::new(Cbor::Bytes([&[0x7f][..], &[0xff; 15]].concat())),
+            ),
+        ),
+        (text("value"), Cbor::Float(0.1)),
+        (text("ratio"), Cbor::Float(0.5)),
+        (text("ok"), Cbor::Bool(true)),
+        (text("mark"), text("é")),
+        (
+            text("events"),
+            Cbor::Array(vec![
+                text("Boot"),
+                Cbor::Map(vec![(text("Code"), Cbor::Unsigned(24))]),
+                Cbor::Map(vec![(
+                    text("Move"),
+                    Cbor::Array(vec![Cbor::Negative(0), Cbor::Unsigned(1)]),
+                )]),
+                Cbor::Map(vec![(
+                    text("Alarm"),
+                    Cbor::Map(vec![
+                        (text("level"), Cbor::Unsigned(2)),
+                        (text("note"), Cbor::Null),
+                    ]),
+                )]),
+            ]),
+        ),
+        (
+            text("pos"),
+            Cbor::Array(vec![Cbor::Unsigned(3), Cbor::Negative(2)]),
+        ),
+        (text("id"), Cbor::Unsigned(u64::MAX)),
+        (text("marker"), Cbor::Null),
+        (
+            text("window"),
+            Cbor::Array(vec![Cbor::Unsigned(0), Cbor::Unsigned(255)]),
+        ),
+        (
+            text("pair"),
+            Cbor::Array(vec![Cbor::Unsigned(1), Cbor::Unsigned(2)]),
+        ),
+    ]);
+    let bytes = encode_cbor(reading.clone());
+    assert_eq!(bytes, encode(cbor.clone()));
+    assert_eq!(Cbor::decode(&bytes), Ok(cbor));
+    assert_eq!(decode_cbor::<Reading>(&bytes), Ok(reading));
+
+    // Heads and floats use their shortest forms.
+    assert_eq!(encode_cbor(23u16), [0x17]);
+    assert_eq!(encode_cbor(24u16), [0x18, 24]);
+    assert_eq!(encode_cbor(-256i32), [0x38, 0xff]);
+    assert_eq!(encode_cbor(65536u32), [0x1a, 0, 1, 0, 0]);
+    assert_eq!(encode_cbor(1.5f64), [0xfa, 0x3f, 0xc0, 0, 0]);
+    assert_eq!(decode_cbor::<f64>(&[0xf9, 0x3e, 0x00]), Ok(1.5));
+    assert_eq!(decode_cbor::<f32>(&[0xf9, 0x7c, 0x00]), Ok(f32::INFINITY));
+
+    // Unknown keys, including non-text keys, are ignored, and missing options are `None`.
+    let bytes = encode(Cbor::Map(vec![(
+        text("Alarm"),
+        Cbor::Map(vec![
+            (Cbor::Unsigned(1), Cbor::Bytes(vec![1])),
+            (text("level"), Cbor::Unsigned(9)),
+        ]),
+    )]));
+    assert_eq!(
+        decode_cbor::<Event>(&bytes),
+        Ok(Event::Alarm {
+            level: 9,
+            note: None
+        })
+    );
+
+    assert_eq!(
+        decode_cbor::<Event>(&encode(text("Reboot"))),
+        Err(DecodeCborError::Variant {
+            path: String::new(),
+            name: "Reboot".to_string()
+        })
+    );
+    assert_eq!(
+        decode_cbor::<Vec<Event>>(&encode(Cbor::Array(vec![Cbor::Map(vec![(
+            text("Code"),
+            Cbor::Unsigned(256)
+        )])])))
+        .unwrap_err()
+        .to_string(),
+        "expected a u8 for `0.0`"
+    );
+    assert_eq!(
+        decode_cbor::<Pos>(&encode(Cbor::Array(vec![Cbor::Unsigned(1)]))),
+        Err(DecodeCborError::Invalid {
+            path: String::new(),
+            expected: "an array of length 2".to_string()
+        })
+    );
+    assert_eq!(
+        decode_cbor::<u8>(&[0x01, 0x02]),
+        Err(DecodeCborError::Syntax(1))
+    );
+    assert_eq!(
+        decode_cbor::<Vec<u8>>(&[0x9b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]),
+        Err(DecodeCborError::Syntax(0))
+    );
+    assert_eq!(
+        decode_cbor::<String>(&[0x61, 0xff])
+            .unwrap_err()
+            .to_string(),
+        "invalid CBOR at byte 0"
+    );
+
+    // Nesting is limited, rather than overflowing the stack.
+    let mut nested = vec![0x81; 128];
+    nested.push(0x00);
+    assert!(Cbor::decode(&nested).is_ok());
+    assert_eq!(
+        decode_cbor::<Vec<u8>>(&[0x81; 1_000_000]),
+        Err(DecodeCborError::Depth(128))
+    );
+    assert_eq!(
+        decode_cbor::<u8>(&[0xc2; 200]).unwrap_err().to_string(),
+        "CBOR nested too deeply at byte 128"
+    );
+}
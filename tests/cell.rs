@@ -0,0 +1,45 @@
+#![cfg(feature = "interior_mutability")]
+
+use std::cell::{Cell, RefCell};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+
+use generics::Generic;
+
+#[derive(Generic, Debug)]
+struct Foo {
+    a: Cell<u8>,
+    b: RefCell<Vec<u16>>,
+    c: Mutex<u32>,
+    d: RwLock<Option<u64>>,
+}
+
+#[test]
+fn cell() {
+    let foo = Foo {
+        a: Cell::new(1),
+        b: RefCell::new(vec![2]),
+        c: Mutex::new(3),
+        d: RwLock::new(Some(4)),
+    };
+    let foo = Foo::from_repr(foo.into_repr());
+    assert_eq!(foo.a.get(), 1);
+    assert_eq!(*foo.b.borrow(), [2]);
+    assert_eq!(*foo.c.lock().unwrap(), 3);
+    assert_eq!(*foo.d.read().unwrap(), Some(4));
+}
+
+#[test]
+fn cell_poisoned() {
+    let mutex = Arc::new(Mutex::new(5u8));
+    let poisoner = Arc::clone(&mutex);
+    let _ = thread::spawn(move || {
+        let _guard = poisoner.lock().unwrap();
+        panic!("poison");
+    })
+    .join();
+    assert!(mutex.is_poisoned());
+
+    let mutex = Arc::try_unwrap(mutex).unwrap();
+    assert_eq!(mutex.into_repr(), 5);
+}
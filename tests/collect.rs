@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use generics::ops;
+use generics::{Generic, Opaque};
+
+#[derive(Generic)]
+struct Inner {
+    name: String,
+    paths: Vec<PathBuf>,
+    #[generic(opaque)]
+    hidden: String,
+    pair: (String, [u8; 2]),
+}
+
+#[derive(Generic)]
+struct Config {
+    name: String,
+    paths: Vec<PathBuf>,
+    nested: Option<Box<Inner>>,
+    env: BTreeMap<String, String>,
+    #[generic(opaque)]
+    hidden: String,
+    pair: (String, [u8; 2]),
+}
+
+fn config() -> Config {
+    Config {
+        name: "outer".to_string(),
+        paths: vec!["/a".into(), "/b".into()],
+        nested: Some(Box::new(Inner {
+            name: "inner".to_string(),
+            paths: vec!["/c".into()],
+            hidden: "x".to_string(),
+            pair: ("p2".to_string(), [3, 4]),
+        })),
+        env: BTreeMap::new(),
+        hidden: "y".to_string(),
+        pair: ("p1".to_string(), [1, 2]),
+    }
+}
+
+#[test]
+fn collect() {
+    assert_eq!(
+        ops::collect::<String, _>(config()),
+        ["outer", "inner", "p2", "p1"]
+    );
+    assert_eq!(
+        ops::collect::<PathBuf, _>(config()),
+        [PathBuf::from("/a"), "/b".into(), "/c".into()]
+    );
+    assert_eq!(ops::collect::<u8, _>(config()), [3, 4, 1, 2]);
+    assert_eq!(
+        ops::collect::<BTreeMap<String, String>, _>(config()).len(),
+        1
+    );
+    assert_eq!(
+        ops::collect::<Opaque<String>, _>(config()),
+        [Opaque("x".to_string()), Opaque("y".to_string())]
+    );
+    assert!(ops::collect::<char, _>(config()).is_empty());
+}
@@ -0,0 +1,40 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Debug;
+
+use generics::{visit, Generic, GenericVisitor, Leaf};
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo {
+    a: HashMap<String, u8>,
+    b: BTreeMap<u8, Vec<u8>>,
+    c: HashSet<u16>,
+    d: BTreeSet<char>,
+}
+
+#[derive(Default)]
+struct Leaves(Vec<String>);
+
+impl GenericVisitor for Leaves {
+    fn visit_leaf<T>(&mut self, leaf: &T)
+    where
+        T: Leaf + Debug + 'static,
+    {
+        self.0.push(format!("{:?}", leaf));
+    }
+}
+
+#[test]
+fn collections() {
+    let foo = Foo {
+        a: vec![("a".to_string(), 1)].into_iter().collect(),
+        b: vec![(2, vec![3])].into_iter().collect(),
+        c: vec![4].into_iter().collect(),
+        d: vec!['e', 'f'].into_iter().collect(),
+    };
+    assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+
+    // Each collection is visited as a single leaf.
+    let mut leaves = Leaves::default();
+    visit(foo, &mut leaves);
+    assert_eq!(leaves.0, ["{\"a\": 1}", "{2: [3]}", "{4}", "{'e', 'f'}"]);
+}
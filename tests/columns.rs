@@ -0,0 +1,52 @@
+use generics::ops::GenericColumns;
+use generics::Generic;
+
+#[derive(Generic, GenericColumns, Debug, Clone, PartialEq)]
+struct Event<T> {
+    id: u64,
+    payload: T,
+    #[generic(opaque)]
+    raw: Box<[u8]>,
+}
+
+#[derive(Generic, GenericColumns)]
+struct Handle {
+    #[generic(opaque)]
+    fd: std::fs::File,
+}
+
+#[test]
+fn columns() {
+    let rows = vec![
+        Event {
+            id: 1,
+            payload: "a".to_string(),
+            raw: Box::new([1]),
+        },
+        Event {
+            id: 2,
+            payload: "b".to_string(),
+            raw: Box::new([]),
+        },
+    ];
+    let mut events = EventColumns::from(rows.clone());
+    assert_eq!(events.len(), 2);
+    assert_eq!(events.id, [1, 2]);
+    assert_eq!(events.payload, ["a", "b"]);
+    assert_eq!(events.get(0), Some(rows[0].clone()));
+    assert_eq!(events.get(2), None);
+    assert_eq!(events.iter().collect::<Vec<_>>(), rows);
+
+    assert_eq!(events.pop(), Some(rows[1].clone()));
+    assert_eq!(events.len(), 1);
+    assert_eq!(Vec::from(events), rows[..1]);
+
+    let mut empty = EventColumns::<u8>::new();
+    assert!(empty.is_empty());
+    assert_eq!(empty.pop(), None);
+
+    // Rows which aren't `Clone` can still be pushed and popped.
+    let mut handles = HandleColumns::new();
+    assert!(handles.fd.is_empty());
+    assert!(handles.pop().is_none());
+}
@@ -0,0 +1,40 @@
+use generics::{Field, Generic};
+
+#[derive(Generic, Debug, PartialEq)]
+#[generic(const_fn)]
+struct Inner(u8, u16);
+
+#[derive(Generic, Debug, PartialEq)]
+#[generic(const_fn)]
+struct Foo {
+    a: u32,
+    inner: Inner,
+}
+
+const FOO: Foo = Foo {
+    a: 1,
+    inner: Inner(2, 3),
+};
+
+const REPR: <Foo as Generic>::Repr = FOO.into_repr_const();
+
+const ROUND_TRIP: Foo = Foo::from_repr_const(
+    Foo {
+        a: 4,
+        inner: Inner(5, 6),
+    }
+    .into_repr_const(),
+);
+
+#[test]
+fn const_fn() {
+    assert_eq!(*Field::<Foo_a, _>::field(&REPR), 1);
+    assert_eq!(Foo::from_repr(REPR), FOO);
+    assert_eq!(
+        ROUND_TRIP,
+        Foo {
+            a: 4,
+            inner: Inner(5, 6)
+        }
+    );
+}
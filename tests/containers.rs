@@ -0,0 +1,83 @@
+#![cfg(all(
+    feature = "arrayvec",
+    feature = "bytes",
+    feature = "indexmap",
+    feature = "smallvec"
+))]
+
+use std::collections::hash_map::RandomState;
+
+use generics::dynamic::to_dyn;
+use generics::ops::{decode_binary, encode_binary, DecodeBinaryError};
+use generics::{Generic, ReprError, TryFromRepr};
+
+mod std_containers {
+    use generics::Generic;
+
+    #[derive(Generic)]
+    pub struct Foo {
+        pub a: Vec<u8>,
+        pub b: Vec<u16>,
+        pub c: Vec<u8>,
+    }
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo {
+    a: smallvec::SmallVec<[u8; 4]>,
+    b: arrayvec::ArrayVec<u16, 4>,
+    c: bytes::Bytes,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Leaves {
+    a: arrayvec::ArrayString<8>,
+    b: bytes::BytesMut,
+    c: indexmap::IndexMap<u8, u8, RandomState>,
+    d: indexmap::IndexSet<u8, RandomState>,
+}
+
+#[test]
+fn containers() {
+    let foo = Foo {
+        a: smallvec::smallvec![1, 2],
+        b: vec![3, 4].into_iter().collect(),
+        c: bytes::Bytes::from_static(b"ab"),
+    };
+    assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+
+    // Represented the same as the equivalent std containers.
+    let std_foo = std_containers::Foo {
+        a: vec![1, 2],
+        b: vec![3, 4],
+        c: b"ab".to_vec(),
+    };
+    assert_eq!(to_dyn(foo), to_dyn(std_foo));
+
+    let leaves = Leaves {
+        a: arrayvec::ArrayString::from("abc").unwrap(),
+        b: bytes::BytesMut::from(&b"xyz"[..]),
+        c: vec![(1, 2)].into_iter().collect(),
+        d: vec![3].into_iter().collect(),
+    };
+    assert_eq!(Leaves::from_repr(leaves.clone().into_repr()), leaves);
+}
+
+#[test]
+fn containers_overflow() {
+    let overflow =
+        ReprError::new("`ArrayVec` represented with 3 elements, more than its capacity of 2");
+    let repr = vec![1u8, 2, 3].into_repr();
+    assert_eq!(
+        arrayvec::ArrayVec::<u8, 2>::try_from_repr(repr),
+        Err(overflow.clone())
+    );
+    assert_eq!(
+        decode_binary::<arrayvec::ArrayVec<u8, 2>>(&encode_binary(vec![1u8, 2, 3])),
+        Err(DecodeBinaryError::Repr(overflow))
+    );
+    assert_eq!(
+        decode_binary::<arrayvec::ArrayVec<u8, 3>>(&encode_binary(vec![1u8, 2, 3])),
+        Ok(vec![1, 2, 3].into_iter().collect())
+    );
+}
@@ -0,0 +1,107 @@
+use generics::ops::{convert, try_convert};
+use generics::Generic;
+
+#[derive(Generic)]
+struct PointDto {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+#[derive(Generic)]
+enum ShapeDto<'a> {
+    Circle { center: PointDto, radius: f32 },
+    Polygon([PointDto; 3]),
+    Named(&'a str, Option<u8>),
+}
+
+#[derive(Generic, Debug, PartialEq)]
+enum Shape {
+    Circle { center: Point, radius: f64 },
+    Polygon([Point; 3]),
+    Named(String, Option<u16>),
+}
+
+#[derive(Generic)]
+struct Large {
+    center: Point,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct Small {
+    center: SmallPoint,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct SmallPoint {
+    x: u8,
+    y: u8,
+}
+
+#[test]
+fn convert_parallel() {
+    let circle = ShapeDto::Circle {
+        center: PointDto { x: 1, y: -2 },
+        radius: 0.5,
+    };
+    assert_eq!(
+        convert::<_, Shape>(circle),
+        Shape::Circle {
+            center: Point { x: 1, y: -2 },
+            radius: 0.5,
+        }
+    );
+
+    let polygon = ShapeDto::Polygon([
+        PointDto { x: 0, y: 0 },
+        PointDto { x: 1, y: 0 },
+        PointDto { x: 0, y: 1 },
+    ]);
+    assert_eq!(
+        convert::<_, Shape>(polygon),
+        Shape::Polygon([
+            Point { x: 0, y: 0 },
+            Point { x: 1, y: 0 },
+            Point { x: 0, y: 1 },
+        ])
+    );
+
+    let named = ShapeDto::Named("blob", Some(3));
+    assert_eq!(
+        convert::<_, Shape>(named),
+        Shape::Named("blob".to_string(), Some(3))
+    );
+
+    assert_eq!(
+        convert::<_, Vec<Option<i64>>>(vec![Some(1i32), None]),
+        vec![Some(1i64), None]
+    );
+
+    let large = Large {
+        center: Point { x: 3, y: 4 },
+    };
+    assert_eq!(
+        try_convert::<_, Small>(large).ok(),
+        Some(Small {
+            center: SmallPoint { x: 3, y: 4 }
+        })
+    );
+
+    let err = try_convert::<_, Small>(Large {
+        center: Point { x: 3, y: 400 },
+    })
+    .unwrap_err();
+    assert_eq!(err.path, ["center", "y"]);
+    assert_eq!(
+        err.to_string(),
+        "failed to convert field `center.y`: out of range integral type conversion attempted"
+    );
+
+    let err = try_convert::<_, Vec<u8>>(vec![1i32, -1]).unwrap_err();
+    assert!(err.path.is_empty());
+}
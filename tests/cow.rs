@@ -0,0 +1,26 @@
+use std::borrow::Cow;
+
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Inner(u8);
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo<'a> {
+    a: Cow<'a, Inner>,
+    b: Cow<'a, [u16; 2]>,
+}
+
+#[test]
+fn cow() {
+    let inner = Inner(1);
+    let foo = Foo {
+        a: Cow::Borrowed(&inner),
+        b: Cow::Owned([2, 3]),
+    };
+
+    let round_trip = Foo::from_repr(foo.clone().into_repr());
+    assert_eq!(round_trip, foo);
+    assert!(matches!(round_trip.a, Cow::Owned(_)));
+    assert!(matches!(round_trip.b, Cow::Owned(_)));
+}
@@ -0,0 +1,167 @@
+#![cfg(feature = "csv")]
+
+use generics::ops::{csv_header, decode_csv, encode_csv, CsvError};
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Order {
+    id: u32,
+    customer: String,
+    total: f64,
+    paid: bool,
+    status: Status,
+    coupon: Option<String>,
+    rating: Option<u8>,
+    ship_to: Address,
+    dims: (u16, u16),
+    flags: [bool; 2],
+    grade: Grade,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Status {
+    Open,
+    Shipped,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Address {
+    city: String,
+    zip: Zip,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Zip(u32);
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Grade(Option<Status>, char);
+
+#[test]
+fn csv() {
+    let header = "id,customer,total,paid,status,coupon,rating,ship_to.city,ship_to.zip.0,\
+                  dims.0,dims.1,flags.0,flags.1,grade.0,grade.1";
+    assert_eq!(csv_header::<Order>().join(","), header);
+
+    let orders = vec![
+        Order {
+            id: 1,
+            customer: "Smith, \"J\"".to_string(),
+            total: 9.5,
+            paid: true,
+            status: Status::Open,
+            coupon: None,
+            rating: Some(5),
+            ship_to: Address {
+                city: "Oslo".to_string(),
+                zip: Zip(150),
+            },
+            dims: (3, 4),
+            flags: [true, false],
+            grade: Grade(None, 'a'),
+        },
+        Order {
+            id: 2,
+            customer: "line\nbreak".to_string(),
+            total: -0.25,
+            paid: false,
+            status: Status::Shipped,
+            coupon: Some("SAVE".to_string()),
+            rating: None,
+            ship_to: Address {
+                city: String::new(),
+                zip: Zip(0),
+            },
+            dims: (0, 0),
+            flags: [false, true],
+            grade: Grade(Some(Status::Shipped), ','),
+        },
+    ];
+    let text = encode_csv(orders.clone());
+    assert_eq!(
+        text,
+        format!(
+            "{}\n{}\n{}\n",
+            header,
+            "1,\"Smith, \"\"J\"\"\",9.5,true,Open,,5,Oslo,150,3,4,true,false,,a",
+            "2,\"line\nbreak\",-0.25,false,Shipped,SAVE,,,0,0,0,false,true,Shipped,\",\"",
+        )
+    );
+    assert_eq!(decode_csv::<Order>(&text), Ok(orders));
+    assert_eq!(
+        decode_csv::<Address>("city,zip.0\r\n\"a\r\nb\",1\r\nOslo,2\r\n"),
+        Ok(vec![
+            Address {
+                city: "a\r\nb".to_string(),
+                zip: Zip(1)
+            },
+            Address {
+                city: "Oslo".to_string(),
+                zip: Zip(2)
+            },
+        ])
+    );
+
+    // Columns may be in any order, and unknown columns are ignored.
+    assert_eq!(
+        decode_csv::<Zip>("extra,0\nx,7\ny,8"),
+        Ok(vec![Zip(7), Zip(8)])
+    );
+    assert_eq!(decode_csv::<Zip>(""), Ok(vec![]));
+    assert_eq!(decode_csv::<Zip>("0\n"), Ok(vec![]));
+
+    assert_eq!(
+        decode_csv::<Address>("city,zip\nOslo,1\n"),
+        Err(CsvError::Missing("zip.0".to_string()))
+    );
+    assert_eq!(
+        decode_csv::<Address>("zip.0,city\n1,Oslo\n2\n"),
+        Err(CsvError::Length {
+            line: 3,
+            expected: 2,
+            found: 1
+        })
+    );
+    assert_eq!(
+        decode_csv::<Address>("city,zip.0\n\"a\nb\",1\nBergen,x\n"),
+        Err(CsvError::Invalid {
+            line: 4,
+            column: "zip.0".to_string(),
+            value: "x".to_string()
+        })
+    );
+    assert_eq!(
+        decode_csv::<Order>(&text_with(&orders_header(), "status", "Lost"))
+            .unwrap_err()
+            .to_string(),
+        "invalid value `Lost` for column `status` on line 2"
+    );
+    assert_eq!(
+        decode_csv::<Grade>("0,1\nOpen,ab\n")
+            .unwrap_err()
+            .to_string(),
+        "invalid value `ab` for column `1` on line 2"
+    );
+    assert_eq!(decode_csv::<Zip>("0\n\"1\n"), Err(CsvError::Syntax(2)));
+    assert_eq!(decode_csv::<Zip>("0\n\"1\"2\n"), Err(CsvError::Syntax(2)));
+    assert_eq!(
+        decode_csv::<Zip>("0\n1\"\n").unwrap_err().to_string(),
+        "invalid CSV on line 2"
+    );
+}
+
+fn orders_header() -> Vec<String> {
+    csv_header::<Order>()
+}
+
+/// A record of an order, with the cell in `column` replaced by `value`.
+fn text_with(header: &[String], column: &str, value: &str) -> String {
+    let cells = [
+        "1", "c", "1", "true", "Open", "", "", "Oslo", "1", "1", "1", "true", "true", "", "a",
+    ];
+    let cells = header
+        .iter()
+        .zip(&cells)
+        .map(|(name, cell)| if name == column { value } else { cell })
+        .collect::<Vec<_>>();
+    format!("{}\n{}\n", header.join(","), cells.join(","))
+}
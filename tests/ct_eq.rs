@@ -0,0 +1,78 @@
+use generics::ops::{ConstantTimeEq, GenericConstantTimeEq};
+use generics::{Generic, Sensitive};
+
+#[derive(Generic, GenericConstantTimeEq, Clone)]
+struct Secret {
+    key: [u8; 8],
+    nonce: u64,
+    #[generic(sensitive)]
+    label: String,
+}
+
+#[derive(Generic, GenericConstantTimeEq, Clone)]
+enum Credential {
+    Password(String),
+    Key { secret: Secret, rounds: Vec<i32> },
+    None,
+}
+
+fn eq<T: ConstantTimeEq>(a: &T, b: &T) -> bool {
+    a.ct_eq(b).into()
+}
+
+#[test]
+fn ct_eq() {
+    let secret = Secret {
+        key: [1, 2, 3, 4, 5, 6, 7, 8],
+        nonce: 9,
+        label: "a".to_string(),
+    };
+    assert!(eq(&secret, &secret.clone()));
+    assert!(!eq(
+        &secret,
+        &Secret {
+            key: [1, 2, 3, 4, 5, 6, 7, 0],
+            ..secret.clone()
+        }
+    ));
+    assert!(!eq(
+        &secret,
+        &Secret {
+            nonce: 1 << 63,
+            ..secret.clone()
+        }
+    ));
+    assert!(!eq(
+        &secret,
+        &Secret {
+            label: "b".to_string(),
+            ..secret.clone()
+        }
+    ));
+
+    let key = Credential::Key {
+        secret: secret.clone(),
+        rounds: vec![-1, 2],
+    };
+    assert!(eq(&key, &key.clone()));
+    assert!(!eq(
+        &key,
+        &Credential::Key {
+            secret,
+            rounds: vec![-1, 2, 3],
+        }
+    ));
+    assert!(!eq(&key, &Credential::None));
+    assert!(eq(&Credential::None, &Credential::None));
+    assert!(!eq(
+        &Credential::Password("x".to_string()),
+        &Credential::Password("y".to_string())
+    ));
+
+    assert!(eq(&i64::MIN, &i64::MIN));
+    assert!(!eq(&i64::MIN, &i64::MAX));
+    assert!(!eq(&0u128, &1));
+    assert!(eq(&'x', &'x'));
+    assert!(eq(&Sensitive(true), &Sensitive(true)));
+    assert_eq!((!0u8.ct_eq(&0)).unwrap_u8(), 0);
+}
@@ -0,0 +1,81 @@
+use generics::{define_generic_op, Generic};
+
+define_generic_op! {
+    trait Describe {
+        fn describe(self) -> String;
+    }
+
+    fn describe;
+
+    unit => String::new(),
+    prod(a, b) => {
+        if b.is_empty() {
+            a
+        } else {
+            format!("{}, {}", a, b)
+        }
+    },
+    sum(x) => x,
+    meta(x, M) => format!("{}: {}", M::get(), x),
+    leaf(x: u8) => x.to_string(),
+    leaf(x: u64) => format!("{}u64", x),
+}
+
+#[derive(Generic)]
+struct Inner {
+    c: u8,
+}
+
+#[derive(Generic)]
+struct Foo {
+    a: u8,
+    b: u64,
+    inner: Inner,
+}
+
+#[test]
+fn define_generic_op() {
+    let foo = Foo {
+        a: 1,
+        b: 2,
+        inner: Inner { c: 3 },
+    };
+
+    assert_eq!(describe(foo), "a: 1, b: 2u64, inner: c: 3");
+}
+
+define_generic_op! {
+    trait DescribeCon {
+        fn describe_con(self) -> String;
+    }
+
+    fn describe_con;
+
+    unit => String::new(),
+    prod(a, b) => {
+        if b.is_empty() {
+            a
+        } else {
+            format!("{}, {}", a, b)
+        }
+    },
+    sum(x) => x,
+    meta(x, M) => format!("{}: {}", M::get(), x),
+    con(x, C) => format!("{} {{ {} }}", C::get(), x),
+    leaf(x: u8) => x.to_string(),
+    leaf(x: u64) => format!("{}u64", x),
+}
+
+#[test]
+fn define_generic_op_con() {
+    let foo = Foo {
+        a: 1,
+        b: 2,
+        inner: Inner { c: 3 },
+    };
+
+    assert_eq!(
+        describe_con(foo),
+        "Foo { a: 1, b: 2u64, inner: Inner { c: 3 } }"
+    );
+}
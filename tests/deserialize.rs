@@ -0,0 +1,171 @@
+#![cfg(feature = "serde")]
+
+use generics::ops::{deserialize_generic, serialize_generic, try_deserialize_generic};
+use generics::{Generic, TryFromRepr};
+use serde::{Deserialize, Serialize};
+use serde_test::{assert_de_tokens, Token};
+
+#[derive(Generic, Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct Record {
+    id: u64,
+    name: String,
+    score: Option<f32>,
+    tags: Vec<Tag>,
+    pos: Pos,
+    id_: Id,
+    unit: Empty,
+    pair: (u8, char),
+    grid: [bool; 2],
+}
+
+#[derive(Generic, Serialize, Deserialize, Clone, Debug, PartialEq)]
+enum Tag {
+    Plain,
+    Named(String),
+    Weighted(String, u8),
+    Scoped { scope: String, weight: Option<u8> },
+}
+
+#[derive(Generic, Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct Pos(i16, i16);
+
+#[derive(Generic, Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct Id(u32);
+
+#[derive(Generic, Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct Empty;
+
+#[derive(Generic, Debug, PartialEq)]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl TryFromRepr for Range {
+    type Error = String;
+    fn try_from_repr(repr: Self::Repr) -> Result<Self, Self::Error> {
+        let range = Range::from_repr(repr);
+        if range.start > range.end {
+            return Err(format!("{} > {}", range.start, range.end));
+        }
+        Ok(range)
+    }
+}
+
+/// Deserializes the wrapped value with `deserialize_generic`, instead of its derived impl.
+#[derive(Debug, PartialEq)]
+struct ViaGeneric<T>(T);
+
+impl<'de, T> Deserialize<'de> for ViaGeneric<T>
+where
+    T: Generic,
+    T::Repr: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_generic(deserializer).map(ViaGeneric)
+    }
+}
+
+fn from_json<'de, T>(json: &'de str) -> Result<T, serde_json::Error>
+where
+    T: Generic,
+    T::Repr: Deserialize<'de>,
+{
+    deserialize_generic(&mut serde_json::Deserializer::from_str(json))
+}
+
+#[test]
+fn deserialize() {
+    let record = Record {
+        id: 9,
+        name: "rec".to_string(),
+        score: Some(0.5),
+        tags: vec![
+            Tag::Plain,
+            Tag::Named("n".to_string()),
+            Tag::Weighted("w".to_string(), 2),
+            Tag::Scoped {
+                scope: "s".to_string(),
+                weight: None,
+            },
+        ],
+        pos: Pos(-1, 1),
+        id_: Id(7),
+        unit: Empty,
+        pair: (3, 'c'),
+        grid: [true, false],
+    };
+
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(from_json::<Record>(&json).unwrap(), record);
+    let json = serialize_generic(record.clone(), serde_json::value::Serializer).unwrap();
+    assert_eq!(
+        deserialize_generic::<Record, _>(json).unwrap(),
+        record.clone()
+    );
+
+    // Fields in any order, unknown fields ignored, structs as sequences.
+    let json = r#"{"x":0,"end":2,"start":1}"#;
+    assert_eq!(
+        from_json::<Range>(json).unwrap(),
+        Range { start: 1, end: 2 }
+    );
+    assert_eq!(
+        from_json::<Range>("[1,2]").unwrap(),
+        Range { start: 1, end: 2 }
+    );
+    assert_eq!(
+        from_json::<Range>(r#"{"start":1}"#)
+            .unwrap_err()
+            .to_string(),
+        "missing field `end` at line 1 column 11"
+    );
+    assert_eq!(
+        from_json::<Range>(r#"{"start":1,"start":2}"#)
+            .unwrap_err()
+            .to_string(),
+        "duplicate field `start` at line 1 column 18"
+    );
+    assert_eq!(
+        from_json::<Tag>(r#""Other""#).unwrap_err().to_string(),
+        "unknown variant `Other`, expected one of `Plain`, `Named`, `Weighted`, `Scoped` at line 1 column 7"
+    );
+
+    let tokens = [
+        Token::Struct {
+            name: "Range",
+            len: 2,
+        },
+        Token::Str("start"),
+        Token::U32(1),
+        Token::Str("end"),
+        Token::U32(2),
+        Token::StructEnd,
+    ];
+    assert_de_tokens(&ViaGeneric(Range { start: 1, end: 2 }), &tokens);
+    let tokens = [
+        Token::NewtypeVariant {
+            name: "Tag",
+            variant: "Named",
+        },
+        Token::Str("n"),
+    ];
+    assert_de_tokens(&ViaGeneric(Tag::Named("n".to_string())), &tokens);
+    assert_de_tokens(&Tag::Named("n".to_string()), &tokens);
+
+    let mut de = serde_json::Deserializer::from_str(r#"{"start":1,"end":2}"#);
+    assert_eq!(
+        try_deserialize_generic::<Range, _>(&mut de).unwrap(),
+        Range { start: 1, end: 2 }
+    );
+    let mut de = serde_json::Deserializer::from_str(r#"{"start":3,"end":2}"#);
+    assert_eq!(
+        try_deserialize_generic::<Range, _>(&mut de)
+            .unwrap_err()
+            .to_string(),
+        "3 > 2"
+    );
+}
@@ -0,0 +1,80 @@
+use generics::ops::{self, Diff, Segment};
+use generics::Generic;
+
+#[derive(Generic)]
+struct Point(i32, i32);
+
+#[derive(Generic)]
+enum Shape {
+    Dot(Point),
+    Line { from: Point, to: Point },
+}
+
+#[derive(Generic)]
+struct Scene {
+    name: String,
+    shapes: Vec<Shape>,
+    origin: (i32, i32),
+    grid: [u8; 2],
+}
+
+fn scene() -> Scene {
+    Scene {
+        name: "a".to_string(),
+        shapes: vec![Shape::Dot(Point(0, 0)), Shape::Dot(Point(1, 1))],
+        origin: (0, 0),
+        grid: [1, 2],
+    }
+}
+
+#[test]
+fn diff() {
+    assert_eq!(ops::diff(scene(), scene()), Diff::Unchanged);
+    assert_eq!(ops::diff(1, 2).to_string(), "1 -> 2");
+
+    let mut new = scene();
+    new.name = "b".to_string();
+    new.shapes[0] = Shape::Dot(Point(0, 5));
+    new.shapes[1] = Shape::Line {
+        from: Point(1, 1),
+        to: Point(2, 2),
+    };
+    new.shapes.push(Shape::Dot(Point(3, 3)));
+    new.origin.1 = 7;
+    new.grid[0] = 9;
+
+    let diff = ops::diff(scene(), new);
+    assert_eq!(
+        diff.to_string(),
+        "name: \"a\" -> \"b\"\n\
+         shapes[0].0.1: 0 -> 5\n\
+         shapes[1]: Dot(Point(1, 1)) -> Line { from: Point(1, 1), to: Point(2, 2) }\n\
+         shapes[2]: added Dot(Point(3, 3))\n\
+         origin[1]: 0 -> 7\n\
+         grid[0]: 1 -> 9",
+    );
+
+    let Diff::Fields(fields) = &diff else {
+        panic!("expected fields");
+    };
+    assert_eq!(fields[3].0, Segment::Field("grid"));
+    assert_eq!(
+        fields[3].1,
+        Diff::Fields(vec![
+            (
+                Segment::Index(0),
+                Diff::Leaf {
+                    old: "1".to_string(),
+                    new: "9".to_string()
+                }
+            ),
+            (Segment::Index(1), Diff::Unchanged),
+        ])
+    );
+
+    let removed = ops::diff(vec![1, 2], vec![1]);
+    assert_eq!(
+        removed.changes(),
+        [("[1]".to_string(), &Diff::Removed("2".to_string()))]
+    );
+}
@@ -0,0 +1,90 @@
+use generics::dynamic::{from_dyn, to_dyn, DynPrim, DynRepr, FromDynError};
+use generics::Generic;
+
+#[derive(Generic, Debug, PartialEq)]
+struct Inner(u8);
+
+#[derive(Generic, Debug, PartialEq)]
+struct Foo {
+    a: u32,
+    inner: Inner,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct Bar {
+    b: u32,
+    inner: Inner,
+}
+
+#[test]
+fn dynamic_round_trip() {
+    let foo = Foo {
+        a: 1,
+        inner: Inner(2),
+    };
+
+    let repr = to_dyn(foo);
+    assert_eq!(
+        repr,
+        DynRepr::Con(
+            "Foo",
+            Box::new(DynRepr::Prod(
+                Box::new(DynRepr::Meta("a", Box::new(DynRepr::Prim(DynPrim::U32(1))))),
+                Box::new(DynRepr::Prod(
+                    Box::new(DynRepr::Meta(
+                        "inner",
+                        Box::new(DynRepr::Con(
+                            "Inner",
+                            Box::new(DynRepr::Prod(
+                                Box::new(DynRepr::Meta(
+                                    "0",
+                                    Box::new(DynRepr::Prim(DynPrim::U8(2)))
+                                )),
+                                Box::new(DynRepr::Unit),
+                            )),
+                        )),
+                    )),
+                    Box::new(DynRepr::Unit),
+                )),
+            )),
+        )
+    );
+
+    assert_eq!(
+        from_dyn::<Foo>(repr),
+        Ok(Foo {
+            a: 1,
+            inner: Inner(2)
+        })
+    );
+}
+
+#[test]
+fn dynamic_mismatch() {
+    let foo = Foo {
+        a: 1,
+        inner: Inner(2),
+    };
+
+    assert_eq!(
+        from_dyn::<Bar>(to_dyn(foo)),
+        Err(FromDynError::Name {
+            expected: "Bar",
+            found: "Foo"
+        })
+    );
+
+    assert_eq!(
+        from_dyn::<Inner>(DynRepr::Con(
+            "Inner",
+            Box::new(DynRepr::Prod(
+                Box::new(DynRepr::Meta("0", Box::new(DynRepr::Prim(DynPrim::U16(2))))),
+                Box::new(DynRepr::Unit),
+            )),
+        )),
+        Err(FromDynError::Shape {
+            expected: "u8",
+            found: "u16"
+        })
+    );
+}
@@ -0,0 +1,29 @@
+#![cfg(all(
+    feature = "chrono",
+    feature = "rust_decimal",
+    feature = "time",
+    feature = "uuid"
+))]
+
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Order {
+    id: uuid::Uuid,
+    placed: chrono::DateTime<chrono::Utc>,
+    due: chrono::NaiveDate,
+    shipped: Option<time::OffsetDateTime>,
+    total: rust_decimal::Decimal,
+}
+
+#[test]
+fn ecosystem() {
+    let order = Order {
+        id: uuid::Uuid::from_u128(1),
+        placed: chrono::DateTime::from_timestamp(1_000_000_000, 0).unwrap(),
+        due: chrono::NaiveDate::from_ymd_opt(2001, 9, 9).unwrap(),
+        shipped: Some(time::OffsetDateTime::UNIX_EPOCH),
+        total: rust_decimal::Decimal::new(1999, 2),
+    };
+    assert_eq!(Order::from_repr(order.clone().into_repr()), order);
+}
@@ -0,0 +1,119 @@
+use generics::{visit, Con, Generic, GenericVisitor, Meta, Prod, Singleton, Sum, Unit, Void};
+
+trait Accumulate {
+    fn acc(self) -> u64;
+}
+
+impl Accumulate for u64 {
+    fn acc(self) -> u64 {
+        self
+    }
+}
+
+impl Accumulate for Unit {
+    fn acc(self) -> u64 {
+        0
+    }
+}
+
+impl<A, B> Accumulate for Prod<A, B>
+where
+    A: Accumulate,
+    B: Accumulate,
+{
+    fn acc(self) -> u64 {
+        let Prod(a, b) = self;
+        a.acc() + b.acc()
+    }
+}
+
+impl<L, R> Accumulate for Sum<L, R>
+where
+    L: Accumulate,
+    R: Accumulate,
+{
+    fn acc(self) -> u64 {
+        match self {
+            Sum::Left(l) => l.acc(),
+            Sum::Right(r) => r.acc(),
+        }
+    }
+}
+
+impl Accumulate for Void {
+    fn acc(self) -> u64 {
+        match self {}
+    }
+}
+
+impl<I, M> Accumulate for Meta<I, M>
+where
+    I: Accumulate,
+    M: Singleton,
+{
+    fn acc(self) -> u64 {
+        let Meta(inner, _) = self;
+        inner.acc()
+    }
+}
+
+impl<I, C> Accumulate for Con<I, C>
+where
+    I: Accumulate,
+    C: Singleton,
+{
+    fn acc(self) -> u64 {
+        let Con(inner, _) = self;
+        inner.acc()
+    }
+}
+
+#[derive(Default)]
+struct Names(Vec<&'static str>);
+
+impl GenericVisitor for Names {
+    fn enter_constructor(&mut self, name: &'static str) {
+        self.0.push(name);
+    }
+
+    fn visit_field(&mut self, name: &'static str) {
+        self.0.push(name);
+    }
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+#[generic(const_fn)]
+enum Foo {
+    A,
+    B(u64, u64),
+    C { a: u64 },
+}
+
+#[derive(Generic)]
+enum Empty {}
+
+const C: <Foo as Generic>::Repr = Foo::C { a: 3 }.into_repr_const();
+
+#[test]
+fn enum_variants() {
+    assert_eq!(Foo::A.into_repr().acc(), 0);
+    assert_eq!(Foo::B(19, 23).into_repr().acc(), 42);
+    assert_eq!(Foo::C { a: 7 }.into_repr().acc(), 7);
+
+    for foo in [Foo::A, Foo::B(1, 2), Foo::C { a: 3 }] {
+        assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+    }
+
+    assert_eq!(Foo::from_repr_const(C), Foo::C { a: 3 });
+
+    let mut names = Names::default();
+    visit(Foo::C { a: 3 }, &mut names);
+    assert_eq!(names.0, ["C", "a"]);
+    let mut names = Names::default();
+    visit(Foo::B(1, 2), &mut names);
+    assert_eq!(names.0, ["B", "0", "1"]);
+
+    let _: fn(<Empty as Generic>::Repr) -> Void = |void| void;
+    assert_eq!(Foo_C_Name::get(), "C");
+    assert_eq!(Foo_C_a::get(), "a");
+}
@@ -0,0 +1,61 @@
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt::Alignment;
+use std::net::Shutdown;
+use std::num::FpCategory;
+
+use generics::dynamic::{from_dyn, to_dyn};
+use generics::{Generic, Void};
+
+mod local {
+    use generics::Generic;
+
+    #[derive(Generic)]
+    pub enum Ordering {
+        Less,
+        Equal,
+        Greater,
+    }
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo {
+    a: Ordering,
+    b: FpCategory,
+    c: Alignment,
+    d: Shutdown,
+    e: Result<u8, Infallible>,
+}
+
+#[test]
+fn enums() {
+    for ordering in [Ordering::Less, Ordering::Equal, Ordering::Greater] {
+        assert_eq!(Ordering::from_repr(ordering.into_repr()), ordering);
+    }
+    for category in [
+        FpCategory::Nan,
+        FpCategory::Infinite,
+        FpCategory::Zero,
+        FpCategory::Subnormal,
+        FpCategory::Normal,
+    ] {
+        assert_eq!(FpCategory::from_repr(category.into_repr()), category);
+    }
+
+    // Represented the same as an equivalent derived enum.
+    assert_eq!(to_dyn(Ordering::Greater), to_dyn(local::Ordering::Greater));
+    assert_eq!(to_dyn(Ordering::Less), to_dyn(local::Ordering::Less));
+    assert_ne!(to_dyn(Ordering::Less), to_dyn(local::Ordering::Equal));
+
+    let foo = Foo {
+        a: Ordering::Equal,
+        b: FpCategory::Normal,
+        c: Alignment::Center,
+        d: Shutdown::Write,
+        e: Ok(1),
+    };
+    assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+    assert_eq!(from_dyn::<Foo>(to_dyn(foo.clone())), Ok(foo));
+
+    let _: fn(<Infallible as Generic>::Repr) -> Void = |void| void;
+}
@@ -0,0 +1,155 @@
+#![cfg(feature = "env")]
+
+use std::net::SocketAddrV4;
+
+use generics::ops::{from_env, from_vars, EnvError};
+use generics::Generic;
+
+#[derive(Generic, Debug, PartialEq)]
+struct Config {
+    name: String,
+    #[generic(env_default = "127.0.0.1:8080")]
+    listen: SocketAddrV4,
+    #[generic(env_default = "Info")]
+    log_level: Level,
+    verbose: Option<bool>,
+    #[generic(env_default = "30")]
+    timeout: Option<u64>,
+    peers: Vec<u16>,
+    database: Database,
+    ratio: (f32, f32),
+    keys: [char; 2],
+}
+
+#[derive(Generic, Debug, PartialEq)]
+enum Level {
+    Info,
+    Debug,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct Database {
+    url: String,
+    #[generic(env_default = "4")]
+    pool_size: u8,
+}
+
+fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn env() {
+    let config = from_vars::<Config, _>(
+        "SVC",
+        vars(&[
+            ("SVC_NAME", "api"),
+            ("SVC_PEERS", "1,2,3"),
+            ("SVC_DATABASE_URL", "postgres://db"),
+            ("SVC_RATIO_0", "0.5"),
+            ("SVC_RATIO_1", "2"),
+            ("SVC_KEYS_0", "x"),
+            ("SVC_KEYS_1", "y"),
+            ("OTHER_NAME", "ignored"),
+        ]),
+    );
+    assert_eq!(
+        config,
+        Ok(Config {
+            name: "api".to_string(),
+            listen: "127.0.0.1:8080".parse().unwrap(),
+            log_level: Level::Info,
+            verbose: None,
+            timeout: Some(30),
+            peers: vec![1, 2, 3],
+            database: Database {
+                url: "postgres://db".to_string(),
+                pool_size: 4,
+            },
+            ratio: (0.5, 2.0),
+            keys: ['x', 'y'],
+        })
+    );
+
+    let config = from_vars::<Config, _>(
+        "",
+        vars(&[
+            ("NAME", ""),
+            ("LISTEN", "10.0.0.1:80"),
+            ("LOG_LEVEL", "Debug"),
+            ("VERBOSE", "true"),
+            ("TIMEOUT", "5"),
+            ("PEERS", ""),
+            ("DATABASE_URL", "sqlite::memory:"),
+            ("DATABASE_POOL_SIZE", "1"),
+            ("RATIO_0", "1"),
+            ("RATIO_1", "1"),
+            ("KEYS_0", "a"),
+            ("KEYS_1", "b"),
+        ]),
+    )
+    .unwrap();
+    assert_eq!(config.name, "");
+    assert_eq!(config.listen, "10.0.0.1:80".parse().unwrap());
+    assert_eq!(config.log_level, Level::Debug);
+    assert_eq!(config.verbose, Some(true));
+    assert_eq!(config.timeout, Some(5));
+    assert!(config.peers.is_empty());
+    assert_eq!(config.database.pool_size, 1);
+
+    let err = from_vars::<Config, _>(
+        "SVC",
+        vars(&[
+            ("SVC_LOG_LEVEL", "Trace"),
+            ("SVC_VERBOSE", "yes"),
+            ("SVC_PEERS", "1,,2"),
+            ("SVC_DATABASE_POOL_SIZE", "-1"),
+            ("SVC_RATIO_0", "1"),
+            ("SVC_KEYS_1", "xy"),
+        ]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        EnvError {
+            missing: vec![
+                "SVC_NAME".to_string(),
+                "SVC_DATABASE_URL".to_string(),
+                "SVC_RATIO_1".to_string(),
+                "SVC_KEYS_0".to_string(),
+            ],
+            invalid: vec![
+                "SVC_LOG_LEVEL".to_string(),
+                "SVC_VERBOSE".to_string(),
+                "SVC_PEERS".to_string(),
+                "SVC_DATABASE_POOL_SIZE".to_string(),
+                "SVC_KEYS_1".to_string(),
+            ],
+            repr: None,
+        }
+    );
+    assert_eq!(
+        from_vars::<Database, _>("DB", vars(&[("DB_POOL_SIZE", "x")]))
+            .unwrap_err()
+            .to_string(),
+        "missing environment variables `DB_URL`; invalid environment variables `DB_POOL_SIZE`"
+    );
+    assert_eq!(
+        from_vars::<Database, _>("DB", vars(&[("DB_URL", "u"), ("DB_POOL_SIZE", "x")]))
+            .unwrap_err()
+            .to_string(),
+        "invalid environment variables `DB_POOL_SIZE`"
+    );
+
+    std::env::set_var("GENERICS_ENV_TEST_URL", "mysql://host");
+    assert_eq!(
+        from_env::<Database>("GENERICS_ENV_TEST"),
+        Ok(Database {
+            url: "mysql://host".to_string(),
+            pool_size: 4,
+        })
+    );
+}
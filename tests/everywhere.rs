@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use generics::ops;
+use generics::Generic;
+
+#[derive(Generic, Debug, PartialEq)]
+struct Mount {
+    from: PathBuf,
+    to: Option<PathBuf>,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+enum Entry {
+    Mount(Mount),
+    Named { name: String, tags: [String; 2] },
+}
+
+#[test]
+fn everywhere() {
+    let entries = (
+        Entry::Mount(Mount {
+            from: "/src".into(),
+            to: Some("/dst".into()),
+        }),
+        Entry::Named {
+            name: "A".to_string(),
+            tags: ["B".to_string(), "C".to_string()],
+        },
+        vec![1u8, 2],
+    );
+
+    let mut seen = Vec::new();
+    let entries = ops::everywhere(entries, |s: String| {
+        seen.push(s.clone());
+        s.to_lowercase()
+    });
+    assert_eq!(seen, ["A", "B", "C"]);
+
+    let entries = ops::everywhere(entries, |path: PathBuf| {
+        PathBuf::from("/root").join(path.strip_prefix("/").unwrap())
+    });
+    let entries = ops::everywhere(entries, |x: u8| x * 10);
+    assert_eq!(
+        entries,
+        (
+            Entry::Mount(Mount {
+                from: "/root/src".into(),
+                to: Some("/root/dst".into()),
+            }),
+            Entry::Named {
+                name: "a".to_string(),
+                tags: ["b".to_string(), "c".to_string()],
+            },
+            vec![10, 20],
+        )
+    );
+}
@@ -0,0 +1,57 @@
+use generics::{Field, Generic, GenericExt};
+
+#[derive(Generic, Debug, PartialEq)]
+struct Foo {
+    a: u8,
+    b: u32,
+}
+
+struct Meters(u32);
+
+impl Generic for Meters {
+    type Repr = u32;
+    fn into_repr(self) -> Self::Repr {
+        self.0
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        Meters(repr)
+    }
+}
+
+struct Millimeters(u32);
+
+impl Generic for Millimeters {
+    type Repr = u32;
+    fn into_repr(self) -> Self::Repr {
+        self.0 / 1000
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        Millimeters(repr * 1000)
+    }
+}
+
+#[test]
+fn ext_map_repr() {
+    let foo = Foo { a: 1, b: 2 };
+
+    let foo = foo.map_repr(|mut repr| {
+        *Field::<Foo_b, _>::field_mut(&mut repr) += 40;
+        repr
+    });
+    assert_eq!(foo, Foo { a: 1, b: 42 });
+
+    let foo = foo.modify_repr(|repr| *Field::<Foo_a, _>::field_mut(repr) = 0);
+    assert_eq!(foo, Foo { a: 0, b: 42 });
+
+    let repr = foo.repr();
+    assert_eq!(*Field::<Foo_b, _>::field(&repr), 42);
+}
+
+#[test]
+fn ext_convert() {
+    let mm: Millimeters = Meters(3).convert();
+    assert_eq!(mm.0, 3000);
+
+    let m: Meters = mm.convert();
+    assert_eq!(m.0, 3);
+}
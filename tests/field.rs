@@ -0,0 +1,31 @@
+use generics::{Field, Generic};
+
+#[derive(Generic)]
+struct Foo {
+    a: u8,
+    b: u16,
+    r#c: u32,
+}
+
+#[derive(Generic)]
+struct Bar(u8, u16);
+
+#[test]
+fn field() {
+    let foo = Foo { a: 1, b: 2, c: 3 };
+
+    let mut repr = foo.into_repr();
+    assert_eq!(*Field::<Foo_a, _>::field(&repr), 1u8);
+    assert_eq!(*Field::<Foo_b, _>::field(&repr), 2u16);
+    assert_eq!(*Field::<Foo_c, _>::field(&repr), 3u32);
+
+    *Field::<Foo_b, _>::field_mut(&mut repr) = 42;
+    let foo = Foo::from_repr(repr);
+    assert_eq!(foo.b, 42);
+
+    let bar = Bar(1, 2);
+
+    let repr = bar.into_repr();
+    assert_eq!(*Field::<Bar_0, _>::field(&repr), 1u8);
+    assert_eq!(Field::<Bar_1, _>::into_field(repr), 2u16);
+}
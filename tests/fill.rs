@@ -0,0 +1,92 @@
+use std::any::Any;
+
+use generics::ops::{self, FillError, LeafSource};
+use generics::{Generic, Leaf};
+
+#[derive(Debug, PartialEq, Generic)]
+enum Shape {
+    Dot,
+    Line(u8, u8),
+}
+
+#[derive(Debug, PartialEq, Generic)]
+struct Scene {
+    name: String,
+    shapes: Vec<Shape>,
+    grid: [u8; 2],
+}
+
+/// Yields increasing `u8`s, and picks the last variant of every sum.
+struct Counter(u8);
+
+impl LeafSource for Counter {
+    fn next_leaf<T>(&mut self) -> Result<T, FillError>
+    where
+        T: Leaf + 'static,
+    {
+        self.0 += 1;
+        let leaf: Box<dyn Any> = Box::new(self.0);
+        leaf.downcast()
+            .map(|leaf| *leaf)
+            .map_err(|_| FillError::Mismatch {
+                expected: std::any::type_name::<T>(),
+            })
+    }
+
+    fn next_variant(&mut self) -> Result<usize, FillError> {
+        Ok(1)
+    }
+
+    fn next_len(&mut self) -> Result<usize, FillError> {
+        Ok(2)
+    }
+}
+
+fn leaves(leaves: Vec<Box<dyn Any>>) -> impl Iterator<Item = Box<dyn Any>> {
+    leaves.into_iter()
+}
+
+#[test]
+fn fill() {
+    let mut source = leaves(vec![
+        Box::new("a".to_string()),
+        Box::new(2usize),
+        Box::new(0usize),
+        Box::new(1usize),
+        Box::new(3u8),
+        Box::new(4u8),
+        Box::new(5u8),
+        Box::new(6u8),
+    ]);
+    assert_eq!(
+        ops::fill::<Scene, _>(&mut source),
+        Ok(Scene {
+            name: "a".to_string(),
+            shapes: vec![Shape::Dot, Shape::Line(3, 4)],
+            grid: [5, 6],
+        })
+    );
+    assert!(source.next().is_none());
+
+    assert_eq!(
+        ops::fill::<Shape, _>(&mut Counter(0)),
+        Ok(Shape::Line(1, 2))
+    );
+    assert_eq!(
+        ops::fill::<[Shape; 2], _>(&mut Counter(0)),
+        Ok([Shape::Line(1, 2), Shape::Line(3, 4)])
+    );
+
+    assert_eq!(
+        ops::fill::<Shape, _>(&mut leaves(vec![Box::new(2usize)])),
+        Err(FillError::Variant(2))
+    );
+    assert_eq!(
+        ops::fill::<Shape, _>(&mut leaves(vec![Box::new(1usize), Box::new(1u32)])),
+        Err(FillError::Mismatch { expected: "u8" })
+    );
+    assert_eq!(
+        ops::fill::<[u8; 2], _>(&mut leaves(vec![Box::new(1u8)])),
+        Err(FillError::Exhausted)
+    );
+}
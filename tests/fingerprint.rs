@@ -0,0 +1,74 @@
+use generics::ops::{fingerprint, shape};
+use generics::Generic;
+
+mod v1 {
+    use generics::Generic;
+
+    #[derive(Generic)]
+    pub struct Record {
+        pub id: u32,
+        pub name: String,
+        pub kind: Kind,
+    }
+
+    #[derive(Generic)]
+    pub enum Kind {
+        A,
+        B(u8),
+    }
+}
+
+mod moved {
+    use generics::Generic;
+
+    #[derive(Generic)]
+    pub struct Record {
+        pub id: u32,
+        pub name: String,
+        pub kind: super::v1::Kind,
+    }
+}
+
+#[derive(Generic)]
+struct Record {
+    id: u32,
+    label: String,
+    kind: v1::Kind,
+}
+
+#[derive(Generic)]
+enum Kind {
+    B(u8),
+    A,
+}
+
+#[derive(Generic)]
+struct Grid {
+    cells: [u8; 4],
+}
+
+#[derive(Generic)]
+struct BigGrid {
+    cells: [u8; 5],
+}
+
+#[test]
+fn fingerprints() {
+    assert_eq!(fingerprint::<v1::Record>(), fingerprint::<v1::Record>());
+    assert_eq!(
+        fingerprint::<v1::Record>(),
+        shape::<v1::Record>().fingerprint()
+    );
+    // Module paths don't matter, only the structure.
+    assert_eq!(fingerprint::<v1::Record>(), fingerprint::<moved::Record>());
+
+    // Renamed fields, reordered variants and resized arrays all change the fingerprint.
+    assert_ne!(fingerprint::<v1::Record>(), fingerprint::<Record>());
+    assert_ne!(fingerprint::<v1::Kind>(), fingerprint::<Kind>());
+    assert_ne!(fingerprint::<Grid>(), fingerprint::<BigGrid>());
+    assert_ne!(fingerprint::<Vec<u8>>(), fingerprint::<[u8; 0]>());
+    assert_ne!(fingerprint::<(u8, (u8,))>(), fingerprint::<((u8,), u8)>());
+
+    // The hash doesn't depend on the platform.
+    assert_eq!(fingerprint::<(u8, bool)>(), 0x5b0b_eea7_23a2_f0dc);
+}
@@ -0,0 +1,96 @@
+use std::num::NonZeroU8;
+
+use generics::ops::{all_values, Finite};
+use generics::{Generic, Opaque};
+
+#[derive(Generic, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Door {
+    Open,
+    Closed { locked: bool },
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct State {
+    door: Door,
+    alarm: Option<bool>,
+    lights: [bool; 2],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Suit {
+    Hearts,
+    Spades,
+}
+
+impl Generic for Suit {
+    type Repr = Opaque<Suit>;
+    fn into_repr(self) -> Self::Repr {
+        Opaque(self)
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr.0
+    }
+}
+
+impl Finite for Suit {
+    fn cardinality() -> usize {
+        2
+    }
+
+    fn nth(index: usize) -> Self {
+        [Suit::Hearts, Suit::Spades][index]
+    }
+}
+
+#[derive(Generic)]
+enum Empty {}
+
+#[test]
+fn finite() {
+    let doors = all_values::<Door>().collect::<Vec<_>>();
+    assert_eq!(
+        doors,
+        [
+            Door::Open,
+            Door::Closed { locked: false },
+            Door::Closed { locked: true },
+        ]
+    );
+    assert!(doors.windows(2).all(|w| w[0] < w[1]));
+
+    let states = all_values::<State>().collect::<Vec<_>>();
+    assert_eq!(states.len(), 3 * 3 * 4);
+    assert_eq!(
+        states[0],
+        State {
+            door: Door::Open,
+            alarm: None,
+            lights: [false, false],
+        }
+    );
+    assert_eq!(states[1].lights, [false, true]);
+    assert_eq!(states[2].lights, [true, false]);
+    assert_eq!(
+        states[35],
+        State {
+            door: Door::Closed { locked: true },
+            alarm: Some(true),
+            lights: [true, true],
+        }
+    );
+    for (i, a) in states.iter().enumerate() {
+        assert!(states[i + 1..].iter().all(|b| a != b));
+    }
+
+    assert_eq!(all_values::<(bool, u8)>().count(), 512);
+    assert_eq!(all_values::<NonZeroU8>().count(), 255);
+    assert_eq!(all_values::<NonZeroU8>().next(), NonZeroU8::new(1));
+    assert_eq!(all_values::<i8>().next(), Some(i8::MIN));
+    assert_eq!(all_values::<i8>().last(), Some(i8::MAX));
+    assert_eq!(
+        all_values::<Suit>().collect::<Vec<_>>(),
+        [Suit::Hearts, Suit::Spades]
+    );
+    assert_eq!(all_values::<Empty>().count(), 0);
+    assert_eq!(all_values::<[Empty; 0]>().count(), 1);
+}
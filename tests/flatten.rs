@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use generics::ops::{flatten, unflatten, UnflattenError};
+use generics::Generic;
+
+#[derive(Generic, Debug, PartialEq, Clone)]
+struct Endpoint {
+    host: String,
+    port: u16,
+}
+
+#[derive(Generic, Debug, PartialEq, Clone)]
+enum Auth {
+    None,
+    Token(String),
+    Basic { user: String, password: String },
+}
+
+#[derive(Generic, Debug, PartialEq, Clone)]
+struct Config {
+    name: String,
+    endpoints: Vec<Endpoint>,
+    auth: Auth,
+    retries: Option<u8>,
+    weights: [f32; 2],
+}
+
+#[derive(Generic, Debug, PartialEq, Clone)]
+struct Marker {
+    label: String,
+    pos: (i32, i32),
+    nested: ((), (u8, bool)),
+}
+
+fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn flatten_unflatten() {
+    let config = Config {
+        name: "svc".to_string(),
+        endpoints: vec![
+            Endpoint {
+                host: "a".to_string(),
+                port: 80,
+            },
+            Endpoint {
+                host: "b".to_string(),
+                port: 443,
+            },
+        ],
+        auth: Auth::Basic {
+            user: "admin".to_string(),
+            password: "pw".to_string(),
+        },
+        retries: None,
+        weights: [0.5, 1.0],
+    };
+
+    let flat = flatten(config.clone());
+    assert_eq!(
+        flat,
+        pairs(&[
+            ("name", "svc"),
+            ("endpoints.0.host", "a"),
+            ("endpoints.0.port", "80"),
+            ("endpoints.1.host", "b"),
+            ("endpoints.1.port", "443"),
+            ("auth", "Basic"),
+            ("auth.user", "admin"),
+            ("auth.password", "pw"),
+            ("retries", "None"),
+            ("weights.0", "0.5"),
+            ("weights.1", "1"),
+        ])
+    );
+    assert_eq!(unflatten::<Config, _>(flat), Ok(config.clone()));
+
+    let map = flatten(Config {
+        endpoints: vec![],
+        auth: Auth::Token("t".to_string()),
+        retries: Some(3),
+        ..config
+    })
+    .into_iter()
+    .collect::<HashMap<_, _>>();
+    assert_eq!(map["auth.0"], "t");
+    assert_eq!(map["retries.0"], "3");
+    assert!(!map.keys().any(|k| k.starts_with("endpoints")));
+    let config = unflatten::<Config, _>(map).unwrap();
+    assert_eq!(config.endpoints, []);
+    assert_eq!(config.auth, Auth::Token("t".to_string()));
+
+    // Tuple elements are named by index, like list items.
+    let marker = Marker {
+        label: "m".to_string(),
+        pos: (1, 2),
+        nested: ((), (3, true)),
+    };
+    let flat = flatten(marker.clone());
+    assert_eq!(
+        flat,
+        pairs(&[
+            ("label", "m"),
+            ("pos.0", "1"),
+            ("pos.1", "2"),
+            ("nested.1.0", "3"),
+            ("nested.1.1", "true"),
+        ])
+    );
+    assert_eq!(unflatten::<Marker, _>(flat), Ok(marker));
+    assert_eq!(
+        unflatten::<(u8, char), _>(pairs(&[("0", "1")])),
+        Err(UnflattenError::Missing("1".to_string()))
+    );
+
+    assert_eq!(flatten(Auth::None), pairs(&[("", "None")]));
+    assert_eq!(flatten(5u8), pairs(&[("", "5")]));
+    assert_eq!(unflatten::<u8, _>(pairs(&[("", "5")])), Ok(5));
+
+    assert_eq!(
+        unflatten::<Endpoint, _>(pairs(&[("host", "a")])),
+        Err(UnflattenError::Missing("port".to_string()))
+    );
+    let err = unflatten::<Endpoint, _>(pairs(&[("host", "a"), ("port", "x")])).unwrap_err();
+    assert_eq!(err.to_string(), "invalid value `x` for `port`");
+    let err = unflatten::<Auth, _>(pairs(&[("", "Oauth")])).unwrap_err();
+    assert_eq!(
+        err,
+        UnflattenError::Variant {
+            path: String::new(),
+            name: "Oauth".to_string(),
+        }
+    );
+}
@@ -0,0 +1,53 @@
+use std::cmp::Ordering;
+
+use generics::ops::{self, GenericDisplay, GenericFromStr, ParseVariantError};
+use generics::Generic;
+
+#[derive(Generic, GenericDisplay, GenericFromStr, Debug, PartialEq)]
+enum Color {
+    Red,
+    #[generic(rename = "verde")]
+    Green,
+    r#Blue,
+}
+
+#[derive(Generic, GenericFromStr, Debug, PartialEq)]
+#[generic(case_insensitive)]
+enum Level {
+    Low,
+    High,
+}
+
+#[derive(Generic, GenericFromStr, Debug)]
+enum Never {}
+
+#[test]
+fn from_str() {
+    assert_eq!("Red".parse(), Ok(Color::Red));
+    assert_eq!("verde".parse(), Ok(Color::Green));
+    assert_eq!("Blue".parse(), Ok(Color::Blue));
+    assert_eq!(Color::Green.to_string(), "verde");
+    assert_eq!(
+        "Green".parse::<Color>(),
+        Err(ParseVariantError {
+            name: "Green".to_string(),
+            expected: vec!["Red", "verde", "Blue"],
+        })
+    );
+    assert_eq!(
+        "red".parse::<Color>().map_err(|e| e.to_string()),
+        Err("unknown variant `red`, expected one of `Red`, `verde`, `Blue`".to_string())
+    );
+
+    assert_eq!("low".parse(), Ok(Level::Low));
+    assert_eq!("HIGH".parse(), Ok(Level::High));
+    assert!("medium".parse::<Level>().is_err());
+
+    assert_eq!(
+        "Anything".parse::<Never>().unwrap_err().to_string(),
+        "unknown variant `Anything`"
+    );
+
+    assert_eq!(ops::from_name("Equal", false), Ok(Ordering::Equal));
+    assert_eq!(ops::from_name("equal", true), Ok(Ordering::Equal));
+}
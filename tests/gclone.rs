@@ -0,0 +1,54 @@
+use std::marker::PhantomData;
+
+use generics::ops::{GClone, GenericClone};
+use generics::{Con, Generic, List, Meta, Prod, Unit};
+
+#[derive(Generic, GenericClone, Debug, PartialEq)]
+struct Empty;
+
+#[derive(Generic, GenericClone, Debug, PartialEq)]
+struct Tuple(u8, String);
+
+#[derive(Generic, GenericClone, Debug, PartialEq)]
+struct Named<T> {
+    a: T,
+    b: Vec<Tuple>,
+    #[generic(opaque)]
+    c: Box<[u8]>,
+}
+
+#[derive(Generic, GenericClone, Debug, PartialEq)]
+enum Enum {
+    A,
+    B(u8),
+    C { named: Named<char> },
+}
+
+#[derive(Generic, GenericClone)]
+enum Never {}
+
+#[test]
+fn gclone() {
+    assert_eq!(Empty.clone(), Empty);
+
+    let named = Named {
+        a: 'x',
+        b: vec![Tuple(1, "y".to_string())],
+        c: vec![2, 3].into_boxed_slice(),
+    };
+    for value in [Enum::A, Enum::B(4), Enum::C { named }] {
+        assert_eq!(value.clone(), value);
+    }
+
+    let repr = Tuple(5, "z".to_string()).into_repr();
+    assert_eq!(Tuple::from_repr(repr.gclone()), Tuple(5, "z".to_string()));
+
+    let repr = Con::<_, Tuple_Name>(
+        Prod(Meta::<_, Tuple_0>(List(vec![[1u8, 2]]), PhantomData), Unit),
+        PhantomData,
+    );
+    let Con(Prod(Meta(List(cloned), _), Unit), _) = repr.gclone();
+    assert_eq!(cloned, [[1, 2]]);
+
+    let _ = |never: &Never| never.clone();
+}
@@ -0,0 +1,50 @@
+use generics::ops;
+use generics::Generic;
+
+#[derive(Generic, Debug, Clone)]
+struct Unit;
+
+#[derive(Generic, Debug, Clone)]
+struct Tuple(u8, String);
+
+#[derive(Generic, Debug, Clone)]
+struct Named {
+    a: u8,
+    b: Vec<Tuple>,
+    c: Option<Unit>,
+    d: [bool; 2],
+    e: (u8, char),
+}
+
+#[derive(Generic, Debug, Clone)]
+enum Enum {
+    A,
+    B(u8),
+    C { named: Named },
+}
+
+#[test]
+fn gdebug() {
+    let named = Named {
+        a: 1,
+        b: vec![Tuple(2, "x".to_string())],
+        c: Some(Unit),
+        d: [true, false],
+        e: (3, 'y'),
+    };
+    let values = vec![Enum::A, Enum::B(4), Enum::C { named }];
+    for value in values {
+        assert_eq!(
+            format!("{:?}", ops::gdebug(value.clone())),
+            format!("{:?}", value)
+        );
+        assert_eq!(
+            format!("{:#?}", ops::gdebug(value.clone())),
+            format!("{:#?}", value)
+        );
+    }
+
+    assert_eq!(format!("{:?}", ops::gdebug(())), "()");
+    assert_eq!(format!("{:?}", ops::gdebug((1u8,))), "(1,)");
+    assert_eq!(format!("{:?}", ops::gdebug((1u8, 2u8))), "(1, 2)");
+}
@@ -0,0 +1,86 @@
+use std::num::NonZeroU8;
+
+use generics::ops::{self, GDefault, GenericDefault};
+use generics::{Generic, List};
+
+#[derive(Generic, GenericDefault, Debug, PartialEq)]
+struct Empty;
+
+#[derive(Generic, GenericDefault, Debug, PartialEq)]
+struct Named<T> {
+    a: T,
+    b: Vec<String>,
+    c: [Option<u8>; 2],
+    d: (bool, char),
+    #[generic(opaque)]
+    e: Box<[u8]>,
+}
+
+#[derive(Generic, GenericDefault, Debug, PartialEq)]
+enum First {
+    A(u8),
+    B,
+}
+
+#[derive(Generic, GenericDefault, Debug, PartialEq)]
+enum Selected {
+    A(u8),
+    B,
+    #[generic(default)]
+    C {
+        named: Named<u16>,
+    },
+}
+
+#[derive(Generic, GenericDefault, Debug, PartialEq)]
+enum Overridden {
+    #[generic(default)]
+    A {
+        #[generic(default = "3")]
+        retries: u8,
+        #[generic(opaque, default = "Box::new([1])")]
+        key: Box<[u8]>,
+        name: String,
+    },
+    B,
+}
+
+#[test]
+fn gdefault() {
+    assert_eq!(<Empty as Default>::default(), Empty);
+    assert_eq!(
+        Named::<u8>::default(),
+        Named {
+            a: 0,
+            b: vec![],
+            c: [None, None],
+            d: (false, '\0'),
+            e: Box::new([]),
+        }
+    );
+    assert_eq!(First::default(), First::A(0));
+    assert_eq!(
+        Selected::default(),
+        Selected::C {
+            named: Named::default()
+        }
+    );
+
+    assert_eq!(
+        Overridden::default(),
+        Overridden::A {
+            retries: 3,
+            key: Box::new([1]),
+            name: String::new(),
+        }
+    );
+
+    assert_eq!(ops::gdefault::<First>(), Ok(First::A(0)));
+    assert_eq!(ops::gdefault::<Option<u8>>(), Ok(None));
+    assert_eq!(ops::gdefault::<(u8, String)>(), Ok((0, String::new())));
+    assert!(ops::gdefault::<NonZeroU8>().is_err());
+    assert!(ops::gdefault::<(u8, Option<NonZeroU8>)>().is_ok());
+    assert!(ops::gdefault::<(u8, [NonZeroU8; 1])>().is_err());
+    let List(items) = List::<u8>::gdefault();
+    assert!(items.is_empty());
+}
@@ -0,0 +1,50 @@
+use generics::{generic_op, Generic, Prod};
+
+#[generic_op]
+trait Accumulate {
+    fn acc(&self) -> u64;
+
+    #[generic_op(unit = "init")]
+    fn acc_from(self, init: u64) -> u64;
+}
+
+impl Accumulate for u64 {
+    fn acc(&self) -> u64 {
+        *self
+    }
+
+    fn acc_from(self, init: u64) -> u64 {
+        init + self
+    }
+}
+
+impl<A, B> Accumulate for Prod<A, B>
+where
+    A: Accumulate,
+    B: Accumulate,
+{
+    fn acc(&self) -> u64 {
+        let Prod(a, b) = self;
+        a.acc() + b.acc()
+    }
+
+    fn acc_from(self, init: u64) -> u64 {
+        let Prod(a, b) = self;
+        b.acc_from(a.acc_from(init))
+    }
+}
+
+#[derive(Generic)]
+struct Foo {
+    a: u64,
+    b: u64,
+}
+
+#[test]
+fn generic_op() {
+    let foo = Foo { a: 19, b: 23 };
+
+    let repr = foo.into_repr();
+    assert_eq!(repr.acc(), 42);
+    assert_eq!(repr.acc_from(100), 142);
+}
@@ -0,0 +1,58 @@
+use generics::ops::{GEq, GPartialEq, GenericEq, GenericPartialEq};
+use generics::{Generic, List};
+
+#[derive(Generic, GenericPartialEq, GenericEq, Debug)]
+struct Empty;
+
+#[derive(Generic, GenericPartialEq, Debug)]
+struct Tuple(u8, f32);
+
+#[derive(Generic, GenericPartialEq, GenericEq, Debug)]
+struct Named<T> {
+    a: T,
+    b: Vec<String>,
+    #[generic(opaque)]
+    c: Box<[u8]>,
+}
+
+#[derive(Generic, GenericPartialEq, GenericEq, Debug)]
+enum Enum {
+    A,
+    B(u8),
+    C { named: Named<char> },
+}
+
+#[derive(Generic, GenericPartialEq, GenericEq)]
+enum Never {}
+
+fn assert_eq_impl<T: Eq>() {}
+
+#[test]
+fn geq() {
+    assert_eq!(Empty, Empty);
+    assert_eq!(Tuple(1, 2.0), Tuple(1, 2.0));
+    assert_ne!(Tuple(1, 2.0), Tuple(1, 3.0));
+    assert_ne!(Tuple(1, f32::NAN), Tuple(1, f32::NAN));
+
+    let named = |a| Named {
+        a,
+        b: vec!["x".to_string()],
+        c: vec![1, 2].into_boxed_slice(),
+    };
+    assert_eq!(Enum::A, Enum::A);
+    assert_eq!(Enum::B(1), Enum::B(1));
+    assert_ne!(Enum::B(1), Enum::B(2));
+    assert_ne!(Enum::A, Enum::B(1));
+    assert_eq!(Enum::C { named: named('a') }, Enum::C { named: named('a') });
+    assert_ne!(Enum::C { named: named('a') }, Enum::C { named: named('b') });
+
+    assert!(List(vec![1u8, 2]).geq(&List(vec![1, 2])));
+    assert!(List(vec![1u8, 2]).gne(&List(vec![1])));
+    assert!([1u8, 2].gne(&[1, 3]));
+
+    assert_eq_impl::<Empty>();
+    assert_eq_impl::<Enum>();
+    assert_eq_impl::<Never>();
+    fn assert_geq<T: GEq>(_: T) {}
+    assert_geq(Enum::A.into_repr());
+}
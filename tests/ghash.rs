@@ -0,0 +1,72 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use generics::ops::{GHash, GenericEq, GenericHash, GenericPartialEq};
+use generics::{Generic, List};
+
+#[derive(Generic, GenericPartialEq, GenericEq, GenericHash)]
+struct Named<T> {
+    a: T,
+    b: Vec<String>,
+    #[generic(opaque)]
+    c: Box<[u8]>,
+}
+
+#[derive(Generic, GenericPartialEq, GenericEq, GenericHash, Clone, Copy)]
+enum Enum {
+    A,
+    B(u8),
+    C(u8),
+    D { a: u8, b: u8 },
+}
+
+#[derive(Generic, GenericHash)]
+enum Never {}
+
+fn hash<T: Hash>(x: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn hash_repr<T: GHash>(x: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.ghash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn ghash() {
+    let named = |a| Named {
+        a,
+        b: vec!["x".to_string()],
+        c: vec![1].into_boxed_slice(),
+    };
+    assert_eq!(hash(&named('a')), hash(&named('a')));
+    assert_ne!(hash(&named('a')), hash(&named('b')));
+
+    let values = [Enum::A, Enum::B(1), Enum::C(1), Enum::D { a: 1, b: 2 }];
+    for (i, a) in values.iter().enumerate() {
+        for (j, b) in values.iter().enumerate() {
+            assert_eq!(hash(a) == hash(b), i == j);
+        }
+        assert_eq!(hash_repr(&a.into_repr()), hash(a));
+    }
+
+    // Lists hash their length, so moving items between them changes the hash.
+    let split = |i| {
+        (
+            List(vec![1u8, 2, 3][..i].to_vec()),
+            List(vec![1u8, 2, 3][i..].to_vec()),
+        )
+    };
+    let mut a = DefaultHasher::new();
+    split(1).0.ghash(&mut a);
+    split(1).1.ghash(&mut a);
+    let mut b = DefaultHasher::new();
+    split(2).0.ghash(&mut b);
+    split(2).1.ghash(&mut b);
+    assert_ne!(a.finish(), b.finish());
+
+    let _ = |never: &Never| hash(never);
+}
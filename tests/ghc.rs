@@ -0,0 +1,32 @@
+#![cfg(feature = "ghc")]
+
+use generics::ghc::{con_name, datatype_name, from, sel_name, to, Rep, L1, R1, U1};
+use generics::{Generic, Sum, Unit};
+
+#[derive(Generic, Debug, PartialEq)]
+struct Foo {
+    a: u8,
+}
+
+#[derive(Generic)]
+enum Bar {
+    #[generic(rename = "b")]
+    B,
+}
+
+#[test]
+fn ghc() {
+    let rep: Rep<Foo> = from(Foo { a: 1 });
+    assert_eq!(con_name(&rep), "Foo");
+    assert_eq!(datatype_name(&rep), "Foo");
+    assert_eq!(sel_name(&(rep.0).0), "a");
+    assert_eq!(to::<Foo>(rep), Foo { a: 1 });
+
+    let Sum::Left(con) = from(Bar::B);
+    assert_eq!((datatype_name(&con), con_name(&con)), ("Bar", "b"));
+
+    let sum: Sum<U1, u8> = L1(Unit);
+    assert!(matches!(sum, Sum::Left(_)));
+    let sum: Sum<U1, u8> = R1(2);
+    assert!(matches!(sum, Sum::Right(2)));
+}
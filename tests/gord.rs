@@ -0,0 +1,71 @@
+use std::cmp::Ordering;
+
+use generics::ops::{
+    GOrd, GPartialOrd, GenericEq, GenericOrd, GenericPartialEq, GenericPartialOrd,
+};
+use generics::{Generic, List};
+
+#[derive(Generic, GenericPartialEq, GenericPartialOrd, Debug)]
+struct Tuple(u8, f32);
+
+#[derive(Generic, GenericPartialEq, GenericEq, GenericPartialOrd, GenericOrd, Debug)]
+struct Named<T> {
+    a: T,
+    b: Vec<String>,
+    #[generic(opaque)]
+    c: Box<[u8]>,
+}
+
+#[derive(Generic, GenericPartialEq, GenericEq, GenericPartialOrd, GenericOrd, Clone, Copy)]
+enum Enum {
+    A,
+    B(u8),
+    C { named: (char, u8) },
+}
+
+#[derive(Generic, GenericPartialEq, GenericEq, GenericPartialOrd, GenericOrd)]
+enum Never {}
+
+#[test]
+fn gord() {
+    assert!(Tuple(1, 9.0) < Tuple(2, 0.0));
+    assert!(Tuple(1, 1.0) < Tuple(1, 2.0));
+    assert_eq!(Tuple(1, f32::NAN).partial_cmp(&Tuple(1, 1.0)), None);
+    assert_eq!(
+        Tuple(0, f32::NAN).partial_cmp(&Tuple(1, 1.0)),
+        Some(Ordering::Less)
+    );
+
+    let named = |a, b: &[&str]| Named {
+        a,
+        b: b.iter().map(|b| b.to_string()).collect(),
+        c: vec![].into_boxed_slice(),
+    };
+    assert!(named(1, &["z"]) < named(2, &[]));
+    assert!(named(1, &["a"]) < named(1, &["a", "a"]));
+    assert_eq!(named(1, &["a"]).cmp(&named(1, &["a"])), Ordering::Equal);
+
+    let values = [
+        Enum::A,
+        Enum::B(0),
+        Enum::B(1),
+        Enum::C { named: ('a', 2) },
+        Enum::C { named: ('b', 1) },
+    ];
+    for (i, a) in values.iter().enumerate() {
+        for (j, b) in values.iter().enumerate() {
+            assert_eq!(a.cmp(b), i.cmp(&j));
+            assert_eq!(a.partial_cmp(b), Some(i.cmp(&j)));
+            assert_eq!(a.into_repr().gcmp(&b.into_repr()), i.cmp(&j));
+        }
+    }
+
+    assert_eq!(
+        List(vec![1u8, 2]).gpartial_cmp(&List(vec![1, 3])),
+        Some(Ordering::Less)
+    );
+    assert_eq!(List(vec![1u8]).gcmp(&List(vec![])), Ordering::Greater);
+    assert_eq!([1u8, 2].gcmp(&[1, 2]), Ordering::Equal);
+
+    let _ = |never: &Never| never.cmp(never);
+}
@@ -0,0 +1,60 @@
+use std::mem;
+
+use generics::ops::{GenericHeapSize, HeapSize};
+use generics::Generic;
+
+#[derive(Generic, GenericHeapSize)]
+struct Inner {
+    name: String,
+    tags: Vec<Box<str>>,
+}
+
+#[derive(Generic, GenericHeapSize)]
+enum Node {
+    Leaf(u32),
+    Named(Inner),
+    Boxed(Box<Inner>),
+}
+
+#[derive(Generic, GenericHeapSize)]
+struct Tree<T> {
+    nodes: Vec<Node>,
+    extra: Option<T>,
+}
+
+#[test]
+fn heap_size() {
+    let inner = || Inner {
+        name: String::with_capacity(8),
+        tags: {
+            let mut tags = Vec::with_capacity(2);
+            tags.push("abc".into());
+            tags
+        },
+    };
+    let inner_heap = 8 + 2 * mem::size_of::<Box<str>>() + 3;
+    assert_eq!(inner().heap_size(), inner_heap);
+
+    assert_eq!(Node::Leaf(1).heap_size(), 0);
+    assert_eq!(Node::Named(inner()).heap_size(), inner_heap);
+    assert_eq!(
+        Node::Boxed(Box::new(inner())).heap_size(),
+        mem::size_of::<Inner>() + inner_heap
+    );
+
+    let mut nodes = Vec::with_capacity(3);
+    nodes.push(Node::Leaf(1));
+    nodes.push(Node::Named(inner()));
+    let tree = Tree {
+        nodes,
+        extra: Some(String::with_capacity(5)),
+    };
+    assert_eq!(
+        tree.heap_size(),
+        3 * mem::size_of::<Node>() + inner_heap + 5
+    );
+    assert_eq!(
+        tree.deep_size_of(),
+        mem::size_of::<Tree<String>>() + tree.heap_size()
+    );
+}
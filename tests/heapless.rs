@@ -0,0 +1,80 @@
+#![cfg(feature = "heapless")]
+
+use std::convert::TryFrom;
+
+use generics::dynamic::{from_dyn, to_dyn, FromDynError};
+use generics::ops::{decode_binary, encode_binary, DecodeBinaryError};
+use generics::{Generic, ReprError, TryFromRepr};
+
+mod std_containers {
+    use generics::Generic;
+
+    #[derive(Generic)]
+    pub struct Reading {
+        pub samples: Vec<u16>,
+    }
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Reading {
+    samples: heapless::Vec<u16, 8>,
+}
+
+#[derive(Generic, Clone, Debug)]
+struct Device {
+    name: heapless::String<16>,
+    registers: heapless::FnvIndexMap<u8, u32, 4>,
+    reading: Reading,
+}
+
+#[test]
+fn heapless() {
+    let reading = Reading {
+        samples: heapless::Vec::from_slice(&[1, 2, 3]).unwrap(),
+    };
+    assert_eq!(Reading::from_repr(reading.clone().into_repr()), reading);
+
+    // Represented the same as the equivalent std containers.
+    let std_reading = std_containers::Reading {
+        samples: vec![1, 2, 3],
+    };
+    assert_eq!(to_dyn(reading.clone()), to_dyn(std_reading));
+
+    let mut registers = heapless::FnvIndexMap::new();
+    registers.insert(1, 2).unwrap();
+    let device = Device {
+        name: heapless::String::try_from("sensor").unwrap(),
+        registers,
+        reading,
+    };
+    let round_trip = Device::from_repr(device.clone().into_repr());
+    assert_eq!(round_trip.name, device.name);
+    assert_eq!(round_trip.registers, device.registers);
+    assert_eq!(round_trip.reading, device.reading);
+}
+
+#[test]
+#[should_panic]
+fn heapless_overflow() {
+    let samples: Vec<u16> = vec![0; 9];
+    heapless::Vec::<u16, 8>::from_repr(samples.into_repr());
+}
+
+#[test]
+fn heapless_checked() {
+    let overflow =
+        ReprError::new("`heapless::Vec` represented with 9 elements, more than its capacity of 8");
+    let samples: Vec<u16> = vec![0; 9];
+    assert_eq!(
+        heapless::Vec::<u16, 8>::try_from_repr(samples.clone().into_repr()),
+        Err(overflow.clone())
+    );
+    assert_eq!(
+        decode_binary::<Reading>(&encode_binary(samples.clone())),
+        Err(DecodeBinaryError::Repr(overflow.clone()))
+    );
+    assert_eq!(
+        from_dyn::<heapless::Vec<u16, 8>>(to_dyn(samples)),
+        Err(FromDynError::Repr(overflow))
+    );
+}
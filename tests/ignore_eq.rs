@@ -0,0 +1,64 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use generics::ops::{GenericEq, GenericHash, GenericOrd, GenericPartialEq, GenericPartialOrd};
+use generics::Generic;
+
+struct Connection;
+
+#[derive(
+    Generic, GenericPartialEq, GenericEq, GenericHash, GenericPartialOrd, GenericOrd, Debug,
+)]
+struct Record {
+    name: String,
+    #[generic(ignore_eq)]
+    id: u64,
+    #[generic(opaque, ignore_eq)]
+    connection: Connection,
+}
+
+impl std::fmt::Debug for Connection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Connection")
+    }
+}
+
+#[derive(Generic, GenericPartialEq, Debug)]
+enum Event {
+    Created {
+        #[generic(ignore_eq)]
+        at: u64,
+    },
+    Deleted(#[generic(ignore_eq)] u64, String),
+}
+
+fn hash<T: Hash>(x: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn ignore_eq() {
+    let record = |name: &str, id| Record {
+        name: name.to_string(),
+        id,
+        connection: Connection,
+    };
+
+    assert_eq!(record("a", 1), record("a", 2));
+    assert_ne!(record("a", 1), record("b", 1));
+    assert_eq!(hash(&record("a", 1)), hash(&record("a", 2)));
+    assert_eq!(
+        record("a", 1).cmp(&record("a", 0)),
+        std::cmp::Ordering::Equal
+    );
+    assert!(record("a", 9) < record("b", 0));
+
+    assert_eq!(Event::Created { at: 1 }, Event::Created { at: 2 });
+    assert_eq!(
+        Event::Deleted(1, "x".to_string()),
+        Event::Deleted(2, "x".to_string())
+    );
+    assert_ne!(Event::Created { at: 1 }, Event::Deleted(1, String::new()));
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "serde_json")]
+
+use generics::ops::{from_value, serialize_generic, to_value, FromValueError};
+use generics::Generic;
+use serde_json::json;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Service {
+    name: String,
+    port: Option<u16>,
+    replicas: Vec<Replica>,
+    limits: Limits,
+    weights: [u8; 2],
+    pair: (bool, char),
+    marker: Marker,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Replica {
+    Local,
+    Remote(String),
+    Pinned(String, u8),
+    Scaled { min: u8, max: Option<u8> },
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Limits(u32, u32);
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Marker;
+
+#[test]
+fn json() {
+    let service = Service {
+        name: "api".to_string(),
+        port: Some(80),
+        replicas: vec![
+            Replica::Local,
+            Replica::Remote("r".to_string()),
+            Replica::Pinned("p".to_string(), 1),
+            Replica::Scaled { min: 1, max: None },
+        ],
+        limits: Limits(1, 2),
+        weights: [3, 4],
+        pair: (true, 'x'),
+        marker: Marker,
+    };
+    let value = json!({
+        "name": "api",
+        "port": 80,
+        "replicas": [
+            "Local",
+            { "Remote": "r" },
+            { "Pinned": ["p", 1] },
+            { "Scaled": { "min": 1, "max": null } },
+        ],
+        "limits": [1, 2],
+        "weights": [3, 4],
+        "pair": [true, "x"],
+        "marker": null,
+    });
+    assert_eq!(to_value(service.clone()).unwrap(), value);
+    assert_eq!(
+        serialize_generic(service.clone(), serde_json::value::Serializer).unwrap(),
+        value
+    );
+    assert_eq!(from_value::<Service>(value.clone()), Ok(service.clone()));
+
+    // Missing options are `None`, unknown fields are ignored, and structs can be arrays.
+    let mut merged = value;
+    merged["port"] = json!(null);
+    merged["extra"] = json!(true);
+    merged["replicas"] = json!([{ "Scaled": [2, null] }]);
+    let merged = from_value::<Service>(merged).unwrap();
+    assert_eq!(merged.port, None);
+    assert_eq!(merged.replicas, [Replica::Scaled { min: 2, max: None }]);
+
+    let err = |value| from_value::<Service>(value).unwrap_err();
+    assert_eq!(
+        err(json!({ "port": 80 })),
+        FromValueError::Missing("name".to_string())
+    );
+    assert_eq!(
+        err(json!({ "name": "api", "replicas": [{ "Remote": 1 }] })).to_string(),
+        "invalid value for `replicas.0.0`: invalid type: integer `1`, expected a string"
+    );
+    assert_eq!(
+        err(json!({ "name": "api", "replicas": [], "limits": [1] })).to_string(),
+        "expected an array of length 2 for `limits`"
+    );
+    assert_eq!(
+        err(json!({ "name": "api", "replicas": ["Gone"] })).to_string(),
+        "unknown variant `Gone` for `replicas.0`"
+    );
+}
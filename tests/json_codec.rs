@@ -0,0 +1,175 @@
+#![cfg(feature = "json")]
+
+use std::num::NonZeroU8;
+use std::time::Duration;
+
+use generics::ops::{decode_json, encode_json, DecodeJsonError, Json};
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Document {
+    title: String,
+    rev: u64,
+    ratio: f32,
+    origin: Option<Size>,
+    blocks: Vec<Block>,
+    size: Size,
+    flags: [bool; 2],
+    anchor: (char, i8),
+    draft: Draft,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Block {
+    Break,
+    Text(String),
+    Link(String, u16),
+    Image { src: String, alt: Option<String> },
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Size(u32, u32);
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Draft;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Fieldless {
+    Braced {},
+    Tuple(),
+}
+
+#[test]
+fn json_codec() {
+    let doc = Document {
+        title: "a \"b\"\n\u{1}é😀".to_string(),
+        rev: u64::MAX,
+        ratio: 2.0,
+        origin: Some(Size(1, 2)),
+        blocks: vec![
+            Block::Break,
+            Block::Text("t".to_string()),
+            Block::Link("l".to_string(), 80),
+            Block::Image {
+                src: "s".to_string(),
+                alt: None,
+            },
+        ],
+        size: Size(3, 4),
+        flags: [true, false],
+        anchor: ('\\', -1),
+        draft: Draft,
+    };
+    let json = encode_json(doc.clone());
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"title":"a \"b\"\n\u0001é😀","rev":18446744073709551615,"ratio":2.0,"#,
+            r#""origin":[1,2],"#,
+            r#""blocks":["Break",{"Text":"t"},{"Link":["l",80]},{"Image":{"src":"s","alt":null}}],"#,
+            r#""size":[3,4],"flags":[true,false],"anchor":["\\",-1],"draft":null}"#,
+        )
+    );
+    assert_eq!(decode_json::<Document>(&json), Ok(doc));
+
+    // Whitespace, escapes, reordered and unknown keys, missing options, and structs as arrays.
+    let block =
+        decode_json::<Block>(r#" { "Image" : { "extra" : [1, {"x": null}], "src" : "é😀\/" } } "#);
+    assert_eq!(
+        block,
+        Ok(Block::Image {
+            src: "é😀/".to_string(),
+            alt: None
+        })
+    );
+    assert_eq!(
+        decode_json::<Block>(r#"{"Image":["s","a"]}"#),
+        Ok(Block::Image {
+            src: "s".to_string(),
+            alt: Some("a".to_string())
+        })
+    );
+    assert_eq!(
+        "[1.5e-3, true]".parse::<Json>(),
+        Ok(Json::Array(vec![
+            Json::Number("1.5e-3".to_string()),
+            Json::Bool(true)
+        ]))
+    );
+
+    assert_eq!(
+        decode_json::<Block>(r#"{"Text": "t"} x"#),
+        Err(DecodeJsonError::Syntax(14))
+    );
+    assert_eq!("-0".parse::<Json>(), Ok(Json::Number("-0".to_string())));
+    assert_eq!("01".parse::<Json>(), Err(DecodeJsonError::Syntax(1)));
+    assert_eq!("-00.5".parse::<Json>(), Err(DecodeJsonError::Syntax(2)));
+    assert_eq!("\"a\tb\"".parse::<Json>(), Err(DecodeJsonError::Syntax(2)));
+    assert_eq!(
+        "\"a\\tb\"".parse::<Json>(),
+        Ok(Json::String("a\tb".to_string()))
+    );
+
+    // Nesting is limited, rather than overflowing the stack.
+    assert!(format!("{}{}", "[".repeat(128), "]".repeat(128))
+        .parse::<Json>()
+        .is_ok());
+    assert_eq!(
+        decode_json::<Vec<u8>>(&"[".repeat(1_000_000)),
+        Err(DecodeJsonError::Depth(128))
+    );
+    assert_eq!(
+        decode_json::<u8>(&r#"{"a":"#.repeat(200))
+            .unwrap_err()
+            .to_string(),
+        "JSON nested too deeply at byte 640"
+    );
+    assert_eq!(
+        decode_json::<Block>(r#"["Text"]"#).unwrap_err().to_string(),
+        "expected a string or an object with one key for ``"
+    );
+    assert_eq!(
+        decode_json::<Vec<Block>>(r#"["Break", {"Link": ["l", 70000]}]"#)
+            .unwrap_err()
+            .to_string(),
+        "expected a u16 for `1.1`"
+    );
+    assert_eq!(
+        decode_json::<Block>(r#"{"Image": {"alt": "a"}}"#),
+        Err(DecodeJsonError::Missing("src".to_string()))
+    );
+    assert_eq!(
+        decode_json::<Size>("[1]").unwrap_err().to_string(),
+        "expected an array of length 2 for ``"
+    );
+    assert_eq!(
+        decode_json::<Block>(r#""Quote""#).unwrap_err().to_string(),
+        "unknown variant `Quote` for ``"
+    );
+    assert_eq!(
+        decode_json::<Vec<Option<NonZeroU8>>>("[1, null, 0]")
+            .unwrap_err()
+            .to_string(),
+        "invalid value: `NonZeroU8` represented as zero"
+    );
+    assert_eq!(
+        decode_json::<Duration>(r#"{"secs":18446744073709551615,"nanos":4000000000}"#)
+            .unwrap_err()
+            .to_string(),
+        "invalid value: `Duration` represented as more than `Duration::MAX`"
+    );
+
+    // Constructors without fields keep how they were declared, as in serde's derive.
+    assert_eq!(encode_json(Fieldless::Braced {}), r#"{"Braced":{}}"#);
+    assert_eq!(encode_json(Fieldless::Tuple()), r#"{"Tuple":[]}"#);
+    assert_eq!(
+        decode_json::<Fieldless>(r#"{"Braced":{}}"#),
+        Ok(Fieldless::Braced {})
+    );
+    assert_eq!(
+        decode_json::<Fieldless>(r#"{"Tuple":null}"#)
+            .unwrap_err()
+            .to_string(),
+        "expected an array of length 0 for ``"
+    );
+}
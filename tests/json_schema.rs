@@ -0,0 +1,123 @@
+#![cfg(feature = "json")]
+
+use generics::ops::{json_schema, Json};
+use generics::Generic;
+
+/// A service to run.
+///
+/// Loaded from `services.json`.
+#[derive(Generic)]
+struct Service {
+    /// The name shown in logs.
+    name: String,
+    ports: Vec<Port>,
+    restart: Restart,
+    /// Overrides the default timeout.
+    timeout: Option<f64>,
+    checksum: [u8; 2],
+    limits: (u32, bool),
+    owner: Owner,
+    marker: Marker,
+}
+
+#[derive(Generic)]
+struct Port(u16);
+
+#[derive(Generic)]
+struct Owner(char, i8);
+
+#[derive(Generic)]
+struct Marker;
+
+#[derive(Generic)]
+enum Restart {
+    /// Never restart.
+    Never,
+    Backoff {
+        max: u8,
+    },
+    Every(u8, u8),
+}
+
+#[derive(Generic)]
+enum Never {}
+
+fn parse(s: &str) -> Json {
+    s.parse().unwrap()
+}
+
+#[test]
+fn json_schema_of() {
+    let int = |min: i64, max: i64| {
+        format!(
+            r#"{{"type":"integer","minimum":{},"maximum":{}}}"#,
+            min, max
+        )
+    };
+    let u8 = int(0, 255);
+    let expected = format!(
+        r#"{{
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {{
+                "name": {{ "type": "string", "description": "The name shown in logs." }},
+                "ports": {{ "type": "array", "items": {port} }},
+                "restart": {{ "oneOf": [
+                    {{ "const": "Never", "description": "Never restart." }},
+                    {{
+                        "type": "object",
+                        "properties": {{ "Backoff": {{
+                            "type": "object",
+                            "properties": {{ "max": {u8} }},
+                            "required": ["max"]
+                        }} }},
+                        "required": ["Backoff"],
+                        "additionalProperties": false
+                    }},
+                    {{
+                        "type": "object",
+                        "properties": {{ "Every": {{
+                            "type": "array",
+                            "prefixItems": [{u8}, {u8}],
+                            "minItems": 2,
+                            "maxItems": 2
+                        }} }},
+                        "required": ["Every"],
+                        "additionalProperties": false
+                    }}
+                ] }},
+                "timeout": {{
+                    "anyOf": [{{ "type": "null" }}, {{ "type": ["number", "null"] }}],
+                    "description": "Overrides the default timeout."
+                }},
+                "checksum": {{ "type": "array", "items": {u8}, "minItems": 2, "maxItems": 2 }},
+                "limits": {{
+                    "type": "array",
+                    "prefixItems": [{u32}, {{ "type": "boolean" }}],
+                    "minItems": 2,
+                    "maxItems": 2
+                }},
+                "owner": {{
+                    "type": "array",
+                    "prefixItems": [{{ "type": "string", "minLength": 1, "maxLength": 1 }}, {i8}],
+                    "minItems": 2,
+                    "maxItems": 2
+                }},
+                "marker": {{ "type": "null" }}
+            }},
+            "required": ["name", "ports", "restart", "checksum", "limits", "owner"],
+            "description": "A service to run.\n\nLoaded from `services.json`."
+        }}"#,
+        port = int(0, 65535),
+        u8 = u8,
+        u32 = int(0, u32::MAX.into()),
+        i8 = int(-128, 127),
+    );
+    assert_eq!(json_schema::<Service>(), parse(&expected));
+
+    assert_eq!(json_schema::<Never>(), Json::Bool(false));
+    assert_eq!(
+        json_schema::<Option<String>>().to_string(),
+        r#"{"$schema":"https://json-schema.org/draft/2020-12/schema","anyOf":[{"type":"null"},{"type":"string"}]}"#
+    );
+}
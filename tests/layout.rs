@@ -0,0 +1,107 @@
+use generics::ops::{FieldLayout, GenericLayout};
+use generics::Generic;
+
+#[derive(Generic, GenericLayout)]
+#[repr(C)]
+struct Foo {
+    a: u8,
+    b: u32,
+    c: u16,
+    d: u8,
+}
+
+#[derive(Generic, GenericLayout)]
+struct Wrapper<T>(T, [u16; 3]);
+
+#[derive(Generic, GenericLayout)]
+struct Reordered {
+    a: u8,
+    b: u32,
+    c: u8,
+}
+
+#[derive(Generic, GenericLayout)]
+struct Empty;
+
+#[test]
+fn layout() {
+    let layout = Foo::layout();
+    assert_eq!(layout.name, "Foo");
+    assert_eq!((layout.size, layout.align), (12, 4));
+    assert_eq!(
+        layout.fields,
+        [
+            FieldLayout {
+                name: "a",
+                offset: 0,
+                size: 1,
+                align: 1,
+                padding: 0
+            },
+            FieldLayout {
+                name: "b",
+                offset: 4,
+                size: 4,
+                align: 4,
+                padding: 3
+            },
+            FieldLayout {
+                name: "c",
+                offset: 8,
+                size: 2,
+                align: 2,
+                padding: 0
+            },
+            FieldLayout {
+                name: "d",
+                offset: 10,
+                size: 1,
+                align: 1,
+                padding: 0
+            },
+        ]
+    );
+    assert_eq!(layout.padding(), 4);
+    assert_eq!(
+        layout.to_string(),
+        "Foo: size 12, align 4, padding 4\n  \
+         a: offset 0, size 1, align 1, padding 0\n  \
+         b: offset 4, size 4, align 4, padding 3\n  \
+         c: offset 8, size 2, align 2, padding 0\n  \
+         d: offset 10, size 1, align 1, padding 0",
+    );
+
+    let layout = Wrapper::<u64>::layout();
+    assert_eq!(
+        layout
+            .fields
+            .iter()
+            .map(|f| (f.name, f.size))
+            .collect::<Vec<_>>(),
+        [("0", 8), ("1", 6)]
+    );
+    assert_eq!(layout.padding(), 2);
+
+    // Without `#[repr(C)]`, the compiler may reorder fields, which the offsets and padding account for.
+    let layout = Reordered::layout();
+    assert_eq!(
+        layout
+            .fields
+            .iter()
+            .map(|f| (f.name, f.offset))
+            .collect::<Vec<_>>(),
+        [
+            ("a", std::mem::offset_of!(Reordered, a)),
+            ("b", std::mem::offset_of!(Reordered, b)),
+            ("c", std::mem::offset_of!(Reordered, c)),
+        ]
+    );
+    let field_padding = layout.fields.iter().map(|f| f.padding).sum::<usize>();
+    assert!(field_padding <= layout.padding());
+
+    let layout = Empty::layout();
+    assert_eq!(
+        (layout.size, layout.fields.len(), layout.padding()),
+        (0, 0, 0)
+    );
+}
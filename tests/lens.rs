@@ -0,0 +1,37 @@
+use generics::{Generic, GenericLens, Lens};
+
+#[derive(Debug, PartialEq, Generic, GenericLens)]
+struct Point<T>(T, T);
+
+#[derive(Debug, PartialEq, Generic, GenericLens)]
+struct Line {
+    from: Point<i32>,
+    to: Point<i32>,
+    label: String,
+}
+
+#[test]
+fn lens() {
+    let from_x = generics::lens::<Line_from>().then(generics::lens::<Point_0>());
+    let to_y = generics::lens::<Line_to>().then(generics::lens::<Point_1>());
+
+    let mut line = Line {
+        from: Point(1, 2),
+        to: Point(3, 4),
+        label: "a".to_string(),
+    };
+    assert_eq!(*from_x.get(&line), 1);
+    assert_eq!(*to_y.get(&line), 4);
+
+    *to_y.get_mut(&mut line) += 1;
+    let line = from_x.set(line, 10);
+    let line = generics::lens::<Line_label>().modify(line, |label| label.push('b'));
+    assert_eq!(
+        line,
+        Line {
+            from: Point(10, 2),
+            to: Point(3, 5),
+            label: "ab".to_string(),
+        }
+    );
+}
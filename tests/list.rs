@@ -0,0 +1,69 @@
+use std::collections::{LinkedList, VecDeque};
+
+use generics::dynamic::{from_dyn, to_dyn, DynPrim, DynRepr};
+use generics::{define_generic_op, visit, Generic, GenericVisitor, Leaf, List};
+
+define_generic_op! {
+    trait Accumulate {
+        fn acc(self) -> u64;
+    }
+
+    fn accumulate;
+
+    unit => 0,
+    prod(a, b) => a + b,
+    sum(x) => x,
+    meta(x, M) => x,
+    con(x, C) => x,
+    list(xs) => xs.iter().sum(),
+    leaf(x: u8) => u64::from(x),
+}
+
+#[derive(Default)]
+struct Count(usize);
+
+impl GenericVisitor for Count {
+    fn visit_leaf<T>(&mut self, _leaf: &T)
+    where
+        T: Leaf,
+    {
+        self.0 += 1;
+    }
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Inner(u8);
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo {
+    a: Vec<u8>,
+    b: VecDeque<Inner>,
+    c: LinkedList<u8>,
+    d: Box<[Vec<u8>]>,
+}
+
+#[test]
+fn list() {
+    let foo = Foo {
+        a: vec![1, 2],
+        b: vec![Inner(3)].into(),
+        c: vec![4].into_iter().collect(),
+        d: vec![vec![5], vec![]].into(),
+    };
+
+    assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+    assert_eq!(accumulate(foo.clone()), 15);
+
+    let mut count = Count::default();
+    visit(foo.clone(), &mut count);
+    assert_eq!(count.0, 5);
+
+    assert_eq!(from_dyn::<Foo>(to_dyn(foo.clone())), Ok(foo));
+    assert_eq!(
+        to_dyn(vec![1u8]),
+        DynRepr::List(vec![DynRepr::Prim(DynPrim::U8(1))])
+    );
+
+    let List(items) = VecDeque::from(vec![Inner(1), Inner(2)]).into_repr();
+    assert_eq!(items.len(), 2);
+}
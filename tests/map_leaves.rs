@@ -0,0 +1,43 @@
+use std::marker::PhantomData;
+
+use generics::{Con, Generic, MapLeaves, Meta, Prod, TypeFn, Unit};
+
+struct ToVec;
+
+impl<T> TypeFn<T> for ToVec {
+    type Output = Vec<T>;
+}
+
+#[derive(Generic)]
+struct Inner {
+    a: u8,
+}
+
+#[derive(Generic)]
+struct Foo {
+    a: u16,
+    b: Inner,
+}
+
+type Columns = <<Foo as Generic>::Repr as MapLeaves<ToVec>>::Output;
+
+#[test]
+fn map_leaves() {
+    let columns: Columns = Con(
+        Prod(
+            Meta(vec![1u16, 2], PhantomData),
+            Prod(
+                Meta(
+                    Con(Prod(Meta(vec![3u8, 4], PhantomData), Unit), PhantomData),
+                    PhantomData,
+                ),
+                Unit,
+            ),
+        ),
+        PhantomData,
+    );
+
+    let Con(Prod(Meta(a, _), Prod(Meta(Con(Prod(Meta(b_a, _), Unit), _), _), Unit)), _) = columns;
+    assert_eq!(a, vec![1, 2]);
+    assert_eq!(b_a, vec![3, 4]);
+}
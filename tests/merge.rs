@@ -0,0 +1,104 @@
+use generics::ops::{self, GenericMerge, Merge, MergeStrategy};
+use generics::Generic;
+
+struct Max;
+
+impl MergeStrategy<u32> for Max {
+    fn merge(old: u32, newer: u32) -> u32 {
+        old.max(newer)
+    }
+}
+
+#[derive(Debug, PartialEq, Generic, GenericMerge)]
+struct Limits {
+    #[generic(merge = "Max")]
+    connections: u32,
+    #[generic(merge = "non_default")]
+    timeout: u64,
+}
+
+#[derive(Debug, PartialEq, Generic, GenericMerge)]
+enum Backend {
+    Memory,
+    Disk {
+        #[generic(merge = "concat")]
+        paths: Vec<String>,
+        #[generic(merge = "deep")]
+        limits: Option<Limits>,
+    },
+}
+
+#[derive(Debug, PartialEq, Generic, GenericMerge)]
+struct Config {
+    name: String,
+    #[generic(merge = "deep")]
+    backend: Backend,
+}
+
+fn disk(paths: &[&str], limits: Option<Limits>) -> Backend {
+    Backend::Disk {
+        paths: paths.iter().map(|p| p.to_string()).collect(),
+        limits,
+    }
+}
+
+#[test]
+fn merge() {
+    let base = Config {
+        name: "base".to_string(),
+        backend: disk(
+            &["a"],
+            Some(Limits {
+                connections: 10,
+                timeout: 30,
+            }),
+        ),
+    };
+    let layer = Config {
+        name: "layer".to_string(),
+        backend: disk(
+            &["b"],
+            Some(Limits {
+                connections: 5,
+                timeout: 0,
+            }),
+        ),
+    };
+    assert_eq!(
+        base.merge(layer),
+        Config {
+            name: "layer".to_string(),
+            backend: disk(
+                &["a", "b"],
+                Some(Limits {
+                    connections: 10,
+                    timeout: 30,
+                })
+            ),
+        }
+    );
+
+    // Different variants take the newer variant, and `None` never replaces a value.
+    assert_eq!(
+        Backend::Memory.merge(disk(&["a"], None)),
+        disk(&["a"], None)
+    );
+    let limits = || {
+        Some(Limits {
+            connections: 1,
+            timeout: 2,
+        })
+    };
+    assert_eq!(
+        disk(&[], limits()).merge(disk(&[], None)),
+        disk(&[], limits())
+    );
+
+    // Without a derive, leaves and lists take the newer value, and matching variants are merged.
+    assert_eq!(ops::gmerge((1, Some(2)), (3, Some(4))), (3, Some(4)));
+    assert_eq!(ops::gmerge(vec![1, 2], vec![3]), vec![3]);
+    assert_eq!(
+        ops::gmerge([Ok(1), Ok(2)], [Ok(3), Err(4)]),
+        [Ok(3), Err::<u8, u8>(4)]
+    );
+}
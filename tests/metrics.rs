@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use generics::ops::{emit_metrics, MetricSink};
+use generics::Generic;
+
+#[derive(Generic)]
+struct CacheStats {
+    hits: u64,
+    misses: u64,
+    ratio: f64,
+    name: String,
+    shards: Vec<Shard>,
+    state: State,
+    latency: (f32, f32),
+}
+
+#[derive(Generic)]
+struct Shard {
+    entries: usize,
+    evictions: i32,
+}
+
+#[derive(Generic)]
+enum State {
+    Warm { since_secs: u32 },
+    Cold,
+}
+
+fn stats(state: State) -> CacheStats {
+    CacheStats {
+        hits: 90,
+        misses: 10,
+        ratio: 0.9,
+        name: "main".to_string(),
+        shards: vec![
+            Shard {
+                entries: 5,
+                evictions: -1,
+            },
+            Shard {
+                entries: 7,
+                evictions: 2,
+            },
+        ],
+        state,
+        latency: (1.5, 4.0),
+    }
+}
+
+struct Counting<'a>(&'a mut usize);
+
+impl MetricSink for Counting<'_> {
+    fn record(&mut self, _name: &str, _value: f64) {
+        *self.0 += 1;
+    }
+}
+
+#[test]
+fn metrics() {
+    let mut metrics = Vec::new();
+    emit_metrics("cache", stats(State::Warm { since_secs: 60 }), &mut metrics);
+    assert_eq!(
+        metrics,
+        [
+            ("cache.hits".to_string(), 90.0),
+            ("cache.misses".to_string(), 10.0),
+            ("cache.ratio".to_string(), 0.9),
+            ("cache.shards.0.entries".to_string(), 5.0),
+            ("cache.shards.0.evictions".to_string(), -1.0),
+            ("cache.shards.1.entries".to_string(), 7.0),
+            ("cache.shards.1.evictions".to_string(), 2.0),
+            ("cache.state.since_secs".to_string(), 60.0),
+            ("cache.latency.0".to_string(), 1.5),
+            ("cache.latency.1".to_string(), 4.0),
+        ]
+    );
+
+    let mut metrics = HashMap::new();
+    emit_metrics("", stats(State::Cold), &mut metrics);
+    assert_eq!(metrics.len(), 9);
+    assert_eq!(metrics["latency.1"], 4.0);
+    assert_eq!(metrics["shards.1.entries"], 7.0);
+    assert!(!metrics.contains_key("state.since_secs"));
+
+    let mut count = 0;
+    let sink: &mut dyn MetricSink = &mut Counting(&mut count);
+    emit_metrics("x", (1u8, "a", 2.5f32), sink);
+    assert_eq!(count, 2);
+
+    let mut metrics = Vec::new();
+    emit_metrics("x", (1u8, "a", 2.5f32), &mut metrics);
+    assert_eq!(
+        metrics,
+        [("x.0".to_string(), 1.0), ("x.2".to_string(), 2.5)]
+    );
+}
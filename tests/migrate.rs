@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+use std::num::{NonZeroU8, Wrapping};
+
+use generics::ops::{migrate, GenericDefault, MigrateError};
+use generics::Generic;
+
+#[derive(Generic, Debug, PartialEq)]
+struct UserV1 {
+    id: u32,
+    name: String,
+    legacy_flags: u8,
+}
+
+#[derive(Generic, GenericDefault, Debug, PartialEq)]
+struct UserV2 {
+    id: u32,
+    name: String,
+    #[generic(default = "\"en\".to_string()")]
+    locale: String,
+    tags: Vec<String>,
+}
+
+#[derive(Generic, GenericDefault, Debug, PartialEq)]
+struct UserV3(u32);
+
+#[derive(Generic, Debug, PartialEq)]
+struct UserId {
+    id: u64,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct UserTags {
+    tags: Option<String>,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct UserQueue {
+    tags: VecDeque<String>,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct Counter {
+    id: Wrapping<u32>,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct Limit<T> {
+    max: T,
+}
+
+impl Default for Limit<NonZeroU8> {
+    fn default() -> Self {
+        Limit {
+            max: NonZeroU8::MAX,
+        }
+    }
+}
+
+#[test]
+fn migrate_versions() {
+    let v1 = UserV1 {
+        id: 7,
+        name: "ann".to_string(),
+        legacy_flags: 3,
+    };
+    let v2: UserV2 = migrate(v1).unwrap();
+    assert_eq!(
+        v2,
+        UserV2 {
+            id: 7,
+            name: "ann".to_string(),
+            locale: "en".to_string(),
+            tags: vec![],
+        }
+    );
+
+    // Fields are matched by name, so the tuple field `0` has no counterpart, and keeps its default.
+    assert_eq!(migrate::<_, UserV3>(v2), Ok(UserV3(0)));
+
+    let err = migrate::<_, UserV2>(UserId { id: 7 }).unwrap_err();
+    assert_eq!(
+        err,
+        MigrateError::Type {
+            field: "id",
+            expected: "u32",
+            found: "u64"
+        }
+    );
+    assert_eq!(err.to_string(), "field `id` has type `u64`, expected `u32`");
+
+    // The expected type is reported as declared, rather than as its representation.
+    assert_eq!(
+        migrate::<_, UserV2>(UserTags { tags: None }),
+        Err(MigrateError::Type {
+            field: "tags",
+            expected: "Vec<String>",
+            found: "Option<String>"
+        })
+    );
+
+    // Types with the same representation, but declared differently, don't migrate.
+    let queue = UserQueue {
+        tags: VecDeque::from(vec!["a".to_string()]),
+    };
+    assert_eq!(
+        migrate::<_, UserV2>(queue),
+        Err(MigrateError::Type {
+            field: "tags",
+            expected: "Vec<String>",
+            found: "VecDeque<String>"
+        })
+    );
+    assert_eq!(
+        migrate::<_, UserV2>(Counter { id: Wrapping(7) }),
+        Err(MigrateError::Type {
+            field: "id",
+            expected: "u32",
+            found: "Wrapping<u32>"
+        })
+    );
+
+    // Migrated values the target rejects are an error, rather than a panic.
+    assert!(matches!(
+        migrate::<_, Limit<NonZeroU8>>(Limit { max: 0u8 }),
+        Err(MigrateError::Repr(_))
+    ));
+    assert_eq!(
+        migrate::<_, Limit<NonZeroU8>>(Limit { max: 5u8 }),
+        Ok(Limit {
+            max: NonZeroU8::new(5).unwrap()
+        })
+    );
+}
@@ -0,0 +1,124 @@
+use std::collections::BTreeSet;
+use std::num::NonZeroU32;
+
+use generics::ops::{gappend, gempty, Monoid, Semigroup};
+use generics::{Generic, Leaf};
+
+/// A user-defined leaf, combined by taking the maximum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Max(u8);
+
+impl Generic for Max {
+    type Repr = Self;
+    fn into_repr(self) -> Self::Repr {
+        self
+    }
+    fn from_repr(repr: Self::Repr) -> Self {
+        repr
+    }
+}
+
+impl Leaf for Max {}
+
+impl Semigroup for Max {
+    fn append(self, other: Self) -> Self {
+        Max(self.0.max(other.0))
+    }
+}
+
+impl Monoid for Max {
+    fn empty() -> Self {
+        Max(0)
+    }
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct Metrics {
+    requests: u64,
+    latency: f64,
+    max: Max,
+    log: String,
+    last: Option<u32>,
+    samples: Vec<u8>,
+    tags: BTreeSet<&'static str>,
+    per_shard: [u32; 2],
+    pair: (i8, String),
+    #[generic(opaque)]
+    opaque: Vec<u8>,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+enum Status {
+    Idle,
+    Busy(u32),
+}
+
+#[test]
+fn monoid() {
+    let a = Metrics {
+        requests: 1,
+        latency: 0.5,
+        max: Max(3),
+        log: "a".to_string(),
+        last: Some(5),
+        samples: vec![1],
+        tags: vec!["a"].into_iter().collect(),
+        per_shard: [1, 2],
+        pair: (-1, "p".to_string()),
+        opaque: vec![9],
+    };
+    let b = Metrics {
+        requests: 2,
+        latency: 0.25,
+        max: Max(2),
+        log: "b".to_string(),
+        last: None,
+        samples: vec![2, 3],
+        tags: vec!["a", "b"].into_iter().collect(),
+        per_shard: [10, 20],
+        pair: (3, "q".to_string()),
+        opaque: vec![],
+    };
+
+    let empty: Metrics = gempty().unwrap();
+    assert_eq!(
+        empty,
+        Metrics {
+            requests: 0,
+            latency: 0.0,
+            max: Max(0),
+            log: String::new(),
+            last: None,
+            samples: vec![],
+            tags: BTreeSet::new(),
+            per_shard: [0, 0],
+            pair: (0, String::new()),
+            opaque: vec![],
+        }
+    );
+
+    let combined = gappend(gappend(empty, a), b);
+    assert_eq!(
+        combined,
+        Metrics {
+            requests: 3,
+            latency: 0.75,
+            max: Max(3),
+            log: "ab".to_string(),
+            last: Some(5),
+            samples: vec![1, 2, 3],
+            tags: vec!["a", "b"].into_iter().collect(),
+            per_shard: [11, 22],
+            pair: (2, "pq".to_string()),
+            opaque: vec![9],
+        }
+    );
+
+    assert_eq!(gappend(Some(1u8), Some(2)), Some(3));
+    assert_eq!(gappend(None, Some(2u8)), Some(2));
+    assert_eq!(gappend(Some(1u8), None), Some(1));
+    assert_eq!(gappend(Status::Busy(1), Status::Idle), Status::Busy(1));
+    assert_eq!(gappend(Status::Busy(1), Status::Busy(2)), Status::Busy(3));
+    assert_eq!(gempty::<Status>(), Ok(Status::Idle));
+    assert!(gempty::<NonZeroU32>().is_err());
+}
@@ -0,0 +1,216 @@
+#![cfg(feature = "msgpack")]
+
+use generics::ops::{decode_msgpack, encode_msgpack, DecodeMsgPackError, MsgPack};
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Reading {
+    device: String,
+    seq: u32,
+    offset: i64,
+    big: u128,
+    small: i128,
+    value: f64,
+    ratio: f32,
+    ok: bool,
+    mark: char,
+    events: Vec<Event>,
+    pos: Pos,
+    id: Id,
+    marker: Marker,
+    window: [u8; 2],
+    pair: (u16, Option<u16>),
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Event {
+    Boot,
+    Code(u8),
+    Move(i8, i8),
+    Alarm { level: u8, note: Option<String> },
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Pos(i16, i16);
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Id(u64);
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Marker;
+
+fn string(s: &str) -> MsgPack {
+    MsgPack::Str(s.to_string())
+}
+
+fn encode(msgpack: MsgPack) -> Vec<u8> {
+    let mut out = Vec::new();
+    msgpack.encode(&mut out);
+    out
+}
+
+#[test]
+fn msgpack() {
+    let reading = Reading {
+        device: "d".to_string(),
+        seq: 500,
+        offset: -500,
+        big: u128::MAX,
+        small: i128::MIN,
+        value: 0.1,
+        ratio: 0.5,
+        ok: true,
+        mark: 'é',
+        events: vec![
+            Event::Boot,
+            Event::Code(24),
+            Event::Move(-1, 1),
+            Event::Alarm {
+                level: 2,
+                note: None,
+            },
+        ],
+        pos: Pos(3, -3),
+        id: Id(u64::MAX),
+        marker: Marker,
+        window: [0, 255],
+        pair: (1, Some(2)),
+    };
+    let msgpack = MsgPack::Map(vec![
+        (string("device"), string("d")),
+        (string("seq"), MsgPack::UInt(500)),
+        (string("offset"), MsgPack::Int(-500)),
+        (string("big"), MsgPack::Bin(vec![0xff; 16])),
+        (
+            string("small"),
+            MsgPack::Bin([&[0x80][..], &[0; 15]].concat()),
+        ),
+        (string("value"), MsgPack::Float64(0.1)),
+        (string("ratio"), MsgPack::Float32(0.5)),
+        (string("ok"), MsgPack::Bool(true)),
+        (string("mark"), string("é")),
+        (
+            string("events"),
+            MsgPack::Array(vec![
+                string("Boot"),
+                MsgPack::Map(vec![(string("Code"), MsgPack::UInt(24))]),
+                MsgPack::Map(vec![(
+                    string("Move"),
+                    MsgPack::Array(vec![MsgPack::Int(-1), MsgPack::UInt(1)]),
+                )]),
+                MsgPack::Map(vec![(
+                    string("Alarm"),
+                    MsgPack::Map(vec![
+                        (string("level"), MsgPack::UInt(2)),
+                        (string("note"), MsgPack::Nil),
+                    ]),
+                )]),
+            ]),
+        ),
+        (
+            string("pos"),
+            MsgPack::Array(vec![MsgPack::UInt(3), MsgPack::Int(-3)]),
+        ),
+        (string("id"), MsgPack::UInt(u64::MAX)),
+        (string("marker"), MsgPack::Nil),
+        (
+            string("window"),
+            MsgPack::Array(vec![MsgPack::UInt(0), MsgPack::UInt(255)]),
+        ),
+        (
+            string("pair"),
+            MsgPack::Array(vec![MsgPack::UInt(1), MsgPack::UInt(2)]),
+        ),
+    ]);
+    let bytes = encode_msgpack(reading.clone());
+    assert_eq!(bytes, encode(msgpack.clone()));
+    assert_eq!(MsgPack::decode(&bytes), Ok(msgpack));
+    assert_eq!(decode_msgpack::<Reading>(&bytes), Ok(reading));
+
+    // Integers and lengths use their shortest formats.
+    assert_eq!(encode_msgpack(127u16), [0x7f]);
+    assert_eq!(encode_msgpack(128u16), [0xcc, 0x80]);
+    assert_eq!(encode_msgpack(-32i32), [0xe0]);
+    assert_eq!(encode_msgpack(-33i32), [0xd0, 0xdf]);
+    assert_eq!(encode_msgpack(65536u32), [0xce, 0, 1, 0, 0]);
+    assert_eq!(encode_msgpack("a".repeat(32))[..2], [0xd9, 32]);
+    assert_eq!(encode_msgpack(vec![0u8; 16])[..3], [0xdc, 0, 16]);
+    assert_eq!(encode_msgpack(1.5f32), [0xca, 0x3f, 0xc0, 0, 0]);
+    assert_eq!(decode_msgpack::<u8>(&[0xd3, 0, 0, 0, 0, 0, 0, 0, 7]), Ok(7));
+    assert_eq!(decode_msgpack::<f64>(&[0xca, 0x3f, 0xc0, 0, 0]), Ok(1.5));
+    assert_eq!(
+        MsgPack::decode(&[0xd4, 0xff, 0x2a]),
+        Ok(MsgPack::Ext(-1, vec![0x2a]))
+    );
+
+    // Unknown keys, including non-string keys, are ignored, and missing options are `None`.
+    let bytes = encode(MsgPack::Map(vec![(
+        string("Alarm"),
+        MsgPack::Map(vec![
+            (MsgPack::UInt(1), MsgPack::Bin(vec![1])),
+            (string("level"), MsgPack::UInt(9)),
+        ]),
+    )]));
+    assert_eq!(
+        decode_msgpack::<Event>(&bytes),
+        Ok(Event::Alarm {
+            level: 9,
+            note: None
+        })
+    );
+
+    assert_eq!(
+        decode_msgpack::<Event>(&encode(string("Reboot"))),
+        Err(DecodeMsgPackError::Variant {
+            path: String::new(),
+            name: "Reboot".to_string()
+        })
+    );
+    assert_eq!(
+        decode_msgpack::<Vec<Event>>(&encode(MsgPack::Array(vec![MsgPack::Map(vec![(
+            string("Code"),
+            MsgPack::UInt(256)
+        )])])))
+        .unwrap_err()
+        .to_string(),
+        "expected a u8 for `0.0`"
+    );
+    assert_eq!(
+        decode_msgpack::<Pos>(&encode(MsgPack::Array(vec![MsgPack::UInt(1)]))),
+        Err(DecodeMsgPackError::Invalid {
+            path: String::new(),
+            expected: "an array of length 2".to_string()
+        })
+    );
+    assert_eq!(
+        decode_msgpack::<u8>(&[0x01, 0x02]),
+        Err(DecodeMsgPackError::Syntax(1))
+    );
+    assert_eq!(
+        decode_msgpack::<Vec<u8>>(&[0xdd, 0xff, 0xff, 0xff, 0xff]),
+        Err(DecodeMsgPackError::Syntax(0))
+    );
+    assert_eq!(
+        decode_msgpack::<String>(&[0xa1, 0xff])
+            .unwrap_err()
+            .to_string(),
+        "invalid MessagePack at byte 0"
+    );
+    assert_eq!(
+        decode_msgpack::<u8>(&[0xc1]),
+        Err(DecodeMsgPackError::Syntax(0))
+    );
+
+    // Nesting is limited, rather than overflowing the stack.
+    let mut nested = vec![0x91; 128];
+    nested.push(0x00);
+    assert!(MsgPack::decode(&nested).is_ok());
+    assert_eq!(
+        decode_msgpack::<Vec<u8>>(&[0x91; 1_000_000]),
+        Err(DecodeMsgPackError::Depth(128))
+    );
+    assert_eq!(
+        decode_msgpack::<u8>(&[0x81; 200]).unwrap_err().to_string(),
+        "MessagePack nested too deeply at byte 128"
+    );
+}
@@ -0,0 +1,36 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use generics::{visit, Generic, GenericVisitor};
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Config {
+    bind: SocketAddr,
+    allow: Vec<IpAddr>,
+    gateway: Ipv4Addr,
+}
+
+#[derive(Default)]
+struct Constructors(Vec<&'static str>);
+
+impl GenericVisitor for Constructors {
+    fn enter_constructor(&mut self, name: &'static str) {
+        self.0.push(name);
+    }
+}
+
+#[test]
+fn net() {
+    let config = Config {
+        bind: "127.0.0.1:8080".parse().unwrap(),
+        allow: vec![
+            IpAddr::V4(Ipv4Addr::LOCALHOST),
+            IpAddr::V6(Ipv6Addr::LOCALHOST),
+        ],
+        gateway: Ipv4Addr::new(10, 0, 0, 1),
+    };
+    assert_eq!(Config::from_repr(config.clone().into_repr()), config);
+
+    let mut constructors = Constructors::default();
+    visit(config, &mut constructors);
+    assert_eq!(constructors.0, ["Config", "V4", "V4", "V6"]);
+}
@@ -0,0 +1,59 @@
+use std::num::{NonZeroU8, NonZeroUsize, Saturating, Wrapping};
+
+use generics::dynamic::{from_dyn, to_dyn, FromDynError};
+use generics::ops::{decode_binary, encode_binary, DecodeBinaryError};
+use generics::{Generic, List, ReprError};
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo {
+    a: NonZeroU8,
+    b: Wrapping<u16>,
+    c: Saturating<i32>,
+    d: Option<NonZeroUsize>,
+}
+
+#[test]
+fn num() {
+    let foo = Foo {
+        a: NonZeroU8::new(1).unwrap(),
+        b: Wrapping(2),
+        c: Saturating(-3),
+        d: NonZeroUsize::new(4),
+    };
+    assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+    assert_eq!(from_dyn::<Foo>(to_dyn(foo.clone())), Ok(foo));
+
+    let x: u8 = NonZeroU8::new(5).unwrap().into_repr();
+    assert_eq!(x, 5);
+}
+
+#[test]
+#[should_panic]
+fn num_zero() {
+    NonZeroU8::from_repr(0);
+}
+
+#[test]
+fn num_checked() {
+    let zero = ReprError::new("`NonZeroU8` represented as zero");
+    assert_eq!(
+        NonZeroU8::checked_from_repr(5),
+        Ok(NonZeroU8::new(5).unwrap())
+    );
+    assert_eq!(NonZeroU8::checked_from_repr(0), Err(zero.clone()));
+    assert_eq!(
+        Vec::<Wrapping<NonZeroU8>>::checked_from_repr(List(vec![1, 0])),
+        Err(zero.clone())
+    );
+    assert_eq!(
+        from_dyn::<Option<NonZeroU8>>(to_dyn(Some(0u8))),
+        Err(FromDynError::Repr(zero.clone()))
+    );
+
+    // Decoders report the error, rather than panicking, even within a derived type.
+    let bytes = encode_binary((0u8, Wrapping(2u16), Saturating(-3i32), None::<usize>));
+    assert_eq!(
+        decode_binary::<Foo>(&bytes),
+        Err(DecodeBinaryError::Repr(zero))
+    );
+}
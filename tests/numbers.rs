@@ -0,0 +1,78 @@
+use generics::ops::{
+    count_numbers, max_number, mean_number, min_number, number_stats, sum_numbers, NumberStats,
+};
+use generics::Generic;
+
+#[derive(Generic, Clone)]
+struct Reading {
+    sensor: String,
+    #[generic(opaque)]
+    calibration: f64,
+    samples: [i16; 3],
+    extra: Option<Extra>,
+    flag: bool,
+}
+
+#[derive(Generic, Clone)]
+enum Extra {
+    Temperature(f32),
+    Humidity { percent: u8, raw: u64 },
+}
+
+#[test]
+fn numbers() {
+    let reading = Reading {
+        sensor: "s1".to_string(),
+        calibration: 1000.0,
+        samples: [-4, 0, 10],
+        extra: Some(Extra::Humidity {
+            percent: 50,
+            raw: 2,
+        }),
+        flag: true,
+    };
+    assert_eq!(
+        number_stats(reading.clone()),
+        NumberStats {
+            count: 5,
+            sum: 58.0,
+            min: Some(-4.0),
+            max: Some(50.0),
+        }
+    );
+    assert_eq!(count_numbers(reading.clone()), 5);
+    assert_eq!(sum_numbers(reading.clone()), 58.0);
+    assert_eq!(min_number(reading.clone()), Some(-4.0));
+    assert_eq!(max_number(reading.clone()), Some(50.0));
+    assert_eq!(mean_number(reading.clone()), Some(11.6));
+
+    let reading = Reading {
+        extra: Some(Extra::Temperature(0.5)),
+        ..reading
+    };
+    assert_eq!(sum_numbers(reading.clone()), 6.5);
+    assert_eq!(
+        count_numbers(Reading {
+            extra: None,
+            ..reading
+        }),
+        3
+    );
+
+    let stats = number_stats((f64::NAN, 1u128, -2isize, 3usize));
+    assert_eq!(stats.count, 4);
+    assert!(stats.sum.is_nan());
+    assert_eq!((stats.min, stats.max), (Some(-2.0), Some(3.0)));
+
+    assert_eq!(number_stats(vec![f32::NAN]).min, None);
+    assert_eq!(
+        number_stats(("none", 'c')),
+        NumberStats {
+            count: 0,
+            sum: 0.0,
+            min: None,
+            max: None,
+        }
+    );
+    assert_eq!(mean_number(Vec::<u8>::new()), None);
+}
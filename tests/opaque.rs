@@ -0,0 +1,69 @@
+use std::fmt::Debug;
+
+use generics::{visit, Generic, GenericVisitor, Leaf, List, Meta, Opaque, Prod, Unit};
+
+struct NotGeneric(u8);
+
+#[derive(Generic)]
+struct Foo {
+    #[generic(opaque)]
+    a: Vec<u8>,
+    b: Vec<u8>,
+    #[generic(opaque)]
+    c: NotGeneric,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+#[generic(const_fn)]
+struct Const {
+    #[generic(opaque)]
+    a: u8,
+    b: u16,
+}
+
+const CONST: Const = Const::from_repr_const(Const { a: 1, b: 2 }.into_repr_const());
+
+#[derive(Default)]
+struct Leaves(Vec<String>);
+
+impl GenericVisitor for Leaves {
+    fn visit_leaf<T>(&mut self, leaf: &T)
+    where
+        T: Leaf + Debug + 'static,
+    {
+        self.0.push(format!("{:?}", leaf));
+    }
+}
+
+#[derive(Generic)]
+struct Bytes {
+    #[generic(opaque)]
+    a: Vec<u8>,
+    b: Vec<u8>,
+}
+
+#[test]
+fn opaque() {
+    let foo = Foo {
+        a: vec![1, 2],
+        b: vec![3],
+        c: NotGeneric(4),
+    };
+    let repr = foo.into_repr();
+    let Prod(Meta(Opaque(a), _), Prod(Meta(List(b), _), Prod(Meta(Opaque(c), _), Unit))) = repr.0;
+    assert_eq!(a, [1, 2]);
+    assert_eq!(b, [3]);
+    assert_eq!(c.0, 4);
+
+    assert_eq!(CONST, Const { a: 1, b: 2 });
+
+    let mut leaves = Leaves::default();
+    visit(
+        Bytes {
+            a: vec![1, 2],
+            b: vec![3, 4],
+        },
+        &mut leaves,
+    );
+    assert_eq!(leaves.0, ["[1, 2]", "3", "4"]);
+}
@@ -0,0 +1,38 @@
+use generics::dynamic::{from_dyn, to_dyn, DynRepr};
+use generics::markers::Option_Some_0;
+use generics::{Field, Generic, Sum};
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Local<T> {
+    None,
+    Some(T),
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo {
+    a: Option<u8>,
+    b: Option<Option<u16>>,
+}
+
+#[test]
+fn option() {
+    let foo = Foo {
+        a: Some(1),
+        b: Some(None),
+    };
+    assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+    assert_eq!(from_dyn::<Foo>(to_dyn(foo.clone())), Ok(foo));
+
+    match Some(2u8).into_repr() {
+        Sum::Right(Sum::Left(some)) => assert_eq!(*Field::<Option_Some_0, _>::field(&some), 2),
+        _ => panic!("expected `Some`"),
+    }
+
+    // Represented the same as an equivalent derived enum.
+    assert_eq!(to_dyn(None::<u8>), to_dyn(Local::<u8>::None));
+    assert_eq!(to_dyn(Some(3u8)), to_dyn(Local::Some(3u8)));
+    assert_eq!(
+        to_dyn(None::<u8>),
+        DynRepr::Left(Box::new(DynRepr::Con("None", Box::new(DynRepr::Unit))))
+    );
+}
@@ -0,0 +1,26 @@
+use std::ffi::{CString, OsString};
+use std::path::{Path, PathBuf};
+
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Cli {
+    config: PathBuf,
+    cache: Box<Path>,
+    arg: OsString,
+    name: CString,
+}
+
+#[test]
+fn os_string() {
+    let cli = Cli {
+        config: PathBuf::from("/etc/app.toml"),
+        cache: Path::new("/tmp/cache").into(),
+        arg: OsString::from("--verbose"),
+        name: CString::new("app").unwrap(),
+    };
+    assert_eq!(Cli::from_repr(cli.clone().into_repr()), cli);
+
+    let path: PathBuf = PathBuf::from("a/b").into_repr();
+    assert_eq!(path, Path::new("a/b"));
+}
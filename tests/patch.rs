@@ -0,0 +1,60 @@
+use std::marker::PhantomData;
+
+use generics::ops::{self, Patch};
+use generics::{Con, Generic, List, Meta, Prod, Sum, Unit};
+
+#[derive(Debug, PartialEq, Generic)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Generic)]
+struct Shape {
+    name: String,
+    points: Vec<Point>,
+}
+
+fn point_patch(x: Option<i32>, y: Option<i32>) -> Patch<Point> {
+    Con(
+        Prod(Meta(x, PhantomData), Prod(Meta(y, PhantomData), Unit)),
+        PhantomData,
+    )
+}
+
+#[test]
+fn patch() {
+    let shape = Shape {
+        name: "a".to_string(),
+        points: vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }],
+    };
+    let patch: Patch<Shape> = Con(
+        Prod(
+            Meta(Some("b".to_string()), PhantomData),
+            Prod(
+                Meta(
+                    List(vec![
+                        point_patch(None, Some(5)),
+                        point_patch(Some(6), None),
+                        point_patch(Some(7), Some(8)),
+                    ]),
+                    PhantomData,
+                ),
+                Unit,
+            ),
+        ),
+        PhantomData,
+    );
+    assert_eq!(
+        ops::patch(shape, patch),
+        Shape {
+            name: "b".to_string(),
+            points: vec![Point { x: 1, y: 5 }, Point { x: 6, y: 4 }],
+        }
+    );
+
+    // A patch holding another variant is ignored.
+    let none: Patch<Option<u8>> = Sum::Left(Con(Unit, PhantomData));
+    assert_eq!(ops::patch(Some(1u8), none), Some(1));
+    assert_eq!(ops::patch([1u8, 2], [None, Some(3)]), [1, 3]);
+}
@@ -0,0 +1,39 @@
+use generics::{path, FieldPath, Generic};
+
+#[derive(Generic)]
+struct Point(i32, i32);
+
+#[derive(Generic)]
+struct Line {
+    from: Point,
+    to: Point,
+}
+
+#[derive(Generic)]
+struct Shape {
+    name: String,
+    line: Line,
+}
+
+#[test]
+fn field_path() {
+    let shape = Shape {
+        name: "a".to_string(),
+        line: Line {
+            from: Point(1, 2),
+            to: Point(3, 4),
+        },
+    };
+    let mut repr = shape.into_repr();
+
+    assert_eq!(
+        *FieldPath::<path!(Shape_line.Line_to.Point_1), _>::field_path(&repr),
+        4
+    );
+    assert_eq!(FieldPath::<path!(Shape_name), _>::field_path(&repr), "a");
+
+    *FieldPath::<path!(Shape_line.Line_from.Point_0), _>::field_path_mut(&mut repr) = 10;
+    let from = FieldPath::<path!(Shape_line.Line_from), _>::into_field_path(repr);
+    let Point(x, y) = Point::from_repr(from);
+    assert_eq!((x, y), (10, 2));
+}
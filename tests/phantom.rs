@@ -0,0 +1,30 @@
+use std::marker::PhantomData;
+
+use generics::dynamic::{to_dyn, DynRepr};
+use generics::{Generic, Unit};
+
+struct NotGeneric;
+
+#[derive(Generic, Debug, PartialEq)]
+struct Foo<T> {
+    marker: PhantomData<T>,
+}
+
+#[test]
+fn phantom() {
+    let Unit = PhantomData::<NotGeneric>.into_repr();
+
+    let foo = Foo::<NotGeneric> {
+        marker: PhantomData,
+    };
+    assert_eq!(
+        to_dyn(foo),
+        DynRepr::Con(
+            "Foo",
+            Box::new(DynRepr::Prod(
+                Box::new(DynRepr::Meta("marker", Box::new(DynRepr::Unit))),
+                Box::new(DynRepr::Unit),
+            )),
+        )
+    );
+}
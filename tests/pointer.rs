@@ -0,0 +1,51 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use generics::dynamic::to_dyn;
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+pub struct Inner(u8);
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo {
+    a: Box<Inner>,
+    b: Rc<Inner>,
+    c: Arc<u16>,
+}
+
+mod bare {
+    use super::Inner;
+    use generics::Generic;
+
+    #[derive(Generic)]
+    pub struct Foo {
+        pub a: Inner,
+        pub b: Inner,
+        pub c: u16,
+    }
+}
+
+#[test]
+fn pointer() {
+    let foo = Foo {
+        a: Box::new(Inner(1)),
+        b: Rc::new(Inner(2)),
+        c: Arc::new(3),
+    };
+    assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+
+    // Shared pointees are cloned.
+    let shared = Rc::clone(&foo.b);
+    assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+    assert_eq!(*shared, Inner(2));
+
+    // Pointers don't change the shape of the representation.
+    let bare = bare::Foo {
+        a: Inner(1),
+        b: Inner(2),
+        c: 3,
+    };
+    let _: <Inner as Generic>::Repr = Box::new(Inner(1)).into_repr();
+    assert_eq!(to_dyn(foo), to_dyn(bare));
+}
@@ -0,0 +1,101 @@
+use generics::ops::{pretty, PrettyOptions};
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug)]
+struct Point(i32, i32);
+
+#[derive(Generic, Clone, Debug)]
+enum Shape {
+    Empty,
+    Circle { center: Point, radius: u32 },
+    Polygon(Vec<Point>),
+}
+
+#[derive(Generic, Clone, Debug)]
+struct Scene {
+    name: String,
+    shapes: Vec<Shape>,
+    origin: [Point; 1],
+    tags: (),
+}
+
+fn scene() -> Scene {
+    Scene {
+        name: "scene".to_string(),
+        shapes: vec![
+            Shape::Empty,
+            Shape::Circle {
+                center: Point(0, 1),
+                radius: 2,
+            },
+            Shape::Polygon((0..5).map(|i| Point(i, -i)).collect()),
+        ],
+        origin: [Point(0, 0)],
+        tags: (),
+    }
+}
+
+#[test]
+fn pretty_print() {
+    let default = PrettyOptions::default();
+    assert_eq!(
+        pretty(scene(), &default).to_string(),
+        format!("{:#?}", scene())
+    );
+
+    let compact = PrettyOptions {
+        compact: true,
+        ..PrettyOptions::default()
+    };
+    assert_eq!(
+        pretty(scene(), &compact).to_string(),
+        format!("{:?}", scene())
+    );
+
+    let truncated = PrettyOptions {
+        compact: true,
+        max_items: Some(2),
+        max_depth: Some(3),
+        ..PrettyOptions::default()
+    };
+    assert_eq!(
+        pretty(scene(), &truncated).to_string(),
+        "Scene { \
+         name: \"scene\", \
+         shapes: [Empty, Circle { center: Point(..), radius: 2 }, ... 1 more], \
+         origin: [Point(0, 0)], \
+         tags: () \
+         }"
+    );
+
+    let shallow = PrettyOptions {
+        indent: 1,
+        max_depth: Some(1),
+        ..PrettyOptions::default()
+    };
+    assert_eq!(
+        pretty(scene(), &shallow).to_string(),
+        "Scene {\n name: \"scene\",\n shapes: [..],\n origin: [..],\n tags: (),\n}"
+    );
+
+    let none = PrettyOptions {
+        max_items: Some(0),
+        ..PrettyOptions::default()
+    };
+    assert_eq!(
+        pretty(vec![1u8, 2], &none).to_string(),
+        "[\n    ... 2 more\n]"
+    );
+    assert_eq!(pretty(Vec::<u8>::new(), &none).to_string(), "[]");
+    assert_eq!(
+        pretty(
+            (1u8, 'x'),
+            &PrettyOptions {
+                compact: true,
+                ..PrettyOptions::default()
+            }
+        )
+        .to_string(),
+        "(1, 'x')"
+    );
+}
@@ -0,0 +1,31 @@
+use generics::dynamic::{from_dyn, to_dyn};
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+#[generic(const_fn)]
+struct Foo {
+    a: bool,
+    b: char,
+    c: f32,
+    d: f64,
+    e: usize,
+    f: isize,
+}
+
+const FOO: Foo = Foo::from_repr_const(
+    Foo {
+        a: true,
+        b: 'x',
+        c: 1.5,
+        d: -2.5,
+        e: 3,
+        f: -4,
+    }
+    .into_repr_const(),
+);
+
+#[test]
+fn prim() {
+    assert_eq!(Foo::from_repr(FOO.clone().into_repr()), FOO);
+    assert_eq!(from_dyn::<Foo>(to_dyn(FOO.clone())), Ok(FOO));
+}
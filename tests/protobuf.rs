@@ -0,0 +1,326 @@
+#![cfg(feature = "protobuf")]
+
+use generics::ops::{decode_proto, encode_proto, proto_file, DecodeProtoError, ProtoValue};
+use generics::Generic;
+
+/// A reading from a device.
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Reading {
+    /// The name of the device.
+    ///
+    /// Unique per site.
+    device: String,
+    seq: u32,
+    offset: i64,
+    big: u128,
+    value: f64,
+    ratio: f32,
+    ok: bool,
+    mark: char,
+    samples: Vec<i16>,
+    events: Vec<Event>,
+    pos: Pos,
+    limit: Option<u8>,
+    grid: Vec<Vec<u8>>,
+    pair: (u16, Option<u16>),
+    marker: Marker,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Event {
+    Boot,
+    /// A status code.
+    StatusCode(u8),
+    Alarm {
+        level: u8,
+        note: Option<String>,
+    },
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Pos(i16, i16);
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Marker;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Series {
+    limit: Option<u8>,
+    samples: Vec<i16>,
+    grid: Vec<Vec<u8>>,
+    flags: Vec<Option<bool>>,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Wrappers {
+    a: Wrapper<u8>,
+    b: Wrapper<String>,
+    c: Wrapper<u8>,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    label: Option<String>,
+}
+
+#[test]
+fn proto_file_of() {
+    assert_eq!(
+        proto_file::<Reading>().to_string(),
+        r#"syntax = "proto3";
+
+// A reading from a device.
+message Reading {
+  // The name of the device.
+  //
+  // Unique per site.
+  string device = 1;
+  uint32 seq = 2;
+  sint64 offset = 3;
+  bytes big = 4;
+  double value = 5;
+  float ratio = 6;
+  bool ok = 7;
+  string mark = 8;
+  repeated sint32 samples = 9;
+  repeated Event events = 10;
+  Pos pos = 11;
+  optional uint32 limit = 12;
+  repeated Reading.Grid grid = 13;
+  Reading.Pair pair = 14;
+  Marker marker = 15;
+
+  message Grid {
+    repeated uint32 value = 1;
+  }
+
+  message Pair {
+    uint32 field_0 = 1;
+    optional uint32 field_1 = 2;
+  }
+}
+
+message Event {
+  oneof variant {
+    Event.Boot boot = 1;
+    Event.StatusCode status_code = 2;
+    Event.Alarm alarm = 3;
+  }
+
+  message Boot {}
+
+  // A status code.
+  message StatusCode {
+    uint32 field_0 = 1;
+  }
+
+  message Alarm {
+    uint32 level = 1;
+    optional string note = 2;
+  }
+}
+
+message Pos {
+  sint32 field_0 = 1;
+  sint32 field_1 = 2;
+}
+
+message Marker {}
+"#
+    );
+
+    // Different instantiations of a generic type get different names.
+    assert_eq!(
+        proto_file::<Wrappers>().to_string(),
+        r#"syntax = "proto3";
+
+message Wrappers {
+  Wrapper a = 1;
+  Wrapper2 b = 2;
+  Wrapper c = 3;
+}
+
+message Wrapper {
+  uint32 inner = 1;
+}
+
+message Wrapper2 {
+  string inner = 1;
+}
+"#
+    );
+
+    let mut file = proto_file::<Point>();
+    file.name = "point.proto".to_string();
+    file.package = "geo".to_string();
+    let x = [&[0x0a, 0x01][..], b"x", &[0x18, 0x01, 0x20, 0x01, 0x28, 17]].concat();
+    let label = [
+        &[0x0a, 0x05][..],
+        b"label",
+        &[
+            0x18, 0x02, 0x20, 0x01, 0x28, 9, 0x48, 0x00, 0x88, 0x01, 0x01,
+        ],
+    ]
+    .concat();
+    let oneof = [&[0x0a, 0x06][..], b"_label"].concat();
+    let message = [
+        &[0x0a, 0x05][..],
+        b"Point",
+        &[0x12, x.len() as u8],
+        &x,
+        &[0x12, label.len() as u8],
+        &label,
+        &[0x42, oneof.len() as u8],
+        &oneof,
+    ]
+    .concat();
+    let descriptor = [
+        &[0x0a, 0x0b][..],
+        b"point.proto",
+        &[0x12, 0x03],
+        b"geo",
+        &[0x22, message.len() as u8],
+        &message,
+        &[0x62, 0x06],
+        b"proto3",
+    ]
+    .concat();
+    assert_eq!(file.encode_descriptor(), descriptor);
+}
+
+#[test]
+fn proto() {
+    let reading = Reading {
+        device: "d".to_string(),
+        seq: 500,
+        offset: -500,
+        big: u128::MAX,
+        value: 0.1,
+        ratio: 0.5,
+        ok: true,
+        mark: 'é',
+        samples: vec![-1, 1],
+        events: vec![
+            Event::Boot,
+            Event::StatusCode(24),
+            Event::Alarm {
+                level: 2,
+                note: None,
+            },
+        ],
+        pos: Pos(3, -3),
+        limit: Some(0),
+        grid: vec![vec![], vec![1, 2]],
+        pair: (1, Some(2)),
+        marker: Marker,
+    };
+    let bytes = encode_proto(reading.clone());
+    assert_eq!(decode_proto::<Reading>(&bytes), Ok(reading));
+
+    // Missing fields are their default values, which aren't encoded.
+    let empty = Reading {
+        device: String::new(),
+        seq: 0,
+        offset: 0,
+        big: 0,
+        value: 0.0,
+        ratio: 0.0,
+        ok: false,
+        mark: '\0',
+        samples: vec![],
+        events: vec![],
+        pos: Pos(0, 0),
+        limit: None,
+        grid: vec![],
+        pair: (0, None),
+        marker: Marker,
+    };
+    assert_eq!(decode_proto::<Reading>(&[]), Ok(empty.clone()));
+    assert_eq!(encode_proto(empty), [0x5a, 0x00, 0x72, 0x00, 0x7a, 0x00]);
+
+    let series = Series {
+        limit: Some(0),
+        samples: vec![-1, 2],
+        grid: vec![vec![], vec![1, 2]],
+        flags: vec![None, Some(false)],
+    };
+    let bytes = [
+        0x08, 0x00, // `limit`, present though it's zero
+        0x12, 0x02, 0x01, 0x04, // `samples`, packed
+        0x1a, 0x00, // `grid`, each in a message
+        0x1a, 0x04, 0x0a, 0x02, 0x01, 0x02, //
+        0x22, 0x00, // `flags`, each in a message
+        0x22, 0x02, 0x08, 0x00,
+    ];
+    assert_eq!(encode_proto(series.clone()), bytes);
+    assert_eq!(decode_proto::<Series>(&bytes), Ok(series));
+
+    // Repeated numbers may be unpacked, singular fields take the last value,
+    // messages are merged, and unknown fields are ignored.
+    let mut bytes = Vec::new();
+    ProtoValue::Varint(1).encode(2, &mut bytes);
+    ProtoValue::Len(vec![0x04]).encode(2, &mut bytes);
+    ProtoValue::I32(7).encode(99, &mut bytes);
+    ProtoValue::Varint(3).encode(1, &mut bytes);
+    ProtoValue::Varint(4).encode(1, &mut bytes);
+    assert_eq!(
+        decode_proto::<Series>(&bytes),
+        Ok(Series {
+            limit: Some(4),
+            samples: vec![-1, 2],
+            grid: vec![],
+            flags: vec![],
+        })
+    );
+    assert_eq!(
+        decode_proto::<Pos>(&[0x08, 0x02, 0x10, 0x04, 0x08, 0x06]),
+        Ok(Pos(3, 2))
+    );
+    assert_eq!(
+        decode_proto::<Wrapper<Pos>>(&[0x0a, 0x02, 0x08, 0x02, 0x0a, 0x02, 0x10, 0x04]),
+        Ok(Wrapper { inner: Pos(1, 2) })
+    );
+
+    assert_eq!(
+        decode_proto::<Event>(&[]),
+        Err(DecodeProtoError::Missing(String::new()))
+    );
+    assert_eq!(
+        decode_proto::<Reading>(&[0x15, 0, 0, 0, 0]),
+        Err(DecodeProtoError::Invalid {
+            path: "seq".to_string(),
+            expected: "a u32".to_string()
+        })
+    );
+    assert_eq!(
+        decode_proto::<Series>(&[0x08, 0xac, 0x02])
+            .unwrap_err()
+            .to_string(),
+        "expected a u8 for `limit.0`"
+    );
+    assert_eq!(
+        decode_proto::<Reading>(&[0x52, 0x02, 0x0a, 0x01]),
+        Err(DecodeProtoError::Invalid {
+            path: "events.0".to_string(),
+            expected: "a message".to_string()
+        })
+    );
+    assert_eq!(
+        decode_proto::<Pos>(&[0x08]),
+        Err(DecodeProtoError::Syntax(1))
+    );
+    assert_eq!(
+        decode_proto::<Pos>(&[0x00, 0x00]),
+        Err(DecodeProtoError::Syntax(0))
+    );
+    assert_eq!(
+        decode_proto::<Pos>(&[0x0b]).unwrap_err().to_string(),
+        "invalid protobuf at byte 0"
+    );
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "quickcheck")]
+
+use std::num::NonZeroU8;
+
+use generics::ops::{gquickcheck, gshrink, GenericQuickcheck};
+use generics::Generic;
+use quickcheck::{Arbitrary, Gen};
+
+#[derive(Generic, GenericQuickcheck, Clone, Debug, PartialEq)]
+struct Order {
+    item: Item,
+    quantities: Vec<u8>,
+}
+
+#[derive(Generic, GenericQuickcheck, Clone, Debug, PartialEq)]
+enum Item {
+    Nothing,
+    Widget { size: u8 },
+    Gadget(bool, char),
+}
+
+#[derive(Generic, GenericQuickcheck, Clone, Debug)]
+enum Never {}
+
+fn minimize<T: Arbitrary>(mut x: T, fails: impl Fn(&T) -> bool) -> T {
+    while let Some(smaller) = x.shrink().find(|c| fails(c)) {
+        x = smaller;
+    }
+    x
+}
+
+#[test]
+fn quickcheck() {
+    let mut g = Gen::new(10);
+    for _ in 0..100 {
+        let order: Order = gquickcheck(&mut g);
+        assert!(order.quantities.len() < 10);
+    }
+
+    // Earlier variants are tried first, then each field.
+    let shrunk = Item::Gadget(true, 'x').shrink().collect::<Vec<_>>();
+    assert_eq!(shrunk[0], Item::Nothing);
+    assert!(matches!(shrunk[1], Item::Widget { .. }));
+    assert!(shrunk.contains(&Item::Gadget(false, 'x')));
+    assert!(Item::Nothing.shrink().next().is_none());
+
+    // A failing property is reduced to a minimal counterexample.
+    let order = Order {
+        item: Item::Gadget(true, 'q'),
+        quantities: vec![3, 200, 17, 90],
+    };
+    let minimal = minimize(order, |o| o.quantities.iter().any(|&q| q > 50));
+    assert_eq!(
+        minimal,
+        Order {
+            item: Item::Nothing,
+            quantities: vec![51],
+        }
+    );
+
+    // Representations the type rejects are never generated, nor shrunk to.
+    for _ in 0..1000 {
+        let _: NonZeroU8 = gquickcheck(&mut g);
+    }
+    let shrunk = gshrink(&NonZeroU8::new(5).unwrap()).collect::<Vec<_>>();
+    assert!(!shrunk.is_empty());
+    assert!(shrunk.iter().all(|n| n.get() < 5));
+
+    let _ = Never::shrink;
+}
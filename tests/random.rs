@@ -0,0 +1,75 @@
+#![cfg(feature = "rand")]
+
+use std::num::NonZeroU8;
+
+use generics::ops::{gen_random, GStandard, Random, MAX_RANDOM_LEN};
+use generics::{Generic, Opaque};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[derive(Generic, Debug, Clone, PartialEq)]
+enum Op {
+    Nop,
+    Push(i32),
+    Jump { to: u16, when: Option<bool> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Even(u8);
+
+impl Random for Even {
+    fn random<R>(rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        Even(rng.gen::<u8>() & !1)
+    }
+}
+
+#[derive(Generic, Debug, Clone, PartialEq)]
+struct Program {
+    name: String,
+    ops: Vec<Op>,
+    registers: [u8; 3],
+    #[generic(opaque)]
+    even: Even,
+}
+
+#[test]
+fn random() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let programs = (0..100)
+        .map(|_| rng.sample::<Program, _>(GStandard))
+        .collect::<Vec<_>>();
+
+    let mut again = StdRng::seed_from_u64(42);
+    for program in &programs {
+        assert_eq!(&again.sample::<Program, _>(GStandard), program);
+        assert!(program.name.len() <= MAX_RANDOM_LEN);
+        assert!(program.ops.len() <= MAX_RANDOM_LEN);
+        assert_eq!(program.even.0 % 2, 0);
+    }
+
+    let ops = programs.iter().flat_map(|p| &p.ops).collect::<Vec<_>>();
+    assert!(ops.iter().any(|op| matches!(op, Op::Nop)));
+    assert!(ops.iter().any(|op| matches!(op, Op::Push(_))));
+    assert!(ops
+        .iter()
+        .any(|op| matches!(op, Op::Jump { when: None, .. })));
+    assert!(ops.iter().any(|op| matches!(
+        op,
+        Op::Jump {
+            when: Some(true),
+            ..
+        }
+    )));
+    assert!(programs.iter().any(|p| p.ops.is_empty()));
+    assert!(programs.iter().any(|p| p.ops.len() == MAX_RANDOM_LEN));
+
+    let _: (Op, Opaque<Even>) = gen_random();
+
+    // Zero is generated for the representation, but never returned.
+    for _ in 0..1000 {
+        let _: NonZeroU8 = rng.sample(GStandard);
+    }
+}
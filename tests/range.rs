@@ -0,0 +1,71 @@
+use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use generics::dynamic::{from_dyn, to_dyn};
+use generics::markers::{RangeInclusive_end, Range_start};
+use generics::{Field, Generic};
+
+mod local {
+    use generics::Generic;
+
+    #[derive(Generic)]
+    pub struct Range<T> {
+        pub start: T,
+        pub end: T,
+    }
+
+    #[derive(Generic)]
+    pub enum Bound<T> {
+        Included(T),
+        Excluded(T),
+        Unbounded,
+    }
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo {
+    a: Range<u8>,
+    b: RangeInclusive<u16>,
+    c: RangeFrom<u32>,
+    d: RangeTo<u64>,
+    e: RangeToInclusive<i8>,
+    f: RangeFull,
+    g: (Bound<u8>, Bound<u8>, Bound<u8>),
+}
+
+#[test]
+fn range() {
+    let foo = Foo {
+        a: 1..2,
+        b: 3..=4,
+        c: 5..,
+        d: ..6,
+        e: ..=7,
+        f: ..,
+        g: (Bound::Included(8), Bound::Excluded(9), Bound::Unbounded),
+    };
+    assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+    assert_eq!(from_dyn::<Foo>(to_dyn(foo.clone())), Ok(foo));
+
+    assert_eq!(*Field::<Range_start, _>::field(&(1u8..2).into_repr()), 1);
+    assert_eq!(
+        *Field::<RangeInclusive_end, _>::field(&(1u8..=2).into_repr()),
+        2
+    );
+
+    // Represented the same as an equivalent derived type.
+    assert_eq!(
+        to_dyn(1u8..2),
+        to_dyn(local::Range {
+            start: 1u8,
+            end: 2u8
+        })
+    );
+    assert_eq!(
+        to_dyn(Bound::Excluded(3u8)),
+        to_dyn(local::Bound::Excluded(3u8))
+    );
+    assert_eq!(
+        to_dyn(Bound::<u8>::Unbounded),
+        to_dyn(local::Bound::<u8>::Unbounded)
+    );
+}
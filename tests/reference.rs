@@ -0,0 +1,48 @@
+use generics::{visit, Generic, GenericVisitor, Leaf};
+use std::fmt::Debug;
+
+#[derive(Generic, Debug, PartialEq)]
+struct Foo<'a> {
+    a: &'a str,
+    b: &'a [u8],
+    c: &'a mut u32,
+}
+
+#[derive(Default)]
+struct Leaves(Vec<String>);
+
+impl GenericVisitor for Leaves {
+    fn visit_leaf<T>(&mut self, leaf: &T)
+    where
+        T: Leaf + Debug + 'static,
+    {
+        self.0.push(format!("{:?}", leaf));
+    }
+}
+
+#[derive(Generic)]
+struct Static {
+    a: &'static str,
+    b: &'static [u8],
+}
+
+#[test]
+fn reference() {
+    let bytes = [1, 2];
+    let mut c = 3;
+    let foo = Foo {
+        a: "a",
+        b: &bytes,
+        c: &mut c,
+    };
+    let repr = foo.into_repr();
+    let foo = Foo::from_repr(repr);
+    *foo.c += 1;
+    assert_eq!(foo.a, "a");
+    assert_eq!(foo.b, [1, 2]);
+    assert_eq!(c, 4);
+
+    let mut leaves = Leaves::default();
+    visit(Static { a: "a", b: &[1] }, &mut leaves);
+    assert_eq!(leaves.0, ["\"a\"", "[1]"]);
+}
@@ -0,0 +1,53 @@
+use generics::dynamic::{DynPrim, DynRepr, FromDynError, Reflect};
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo {
+    a: u8,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Bar(u16);
+
+#[test]
+fn reflect() {
+    let mut values: Vec<Box<dyn Reflect>> = vec![Box::new(Foo { a: 1 }), Box::new(Bar(2))];
+
+    assert!(values[0].type_name().ends_with("Foo"));
+    assert!(values[1].type_name().ends_with("Bar"));
+
+    let bar = values[1].to_dyn();
+    assert_eq!(
+        values[0].set_dyn(bar.clone()),
+        Err(FromDynError::Name {
+            expected: "Foo",
+            found: "Bar"
+        })
+    );
+
+    let bar = match bar {
+        DynRepr::Con(name, inner) => match *inner {
+            DynRepr::Prod(field, rest) => match *field {
+                DynRepr::Meta(field_name, _) => DynRepr::Con(
+                    name,
+                    Box::new(DynRepr::Prod(
+                        Box::new(DynRepr::Meta(
+                            field_name,
+                            Box::new(DynRepr::Prim(DynPrim::U16(42))),
+                        )),
+                        rest,
+                    )),
+                ),
+                _ => panic!("expected field"),
+            },
+            _ => panic!("expected product"),
+        },
+        _ => panic!("expected constructor"),
+    };
+    assert_eq!(values[1].set_dyn(bar), Ok(()));
+
+    let foo = values.remove(0).into_dyn();
+    assert_eq!(generics::dynamic::from_dyn::<Foo>(foo), Ok(Foo { a: 1 }));
+    let bar = values.remove(0).into_dyn();
+    assert_eq!(generics::dynamic::from_dyn::<Bar>(bar), Ok(Bar(42)));
+}
@@ -0,0 +1,41 @@
+use generics::dynamic::{from_dyn, to_dyn};
+use generics::{visit, Generic, GenericVisitor};
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Local<T, E> {
+    Ok(T),
+    Err(E),
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo {
+    a: Result<u8, u16>,
+    b: Result<(), Option<u32>>,
+}
+
+#[derive(Default)]
+struct Constructors(Vec<&'static str>);
+
+impl GenericVisitor for Constructors {
+    fn enter_constructor(&mut self, name: &'static str) {
+        self.0.push(name);
+    }
+}
+
+#[test]
+fn result() {
+    let foo = Foo {
+        a: Ok(1),
+        b: Err(Some(2)),
+    };
+    assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+    assert_eq!(from_dyn::<Foo>(to_dyn(foo.clone())), Ok(foo.clone()));
+
+    let mut constructors = Constructors::default();
+    visit(foo, &mut constructors);
+    assert_eq!(constructors.0, ["Foo", "Ok", "Err", "Some"]);
+
+    // Represented the same as an equivalent derived enum.
+    assert_eq!(to_dyn(Ok::<u8, u16>(3)), to_dyn(Local::<u8, u16>::Ok(3)));
+    assert_eq!(to_dyn(Err::<u8, u16>(4)), to_dyn(Local::<u8, u16>::Err(4)));
+}
@@ -0,0 +1,73 @@
+use generics::ops::{clone_with, rewrite, Hooks};
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Document {
+    id: u32,
+    title: String,
+    blocks: Vec<Block>,
+    tags: [String; 2],
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Block {
+    Text { id: u32, body: String },
+    Embed(u32, String),
+}
+
+#[test]
+fn rewrite_leaves() {
+    let doc = Document {
+        id: 1,
+        title: "Draft".to_string(),
+        blocks: vec![
+            Block::Text {
+                id: 2,
+                body: "hello".to_string(),
+            },
+            Block::Embed(3, "https://example.com".to_string()),
+        ],
+        tags: ["a".to_string(), "b".to_string()],
+    };
+
+    let mut paths = Vec::new();
+    let copy = clone_with(
+        &doc,
+        Hooks::new()
+            .leaf(|id: u32| id + 10)
+            .leaf(|id: u32| id * 2)
+            .path("title", |title: String| format!("Copy of {}", title))
+            .path("blocks.0.body", |body: String| body.to_uppercase())
+            .path("tags.1", |_: String| "z".to_string())
+            .path("id", |_: String| unreachable!())
+            .path("missing", |_: u32| unreachable!()),
+    );
+    assert_eq!(
+        copy,
+        Document {
+            id: 22,
+            title: "Copy of Draft".to_string(),
+            blocks: vec![
+                Block::Text {
+                    id: 24,
+                    body: "HELLO".to_string(),
+                },
+                Block::Embed(26, "https://example.com".to_string()),
+            ],
+            tags: ["a".to_string(), "z".to_string()],
+        }
+    );
+    assert_eq!(doc.id, 1);
+
+    let doc = rewrite(
+        doc,
+        Hooks::new().leaf(|s: String| {
+            paths.push(s.clone());
+            s
+        }),
+    );
+    assert_eq!(paths, ["Draft", "hello", "https://example.com", "a", "b"]);
+
+    assert_eq!(rewrite(doc.clone(), Hooks::new()), doc);
+    assert_eq!(rewrite(5u8, Hooks::new().path("", |x: u8| x + 1)), 6);
+}
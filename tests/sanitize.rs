@@ -0,0 +1,130 @@
+use generics::ops::{GenericSanitize, Sanitize, SanitizeRule};
+use generics::Generic;
+
+/// Replaces runs of whitespace with a single space.
+struct CollapseSpaces;
+
+impl SanitizeRule<String> for CollapseSpaces {
+    fn apply(&self, value: &mut String) {
+        *value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    }
+}
+
+#[derive(Debug, PartialEq, Generic, GenericSanitize)]
+struct Tag {
+    #[generic(sanitize = "trim, lowercase")]
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Generic, GenericSanitize)]
+struct Form {
+    #[generic(sanitize = "CollapseSpaces")]
+    title: String,
+    #[generic(sanitize = "clamp(0.0, 1.0)")]
+    weight: f64,
+    #[generic(sanitize = "clamp(1, 10)")]
+    ratings: Vec<i32>,
+    #[generic(sanitize = "dedup")]
+    tags: Vec<Tag>,
+    #[generic(sanitize = "trim")]
+    nickname: Option<String>,
+    #[generic(opaque, sanitize = "clamp(0, 99)")]
+    code: u32,
+}
+
+#[derive(Debug, PartialEq, Generic, GenericSanitize)]
+enum Contact {
+    Email(#[generic(sanitize = "trim, lowercase")] String),
+    Phone {
+        #[generic(sanitize = "trim")]
+        number: String,
+    },
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, Generic, GenericSanitize)]
+enum Never {}
+
+fn assert_sanitize_impl<T: Sanitize>() {}
+
+#[test]
+fn sanitize() {
+    let mut form = Form {
+        title: "  a   long \t title ".to_string(),
+        weight: 1.5,
+        ratings: vec![0, 5, 11],
+        tags: vec![
+            Tag {
+                name: "Rust".to_string(),
+            },
+            Tag {
+                name: " rust ".to_string(),
+            },
+            Tag {
+                name: "serde".to_string(),
+            },
+        ],
+        nickname: Some(" bob ".to_string()),
+        code: 123,
+    };
+    form.sanitize();
+    assert_eq!(
+        form,
+        Form {
+            title: "a long title".to_string(),
+            weight: 1.0,
+            ratings: vec![1, 5, 10],
+            tags: vec![
+                Tag {
+                    name: "rust".to_string()
+                },
+                Tag {
+                    name: "serde".to_string()
+                },
+            ],
+            nickname: Some("bob".to_string()),
+            code: 99,
+        }
+    );
+
+    let mut email = Contact::Email(" A@B.C ".to_string());
+    email.sanitize();
+    assert_eq!(email, Contact::Email("a@b.c".to_string()));
+
+    let mut phone = Contact::Phone {
+        number: " 555 ".to_string(),
+    };
+    phone.sanitize();
+    assert_eq!(
+        phone,
+        Contact::Phone {
+            number: "555".to_string()
+        }
+    );
+
+    let mut unknown = Contact::Unknown;
+    unknown.sanitize();
+    assert_eq!(unknown, Contact::Unknown);
+
+    assert_sanitize_impl::<Never>();
+
+    #[cfg(feature = "unicode-normalization")]
+    {
+        #[derive(Debug, PartialEq, Generic, GenericSanitize)]
+        struct Name {
+            #[generic(sanitize = "nfc, trim")]
+            name: String,
+        }
+
+        let mut name = Name {
+            name: " Cafe\u{301} ".to_string(),
+        };
+        name.sanitize();
+        assert_eq!(
+            name,
+            Name {
+                name: "Caf\u{e9}".to_string()
+            }
+        );
+    }
+}
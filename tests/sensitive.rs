@@ -0,0 +1,55 @@
+use generics::ops::{gdebug, GenericDebug};
+use generics::{Generic, Meta, Sensitive};
+
+struct Token(u64);
+
+#[derive(Generic, GenericDebug)]
+struct Credentials {
+    user: String,
+    #[generic(sensitive)]
+    password: String,
+    #[generic(sensitive)]
+    token: Token,
+}
+
+#[derive(Generic, GenericDebug)]
+enum Auth {
+    Anonymous,
+    Basic(Credentials),
+    Key(#[generic(sensitive)] String, u8),
+}
+
+#[test]
+fn sensitive() {
+    let credentials = || Credentials {
+        user: "admin".to_string(),
+        password: "hunter2".to_string(),
+        token: Token(42),
+    };
+
+    assert_eq!(
+        format!("{:?}", credentials()),
+        r#"Credentials { user: "admin", password: ***, token: *** }"#
+    );
+    assert_eq!(
+        format!("{:?}", Auth::Basic(credentials())),
+        r#"Basic(Credentials { user: "admin", password: ***, token: *** })"#
+    );
+    assert_eq!(
+        format!("{:#?}", Auth::Key("secret".to_string(), 1)),
+        "Key(\n    ***,\n    1,\n)"
+    );
+    assert_eq!(format!("{:?}", Auth::Anonymous), "Anonymous");
+
+    let repr = credentials().into_repr();
+    let Meta(Sensitive(password), _) = ((repr.0).1).0;
+    assert_eq!(password, "hunter2");
+
+    let credentials = Credentials::from_repr(credentials().into_repr());
+    assert_eq!(credentials.password, "hunter2");
+    assert_eq!(credentials.token.0, 42);
+    assert_eq!(
+        format!("{:?}", gdebug(credentials)),
+        r#"Credentials { user: "admin", password: ***, token: *** }"#
+    );
+}
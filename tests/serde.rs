@@ -0,0 +1,136 @@
+#![cfg(feature = "serde")]
+
+use generics::{Generic, Opaque};
+
+#[derive(Generic, Debug, Clone, PartialEq)]
+struct Event {
+    id: u64,
+    kind: Kind,
+    tags: Vec<String>,
+    point: Point,
+    pair: (i8, bool),
+    window: [u16; 2],
+    marker: Marker,
+    #[generic(opaque)]
+    raw: Vec<u8>,
+}
+
+#[derive(Generic, Debug, Clone, PartialEq)]
+enum Kind {
+    Start,
+    Progress(u8),
+    Stop { reason: Option<String> },
+}
+
+#[derive(Generic, Debug, Clone, PartialEq)]
+struct Point(i32, i32);
+
+#[derive(Generic, Debug, Clone, PartialEq)]
+struct Marker;
+
+#[derive(Generic, Debug, Clone, PartialEq)]
+struct Endpoint {
+    host: Option<String>,
+    port: u16,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+enum Never {}
+
+fn to_json<T>(x: T) -> String
+where
+    T: Generic,
+    T::Repr: serde::Serialize,
+{
+    serde_json::to_string(&x.into_repr()).unwrap()
+}
+
+fn from_json<T>(json: &str) -> Result<T, String>
+where
+    T: Generic,
+    T::Repr: for<'de> serde::Deserialize<'de>,
+{
+    serde_json::from_str(json)
+        .map(T::from_repr)
+        .map_err(|e| e.to_string())
+}
+
+#[test]
+fn serde() {
+    let event = Event {
+        id: 7,
+        kind: Kind::Stop {
+            reason: Some("done".to_string()),
+        },
+        tags: vec!["a".to_string()],
+        point: Point(-1, 2),
+        pair: (3, true),
+        window: [10, 20],
+        marker: Marker,
+        raw: vec![0xff],
+    };
+    let json = to_json(event.clone());
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"id":7,"kind":{"Stop":{"reason":"done"}},"tags":["a"],"#,
+            r#""point":[-1,2],"pair":[3,true],"window":[10,20],"marker":null,"raw":[255]}"#
+        )
+    );
+    assert_eq!(from_json::<Event>(&json), Ok(event.clone()));
+
+    let value = generics::serde::serialize(&event.kind, serde_json::value::Serializer).unwrap();
+    assert_eq!(
+        generics::serde::deserialize::<Kind, _>(value).unwrap(),
+        event.kind
+    );
+
+    assert_eq!(to_json(Kind::Start), r#""Start""#);
+    assert_eq!(to_json(Kind::Progress(5)), r#"{"Progress":5}"#);
+    assert_eq!(from_json::<Kind>(r#""Start""#), Ok(Kind::Start));
+    assert_eq!(
+        from_json::<Kind>(r#"{"Progress":5}"#),
+        Ok(Kind::Progress(5))
+    );
+    assert_eq!(from_json::<Point>("[4, 5]"), Ok(Point(4, 5)));
+    assert_eq!(
+        from_json::<Kind>(r#"{"Stop":{"reason":null}}"#),
+        Ok(Kind::Stop { reason: None })
+    );
+    assert_eq!(from_json::<Marker>("null"), Ok(Marker));
+    assert_eq!(to_json(Opaque(1u8)), "1");
+    assert_eq!(to_json(()), "null");
+
+    assert_eq!(
+        from_json::<Kind>(r#""Pause""#),
+        Err(
+            "unknown variant `Pause`, expected one of `Start`, `Progress`, `Stop` at line 1 column 7"
+                .to_string()
+        )
+    );
+    // Missing options are `None`, like serde's derive, but other missing fields are an error.
+    assert_eq!(
+        from_json::<Kind>(r#"{"Stop":{}}"#),
+        Ok(Kind::Stop { reason: None })
+    );
+    assert_eq!(
+        from_json::<Endpoint>(r#"{"port":80}"#),
+        Ok(Endpoint {
+            host: None,
+            port: 80
+        })
+    );
+    assert_eq!(
+        from_json::<Endpoint>(r#"{"host":"a"}"#),
+        Err("missing field `port` at line 1 column 12".to_string())
+    );
+    assert_eq!(
+        from_json::<Kind>(r#"{"Stop":{"reason":null,"reason":"x"}}"#),
+        Err("duplicate field `reason` at line 1 column 31".to_string())
+    );
+    assert_eq!(
+        from_json::<Point>("[1]"),
+        Err("invalid length 1, expected tuple struct Point at line 1 column 3".to_string())
+    );
+    assert!(from_json::<Never>(r#""A""#).is_err());
+}
@@ -0,0 +1,218 @@
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use generics::ops::serialize_generic;
+use generics::{Generic, Opaque};
+use serde::Serialize;
+use serde_test::{assert_ser_tokens, Token};
+
+#[derive(Generic, Serialize, Clone)]
+struct Record {
+    id: u64,
+    name: String,
+    score: Option<f32>,
+    tags: Vec<Tag>,
+    pos: Pos,
+    id_: Id,
+    unit: Empty,
+    pair: (u8, char),
+    grid: [bool; 2],
+    state: State,
+    #[generic(opaque)]
+    attrs: BTreeMap<String, u8>,
+}
+
+#[derive(Generic, Serialize, Clone)]
+enum Tag {
+    Plain,
+    Named(String),
+    Weighted(String, u8),
+    Scoped { scope: String, weight: Option<u8> },
+}
+
+#[derive(Generic, Serialize, Clone)]
+struct Pos(i16, i16);
+
+#[derive(Generic, Serialize, Clone)]
+struct Id(u32);
+
+#[derive(Generic, Serialize, Clone)]
+struct Empty;
+
+#[derive(Generic, Serialize, Clone)]
+enum State {
+    On,
+    Off,
+}
+
+#[derive(Generic, Serialize, Clone)]
+struct Braced {}
+
+#[derive(Generic, Serialize, Clone)]
+struct Tuple();
+
+#[derive(Generic, Serialize, Clone)]
+enum Fieldless {
+    Braced {},
+    Tuple(),
+}
+
+/// Serializes the wrapped value with `serialize_generic`, instead of its derived impl.
+struct ViaGeneric<T>(T);
+
+impl<T> Serialize for ViaGeneric<T>
+where
+    T: Generic + Clone,
+    T::Repr: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_generic(self.0.clone(), serializer)
+    }
+}
+
+/// Serializes `x` to JSON with `serialize_generic`, checking that it matches its derived impl.
+fn to_json<T>(x: T) -> String
+where
+    T: Generic + Serialize + Clone,
+    T::Repr: Serialize,
+{
+    let json = serde_json::to_string(&x).unwrap();
+    let mut generic = Vec::new();
+    serialize_generic(x, &mut serde_json::Serializer::new(&mut generic)).unwrap();
+    assert_eq!(String::from_utf8(generic).unwrap(), json);
+    json
+}
+
+#[test]
+fn serialize() {
+    let record = Record {
+        id: 9,
+        name: "rec".to_string(),
+        score: Some(0.5),
+        tags: vec![
+            Tag::Plain,
+            Tag::Named("n".to_string()),
+            Tag::Weighted("w".to_string(), 2),
+            Tag::Scoped {
+                scope: "s".to_string(),
+                weight: None,
+            },
+        ],
+        pos: Pos(-1, 1),
+        id_: Id(7),
+        unit: Empty,
+        pair: (3, 'c'),
+        grid: [true, false],
+        state: State::Off,
+        attrs: vec![("k".to_string(), 1)].into_iter().collect(),
+    };
+
+    let tokens = [
+        Token::Struct {
+            name: "Record",
+            len: 11,
+        },
+        Token::Str("id"),
+        Token::U64(9),
+        Token::Str("name"),
+        Token::Str("rec"),
+        Token::Str("score"),
+        Token::Some,
+        Token::F32(0.5),
+        Token::Str("tags"),
+        Token::Seq { len: Some(4) },
+        Token::UnitVariant {
+            name: "Tag",
+            variant: "Plain",
+        },
+        Token::NewtypeVariant {
+            name: "Tag",
+            variant: "Named",
+        },
+        Token::Str("n"),
+        Token::TupleVariant {
+            name: "Tag",
+            variant: "Weighted",
+            len: 2,
+        },
+        Token::Str("w"),
+        Token::U8(2),
+        Token::TupleVariantEnd,
+        Token::StructVariant {
+            name: "Tag",
+            variant: "Scoped",
+            len: 2,
+        },
+        Token::Str("scope"),
+        Token::Str("s"),
+        Token::Str("weight"),
+        Token::None,
+        Token::StructVariantEnd,
+        Token::SeqEnd,
+        Token::Str("pos"),
+        Token::TupleStruct {
+            name: "Pos",
+            len: 2,
+        },
+        Token::I16(-1),
+        Token::I16(1),
+        Token::TupleStructEnd,
+        Token::Str("id_"),
+        Token::NewtypeStruct { name: "Id" },
+        Token::U32(7),
+        Token::Str("unit"),
+        Token::UnitStruct { name: "Empty" },
+        Token::Str("pair"),
+        Token::Tuple { len: 2 },
+        Token::U8(3),
+        Token::Char('c'),
+        Token::TupleEnd,
+        Token::Str("grid"),
+        Token::Tuple { len: 2 },
+        Token::Bool(true),
+        Token::Bool(false),
+        Token::TupleEnd,
+        Token::Str("state"),
+        Token::UnitVariant {
+            name: "State",
+            variant: "Off",
+        },
+        Token::Str("attrs"),
+        Token::Map { len: Some(1) },
+        Token::Str("k"),
+        Token::U8(1),
+        Token::MapEnd,
+        Token::StructEnd,
+    ];
+    assert_ser_tokens(&record, &tokens);
+    assert_ser_tokens(&ViaGeneric(record.clone()), &tokens);
+
+    assert_eq!(
+        serialize_generic(record.clone(), serde_json::value::Serializer).unwrap(),
+        serde_json::to_value(&record).unwrap()
+    );
+    assert_eq!(
+        serialize_generic(Opaque(3u8), serde_json::value::Serializer).unwrap(),
+        serde_json::json!(3)
+    );
+
+    assert_eq!(to_json(Braced {}), "{}");
+    assert_eq!(to_json(Tuple()), "[]");
+    assert_eq!(to_json(Fieldless::Braced {}), r#"{"Braced":{}}"#);
+    assert_eq!(to_json(Fieldless::Tuple()), r#"{"Tuple":[]}"#);
+    assert_ser_tokens(
+        &ViaGeneric(Fieldless::Tuple()),
+        &[
+            Token::TupleVariant {
+                name: "Fieldless",
+                variant: "Tuple",
+                len: 0,
+            },
+            Token::TupleVariantEnd,
+        ],
+    );
+}
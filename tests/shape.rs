@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use generics::ops::{shape, Shape};
+use generics::Generic;
+
+#[derive(Generic)]
+struct Id(u64);
+
+#[derive(Generic)]
+struct Unit;
+
+#[derive(Generic)]
+enum Status {
+    Active,
+    Suspended { until: u64, reason: Option<String> },
+}
+
+#[derive(Generic)]
+struct User<T> {
+    id: Id,
+    tags: Vec<String>,
+    status: Status,
+    extra: T,
+    #[generic(opaque)]
+    attrs: HashMap<String, u8>,
+    marker: Unit,
+}
+
+#[derive(Generic)]
+enum Never {}
+
+#[test]
+fn shape_of() {
+    assert_eq!(
+        shape::<User<[u8; 4]>>().to_string(),
+        "User { \
+         id: Id(u64), \
+         tags: [String], \
+         status: Active | Suspended { until: u64, reason: None | Some(String) }, \
+         extra: [u8; 4], \
+         attrs: Opaque<HashMap<String, u8>>, \
+         marker: Unit \
+         }"
+    );
+
+    assert_eq!(
+        shape::<Id>(),
+        Shape::Con(
+            "Id",
+            Box::new(Shape::Prod(vec![Shape::Field(
+                "0",
+                Box::new(Shape::Leaf("u64"))
+            )]))
+        )
+    );
+    assert_eq!(shape::<Never>().to_string(), "!");
+    assert_eq!(shape::<()>().to_string(), "()");
+    assert_eq!(shape::<char>(), Shape::Leaf("char"));
+}
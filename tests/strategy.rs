@@ -0,0 +1,81 @@
+#![cfg(feature = "proptest")]
+
+use std::num::NonZeroU8;
+
+use generics::ops::{generic_strategy, GenericStrategy};
+use generics::Generic;
+use proptest::prelude::*;
+use proptest::test_runner::{TestError, TestRunner};
+
+#[derive(Generic, GenericStrategy, Clone, Debug, PartialEq)]
+struct Order {
+    item: Item,
+    #[generic(strategy = "1..10u32")]
+    count: u32,
+    #[generic(opaque)]
+    gift: bool,
+    quantities: Vec<u8>,
+}
+
+#[derive(Generic, GenericStrategy, Clone, Debug, PartialEq)]
+enum Item {
+    Nothing,
+    Widget { size: u8 },
+    Gadget(bool, [char; 2]),
+}
+
+#[derive(Generic, GenericStrategy, Debug)]
+enum Never {}
+
+#[test]
+fn strategy() {
+    let mut runner = TestRunner::deterministic();
+    runner
+        .run(&any::<Order>(), |order| {
+            prop_assert!((1..10).contains(&order.count));
+            prop_assert!(order.quantities.len() <= 8);
+            Ok(())
+        })
+        .unwrap();
+
+    // A failing property is reduced to a minimal counterexample.
+    let mut runner = TestRunner::deterministic();
+    let result = runner.run(&any::<Order>(), |order| {
+        prop_assert!(order.quantities.iter().all(|&q| q <= 50));
+        Ok(())
+    });
+    match result {
+        Err(TestError::Fail(_, minimal)) => assert_eq!(
+            minimal,
+            Order {
+                item: Item::Nothing,
+                count: 1,
+                gift: false,
+                quantities: vec![51],
+            }
+        ),
+        result => panic!("expected failure, got {:?}", result),
+    }
+
+    // Uninhabited types can't be generated.
+    let mut runner = TestRunner::deterministic();
+    assert!(any::<Never>().new_tree(&mut runner).is_err());
+
+    // Representations the type rejects are never generated, nor shrunk to.
+    let mut runner = TestRunner::deterministic();
+    runner
+        .run(&generic_strategy::<NonZeroU8>(), |n| {
+            prop_assert!(n.get() > 0);
+            Ok(())
+        })
+        .unwrap();
+    let mut runner = TestRunner::deterministic();
+    let result = runner.run(&generic_strategy::<NonZeroU8>(), |n| {
+        prop_assert!(n.get() < 10);
+        Ok(())
+    });
+    match result {
+        Err(TestError::Fail(_, minimal)) => assert_eq!(minimal.get(), 10),
+        result => panic!("expected failure, got {:?}", result),
+    }
+}
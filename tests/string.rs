@@ -0,0 +1,60 @@
+use generics::dynamic::{from_dyn, to_dyn, DynPrim, DynRepr};
+use generics::{visit, Generic, GenericVisitor, Leaf};
+use std::any::Any;
+use std::fmt::Debug;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Owned {
+    a: String,
+    b: Box<str>,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Borrowed<'a> {
+    a: &'a str,
+}
+
+#[derive(Default)]
+struct Strings(Vec<String>);
+
+impl GenericVisitor for Strings {
+    fn visit_leaf<T>(&mut self, leaf: &T)
+    where
+        T: Leaf + Debug + 'static,
+    {
+        if let Some(s) = (leaf as &dyn Any).downcast_ref::<String>() {
+            self.0.push(s.clone());
+        }
+    }
+}
+
+#[test]
+fn string() {
+    let owned = Owned {
+        a: "a".to_string(),
+        b: "b".into(),
+    };
+    assert_eq!(Owned::from_repr(owned.clone().into_repr()), owned);
+    assert_eq!(from_dyn::<Owned>(to_dyn(owned.clone())), Ok(owned.clone()));
+
+    let mut strings = Strings::default();
+    visit(owned, &mut strings);
+    assert_eq!(strings.0, ["a"]);
+
+    let text = String::from("borrowed");
+    let borrowed = Borrowed { a: &text };
+    assert_eq!(Borrowed::from_repr(borrowed.clone().into_repr()), borrowed);
+    assert_eq!(
+        to_dyn(borrowed),
+        DynRepr::Con(
+            "Borrowed",
+            Box::new(DynRepr::Prod(
+                Box::new(DynRepr::Meta(
+                    "a",
+                    Box::new(DynRepr::Prim(DynPrim::String(text.clone())))
+                )),
+                Box::new(DynRepr::Unit),
+            )),
+        )
+    );
+}
@@ -0,0 +1,74 @@
+use generics::ops::{strip, structural_convert, structural_eq, unstrip};
+use generics::{Generic, Prod, Sum, Unit};
+
+#[derive(Generic, Debug, PartialEq)]
+struct User {
+    name: String,
+    tags: Vec<Tag>,
+    role: Option<Role>,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct Tag(u8);
+
+#[derive(Generic, Debug, PartialEq)]
+enum Role {
+    Admin,
+    Member { since: u32 },
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct UserRecord {
+    user_name: String,
+    user_tags: Vec<TagRecord>,
+    user_role: Option<RoleRecord>,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct TagRecord {
+    value: u8,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+enum RoleRecord {
+    RoleAdmin,
+    RoleMember(u32),
+}
+
+#[test]
+fn strip_metadata() {
+    let user = User {
+        name: "a".to_string(),
+        tags: vec![Tag(1), Tag(2)],
+        role: Some(Role::Member { since: 2020 }),
+    };
+    let record = structural_convert::<_, UserRecord>(user);
+    assert_eq!(
+        record,
+        UserRecord {
+            user_name: "a".to_string(),
+            user_tags: vec![TagRecord { value: 1 }, TagRecord { value: 2 }],
+            user_role: Some(RoleRecord::RoleMember(2020)),
+        }
+    );
+    assert_eq!(
+        structural_convert::<_, User>(record),
+        User {
+            name: "a".to_string(),
+            tags: vec![Tag(1), Tag(2)],
+            role: Some(Role::Member { since: 2020 }),
+        }
+    );
+
+    assert!(structural_eq(Role::Admin, RoleRecord::RoleAdmin));
+    assert!(!structural_eq(Role::Admin, RoleRecord::RoleMember(0)));
+    assert!(!structural_eq(Tag(1), TagRecord { value: 2 }));
+
+    let Prod(value, Unit) = strip(Tag(3));
+    assert_eq!(value, 3);
+    assert_eq!(unstrip::<Tag>(Prod(4, Unit)), Tag(4));
+    assert_eq!(
+        unstrip::<Role>(Sum::Right(Sum::Left(Prod(1, Unit)))),
+        Role::Member { since: 1 }
+    );
+}
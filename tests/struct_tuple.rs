@@ -1,4 +1,4 @@
-use generics::{Generic, Prod, Unit};
+use generics::{Con, Generic, Meta, Prod, Singleton, Unit};
 
 trait Accumulate {
     fn acc(self) -> u64;
@@ -27,6 +27,28 @@ where
     }
 }
 
+impl<I, M> Accumulate for Meta<I, M>
+where
+    I: Accumulate,
+    M: Singleton,
+{
+    fn acc(self) -> u64 {
+        let Meta(inner, _) = self;
+        inner.acc()
+    }
+}
+
+impl<I, C> Accumulate for Con<I, C>
+where
+    I: Accumulate,
+    C: Singleton,
+{
+    fn acc(self) -> u64 {
+        let Con(inner, _) = self;
+        inner.acc()
+    }
+}
+
 #[derive(Generic)]
 struct Foo(u64, u64);
 
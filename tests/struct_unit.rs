@@ -1,4 +1,4 @@
-use generics::{Generic, Unit};
+use generics::{Con, Generic, Singleton, Unit};
 
 trait Accumulate {
     fn acc(self) -> u64;
@@ -10,6 +10,17 @@ impl Accumulate for Unit {
     }
 }
 
+impl<I, C> Accumulate for Con<I, C>
+where
+    I: Accumulate,
+    C: Singleton,
+{
+    fn acc(self) -> u64 {
+        let Con(inner, _) = self;
+        inner.acc()
+    }
+}
+
 #[derive(Generic)]
 struct Foo;
 
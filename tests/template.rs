@@ -0,0 +1,90 @@
+use generics::ops::{render, TemplateError};
+use generics::Generic;
+
+#[derive(Generic, Clone)]
+struct Notification {
+    recipient: Recipient,
+    items: Vec<String>,
+    total: f64,
+    window: (u8, u8),
+}
+
+#[derive(Generic, Clone)]
+struct Recipient {
+    name: String,
+    channel: Channel,
+}
+
+#[derive(Generic, Clone)]
+enum Channel {
+    Email(String),
+    Sms { number: u64 },
+}
+
+#[test]
+fn render_template() {
+    let notification = Notification {
+        recipient: Recipient {
+            name: "Grace".to_string(),
+            channel: Channel::Email("g@example.com".to_string()),
+        },
+        items: vec!["tea".to_string(), "cake".to_string()],
+        total: 12.5,
+        window: (9, 17),
+    };
+    assert_eq!(
+        render(
+            "Dear {recipient.name}: {items.0} and {items.1} cost ${ total }. Sent by {recipient.channel} to {recipient.channel.0}.",
+            notification.clone()
+        ),
+        Ok("Dear Grace: tea and cake cost $12.5. Sent by Email to g@example.com.".to_string())
+    );
+    assert_eq!(
+        render(
+            "Delivery between {window.0}:00 and {window.1}:00",
+            notification.clone()
+        ),
+        Ok("Delivery between 9:00 and 17:00".to_string())
+    );
+    assert_eq!(
+        render("{{literal}} }}{{", notification.clone()),
+        Ok("{literal} }{".to_string())
+    );
+    assert_eq!(render("", notification.clone()), Ok(String::new()));
+    assert_eq!(render("héllo {}", 7u8), Ok("héllo 7".to_string()));
+    assert_eq!(
+        render("{0} at ({1.0}, {1.1})", ("origin", (0, -2))),
+        Ok("origin at (0, -2)".to_string())
+    );
+
+    assert_eq!(
+        render("{recipient.channel.number}", notification.clone()),
+        Err(TemplateError::Unknown(
+            "recipient.channel.number".to_string()
+        ))
+    );
+    assert_eq!(
+        render("ab {total", notification.clone()),
+        Err(TemplateError::Unclosed(3))
+    );
+    assert_eq!(
+        render("a}b", notification.clone()),
+        Err(TemplateError::Unopened(1))
+    );
+    assert_eq!(
+        TemplateError::Unknown("x".to_string()).to_string(),
+        "unknown field `x` in template"
+    );
+
+    let sms = Notification {
+        recipient: Recipient {
+            name: "Alan".to_string(),
+            channel: Channel::Sms { number: 5550100 },
+        },
+        ..notification
+    };
+    assert_eq!(
+        render("{recipient.channel}: {recipient.channel.number}", sms),
+        Ok("Sms: 5550100".to_string())
+    );
+}
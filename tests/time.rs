@@ -0,0 +1,57 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use generics::dynamic::{from_dyn, to_dyn};
+use generics::markers::Duration_nanos;
+use generics::{Field, Generic, Meta, Prod, ReprError, TryFromRepr};
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Config {
+    timeout: Duration,
+    retry: Option<Duration>,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Telemetry {
+    started: Instant,
+    at: SystemTime,
+}
+
+#[test]
+fn time() {
+    let config = Config {
+        timeout: Duration::new(1, 2),
+        retry: Some(Duration::from_millis(1500)),
+    };
+    assert_eq!(Config::from_repr(config.clone().into_repr()), config);
+    assert_eq!(from_dyn::<Config>(to_dyn(config.clone())), Ok(config));
+
+    let repr = Duration::from_millis(1500).into_repr();
+    assert_eq!(*Field::<Duration_nanos, _>::field(&repr), 500_000_000);
+
+    let telemetry = Telemetry {
+        started: Instant::now(),
+        at: SystemTime::now(),
+    };
+    assert_eq!(
+        Telemetry::from_repr(telemetry.clone().into_repr()),
+        telemetry
+    );
+}
+
+#[test]
+fn time_overflow() {
+    let max = Duration::MAX.into_repr();
+    assert_eq!(Duration::checked_from_repr(max), Ok(Duration::MAX));
+
+    let mut repr = Duration::new(u64::MAX, 0).into_repr();
+    let Prod(_, Prod(Meta(nanos, _), _)) = &mut repr.0;
+    *nanos = 4_000_000_000;
+    let overflow = ReprError::new("`Duration` represented as more than `Duration::MAX`");
+    assert_eq!(Duration::try_from_repr(repr), Err(overflow));
+
+    // Nanoseconds carry into seconds, as with `Duration::new`.
+    let mut repr = Duration::new(1, 0).into_repr();
+    let Prod(_, Prod(Meta(nanos, _), _)) = &mut repr.0;
+    *nanos = 1_500_000_000;
+    assert_eq!(Duration::from_repr(repr), Duration::from_millis(2500));
+}
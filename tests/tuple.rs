@@ -0,0 +1,26 @@
+use generics::dynamic::{from_dyn, to_dyn};
+use generics::{At, Generic, Prod, Unit, S, Z};
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Foo {
+    a: (u8, (u16, ())),
+    b: (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u32),
+}
+
+#[test]
+fn tuple() {
+    let Unit = ().into_repr();
+
+    let repr = (1u8, 2u16, 3u32).into_repr();
+    assert_eq!(*At::<Z>::at(&repr), 1);
+    assert_eq!(*At::<S<S<Z>>>::at(&repr), 3);
+    let Prod(_, Prod(_, Prod(_, Unit))) = repr;
+    assert_eq!(<(u8, u16, u32)>::from_repr(repr), (1, 2, 3));
+
+    let foo = Foo {
+        a: (1, (2, ())),
+        b: (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12),
+    };
+    assert_eq!(Foo::from_repr(foo.clone().into_repr()), foo);
+    assert_eq!(from_dyn::<Foo>(to_dyn(foo.clone())), Ok(foo));
+}
@@ -0,0 +1,76 @@
+use std::num::NonZeroU8;
+
+use generics::ops::{from_tuple, into_tuple};
+use generics::Generic;
+
+#[derive(Generic, Debug, PartialEq)]
+struct Row {
+    id: i64,
+    name: String,
+    scores: Vec<u8>,
+    location: Point,
+}
+
+#[derive(Generic, Debug, PartialEq)]
+struct Point(f32, f32);
+
+#[derive(Generic, Debug, PartialEq)]
+struct Marker;
+
+#[derive(Generic, Debug, PartialEq)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+#[test]
+fn tuple_conversion() {
+    let tuple: (i64, String, Vec<u8>, Point) = into_tuple(Row {
+        id: 7,
+        name: "a".to_string(),
+        scores: vec![1, 2],
+        location: Point(0.5, 1.5),
+    })
+    .unwrap();
+    assert_eq!(tuple, (7, "a".to_string(), vec![1, 2], Point(0.5, 1.5)));
+
+    // Elements need only have the same representation as the fields.
+    let row: Row = from_tuple((
+        8i64,
+        "b".to_string(),
+        std::collections::VecDeque::from(vec![3u8]),
+        Point(2.0, 3.0),
+    ))
+    .unwrap();
+    assert_eq!(
+        row,
+        Row {
+            id: 8,
+            name: "b".to_string(),
+            scores: vec![3],
+            location: Point(2.0, 3.0),
+        }
+    );
+
+    let (x, y): (f32, f32) = into_tuple(Point(1.0, 2.0)).unwrap();
+    assert_eq!((x, y), (1.0, 2.0));
+
+    let () = into_tuple(Marker).unwrap();
+    assert_eq!(from_tuple::<_, Marker>(()), Ok(Marker));
+
+    let (inner,): (u8,) = into_tuple(Wrapper { inner: 3u8 }).unwrap();
+    assert_eq!(inner, 3);
+    assert_eq!(
+        from_tuple::<_, Wrapper<u8>>((4u8,)),
+        Ok(Wrapper { inner: 4 })
+    );
+
+    // Values the target rejects are an error.
+    assert!(from_tuple::<_, Wrapper<NonZeroU8>>((0u8,)).is_err());
+    assert_eq!(
+        from_tuple::<_, Wrapper<NonZeroU8>>((5u8,)),
+        Ok(Wrapper {
+            inner: NonZeroU8::new(5).unwrap()
+        })
+    );
+    assert!(into_tuple::<_, (NonZeroU8,)>(Wrapper { inner: 0u8 }).is_err());
+}
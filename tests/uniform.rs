@@ -0,0 +1,50 @@
+use generics::ops::{uniform_leaves, GenericIter};
+use generics::Generic;
+
+#[derive(Generic, GenericIter, Debug, PartialEq)]
+struct Vec3<T>(T, T, T);
+
+#[derive(Generic, Debug, PartialEq, Clone)]
+struct Counter {
+    hits: u64,
+    misses: u64,
+}
+
+#[derive(Generic, GenericIter, Debug, PartialEq)]
+struct Counters {
+    read: Counter,
+    write: Counter,
+}
+
+#[test]
+fn uniform() {
+    let mut v = Vec3(1.0f32, -2.0, 3.0);
+    assert_eq!(v.iter().len(), 3);
+    for x in &mut v {
+        *x *= 2.0;
+    }
+    let mut sum = 0.0;
+    for x in &v {
+        sum += x;
+    }
+    assert_eq!(sum, 4.0);
+    assert_eq!(v.into_iter().collect::<Vec<_>>(), [2.0, -4.0, 6.0]);
+
+    let mut counters = Counters {
+        read: Counter { hits: 1, misses: 2 },
+        write: Counter { hits: 3, misses: 4 },
+    };
+    counters.iter_mut().for_each(|c| c.hits += 10);
+    assert_eq!(counters.iter().map(|c| c.hits).sum::<u64>(), 24);
+    assert_eq!(
+        counters.into_iter().last(),
+        Some(Counter {
+            hits: 13,
+            misses: 4
+        })
+    );
+
+    assert_eq!(uniform_leaves(Counter { hits: 5, misses: 6 }), [5, 6]);
+    assert_eq!(uniform_leaves(([1u8, 2], 3u8)), [1, 2, 3]);
+    assert_eq!(uniform_leaves::<String, _>(()), Vec::<String>::new());
+}
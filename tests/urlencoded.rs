@@ -0,0 +1,105 @@
+#![cfg(feature = "urlencoded")]
+
+use generics::ops::{decode_query, encode_query, QueryError, UnflattenError};
+use generics::Generic;
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Request {
+    name: String,
+    page: Page,
+    tags: Vec<String>,
+    sort: Sort,
+    limit: Option<u32>,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Page {
+    number: u32,
+    size: u8,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Viewport {
+    origin: (i32, i32),
+    zoom: Option<(u8, u8)>,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Sort {
+    Newest,
+    By { field: String },
+}
+
+#[test]
+fn query() {
+    let request = Request {
+        name: "a b+c=d&e/100%".to_string(),
+        page: Page {
+            number: 2,
+            size: 50,
+        },
+        tags: vec!["x".to_string(), "ü".to_string()],
+        sort: Sort::By {
+            field: "date".to_string(),
+        },
+        limit: None,
+    };
+    let query = encode_query(request.clone());
+    assert_eq!(
+        query,
+        "name=a+b%2Bc%3Dd%26e%2F100%25&page.number=2&page.size=50&tags.0=x&tags.1=%C3%BC\
+         &sort=By&sort.field=date&limit=None"
+    );
+    assert_eq!(decode_query::<Request>(&query), Ok(request));
+
+    assert_eq!(
+        decode_query::<Page>("size=1&&number=%33&size=9&other"),
+        Ok(Page { number: 3, size: 9 })
+    );
+    assert_eq!(
+        decode_query::<Sort>("sort=Newest&=&"),
+        Err(QueryError::Unflatten(UnflattenError::Variant {
+            path: String::new(),
+            name: String::new()
+        }))
+    );
+    assert_eq!(decode_query::<Sort>("=Newest"), Ok(Sort::Newest));
+    assert_eq!(encode_query(Page { number: 0, size: 0 }), "number=0&size=0");
+
+    // Tuple elements are named by index.
+    let viewport = Viewport {
+        origin: (-1, 2),
+        zoom: Some((3, 4)),
+    };
+    let query = encode_query(viewport.clone());
+    assert_eq!(
+        query,
+        "origin.0=-1&origin.1=2&zoom=Some&zoom.0.0=3&zoom.0.1=4"
+    );
+    assert_eq!(decode_query::<Viewport>(&query), Ok(viewport));
+
+    assert_eq!(
+        decode_query::<Page>("number=1"),
+        Err(QueryError::Unflatten(UnflattenError::Missing(
+            "size".to_string()
+        )))
+    );
+    assert_eq!(
+        decode_query::<Page>("number=1&size=300")
+            .unwrap_err()
+            .to_string(),
+        "invalid value `300` for `size`"
+    );
+    assert_eq!(
+        decode_query::<Page>("number=1&size=%F0%28").unwrap_err(),
+        QueryError::Encoding("size=%F0%28".to_string())
+    );
+    assert_eq!(
+        decode_query::<Page>("number=1%2").unwrap_err().to_string(),
+        "invalid percent-encoding in `number=1%2`"
+    );
+    assert_eq!(
+        decode_query::<Page>("number=%+1&size=1"),
+        Err(QueryError::Encoding("number=%+1".to_string()))
+    );
+}
@@ -0,0 +1,33 @@
+use generics::ops::{self, GVariantName, GenericDisplay};
+use generics::Generic;
+
+#[derive(Generic, GenericDisplay, Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    r#Blue,
+}
+
+#[derive(Generic, GenericDisplay)]
+enum Never {}
+
+#[derive(Generic)]
+struct Foo {
+    _a: u8,
+}
+
+#[test]
+fn variant_name() {
+    assert_eq!(Color::Red.as_str(), "Red");
+    assert_eq!(Color::Green.as_str(), "Green");
+    assert_eq!(Color::Blue.as_str(), "Blue");
+    assert_eq!(format!("{}", Color::Red), "Red");
+    assert_eq!(format!("{:>5}|", Color::Red), "  Red|");
+    assert_eq!(Color::Green.into_repr().variant_name(), "Green");
+
+    assert_eq!(ops::variant_name(None::<u8>), "None");
+    assert_eq!(ops::variant_name(Ok::<_, ()>(1)), "Ok");
+    assert_eq!(ops::variant_name(Foo { _a: 1 }), "Foo");
+
+    let _ = |never: &Never| never.as_str();
+}
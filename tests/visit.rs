@@ -0,0 +1,99 @@
+use std::any::Any;
+use std::fmt::Debug;
+
+use generics::{visit, Generic, GenericVisitor, Leaf};
+
+#[derive(Default)]
+struct Events(Vec<String>);
+
+impl GenericVisitor for Events {
+    fn enter_constructor(&mut self, name: &'static str) {
+        self.0.push(format!("enter {}", name));
+    }
+
+    fn leave_constructor(&mut self, name: &'static str) {
+        self.0.push(format!("leave {}", name));
+    }
+
+    fn visit_field(&mut self, name: &'static str) {
+        self.0.push(format!("field {}", name));
+    }
+
+    fn visit_leaf<T>(&mut self, leaf: &T)
+    where
+        T: Leaf + Debug + 'static,
+    {
+        self.0.push(format!("leaf {:?}", leaf));
+    }
+}
+
+#[derive(Default)]
+struct CollectU8(Vec<u8>);
+
+impl GenericVisitor for CollectU8 {
+    fn visit_leaf<T>(&mut self, leaf: &T)
+    where
+        T: Leaf + Debug + 'static,
+    {
+        if let Some(x) = (leaf as &dyn Any).downcast_ref::<u8>() {
+            self.0.push(*x);
+        }
+    }
+}
+
+#[derive(Generic)]
+struct Unit;
+
+#[derive(Generic)]
+struct Pair(u8, u32);
+
+#[derive(Generic)]
+struct Foo {
+    a: u8,
+    pair: Pair,
+    unit: Unit,
+}
+
+#[test]
+fn visit_events() {
+    let foo = Foo {
+        a: 1,
+        pair: Pair(2, 3),
+        unit: Unit,
+    };
+
+    let mut events = Events::default();
+    visit(foo, &mut events);
+    assert_eq!(
+        events.0,
+        [
+            "enter Foo",
+            "field a",
+            "leaf 1",
+            "field pair",
+            "enter Pair",
+            "field 0",
+            "leaf 2",
+            "field 1",
+            "leaf 3",
+            "leave Pair",
+            "field unit",
+            "enter Unit",
+            "leave Unit",
+            "leave Foo",
+        ]
+    );
+}
+
+#[test]
+fn visit_collect() {
+    let foo = Foo {
+        a: 1,
+        pair: Pair(2, 3),
+        unit: Unit,
+    };
+
+    let mut collect = CollectU8::default();
+    visit(foo, &mut collect);
+    assert_eq!(collect.0, [1, 2]);
+}
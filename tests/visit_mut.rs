@@ -0,0 +1,130 @@
+use std::any::Any;
+use std::fmt::Debug;
+
+use generics::{visit_mut, Generic, GenericMut, GenericVisitorMut};
+
+#[derive(Default)]
+struct Events(Vec<String>);
+
+impl GenericVisitorMut for Events {
+    fn enter_constructor(&mut self, name: &'static str) {
+        self.0.push(format!("enter {}", name));
+    }
+
+    fn leave_constructor(&mut self, name: &'static str) {
+        self.0.push(format!("leave {}", name));
+    }
+
+    fn visit_field(&mut self, name: &'static str) {
+        self.0.push(format!("field {}", name));
+    }
+
+    fn visit_leaf_mut<T>(&mut self, leaf: &mut T)
+    where
+        T: Debug + 'static,
+    {
+        self.0.push(format!("leaf {:?}", leaf));
+    }
+}
+
+struct Increment;
+
+impl GenericVisitorMut for Increment {
+    fn visit_leaf_mut<T>(&mut self, leaf: &mut T)
+    where
+        T: Debug + 'static,
+    {
+        if let Some(x) = (leaf as &mut dyn Any).downcast_mut::<u8>() {
+            *x += 1;
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Generic, GenericMut)]
+struct Pair(u8, u32);
+
+#[derive(Debug, PartialEq, Generic, GenericMut)]
+enum Shape {
+    Empty,
+    Point(u8, u8),
+    Named { name: String, size: u8 },
+}
+
+#[derive(Debug, PartialEq, Generic, GenericMut)]
+struct Foo {
+    a: u8,
+    pair: Pair,
+    shapes: Vec<Shape>,
+    grid: [Option<u8>; 2],
+    tuple: (u8, Result<u8, String>),
+    #[generic(opaque)]
+    raw: u8,
+}
+
+fn foo() -> Foo {
+    Foo {
+        a: 1,
+        pair: Pair(2, 3),
+        shapes: vec![
+            Shape::Empty,
+            Shape::Point(4, 5),
+            Shape::Named {
+                name: "x".to_string(),
+                size: 6,
+            },
+        ],
+        grid: [Some(7), None],
+        tuple: (8, Ok(9)),
+        raw: 10,
+    }
+}
+
+#[test]
+fn visit_mut_events() {
+    let mut pair = Pair(1, 2);
+    let mut events = Events::default();
+    visit_mut(&mut pair, &mut events);
+    assert_eq!(
+        events.0,
+        [
+            "enter Pair",
+            "field 0",
+            "leaf 1",
+            "field 1",
+            "leaf 2",
+            "leave Pair",
+        ]
+    );
+}
+
+#[test]
+fn visit_mut_updates_leaves() {
+    let mut foo = foo();
+    visit_mut(&mut foo, &mut Increment);
+    assert_eq!(
+        foo,
+        Foo {
+            a: 2,
+            pair: Pair(3, 3),
+            shapes: vec![
+                Shape::Empty,
+                Shape::Point(5, 6),
+                Shape::Named {
+                    name: "x".to_string(),
+                    size: 7,
+                },
+            ],
+            grid: [Some(8), None],
+            tuple: (9, Ok(10)),
+            raw: 11,
+        }
+    );
+}
+
+#[test]
+fn as_repr_mut() {
+    let mut pair = Pair(1, 2);
+    let generics::Con(generics::Prod(generics::Meta(a, _), _), _) = pair.as_repr_mut();
+    *a = 5;
+    assert_eq!(pair, Pair(5, 2));
+}
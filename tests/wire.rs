@@ -0,0 +1,108 @@
+use generics::ops::{
+    decode_wire, encode_wire, encode_wire_to_slice, GEncodeWire, SliceWriter, WireError,
+};
+use generics::{Generic, Opaque};
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+struct Message {
+    id: u64,
+    offset: i64,
+    level: i8,
+    ok: bool,
+    sep: char,
+    text: String,
+    ratio: f32,
+    events: Vec<Event>,
+    pos: [u16; 2],
+    extra: (u128, Option<i128>),
+    #[generic(opaque)]
+    code: u32,
+}
+
+#[derive(Generic, Clone, Debug, PartialEq)]
+enum Event {
+    Start,
+    Value(i16),
+    Range { from: u32, to: u32 },
+}
+
+#[test]
+fn wire() {
+    let msg = Message {
+        id: 128,
+        offset: -65,
+        level: -1,
+        ok: true,
+        sep: 'é',
+        text: "hi".to_string(),
+        ratio: 0.5,
+        events: vec![
+            Event::Start,
+            Event::Value(-3),
+            Event::Range { from: 1, to: 300 },
+        ],
+        pos: [0, 16384],
+        extra: (u128::MAX, Some(i128::MIN)),
+        code: 7,
+    };
+    let bytes = encode_wire(msg.clone());
+    #[rustfmt::skip]
+    let expected: Vec<u8> = [
+        &[0x80, 0x01][..],
+        &[0x81, 0x01],
+        &[0xff],
+        &[1],
+        &[2, 0xc3, 0xa9],
+        &[2, b'h', b'i'],
+        &0.5f32.to_le_bytes(),
+        &[3, 0, 1, 5, 2, 1, 0xac, 0x02],
+        &[0, 0x80, 0x80, 0x01],
+        &[0xff; 18], &[0x03],
+        &[1], &[0xff; 18], &[0x03],
+        &[7],
+    ]
+    .concat();
+    assert_eq!(bytes, expected);
+    assert_eq!(decode_wire::<Message>(&bytes), Ok(msg.clone()));
+
+    let mut buf = [0; 128];
+    let written = encode_wire_to_slice(msg.clone(), &mut buf).unwrap();
+    assert_eq!(written, &expected[..]);
+    let mut small = [0; 16];
+    assert_eq!(
+        encode_wire_to_slice(msg.clone(), &mut small),
+        Err(WireError::Full)
+    );
+
+    let mut buf = [0; 4];
+    let mut w = SliceWriter::new(&mut buf);
+    Opaque(300u16).gencode_wire(&mut w).unwrap();
+    assert_eq!(w.into_written(), [0xac, 0x02]);
+
+    assert_eq!(
+        decode_wire::<u8>(&[0x80, 0x02]),
+        Err(WireError::Trailing(1))
+    );
+    assert_eq!(decode_wire::<Event>(&[3]), Err(WireError::Variant(3)));
+    assert_eq!(
+        decode_wire::<u16>(&[0x80, 0x80, 0x04]),
+        Err(WireError::Invalid("varint"))
+    );
+    assert_eq!(decode_wire::<u32>(&[0x80]), Err(WireError::Eof));
+    assert_eq!(
+        decode_wire::<u128>(&[0xff; 19]),
+        Err(WireError::Invalid("varint"))
+    );
+    assert_eq!(
+        decode_wire::<char>(&[2, b'a', b'b'])
+            .unwrap_err()
+            .to_string(),
+        "invalid encoding of char"
+    );
+    assert_eq!(
+        decode_wire::<Vec<()>>(&[0xff, 0xff, 0xff, 0xff, 0x0f]),
+        Err(WireError::Invalid("length"))
+    );
+    assert_eq!(decode_wire::<Vec<u8>>(&[3, 1, 2]), Err(WireError::Eof));
+    assert_eq!(decode_wire::<Vec<()>>(&[3]), Ok(vec![(); 3]));
+}
@@ -0,0 +1,69 @@
+use generics::ops::{zeroize, GenericZeroize, Zeroize, Zeroizing};
+use generics::{Generic, GenericMut};
+
+#[derive(Generic, GenericMut, GenericZeroize, Debug, PartialEq)]
+struct Inner {
+    pin: [u8; 4],
+    valid: bool,
+}
+
+#[derive(Generic, GenericMut, GenericZeroize, Debug, PartialEq)]
+enum Secret {
+    Password(String),
+    Key {
+        inner: Inner,
+        scores: Vec<f64>,
+        #[generic(opaque)]
+        raw: Vec<i16>,
+    },
+}
+
+#[test]
+fn zeroize_in_place() {
+    let mut password = Secret::Password(String::from("hunter2"));
+    password.zeroize();
+    assert_eq!(password, Secret::Password(String::new()));
+
+    let mut key = Secret::Key {
+        inner: Inner {
+            pin: [1, 2, 3, 4],
+            valid: true,
+        },
+        scores: vec![0.5, 1.5],
+        raw: vec![-1, 2],
+    };
+    key.zeroize();
+    assert_eq!(
+        key,
+        Secret::Key {
+            inner: Inner {
+                pin: [0; 4],
+                valid: false,
+            },
+            scores: vec![0.0, 0.0],
+            raw: vec![],
+        }
+    );
+
+    let mut tuple = (String::from("x"), Some('y'), [1u64; 2]);
+    zeroize(&mut tuple);
+    assert_eq!(tuple, (String::new(), Some('\0'), [0; 2]));
+
+    let mut string = String::with_capacity(16);
+    string.push_str("secret");
+    string.zeroize();
+    assert!(string.is_empty());
+    assert!(string.capacity() >= 16);
+
+    let mut option = Some(3u8);
+    Zeroize::zeroize(&mut option);
+    assert_eq!(option, None);
+
+    let mut wrapped = Zeroizing::new(Inner {
+        pin: [9; 4],
+        valid: true,
+    });
+    wrapped.pin[0] = 8;
+    assert_eq!(wrapped.pin, [8, 9, 9, 9]);
+    drop(wrapped);
+}
@@ -0,0 +1,58 @@
+use generics::ops::{gzip, ZipError};
+use generics::{Con, Generic, List, Meta, Prod, Sum, Unit};
+
+#[derive(Generic, Debug)]
+enum Shape {
+    Circle { r: u8 },
+    Poly(Vec<(i8, i8)>, [bool; 2]),
+}
+
+#[test]
+fn zip() {
+    let zipped = gzip(Shape::Circle { r: 1 }, Shape::Circle { r: 2 }).unwrap();
+    match zipped {
+        Sum::Left(Con(Prod(Meta(r, _), Unit), _)) => assert_eq!(r, (1, 2)),
+        _ => panic!("expected Circle"),
+    }
+
+    let zipped = gzip(
+        Shape::Poly(vec![(1, 2), (3, 4)], [true, false]),
+        Shape::Poly(vec![(5, 6), (7, 8)], [false, false]),
+    )
+    .unwrap();
+    match zipped {
+        Sum::Right(Sum::Left(Con(Prod(Meta(List(points), _), Prod(Meta(flags, _), Unit)), _))) => {
+            let points = points
+                .into_iter()
+                .map(|Prod(x, Prod(y, Unit))| (x, y))
+                .collect::<Vec<_>>();
+            assert_eq!(points, [((1, 5), (2, 6)), ((3, 7), (4, 8))]);
+            assert_eq!(flags, [(true, false), (false, false)]);
+        }
+        _ => panic!("expected Poly"),
+    }
+
+    let err = gzip(Shape::Circle { r: 1 }, Shape::Poly(vec![], [true; 2]))
+        .err()
+        .unwrap();
+    assert_eq!(
+        err,
+        ZipError::Variant {
+            left: "Circle",
+            right: "Poly"
+        }
+    );
+    assert_eq!(err.to_string(), "mismatched variants `Circle` and `Poly`");
+
+    let err = gzip(vec![1, 2], vec![3]).err().unwrap();
+    assert_eq!(err, ZipError::Length { left: 2, right: 1 });
+    assert_eq!(err.to_string(), "mismatched lengths 2 and 1");
+
+    assert_eq!(
+        gzip([Some(1), None], [Some(2), Some(3)]).err().unwrap(),
+        ZipError::Variant {
+            left: "None",
+            right: "Some"
+        }
+    );
+}
@@ -0,0 +1,63 @@
+use generics::zipper::Zipper;
+use generics::{Generic, Sum, Unit};
+
+#[derive(Generic)]
+struct Inner {
+    x: u8,
+    y: u8,
+}
+
+#[derive(Generic)]
+struct Foo {
+    a: u32,
+    inner: Inner,
+}
+
+#[test]
+fn zipper_navigate() {
+    let foo = Foo {
+        a: 1,
+        inner: Inner { x: 2, y: 3 },
+    };
+
+    let zipper = Zipper::new(foo.into_repr()).down().down();
+    assert_eq!(zipper.focus().0, 1);
+
+    let zipper = zipper.right().down().down().down().down().down();
+    assert_eq!(*zipper.focus(), 2);
+
+    let zipper = zipper.up().right().down().down();
+    assert_eq!(*zipper.focus(), 3);
+
+    let (zipper, old) = zipper.replace(42);
+    assert_eq!(old, 3);
+
+    let zipper = zipper.up().up().left().down();
+    assert_eq!(*zipper.focus(), 2);
+
+    let foo: Foo = zipper.finish();
+    assert_eq!(foo.a, 1);
+    assert_eq!(foo.inner.x, 2);
+    assert_eq!(foo.inner.y, 42);
+}
+
+#[test]
+fn zipper_sum() {
+    let repr: Sum<u8, Unit> = Sum::Left(1);
+
+    let zipper = Zipper::new(repr);
+    let zipper = match zipper.down_right() {
+        Ok(_) => panic!("expected left variant"),
+        Err(zipper) => zipper,
+    };
+    let mut zipper = match zipper.down_left() {
+        Ok(zipper) => zipper,
+        Err(_) => panic!("expected left variant"),
+    };
+    *zipper.focus_mut() += 41;
+
+    match zipper.into_repr() {
+        Sum::Left(x) => assert_eq!(x, 42),
+        Sum::Right(_) => panic!("expected left variant"),
+    }
+}